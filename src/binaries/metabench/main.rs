@@ -144,6 +144,7 @@ async fn benchmark_table(client: &Arc<ClientHandle>, client_num: u32, i: u32) {
                 table_name: format!("table-{}", client_num),
             },
             table_meta: Default::default(),
+            dependency: None,
         })
         .await;
 
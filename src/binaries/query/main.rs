@@ -34,6 +34,7 @@ use common_tracing::set_panic_hook;
 use databend_query::api::HttpService;
 use databend_query::api::RpcService;
 use databend_query::clusters::ClusterDiscovery;
+use databend_query::maintenance::BackgroundMaintenance;
 use databend_query::metrics::MetricService;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
@@ -210,6 +211,11 @@ async fn main_entrypoint() -> Result<()> {
         );
     }
 
+    // Background maintenance of the tables.
+    {
+        BackgroundMaintenance::instance().start().await?;
+    }
+
     // Print information to users.
     println!("Databend Query");
     println!();
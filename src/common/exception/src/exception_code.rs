@@ -127,6 +127,11 @@ build_exceptions! {
     InvalidTimestamp(1080),
     InvalidClusterKeys(1081),
     UnknownFragmentExchange(1082),
+    /// UnsupportedPlaceholder is used when a parameter placeholder is in a clause
+    /// which only accepts constants.
+    ///
+    /// For example: `SELECT * FROM t LIMIT ?`.
+    UnsupportedPlaceholder(1083),
     TenantIsEmpty(1101),
     IndexOutOfBounds(1102),
     LayoutError(1103),
@@ -204,6 +209,18 @@ build_exceptions! {
     /// - not exists.
     /// - and without `IF EXISTS`
     CatalogNotFound(2320),
+    IndexAlreadyExists(2321),
+    UnknownIndex(2322),
+    /// The offset of a stream has been changed since it was read by the statement.
+    StreamVersionMismatched(2323),
+    IllegalStream(2324),
+    /// A tag or branch of a table, both named snapshots sharing one namespace.
+    UnknownSnapshotReference(2325),
+    SnapshotReferenceAlreadyExists(2326),
+    /// The head of the table is not an ancestor of the head of the branch fast-forwarded to.
+    IllegalFastForward(2327),
+    VirtualColumnAlreadyExists(2328),
+    UnknownVirtualColumn(2329),
 
     // Cluster error codes.
     ClusterUnknownNode(2401),
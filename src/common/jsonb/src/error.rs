@@ -79,6 +79,12 @@ pub enum Error {
     InvalidJsonbHeader,
     InvalidJsonbJEntry,
 
+    InvalidJsonPath,
+
+    InvalidObject,
+    InvalidArray,
+    ObjectDuplicateKey,
+
     Syntax(ParseErrorCode, usize),
 }
 
@@ -15,6 +15,7 @@
 use core::convert::TryInto;
 use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::collections::BTreeSet;
 use std::collections::VecDeque;
 
 use super::constants::*;
@@ -351,6 +352,91 @@ pub fn object_keys(value: &[u8]) -> Option<Vec<u8>> {
     }
 }
 
+/// Insert a new key-value pair into a `JSONB` object, the keys are kept in sorted order.
+/// If the key already exists, the value is replaced when `update_flag` is true,
+/// otherwise an error is returned.
+pub fn object_insert(
+    value: &[u8],
+    new_key: &str,
+    new_val: &[u8],
+    update_flag: bool,
+    buf: &mut Vec<u8>,
+) -> Result<(), Error> {
+    let value = to_jsonb(value);
+    let new_val = to_jsonb(new_val);
+    let mut entries = object_entries(&value)?.ok_or(Error::InvalidObject)?;
+    match entries.binary_search_by(|(key, _)| key.as_str().cmp(new_key)) {
+        Ok(idx) => {
+            if !update_flag {
+                return Err(Error::ObjectDuplicateKey);
+            }
+            entries[idx].1 = new_val.to_vec();
+        }
+        Err(idx) => entries.insert(idx, (new_key.to_string(), new_val.to_vec())),
+    }
+    build_object(entries.iter().map(|(k, v)| (k, v.as_slice())), buf)
+}
+
+/// Delete the keys from a `JSONB` object, keys that do not exist are ignored.
+pub fn object_delete(value: &[u8], keys: &BTreeSet<&str>, buf: &mut Vec<u8>) -> Result<(), Error> {
+    let value = to_jsonb(value);
+    let entries = object_entries(&value)?.ok_or(Error::InvalidObject)?;
+    let items = entries
+        .iter()
+        .filter(|(k, _)| !keys.contains(k.as_str()))
+        .map(|(k, v)| (k, v.as_slice()));
+    build_object(items, buf)
+}
+
+/// Build a new `JSONB` object that only contains the given keys of the object.
+pub fn object_pick(value: &[u8], keys: &BTreeSet<&str>, buf: &mut Vec<u8>) -> Result<(), Error> {
+    let value = to_jsonb(value);
+    let entries = object_entries(&value)?.ok_or(Error::InvalidObject)?;
+    let items = entries
+        .iter()
+        .filter(|(k, _)| keys.contains(k.as_str()))
+        .map(|(k, v)| (k, v.as_slice()));
+    build_object(items, buf)
+}
+
+/// Append a value to the end of a `JSONB` array.
+pub fn array_append(value: &[u8], item: &[u8], buf: &mut Vec<u8>) -> Result<(), Error> {
+    let value = to_jsonb(value);
+    let item = to_jsonb(item);
+    let mut values = array_values(&value)?.ok_or(Error::InvalidArray)?;
+    values.push(item.to_vec());
+    build_array(values.iter().map(|v| v.as_slice()), buf)
+}
+
+/// Remove all the object fields that have null values recursively,
+/// null values in arrays are kept.
+pub fn strip_nulls(value: &[u8], buf: &mut Vec<u8>) -> Result<(), Error> {
+    let value = to_jsonb(value);
+    if let Some(entries) = object_entries(&value)? {
+        let mut items = Vec::with_capacity(entries.len());
+        for (key, val) in entries {
+            if as_null(&val).is_some() {
+                continue;
+            }
+            let mut val_buf = Vec::new();
+            strip_nulls(&val, &mut val_buf)?;
+            items.push((key, val_buf));
+        }
+        build_object(items.iter().map(|(k, v)| (k, v.as_slice())), buf)
+    } else if let Some(values) = array_values(&value)? {
+        let mut items = Vec::with_capacity(values.len());
+        for val in values {
+            let mut val_buf = Vec::new();
+            strip_nulls(&val, &mut val_buf)?;
+            items.push(val_buf);
+        }
+        build_array(items.iter().map(|v| v.as_slice()), buf)
+    } else {
+        buf.extend_from_slice(&value);
+        Ok(())
+    }
+}
+
 /// `JSONB` values supports partial decode for comparison,
 /// if the values are found to be unequal, the result will be returned immediately.
 /// In first level header, values compare as the following order:
@@ -868,6 +954,28 @@ pub fn is_object(value: &[u8]) -> bool {
     matches!(header & CONTAINER_HEADER_TYPE_MASK, OBJECT_CONTAINER_TAG)
 }
 
+/// Returns the type name of the `JSONB` value, one of
+/// `null`, `boolean`, `number`, `string`, `array` and `object`.
+pub fn type_of(value: &[u8]) -> Result<&'static str, Error> {
+    let value = to_jsonb(value);
+    let header = read_u32(&value, 0)?;
+    match header & CONTAINER_HEADER_TYPE_MASK {
+        SCALAR_CONTAINER_TAG => {
+            let jentry = JEntry::decode_jentry(read_u32(&value, 4)?);
+            match jentry.type_code {
+                NULL_TAG => Ok("null"),
+                TRUE_TAG | FALSE_TAG => Ok("boolean"),
+                NUMBER_TAG => Ok("number"),
+                STRING_TAG => Ok("string"),
+                _ => Err(Error::InvalidJsonbJEntry),
+            }
+        }
+        ARRAY_CONTAINER_TAG => Ok("array"),
+        OBJECT_CONTAINER_TAG => Ok("object"),
+        _ => Err(Error::InvalidJsonbHeader),
+    }
+}
+
 /// Parse path string to Json path.
 /// Support `["<name>"]`, `[<index>]`, `:name` and `.name`.
 pub fn parse_json_path(path: &[u8]) -> Result<Vec<JsonPathRef>, Error> {
@@ -1068,9 +1176,122 @@ fn scalar_to_string(
     *value_offset += length;
 }
 
+/// Convert `JSONB` value to a pretty String, nested values are indented with two spaces.
+pub fn to_pretty_string(value: &[u8]) -> String {
+    let value = to_jsonb(value);
+    let mut json = String::new();
+    container_to_pretty_string(&value, &mut 0, 0, &mut json);
+    json
+}
+
+fn container_to_pretty_string(value: &[u8], offset: &mut usize, indent: usize, json: &mut String) {
+    let header = read_u32(value, *offset).unwrap();
+    let length = (header & CONTAINER_HEADER_LEN_MASK) as usize;
+    match header & CONTAINER_HEADER_TYPE_MASK {
+        SCALAR_CONTAINER_TAG => {
+            let mut jentry_offset = 4 + *offset;
+            let mut value_offset = 8 + *offset;
+            scalar_to_pretty_string(value, &mut jentry_offset, &mut value_offset, indent, json);
+        }
+        ARRAY_CONTAINER_TAG => {
+            if length == 0 {
+                json.push_str("[]");
+                return;
+            }
+            json.push_str("[\n");
+            let mut jentry_offset = 4 + *offset;
+            let mut value_offset = 4 + *offset + 4 * length;
+            for i in 0..length {
+                if i > 0 {
+                    json.push_str(",\n");
+                }
+                push_indent(indent + 1, json);
+                scalar_to_pretty_string(
+                    value,
+                    &mut jentry_offset,
+                    &mut value_offset,
+                    indent + 1,
+                    json,
+                );
+            }
+            json.push('\n');
+            push_indent(indent, json);
+            json.push(']');
+        }
+        OBJECT_CONTAINER_TAG => {
+            if length == 0 {
+                json.push_str("{}");
+                return;
+            }
+            json.push_str("{\n");
+            let mut jentry_offset = 4 + *offset;
+            let mut key_offset = 4 + *offset + 8 * length;
+            let mut keys = VecDeque::with_capacity(length);
+            for _ in 0..length {
+                let jentry_encoded = read_u32(value, jentry_offset).unwrap();
+                let jentry = JEntry::decode_jentry(jentry_encoded);
+                let key_length = jentry.length as usize;
+                let key = unsafe {
+                    std::str::from_utf8_unchecked(&value[key_offset..key_offset + key_length])
+                };
+                keys.push_back(key);
+                jentry_offset += 4;
+                key_offset += key_length;
+            }
+            let mut value_offset = key_offset;
+            for i in 0..length {
+                if i > 0 {
+                    json.push_str(",\n");
+                }
+                push_indent(indent + 1, json);
+                let key = keys.pop_front().unwrap();
+                json.push('\"');
+                json.push_str(key);
+                json.push_str("\": ");
+                scalar_to_pretty_string(
+                    value,
+                    &mut jentry_offset,
+                    &mut value_offset,
+                    indent + 1,
+                    json,
+                );
+            }
+            json.push('\n');
+            push_indent(indent, json);
+            json.push('}');
+        }
+        _ => {}
+    }
+}
+
+fn scalar_to_pretty_string(
+    value: &[u8],
+    jentry_offset: &mut usize,
+    value_offset: &mut usize,
+    indent: usize,
+    json: &mut String,
+) {
+    let jentry_encoded = read_u32(value, *jentry_offset).unwrap();
+    let jentry = JEntry::decode_jentry(jentry_encoded);
+    if jentry.type_code == CONTAINER_TAG {
+        let length = jentry.length as usize;
+        container_to_pretty_string(value, value_offset, indent, json);
+        *jentry_offset += 4;
+        *value_offset += length;
+    } else {
+        scalar_to_string(value, jentry_offset, value_offset, json);
+    }
+}
+
+fn push_indent(indent: usize, json: &mut String) {
+    for _ in 0..indent {
+        json.push_str("  ");
+    }
+}
+
 // Check whether the value is `JSONB` format,
 // for compatibility with previous `JSON` string.
-fn is_jsonb(value: &[u8]) -> bool {
+pub(crate) fn is_jsonb(value: &[u8]) -> bool {
     if let Some(v) = value.first() {
         if *v == ARRAY_PREFIX || *v == OBJECT_PREFIX || *v == SCALAR_PREFIX {
             return true;
@@ -1079,6 +1300,82 @@ fn is_jsonb(value: &[u8]) -> bool {
     false
 }
 
+// Convert the previous `JSON` string to `JSONB` format.
+pub(crate) fn to_jsonb(value: &[u8]) -> Cow<'_, [u8]> {
+    if is_jsonb(value) {
+        Cow::Borrowed(value)
+    } else {
+        let json_value = decode_value(value).unwrap();
+        Cow::Owned(json_value.to_vec())
+    }
+}
+
+// Extract the value of a jentry as a standalone `JSONB` value.
+fn extract_value(value: &[u8], jentry_encoded: u32, offset: usize) -> Vec<u8> {
+    let jentry = JEntry::decode_jentry(jentry_encoded);
+    let length = jentry.length as usize;
+    if jentry.type_code == CONTAINER_TAG {
+        return value[offset..offset + length].to_vec();
+    }
+    let mut buf = Vec::with_capacity(8 + length);
+    buf.extend_from_slice(&SCALAR_CONTAINER_TAG.to_be_bytes());
+    buf.extend_from_slice(&jentry_encoded.to_be_bytes());
+    buf.extend_from_slice(&value[offset..offset + length]);
+    buf
+}
+
+// Returns the elements of a `JSONB` array as standalone `JSONB` values,
+// or None if the value is not an array.
+fn array_values(value: &[u8]) -> Result<Option<Vec<Vec<u8>>>, Error> {
+    let header = read_u32(value, 0)?;
+    if header & CONTAINER_HEADER_TYPE_MASK != ARRAY_CONTAINER_TAG {
+        return Ok(None);
+    }
+    let length = (header & CONTAINER_HEADER_LEN_MASK) as usize;
+    let mut values = Vec::with_capacity(length);
+    let mut jentry_offset = 4;
+    let mut val_offset = 4 * length + 4;
+    for _ in 0..length {
+        let jentry_encoded = read_u32(value, jentry_offset)?;
+        values.push(extract_value(value, jentry_encoded, val_offset));
+        jentry_offset += 4;
+        val_offset += JEntry::decode_jentry(jentry_encoded).length as usize;
+    }
+    Ok(Some(values))
+}
+
+type ObjectEntries = Vec<(String, Vec<u8>)>;
+
+// Returns the keys and values of a `JSONB` object, values are standalone `JSONB` values,
+// or None if the value is not an object.
+fn object_entries(value: &[u8]) -> Result<Option<ObjectEntries>, Error> {
+    let header = read_u32(value, 0)?;
+    if header & CONTAINER_HEADER_TYPE_MASK != OBJECT_CONTAINER_TAG {
+        return Ok(None);
+    }
+    let length = (header & CONTAINER_HEADER_LEN_MASK) as usize;
+    let mut keys = Vec::with_capacity(length);
+    let mut jentry_offset = 4;
+    let mut key_offset = 8 * length + 4;
+    for _ in 0..length {
+        let key_jentry = JEntry::decode_jentry(read_u32(value, jentry_offset)?);
+        let key_length = key_jentry.length as usize;
+        let key = std::str::from_utf8(&value[key_offset..key_offset + key_length])?;
+        keys.push(key.to_string());
+        jentry_offset += 4;
+        key_offset += key_length;
+    }
+    let mut entries = Vec::with_capacity(length);
+    let mut val_offset = key_offset;
+    for key in keys {
+        let jentry_encoded = read_u32(value, jentry_offset)?;
+        entries.push((key, extract_value(value, jentry_encoded, val_offset)));
+        jentry_offset += 4;
+        val_offset += JEntry::decode_jentry(jentry_encoded).length as usize;
+    }
+    Ok(Some(entries))
+}
+
 fn read_char(buf: &[u8], idx: &mut usize) -> Result<u8, Error> {
     match buf.get(*idx) {
         Some(v) => {
@@ -1089,7 +1386,7 @@ fn read_char(buf: &[u8], idx: &mut usize) -> Result<u8, Error> {
     }
 }
 
-fn read_u32(buf: &[u8], idx: usize) -> Result<u32, Error> {
+pub(crate) fn read_u32(buf: &[u8], idx: usize) -> Result<u32, Error> {
     let bytes: [u8; 4] = buf
         .get(idx..idx + 4)
         .ok_or(Error::InvalidEOF)?
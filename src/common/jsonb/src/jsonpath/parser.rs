@@ -0,0 +1,396 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+
+use super::path::*;
+use crate::error::Error;
+use crate::number::Number;
+
+/// Parse a SQL/JSON path string, such as `$.a[*] ? (@.b > 1).c`.
+pub fn parse_json_path(input: &[u8]) -> Result<JsonPath<'_>, Error> {
+    let mut parser = PathParser::new(input);
+    let path = parser.parse()?;
+    Ok(path)
+}
+
+// A predicate or a plain expression, only the top level of the path
+// and the operands of comparisons allow plain expressions.
+enum Node<'a> {
+    Predicate(Predicate<'a>),
+    Expr(Expr<'a>),
+}
+
+impl<'a> Node<'a> {
+    fn into_predicate(self) -> Result<Predicate<'a>, Error> {
+        match self {
+            Node::Predicate(pred) => Ok(pred),
+            Node::Expr(_) => Err(Error::InvalidJsonPath),
+        }
+    }
+}
+
+struct PathParser<'a> {
+    buf: &'a [u8],
+    idx: usize,
+}
+
+impl<'a> PathParser<'a> {
+    fn new(buf: &'a [u8]) -> PathParser<'a> {
+        Self { buf, idx: 0 }
+    }
+
+    fn parse(&mut self) -> Result<JsonPath<'a>, Error> {
+        let mode = if self.eat_keyword("strict") {
+            Mode::Strict
+        } else {
+            self.eat_keyword("lax");
+            Mode::Lax
+        };
+        let expr = match self.parse_or()? {
+            Node::Predicate(pred) => PathExpr::Predicate(Box::new(pred)),
+            Node::Expr(Expr::Paths(paths)) => PathExpr::Paths(paths),
+            Node::Expr(Expr::Value(_)) => return Err(Error::InvalidJsonPath),
+        };
+        self.skip_whitespace();
+        if self.idx < self.buf.len() {
+            return Err(Error::InvalidJsonPath);
+        }
+        Ok(JsonPath { mode, expr })
+    }
+
+    fn parse_or(&mut self) -> Result<Node<'a>, Error> {
+        let mut left = self.parse_and()?;
+        while self.eat_str("||") {
+            let right = self.parse_and()?.into_predicate()?;
+            left = Node::Predicate(Predicate::Or(
+                Box::new(left.into_predicate()?),
+                Box::new(right),
+            ));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<Node<'a>, Error> {
+        let mut left = self.parse_unary()?;
+        while self.eat_str("&&") {
+            let right = self.parse_unary()?.into_predicate()?;
+            left = Node::Predicate(Predicate::And(
+                Box::new(left.into_predicate()?),
+                Box::new(right),
+            ));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<Node<'a>, Error> {
+        if self.eat_str("!") {
+            self.expect_str("(")?;
+            let pred = self.parse_or()?.into_predicate()?;
+            self.expect_str(")")?;
+            return Ok(Node::Predicate(Predicate::Not(Box::new(pred))));
+        }
+        if self.eat_str("(") {
+            let node = self.parse_or()?;
+            self.expect_str(")")?;
+            return Ok(node);
+        }
+        if self.eat_keyword("exists") {
+            self.expect_str("(")?;
+            let paths = self.parse_paths()?;
+            self.expect_str(")")?;
+            return Ok(Node::Predicate(Predicate::Exists(paths)));
+        }
+
+        let left = self.parse_expr()?;
+        match self.parse_comparison_op() {
+            Some(op) => {
+                let right = self.parse_expr()?;
+                Ok(Node::Predicate(Predicate::Comparison {
+                    op,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                }))
+            }
+            None => Ok(Node::Expr(left)),
+        }
+    }
+
+    fn parse_comparison_op(&mut self) -> Option<ComparisonOp> {
+        if self.eat_str("==") {
+            Some(ComparisonOp::Eq)
+        } else if self.eat_str("!=") || self.eat_str("<>") {
+            Some(ComparisonOp::NotEq)
+        } else if self.eat_str("<=") {
+            Some(ComparisonOp::Lte)
+        } else if self.eat_str("<") {
+            Some(ComparisonOp::Lt)
+        } else if self.eat_str(">=") {
+            Some(ComparisonOp::Gte)
+        } else if self.eat_str(">") {
+            Some(ComparisonOp::Gt)
+        } else {
+            None
+        }
+    }
+
+    fn parse_expr(&mut self) -> Result<Expr<'a>, Error> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'$') | Some(b'@') => Ok(Expr::Paths(self.parse_paths()?)),
+            _ => Ok(Expr::Value(self.parse_value()?)),
+        }
+    }
+
+    fn parse_paths(&mut self) -> Result<Vec<Path<'a>>, Error> {
+        let mut paths = Vec::new();
+        if self.eat_str("$") {
+            paths.push(Path::Root);
+        } else if self.eat_str("@") {
+            paths.push(Path::Current);
+        } else {
+            return Err(Error::InvalidJsonPath);
+        }
+        loop {
+            if self.eat_str(".") {
+                if self.eat_str("*") {
+                    paths.push(Path::DotWildcard);
+                } else if self.peek() == Some(b'"') {
+                    paths.push(Path::DotField(self.parse_string()?));
+                } else {
+                    paths.push(Path::DotField(Cow::Borrowed(self.parse_ident()?)));
+                }
+            } else if self.eat_str("[") {
+                if self.eat_str("*") {
+                    self.expect_str("]")?;
+                    paths.push(Path::ArrayWildcard);
+                } else {
+                    paths.push(Path::ArrayIndices(self.parse_array_indices()?));
+                }
+            } else if self.eat_str("?") {
+                self.expect_str("(")?;
+                let pred = self.parse_or()?.into_predicate()?;
+                self.expect_str(")")?;
+                paths.push(Path::FilterExpr(Box::new(pred)));
+            } else {
+                break;
+            }
+        }
+        Ok(paths)
+    }
+
+    fn parse_array_indices(&mut self) -> Result<Vec<ArrayIndex>, Error> {
+        let mut indices = Vec::new();
+        loop {
+            let start = self.parse_index()?;
+            if self.eat_keyword("to") {
+                let end = self.parse_index()?;
+                indices.push(ArrayIndex::Slice(start, end));
+            } else {
+                indices.push(ArrayIndex::Index(start));
+            }
+            if self.eat_str("]") {
+                break;
+            }
+            self.expect_str(",")?;
+        }
+        Ok(indices)
+    }
+
+    fn parse_index(&mut self) -> Result<Index, Error> {
+        if self.eat_keyword("last") {
+            if self.eat_str("-") {
+                let offset = self.parse_integer()?;
+                return Ok(Index::LastIndex(-offset));
+            } else if self.eat_str("+") {
+                let offset = self.parse_integer()?;
+                return Ok(Index::LastIndex(offset));
+            }
+            return Ok(Index::LastIndex(0));
+        }
+        Ok(Index::Index(self.parse_integer()?))
+    }
+
+    fn parse_integer(&mut self) -> Result<i64, Error> {
+        self.skip_whitespace();
+        let start = self.idx;
+        while let Some(c) = self.peek() {
+            if !c.is_ascii_digit() {
+                break;
+            }
+            self.idx += 1;
+        }
+        if start == self.idx {
+            return Err(Error::InvalidJsonPath);
+        }
+        let s = std::str::from_utf8(&self.buf[start..self.idx])?;
+        s.parse::<i64>().map_err(|_| Error::InvalidJsonPath)
+    }
+
+    fn parse_value(&mut self) -> Result<PathValue<'a>, Error> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'"') => Ok(PathValue::String(self.parse_string()?)),
+            Some(c) if c == b'-' || c.is_ascii_digit() => {
+                Ok(PathValue::Number(self.parse_number()?))
+            }
+            _ => {
+                if self.eat_keyword("null") {
+                    Ok(PathValue::Null)
+                } else if self.eat_keyword("true") {
+                    Ok(PathValue::Boolean(true))
+                } else if self.eat_keyword("false") {
+                    Ok(PathValue::Boolean(false))
+                } else {
+                    Err(Error::InvalidJsonPath)
+                }
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<Number, Error> {
+        let start = self.idx;
+        let mut is_float = false;
+        if self.peek() == Some(b'-') {
+            self.idx += 1;
+        }
+        while let Some(c) = self.peek() {
+            match c {
+                b'0'..=b'9' => {}
+                b'.' | b'e' | b'E' => is_float = true,
+                b'+' | b'-' if is_float => {}
+                _ => break,
+            }
+            self.idx += 1;
+        }
+        let s = std::str::from_utf8(&self.buf[start..self.idx])?;
+        if !is_float {
+            if let Ok(v) = s.parse::<u64>() {
+                return Ok(Number::UInt64(v));
+            }
+            if let Ok(v) = s.parse::<i64>() {
+                return Ok(Number::Int64(v));
+            }
+        }
+        s.parse::<f64>()
+            .map(Number::Float64)
+            .map_err(|_| Error::InvalidJsonPath)
+    }
+
+    fn parse_string(&mut self) -> Result<Cow<'a, str>, Error> {
+        self.expect_str("\"")?;
+        let start = self.idx;
+        let mut escaped = false;
+        loop {
+            match self.peek() {
+                Some(b'"') => break,
+                Some(b'\\') => {
+                    escaped = true;
+                    self.idx += 2;
+                }
+                Some(_) => self.idx += 1,
+                None => return Err(Error::InvalidJsonPath),
+            }
+        }
+        let raw = self
+            .buf
+            .get(start..self.idx)
+            .ok_or(Error::InvalidJsonPath)?;
+        self.idx += 1;
+        let raw = std::str::from_utf8(raw)?;
+        if !escaped {
+            return Ok(Cow::Borrowed(raw));
+        }
+
+        let mut s = String::with_capacity(raw.len());
+        let mut chars = raw.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                s.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('n') => s.push('\n'),
+                Some('t') => s.push('\t'),
+                Some('r') => s.push('\r'),
+                Some('b') => s.push('\x08'),
+                Some('f') => s.push('\x0C'),
+                Some(c) => s.push(c),
+                None => return Err(Error::InvalidJsonPath),
+            }
+        }
+        Ok(Cow::Owned(s))
+    }
+
+    fn parse_ident(&mut self) -> Result<&'a str, Error> {
+        let start = self.idx;
+        while let Some(c) = self.peek() {
+            if !(c.is_ascii_alphanumeric() || c == b'_' || c == b'$' || c >= 0x80) {
+                break;
+            }
+            self.idx += 1;
+        }
+        if start == self.idx {
+            return Err(Error::InvalidJsonPath);
+        }
+        Ok(std::str::from_utf8(&self.buf[start..self.idx])?)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.buf.get(self.idx).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if !c.is_ascii_whitespace() {
+                break;
+            }
+            self.idx += 1;
+        }
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        self.skip_whitespace();
+        if self.buf[self.idx..].starts_with(s.as_bytes()) {
+            self.idx += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_str(&mut self, s: &str) -> Result<(), Error> {
+        if self.eat_str(s) {
+            Ok(())
+        } else {
+            Err(Error::InvalidJsonPath)
+        }
+    }
+
+    // Keywords must not be followed by identifier characters,
+    // so that `lastname` is not taken as `last`.
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        self.skip_whitespace();
+        let end = self.idx + keyword.len();
+        if end <= self.buf.len()
+            && self.buf[self.idx..end].eq_ignore_ascii_case(keyword.as_bytes())
+            && !matches!(self.buf.get(end), Some(c) if c.is_ascii_alphanumeric() || *c == b'_')
+        {
+            self.idx = end;
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::number::Number;
+
+/// Represents a SQL/JSON path, such as `$.store.book[*] ? (@.price > 10).title`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath<'a> {
+    pub mode: Mode,
+    pub expr: PathExpr<'a>,
+}
+
+/// In `lax` mode, arrays are automatically unwrapped and non-array values are
+/// treated as single element arrays, structural errors are suppressed.
+/// In `strict` mode, the structure of the value must match the path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    #[default]
+    Lax,
+    Strict,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathExpr<'a> {
+    /// A sequence of path accessors used to select values.
+    Paths(Vec<Path<'a>>),
+    /// A predicate check over the value, such as `$.a == 1`.
+    Predicate(Box<Predicate<'a>>),
+}
+
+/// Represents a single accessor of the SQL/JSON path.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Path<'a> {
+    /// `$` represents the root value.
+    Root,
+    /// `@` represents the value being filtered.
+    Current,
+    /// `.*` selects all the values of an object.
+    DotWildcard,
+    /// `.name` or `."name"` selects the value of the key in an object.
+    DotField(Cow<'a, str>),
+    /// `[*]` selects all the elements of an array.
+    ArrayWildcard,
+    /// `[1, 3 to last]` selects the elements of an array by indices.
+    ArrayIndices(Vec<ArrayIndex>),
+    /// `? (<predicate>)` keeps the values for which the predicate is true.
+    FilterExpr(Box<Predicate<'a>>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayIndex {
+    Index(Index),
+    Slice(Index, Index),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Index {
+    /// Zero based index counted from the start of the array.
+    Index(i64),
+    /// Index counted from the last element, `last - 1` is `LastIndex(-1)`.
+    LastIndex(i64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate<'a> {
+    Comparison {
+        op: ComparisonOp,
+        left: Box<Expr<'a>>,
+        right: Box<Expr<'a>>,
+    },
+    And(Box<Predicate<'a>>, Box<Predicate<'a>>),
+    Or(Box<Predicate<'a>>, Box<Predicate<'a>>),
+    Not(Box<Predicate<'a>>),
+    /// `exists(<path>)` checks whether the path selects any value.
+    Exists(Vec<Path<'a>>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr<'a> {
+    Paths(Vec<Path<'a>>),
+    Value(PathValue<'a>),
+}
+
+/// Literal values that can be used in predicates.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathValue<'a> {
+    Null,
+    Boolean(bool),
+    Number(Number),
+    String(Cow<'a, str>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonOp {
+    Eq,
+    NotEq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+impl<'a> Display for JsonPath<'a> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.mode == Mode::Strict {
+            write!(f, "strict ")?;
+        }
+        match &self.expr {
+            PathExpr::Paths(paths) => write_paths(f, paths),
+            PathExpr::Predicate(pred) => write!(f, "{pred}"),
+        }
+    }
+}
+
+fn write_paths(f: &mut Formatter, paths: &[Path]) -> std::fmt::Result {
+    for path in paths {
+        write!(f, "{path}")?;
+    }
+    Ok(())
+}
+
+impl<'a> Display for Path<'a> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Path::Root => write!(f, "$"),
+            Path::Current => write!(f, "@"),
+            Path::DotWildcard => write!(f, ".*"),
+            Path::DotField(name) => write!(f, ".{:?}", name),
+            Path::ArrayWildcard => write!(f, "[*]"),
+            Path::ArrayIndices(indices) => {
+                write!(f, "[")?;
+                for (i, index) in indices.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{index}")?;
+                }
+                write!(f, "]")
+            }
+            Path::FilterExpr(pred) => write!(f, " ? ({pred})"),
+        }
+    }
+}
+
+impl Display for ArrayIndex {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ArrayIndex::Index(index) => write!(f, "{index}"),
+            ArrayIndex::Slice(start, end) => write!(f, "{start} to {end}"),
+        }
+    }
+}
+
+impl Display for Index {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Index::Index(idx) => write!(f, "{idx}"),
+            Index::LastIndex(0) => write!(f, "last"),
+            Index::LastIndex(idx) if *idx > 0 => write!(f, "last + {idx}"),
+            Index::LastIndex(idx) => write!(f, "last - {}", -idx),
+        }
+    }
+}
+
+impl<'a> Display for Predicate<'a> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Predicate::Comparison { op, left, right } => write!(f, "{left} {op} {right}"),
+            Predicate::And(left, right) => write!(f, "({left} && {right})"),
+            Predicate::Or(left, right) => write!(f, "({left} || {right})"),
+            Predicate::Not(pred) => write!(f, "!({pred})"),
+            Predicate::Exists(paths) => {
+                write!(f, "exists(")?;
+                write_paths(f, paths)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+impl<'a> Display for Expr<'a> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Expr::Paths(paths) => write_paths(f, paths),
+            Expr::Value(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl<'a> Display for PathValue<'a> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            PathValue::Null => write!(f, "null"),
+            PathValue::Boolean(v) => write!(f, "{v}"),
+            PathValue::Number(v) => write!(f, "{v}"),
+            PathValue::String(v) => write!(f, "{:?}", v),
+        }
+    }
+}
+
+impl Display for ComparisonOp {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ComparisonOp::Eq => write!(f, "=="),
+            ComparisonOp::NotEq => write!(f, "!="),
+            ComparisonOp::Lt => write!(f, "<"),
+            ComparisonOp::Lte => write!(f, "<="),
+            ComparisonOp::Gt => write!(f, ">"),
+            ComparisonOp::Gte => write!(f, ">="),
+        }
+    }
+}
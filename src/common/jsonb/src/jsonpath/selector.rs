@@ -0,0 +1,415 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use super::path::*;
+use crate::constants::*;
+use crate::functions::read_u32;
+use crate::functions::to_jsonb;
+use crate::jentry::JEntry;
+use crate::number::Number;
+use crate::value::Value;
+
+/// A value referenced in the `JSONB` buffer, the selector walks
+/// through the containers by offsets without decoding the values.
+#[derive(Debug, Clone, Copy)]
+enum Item<'a> {
+    /// The full bytes of an array or object container.
+    Container(&'a [u8]),
+    /// The encoded `JEntry` and the data of a scalar value.
+    Scalar(u32, &'a [u8]),
+}
+
+impl<'a> Item<'a> {
+    fn from_root(value: &'a [u8]) -> Item<'a> {
+        let header = read_u32(value, 0).unwrap();
+        match header & CONTAINER_HEADER_TYPE_MASK {
+            SCALAR_CONTAINER_TAG => {
+                let encoded = read_u32(value, 4).unwrap();
+                Item::Scalar(encoded, &value[8..])
+            }
+            _ => Item::Container(value),
+        }
+    }
+
+    fn from_jentry(container: &'a [u8], encoded: u32, offset: usize) -> Item<'a> {
+        let jentry = JEntry::decode_jentry(encoded);
+        let data = &container[offset..offset + jentry.length as usize];
+        match jentry.type_code {
+            CONTAINER_TAG => Item::Container(data),
+            _ => Item::Scalar(encoded, data),
+        }
+    }
+
+    fn array_values(&self) -> Option<Vec<Item<'a>>> {
+        let data = match self {
+            Item::Container(data) => *data,
+            Item::Scalar(_, _) => return None,
+        };
+        let header = read_u32(data, 0).unwrap();
+        if header & CONTAINER_HEADER_TYPE_MASK != ARRAY_CONTAINER_TAG {
+            return None;
+        }
+        let length = (header & CONTAINER_HEADER_LEN_MASK) as usize;
+        let mut values = Vec::with_capacity(length);
+        let mut jentry_offset = 4;
+        let mut val_offset = 4 * length + 4;
+        for _ in 0..length {
+            let encoded = read_u32(data, jentry_offset).unwrap();
+            let item = Item::from_jentry(data, encoded, val_offset);
+            jentry_offset += 4;
+            val_offset += JEntry::decode_jentry(encoded).length as usize;
+            values.push(item);
+        }
+        Some(values)
+    }
+
+    fn object_entries(&self) -> Option<Vec<(&'a str, Item<'a>)>> {
+        let data = match self {
+            Item::Container(data) => *data,
+            Item::Scalar(_, _) => return None,
+        };
+        let header = read_u32(data, 0).unwrap();
+        if header & CONTAINER_HEADER_TYPE_MASK != OBJECT_CONTAINER_TAG {
+            return None;
+        }
+        let length = (header & CONTAINER_HEADER_LEN_MASK) as usize;
+        let mut keys = Vec::with_capacity(length);
+        let mut jentry_offset = 4;
+        let mut key_offset = 8 * length + 4;
+        for _ in 0..length {
+            let encoded = read_u32(data, jentry_offset).unwrap();
+            let key_length = JEntry::decode_jentry(encoded).length as usize;
+            let key = unsafe {
+                std::str::from_utf8_unchecked(&data[key_offset..key_offset + key_length])
+            };
+            keys.push(key);
+            jentry_offset += 4;
+            key_offset += key_length;
+        }
+        let mut entries = Vec::with_capacity(length);
+        let mut val_offset = key_offset;
+        for key in keys {
+            let encoded = read_u32(data, jentry_offset).unwrap();
+            let item = Item::from_jentry(data, encoded, val_offset);
+            jentry_offset += 4;
+            val_offset += JEntry::decode_jentry(encoded).length as usize;
+            entries.push((key, item));
+        }
+        Some(entries)
+    }
+
+    fn as_value(&self) -> Option<PathValue<'a>> {
+        match self {
+            Item::Container(_) => None,
+            Item::Scalar(encoded, data) => {
+                let jentry = JEntry::decode_jentry(*encoded);
+                match jentry.type_code {
+                    NULL_TAG => Some(PathValue::Null),
+                    TRUE_TAG => Some(PathValue::Boolean(true)),
+                    FALSE_TAG => Some(PathValue::Boolean(false)),
+                    NUMBER_TAG => Some(PathValue::Number(Number::decode(data))),
+                    STRING_TAG => {
+                        let s = unsafe { std::str::from_utf8_unchecked(data) };
+                        Some(PathValue::String(Cow::Borrowed(s)))
+                    }
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    fn write_to_vec(&self, buf: &mut Vec<u8>) {
+        match self {
+            Item::Container(data) => buf.extend_from_slice(data),
+            Item::Scalar(encoded, data) => {
+                buf.extend_from_slice(&SCALAR_CONTAINER_TAG.to_be_bytes());
+                buf.extend_from_slice(&encoded.to_be_bytes());
+                buf.extend_from_slice(data);
+            }
+        }
+    }
+}
+
+/// Evaluates a SQL/JSON path over `JSONB` values.
+pub struct Selector<'a> {
+    json_path: JsonPath<'a>,
+}
+
+impl<'a> Selector<'a> {
+    pub fn new(json_path: JsonPath<'a>) -> Self {
+        Self { json_path }
+    }
+
+    /// Returns all the values selected by the path, each value is encoded as `JSONB`.
+    /// If the path is a predicate, the result of the predicate is returned.
+    pub fn select(&self, value: &[u8]) -> Vec<Vec<u8>> {
+        let value = to_jsonb(value);
+        let root = Item::from_root(&value);
+        match &self.json_path.expr {
+            PathExpr::Paths(paths) => self
+                .select_paths(root, root, paths)
+                .into_iter()
+                .map(|item| {
+                    let mut buf = Vec::new();
+                    item.write_to_vec(&mut buf);
+                    buf
+                })
+                .collect(),
+            PathExpr::Predicate(pred) => {
+                let result = match self.eval_predicate(root, root, pred) {
+                    Some(v) => Value::Bool(v),
+                    None => Value::Null,
+                };
+                vec![result.to_vec()]
+            }
+        }
+    }
+
+    /// Checks whether the path selects any value.
+    /// If the path is a predicate, checks whether the predicate is true.
+    pub fn exists(&self, value: &[u8]) -> bool {
+        let value = to_jsonb(value);
+        let root = Item::from_root(&value);
+        match &self.json_path.expr {
+            PathExpr::Paths(paths) => !self.select_paths(root, root, paths).is_empty(),
+            PathExpr::Predicate(pred) => self.eval_predicate(root, root, pred) == Some(true),
+        }
+    }
+
+    /// Returns the result of the predicate check, `None` means the result is unknown.
+    /// If the path is not a predicate, it must select a single boolean value.
+    pub fn predicate_match(&self, value: &[u8]) -> Option<bool> {
+        let value = to_jsonb(value);
+        let root = Item::from_root(&value);
+        match &self.json_path.expr {
+            PathExpr::Paths(paths) => {
+                let items = self.select_paths(root, root, paths);
+                match items.as_slice() {
+                    [item] => match item.as_value() {
+                        Some(PathValue::Boolean(v)) => Some(v),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+            PathExpr::Predicate(pred) => self.eval_predicate(root, root, pred),
+        }
+    }
+
+    fn is_lax(&self) -> bool {
+        self.json_path.mode == Mode::Lax
+    }
+
+    fn select_paths<'b>(
+        &self,
+        root: Item<'b>,
+        current: Item<'b>,
+        paths: &[Path<'a>],
+    ) -> Vec<Item<'b>> {
+        let mut items = Vec::new();
+        for path in paths {
+            match path {
+                Path::Root => items = vec![root],
+                Path::Current => items = vec![current],
+                Path::DotWildcard => {
+                    items = self
+                        .unwrap_arrays(items)
+                        .iter()
+                        .filter_map(|item| item.object_entries())
+                        .flat_map(|entries| entries.into_iter().map(|(_, item)| item))
+                        .collect();
+                }
+                Path::DotField(name) => {
+                    items = self
+                        .unwrap_arrays(items)
+                        .iter()
+                        .filter_map(|item| item.object_entries())
+                        .filter_map(|entries| {
+                            entries
+                                .into_iter()
+                                .find(|(key, _)| *key == name.as_ref())
+                                .map(|(_, item)| item)
+                        })
+                        .collect();
+                }
+                Path::ArrayWildcard => {
+                    let mut next_items = Vec::new();
+                    for item in items {
+                        match item.array_values() {
+                            Some(values) => next_items.extend(values),
+                            None if self.is_lax() => next_items.push(item),
+                            None => {}
+                        }
+                    }
+                    items = next_items;
+                }
+                Path::ArrayIndices(indices) => {
+                    let mut next_items = Vec::new();
+                    for item in items {
+                        let values = match item.array_values() {
+                            Some(values) => values,
+                            None if self.is_lax() => vec![item],
+                            None => continue,
+                        };
+                        for index in indices {
+                            let (start, end) = match index {
+                                ArrayIndex::Index(idx) => (*idx, *idx),
+                                ArrayIndex::Slice(start, end) => (*start, *end),
+                            };
+                            let start = resolve_index(start, values.len()).max(0);
+                            let end = resolve_index(end, values.len()).min(values.len() as i64 - 1);
+                            for idx in start..=end {
+                                next_items.push(values[idx as usize]);
+                            }
+                        }
+                    }
+                    items = next_items;
+                }
+                Path::FilterExpr(pred) => {
+                    items = self
+                        .unwrap_arrays(items)
+                        .into_iter()
+                        .filter(|item| self.eval_predicate(root, *item, pred) == Some(true))
+                        .collect();
+                }
+            }
+        }
+        items
+    }
+
+    // In lax mode, arrays are unwrapped to its elements before accessing.
+    fn unwrap_arrays<'b>(&self, items: Vec<Item<'b>>) -> Vec<Item<'b>> {
+        if !self.is_lax() {
+            return items;
+        }
+        let mut unwrapped = Vec::with_capacity(items.len());
+        for item in items {
+            match item.array_values() {
+                Some(values) => unwrapped.extend(values),
+                None => unwrapped.push(item),
+            }
+        }
+        unwrapped
+    }
+
+    fn eval_predicate<'b>(
+        &self,
+        root: Item<'b>,
+        current: Item<'b>,
+        pred: &Predicate<'a>,
+    ) -> Option<bool> {
+        match pred {
+            Predicate::Comparison { op, left, right } => {
+                let lhs = self.eval_expr(root, current, left);
+                let rhs = self.eval_expr(root, current, right);
+                let mut unknown = false;
+                for l in lhs.iter() {
+                    for r in rhs.iter() {
+                        match (l, r) {
+                            (Some(l), Some(r)) => match compare(l, r, *op) {
+                                Some(true) => return Some(true),
+                                Some(false) => {}
+                                None => unknown = true,
+                            },
+                            _ => unknown = true,
+                        }
+                    }
+                }
+                if unknown { None } else { Some(false) }
+            }
+            Predicate::And(left, right) => {
+                match (
+                    self.eval_predicate(root, current, left),
+                    self.eval_predicate(root, current, right),
+                ) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                }
+            }
+            Predicate::Or(left, right) => {
+                match (
+                    self.eval_predicate(root, current, left),
+                    self.eval_predicate(root, current, right),
+                ) {
+                    (Some(true), _) | (_, Some(true)) => Some(true),
+                    (Some(false), Some(false)) => Some(false),
+                    _ => None,
+                }
+            }
+            Predicate::Not(pred) => self.eval_predicate(root, current, pred).map(|v| !v),
+            Predicate::Exists(paths) => Some(!self.select_paths(root, current, paths).is_empty()),
+        }
+    }
+
+    // Containers can't be compared, they are returned as `None`.
+    fn eval_expr<'b>(
+        &self,
+        root: Item<'b>,
+        current: Item<'b>,
+        expr: &Expr<'a>,
+    ) -> Vec<Option<PathValue<'b>>> {
+        match expr {
+            Expr::Value(value) => {
+                let value = match value {
+                    PathValue::String(s) => PathValue::String(Cow::Owned(s.to_string())),
+                    PathValue::Null => PathValue::Null,
+                    PathValue::Boolean(v) => PathValue::Boolean(*v),
+                    PathValue::Number(v) => PathValue::Number(v.clone()),
+                };
+                vec![Some(value)]
+            }
+            Expr::Paths(paths) => {
+                let items = self.select_paths(root, current, paths);
+                self.unwrap_arrays(items)
+                    .iter()
+                    .map(|item| item.as_value())
+                    .collect()
+            }
+        }
+    }
+}
+
+fn resolve_index(index: Index, len: usize) -> i64 {
+    match index {
+        Index::Index(idx) => idx,
+        Index::LastIndex(idx) => len as i64 - 1 + idx,
+    }
+}
+
+// Values of different types are not comparable, the result is unknown.
+fn compare(left: &PathValue, right: &PathValue, op: ComparisonOp) -> Option<bool> {
+    let ord = match (left, right) {
+        (PathValue::Null, PathValue::Null) => Ordering::Equal,
+        (PathValue::Null, _) | (_, PathValue::Null) => {
+            return Some(op == ComparisonOp::NotEq);
+        }
+        (PathValue::Boolean(l), PathValue::Boolean(r)) => l.cmp(r),
+        (PathValue::Number(l), PathValue::Number(r)) => l.cmp(r),
+        (PathValue::String(l), PathValue::String(r)) => l.cmp(r),
+        (_, _) => return None,
+    };
+    let result = match op {
+        ComparisonOp::Eq => ord == Ordering::Equal,
+        ComparisonOp::NotEq => ord != Ordering::Equal,
+        ComparisonOp::Lt => ord == Ordering::Less,
+        ComparisonOp::Lte => ord != Ordering::Greater,
+        ComparisonOp::Gt => ord == Ordering::Greater,
+        ComparisonOp::Gte => ord != Ordering::Less,
+    };
+    Some(result)
+}
@@ -21,6 +21,7 @@ mod from;
 mod functions;
 mod jentry;
 mod json_path;
+pub mod jsonpath;
 mod number;
 mod parser;
 mod ser;
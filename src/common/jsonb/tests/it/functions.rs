@@ -14,7 +14,9 @@
 
 use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::collections::BTreeSet;
 
+use common_jsonb::array_append;
 use common_jsonb::array_length;
 use common_jsonb::as_bool;
 use common_jsonb::as_null;
@@ -28,15 +30,21 @@ use common_jsonb::get_by_name_ignore_case;
 use common_jsonb::get_by_path;
 use common_jsonb::is_array;
 use common_jsonb::is_object;
+use common_jsonb::object_delete;
+use common_jsonb::object_insert;
 use common_jsonb::object_keys;
+use common_jsonb::object_pick;
 use common_jsonb::parse_json_path;
 use common_jsonb::parse_value;
+use common_jsonb::strip_nulls;
 use common_jsonb::to_bool;
 use common_jsonb::to_f64;
 use common_jsonb::to_i64;
+use common_jsonb::to_pretty_string;
 use common_jsonb::to_str;
 use common_jsonb::to_string;
 use common_jsonb::to_u64;
+use common_jsonb::type_of;
 use common_jsonb::Error;
 use common_jsonb::JsonPathRef;
 use common_jsonb::Number;
@@ -653,3 +661,98 @@ fn test_to_string() {
         buf.clear();
     }
 }
+
+#[test]
+fn test_object_modify() {
+    let source = r#"{"a":1,"c":[1,2],"d":null}"#;
+    let value = parse_value(source.as_bytes()).unwrap().to_vec();
+    let new_val = parse_value(r#""x""#.as_bytes()).unwrap().to_vec();
+
+    let mut buf: Vec<u8> = Vec::new();
+    object_insert(&value, "b", &new_val, false, &mut buf).unwrap();
+    assert_eq!(to_string(&buf), r#"{"a":1,"b":"x","c":[1,2],"d":null}"#);
+
+    buf.clear();
+    let res = object_insert(&value, "a", &new_val, false, &mut buf);
+    assert_eq!(res, Err(Error::ObjectDuplicateKey));
+
+    buf.clear();
+    object_insert(source.as_bytes(), "a", &new_val, true, &mut buf).unwrap();
+    assert_eq!(to_string(&buf), r#"{"a":"x","c":[1,2],"d":null}"#);
+
+    let keys = BTreeSet::from(["a", "d", "e"]);
+    buf.clear();
+    object_delete(&value, &keys, &mut buf).unwrap();
+    assert_eq!(to_string(&buf), r#"{"c":[1,2]}"#);
+
+    buf.clear();
+    object_pick(&value, &keys, &mut buf).unwrap();
+    assert_eq!(to_string(&buf), r#"{"a":1,"d":null}"#);
+
+    let array = parse_value(r#"[1,2]"#.as_bytes()).unwrap().to_vec();
+    buf.clear();
+    let res = object_pick(&array, &keys, &mut buf);
+    assert_eq!(res, Err(Error::InvalidObject));
+
+    buf.clear();
+    array_append(&array, &value, &mut buf).unwrap();
+    assert_eq!(to_string(&buf), r#"[1,2,{"a":1,"c":[1,2],"d":null}]"#);
+
+    buf.clear();
+    let res = array_append(&value, &array, &mut buf);
+    assert_eq!(res, Err(Error::InvalidArray));
+}
+
+#[test]
+fn test_strip_nulls() {
+    let sources = vec![
+        (r#"null"#, r#"null"#),
+        (r#"[1,null,{"a":null}]"#, r#"[1,null,{}]"#),
+        (
+            r#"{"a":null,"b":{"c":null,"d":2},"e":[null]}"#,
+            r#"{"b":{"d":2},"e":[null]}"#,
+        ),
+    ];
+    let mut buf: Vec<u8> = Vec::new();
+    for (s, expect) in sources {
+        strip_nulls(s.as_bytes(), &mut buf).unwrap();
+        assert_eq!(to_string(&buf), expect);
+        buf.clear();
+    }
+}
+
+#[test]
+fn test_type_of() {
+    let sources = vec![
+        (r#"null"#, "null"),
+        (r#"true"#, "boolean"),
+        (r#"-1.5"#, "number"),
+        (r#""abc""#, "string"),
+        (r#"[1,2]"#, "array"),
+        (r#"{"a":1}"#, "object"),
+    ];
+    for (s, expect) in sources {
+        assert_eq!(type_of(s.as_bytes()).unwrap(), expect);
+        let value = parse_value(s.as_bytes()).unwrap().to_vec();
+        assert_eq!(type_of(&value).unwrap(), expect);
+    }
+}
+
+#[test]
+fn test_to_pretty_string() {
+    let sources = vec![
+        (r#"1"#, "1"),
+        (r#"[]"#, "[]"),
+        (r#"{}"#, "{}"),
+        (r#"[1,"a"]"#, "[\n  1,\n  \"a\"\n]"),
+        (
+            r#"{"k1":"v1","k2":[1,{"a":true}],"k3":{}}"#,
+            "{\n  \"k1\": \"v1\",\n  \"k2\": [\n    1,\n    {\n      \"a\": true\n    }\n  ],\n  \"k3\": {}\n}",
+        ),
+    ];
+    for (s, expect) in sources {
+        let value = parse_value(s.as_bytes()).unwrap().to_vec();
+        assert_eq!(to_pretty_string(&value), expect);
+        assert_eq!(to_pretty_string(s.as_bytes()), expect);
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_jsonb::jsonpath::parse_json_path;
+use common_jsonb::jsonpath::Selector;
+use common_jsonb::parse_value;
+use common_jsonb::to_string;
+use common_jsonb::Error;
+
+#[test]
+fn test_parse_json_path() {
+    let sources = vec![
+        (r#"$"#, r#"$"#),
+        (r#"lax $.a.b"#, r#"$."a"."b""#),
+        (r#"strict $.a[*]"#, r#"strict $."a"[*]"#),
+        (r#"$."k 1".*"#, r#"$."k 1".*"#),
+        (
+            r#"$[0, 2 to last, last - 1]"#,
+            r#"$[0, 2 to last, last - 1]"#,
+        ),
+        (
+            r#"$.a ? (@.b > 1 && (@.c == "x" || !(@.d != null)))"#,
+            r#"$."a" ? ((@."b" > 1 && (@."c" == "x" || !(@."d" != null))))"#,
+        ),
+        (r#"$.a ? (exists(@.b))"#, r#"$."a" ? (exists(@."b"))"#),
+        (r#"$.a[0] == -1.5"#, r#"$."a"[0] == -1.5"#),
+    ];
+    for (s, expect) in sources {
+        let path = parse_json_path(s.as_bytes()).unwrap();
+        assert_eq!(path.to_string(), expect);
+    }
+
+    let errors = vec![
+        r#""#,
+        r#"a"#,
+        r#"$."#,
+        r#"$[1"#,
+        r#"$ ? (@.a)"#,
+        r#"$.a =="#,
+        r#"1"#,
+    ];
+    for s in errors {
+        let res = parse_json_path(s.as_bytes());
+        assert_eq!(res.err(), Some(Error::InvalidJsonPath));
+    }
+}
+
+#[test]
+fn test_select_json_path() {
+    let json =
+        r#"{"a": [{"b": 1, "c": "x"}, {"b": 2, "c": "y"}, {"b": 3}], "d": {"e": true, "f": null}}"#;
+    let value = parse_value(json.as_bytes()).unwrap().to_vec();
+
+    let sources = vec![
+        (r#"$.a[0].b"#, vec!["1"]),
+        (r#"$.a[*].b"#, vec!["1", "2", "3"]),
+        (r#"$.a.b"#, vec!["1", "2", "3"]),
+        (r#"strict $.a.b"#, vec![]),
+        (r#"$.a[1 to last].c"#, vec![r#""y""#]),
+        (r#"$.a[last - 2]"#, vec![r#"{"b":1,"c":"x"}"#]),
+        (r#"$.d.*"#, vec!["true", "null"]),
+        (r#"$.a ? (@.b >= 2).b"#, vec!["2", "3"]),
+        (r#"$.a ? (@.c == "x" || @.b == 3).b"#, vec!["1", "3"]),
+        (r#"$.a ? (exists(@.c)).c"#, vec![r#""x""#, r#""y""#]),
+        (r#"$.a ? (!(@.b < 3)).b"#, vec!["3"]),
+        (r#"$.a[*].b ? (@ > 1)"#, vec!["2", "3"]),
+        (r#"$.d.e[0]"#, vec!["true"]),
+        (r#"$.x"#, vec![]),
+        (r#"$.a[*].b == 2"#, vec!["true"]),
+        (r#"$.a[*].c == 2"#, vec!["null"]),
+    ];
+    for (path, expect) in sources {
+        let selector = Selector::new(parse_json_path(path.as_bytes()).unwrap());
+        let result: Vec<String> = selector
+            .select(&value)
+            .iter()
+            .map(|v| to_string(v))
+            .collect();
+        assert_eq!(result, expect, "path: {}", path);
+    }
+}
+
+#[test]
+fn test_json_path_exists_and_match() {
+    let json = r#"{"a": [1, 2, 3], "b": true, "c": "x"}"#;
+    let value = parse_value(json.as_bytes()).unwrap().to_vec();
+
+    let sources = vec![
+        (r#"$.a"#, true, None),
+        (r#"$.a ? (@ > 2)"#, true, None),
+        (r#"$.a ? (@ > 3)"#, false, None),
+        (r#"$.b"#, true, Some(true)),
+        (r#"$.a[*] > 2"#, true, Some(true)),
+        (r#"$.a[*] > 3"#, false, Some(false)),
+        (r#"$.c > 1"#, false, None),
+        (r#"$.c == "x" && $.b == true"#, true, Some(true)),
+    ];
+    for (path, exists, matched) in sources {
+        let selector = Selector::new(parse_json_path(path.as_bytes()).unwrap());
+        assert_eq!(selector.exists(&value), exists, "path: {}", path);
+        assert_eq!(selector.predicate_match(&value), matched, "path: {}", path);
+    }
+}
@@ -31,6 +31,8 @@ use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::RemoveDroppedTableReply;
+use common_meta_app::schema::RemoveDroppedTableReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
@@ -113,6 +115,20 @@ pub trait SchemaApi: Send + Sync {
     async fn get_table_history(&self, req: ListTableReq)
     -> Result<Vec<Arc<TableInfo>>, KVAppError>;
 
+    /// List the dropped tables of a database, including the ones out of the undrop window but not
+    /// yet garbage collected.
+    async fn get_drop_table_infos(
+        &self,
+        req: ListTableReq,
+    ) -> Result<Vec<Arc<TableInfo>>, KVAppError>;
+
+    /// Removes the meta of a dropped table right away, instead of waiting for it to be out of
+    /// the retention time and garbage collected.
+    async fn remove_dropped_table(
+        &self,
+        req: RemoveDroppedTableReq,
+    ) -> Result<RemoveDroppedTableReply, KVAppError>;
+
     async fn list_tables(&self, req: ListTableReq) -> Result<Vec<Arc<TableInfo>>, KVAppError>;
 
     async fn get_table_by_id(
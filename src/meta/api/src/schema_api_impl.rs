@@ -46,6 +46,8 @@ use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::RemoveDroppedTableReply;
+use common_meta_app::schema::RemoveDroppedTableReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
@@ -86,6 +88,7 @@ use common_meta_types::errors::app_error::DropDbWithDropTime;
 use common_meta_types::errors::app_error::DropTableWithDropTime;
 use common_meta_types::errors::app_error::ShareHasNoGrantedDatabase;
 use common_meta_types::errors::app_error::ShareHasNoGrantedPrivilege;
+use common_meta_types::errors::app_error::StreamVersionMismatched;
 use common_meta_types::errors::app_error::TableAlreadyExists;
 use common_meta_types::errors::app_error::TableVersionMismatched;
 use common_meta_types::errors::app_error::TxnRetryMaxTimes;
@@ -972,29 +975,62 @@ impl<KV: kvapi::KVApi<Error = KVAppError>> SchemaApi for KV {
                 // append new table_id into list
                 tb_id_list.append(table_id);
 
+                let mut condition = vec![
+                    // db has not to change, i.e., no new table is created.
+                    // Renaming db is OK and does not affect the seq of db_meta.
+                    txn_cond_seq(&DatabaseId { db_id }, Eq, db_meta_seq),
+                    // no other table with the same name is inserted.
+                    txn_cond_seq(&dbid_tbname, Eq, 0),
+                    // no other table id with the same name is append.
+                    txn_cond_seq(&dbid_tbname_idlist, Eq, tb_id_list_seq),
+                    // update table count atomicly
+                    txn_cond_seq(&tb_count_key, Eq, tb_count_seq),
+                    txn_cond_seq(&table_id_to_name_key, Eq, 0),
+                ];
+                let mut if_then = vec![
+                    // Changing a table in a db has to update the seq of db_meta,
+                    // to block the batch-delete-tables when deleting a db.
+                    txn_op_put(&DatabaseId { db_id }, serialize_struct(&db_meta)?), /* (db_id) -> db_meta */
+                    txn_op_put(&dbid_tbname, serialize_u64(table_id)?), /* (tenant, db_id, tb_name) -> tb_id */
+                    txn_op_put(&tbid, serialize_struct(&req.table_meta)?), /* (tenant, db_id, tb_id) -> tb_meta */
+                    txn_op_put(&dbid_tbname_idlist, serialize_struct(&tb_id_list)?), /* _fd_table_id_list/db_id/table_name -> tb_id_list */
+                    txn_op_put(&tb_count_key, serialize_u64(tb_count + 1)?), /* _fd_table_count/tenant -> tb_count */
+                    txn_op_put(&table_id_to_name_key, serialize_struct(&db_id_table_name)?), /* __fd_table_id_to_name/db_id/table_name -> DBIdTableName */
+                ];
+
+                // register the new table on the tables it depends on
+                if let Some(dependency) = &req.dependency {
+                    for dep_table_id in &dependency.table_ids {
+                        let dep_tbid = TableId {
+                            table_id: *dep_table_id,
+                        };
+                        let (dep_meta_seq, dep_meta): (_, Option<TableMeta>) =
+                            get_struct_value(self, &dep_tbid).await?;
+                        let mut dep_meta = match dep_meta {
+                            Some(dep_meta) if dep_meta_seq != 0 => dep_meta,
+                            _ => {
+                                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                                    UnknownTableId::new(*dep_table_id, "create_table"),
+                                )));
+                            }
+                        };
+                        let dependents = dep_meta
+                            .options
+                            .entry(dependency.option_key.clone())
+                            .or_default();
+                        if !dependents.is_empty() {
+                            dependents.push(',');
+                        }
+                        dependents.push_str(&table_id.to_string());
+
+                        condition.push(txn_cond_seq(&dep_tbid, Eq, dep_meta_seq));
+                        if_then.push(txn_op_put(&dep_tbid, serialize_struct(&dep_meta)?));
+                    }
+                }
+
                 let txn_req = TxnRequest {
-                    condition: vec![
-                        // db has not to change, i.e., no new table is created.
-                        // Renaming db is OK and does not affect the seq of db_meta.
-                        txn_cond_seq(&DatabaseId { db_id }, Eq, db_meta_seq),
-                        // no other table with the same name is inserted.
-                        txn_cond_seq(&dbid_tbname, Eq, 0),
-                        // no other table id with the same name is append.
-                        txn_cond_seq(&dbid_tbname_idlist, Eq, tb_id_list_seq),
-                        // update table count atomicly
-                        txn_cond_seq(&tb_count_key, Eq, tb_count_seq),
-                        txn_cond_seq(&table_id_to_name_key, Eq, 0),
-                    ],
-                    if_then: vec![
-                        // Changing a table in a db has to update the seq of db_meta,
-                        // to block the batch-delete-tables when deleting a db.
-                        txn_op_put(&DatabaseId { db_id }, serialize_struct(&db_meta)?), /* (db_id) -> db_meta */
-                        txn_op_put(&dbid_tbname, serialize_u64(table_id)?), /* (tenant, db_id, tb_name) -> tb_id */
-                        txn_op_put(&tbid, serialize_struct(&req.table_meta)?), /* (tenant, db_id, tb_id) -> tb_meta */
-                        txn_op_put(&dbid_tbname_idlist, serialize_struct(&tb_id_list)?), /* _fd_table_id_list/db_id/table_name -> tb_id_list */
-                        txn_op_put(&tb_count_key, serialize_u64(tb_count + 1)?), /* _fd_table_count/tenant -> tb_count */
-                        txn_op_put(&table_id_to_name_key, serialize_struct(&db_id_table_name)?), /* __fd_table_id_to_name/db_id/table_name -> DBIdTableName */
-                    ],
+                    condition,
+                    if_then,
                     else_then: vec![],
                 };
 
@@ -1631,101 +1667,103 @@ impl<KV: kvapi::KVApi<Error = KVAppError>> SchemaApi for KV {
     ) -> Result<Vec<Arc<TableInfo>>, KVAppError> {
         debug!(req = debug(&req), "SchemaApi: {}", func_name!());
 
-        let tenant_dbname = &req.inner;
-
-        // Get db by name to ensure presence
-        let res = get_db_or_err(
-            self,
-            tenant_dbname,
-            format!("get_table_history: {}", tenant_dbname),
-        )
-        .await;
+        let now = Utc::now();
+        list_table_infos_of_db(self, &req.inner, "get_table_history", |tb_meta| {
+            !is_drop_time_out_of_retention_time(&tb_meta.drop_on, &now)
+        })
+        .await
+    }
 
-        let (_db_id_seq, db_id, _db_meta_seq, db_meta) = match res {
-            Ok(x) => x,
-            Err(e) => {
-                return Err(e);
-            }
-        };
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn get_drop_table_infos(
+        &self,
+        req: ListTableReq,
+    ) -> Result<Vec<Arc<TableInfo>>, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
 
-        // List tables by tenant, db_id, table_name.
-        let dbid_tbname_idlist = TableIdListKey {
-            db_id,
-            table_name: "".to_string(),
-        };
+        list_table_infos_of_db(self, &req.inner, "get_drop_table_infos", |tb_meta| {
+            tb_meta.drop_on.is_some()
+        })
+        .await
+    }
 
-        let table_id_list_keys = list_keys(self, &dbid_tbname_idlist).await?;
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn remove_dropped_table(
+        &self,
+        req: RemoveDroppedTableReq,
+    ) -> Result<RemoveDroppedTableReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
 
-        let mut tb_info_list = vec![];
-        let now = Utc::now();
-        for table_id_list_key in table_id_list_keys.iter() {
-            // get table id list from _fd_table_id_list/db_id/table_name
-            let dbid_tbname_idlist = TableIdListKey {
-                db_id,
-                table_name: table_id_list_key.table_name.clone(),
-            };
-            let (tb_id_list_seq, tb_id_list_opt): (_, Option<TableIdList>) =
-                get_struct_value(self, &dbid_tbname_idlist).await?;
+        let table_id = req.table_id;
+        let tbid = TableId { table_id };
+        let id_to_name = TableIdToName { table_id };
 
-            let tb_id_list = if tb_id_list_seq == 0 {
-                continue;
-            } else {
-                match tb_id_list_opt {
-                    Some(list) => list,
-                    None => {
-                        continue;
-                    }
-                }
-            };
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
 
-            debug!(name = display(&table_id_list_key), "get_table_history");
+            let (tb_meta_seq, tb_meta): (_, Option<TableMeta>) =
+                get_struct_value(self, &tbid).await?;
+            // a table which is not dropped can not be removed
+            if tb_meta_seq == 0 || !tb_meta.map_or(false, |meta| meta.drop_on.is_some()) {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(table_id, "remove_dropped_table"),
+                )));
+            }
 
-            for table_id in tb_id_list.id_list.iter() {
-                let tbid = TableId {
-                    table_id: *table_id,
+            let mut condition = vec![txn_cond_seq(&tbid, Eq, tb_meta_seq)];
+            let mut if_then = vec![txn_op_del(&tbid)];
+
+            // remove the table id from the history of the table name
+            let (name_seq, name): (_, Option<DBIdTableName>) =
+                get_struct_value(self, &id_to_name).await?;
+            if let Some(name) = name {
+                let dbid_tbname_idlist = TableIdListKey {
+                    db_id: name.db_id,
+                    table_name: name.table_name,
                 };
-
-                let (tb_meta_seq, tb_meta): (_, Option<TableMeta>) =
-                    get_struct_value(self, &tbid).await?;
-                if tb_meta_seq == 0 || tb_meta.is_none() {
-                    error!("get_table_history cannot find {:?} table_meta", table_id);
-                    continue;
+                let (tb_id_list_seq, tb_id_list): (_, Option<TableIdList>) =
+                    get_struct_value(self, &dbid_tbname_idlist).await?;
+                if let Some(tb_id_list) = tb_id_list {
+                    let mut new_tb_id_list = TableIdList::new();
+                    for id in tb_id_list.id_list().iter().filter(|id| **id != table_id) {
+                        new_tb_id_list.append(*id);
+                    }
+                    condition.push(txn_cond_seq(&dbid_tbname_idlist, Eq, tb_id_list_seq));
+                    if_then.push(txn_op_put(
+                        &dbid_tbname_idlist,
+                        serialize_struct(&new_tb_id_list)?,
+                    ));
                 }
+                condition.push(txn_cond_seq(&id_to_name, Eq, name_seq));
+                if_then.push(txn_op_del(&id_to_name));
+            }
 
-                // Safe unwrap() because: tb_meta_seq > 0
-                let tb_meta = tb_meta.unwrap();
-                if is_drop_time_out_of_retention_time(&tb_meta.drop_on, &now) {
-                    continue;
-                }
+            // remove stage file info of the table
+            remove_table_copied_files(self, table_id, &mut condition, &mut if_then).await?;
 
-                let tenant_dbname_tbname: TableNameIdent = TableNameIdent {
-                    tenant: tenant_dbname.tenant.clone(),
-                    db_name: tenant_dbname.db_name.clone(),
-                    table_name: table_id_list_key.table_name.clone(),
-                };
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
 
-                let db_type = match db_meta.from_share.clone() {
-                    Some(share_ident) => DatabaseType::ShareDB(share_ident),
-                    None => DatabaseType::NormalDB,
-                };
+            let (succ, _responses) = send_txn(self, txn_req).await?;
 
-                let tb_info = TableInfo {
-                    ident: TableIdent {
-                        table_id: *table_id,
-                        seq: tb_meta_seq,
-                    },
-                    desc: tenant_dbname_tbname.to_string(),
-                    name: table_id_list_key.table_name.clone(),
-                    meta: tb_meta,
-                    tenant: tenant_dbname.tenant.clone(),
-                    db_type,
-                };
+            debug!(
+                id = debug(&tbid),
+                succ = display(succ),
+                "remove_dropped_table"
+            );
 
-                tb_info_list.push(Arc::new(tb_info));
+            if succ {
+                return Ok(RemoveDroppedTableReply {});
             }
         }
 
-        return Ok(tb_info_list);
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("remove_dropped_table", TXN_MAX_RETRY_TIMES),
+        )))
     }
 
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
@@ -2081,14 +2119,49 @@ impl<KV: kvapi::KVApi<Error = KVAppError>> SchemaApi for KV {
                 )));
             }
 
+            let mut condition = vec![
+                // table is not changed
+                txn_cond_seq(&tbid, Eq, tb_meta_seq),
+            ];
+            let mut if_then = vec![
+                txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?), // tb_id -> tb_meta
+            ];
+
+            // advance the offsets of the consumed streams along with the table
+            for stream_req in &req.update_stream_metas {
+                let stream_id = TableId {
+                    table_id: stream_req.stream_id,
+                };
+                let (stream_meta_seq, stream_meta): (_, Option<TableMeta>) =
+                    get_struct_value(self, &stream_id).await?;
+                let mut stream_meta = match stream_meta {
+                    Some(stream_meta) if stream_meta_seq != 0 => stream_meta,
+                    _ => {
+                        return Err(KVAppError::AppError(AppError::UnknownTableId(
+                            UnknownTableId::new(stream_req.stream_id, "update_stream_meta"),
+                        )));
+                    }
+                };
+                if stream_req.seq.match_seq(stream_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        StreamVersionMismatched::new(
+                            stream_req.stream_id,
+                            stream_req.seq,
+                            stream_meta_seq,
+                            "update_stream_meta",
+                        ),
+                    )));
+                }
+                stream_meta.options = stream_req.options.clone();
+                stream_meta.updated_on = Utc::now();
+
+                condition.push(txn_cond_seq(&stream_id, Eq, stream_meta_seq));
+                if_then.push(txn_op_put(&stream_id, serialize_struct(&stream_meta)?));
+            }
+
             let txn_req = TxnRequest {
-                condition: vec![
-                    // table is not changed
-                    txn_cond_seq(&tbid, Eq, tb_meta_seq),
-                ],
-                if_then: vec![
-                    txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?), // tb_id -> tb_meta
-                ],
+                condition,
+                if_then,
                 else_then: vec![],
             };
 
@@ -2470,6 +2543,107 @@ async fn gc_dropped_db(
     Ok(cnt)
 }
 
+/// List the infos of the tables of a database, current and dropped, whose meta match the filter.
+async fn list_table_infos_of_db(
+    kv_api: &(impl kvapi::KVApi<Error = KVAppError> + ?Sized),
+    tenant_dbname: &DatabaseNameIdent,
+    fn_name: &str,
+    filter: impl Fn(&TableMeta) -> bool,
+) -> Result<Vec<Arc<TableInfo>>, KVAppError> {
+    // Get db by name to ensure presence
+    let res = get_db_or_err(
+        kv_api,
+        tenant_dbname,
+        format!("{}: {}", fn_name, tenant_dbname),
+    )
+    .await;
+
+    let (_db_id_seq, db_id, _db_meta_seq, db_meta) = match res {
+        Ok(x) => x,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+
+    // List tables by tenant, db_id, table_name.
+    let dbid_tbname_idlist = TableIdListKey {
+        db_id,
+        table_name: "".to_string(),
+    };
+
+    let table_id_list_keys = list_keys(kv_api, &dbid_tbname_idlist).await?;
+
+    let mut tb_info_list = vec![];
+    for table_id_list_key in table_id_list_keys.iter() {
+        // get table id list from _fd_table_id_list/db_id/table_name
+        let dbid_tbname_idlist = TableIdListKey {
+            db_id,
+            table_name: table_id_list_key.table_name.clone(),
+        };
+        let (tb_id_list_seq, tb_id_list_opt): (_, Option<TableIdList>) =
+            get_struct_value(kv_api, &dbid_tbname_idlist).await?;
+
+        let tb_id_list = if tb_id_list_seq == 0 {
+            continue;
+        } else {
+            match tb_id_list_opt {
+                Some(list) => list,
+                None => {
+                    continue;
+                }
+            }
+        };
+
+        debug!(name = display(&table_id_list_key), "{}", fn_name);
+
+        for table_id in tb_id_list.id_list.iter() {
+            let tbid = TableId {
+                table_id: *table_id,
+            };
+
+            let (tb_meta_seq, tb_meta): (_, Option<TableMeta>) =
+                get_struct_value(kv_api, &tbid).await?;
+            if tb_meta_seq == 0 || tb_meta.is_none() {
+                error!("{} cannot find {:?} table_meta", fn_name, table_id);
+                continue;
+            }
+
+            // Safe unwrap() because: tb_meta_seq > 0
+            let tb_meta = tb_meta.unwrap();
+            if !filter(&tb_meta) {
+                continue;
+            }
+
+            let tenant_dbname_tbname: TableNameIdent = TableNameIdent {
+                tenant: tenant_dbname.tenant.clone(),
+                db_name: tenant_dbname.db_name.clone(),
+                table_name: table_id_list_key.table_name.clone(),
+            };
+
+            let db_type = match db_meta.from_share.clone() {
+                Some(share_ident) => DatabaseType::ShareDB(share_ident),
+                None => DatabaseType::NormalDB,
+            };
+
+            let tb_info = TableInfo {
+                ident: TableIdent {
+                    table_id: *table_id,
+                    seq: tb_meta_seq,
+                },
+                desc: tenant_dbname_tbname.to_string(),
+                name: table_id_list_key.table_name.clone(),
+                meta: tb_meta,
+                tenant: tenant_dbname.tenant.clone(),
+                db_type,
+            };
+
+            tb_info_list.push(Arc::new(tb_info));
+        }
+    }
+
+    Ok(tb_info_list)
+}
+
 // Return true if drop time is out of `DATA_RETENTION_TIME_IN_DAYS option,
 // use DEFAULT_DATA_RETENTION_SECONDS by default.
 fn is_drop_time_out_of_retention_time(
@@ -43,10 +43,12 @@ use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::RemoveDroppedTableReq;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableCopiedFileNameIdent;
+use common_meta_app::schema::TableDependency;
 use common_meta_app::schema::TableId;
 use common_meta_app::schema::TableIdList;
 use common_meta_app::schema::TableIdListKey;
@@ -254,6 +256,7 @@ impl SchemaApiTestSuite {
             .table_drop_out_of_retention_time_history(&b.build().await)
             .await?;
         suite.get_table_by_id(&b.build().await).await?;
+        suite.table_create_with_dependency(&b.build().await).await?;
         suite.get_table_copied_file(&b.build().await).await?;
         suite.truncate_table(&b.build().await).await?;
         suite.get_tables_from_share(&b.build().await).await?;
@@ -374,6 +377,7 @@ impl SchemaApiTestSuite {
             if_not_exists: false,
             name_ident: db_table_name_ident.clone(),
             table_meta: table_meta(created_on),
+            dependency: None,
         };
 
         {
@@ -1428,6 +1432,7 @@ impl SchemaApiTestSuite {
                 },
 
                 table_meta: table_meta(created_on),
+                dependency: None,
             };
             // test create table
             {
@@ -1510,6 +1515,7 @@ impl SchemaApiTestSuite {
                 table_name: tbl_name.to_string(),
             },
             table_meta: table_meta(created_on),
+            dependency: None,
         };
         let tb_ident_2 = {
             {
@@ -1620,6 +1626,7 @@ impl SchemaApiTestSuite {
                     table_name: "tb3".to_string(),
                 },
                 table_meta: table_meta(created_on),
+                dependency: None,
             };
 
             let old_db = mt.get_database(Self::req_get_db(tenant, db_name)).await?;
@@ -1786,6 +1793,7 @@ impl SchemaApiTestSuite {
                 table_name: tb2_name.to_string(),
             },
             table_meta: table_meta(created_on),
+            dependency: None,
         };
 
         info!("--- create table for rename");
@@ -2009,6 +2017,7 @@ impl SchemaApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: table_meta(created_on),
+                dependency: None,
             };
 
             let _tb_ident_2 = {
@@ -2055,6 +2064,7 @@ impl SchemaApiTestSuite {
                     table_id,
                     seq: MatchSeq::Exact(table_version),
                     new_table_meta: new_table_meta.clone(),
+                    update_stream_metas: vec![],
                 })
                 .await?;
 
@@ -2074,6 +2084,7 @@ impl SchemaApiTestSuite {
                         table_id,
                         seq: MatchSeq::Exact(table_version + 1),
                         new_table_meta: new_table_meta.clone(),
+                        update_stream_metas: vec![],
                     })
                     .await;
 
@@ -2141,6 +2152,7 @@ impl SchemaApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: table_meta(created_on),
+                dependency: None,
             };
 
             let _tb_ident_2 = {
@@ -2451,6 +2463,7 @@ impl SchemaApiTestSuite {
             if_not_exists: false,
             name_ident,
             table_meta: create_table_meta.clone(),
+            dependency: None,
         };
 
         let res = mt.create_table(req).await?;
@@ -2666,6 +2679,7 @@ impl SchemaApiTestSuite {
                 if_not_exists: false,
                 name_ident: tbl_name_ident.clone(),
                 table_meta: create_table_meta.clone(),
+                dependency: None,
             };
 
             let old_db = mt.get_database(Self::req_get_db(tenant, db_name)).await?;
@@ -2698,6 +2712,29 @@ impl SchemaApiTestSuite {
                 .await?;
 
             assert_eq!(res.len(), 0);
+
+            // but the dropped ones are still listed until garbage collected
+            let res = mt
+                .get_drop_table_infos(ListTableReq::new(tenant, db_name))
+                .await?;
+
+            assert_eq!(res.len(), 1);
+            assert_eq!(res[0].ident.table_id, table_id);
+
+            // until their meta is removed
+            mt.remove_dropped_table(RemoveDroppedTableReq { table_id })
+                .await?;
+            let res = mt
+                .get_drop_table_infos(ListTableReq::new(tenant, db_name))
+                .await?;
+            assert!(res.is_empty());
+
+            let res = mt.get_table_by_id(table_id).await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownTableId("").code(),
+                ErrorCode::from(err).code()
+            );
         }
 
         Ok(())
@@ -2771,6 +2808,7 @@ impl SchemaApiTestSuite {
                 if_not_exists: false,
                 name_ident: tbl_name_ident.clone(),
                 table_meta: create_table_meta.clone(),
+                dependency: None,
             };
 
             let old_db = mt.get_database(Self::req_get_db(tenant, db_name)).await?;
@@ -2886,6 +2924,7 @@ impl SchemaApiTestSuite {
                     if_not_exists: false,
                     name_ident: tbl_name_ident.clone(),
                     table_meta: create_table_meta.clone(),
+                    dependency: None,
                 })
                 .await?;
             let cur_db = mt.get_database(Self::req_get_db(tenant, db_name)).await?;
@@ -2978,6 +3017,7 @@ impl SchemaApiTestSuite {
                 if_not_exists: false,
                 name_ident: new_tbl_name_ident.clone(),
                 table_meta: create_table_meta.clone(),
+                dependency: None,
             };
 
             let old_db = mt.get_database(Self::req_get_db(tenant, db_name)).await?;
@@ -3139,6 +3179,7 @@ impl SchemaApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: table_meta(created_on),
+                dependency: None,
             };
 
             let _tb_ident_2 = {
@@ -3192,6 +3233,95 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn table_create_with_dependency<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+
+        let create_table_req = |table_name: &str, dependency| CreateTableReq {
+            if_not_exists: false,
+            name_ident: TableNameIdent {
+                tenant: tenant.to_string(),
+                db_name: db_name.to_string(),
+                table_name: table_name.to_string(),
+            },
+            table_meta: TableMeta {
+                schema: Arc::new(TableSchema::new(vec![TableField::new(
+                    "number",
+                    TableDataType::Number(NumberDataType::UInt64),
+                )])),
+                engine: "JSON".to_string(),
+                ..TableMeta::default()
+            },
+            dependency,
+        };
+        let dependency = |table_ids| {
+            Some(TableDependency {
+                table_ids,
+                option_key: "dependents".to_string(),
+            })
+        };
+
+        info!("--- prepare db");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+        }
+
+        info!("--- the dependents are registered on the tables depended on");
+        {
+            let tb1 = mt.create_table(create_table_req("tb1", None)).await?;
+            let tb2 = mt
+                .create_table(create_table_req("tb2", dependency(vec![tb1.table_id])))
+                .await?;
+            let tb3 = mt
+                .create_table(create_table_req(
+                    "tb3",
+                    dependency(vec![tb2.table_id, tb1.table_id]),
+                ))
+                .await?;
+
+            let (_, tb1_meta) = mt.get_table_by_id(tb1.table_id).await?;
+            assert_eq!(
+                Some(&format!("{},{}", tb2.table_id, tb3.table_id)),
+                tb1_meta.options.get("dependents")
+            );
+            let (_, tb2_meta) = mt.get_table_by_id(tb2.table_id).await?;
+            assert_eq!(
+                Some(&tb3.table_id.to_string()),
+                tb2_meta.options.get("dependents")
+            );
+            let (_, tb3_meta) = mt.get_table_by_id(tb3.table_id).await?;
+            assert_eq!(None, tb3_meta.options.get("dependents"));
+        }
+
+        info!("--- depending on an unknown table fails");
+        {
+            let res = mt
+                .create_table(create_table_req("tb4", dependency(vec![9999])))
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownTableId("").code(),
+                ErrorCode::from(err).code()
+            );
+            let res = mt.get_table((tenant, db_name, "tb4").into()).await;
+            assert!(res.is_err());
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn get_table_copied_file<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
@@ -3241,6 +3371,7 @@ impl SchemaApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: table_meta(created_on),
+                dependency: None,
             };
             let resp = mt.create_table(req.clone()).await?;
             table_id = resp.table_id;
@@ -3354,6 +3485,7 @@ impl SchemaApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: table_meta(created_on),
+                dependency: None,
             };
             let resp = mt.create_table(req.clone()).await?;
             table_id = resp.table_id;
@@ -3487,6 +3619,7 @@ impl SchemaApiTestSuite {
                     if_not_exists: false,
                     name_ident: tb_name.clone(),
                     table_meta: table_meta(create_on),
+                    dependency: None,
                 };
                 let res = mt.create_table(req).await?;
                 if tb_name == &tb_name1 {
@@ -3613,6 +3746,7 @@ impl SchemaApiTestSuite {
                     options: options.clone(),
                     ..Default::default()
                 },
+                dependency: None,
             };
 
             let tb_ids = {
@@ -3682,6 +3816,7 @@ impl SchemaApiTestSuite {
                     options: options.clone(),
                     ..Default::default()
                 },
+                dependency: None,
             };
 
             let tb_ids = {
@@ -4060,6 +4195,7 @@ impl SchemaApiTestSuite {
                         options: options.clone(),
                         ..Default::default()
                     },
+                    dependency: None,
                 };
                 let old_db = node_a
                     .get_database(Self::req_get_db(tenant, db_name))
@@ -4133,6 +4269,7 @@ impl SchemaApiTestSuite {
                     options: options.clone(),
                     ..Default::default()
                 },
+                dependency: None,
             };
 
             let old_db = node_a
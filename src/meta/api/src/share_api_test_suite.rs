@@ -547,6 +547,7 @@ impl ShareApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: TableMeta::default(),
+                dependency: None,
             };
 
             let res = mt.create_table(req.clone()).await?;
@@ -573,6 +574,7 @@ impl ShareApiTestSuite {
                     table_name: tbl2_name.to_string(),
                 },
                 table_meta: TableMeta::default(),
+                dependency: None,
             };
 
             let res = mt.create_table(req.clone()).await?;
@@ -908,6 +910,7 @@ impl ShareApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: TableMeta::default(),
+                dependency: None,
             };
 
             let res = mt.create_table(req.clone()).await?;
@@ -1056,6 +1059,7 @@ impl ShareApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: TableMeta::default(),
+                dependency: None,
             };
 
             let res = mt.create_table(req.clone()).await?;
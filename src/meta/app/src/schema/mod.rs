@@ -54,12 +54,15 @@ pub use table::GetTableCopiedFileReply;
 pub use table::GetTableCopiedFileReq;
 pub use table::GetTableReq;
 pub use table::ListTableReq;
+pub use table::RemoveDroppedTableReply;
+pub use table::RemoveDroppedTableReq;
 pub use table::RenameTableReply;
 pub use table::RenameTableReq;
 pub use table::TableCopiedFileInfo;
 pub use table::TableCopiedFileLock;
 pub use table::TableCopiedFileLockKey;
 pub use table::TableCopiedFileNameIdent;
+pub use table::TableDependency;
 pub use table::TableId;
 pub use table::TableIdList;
 pub use table::TableIdListKey;
@@ -73,6 +76,7 @@ pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateStreamMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableCopiedFileReply;
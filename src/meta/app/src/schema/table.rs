@@ -389,6 +389,19 @@ pub struct CreateTableReq {
     pub if_not_exists: bool,
     pub name_ident: TableNameIdent,
     pub table_meta: TableMeta,
+    /// The tables whose data the new table reads, such as the source tables of a clone or a
+    /// stream. The new table is registered on them in the same transaction as it is created.
+    #[serde(default)]
+    pub dependency: Option<TableDependency>,
+}
+
+/// The tables a new table depends on, see `CreateTableReq::dependency`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TableDependency {
+    pub table_ids: Vec<u64>,
+    /// The option of the tables depended on, which holds the comma separated ids of the
+    /// tables depending on them. The id of the new table is appended to it.
+    pub option_key: String,
 }
 
 impl CreateTableReq {
@@ -488,6 +501,21 @@ impl Display for UndropTableReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct UndropTableReply {}
 
+/// Removes the meta of a dropped table, once its data has been removed.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RemoveDroppedTableReq {
+    pub table_id: u64,
+}
+
+impl Display for RemoveDroppedTableReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "remove_dropped_table:{}", self.table_id)
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RemoveDroppedTableReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RenameTableReq {
     pub if_exists: bool,
@@ -544,6 +572,18 @@ pub struct UpdateTableMetaReq {
     pub table_id: u64,
     pub seq: MatchSeq,
     pub new_table_meta: TableMeta,
+    /// The offsets of the streams consumed by the statement, which are
+    /// updated in the same transaction as the table.
+    #[serde(default)]
+    pub update_stream_metas: Vec<UpdateStreamMetaReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateStreamMetaReq {
+    pub stream_id: u64,
+    pub seq: MatchSeq,
+    /// The new options of the stream, replacing the old ones.
+    pub options: BTreeMap<String, String>,
 }
 
 impl UpsertTableOptionReq {
@@ -19,6 +19,7 @@ use common_expression as ex;
 use common_expression::types::NumberDataType;
 use common_expression::TableDataType;
 use common_protos::pb;
+use common_protos::pb::computed_expr::ComputedExpr as ComputedExprPb;
 use common_protos::pb::data_type::Dt;
 use common_protos::pb::data_type::Dt24;
 use common_protos::pb::number::Num;
@@ -76,7 +77,11 @@ impl FromToProto for ex::TableField {
                 reason: "DataField.data_type can not be None".to_string(),
             })?)?,
         )
-        .with_default_expr(p.default_expr);
+        .with_default_expr(p.default_expr)
+        .with_computed_expr(match p.computed_expr {
+            Some(computed_expr) => Some(ex::ComputedExpr::from_pb(computed_expr)?),
+            None => None,
+        });
         Ok(v)
     }
 
@@ -87,11 +92,53 @@ impl FromToProto for ex::TableField {
             name: self.name().clone(),
             default_expr: self.default_expr().cloned(),
             data_type: Some(self.data_type().to_pb()?),
+            computed_expr: match self.computed_expr() {
+                Some(computed_expr) => Some(computed_expr.to_pb()?),
+                None => None,
+            },
         };
         Ok(p)
     }
 }
 
+impl FromToProto for ex::ComputedExpr {
+    type PB = pb::ComputedExpr;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::ComputedExpr) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let computed_expr = match p.computed_expr {
+            None => {
+                return Err(Incompatible {
+                    reason: "Invalid ComputedExpr: .computed_expr can not be None".to_string(),
+                });
+            }
+            Some(x) => x,
+        };
+
+        let x = match computed_expr {
+            ComputedExprPb::Virtual(expr) => Self::Virtual(expr),
+            ComputedExprPb::Stored(expr) => Self::Stored(expr),
+        };
+        Ok(x)
+    }
+
+    fn to_pb(&self) -> Result<pb::ComputedExpr, Incompatible> {
+        let x = match self {
+            ex::ComputedExpr::Virtual(expr) => ComputedExprPb::Virtual(expr.clone()),
+            ex::ComputedExpr::Stored(expr) => ComputedExprPb::Stored(expr.clone()),
+        };
+        Ok(pb::ComputedExpr {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+
+            computed_expr: Some(x),
+        })
+    }
+}
+
 impl FromToProto for ex::TableDataType {
     type PB = pb::DataType;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -0,0 +1,68 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_expression as ex;
+use common_expression::types::NumberDataType;
+use common_meta_app::schema as mt;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v26_table_meta() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 125, 10, 28, 10, 1, 97, 26, 17, 154, 2, 8, 42, 0, 160, 6, 26, 168, 6, 24, 160, 6, 26,
+        168, 6, 24, 160, 6, 26, 168, 6, 24, 10, 43, 10, 1, 98, 26, 17, 154, 2, 8, 42, 0, 160, 6,
+        26, 168, 6, 24, 160, 6, 26, 168, 6, 24, 34, 13, 18, 5, 97, 32, 43, 32, 49, 160, 6, 26, 168,
+        6, 24, 160, 6, 26, 168, 6, 24, 10, 42, 10, 1, 99, 26, 9, 146, 2, 0, 160, 6, 26, 168, 6, 24,
+        34, 20, 10, 12, 116, 111, 95, 115, 116, 114, 105, 110, 103, 40, 97, 41, 160, 6, 26, 168, 6,
+        24, 160, 6, 26, 168, 6, 24, 160, 6, 26, 168, 6, 24, 50, 4, 70, 85, 83, 69, 82, 7, 100, 101,
+        102, 97, 117, 108, 116, 162, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58,
+        48, 48, 58, 48, 57, 32, 85, 84, 67, 170, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32,
+        49, 50, 58, 48, 48, 58, 49, 48, 32, 85, 84, 67, 202, 1, 0, 202, 1, 0, 202, 1, 0, 160, 6,
+        26, 168, 6, 24,
+    ];
+
+    let want = || mt::TableMeta {
+        schema: Arc::new(ex::TableSchema::new(vec![
+            ex::TableField::new("a", ex::TableDataType::Number(NumberDataType::Int64)),
+            ex::TableField::new("b", ex::TableDataType::Number(NumberDataType::Int64))
+                .with_computed_expr(Some(ex::ComputedExpr::Stored("a + 1".to_string()))),
+            ex::TableField::new("c", ex::TableDataType::String)
+                .with_computed_expr(Some(ex::ComputedExpr::Virtual("to_string(a)".to_string()))),
+        ])),
+        catalog: "default".to_string(),
+        engine: "FUSE".to_string(),
+        created_on: Utc.ymd(2014, 11, 28).and_hms(12, 0, 9),
+        updated_on: Utc.ymd(2014, 11, 29).and_hms(12, 0, 10),
+        field_comments: vec!["".to_string(); 3],
+        ..Default::default()
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 26, want())
+}
@@ -205,6 +205,26 @@ impl TableVersionMismatched {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("StreamVersionMismatched: {stream_id} expect `{expect}` but `{curr}`  while `{context}`")]
+pub struct StreamVersionMismatched {
+    stream_id: u64,
+    expect: MatchSeq,
+    curr: u64,
+    context: String,
+}
+
+impl StreamVersionMismatched {
+    pub fn new(stream_id: u64, expect: MatchSeq, curr: u64, context: impl Into<String>) -> Self {
+        Self {
+            stream_id,
+            expect,
+            curr,
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[error("UnknownDatabase: `{db_name}` while `{context}`")]
 pub struct UnknownDatabase {
@@ -438,6 +458,9 @@ pub enum AppError {
     #[error(transparent)]
     TableVersionMismatched(#[from] TableVersionMismatched),
 
+    #[error(transparent)]
+    StreamVersionMismatched(#[from] StreamVersionMismatched),
+
     #[error(transparent)]
     TableAlreadyExists(#[from] TableAlreadyExists),
 
@@ -551,6 +574,8 @@ impl AppErrorMessage for UnknownDatabaseId {}
 
 impl AppErrorMessage for TableVersionMismatched {}
 
+impl AppErrorMessage for StreamVersionMismatched {}
+
 impl AppErrorMessage for TableAlreadyExists {
     fn message(&self) -> String {
         format!("Table '{}' already exists", self.table_name)
@@ -710,6 +735,9 @@ impl From<AppError> for ErrorCode {
             AppError::TableVersionMismatched(err) => {
                 ErrorCode::TableVersionMismatched(err.message())
             }
+            AppError::StreamVersionMismatched(err) => {
+                ErrorCode::StreamVersionMismatched(err.message())
+            }
             AppError::ShareAlreadyExists(err) => ErrorCode::ShareAlreadyExists(err.message()),
             AppError::UnknownShare(err) => ErrorCode::UnknownShare(err.message()),
             AppError::UnknownShareId(err) => ErrorCode::UnknownShareId(err.message()),
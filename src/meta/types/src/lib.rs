@@ -76,6 +76,7 @@ pub use errors::app_error::DatabaseAlreadyExists;
 pub use errors::app_error::DropDbWithDropTime;
 pub use errors::app_error::DropTableWithDropTime;
 pub use errors::app_error::ShareAlreadyExists;
+pub use errors::app_error::StreamVersionMismatched;
 pub use errors::app_error::TableAlreadyExists;
 pub use errors::app_error::TableVersionMismatched;
 pub use errors::app_error::UndropDbHasNoHistory;
@@ -137,6 +137,9 @@ pub enum Expr {
     },
     /// A literal value, such as string, number, date or NULL
     Literal { span: Span, lit: Literal },
+    /// A parameter placeholder of a prepared statement, `$n` numbered from 1,
+    /// or `?` numbered by 0 until the statement is planned
+    Placeholder { span: Span, index: usize },
     /// `COUNT(*)` expression
     CountAll { span: Span },
     /// `(foo, bar)`
@@ -179,6 +182,8 @@ pub enum Expr {
     },
     /// The `Array` expr
     Array { span: Span, exprs: Vec<Expr> },
+    /// The `Map` expr, like `{'k1': v1, 'k2': v2}`
+    Map { span: Span, kvs: Vec<(Expr, Expr)> },
     /// The `Interval 1 DAY` expr
     Interval {
         span: Span,
@@ -259,6 +264,10 @@ pub enum TypeName {
         fields_name: Option<Vec<String>>,
         fields_type: Vec<TypeName>,
     },
+    Map {
+        key_type: Box<TypeName>,
+        val_type: Box<TypeName>,
+    },
     Object,
     Variant,
     Nullable(Box<TypeName>),
@@ -371,6 +380,7 @@ impl Expr {
             | Expr::Substring { span, .. }
             | Expr::Trim { span, .. }
             | Expr::Literal { span, .. }
+            | Expr::Placeholder { span, .. }
             | Expr::CountAll { span }
             | Expr::Tuple { span, .. }
             | Expr::FunctionCall { span, .. }
@@ -379,6 +389,7 @@ impl Expr {
             | Expr::Subquery { span, .. }
             | Expr::MapAccess { span, .. }
             | Expr::Array { span, .. }
+            | Expr::Map { span, .. }
             | Expr::Interval { span, .. }
             | Expr::DateAdd { span, .. }
             | Expr::DateSub { span, .. }
@@ -590,6 +601,9 @@ impl Display for TypeName {
                 }
                 write!(f, ")")?;
             }
+            TypeName::Map { key_type, val_type } => {
+                write!(f, "MAP({}, {})", key_type, val_type)?;
+            }
             TypeName::Object => {
                 write!(f, "OBJECT")?;
             }
@@ -781,6 +795,12 @@ impl Display for Expr {
             Expr::Literal { lit, .. } => {
                 write!(f, "{lit}")?;
             }
+            Expr::Placeholder { index: 0, .. } => {
+                write!(f, "?")?;
+            }
+            Expr::Placeholder { index, .. } => {
+                write!(f, "${index}")?;
+            }
             Expr::CountAll { .. } => {
                 write!(f, "COUNT(*)")?;
             }
@@ -859,6 +879,16 @@ impl Display for Expr {
                 write_comma_separated_list(f, exprs)?;
                 write!(f, "]")?;
             }
+            Expr::Map { kvs, .. } => {
+                write!(f, "{{")?;
+                for (i, (k, v)) in kvs.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{k}: {v}")?;
+                }
+                write!(f, "}}")?;
+            }
             Expr::Interval { expr, unit, .. } => {
                 write!(f, "INTERVAL {expr} {unit}")?;
             }
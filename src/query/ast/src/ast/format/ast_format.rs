@@ -404,6 +404,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_placeholder(&mut self, _span: Span, index: usize) {
+        let name = format!("Placeholder ${index}");
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_count_all(&mut self, _span: Span) {
         let name = "Function CountAll".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -893,6 +900,10 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         let mut children = Vec::new();
         self.visit_table_ref(&insert.catalog, &insert.database, &insert.table);
         children.push(self.children.pop().unwrap());
+        if let Some(branch) = &insert.branch {
+            let branch_format_ctx = AstFormatContext::new(format!("Branch {}", branch));
+            children.push(FormatTreeNode::new(branch_format_ctx));
+        }
         if !insert.columns.is_empty() {
             let mut columns_children = Vec::with_capacity(insert.columns.len());
             for column in insert.columns.iter() {
@@ -1228,6 +1239,23 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                self.visit_table_ref(catalog, database, table);
+                let mut children = vec![self.children.pop().unwrap()];
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, children.len());
+                let node = FormatTreeNode::with_children(format_ctx, children);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1306,6 +1334,46 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::with_children(action_name, 1);
                 FormatTreeNode::with_children(action_format_ctx, vec![point_node])
             }
+            AlterTableAction::CreateTag { tag, travel_point } => {
+                let mut children = Vec::new();
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let action_name = format!("Action CreateTag {}", tag);
+                let action_format_ctx =
+                    AstFormatContext::with_children(action_name, children.len());
+                FormatTreeNode::with_children(action_format_ctx, children)
+            }
+            AlterTableAction::DropTag { tag } => {
+                let action_name = format!("Action DropTag {}", tag);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::CreateBranch {
+                branch,
+                travel_point,
+            } => {
+                let mut children = Vec::new();
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let action_name = format!("Action CreateBranch {}", branch);
+                let action_format_ctx =
+                    AstFormatContext::with_children(action_name, children.len());
+                FormatTreeNode::with_children(action_format_ctx, children)
+            }
+            AlterTableAction::DropBranch { branch } => {
+                let action_name = format!("Action DropBranch {}", branch);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::FastForward { branch } => {
+                let action_name = format!("Action FastForward {}", branch);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1361,6 +1429,36 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_vacuum_table(&mut self, stmt: &'ast VacuumTableStmt) {
+        let mut children = Vec::new();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        children.push(self.children.pop().unwrap());
+        let option_name = format!("Option{}", stmt.option);
+        let option_format_ctx = AstFormatContext::new(option_name);
+        children.push(FormatTreeNode::new(option_format_ctx));
+
+        let name = "VacuumTable".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_vacuum_drop_table(&mut self, stmt: &'ast VacuumDropTableStmt) {
+        let mut children = Vec::new();
+        if let Some(database) = &stmt.database {
+            self.visit_database_ref(&stmt.catalog, database);
+            children.push(self.children.pop().unwrap());
+        }
+        let option_name = format!("Option{}", stmt.option);
+        let option_format_ctx = AstFormatContext::new(option_name);
+        children.push(FormatTreeNode::new(option_format_ctx));
+
+        let name = "VacuumDropTable".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_exists_table(&mut self, stmt: &'ast ExistsTableStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let child = self.children.pop().unwrap();
@@ -1377,7 +1475,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.visit_query(&stmt.query);
         let query_child = self.children.pop().unwrap();
 
-        let name = "CreateView".to_string();
+        let name = if stmt.materialized {
+            "CreateMaterializedView".to_string()
+        } else {
+            "CreateView".to_string()
+        };
         let format_ctx = AstFormatContext::with_children(name, 2);
         let node = FormatTreeNode::with_children(format_ctx, vec![view_child, query_child]);
         self.children.push(node);
@@ -1405,6 +1507,95 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_refresh_materialized_view(&mut self, stmt: &'ast RefreshMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "RefreshMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt) {
+        self.visit_identifier(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+        let mut children = vec![index_child, table_child];
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            children.push(self.children.pop().unwrap());
+        }
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_inverted_index(&mut self, stmt: &'ast DropInvertedIndexStmt) {
+        self.visit_identifier(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_virtual_column(&mut self, stmt: &'ast CreateVirtualColumnStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let mut children = vec![self.children.pop().unwrap()];
+        for expr in stmt.virtual_columns.iter() {
+            self.visit_expr(expr);
+            children.push(self.children.pop().unwrap());
+        }
+
+        let name = "CreateVirtualColumn".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_virtual_column(&mut self, stmt: &'ast DropVirtualColumnStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let mut children = vec![self.children.pop().unwrap()];
+        for expr in stmt.virtual_columns.iter() {
+            self.visit_expr(expr);
+            children.push(self.children.pop().unwrap());
+        }
+
+        let name = "DropVirtualColumn".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let stream_child = self.children.pop().unwrap();
+        self.visit_table_ref(&None, &stmt.table_database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "CreateStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![stream_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_stream(&mut self, stmt: &'ast DropStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_show_users(&mut self) {
         let name = "ShowUsers".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -2240,6 +2431,18 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            TimeTravelPoint::Tag(tag) => {
+                let name = format!("Tag {}", tag);
+                let format_ctx = AstFormatContext::new(name);
+                let node = FormatTreeNode::new(format_ctx);
+                self.children.push(node);
+            }
+            TimeTravelPoint::Branch(branch) => {
+                let name = format!("Branch {}", branch);
+                let format_ctx = AstFormatContext::new(name);
+                let node = FormatTreeNode::new(format_ctx);
+                self.children.push(node);
+            }
         }
     }
 
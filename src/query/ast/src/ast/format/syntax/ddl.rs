@@ -131,6 +131,38 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point,
+        } => RcDoc::space()
+            .append(RcDoc::text("CLONE"))
+            .append(RcDoc::space())
+            .append(if let Some(catalog) = catalog {
+                RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(database) = database {
+                RcDoc::text(database.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(table.to_string()))
+            .append(match travel_point {
+                Some(TimeTravelPoint::Snapshot(sid)) => {
+                    RcDoc::text(format!(" AT (SNAPSHOT => {sid})"))
+                }
+                Some(TimeTravelPoint::Timestamp(ts)) => {
+                    RcDoc::text(format!(" AT (TIMESTAMP => {ts})"))
+                }
+                Some(TimeTravelPoint::Tag(tag)) => RcDoc::text(format!(" AT (TAG => {tag})")),
+                Some(TimeTravelPoint::Branch(branch)) => {
+                    RcDoc::text(format!(" AT (BRANCH => {branch})"))
+                }
+                None => RcDoc::nil(),
+            }),
     }
 }
 
@@ -184,12 +216,46 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::RevertTo { point } => match point {
             TimeTravelPoint::Snapshot(sid) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
             TimeTravelPoint::Timestamp(ts) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
+            TimeTravelPoint::Tag(tag) => RcDoc::text(format!(" AT (TAG => {tag})")),
+            TimeTravelPoint::Branch(branch) => RcDoc::text(format!(" AT (BRANCH => {branch})")),
         },
+        AlterTableAction::CreateTag { tag, travel_point } => RcDoc::line()
+            .append(RcDoc::text(format!("CREATE TAG {tag}")))
+            .append(if let Some(travel_point) = travel_point {
+                RcDoc::text(format!(" AT{travel_point}"))
+            } else {
+                RcDoc::nil()
+            }),
+        AlterTableAction::DropTag { tag } => {
+            RcDoc::line().append(RcDoc::text(format!("DROP TAG {tag}")))
+        }
+        AlterTableAction::CreateBranch {
+            branch,
+            travel_point,
+        } => RcDoc::line()
+            .append(RcDoc::text(format!("CREATE BRANCH {branch}")))
+            .append(if let Some(travel_point) = travel_point {
+                RcDoc::text(format!(" AT{travel_point}"))
+            } else {
+                RcDoc::nil()
+            }),
+        AlterTableAction::DropBranch { branch } => {
+            RcDoc::line().append(RcDoc::text(format!("DROP BRANCH {branch}")))
+        }
+        AlterTableAction::FastForward { branch } => {
+            RcDoc::line().append(RcDoc::text(format!("FAST FORWARD TO BRANCH {branch}")))
+        }
     }
 }
 
 pub(crate) fn pretty_create_view(stmt: CreateViewStmt) -> RcDoc<'static> {
-    RcDoc::text("CREATE VIEW")
+    RcDoc::text("CREATE")
+        .append(if stmt.materialized {
+            RcDoc::space().append(RcDoc::text("MATERIALIZED"))
+        } else {
+            RcDoc::nil()
+        })
+        .append(RcDoc::space().append(RcDoc::text("VIEW")))
         .append(if stmt.if_not_exists {
             RcDoc::space().append(RcDoc::text("IF NOT EXISTS"))
         } else {
@@ -52,6 +52,11 @@ pub(crate) fn pretty_insert(insert_stmt: InsertStmt) -> RcDoc<'static> {
                     RcDoc::nil()
                 })
                 .append(RcDoc::text(insert_stmt.table.to_string()))
+                .append(if let Some(branch) = insert_stmt.branch {
+                    RcDoc::text(format!(" AT (BRANCH => {branch})"))
+                } else {
+                    RcDoc::nil()
+                })
                 .append(if !insert_stmt.columns.is_empty() {
                     RcDoc::space()
                         .append(RcDoc::text("("))
@@ -220,6 +220,8 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(pretty_expr(*expr))
             .append(RcDoc::text(")")),
         Expr::Literal { lit, .. } => RcDoc::text(lit.to_string()),
+        Expr::Placeholder { index: 0, .. } => RcDoc::text("?"),
+        Expr::Placeholder { index, .. } => RcDoc::text(format!("${index}")),
         Expr::CountAll { .. } => RcDoc::text("COUNT(*)"),
         Expr::Tuple { exprs, .. } => RcDoc::text("(")
             .append(inline_comma(exprs.into_iter().map(pretty_expr)))
@@ -318,6 +320,14 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
         Expr::Array { exprs, .. } => RcDoc::text("[")
             .append(inline_comma(exprs.into_iter().map(pretty_expr)))
             .append(RcDoc::text("]")),
+        Expr::Map { kvs, .. } => RcDoc::text("{")
+            .append(inline_comma(kvs.into_iter().map(|(k, v)| {
+                pretty_expr(k)
+                    .append(RcDoc::text(":"))
+                    .append(RcDoc::space())
+                    .append(pretty_expr(v))
+            })))
+            .append(RcDoc::text("}")),
         Expr::Interval { expr, unit, .. } => RcDoc::text("INTERVAL")
             .append(RcDoc::space())
             .append(pretty_expr(*expr))
@@ -251,6 +251,10 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             RcDoc::text(format!(" AT (SNAPSHOT => {sid})"))
         } else if let Some(TimeTravelPoint::Timestamp(ts)) = travel_point {
             RcDoc::text(format!(" AT (TIMESTAMP => {ts})"))
+        } else if let Some(TimeTravelPoint::Tag(tag)) = travel_point {
+            RcDoc::text(format!(" AT (TAG => {tag})"))
+        } else if let Some(TimeTravelPoint::Branch(branch)) = travel_point {
+            RcDoc::text(format!(" AT (BRANCH => {branch})"))
         } else {
             RcDoc::nil()
         })
@@ -146,6 +146,8 @@ pub enum Indirection {
 pub enum TimeTravelPoint {
     Snapshot(String),
     Timestamp(Box<Expr>),
+    Tag(Identifier),
+    Branch(Identifier),
 }
 
 /// A table name or a parenthesized subquery with an optional alias
@@ -272,12 +274,8 @@ impl Display for TableReference {
                     catalog.iter().chain(database.iter()).chain(Some(table)),
                 )?;
 
-                if let Some(TimeTravelPoint::Snapshot(sid)) = travel_point {
-                    write!(f, " AT (SNAPSHOT => {sid})")?;
-                }
-
-                if let Some(TimeTravelPoint::Timestamp(ts)) = travel_point {
-                    write!(f, " AT (TIMESTAMP => {ts})")?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
                 }
 
                 if let Some(alias) = alias {
@@ -547,6 +545,12 @@ impl Display for TimeTravelPoint {
             TimeTravelPoint::Timestamp(ts) => {
                 write!(f, " (TIMESTAMP => {ts})")?;
             }
+            TimeTravelPoint::Tag(tag) => {
+                write!(f, " (TAG => {tag})")?;
+            }
+            TimeTravelPoint::Branch(branch) => {
+                write!(f, " (BRANCH => {branch})")?;
+            }
         }
 
         Ok(())
@@ -26,6 +26,8 @@ pub struct InsertStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub table: Identifier,
+    /// The branch written instead of the table, by `INSERT INTO t AT (BRANCH => b)`.
+    pub branch: Option<Identifier>,
     pub columns: Vec<Identifier>,
     pub source: InsertSource,
     pub overwrite: bool,
@@ -46,6 +48,9 @@ impl Display for InsertStmt {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
+        if let Some(branch) = &self.branch {
+            write!(f, " AT (BRANCH => {branch})")?;
+        }
         if !self.columns.is_empty() {
             write!(f, " (")?;
             write_comma_separated_list(f, &self.columns)?;
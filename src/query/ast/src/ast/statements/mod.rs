@@ -17,6 +17,7 @@ mod catalog;
 mod copy;
 mod database;
 mod explain;
+mod index;
 mod insert;
 mod kill;
 mod presign;
@@ -24,17 +25,20 @@ mod share;
 mod show;
 mod stage;
 mod statement;
+mod stream;
 mod table;
 mod unset;
 mod update;
 mod user;
 mod view;
+mod virtual_column;
 
 pub use call::*;
 pub use catalog::*;
 pub use copy::*;
 pub use database::*;
 pub use explain::*;
+pub use index::*;
 pub use insert::*;
 pub use kill::*;
 pub use presign::*;
@@ -42,8 +46,10 @@ pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
+pub use stream::*;
 pub use table::*;
 pub use unset::*;
 pub use update::*;
 pub use user::*;
 pub use view::*;
+pub use virtual_column::*;
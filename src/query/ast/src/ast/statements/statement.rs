@@ -105,12 +105,27 @@ pub enum Statement {
     TruncateTable(TruncateTableStmt),
     OptimizeTable(OptimizeTableStmt),
     AnalyzeTable(AnalyzeTableStmt),
+    VacuumTable(VacuumTableStmt),
+    VacuumDropTable(VacuumDropTableStmt),
     ExistsTable(ExistsTableStmt),
 
     // Views
     CreateView(CreateViewStmt),
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
+
+    // Index
+    CreateInvertedIndex(CreateInvertedIndexStmt),
+    DropInvertedIndex(DropInvertedIndexStmt),
+
+    // Virtual columns
+    CreateVirtualColumn(CreateVirtualColumnStmt),
+    DropVirtualColumn(DropVirtualColumnStmt),
+
+    // Streams
+    CreateStream(CreateStreamStmt),
+    DropStream(DropStreamStmt),
 
     // User
     ShowUsers,
@@ -296,10 +311,19 @@ impl Display for Statement {
             Statement::TruncateTable(stmt) => write!(f, "{stmt}")?,
             Statement::OptimizeTable(stmt) => write!(f, "{stmt}")?,
             Statement::AnalyzeTable(stmt) => write!(f, "{stmt}")?,
+            Statement::VacuumTable(stmt) => write!(f, "{stmt}")?,
+            Statement::VacuumDropTable(stmt) => write!(f, "{stmt}")?,
             Statement::ExistsTable(stmt) => write!(f, "{stmt}")?,
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateVirtualColumn(stmt) => write!(f, "{stmt}")?,
+            Statement::DropVirtualColumn(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStream(stmt) => write!(f, "{stmt}")?,
             Statement::ShowUsers => write!(f, "SHOW USERS")?,
             Statement::ShowRoles => write!(f, "SHOW ROLES")?,
             Statement::CreateUser(stmt) => write!(f, "{stmt}")?,
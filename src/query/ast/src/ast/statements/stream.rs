@@ -0,0 +1,77 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_period_separated_list;
+use crate::ast::Identifier;
+use crate::ast::TimeTravelPoint;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateStreamStmt {
+    pub if_not_exists: bool,
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub stream: Identifier,
+    pub table_database: Option<Identifier>,
+    pub table: Identifier,
+    pub travel_point: Option<TimeTravelPoint>,
+}
+
+impl Display for CreateStreamStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE STREAM ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.stream)),
+        )?;
+        write!(f, " ON TABLE ")?;
+        write_period_separated_list(f, self.table_database.iter().chain(Some(&self.table)))?;
+        if let Some(travel_point) = &self.travel_point {
+            write!(f, " AT{travel_point}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropStreamStmt {
+    pub if_exists: bool,
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub stream: Identifier,
+}
+
+impl Display for DropStreamStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP STREAM ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.stream)),
+        )
+    }
+}
@@ -168,6 +168,12 @@ pub enum CreateTableSource {
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
@@ -186,6 +192,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -293,6 +312,23 @@ pub enum AlterTableAction {
     RevertTo {
         point: TimeTravelPoint,
     },
+    CreateTag {
+        tag: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
+    DropTag {
+        tag: Identifier,
+    },
+    CreateBranch {
+        branch: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
+    DropBranch {
+        branch: Identifier,
+    },
+    FastForward {
+        branch: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -325,6 +361,32 @@ impl Display for AlterTableAction {
                 write!(f, "REVERT TO {}", point)?;
                 Ok(())
             }
+            AlterTableAction::CreateTag { tag, travel_point } => {
+                write!(f, "CREATE TAG {tag}")?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+                Ok(())
+            }
+            AlterTableAction::DropTag { tag } => {
+                write!(f, "DROP TAG {tag}")
+            }
+            AlterTableAction::CreateBranch {
+                branch,
+                travel_point,
+            } => {
+                write!(f, "CREATE BRANCH {branch}")?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+                Ok(())
+            }
+            AlterTableAction::DropBranch { branch } => {
+                write!(f, "DROP BRANCH {branch}")
+            }
+            AlterTableAction::FastForward { branch } => {
+                write!(f, "FAST FORWARD TO BRANCH {branch}")
+            }
         }
     }
 }
@@ -436,6 +498,68 @@ impl Display for AnalyzeTableStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VacuumTableStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub option: VacuumTableOption,
+}
+
+impl Display for VacuumTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "VACUUM TABLE ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, "{}", &self.option)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VacuumDropTableStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub option: VacuumTableOption,
+}
+
+impl Display for VacuumDropTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "VACUUM DROP TABLE")?;
+        if self.database.is_some() {
+            write!(f, " FROM ")?;
+            write_period_separated_list(f, self.catalog.iter().chain(&self.database))?;
+        }
+        write!(f, "{}", &self.option)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VacuumTableOption {
+    pub retain_hours: Option<u64>,
+    pub dry_run: bool,
+}
+
+impl Display for VacuumTableOption {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(retain_hours) = self.retain_hours {
+            write!(f, " RETAIN {retain_hours} HOURS")?;
+        }
+        if self.dry_run {
+            write!(f, " DRY RUN")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExistsTableStmt {
     pub catalog: Option<Identifier>,
@@ -529,9 +653,27 @@ pub struct ColumnDefinition {
     pub name: Identifier,
     pub data_type: TypeName,
     pub default_expr: Option<Box<Expr>>,
+    pub computed_expr: Option<ComputedExpr>,
     pub comment: Option<String>,
 }
 
+/// The expression of a generated column, either computed when the rows are read, or
+/// computed and stored when they are written.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComputedExpr {
+    Virtual(Box<Expr>),
+    Stored(Box<Expr>),
+}
+
+impl Display for ComputedExpr {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ComputedExpr::Virtual(expr) => write!(f, "AS ({expr}) VIRTUAL"),
+            ComputedExpr::Stored(expr) => write!(f, "AS ({expr}) STORED"),
+        }
+    }
+}
+
 impl Display for ColumnDefinition {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "{} {}", self.name, self.data_type)?;
@@ -543,6 +685,9 @@ impl Display for ColumnDefinition {
         if let Some(default_expr) = &self.default_expr {
             write!(f, " DEFAULT {default_expr}")?;
         }
+        if let Some(computed_expr) = &self.computed_expr {
+            write!(f, " {computed_expr}")?;
+        }
         if let Some(comment) = &self.comment {
             write!(f, " COMMENT '{comment}'")?;
         }
@@ -23,6 +23,7 @@ use crate::ast::Query;
 #[derive(Debug, Clone, PartialEq)]
 pub struct CreateViewStmt {
     pub if_not_exists: bool,
+    pub materialized: bool,
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub view: Identifier,
@@ -32,7 +33,11 @@ pub struct CreateViewStmt {
 
 impl Display for CreateViewStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        write!(f, "CREATE VIEW ")?;
+        write!(f, "CREATE ")?;
+        if self.materialized {
+            write!(f, "MATERIALIZED ")?;
+        }
+        write!(f, "VIEW ")?;
         if self.if_not_exists {
             write!(f, "IF NOT EXISTS ")?;
         }
@@ -103,3 +108,23 @@ impl Display for DropViewStmt {
         )
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshMaterializedViewStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub view: Identifier,
+}
+
+impl Display for RefreshMaterializedViewStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "REFRESH MATERIALIZED VIEW ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.view)),
+        )
+    }
+}
@@ -237,6 +237,10 @@ pub enum ExprElement {
     Literal {
         lit: Literal,
     },
+    /// `?` or `$n`, the anonymous `?` is numbered by 0
+    Placeholder {
+        index: usize,
+    },
     /// `Count(*)` expression
     CountAll,
     /// `(foo, bar)`
@@ -278,6 +282,10 @@ pub enum ExprElement {
     Array {
         exprs: Vec<Expr>,
     },
+    /// `{'k1': 'v1', 'k2': 'v2'}`
+    Map {
+        kvs: Vec<(Expr, Expr)>,
+    },
     Interval {
         expr: Expr,
         unit: IntervalKind,
@@ -415,6 +423,10 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 span: transform_span(elem.span.0),
                 lit,
             },
+            ExprElement::Placeholder { index } => Expr::Placeholder {
+                span: transform_span(elem.span.0),
+                index,
+            },
             ExprElement::CountAll => Expr::CountAll {
                 span: transform_span(elem.span.0),
             },
@@ -461,6 +473,10 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 span: transform_span(elem.span.0),
                 exprs,
             },
+            ExprElement::Map { kvs } => Expr::Map {
+                span: transform_span(elem.span.0),
+                kvs,
+            },
             ExprElement::Interval { expr, unit } => Expr::Interval {
                 span: transform_span(elem.span.0),
                 expr: Box::new(expr),
@@ -818,6 +834,14 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     // and then will be converted back to a floating point literal if the map access
     // is not following a primary element nor a postfix element.
     let literal = map(literal, |lit| ExprElement::Literal { lit });
+    let placeholder = alt((
+        value(ExprElement::Placeholder { index: 0 }, rule! { "?" }),
+        map_res(rule! { NumberedPlaceholder }, |token| {
+            Ok(ExprElement::Placeholder {
+                index: token.text()[1..].parse()?,
+            })
+        }),
+    ));
     let array = map(
         // Array that contains a single literal item will be parsed as a bracket map access,
         // and then will be converted back to an array if the map access is not following
@@ -830,6 +854,15 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             ExprElement::Array { exprs }
         },
     );
+    let map_expr = map(
+        rule! {
+            "{" ~ #comma_separated_list0_ignore_trailling(map_element)? ~ ","? ~ ^"}"
+        },
+        |(_, opt_kvs, _, _)| {
+            let kvs = opt_kvs.unwrap_or_default();
+            ExprElement::Map { kvs }
+        },
+    );
     let date_add = map(
         rule! {
             DATE_ADD ~ "(" ~ #interval_kind ~ "," ~ #subexpr(0) ~ "," ~ #subexpr(0) ~ ")"
@@ -904,13 +937,47 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #column_ref : "<column>"
             | #map_access : "[<key>] | .<key> | :<key>"
             | #literal : "<literal>"
+            | #placeholder : "`?` | `$<number>`"
             | #array : "`[...]`"
+            | #map_expr : "`{...}`"
         ),
     )))(i)?;
 
     Ok((rest, WithSpan { span, elem }))
 }
 
+/// Key-value pair in a map literal. The key must be a literal, otherwise
+/// `{'k': v}` would be ambiguous with the colon map access `'k':v`.
+pub fn map_element(i: Input) -> IResult<(Expr, Expr)> {
+    let literal_key = map(
+        rule! {
+            #consumed(literal) ~ ":" ~ #subexpr(0)
+        },
+        |((span, key), _, value)| {
+            let key = Expr::Literal {
+                span: transform_span(span.0),
+                lit: key,
+            };
+            (key, value)
+        },
+    );
+    // Report the keys like `{a: 1}` at the colon, instead of listing the expected literals.
+    let non_literal_key = map_res(rule! { ":" }, |_| {
+        Err::<(Expr, Expr), _>(ErrorKind::Other("map keys must be literals"))
+    });
+    let invalid_key = map(
+        rule! {
+            #expr_element ~ #non_literal_key
+        },
+        |(_, kv)| kv,
+    );
+
+    rule!(
+        #literal_key
+        | #invalid_key
+    )(i)
+}
+
 pub fn unary_op(i: Input) -> IResult<UnaryOperator> {
     // Plus and Minus are parsed as binary op at first.
     value(UnaryOperator::Not, rule! { NOT })(i)
@@ -1153,6 +1220,13 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         TypeName::String,
         rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT  ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
     );
+    let ty_map = map(
+        rule! { MAP ~ "(" ~ #type_name ~ "," ~ #type_name ~ ")" },
+        |(_, _, key_type, _, val_type, _)| TypeName::Map {
+            key_type: Box::new(key_type),
+            val_type: Box::new(val_type),
+        },
+    );
     let ty_object = value(TypeName::Object, rule! { OBJECT | MAP });
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
     map(
@@ -1173,6 +1247,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_date
             | #ty_datetime
             | #ty_string
+            | #ty_map
             | #ty_object
             | #ty_variant
             | #ty_nullable
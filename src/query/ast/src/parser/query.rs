@@ -157,9 +157,17 @@ pub fn travel_point(i: Input) -> IResult<TimeTravelPoint> {
         rule! { "(" ~ TIMESTAMP ~ "=>" ~ #expr ~ ")" },
         |(_, _, _, e, _)| TimeTravelPoint::Timestamp(Box::new(e)),
     );
+    let at_tag = map(
+        rule! { "(" ~ TAG ~ "=>" ~ #ident ~ ")" },
+        |(_, _, _, tag, _)| TimeTravelPoint::Tag(tag),
+    );
+    let at_branch = map(
+        rule! { "(" ~ BRANCH ~ "=>" ~ #ident ~ ")" },
+        |(_, _, _, branch, _)| TimeTravelPoint::Branch(branch),
+    );
 
     rule!(
-        #at_snapshot | #at_timestamp
+        #at_snapshot | #at_timestamp | #at_tag | #at_branch
     )(i)
 }
 
@@ -86,14 +86,16 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! {
             INSERT ~ ( INTO | OVERWRITE ) ~ TABLE?
             ~ #peroid_separated_idents_1_to_3
+            ~ ( AT ~ "(" ~ BRANCH ~ "=>" ~ ^#ident ~ ^")" )?
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ #insert_source
         },
-        |(_, overwrite, _, (catalog, database, table), opt_columns, source)| {
+        |(_, overwrite, _, (catalog, database, table), opt_branch, opt_columns, source)| {
             Statement::Insert(InsertStmt {
                 catalog,
                 database,
                 table,
+                branch: opt_branch.map(|(_, _, _, _, branch, _)| branch),
                 columns: opt_columns
                     .map(|(_, columns, _)| columns)
                     .unwrap_or_default(),
@@ -540,6 +542,35 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let vacuum_table = map(
+        rule! {
+            VACUUM ~ TABLE ~ #peroid_separated_idents_1_to_3 ~ #vacuum_table_option
+        },
+        |(_, _, (catalog, database, table), option)| {
+            Statement::VacuumTable(VacuumTableStmt {
+                catalog,
+                database,
+                table,
+                option,
+            })
+        },
+    );
+    let vacuum_drop_table = map(
+        rule! {
+            VACUUM ~ DROP ~ TABLE ~ ( FROM ~ ^#peroid_separated_idents_1_to_2 )? ~ #vacuum_table_option
+        },
+        |(_, _, _, opt_ctl_db, option)| {
+            let (catalog, database) = match opt_ctl_db {
+                Some((_, (catalog, database))) => (catalog, Some(database)),
+                None => (None, None),
+            };
+            Statement::VacuumDropTable(VacuumDropTableStmt {
+                catalog,
+                database,
+                option,
+            })
+        },
+    );
     let exists_table = map(
         rule! {
             EXISTS ~ TABLE ~ #peroid_separated_idents_1_to_3
@@ -554,14 +585,24 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     );
     let create_view = map(
         rule! {
-            CREATE ~ VIEW ~ ( IF ~ NOT ~ EXISTS )?
+            CREATE ~ MATERIALIZED? ~ VIEW ~ ( IF ~ NOT ~ EXISTS )?
             ~ #peroid_separated_idents_1_to_3
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ AS ~ #query
         },
-        |(_, _, opt_if_not_exists, (catalog, database, view), opt_columns, _, query)| {
+        |(
+            _,
+            opt_materialized,
+            _,
+            opt_if_not_exists,
+            (catalog, database, view),
+            opt_columns,
+            _,
+            query,
+        )| {
             Statement::CreateView(CreateViewStmt {
                 if_not_exists: opt_if_not_exists.is_some(),
+                materialized: opt_materialized.is_some(),
                 catalog,
                 database,
                 view,
@@ -604,6 +645,137 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ VIEW ~ #peroid_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ INVERTED ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident ~ ON ~ #peroid_separated_idents_1_to_3
+            ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+            ~ ( TOKENIZER ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            columns,
+            _,
+            opt_tokenizer,
+        )| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+                tokenizer: opt_tokenizer.map(|(_, _, tokenizer)| tokenizer),
+            })
+        },
+    );
+    let create_virtual_column = map(
+        rule! {
+            CREATE ~ VIRTUAL ~ COLUMN ~ ( IF ~ NOT ~ EXISTS )?
+            ~ "(" ~ #comma_separated_list1(expr) ~ ")"
+            ~ FOR ~ #peroid_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_not_exists, _, virtual_columns, _, _, (catalog, database, table))| {
+            Statement::CreateVirtualColumn(CreateVirtualColumnStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                virtual_columns,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+    let drop_virtual_column = map(
+        rule! {
+            DROP ~ VIRTUAL ~ COLUMN ~ ( IF ~ EXISTS )?
+            ~ "(" ~ #comma_separated_list1(expr) ~ ")"
+            ~ FOR ~ #peroid_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, _, virtual_columns, _, _, (catalog, database, table))| {
+            Statement::DropVirtualColumn(DropVirtualColumnStmt {
+                if_exists: opt_if_exists.is_some(),
+                virtual_columns,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ EXISTS )?
+            ~ #ident ~ ON ~ #peroid_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+    let create_stream = map(
+        rule! {
+            CREATE ~ STREAM ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #peroid_separated_idents_1_to_3
+            ~ ON ~ TABLE ~ #peroid_separated_idents_1_to_2
+            ~ ( AT ~ ^#travel_point )?
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, stream),
+            _,
+            _,
+            (table_database, table),
+            opt_travel_point,
+        )| {
+            Statement::CreateStream(CreateStreamStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                table_database,
+                table,
+                travel_point: opt_travel_point.map(|(_, travel_point)| travel_point),
+            })
+        },
+    );
+    let drop_stream = map(
+        rule! {
+            DROP ~ STREAM ~ ( IF ~ EXISTS )? ~ #peroid_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream))| {
+            Statement::DropStream(DropStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+            })
+        },
+    );
     let show_users = value(Statement::ShowUsers, rule! { SHOW ~ USERS });
     let create_user = map(
         rule! {
@@ -1029,12 +1201,23 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #truncate_table : "`TRUNCATE TABLE [<database>.]<table> [PURGE]`"
             | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
             | #analyze_table : "`ANALYZE TABLE [<database>.]<table>`"
+            | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN <n> HOURS] [DRY RUN]`"
+            | #vacuum_drop_table : "`VACUUM DROP TABLE [FROM <database>] [RETAIN <n> HOURS] [DRY RUN]`"
             | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
         ),
         rule!(
-            #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            #create_view : "`CREATE [MATERIALIZED] VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
+        ),
+        rule!(
+            #create_inverted_index : "`CREATE INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<column>, ...) [TOKENIZER = '<tokenizer>']`"
+            | #drop_inverted_index : "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+            | #create_virtual_column : "`CREATE VIRTUAL COLUMN [IF NOT EXISTS] (<expr>, ...) FOR [<database>.]<table>`"
+            | #drop_virtual_column : "`DROP VIRTUAL COLUMN [IF EXISTS] (<expr>, ...) FOR [<database>.]<table>`"
+            | #create_stream : "`CREATE STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table> [AT (SNAPSHOT => <snapshot_id> | TIMESTAMP => <timestamp>)]`"
+            | #drop_stream : "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
         ),
         rule!(
             #show_users : "`SHOW USERS`"
@@ -1190,6 +1373,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     enum ColumnConstraint {
         Nullable(bool),
         DefaultExpr(Box<Expr>),
+        ComputedExpr(ComputedExpr),
     }
 
     let nullable = alt((
@@ -1202,6 +1386,15 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         },
         |(_, default_expr)| ColumnConstraint::DefaultExpr(Box::new(default_expr)),
     );
+    let computed_expr = map(
+        rule! {
+            AS ~ ^"(" ~ ^#expr ~ ^")" ~ ^( VIRTUAL | STORED )
+        },
+        |(_, _, expr, _, kind)| match kind.kind {
+            VIRTUAL => ColumnConstraint::ComputedExpr(ComputedExpr::Virtual(Box::new(expr))),
+            _ => ColumnConstraint::ComputedExpr(ComputedExpr::Stored(Box::new(expr))),
+        },
+    );
 
     let comment = map(
         rule! {
@@ -1214,15 +1407,16 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         rule! {
             #ident
             ~ #type_name
-            ~ ( #nullable | #default_expr )*
+            ~ ( #nullable | #default_expr | #computed_expr )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <default value>] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <default value> | AS (<expr>) {VIRTUAL | STORED}] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let mut def = ColumnDefinition {
                 name,
                 data_type,
                 default_expr: None,
+                computed_expr: None,
                 comment,
             };
             for constraint in constraints {
@@ -1230,6 +1424,9 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
                     ColumnConstraint::DefaultExpr(default_expr) => {
                         def.default_expr = Some(default_expr)
                     }
+                    ColumnConstraint::ComputedExpr(computed_expr) => {
+                        def.computed_expr = Some(computed_expr)
+                    }
                     ColumnConstraint::Nullable(nullable) => {
                         if nullable {
                             def.data_type = def.data_type.wrap_nullable();
@@ -1425,9 +1622,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
     );
 
+    let clone = map(
+        rule! {
+            CLONE ~ #peroid_separated_idents_1_to_3 ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, (catalog, database, table), travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: travel_point.map(|(_, point)| point),
+        },
+    );
+
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -1483,12 +1693,58 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, point)| AlterTableAction::RevertTo { point },
     );
 
+    let create_tag = map(
+        rule! {
+            CREATE ~ TAG ~ #ident ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, _, tag, opt_travel_point)| AlterTableAction::CreateTag {
+            tag,
+            travel_point: opt_travel_point.map(|(_, p)| p),
+        },
+    );
+
+    let drop_tag = map(
+        rule! {
+            DROP ~ TAG ~ #ident
+        },
+        |(_, _, tag)| AlterTableAction::DropTag { tag },
+    );
+
+    let create_branch = map(
+        rule! {
+            CREATE ~ BRANCH ~ #ident ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, _, branch, opt_travel_point)| AlterTableAction::CreateBranch {
+            branch,
+            travel_point: opt_travel_point.map(|(_, p)| p),
+        },
+    );
+
+    let drop_branch = map(
+        rule! {
+            DROP ~ BRANCH ~ #ident
+        },
+        |(_, _, branch)| AlterTableAction::DropBranch { branch },
+    );
+
+    let fast_forward = map(
+        rule! {
+            FAST ~ FORWARD ~ TO ~ BRANCH ~ #ident
+        },
+        |(_, _, _, _, branch)| AlterTableAction::FastForward { branch },
+    );
+
     rule!(
         #rename_table
         | #alter_table_cluster_key
         | #drop_table_cluster_key
         | #recluster_table
         | #revert_table
+        | #create_tag
+        | #drop_tag
+        | #create_branch
+        | #drop_branch
+        | #fast_forward
     )(i)
 }
 
@@ -1511,6 +1767,18 @@ pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
     ))(i)
 }
 
+pub fn vacuum_table_option(i: Input) -> IResult<VacuumTableOption> {
+    map(
+        rule! {
+            ( RETAIN ~ ^#literal_u64 ~ ^HOURS )? ~ ( DRY ~ ^RUN )?
+        },
+        |(opt_retain_hours, opt_dry_run)| VacuumTableOption {
+            retain_hours: opt_retain_hours.map(|(_, hours, _)| hours),
+            dry_run: opt_dry_run.is_some(),
+        },
+    )(i)
+}
+
 pub fn kill_target(i: Input) -> IResult<KillTarget> {
     alt((
         value(KillTarget::Query, rule! { QUERY }),
@@ -229,6 +229,9 @@ pub enum TokenKind {
     /// Placeholder used in prepared stmt
     #[token("?")]
     Placeholder,
+    /// Numbered placeholder `$n` used in prepared stmt
+    #[regex(r"\$[0-9]+")]
+    NumberedPlaceholder,
 
     // Keywords
     //
@@ -283,6 +286,8 @@ pub enum TokenKind {
     BOOLEAN,
     #[token("BOTH", ignore(ascii_case))]
     BOTH,
+    #[token("BRANCH", ignore(ascii_case))]
+    BRANCH,
     #[token("BY", ignore(ascii_case))]
     BY,
     #[token("BROTLI", ignore(ascii_case))]
@@ -301,8 +306,12 @@ pub enum TokenKind {
     CATALOGS,
     #[token("CENTURY", ignore(ascii_case))]
     CENTURY,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
+    #[token("COLUMN", ignore(ascii_case))]
+    COLUMN,
     #[token("COMMENT", ignore(ascii_case))]
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
@@ -379,6 +388,8 @@ pub enum TokenKind {
     DOWNLOAD,
     #[token("DROP", ignore(ascii_case))]
     DROP,
+    #[token("DRY", ignore(ascii_case))]
+    DRY,
     #[token("EXCEPT", ignore(ascii_case))]
     EXCEPT,
     #[token("EXCLUDE", ignore(ascii_case))]
@@ -409,6 +420,8 @@ pub enum TokenKind {
     EXTRACT,
     #[token("FALSE", ignore(ascii_case))]
     FALSE,
+    #[token("FAST", ignore(ascii_case))]
+    FAST,
     #[token("FIELDS", ignore(ascii_case))]
     FIELDS,
     #[token("FIELD_DELIMITER", ignore(ascii_case))]
@@ -435,6 +448,8 @@ pub enum TokenKind {
     FORCE,
     #[token("FORMAT", ignore(ascii_case))]
     FORMAT,
+    #[token("FORWARD", ignore(ascii_case))]
+    FORWARD,
     #[token("FRAGMENTS", ignore(ascii_case))]
     FRAGMENTS,
     #[token("FROM", ignore(ascii_case))]
@@ -463,6 +478,8 @@ pub enum TokenKind {
     HIVE,
     #[token("HOUR", ignore(ascii_case))]
     HOUR,
+    #[token("HOURS", ignore(ascii_case))]
+    HOURS,
     #[token("INTERSECT", ignore(ascii_case))]
     INTERSECT,
     #[token("IDENTIFIED", ignore(ascii_case))]
@@ -471,6 +488,8 @@ pub enum TokenKind {
     IF,
     #[token("IN", ignore(ascii_case))]
     IN,
+    #[token("INDEX", ignore(ascii_case))]
+    INDEX,
     #[token("INNER", ignore(ascii_case))]
     INNER,
     #[token("INSERT", ignore(ascii_case))]
@@ -491,6 +510,8 @@ pub enum TokenKind {
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
     INTO,
+    #[token("INVERTED", ignore(ascii_case))]
+    INVERTED,
     #[token("IS", ignore(ascii_case))]
     IS,
     #[token("ISODOW", ignore(ascii_case))]
@@ -527,6 +548,8 @@ pub enum TokenKind {
     LZO,
     #[token("MAP", ignore(ascii_case))]
     MAP,
+    #[token("MATERIALIZED", ignore(ascii_case))]
+    MATERIALIZED,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
     #[token("MASTER_KEY", ignore(ascii_case))]
@@ -627,10 +650,14 @@ pub enum TokenKind {
     PRESIGN,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
+    #[token("REFRESH", ignore(ascii_case))]
+    REFRESH,
     #[token("REGION", ignore(ascii_case))]
     REGION,
     #[token("REMOVE", ignore(ascii_case))]
     REMOVE,
+    #[token("RETAIN", ignore(ascii_case))]
+    RETAIN,
     #[token("REVOKE", ignore(ascii_case))]
     REVOKE,
     #[token("RECURSIVE", ignore(ascii_case))]
@@ -641,6 +668,8 @@ pub enum TokenKind {
     RIGHT,
     #[token("RLIKE", ignore(ascii_case))]
     RLIKE,
+    #[token("RUN", ignore(ascii_case))]
+    RUN,
     #[token("RAW", ignore(ascii_case))]
     RAW,
     #[token("SCHEMA", ignore(ascii_case))]
@@ -705,6 +734,10 @@ pub enum TokenKind {
     SUPER,
     #[token("STATUS", ignore(ascii_case))]
     STATUS,
+    #[token("STORED", ignore(ascii_case))]
+    STORED,
+    #[token("STREAM", ignore(ascii_case))]
+    STREAM,
     #[token("STRING", ignore(ascii_case))]
     STRING,
     #[token("SUBSTRING", ignore(ascii_case))]
@@ -717,6 +750,8 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TAG", ignore(ascii_case))]
+    TAG,
     #[token("TEXT", ignore(ascii_case))]
     TEXT,
     #[token("TENANTSETTING", ignore(ascii_case))]
@@ -739,6 +774,8 @@ pub enum TokenKind {
     TO,
     #[token("TOKEN", ignore(ascii_case))]
     TOKEN,
+    #[token("TOKENIZER", ignore(ascii_case))]
+    TOKENIZER,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
     #[token("TRANSIENT", ignore(ascii_case))]
@@ -781,6 +818,8 @@ pub enum TokenKind {
     USERS,
     #[token("USING", ignore(ascii_case))]
     USING,
+    #[token("VACUUM", ignore(ascii_case))]
+    VACUUM,
     #[token("VALUES", ignore(ascii_case))]
     VALUES,
     #[token("VALIDATION_MODE", ignore(ascii_case))]
@@ -791,6 +830,8 @@ pub enum TokenKind {
     VARIANT,
     #[token("VIEW", ignore(ascii_case))]
     VIEW,
+    #[token("VIRTUAL", ignore(ascii_case))]
+    VIRTUAL,
     #[token("WEEK", ignore(ascii_case))]
     WEEK,
     #[token("WHEN", ignore(ascii_case))]
@@ -881,6 +922,8 @@ impl TokenKind {
                 | AtSign
                 | PGSquareRoot
                 | PGCubeRoot
+                | Placeholder
+                | NumberedPlaceholder
                 | EOI
         )
     }
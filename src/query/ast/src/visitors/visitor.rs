@@ -191,6 +191,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_literal(&mut self, _span: Span, _lit: &'ast Literal) {}
 
+    fn visit_placeholder(&mut self, _span: Span, _index: usize) {}
+
     fn visit_count_all(&mut self, _span: Span) {}
 
     fn visit_tuple(&mut self, _span: Span, elements: &'ast [Expr]) {
@@ -257,6 +259,13 @@ pub trait Visitor<'ast>: Sized {
         }
     }
 
+    fn visit_map(&mut self, _span: Span, kvs: &'ast [(Expr, Expr)]) {
+        for (key_expr, val_expr) in kvs {
+            walk_expr(self, key_expr);
+            walk_expr(self, val_expr);
+        }
+    }
+
     fn visit_interval(&mut self, _span: Span, expr: &'ast Expr, _unit: &'ast IntervalKind) {
         walk_expr(self, expr);
     }
@@ -392,6 +401,10 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_analyze_table(&mut self, _stmt: &'ast AnalyzeTableStmt) {}
 
+    fn visit_vacuum_table(&mut self, _stmt: &'ast VacuumTableStmt) {}
+
+    fn visit_vacuum_drop_table(&mut self, _stmt: &'ast VacuumDropTableStmt) {}
+
     fn visit_exists_table(&mut self, _stmt: &'ast ExistsTableStmt) {}
 
     fn visit_create_view(&mut self, _stmt: &'ast CreateViewStmt) {}
@@ -400,6 +413,20 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_refresh_materialized_view(&mut self, _stmt: &'ast RefreshMaterializedViewStmt) {}
+
+    fn visit_create_inverted_index(&mut self, _stmt: &'ast CreateInvertedIndexStmt) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &'ast DropInvertedIndexStmt) {}
+
+    fn visit_create_virtual_column(&mut self, _stmt: &'ast CreateVirtualColumnStmt) {}
+
+    fn visit_drop_virtual_column(&mut self, _stmt: &'ast DropVirtualColumnStmt) {}
+
+    fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &'ast CreateUserStmt) {}
@@ -192,6 +192,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_literal(&mut self, _span: Span, _lit: &mut Literal) {}
 
+    fn visit_placeholder(&mut self, _span: Span, _index: &mut usize) {}
+
     fn visit_count_all(&mut self, _span: Span) {}
 
     fn visit_tuple(&mut self, _span: Span, elements: &mut [Expr]) {
@@ -261,6 +263,13 @@ pub trait VisitorMut: Sized {
         }
     }
 
+    fn visit_map(&mut self, _span: Span, kvs: &mut [(Expr, Expr)]) {
+        for (key_expr, val_expr) in kvs.iter_mut() {
+            walk_expr_mut(self, key_expr);
+            walk_expr_mut(self, val_expr);
+        }
+    }
+
     fn visit_interval(&mut self, _span: Span, expr: &mut Expr, _unit: &mut IntervalKind) {
         walk_expr_mut(self, expr);
     }
@@ -398,6 +407,10 @@ pub trait VisitorMut: Sized {
 
     fn visit_analyze_table(&mut self, _stmt: &mut AnalyzeTableStmt) {}
 
+    fn visit_vacuum_table(&mut self, _stmt: &mut VacuumTableStmt) {}
+
+    fn visit_vacuum_drop_table(&mut self, _stmt: &mut VacuumDropTableStmt) {}
+
     fn visit_exists_table(&mut self, _stmt: &mut ExistsTableStmt) {}
 
     fn visit_create_view(&mut self, _stmt: &mut CreateViewStmt) {}
@@ -406,6 +419,20 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_refresh_materialized_view(&mut self, _stmt: &mut RefreshMaterializedViewStmt) {}
+
+    fn visit_create_inverted_index(&mut self, _stmt: &mut CreateInvertedIndexStmt) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &mut DropInvertedIndexStmt) {}
+
+    fn visit_create_virtual_column(&mut self, _stmt: &mut CreateVirtualColumnStmt) {}
+
+    fn visit_drop_virtual_column(&mut self, _stmt: &mut DropVirtualColumnStmt) {}
+
+    fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &mut CreateUserStmt) {}
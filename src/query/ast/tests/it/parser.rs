@@ -103,6 +103,8 @@ fn test_statement() {
         r#"drop view v;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
+        r#"create inverted index idx on t(body, title) tokenizer = 'english';"#,
+        r#"drop inverted index if exists idx on db.t;"#,
         r#"rename table d.t to e.s;"#,
         r#"truncate table test;"#,
         r#"truncate table test_db.test;"#,
@@ -111,6 +113,7 @@ fn test_statement() {
         r#"CREATE TABLE t(c1 int null, c2 bigint null, c3 varchar null);"#,
         r#"CREATE TABLE t(c1 int not null, c2 bigint not null, c3 varchar not null);"#,
         r#"CREATE TABLE t(c1 int default 1);"#,
+        r#"CREATE TABLE t(m MAP(STRING, INT32) NULL, n MAP(STRING, STRING));"#,
         r#"ALTER USER u1 IDENTIFIED BY '123456';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = 'role1';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = 'role1', TENANTSETTING;"#,
@@ -558,6 +561,11 @@ fn test_expr() {
         r#"1 is distinct from 2"#,
         r#"a is distinct from b"#,
         r#"1 is not distinct from null"#,
+        r#"? + $2"#,
+        r#"{}"#,
+        r#"{'k1': 1, 'k2': 2}"#,
+        r#"{'a': {'b': NULL},}"#,
+        r#"CAST(col1 AS MAP(STRING, INT64))"#,
     ];
 
     for case in cases {
@@ -579,6 +587,7 @@ fn test_expr_error() {
         r#"G.E.B IS NOT NULL AND
             col1 NOT BETWEEN col2 AND
                 AND 1 + col3 DIV sum(col4)"#,
+        r#"{a: 1}"#,
     ];
 
     for case in cases {
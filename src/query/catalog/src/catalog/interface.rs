@@ -26,6 +26,8 @@ use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::DropTableReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::RemoveDroppedTableReply;
+use common_meta_app::schema::RemoveDroppedTableReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
@@ -112,6 +114,27 @@ pub trait Catalog: DynClone + Send + Sync {
     async fn list_tables_history(&self, tenant: &str, db_name: &str)
     -> Result<Vec<Arc<dyn Table>>>;
 
+    // Get the dropped tables of a db, including the ones out of the undrop window.
+    async fn list_dropped_tables(
+        &self,
+        _tenant: &str,
+        _db_name: &str,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        Err(ErrorCode::Unimplemented(
+            "'list_dropped_tables' not implemented",
+        ))
+    }
+
+    // Remove the meta of a dropped table, so that it can not be undropped any more.
+    async fn remove_dropped_table(
+        &self,
+        _req: RemoveDroppedTableReq,
+    ) -> Result<RemoveDroppedTableReply> {
+        Err(ErrorCode::Unimplemented(
+            "'remove_dropped_table' not implemented",
+        ))
+    }
+
     async fn create_table(&self, req: CreateTableReq) -> Result<()>;
 
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply>;
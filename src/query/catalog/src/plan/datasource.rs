@@ -69,7 +69,15 @@ pub enum DataSourceInfo {
 impl DataSourceInfo {
     pub fn schema(&self) -> Arc<TableSchema> {
         match self {
-            DataSourceInfo::TableSource(table_info) => table_info.schema(),
+            // The virtual computed columns are evaluated over the scan, not read from the source.
+            DataSourceInfo::TableSource(table_info) => {
+                let schema = table_info.schema();
+                if schema.has_virtual_computed_fields() {
+                    Arc::new(schema.remove_virtual_computed_fields())
+                } else {
+                    schema
+                }
+            }
             DataSourceInfo::StageSource(table_info) => table_info.schema(),
         }
     }
@@ -103,18 +111,39 @@ pub struct DataSourcePlan {
 
     pub tbl_args: Option<Vec<Scalar>>,
     pub push_downs: Option<PushDownInfo>,
+
+    /// The id of the scan in the query, used to find the runtime filters pushed down to it.
+    pub scan_id: usize,
 }
 
 impl DataSourcePlan {
-    /// Return schema after the projection
+    /// Return schema after the projection, followed by the virtual columns read
     pub fn schema(&self) -> Arc<TableSchema> {
-        self.scan_fields
+        let schema = self
+            .scan_fields
             .clone()
             .map(|x| {
                 let fields: Vec<_> = x.values().cloned().collect();
                 Arc::new(self.source_info.schema().project_by_fields(fields))
             })
-            .unwrap_or_else(|| self.source_info.schema())
+            .unwrap_or_else(|| self.source_info.schema());
+
+        match self
+            .push_downs
+            .as_ref()
+            .and_then(|push_downs| push_downs.virtual_columns.as_ref())
+        {
+            Some(virtual_columns) => {
+                let mut fields = schema.fields().clone();
+                fields.extend(
+                    virtual_columns
+                        .iter()
+                        .map(|c| TableField::new(&c.name, c.data_type.clone())),
+                );
+                Arc::new(TableSchema::new_from(fields, schema.meta().clone()))
+            }
+            None => schema,
+        }
     }
 
     pub fn projections(&self) -> Projection {
@@ -16,6 +16,7 @@ use std::fmt::Debug;
 
 use common_expression::types::DataType;
 use common_expression::RemoteExpr;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 
@@ -33,6 +34,20 @@ pub struct PrewhereInfo {
     pub filter: RemoteExpr<String>,
 }
 
+/// A virtual column of the table read by the scan, the value at a path of a variant column.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VirtualColumnInfo {
+    /// The name of the virtual column, `<source column>:<path>`.
+    pub name: String,
+    pub source_column_name: String,
+    /// The path of the value in the source column, like `user.id` or `items[0]`.
+    pub path: String,
+    /// The type that the value is cast to, `Variant` if it is not cast.
+    pub cast_type: TableDataType,
+    /// The type of the virtual column.
+    pub data_type: TableDataType,
+}
+
 /// Extras is a wrapper for push down items.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, PartialEq, Eq)]
 pub struct PushDownInfo {
@@ -48,6 +63,8 @@ pub struct PushDownInfo {
     pub limit: Option<usize>,
     /// Optional order_by expression plan, asc, null_first
     pub order_by: Vec<(RemoteExpr<String>, bool, bool)>,
+    /// Optional virtual columns to read, which follow the projected columns
+    pub virtual_columns: Option<Vec<VirtualColumnInfo>>,
 }
 
 /// TopK is a wrapper for topk push down items.
@@ -0,0 +1,78 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::Expr;
+use xorfilter::Xor8;
+
+/// The filters built from the build side keys of a hash join once the build is finished,
+/// they are applied to the probe side table scan to skip the blocks and rows which can't be joined.
+///
+/// The filters reference the columns of the scanned table by name.
+#[derive(Clone, Default)]
+pub struct RuntimeFilterInfo {
+    /// `key IN (v1, v2, ...)`, built if the number of distinct build keys is small.
+    inlist: Vec<Expr<String>>,
+    /// `key >= min AND key <= max` of the build keys.
+    min_max: Vec<Expr<String>>,
+    /// The probe key and the bloom filter of the `siphash` digests of the build keys,
+    /// built if the number of distinct build keys is large.
+    bloom: Vec<(Expr<String>, Arc<Xor8>)>,
+}
+
+impl RuntimeFilterInfo {
+    pub fn add_inlist(&mut self, expr: Expr<String>) {
+        self.inlist.push(expr);
+    }
+
+    pub fn add_min_max(&mut self, expr: Expr<String>) {
+        self.min_max.push(expr);
+    }
+
+    pub fn add_bloom(&mut self, probe_key: Expr<String>, filter: Arc<Xor8>) {
+        self.bloom.push((probe_key, filter));
+    }
+
+    pub fn get_inlist(&self) -> &[Expr<String>] {
+        &self.inlist
+    }
+
+    pub fn get_min_max(&self) -> &[Expr<String>] {
+        &self.min_max
+    }
+
+    pub fn get_bloom(&self) -> &[(Expr<String>, Arc<Xor8>)] {
+        &self.bloom
+    }
+
+    /// The filters which can be evaluated on the statistics of the blocks.
+    pub fn range_filters(&self) -> Vec<Expr<String>> {
+        self.inlist
+            .iter()
+            .chain(self.min_max.iter())
+            .cloned()
+            .collect()
+    }
+
+    pub fn merge(&mut self, other: RuntimeFilterInfo) {
+        self.inlist.extend(other.inlist);
+        self.min_max.extend(other.min_max);
+        self.bloom.extend(other.bloom);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inlist.is_empty() && self.min_max.is_empty() && self.bloom.is_empty()
+    }
+}
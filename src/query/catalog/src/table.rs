@@ -39,6 +39,7 @@ use crate::plan::DataSourcePlan;
 use crate::plan::PartStatistics;
 use crate::plan::Partitions;
 use crate::plan::PushDownInfo;
+use crate::plan::VirtualColumnInfo;
 use crate::table::column_stats_provider_impls::DummyColumnStatisticsProvider;
 use crate::table_context::TableContext;
 use crate::table_mutator::TableMutator;
@@ -108,6 +109,12 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// The virtual columns that the table engine can read directly, instead of
+    /// extracting the values from the whole variant column.
+    fn virtual_columns(&self) -> Vec<VirtualColumnInfo> {
+        vec![]
+    }
+
     async fn alter_table_cluster_keys(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -217,6 +224,19 @@ pub trait Table: Sync + Send {
         Ok(Box::new(DummyColumnStatisticsProvider))
     }
 
+    /// Gathers the statistics of the terms from the inverted index on the column,
+    /// returns None if the table has no inverted index on it.
+    async fn inverted_index_statistics(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        column: &str,
+        terms: &[Vec<u8>],
+    ) -> Result<Option<TermStatistics>> {
+        let (_, _, _) = (ctx, column, terms);
+
+        Ok(None)
+    }
+
     async fn navigate_to(&self, instant: &NavigationPoint) -> Result<Arc<dyn Table>> {
         let _ = instant;
 
@@ -344,6 +364,10 @@ impl<T: ?Sized> TableExt for T where T: Table {}
 pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
+    /// The snapshot pinned by a tag.
+    Tag(String),
+    /// The head snapshot of a branch.
+    Branch(String),
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -354,12 +378,31 @@ pub struct TableStatistics {
     pub index_size: Option<u64>,
 }
 
+/// The statistics of the terms of a full-text query, which give their BM25 scores.
+#[derive(Debug, Clone, Default)]
+pub struct TermStatistics {
+    pub num_docs: u64,
+    pub num_tokens: u64,
+    /// The number of rows containing each term.
+    pub doc_freqs: Vec<u64>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ColumnStatistics {
     pub min: Scalar,
     pub max: Scalar,
     pub null_count: u64,
     pub number_of_distinct_values: u64,
+    /// Equi-height histogram of the non-null values collected by `ANALYZE TABLE`,
+    /// the buckets are ordered by their upper bounds.
+    pub histogram: Option<Vec<HistogramBucket>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HistogramBucket {
+    pub upper_bound: Scalar,
+    pub num_values: f64,
+    pub num_distinct: f64,
 }
 
 pub enum CompactTarget {
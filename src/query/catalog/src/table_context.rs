@@ -26,6 +26,7 @@ use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::FunctionContext;
 use common_io::prelude::FormatSettings;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_meta_types::RoleInfo;
 use common_meta_types::UserInfo;
 use common_settings::Settings;
@@ -37,6 +38,7 @@ use crate::cluster_info::Cluster;
 use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
+use crate::runtime_filter_info::RuntimeFilterInfo;
 use crate::table::Table;
 
 #[derive(Debug)]
@@ -116,6 +118,16 @@ pub trait TableContext: Send + Sync {
     fn push_precommit_block(&self, block: DataBlock);
     fn consume_precommit_blocks(&self) -> Vec<DataBlock>;
 
+    /// Add the runtime filters of the table scan with the `scan_id`.
+    fn set_runtime_filter(&self, filters: (usize, RuntimeFilterInfo));
+    fn get_runtime_filter_with_id(&self, scan_id: usize) -> Option<Arc<RuntimeFilterInfo>>;
+
+    /// Record the new offset of a stream read by the query, which is committed along with
+    /// the table changed by the query, if any.
+    fn add_consumed_stream(&self, req: UpdateStreamMetaReq);
+    fn get_consumed_streams(&self) -> Vec<UpdateStreamMetaReq>;
+    fn clear_consumed_streams(&self);
+
     async fn get_table(&self, catalog: &str, database: &str, table: &str)
     -> Result<Arc<dyn Table>>;
 }
@@ -173,6 +173,14 @@ pub struct QueryConfig {
     pub async_insert_max_data_size: u64,
     pub async_insert_busy_timeout: u64,
     pub async_insert_stale_timeout: u64,
+    /// Compact and purge the fuse tables in background
+    pub background_maintenance_enabled: bool,
+    /// Interval of checking all the tables for maintenance (seconds)
+    pub background_maintenance_interval_secs: u64,
+    /// Max number of tables maintained at the same time
+    pub background_maintenance_concurrency: u64,
+    /// Max number of snapshots beyond the retention period kept by a table before it is purged
+    pub background_maintenance_max_snapshots: u64,
     pub idm: IDMConfig,
     pub share_endpoint_address: String,
     pub share_endpoint_auth_token_file: String,
@@ -227,6 +235,10 @@ impl Default for QueryConfig {
             async_insert_max_data_size: 10000,
             async_insert_busy_timeout: 200,
             async_insert_stale_timeout: 0,
+            background_maintenance_enabled: false,
+            background_maintenance_interval_secs: 60,
+            background_maintenance_concurrency: 2,
+            background_maintenance_max_snapshots: 32,
             idm: IDMConfig::default(),
             share_endpoint_address: "".to_string(),
             share_endpoint_auth_token_file: "".to_string(),
@@ -1320,6 +1320,23 @@ pub struct QueryConfig {
     #[clap(long, default_value = "0")]
     pub async_insert_stale_timeout: u64,
 
+    /// Compact, recluster and purge the fuse tables in background after they are written.
+    #[clap(long)]
+    pub background_maintenance_enabled: bool,
+
+    /// The interval in seconds of checking all the tables for maintenance.
+    #[clap(long, default_value = "60")]
+    pub background_maintenance_interval_secs: u64,
+
+    /// The maximum number of tables maintained at the same time.
+    #[clap(long, default_value = "2")]
+    pub background_maintenance_concurrency: u64,
+
+    /// The maximum number of snapshots beyond the retention period kept by a table before its
+    /// history is purged.
+    #[clap(long, default_value = "32")]
+    pub background_maintenance_max_snapshots: u64,
+
     #[clap(skip)]
     users: Vec<UserConfig>,
 
@@ -1391,6 +1408,10 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             async_insert_max_data_size: self.async_insert_max_data_size,
             async_insert_busy_timeout: self.async_insert_busy_timeout,
             async_insert_stale_timeout: self.async_insert_stale_timeout,
+            background_maintenance_enabled: self.background_maintenance_enabled,
+            background_maintenance_interval_secs: self.background_maintenance_interval_secs,
+            background_maintenance_concurrency: self.background_maintenance_concurrency,
+            background_maintenance_max_snapshots: self.background_maintenance_max_snapshots,
             idm: InnerIDMConfig {
                 users: users_to_inner(self.users)?,
             },
@@ -1456,6 +1477,10 @@ impl From<InnerQueryConfig> for QueryConfig {
             async_insert_max_data_size: inner.async_insert_max_data_size,
             async_insert_busy_timeout: inner.async_insert_busy_timeout,
             async_insert_stale_timeout: inner.async_insert_stale_timeout,
+            background_maintenance_enabled: inner.background_maintenance_enabled,
+            background_maintenance_interval_secs: inner.background_maintenance_interval_secs,
+            background_maintenance_concurrency: inner.background_maintenance_concurrency,
+            background_maintenance_max_snapshots: inner.background_maintenance_max_snapshots,
             users: users_from_inner(inner.idm.users),
             share_endpoint_address: inner.share_endpoint_address,
             share_endpoint_auth_token_file: inner.share_endpoint_auth_token_file,
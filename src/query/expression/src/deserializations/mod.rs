@@ -17,6 +17,7 @@ use common_io::prelude::*;
 mod array;
 mod boolean;
 mod date;
+mod map;
 mod null;
 mod nullable;
 mod number;
@@ -30,6 +31,7 @@ pub use boolean::*;
 use common_exception::Result;
 pub use date::*;
 use enum_dispatch::enum_dispatch;
+pub use map::*;
 pub use null::*;
 pub use nullable::*;
 pub use number::*;
@@ -80,6 +82,7 @@ pub enum TypeDeserializerImpl {
     Null(NullDeserializer),
     Nullable(NullableDeserializer),
     Array(ArrayDeserializer),
+    Map(MapDeserializer),
     Boolean(BooleanDeserializer),
     Int8(NumberDeserializer<i8, i8>),
     Int16(NumberDeserializer<i16, i16>),
@@ -313,6 +313,13 @@ impl<'a> Evaluator<'a> {
                 other => unreachable!("source: {}", other),
             },
 
+            (DataType::Map(_), DataType::Map(_)) => self.run_cast(
+                span,
+                &src_type.map_internal_type().unwrap(),
+                &dest_type.map_internal_type().unwrap(),
+                value,
+            ),
+
             (DataType::Tuple(fields_src_ty), DataType::Tuple(fields_dest_ty))
                 if fields_src_ty.len() == fields_dest_ty.len() =>
             {
@@ -455,6 +462,16 @@ impl<'a> Evaluator<'a> {
                 _ => unreachable!(),
             },
 
+            (DataType::Map(_), DataType::Map(_)) => {
+                let inner_dest_type = inner_dest_type.map_internal_type().unwrap();
+                self.run_try_cast(
+                    span,
+                    &src_type.map_internal_type().unwrap(),
+                    &DataType::Nullable(Box::new(inner_dest_type)),
+                    value,
+                )
+            }
+
             (DataType::Tuple(fields_src_ty), DataType::Tuple(fields_dest_ty))
                 if fields_src_ty.len() == fields_dest_ty.len() =>
             {
@@ -817,6 +834,12 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
                 };
                 Some(Domain::Array(inner_domain))
             }
+            (DataType::Map(_), DataType::Map(_)) => self.calculate_cast(
+                span,
+                &src_type.map_internal_type().unwrap(),
+                &dest_type.map_internal_type().unwrap(),
+                domain,
+            ),
 
             (DataType::Tuple(fields_src_ty), DataType::Tuple(fields_dest_ty))
                 if fields_src_ty.len() == fields_dest_ty.len() =>
@@ -905,6 +928,15 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
                     value: Some(Box::new(Domain::Array(inner_domain))),
                 }))
             }
+            (DataType::Map(_), DataType::Map(_)) => {
+                let inner_dest_type = inner_dest_type.map_internal_type().unwrap();
+                self.calculate_try_cast(
+                    span,
+                    &src_type.map_internal_type().unwrap(),
+                    &DataType::Nullable(Box::new(inner_dest_type)),
+                    domain,
+                )
+            }
 
             (DataType::Tuple(fields_src_ty), DataType::Tuple(fields_dest_ty))
                 if fields_src_ty.len() == fields_dest_ty.len() =>
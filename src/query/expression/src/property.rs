@@ -152,7 +152,11 @@ impl Domain {
             }
             DataType::EmptyArray => Domain::Array(None),
             DataType::Array(ty) => Domain::Array(Some(Box::new(Domain::full(ty)))),
-            DataType::Map(_) | DataType::Variant => Domain::Undefined,
+            DataType::Map(ty) => Domain::Array(Some(Box::new(Domain::Tuple(vec![
+                Domain::full(&DataType::String),
+                Domain::full(ty),
+            ])))),
+            DataType::Variant => Domain::Undefined,
             DataType::Generic(_) => unreachable!(),
         }
     }
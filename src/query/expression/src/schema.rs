@@ -83,6 +83,24 @@ pub struct TableField {
     name: String,
     default_expr: Option<String>,
     data_type: TableDataType,
+    #[serde(default)]
+    computed_expr: Option<ComputedExpr>,
+}
+
+/// The SQL expression of a generated column, either computed when the rows are read, or
+/// computed when they are written and stored like the other columns.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum ComputedExpr {
+    Virtual(String),
+    Stored(String),
+}
+
+impl ComputedExpr {
+    pub fn expr(&self) -> &String {
+        match self {
+            ComputedExpr::Virtual(expr) | ComputedExpr::Stored(expr) => expr,
+        }
+    }
 }
 
 /// DataType with more information that is only available for table field, e.g, the
@@ -337,6 +355,36 @@ impl TableSchema {
         true
     }
 
+    /// The schema of the columns stored in the table, i.e. without the virtual computed columns.
+    #[must_use]
+    pub fn remove_virtual_computed_fields(&self) -> Self {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|f| !matches!(f.computed_expr(), Some(ComputedExpr::Virtual(_))))
+            .cloned()
+            .collect();
+        Self::new_from(fields, self.meta().clone())
+    }
+
+    /// The schema of the columns provided by the loaded data, i.e. without the computed columns.
+    #[must_use]
+    pub fn remove_computed_fields(&self) -> Self {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|f| f.computed_expr().is_none())
+            .cloned()
+            .collect();
+        Self::new_from(fields, self.meta().clone())
+    }
+
+    pub fn has_virtual_computed_fields(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|f| matches!(f.computed_expr(), Some(ComputedExpr::Virtual(_))))
+    }
+
     /// project will do column pruning.
     #[must_use]
     pub fn project(&self, projection: &[usize]) -> Self {
@@ -439,6 +487,11 @@ impl TableSchema {
                         break;
                     }
                 }
+                ty @ TableDataType::Map(_) => {
+                    // the leaf fields of map are the same as `Array(Tuple(key, value))`.
+                    let inner_type = ty.map_internal_type().unwrap();
+                    collect_in_field(&TableField::new(field.name(), inner_type), fields);
+                }
                 _ => fields.push(field.clone()),
             }
         }
@@ -524,6 +577,7 @@ impl TableField {
             name: name.to_string(),
             default_expr: None,
             data_type,
+            computed_expr: None,
         }
     }
 
@@ -533,6 +587,12 @@ impl TableField {
         self
     }
 
+    #[must_use]
+    pub fn with_computed_expr(mut self, computed_expr: Option<ComputedExpr>) -> Self {
+        self.computed_expr = computed_expr;
+        self
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
@@ -545,6 +605,10 @@ impl TableField {
         self.default_expr.as_ref()
     }
 
+    pub fn computed_expr(&self) -> Option<&ComputedExpr> {
+        self.computed_expr.as_ref()
+    }
+
     #[inline]
     pub fn is_nullable(&self) -> bool {
         self.data_type.is_nullable()
@@ -597,6 +661,17 @@ impl TableDataType {
         !self.is_nullable_or_null()
     }
 
+    /// Returns the physical type of `Map(T)`, which is `Array(Tuple(key String, value T))`.
+    pub fn map_internal_type(&self) -> Option<Self> {
+        match self {
+            TableDataType::Map(ty) => Some(TableDataType::Array(Box::new(TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![TableDataType::String, (**ty).clone()],
+            }))),
+            _ => None,
+        }
+    }
+
     pub fn remove_nullable(&self) -> Self {
         match self {
             TableDataType::Nullable(ty) => (**ty).clone(),
@@ -853,6 +928,7 @@ impl From<&ArrowField> for TableField {
             name: f.name.clone(),
             data_type: f.into(),
             default_expr: None,
+            computed_expr: None,
         }
     }
 }
@@ -955,14 +1031,7 @@ impl From<&DataType> for ArrowDataType {
                     ty.is_nullable(),
                 )))
             }
-            DataType::Map(ty) => {
-                let arrow_ty = ty.as_ref().into();
-                ArrowDataType::LargeList(Box::new(ArrowField::new(
-                    "_map",
-                    arrow_ty,
-                    ty.is_nullable(),
-                )))
-            }
+            DataType::Map(_) => (&ty.map_internal_type().unwrap()).into(),
             DataType::Tuple(types) => {
                 let fields = types
                     .iter()
@@ -1010,14 +1079,7 @@ impl From<&TableDataType> for ArrowDataType {
                     ty.is_nullable(),
                 )))
             }
-            TableDataType::Map(ty) => {
-                let arrow_ty = ty.as_ref().into();
-                ArrowDataType::LargeList(Box::new(ArrowField::new(
-                    "_map",
-                    arrow_ty,
-                    ty.is_nullable(),
-                )))
-            }
+            TableDataType::Map(_) => (&ty.map_internal_type().unwrap()).into(),
             TableDataType::Tuple {
                 fields_name,
                 fields_type,
@@ -303,6 +303,7 @@ impl Subsitution {
             }),
             DataType::Nullable(box ty) => Ok(DataType::Nullable(Box::new(self.apply(ty)?))),
             DataType::Array(box ty) => Ok(DataType::Array(Box::new(self.apply(ty)?))),
+            DataType::Map(box ty) => Ok(DataType::Map(Box::new(self.apply(ty)?))),
             DataType::Tuple(fields_ty) => {
                 let fields_ty = fields_ty
                     .into_iter()
@@ -383,6 +384,7 @@ pub fn unify(
         (DataType::Array(src_ty), DataType::Array(dest_ty)) => {
             unify(src_ty, dest_ty, additional_rules)
         }
+        (DataType::Map(src_ty), DataType::Map(dest_ty)) => unify(src_ty, dest_ty, additional_rules),
         (DataType::Tuple(src_tys), DataType::Tuple(dest_tys))
             if src_tys.len() == dest_tys.len() =>
         {
@@ -422,6 +424,7 @@ pub fn can_auto_cast_to(src_ty: &DataType, dest_ty: &DataType) -> bool {
         }
         (src_ty, DataType::Nullable(dest_ty)) => can_auto_cast_to(src_ty, dest_ty),
         (DataType::Array(src_ty), DataType::Array(dest_ty)) => can_auto_cast_to(src_ty, dest_ty),
+        (DataType::Map(src_ty), DataType::Map(dest_ty)) => can_auto_cast_to(src_ty, dest_ty),
         (DataType::Number(src_num_ty), DataType::Number(dest_num_ty)) => {
             // all integer types can cast to int64
             (*dest_num_ty == NumberDataType::Int64 && !src_num_ty.is_float())
@@ -459,6 +462,9 @@ pub fn common_super_type(ty1: DataType, ty2: DataType) -> Option<DataType> {
         (DataType::Array(box ty1), DataType::Array(box ty2)) => {
             Some(DataType::Array(Box::new(common_super_type(ty1, ty2)?)))
         }
+        (DataType::Map(box ty1), DataType::Map(box ty2)) => {
+            Some(DataType::Map(Box::new(common_super_type(ty1, ty2)?)))
+        }
         (DataType::Number(num1), DataType::Number(num2)) => {
             Some(DataType::Number(num1.lossful_super_type(num2)))
         }
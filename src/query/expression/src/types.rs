@@ -53,6 +53,7 @@ pub use self::timestamp::TimestampType;
 pub use self::variant::VariantType;
 use crate::deserializations::ArrayDeserializer;
 use crate::deserializations::DateDeserializer;
+use crate::deserializations::MapDeserializer;
 use crate::deserializations::NullableDeserializer;
 use crate::deserializations::NumberDeserializer;
 use crate::deserializations::TimestampDeserializer;
@@ -135,6 +136,17 @@ impl DataType {
         }
     }
 
+    /// Returns the physical type of `Map(T)`, which is `Array(Tuple(String, T))`.
+    pub fn map_internal_type(&self) -> Option<Self> {
+        match self {
+            DataType::Map(ty) => Some(DataType::Array(Box::new(DataType::Tuple(vec![
+                DataType::String,
+                (**ty).clone(),
+            ])))),
+            _ => None,
+        }
+    }
+
     pub fn is_unsigned_numeric(&self) -> bool {
         match self {
             DataType::Number(ty) => ALL_UNSIGNED_INTEGER_TYPES.contains(ty),
@@ -236,6 +248,7 @@ impl DataType {
             }
             DataType::Variant => VariantDeserializer::with_capacity(capacity).into(),
             DataType::Array(ty) => ArrayDeserializer::with_capacity(capacity, ty).into(),
+            DataType::Map(ty) => MapDeserializer::with_capacity(capacity, ty).into(),
             DataType::Tuple(types) => TupleDeserializer::with_capacity(capacity, types).into(),
 
             _ => unimplemented!(),
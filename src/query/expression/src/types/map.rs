@@ -77,6 +77,7 @@ impl<K: ValueType, V: ValueType> ValueType for KvPair<K, V> {
     fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
         match domain {
             Domain::Undefined => Some(()),
+            Domain::Tuple(fields) if fields.len() == 2 => Some(()),
             _ => None,
         }
     }
@@ -890,6 +890,8 @@ impl Column {
 
         let is_nullable = data_type.is_nullable();
         let data_type = data_type.remove_nullable();
+        // `Map(T)` is stored as `Array(Tuple(String, T))` in arrow.
+        let data_type = data_type.map_internal_type().unwrap_or(data_type);
         let column = match arrow_col.data_type() {
             ArrowDataType::Null => Column::Null {
                 len: arrow_col.len(),
@@ -1389,13 +1391,9 @@ impl ColumnBuilder {
                     offsets,
                 }))
             }
-            DataType::Map(ty) => Self::with_capacity(
-                &DataType::Array(Box::new(DataType::Tuple(vec![
-                    DataType::String,
-                    (**ty).clone(),
-                ]))),
-                capacity,
-            ),
+            DataType::Map(_) => {
+                Self::with_capacity(&data_type.map_internal_type().unwrap(), capacity)
+            }
             DataType::Tuple(fields) => ColumnBuilder::Tuple {
                 fields: fields
                     .iter()
@@ -18,6 +18,7 @@ use std::io::Cursor;
 
 use common_exception::Result;
 use common_expression::ArrayDeserializer;
+use common_expression::MapDeserializer;
 use common_expression::StringDeserializer;
 use common_expression::StructDeserializer;
 use common_expression::VariantDeserializer;
@@ -120,6 +121,16 @@ impl FieldDecoderRowBased for FieldDecoderCSV {
         Ok(())
     }
 
+    fn read_map<R: AsRef<[u8]>>(
+        &self,
+        column: &mut MapDeserializer,
+        reader: &mut Cursor<R>,
+        _raw: bool,
+    ) -> Result<()> {
+        self.nested.read_map(column, reader, false)?;
+        Ok(())
+    }
+
     fn read_struct<R: AsRef<[u8]>>(
         &self,
         column: &mut StructDeserializer,
@@ -25,6 +25,7 @@ use common_expression::uniform_date;
 use common_expression::ArrayDeserializer;
 use common_expression::BooleanDeserializer;
 use common_expression::DateDeserializer;
+use common_expression::MapDeserializer;
 use common_expression::NullDeserializer;
 use common_expression::NullableDeserializer;
 use common_expression::NumberDeserializer;
@@ -84,6 +85,7 @@ impl FieldJsonAstDecoder {
             TypeDeserializerImpl::Timestamp(c) => self.read_timestamp(c, value),
             TypeDeserializerImpl::String(c) => self.read_string(c, value),
             TypeDeserializerImpl::Array(c) => self.read_array(c, value),
+            TypeDeserializerImpl::Map(c) => self.read_map(c, value),
             TypeDeserializerImpl::Struct(c) => self.read_struct(c, value),
             TypeDeserializerImpl::Variant(c) => self.read_variant(c, value),
         }
@@ -216,6 +218,20 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_map(&self, column: &mut MapDeserializer, value: &Value) -> Result<()> {
+        match value {
+            Value::Object(obj) => {
+                for (key, val) in obj.iter() {
+                    column.push_key(key.as_bytes())?;
+                    self.read_field(column.value.as_mut(), val)?;
+                }
+                column.add_offset(obj.len());
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be object")),
+        }
+    }
+
     fn read_struct(&self, column: &mut StructDeserializer, value: &Value) -> Result<()> {
         match value {
             Value::Object(obj) => {
@@ -0,0 +1,242 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::variant::cast_scalar_to_variant;
+use common_expression::types::DataType;
+use common_expression::types::ValueType;
+use common_expression::types::VariantType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_io::prelude::*;
+use common_jsonb::build_array;
+use common_jsonb::build_object;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_binary_arguments;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+
+#[derive(Serialize, Deserialize, Default)]
+struct AggregateJsonAggState {
+    // The values of `json_agg`, in the order they were added.
+    pub values: Vec<Vec<u8>>,
+    // The key-value pairs of `object_agg`, the keys of `JSONB` object must be sorted.
+    pub entries: BTreeMap<String, Vec<u8>>,
+}
+
+impl AggregateJsonAggState {
+    fn add_value(&mut self, value: Vec<u8>) {
+        self.values.push(value);
+    }
+
+    fn add_entry(&mut self, key: String, value: Vec<u8>) -> Result<()> {
+        if self.entries.contains_key(&key) {
+            return Err(ErrorCode::BadArguments(format!(
+                "object_agg keys have to be unique, but got duplicate key {:?}",
+                key
+            )));
+        }
+        self.entries.insert(key, value);
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &mut Self) -> Result<()> {
+        self.values.append(&mut other.values);
+        for (key, value) in std::mem::take(&mut other.entries) {
+            self.add_entry(key, value)?;
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, writer: &mut Vec<u8>) -> Result<()> {
+        serialize_into_buf(writer, self)
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        *self = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+}
+
+/// `json_agg(value)` collects the values into a variant array and
+/// `object_agg(key, value)` collects the key-value pairs into a variant object.
+/// The rows with NULL arguments are ignored.
+#[derive(Clone)]
+pub struct AggregateJsonAggFunction {
+    display_name: String,
+    is_object: bool,
+}
+
+impl AggregateJsonAggFunction {
+    pub fn try_create(display_name: &str, is_object: bool) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_owned(),
+            is_object,
+        }))
+    }
+
+    fn add_row(
+        &self,
+        state: &mut AggregateJsonAggState,
+        columns: &[Column],
+        row: usize,
+    ) -> Result<()> {
+        // Aggregate functions have no session context, values are converted in UTC.
+        let mut value = Vec::new();
+        if self.is_object {
+            let key = match columns[0].index(row) {
+                Some(ScalarRef::String(key)) => String::from_utf8_lossy(key).to_string(),
+                _ => unreachable!(),
+            };
+            cast_scalar_to_variant(columns[1].index(row).unwrap(), Tz::UTC, &mut value);
+            state.add_entry(key, value)
+        } else {
+            cast_scalar_to_variant(columns[0].index(row).unwrap(), Tz::UTC, &mut value);
+            state.add_value(value);
+            Ok(())
+        }
+    }
+}
+
+impl AggregateFunction for AggregateJsonAggFunction {
+    fn name(&self) -> &str {
+        "AggregateJsonAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Variant)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateJsonAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateJsonAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateJsonAggState>();
+        for row in 0..input_rows {
+            if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+                self.add_row(state, columns, row)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<AggregateJsonAggState>();
+        self.add_row(state, columns, row)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateJsonAggState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateJsonAggState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateJsonAggState>();
+        let state = place.get::<AggregateJsonAggState>();
+        state.merge(rhs)
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateJsonAggState>();
+        let builder = VariantType::try_downcast_builder(builder).unwrap();
+        let res = if self.is_object {
+            let items = state.entries.iter().map(|(k, v)| (k, v.as_slice()));
+            build_object(items, &mut builder.data)
+        } else {
+            let items = state.values.iter().map(|v| v.as_slice());
+            build_array(items, &mut builder.data)
+        };
+        res.map_err(|e| ErrorCode::Internal(e.to_string()))?;
+        builder.commit_row();
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateJsonAggState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateJsonAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_json_agg_function(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    AggregateJsonAggFunction::try_create(display_name, false)
+}
+
+pub fn try_create_aggregate_object_agg_function(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_binary_arguments(display_name, arguments.len())?;
+    if arguments[0] != DataType::String {
+        return Err(ErrorCode::BadArguments(format!(
+            "The key of {} must be a String, but got {}",
+            display_name, arguments[0]
+        )));
+    }
+    AggregateJsonAggFunction::try_create(display_name, true)
+}
+
+pub fn aggregate_json_agg_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_json_agg_function))
+}
+
+pub fn aggregate_object_agg_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_object_agg_function))
+}
@@ -20,6 +20,8 @@ use super::aggregate_combinator_distinct::aggregate_combinator_distinct_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_uniq_desc;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
 use super::aggregate_covariance::aggregate_covariance_sample_desc;
+use super::aggregate_json_agg::aggregate_json_agg_function_desc;
+use super::aggregate_json_agg::aggregate_object_agg_function_desc;
 use super::aggregate_min_max_any::aggregate_any_function_desc;
 use super::aggregate_min_max_any::aggregate_max_function_desc;
 use super::aggregate_min_max_any::aggregate_min_function_desc;
@@ -59,6 +61,9 @@ impl Aggregators {
         );
 
         factory.register("retention", aggregate_retention_function_desc());
+
+        factory.register("json_agg", aggregate_json_agg_function_desc());
+        factory.register("object_agg", aggregate_object_agg_function_desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
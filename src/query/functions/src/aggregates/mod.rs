@@ -26,6 +26,7 @@ mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
 mod aggregate_covariance;
 mod aggregate_distinct_state;
+mod aggregate_json_agg;
 mod aggregate_min_max_any;
 mod aggregate_null_result;
 mod aggregate_retention;
@@ -49,6 +50,7 @@ pub use aggregate_function_factory::AggregateFunctionFactory;
 pub use aggregate_function_state::get_layout_offsets;
 pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
+pub use aggregate_json_agg::AggregateJsonAggFunction;
 pub use aggregate_min_max_any::AggregateMinMaxAnyFunction;
 pub use aggregate_null_result::AggregateNullResultFunction;
 pub use aggregate_retention::AggregateRetentionFunction;
@@ -19,10 +19,14 @@
 
 use aggregates::AggregateFunctionFactory;
 use scalars::BUILTIN_FUNCTIONS;
+use srfs::BUILTIN_SRFS;
 
 pub mod aggregates;
 pub mod scalars;
+pub mod srfs;
 
 pub fn is_builtin_function(name: &str) -> bool {
-    BUILTIN_FUNCTIONS.contains(name) || AggregateFunctionFactory::instance().contains(name)
+    BUILTIN_FUNCTIONS.contains(name)
+        || AggregateFunctionFactory::instance().contains(name)
+        || BUILTIN_SRFS.contains(name)
 }
@@ -0,0 +1,191 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::Float64Type;
+use common_expression::types::number::F64;
+use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_3_arg;
+use common_expression::vectorize_with_builder_4_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionProperty;
+use common_expression::FunctionRegistry;
+
+use crate::scalars::tokenize_text;
+
+/// Term frequency saturation of BM25.
+const BM25_K1: f64 = 1.2;
+/// Document length normalization of BM25.
+const BM25_B: f64 = 0.75;
+
+/// Stop words dropped by the english tokenizer, the same set as Lucene's `EnglishAnalyzer`.
+const ENGLISH_STOP_WORDS: &[&[u8]] = &[
+    b"a", b"an", b"and", b"are", b"as", b"at", b"be", b"but", b"by", b"for", b"if", b"in", b"into",
+    b"is", b"it", b"no", b"not", b"of", b"on", b"or", b"such", b"that", b"the", b"their", b"then",
+    b"there", b"these", b"they", b"this", b"to", b"was", b"will", b"with",
+];
+
+/// Splits text into the terms indexed by inverted indexes and searched by `match`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextTokenizer {
+    /// Tokens of [`tokenize_text`], kept as they are.
+    Default,
+    /// Tokens of [`tokenize_text`] in ASCII lowercase, without english stop words.
+    English,
+}
+
+impl TryFrom<&str> for TextTokenizer {
+    type Error = ErrorCode;
+
+    fn try_from(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "default" => Ok(TextTokenizer::Default),
+            "english" => Ok(TextTokenizer::English),
+            other => Err(ErrorCode::BadArguments(format!(
+                "unsupported tokenizer: '{}', expected 'default' or 'english'",
+                other
+            ))),
+        }
+    }
+}
+
+impl TextTokenizer {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TextTokenizer::Default => "default",
+            TextTokenizer::English => "english",
+        }
+    }
+
+    pub fn tokenize<'a>(&self, text: &'a [u8]) -> impl Iterator<Item = Cow<'a, [u8]>> {
+        let tokenizer = *self;
+        tokenize_text(text).filter_map(move |token| match tokenizer {
+            TextTokenizer::Default => Some(Cow::Borrowed(token)),
+            TextTokenizer::English => {
+                let token = token.to_ascii_lowercase();
+                if ENGLISH_STOP_WORDS
+                    .iter()
+                    .any(|word| *word == token.as_slice())
+                {
+                    None
+                } else {
+                    Some(Cow::Owned(token))
+                }
+            }
+        })
+    }
+
+    /// The distinct terms of a query, in the order they first appear.
+    pub fn query_terms(&self, query: &[u8]) -> Vec<Vec<u8>> {
+        let mut terms: Vec<Vec<u8>> = vec![];
+        for term in self.tokenize(query) {
+            if !terms.iter().any(|t| t.as_slice() == term.as_ref()) {
+                terms.push(term.into_owned());
+            }
+        }
+        terms
+    }
+}
+
+/// The BM25 inverse document frequency of a term found in `doc_freq` of `num_docs` documents.
+pub fn bm25_idf(num_docs: u64, doc_freq: u64) -> f64 {
+    let (n, df) = (num_docs as f64, doc_freq as f64);
+    (1.0 + (n - df + 0.5) / (df + 0.5)).ln()
+}
+
+/// Scores a document against the query terms with BM25. `stats` holds the average document
+/// length followed by the inverse document frequency of each term of `query_terms`.
+fn bm25_score(
+    tokenizer: TextTokenizer,
+    text: &[u8],
+    query_terms: &[Vec<u8>],
+    stats: &[f64],
+) -> f64 {
+    let mut doc_len = 0;
+    let mut term_freqs = HashMap::new();
+    for token in tokenizer.tokenize(text) {
+        doc_len += 1;
+        *term_freqs.entry(token).or_insert(0) += 1;
+    }
+
+    let avg_doc_len = stats.first().cloned().unwrap_or_default();
+    let norm = if avg_doc_len > 0.0 {
+        1.0 - BM25_B + BM25_B * doc_len as f64 / avg_doc_len
+    } else {
+        1.0
+    };
+    query_terms
+        .iter()
+        .enumerate()
+        .map(|(i, term)| {
+            let tf = term_freqs.get(term.as_slice()).cloned().unwrap_or(0) as f64;
+            let idf = stats.get(i + 1).cloned().unwrap_or_default();
+            idf * tf * (BM25_K1 + 1.0) / (tf + BM25_K1 * norm)
+        })
+        .sum()
+}
+
+fn parse_tokenizer(name: &[u8]) -> std::result::Result<TextTokenizer, String> {
+    TextTokenizer::try_from(String::from_utf8_lossy(name).as_ref()).map_err(|e| e.message())
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, BooleanType, _, _>(
+        "match",
+        FunctionProperty::default(),
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, BooleanType>(
+            |text, query, tokenizer, output, ctx| match parse_tokenizer(tokenizer) {
+                Ok(tokenizer) => {
+                    let tokens = tokenizer.tokenize(text).collect::<HashSet<_>>();
+                    output.push(
+                        tokenizer
+                            .tokenize(query)
+                            .all(|term| tokens.contains(&term)),
+                    );
+                }
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_4_arg::<StringType, StringType, StringType, ArrayType<Float64Type>, Float64Type, _, _>(
+        "match_score",
+        FunctionProperty::default(),
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_4_arg::<StringType, StringType, StringType, ArrayType<Float64Type>, Float64Type>(
+            |text, query, tokenizer, stats, output, ctx| match parse_tokenizer(tokenizer) {
+                Ok(tokenizer) => {
+                    let query_terms = tokenizer.query_terms(query);
+                    let stats = stats.iter().map(|v| v.0).collect::<Vec<_>>();
+                    output.push(F64::from(bm25_score(tokenizer, text, &query_terms, &stats)));
+                }
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(F64::default());
+                }
+            },
+        ),
+    );
+}
@@ -0,0 +1,259 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::types::array::ArrayColumnBuilder;
+use common_expression::types::map::KvPair;
+use common_expression::types::string::StringColumn;
+use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::types::GenericType;
+use common_expression::types::MapType;
+use common_expression::types::NullableType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::vectorize_1_arg;
+use common_expression::vectorize_2_arg;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::Column;
+use common_expression::Function;
+use common_expression::FunctionDomain;
+use common_expression::FunctionProperty;
+use common_expression::FunctionRegistry;
+use common_expression::FunctionSignature;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_function_factory("map", |_, args_type| {
+        if args_type.len() % 2 != 0 {
+            return None;
+        }
+
+        if args_type.is_empty() {
+            return Some(Arc::new(Function {
+                signature: FunctionSignature {
+                    name: "map".to_string(),
+                    args_type: vec![],
+                    return_type: DataType::Map(Box::new(DataType::Null)),
+                    property: FunctionProperty::default(),
+                },
+                calc_domain: Box::new(|_| FunctionDomain::Full),
+                eval: Box::new(|_, _| {
+                    Value::Scalar(Scalar::Array(Column::Tuple {
+                        fields: vec![
+                            Column::String(StringColumn {
+                                data: vec![].into(),
+                                offsets: vec![0].into(),
+                            }),
+                            Column::Null { len: 0 },
+                        ],
+                        len: 0,
+                    }))
+                }),
+            }));
+        }
+
+        let args_type = (0..args_type.len())
+            .map(|idx| {
+                if idx % 2 == 0 {
+                    DataType::String
+                } else {
+                    DataType::Generic(0)
+                }
+            })
+            .collect();
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "map".to_string(),
+                args_type,
+                return_type: DataType::Map(Box::new(DataType::Generic(0))),
+                property: FunctionProperty::default(),
+            },
+            calc_domain: Box::new(|_| FunctionDomain::MayThrow),
+            eval: Box::new(|args, ctx| {
+                let len = args.iter().find_map(|arg| match arg {
+                    ValueRef::Column(col) => Some(col.len()),
+                    _ => None,
+                });
+
+                let mut builder: ArrayColumnBuilder<KvPair<StringType, GenericType<0>>> =
+                    ArrayColumnBuilder::with_capacity(len.unwrap_or(1), 0, ctx.generics);
+
+                for idx in 0..(len.unwrap_or(1)) {
+                    let mut keys: Vec<&[u8]> = Vec::with_capacity(args.len() / 2);
+                    for kv in args.chunks(2) {
+                        let key = match &kv[0] {
+                            ValueRef::Scalar(scalar) => scalar.clone(),
+                            ValueRef::Column(col) => unsafe { col.index_unchecked(idx) },
+                        };
+                        let key = match key {
+                            ScalarRef::String(key) => key,
+                            _ => unreachable!(),
+                        };
+                        if keys.contains(&key) {
+                            ctx.set_error(
+                                idx,
+                                format!(
+                                    "map keys have to be unique, but got duplicate key {:?}",
+                                    String::from_utf8_lossy(key)
+                                ),
+                            );
+                            continue;
+                        }
+                        keys.push(key);
+                        let val = match &kv[1] {
+                            ValueRef::Scalar(scalar) => scalar.clone(),
+                            ValueRef::Column(col) => unsafe { col.index_unchecked(idx) },
+                        };
+                        builder.put_item((key, val));
+                    }
+                    builder.commit_row();
+                }
+
+                match len {
+                    Some(_) => Value::Column(Column::Array(Box::new(builder.build().upcast()))),
+                    None => Value::Scalar(Scalar::Array(
+                        KvPair::<StringType, GenericType<0>>::upcast_column(builder.build_scalar()),
+                    )),
+                }
+            }),
+        }))
+    });
+
+    registry.register_combine_nullable_2_arg::<MapType<NullableType<GenericType<0>>>, StringType, GenericType<0>, _, _>(
+        "get",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<MapType<NullableType<GenericType<0>>>, StringType, NullableType<GenericType<0>>>(
+            |map, key, output, _| {
+                match map.keys.iter().position(|k| k == key) {
+                    Some(idx) => match NullableType::<GenericType<0>>::index_column(&map.values, idx) {
+                        Some(Some(val)) => output.push(val),
+                        _ => output.push_null(),
+                    },
+                    None => output.push_null(),
+                }
+            }
+        ),
+    );
+
+    registry.register_combine_nullable_2_arg::<MapType<GenericType<0>>, StringType, GenericType<0>, _, _>(
+        "get",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<MapType<GenericType<0>>, StringType, NullableType<GenericType<0>>>(
+            |map, key, output, _| {
+                match map.keys.iter().position(|k| k == key) {
+                    Some(idx) => match GenericType::<0>::index_column(&map.values, idx) {
+                        Some(val) => output.push(val),
+                        None => output.push_null(),
+                    },
+                    None => output.push_null(),
+                }
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>>, ArrayType<StringType>, _, _>(
+        "map_keys",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<MapType<GenericType<0>>, ArrayType<StringType>>(
+            |map, output, _| output.push(map.keys)
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>>, ArrayType<GenericType<0>>, _, _>(
+        "map_values",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<MapType<GenericType<0>>, ArrayType<GenericType<0>>>(
+            |map, output, _| output.push(map.values)
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>>, NumberType<u64>, _, _>(
+        "map_size",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_1_arg::<MapType<GenericType<0>>, NumberType<u64>>(|map, _| map.len() as u64),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<MapType<GenericType<0>>, StringType, BooleanType, _, _>(
+        "map_contains_key",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        vectorize_2_arg::<MapType<GenericType<0>>, StringType, BooleanType>(|map, key, _| {
+            map.keys.iter().any(|k| k == key)
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<ArrayType<StringType>, ArrayType<GenericType<0>>, MapType<GenericType<0>>, _, _>(
+        "map_from_arrays",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<ArrayType<StringType>, ArrayType<GenericType<0>>, MapType<GenericType<0>>>(
+            |keys, vals, output, ctx| {
+                if keys.len() != vals.len() {
+                    ctx.set_error(
+                        output.len(),
+                        format!(
+                            "map keys and values should have the same length, but got {} keys and {} values",
+                            keys.len(),
+                            vals.len()
+                        ),
+                    );
+                    output.commit_row();
+                    return;
+                }
+                for (idx, (key, val)) in keys.iter().zip(vals.iter()).enumerate() {
+                    if keys.iter().take(idx).any(|k| k == key) {
+                        ctx.set_error(
+                            output.len(),
+                            format!("map keys have to be unique, but got duplicate key {:?}", String::from_utf8_lossy(key)),
+                        );
+                        break;
+                    }
+                    output.put_item((key, val));
+                }
+                output.commit_row();
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<MapType<GenericType<0>>, MapType<GenericType<0>>, MapType<GenericType<0>>, _, _>(
+        "map_cat",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<MapType<GenericType<0>>, MapType<GenericType<0>>, MapType<GenericType<0>>>(
+            |lhs, rhs, output, _| {
+                // The entries of the right map overwrite the ones with the same key in the left map.
+                for (key, val) in KvPair::<StringType, GenericType<0>>::iter_column(&lhs) {
+                    if !rhs.keys.iter().any(|k| k == key) {
+                        output.put_item((key, val));
+                    }
+                }
+                output.builder.append_column(&rhs);
+                output.commit_row();
+            }
+        ),
+    );
+}
@@ -24,7 +24,9 @@ mod array;
 mod boolean;
 mod control;
 mod datetime;
+mod full_text;
 mod geo;
+mod map;
 mod math;
 mod tuple;
 mod variant;
@@ -38,6 +40,10 @@ mod string_multi_args;
 pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
+pub use full_text::bm25_idf;
+pub use full_text::TextTokenizer;
+pub use string::is_token_byte;
+pub use string::tokenize_text;
 
 use self::comparison::ALL_COMP_FUNC_NAMES;
 use self::comparison::ALL_MATCH_FUNC_NAMES;
@@ -52,6 +58,7 @@ fn builtin_functions() -> FunctionRegistry {
 
     arithmetic::register(&mut registry);
     array::register(&mut registry);
+    map::register(&mut registry);
     boolean::register(&mut registry);
     control::register(&mut registry);
     comparison::register(&mut registry);
@@ -59,6 +66,7 @@ fn builtin_functions() -> FunctionRegistry {
     math::register(&mut registry);
     string::register(&mut registry);
     string_multi_args::register(&mut registry);
+    full_text::register(&mut registry);
     tuple::register(&mut registry);
     variant::register(&mut registry);
     geo::register(&mut registry);
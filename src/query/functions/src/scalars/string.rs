@@ -13,14 +13,19 @@
 // limitations under the License.
 
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::io::Write;
 
 use bstr::ByteSlice;
+use common_expression::types::number::Float64Type;
 use common_expression::types::number::SimpleDomain;
 use common_expression::types::number::UInt64Type;
 use common_expression::types::string::StringColumn;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::string::StringDomain;
+use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
+use common_expression::types::NullableType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::vectorize_with_builder_1_arg;
@@ -44,6 +49,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     registry.register_aliases("char_length", &["character_length", "length_utf8"]);
     registry.register_aliases("substr", &["substring", "mid"]);
     registry.register_aliases("substr_utf8", &["substring_utf8"]);
+    registry.register_aliases("split", &["string_to_array"]);
 
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "upper",
@@ -304,6 +310,16 @@ pub fn register(registry: &mut FunctionRegistry) {
         move |substr: &[u8], str: &[u8], _| find_at(str, substr, 1),
     );
 
+    registry.register_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        |str: &[u8], query: &[u8], _| {
+            let tokens = tokenize_text(str).collect::<HashSet<_>>();
+            tokenize_text(query).all(|token| tokens.contains(token))
+        },
+    );
+
     registry.register_3_arg::<StringType, StringType, NumberType<u64>, NumberType<u64>, _, _>(
         "locate",
         FunctionProperty::default(),
@@ -781,6 +797,195 @@ pub fn register(registry: &mut FunctionRegistry) {
             }
         }),
     );
+
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, StringType, ArrayType<StringType>, _, _>(
+            "split",
+            FunctionProperty::default(),
+            |_, _| FunctionDomain::Full,
+            vectorize_with_builder_2_arg::<StringType, StringType, ArrayType<StringType>>(
+                |str, sep, output, _| {
+                    if sep.is_empty() {
+                        output.put_item(str);
+                    } else {
+                        for part in str.split_str(sep) {
+                            output.put_item(part);
+                        }
+                    }
+                    output.commit_row();
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, NumberType<i64>, StringType, _, _>(
+        "split_part",
+        FunctionProperty::default(),
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_3_arg::<StringType, StringType, NumberType<i64>, StringType>(
+            |str, sep, part, output, _| {
+                if let Some(part) = split_part(str, sep, part) {
+                    output.put_slice(part);
+                }
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "starts_with",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        |str, prefix, _| str.starts_with(prefix),
+    );
+
+    registry.register_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "ends_with",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        |str, suffix, _| str.ends_with(suffix),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, StringType, _, _>(
+        "translate",
+        FunctionProperty::default(),
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, StringType>(
+            |str, from, to, output, _| {
+                // The characters in `from` without a counterpart in `to` are removed.
+                let from = from.chars().collect::<Vec<_>>();
+                let to = to.chars().collect::<Vec<_>>();
+                for (start, end, ch) in str.char_indices() {
+                    match from.iter().position(|c| *c == ch) {
+                        Some(idx) => {
+                            if let Some(c) = to.get(idx) {
+                                output.put_char(*c);
+                            }
+                        }
+                        None => output.put_slice(&str[start..end]),
+                    }
+                }
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "initcap",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_string_to_string(
+            |col| col.data.len(),
+            |val, output, _| {
+                // Words are delimited by the characters which are not alphanumeric.
+                let mut word_start = true;
+                for (start, end, ch) in val.char_indices() {
+                    if ch == '\u{FFFD}' {
+                        // If char is invalid, just copy it.
+                        output.put_slice(&val[start..end]);
+                        word_start = true;
+                    } else if !ch.is_alphanumeric() {
+                        output.put_char(ch);
+                        word_start = true;
+                    } else if word_start {
+                        for x in ch.to_uppercase() {
+                            output.put_char(x);
+                        }
+                        word_start = false;
+                    } else {
+                        for x in ch.to_lowercase() {
+                            output.put_char(x);
+                        }
+                    }
+                }
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "url_host",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_string_to_string(
+            |col| col.data.len(),
+            |val, output, _| {
+                output.put_slice(url::parse(val).host);
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "url_path",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_string_to_string(
+            |col| col.data.len(),
+            |val, output, _| {
+                output.put_slice(url::parse(val).path);
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_combine_nullable_2_arg::<StringType, StringType, StringType, _, _>(
+        "url_query_param",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, NullableType<StringType>>(
+            |val, name, output, _| match url::query_param(url::parse(val).query, name) {
+                Some(param) => output.push(param),
+                None => output.push_null(),
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "url_encode",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_string_to_string(
+            |col| col.data.len() * 3,
+            |val, output, _| {
+                url::encode(val, &mut output.data);
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "url_decode",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_string_to_string(
+            |col| col.data.len(),
+            |val, output, _| {
+                url::decode(val, &mut output.data);
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_2_arg::<StringType, StringType, NumberType<u64>, _, _>(
+        "levenshtein",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        |s1, s2, _| similarity::levenshtein(s1, s2),
+    );
+
+    registry.register_2_arg::<StringType, StringType, Float64Type, _, _>(
+        "jaro_winkler",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        |s1, s2, _| similarity::jaro_winkler(s1, s2).into(),
+    );
+
+    registry.register_2_arg::<StringType, StringType, Float64Type, _, _>(
+        "ngram_distance",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        |s1, s2, _| similarity::ngram_distance(s1, s2).into(),
+    );
 }
 
 mod soundex {
@@ -855,6 +1060,264 @@ fn substr_utf8(builder: &mut StringColumnBuilder, str: &str, pos: i64, len: u64)
     builder.commit_row();
 }
 
+/// Splits the text into tokens of ASCII alphanumeric characters and non-ASCII bytes,
+/// which is shared by `match` and the token bloom index so that they agree on the tokens.
+pub fn tokenize_text(text: &[u8]) -> impl Iterator<Item = &[u8]> {
+    text.split(|b| !is_token_byte(*b))
+        .filter(|token| !token.is_empty())
+}
+
+#[inline]
+pub fn is_token_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || !b.is_ascii()
+}
+
+// Returns the part of the string split by the separator, the part index starts from 1,
+// negative index counts from the end, index 0 is treated as 1.
+#[inline]
+fn split_part<'a>(str: &'a [u8], sep: &[u8], part: i64) -> Option<&'a [u8]> {
+    if sep.is_empty() {
+        return if (-1..=1).contains(&part) {
+            Some(str)
+        } else {
+            None
+        };
+    }
+    match part.cmp(&0) {
+        Ordering::Greater => str.split_str(sep).nth(part as usize - 1),
+        Ordering::Equal => str.split_str(sep).next(),
+        Ordering::Less => str.rsplit_str(sep).nth(part.unsigned_abs() as usize - 1),
+    }
+}
+
+mod url {
+    use bstr::ByteSlice;
+
+    pub struct Url<'a> {
+        pub host: &'a [u8],
+        pub path: &'a [u8],
+        pub query: &'a [u8],
+    }
+
+    /// Split the URL like `scheme://user@host:port/path?query#fragment` into parts.
+    /// The scheme is optional, `www.example.com/path` is parsed with `www.example.com` as host.
+    pub fn parse(url: &[u8]) -> Url<'_> {
+        let url = match url.find_byte(b'#') {
+            Some(pos) => &url[..pos],
+            None => url,
+        };
+        let (url, query) = match url.find_byte(b'?') {
+            Some(pos) => (&url[..pos], &url[pos + 1..]),
+            None => (url, &url[url.len()..]),
+        };
+        let rest = match url.find("://") {
+            Some(pos) if !url[..pos].contains(&b'/') => &url[pos + 3..],
+            _ => match url.strip_prefix(b"//") {
+                Some(rest) => rest,
+                None if url.starts_with(b"/") => {
+                    return Url {
+                        host: &url[..0],
+                        path: url,
+                        query,
+                    };
+                }
+                None => url,
+            },
+        };
+        let (authority, path) = match rest.find_byte(b'/') {
+            Some(pos) => (&rest[..pos], &rest[pos..]),
+            None => (rest, &rest[rest.len()..]),
+        };
+        let host = match authority.rfind_byte(b'@') {
+            Some(pos) => &authority[pos + 1..],
+            None => authority,
+        };
+        let host = if host.starts_with(b"[") {
+            // IPv6 address, such as `[::1]:8080`.
+            match host.find_byte(b']') {
+                Some(pos) => &host[..pos + 1],
+                None => host,
+            }
+        } else {
+            match host.rfind_byte(b':') {
+                Some(pos) => &host[..pos],
+                None => host,
+            }
+        };
+        Url { host, path, query }
+    }
+
+    /// Returns the raw value of the first parameter with the name in the query string,
+    /// the parameter without `=` has an empty value.
+    pub fn query_param<'a>(query: &'a [u8], name: &[u8]) -> Option<&'a [u8]> {
+        for param in query.split_str("&") {
+            let (key, value) = match param.find_byte(b'=') {
+                Some(pos) => (&param[..pos], &param[pos + 1..]),
+                None => (param, &param[param.len()..]),
+            };
+            if key == name {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    /// Percent-encode all the characters except the unreserved characters of RFC 3986.
+    pub fn encode(val: &[u8], output: &mut Vec<u8>) {
+        const HEX_CHARS: &[u8; 16] = b"0123456789ABCDEF";
+        for b in val {
+            if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b'~') {
+                output.push(*b);
+            } else {
+                output.push(b'%');
+                output.push(HEX_CHARS[(b >> 4) as usize]);
+                output.push(HEX_CHARS[(b & 0xf) as usize]);
+            }
+        }
+    }
+
+    /// Decode the percent-encoded characters, invalid escapes are kept as they are.
+    pub fn decode(val: &[u8], output: &mut Vec<u8>) {
+        let mut i = 0;
+        while i < val.len() {
+            if val[i] == b'%' && i + 2 < val.len() {
+                if let (Some(h), Some(l)) = (hex_value(val[i + 1]), hex_value(val[i + 2])) {
+                    output.push(h << 4 | l);
+                    i += 3;
+                    continue;
+                }
+            }
+            output.push(val[i]);
+            i += 1;
+        }
+    }
+
+    fn hex_value(b: u8) -> Option<u8> {
+        (b as char).to_digit(16).map(|v| v as u8)
+    }
+}
+
+mod similarity {
+    use std::collections::HashMap;
+
+    use bstr::ByteSlice;
+
+    /// The minimum number of single-character edits to change one string into the other.
+    pub fn levenshtein(s1: &[u8], s2: &[u8]) -> u64 {
+        let s1 = s1.chars().collect::<Vec<_>>();
+        let s2 = s2.chars().collect::<Vec<_>>();
+        let mut prev = (0..=s2.len()).collect::<Vec<_>>();
+        let mut curr = vec![0; s2.len() + 1];
+        for (i, c1) in s1.iter().enumerate() {
+            curr[0] = i + 1;
+            for (j, c2) in s2.iter().enumerate() {
+                let cost = usize::from(c1 != c2);
+                curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+            }
+            std::mem::swap(&mut prev, &mut curr);
+        }
+        prev[s2.len()] as u64
+    }
+
+    /// Jaro-Winkler similarity between 0 and 1, the strings with a common prefix
+    /// get a higher score, 1 means the strings are the same.
+    pub fn jaro_winkler(s1: &[u8], s2: &[u8]) -> f64 {
+        let s1 = s1.chars().collect::<Vec<_>>();
+        let s2 = s2.chars().collect::<Vec<_>>();
+        let jaro = jaro(&s1, &s2);
+        let prefix = s1
+            .iter()
+            .zip(s2.iter())
+            .take(4)
+            .take_while(|(c1, c2)| c1 == c2)
+            .count();
+        jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+    }
+
+    fn jaro(s1: &[char], s2: &[char]) -> f64 {
+        if s1.is_empty() && s2.is_empty() {
+            return 1.0;
+        }
+        if s1.is_empty() || s2.is_empty() {
+            return 0.0;
+        }
+        let match_distance = (s1.len().max(s2.len()) / 2).saturating_sub(1);
+        let mut s1_matches = vec![false; s1.len()];
+        let mut s2_matches = vec![false; s2.len()];
+        let mut matches = 0;
+        for (i, c1) in s1.iter().enumerate() {
+            let start = i.saturating_sub(match_distance);
+            let end = (i + match_distance + 1).min(s2.len());
+            for j in start..end {
+                if !s2_matches[j] && s2[j] == *c1 {
+                    s1_matches[i] = true;
+                    s2_matches[j] = true;
+                    matches += 1;
+                    break;
+                }
+            }
+        }
+        if matches == 0 {
+            return 0.0;
+        }
+
+        let mut transpositions = 0;
+        let mut k = 0;
+        for (i, c1) in s1.iter().enumerate() {
+            if !s1_matches[i] {
+                continue;
+            }
+            while !s2_matches[k] {
+                k += 1;
+            }
+            if *c1 != s2[k] {
+                transpositions += 1;
+            }
+            k += 1;
+        }
+        let matches = matches as f64;
+        (matches / s1.len() as f64
+            + matches / s2.len() as f64
+            + (matches - transpositions as f64 / 2.0) / matches)
+            / 3.0
+    }
+
+    const NGRAM_SIZE: usize = 4;
+
+    /// The distance of the 4-gram sets of the strings between 0 and 1,
+    /// 0 means the strings have the same 4-grams.
+    pub fn ngram_distance(s1: &[u8], s2: &[u8]) -> f64 {
+        let s1 = s1.chars().collect::<Vec<_>>();
+        let s2 = s2.chars().collect::<Vec<_>>();
+        let mut counts: HashMap<&[char], i64> = HashMap::new();
+        let mut total = 0;
+        for gram in ngrams(&s1) {
+            *counts.entry(gram).or_default() += 1;
+            total += 1;
+        }
+        for gram in ngrams(&s2) {
+            *counts.entry(gram).or_default() -= 1;
+            total += 1;
+        }
+        if total == 0 {
+            return 0.0;
+        }
+        let diff: i64 = counts.values().map(|count| count.abs()).sum();
+        diff as f64 / total as f64
+    }
+
+    // Strings shorter than the n-gram size are taken as a single n-gram.
+    fn ngrams(s: &[char]) -> Vec<&[char]> {
+        if s.is_empty() {
+            vec![]
+        } else if s.len() < NGRAM_SIZE {
+            vec![s]
+        } else {
+            s.windows(NGRAM_SIZE).collect()
+        }
+    }
+}
+
 /// String to String scalar function with estimiated ouput column capacity.
 pub fn vectorize_string_to_string(
     estimate_bytes: impl Fn(&StringColumn) -> usize + Copy,
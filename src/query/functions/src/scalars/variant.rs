@@ -13,19 +13,26 @@
 // limitations under the License.
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::sync::Arc;
 
 use bstr::ByteSlice;
 use chrono::Datelike;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use common_expression::types::date::string_to_date;
 use common_expression::types::nullable::NullableColumn;
+use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::*;
+use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::timestamp::string_to_timestamp;
 use common_expression::types::variant::cast_scalar_to_variant;
 use common_expression::types::variant::cast_scalars_to_variants;
 use common_expression::types::variant::JSONB_NULL;
+use common_expression::types::AnyType;
 use common_expression::types::BooleanType;
+use common_expression::types::DataType;
 use common_expression::types::DateType;
 use common_expression::types::GenericType;
 use common_expression::types::NullableType;
@@ -40,29 +47,49 @@ use common_expression::vectorize_1_arg;
 use common_expression::vectorize_2_arg;
 use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
+use common_expression::vectorize_with_builder_4_arg;
 use common_expression::with_number_mapped_type;
+use common_expression::Column;
+use common_expression::EvalContext;
+use common_expression::Function;
 use common_expression::FunctionDomain;
 use common_expression::FunctionProperty;
 use common_expression::FunctionRegistry;
+use common_expression::FunctionSignature;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
 use common_expression::Value;
 use common_expression::ValueRef;
+use common_jsonb::array_append;
 use common_jsonb::array_length;
 use common_jsonb::as_bool;
 use common_jsonb::as_f64;
 use common_jsonb::as_i64;
 use common_jsonb::as_str;
+use common_jsonb::build_array;
+use common_jsonb::build_object;
 use common_jsonb::get_by_name_ignore_case;
 use common_jsonb::get_by_path;
 use common_jsonb::is_array;
 use common_jsonb::is_object;
+use common_jsonb::jsonpath::parse_json_path as parse_sql_json_path;
+use common_jsonb::jsonpath::Selector;
+use common_jsonb::object_delete;
+use common_jsonb::object_insert;
 use common_jsonb::object_keys;
+use common_jsonb::object_pick;
 use common_jsonb::parse_json_path;
 use common_jsonb::parse_value;
+use common_jsonb::strip_nulls;
 use common_jsonb::to_bool;
 use common_jsonb::to_f64;
 use common_jsonb::to_i64;
+use common_jsonb::to_pretty_string;
 use common_jsonb::to_str;
 use common_jsonb::to_u64;
+use common_jsonb::type_of;
+use common_jsonb::Error as JsonbError;
 use common_jsonb::JsonPathRef;
 use common_jsonb::Number as JsonbNumber;
 use common_jsonb::Value as JsonbValue;
@@ -391,6 +418,321 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_passthrough_nullable_2_arg::<VariantType, StringType, VariantType, _, _>(
+        "json_path_query",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<VariantType, StringType, VariantType>(
+            |val, path, output, ctx| {
+                match parse_sql_json_path(path) {
+                    Ok(json_path) => {
+                        let values = if val.is_empty() {
+                            vec![]
+                        } else {
+                            Selector::new(json_path).select(val)
+                        };
+                        let items = values.iter().map(|v| v.as_slice());
+                        if let Err(err) = build_array(items, &mut output.data) {
+                            ctx.set_error(output.len(), err.to_string());
+                        }
+                    }
+                    Err(_) => {
+                        ctx.set_error(
+                            output.len(),
+                            format!("Invalid JSON path '{}'", &String::from_utf8_lossy(path)),
+                        );
+                    }
+                }
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_combine_nullable_2_arg::<VariantType, StringType, VariantType, _, _>(
+        "json_path_query_first",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<VariantType, StringType, NullableType<VariantType>>(
+            |val, path, output, ctx| {
+                if val.is_empty() {
+                    output.push_null();
+                    return;
+                }
+                match parse_sql_json_path(path) {
+                    Ok(json_path) => match Selector::new(json_path).select(val).first() {
+                        Some(v) => output.push(v.as_slice()),
+                        None => output.push_null(),
+                    },
+                    Err(_) => {
+                        ctx.set_error(
+                            output.len(),
+                            format!("Invalid JSON path '{}'", &String::from_utf8_lossy(path)),
+                        );
+                        output.push_null();
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<VariantType, StringType, BooleanType, _, _>(
+        "json_path_exists",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<VariantType, StringType, BooleanType>(
+            |val, path, output, ctx| match parse_sql_json_path(path) {
+                Ok(json_path) => {
+                    output.push(!val.is_empty() && Selector::new(json_path).exists(val));
+                }
+                Err(_) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("Invalid JSON path '{}'", &String::from_utf8_lossy(path)),
+                    );
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_combine_nullable_2_arg::<VariantType, StringType, BooleanType, _, _>(
+        "json_path_match",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<VariantType, StringType, NullableType<BooleanType>>(
+            |val, path, output, ctx| {
+                if val.is_empty() {
+                    output.push_null();
+                    return;
+                }
+                match parse_sql_json_path(path) {
+                    Ok(json_path) => match Selector::new(json_path).predicate_match(val) {
+                        Some(res) => output.push(res),
+                        None => output.push_null(),
+                    },
+                    Err(_) => {
+                        ctx.set_error(
+                            output.len(),
+                            format!("Invalid JSON path '{}'", &String::from_utf8_lossy(path)),
+                        );
+                        output.push_null();
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_function_factory("json_array", |_, args_type| {
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "json_array".to_string(),
+                args_type: args_type.to_vec(),
+                return_type: DataType::Variant,
+                property: FunctionProperty::default(),
+            },
+            calc_domain: Box::new(|_| FunctionDomain::MayThrow),
+            eval: Box::new(|args, ctx| {
+                let len = args.iter().find_map(|arg| match arg {
+                    ValueRef::Column(col) => Some(col.len()),
+                    _ => None,
+                });
+                let mut builder = StringColumnBuilder::with_capacity(len.unwrap_or(1), 0);
+                let mut items = Vec::with_capacity(args.len());
+                for idx in 0..len.unwrap_or(1) {
+                    items.clear();
+                    for arg in args {
+                        let mut item = Vec::new();
+                        cast_scalar_to_variant(scalar_at(arg, idx), ctx.tz, &mut item);
+                        items.push(item);
+                    }
+                    let items = items.iter().map(|v| v.as_slice());
+                    if let Err(err) = build_array(items, &mut builder.data) {
+                        ctx.set_error(builder.len(), err.to_string());
+                    }
+                    builder.commit_row();
+                }
+                match len {
+                    Some(_) => Value::Column(Column::Variant(builder.build())),
+                    None => Value::Scalar(Scalar::Variant(builder.build_scalar())),
+                }
+            }),
+        }))
+    });
+
+    registry.register_function_factory("json_object", |_, args_type| {
+        if args_type.len() % 2 != 0 {
+            return None;
+        }
+        let args_type = args_type
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                if idx % 2 == 0 {
+                    DataType::String
+                } else {
+                    ty.clone()
+                }
+            })
+            .collect();
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "json_object".to_string(),
+                args_type,
+                return_type: DataType::Variant,
+                property: FunctionProperty::default(),
+            },
+            calc_domain: Box::new(|_| FunctionDomain::MayThrow),
+            eval: Box::new(|args, ctx| {
+                let len = args.iter().find_map(|arg| match arg {
+                    ValueRef::Column(col) => Some(col.len()),
+                    _ => None,
+                });
+                let mut builder = StringColumnBuilder::with_capacity(len.unwrap_or(1), 0);
+                for idx in 0..len.unwrap_or(1) {
+                    // The keys of `JSONB` object must be sorted.
+                    let mut items = BTreeMap::new();
+                    for kv in args.chunks(2) {
+                        let key = match scalar_at(&kv[0], idx) {
+                            ScalarRef::String(key) => String::from_utf8_lossy(key).to_string(),
+                            _ => unreachable!(),
+                        };
+                        if items.contains_key(&key) {
+                            ctx.set_error(
+                                builder.len(),
+                                format!(
+                                    "json_object keys have to be unique, but got duplicate key {:?}",
+                                    key
+                                ),
+                            );
+                            continue;
+                        }
+                        let mut val = Vec::new();
+                        cast_scalar_to_variant(scalar_at(&kv[1], idx), ctx.tz, &mut val);
+                        items.insert(key, val);
+                    }
+                    let items = items.iter().map(|(k, v)| (k, v.as_slice()));
+                    if let Err(err) = build_object(items, &mut builder.data) {
+                        ctx.set_error(builder.len(), err.to_string());
+                    }
+                    builder.commit_row();
+                }
+                match len {
+                    Some(_) => Value::Column(Column::Variant(builder.build())),
+                    None => Value::Scalar(Scalar::Variant(builder.build_scalar())),
+                }
+            }),
+        }))
+    });
+
+    registry.register_3_arg_core::<NullableType<VariantType>, NullableType<StringType>, NullableType<GenericType<0>>, NullableType<VariantType>, _, _>(
+        "object_insert",
+        FunctionProperty::default(),
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<NullableType<VariantType>, NullableType<StringType>, NullableType<GenericType<0>>, NullableType<VariantType>>(
+            |val, key, new_val, output, ctx| {
+                eval_object_insert(val, key, new_val, false, output, ctx)
+            }
+        ),
+    );
+
+    registry.register_4_arg_core::<NullableType<VariantType>, NullableType<StringType>, NullableType<GenericType<0>>, NullableType<BooleanType>, NullableType<VariantType>, _, _>(
+        "object_insert",
+        FunctionProperty::default(),
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_4_arg::<NullableType<VariantType>, NullableType<StringType>, NullableType<GenericType<0>>, NullableType<BooleanType>, NullableType<VariantType>>(
+            |val, key, new_val, update_flag, output, ctx| {
+                let update_flag = update_flag.unwrap_or(false);
+                eval_object_insert(val, key, new_val, update_flag, output, ctx)
+            }
+        ),
+    );
+
+    registry.register_function_factory("object_delete", |_, args_type| {
+        object_keys_function("object_delete", args_type, object_delete)
+    });
+
+    registry.register_function_factory("object_pick", |_, args_type| {
+        object_keys_function("object_pick", args_type, object_pick)
+    });
+
+    registry.register_2_arg_core::<NullableType<VariantType>, NullableType<GenericType<0>>, NullableType<VariantType>, _, _>(
+        "array_append",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<NullableType<VariantType>, NullableType<GenericType<0>>, NullableType<VariantType>>(
+            |val, item, output, ctx| {
+                let val = match val {
+                    Some(val) if !val.is_empty() => val,
+                    _ => {
+                        output.push_null();
+                        return;
+                    }
+                };
+                let mut item_buf = Vec::new();
+                match item {
+                    Some(item) => cast_scalar_to_variant(item, ctx.tz, &mut item_buf),
+                    None => item_buf.extend_from_slice(JSONB_NULL),
+                }
+                let mut buf = Vec::new();
+                match array_append(val, &item_buf, &mut buf) {
+                    Ok(_) => output.push(&buf),
+                    Err(_) => {
+                        ctx.set_error(
+                            output.len(),
+                            "array_append: the first argument must be an array",
+                        );
+                        output.push_null();
+                    }
+                }
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, VariantType, _, _>(
+        "json_strip_nulls",
+        FunctionProperty::default(),
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<VariantType, VariantType>(|val, output, ctx| {
+            if val.is_empty() {
+                output.put_slice(JSONB_NULL);
+            } else if let Err(err) = strip_nulls(val, &mut output.data) {
+                ctx.set_error(output.len(), err.to_string());
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, StringType, _, _>(
+        "json_typeof",
+        FunctionProperty::default(),
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<VariantType, StringType>(|val, output, ctx| {
+            if val.is_empty() {
+                output.put_str("null");
+            } else {
+                match type_of(val) {
+                    Ok(ty) => output.put_str(ty),
+                    Err(err) => ctx.set_error(output.len(), err.to_string()),
+                }
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, StringType, _, _>(
+        "json_pretty",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<VariantType, StringType>(|val, output, _| {
+            if val.is_empty() {
+                output.put_str("null");
+            } else {
+                output.put_str(&to_pretty_string(val));
+            }
+            output.commit_row();
+        }),
+    );
+
     registry.register_combine_nullable_1_arg::<VariantType, BooleanType, _, _>(
         "as_boolean",
         FunctionProperty::default(),
@@ -732,3 +1074,114 @@ pub fn register(registry: &mut FunctionRegistry) {
         });
     }
 }
+
+fn scalar_at<'a>(arg: &'a ValueRef<AnyType>, idx: usize) -> ScalarRef<'a> {
+    match arg {
+        ValueRef::Scalar(scalar) => scalar.clone(),
+        ValueRef::Column(col) => unsafe { col.index_unchecked(idx) },
+    }
+}
+
+fn eval_object_insert(
+    val: Option<&[u8]>,
+    key: Option<&[u8]>,
+    new_val: Option<ScalarRef>,
+    update_flag: bool,
+    output: &mut NullableColumnBuilder<VariantType>,
+    ctx: &mut EvalContext,
+) {
+    let (val, key) = match (val, key) {
+        (Some(val), Some(key)) if !val.is_empty() => (val, key),
+        _ => {
+            output.push_null();
+            return;
+        }
+    };
+    let key = String::from_utf8_lossy(key);
+    let mut new_val_buf = Vec::new();
+    match new_val {
+        Some(new_val) => cast_scalar_to_variant(new_val, ctx.tz, &mut new_val_buf),
+        None => new_val_buf.extend_from_slice(JSONB_NULL),
+    }
+    let mut buf = Vec::new();
+    match object_insert(val, &key, &new_val_buf, update_flag, &mut buf) {
+        Ok(_) => output.push(&buf),
+        Err(err) => {
+            let msg = match err {
+                JsonbError::ObjectDuplicateKey => {
+                    format!("object_insert: duplicate key {:?}", key)
+                }
+                _ => "object_insert: the first argument must be an object".to_string(),
+            };
+            ctx.set_error(output.len(), msg);
+            output.push_null();
+        }
+    }
+}
+
+// Build the function that removes or keeps the given keys of a variant object,
+// such as `object_delete(obj, 'k1', 'k2')`.
+fn object_keys_function(
+    name: &'static str,
+    args_type: &[DataType],
+    func: fn(&[u8], &BTreeSet<&str>, &mut Vec<u8>) -> Result<(), JsonbError>,
+) -> Option<Arc<Function>> {
+    if args_type.len() < 2 {
+        return None;
+    }
+    let mut new_args_type = vec![DataType::Nullable(Box::new(DataType::Variant))];
+    new_args_type.resize(
+        args_type.len(),
+        DataType::Nullable(Box::new(DataType::String)),
+    );
+    Some(Arc::new(Function {
+        signature: FunctionSignature {
+            name: name.to_string(),
+            args_type: new_args_type,
+            return_type: DataType::Nullable(Box::new(DataType::Variant)),
+            property: FunctionProperty::default(),
+        },
+        calc_domain: Box::new(|_| FunctionDomain::MayThrow),
+        eval: Box::new(move |args, ctx| {
+            let len = args.iter().find_map(|arg| match arg {
+                ValueRef::Column(col) => Some(col.len()),
+                _ => None,
+            });
+            let mut builder =
+                NullableColumnBuilder::<VariantType>::with_capacity(len.unwrap_or(1), ctx.generics);
+            for idx in 0..len.unwrap_or(1) {
+                let val = match scalar_at(&args[0], idx) {
+                    ScalarRef::Variant(val) if !val.is_empty() => val,
+                    _ => {
+                        builder.push_null();
+                        continue;
+                    }
+                };
+                let mut keys = BTreeSet::new();
+                for arg in &args[1..] {
+                    if let ScalarRef::String(key) = scalar_at(arg, idx) {
+                        keys.insert(std::str::from_utf8(key).unwrap_or_default());
+                    }
+                }
+                let mut buf = Vec::new();
+                match func(val, &keys, &mut buf) {
+                    Ok(_) => builder.push(&buf),
+                    Err(_) => {
+                        ctx.set_error(
+                            builder.len(),
+                            format!("{}: the first argument must be an object", name),
+                        );
+                        builder.push_null();
+                    }
+                }
+            }
+            match len {
+                Some(_) => Value::Column(Column::Nullable(Box::new(builder.build().upcast()))),
+                None => match builder.build_scalar() {
+                    Some(val) => Value::Scalar(Scalar::Variant(val)),
+                    None => Value::Scalar(Scalar::Null),
+                },
+            }
+        }),
+    }))
+}
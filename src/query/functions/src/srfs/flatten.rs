@@ -0,0 +1,217 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::ScalarRef;
+use common_jsonb::from_slice;
+use common_jsonb::get_by_path;
+use common_jsonb::parse_json_path;
+use common_jsonb::parse_value;
+use common_jsonb::Value as JsonbValue;
+
+use super::check_args_len;
+
+/// The arguments of `flatten`, the ones not given are NULL and take the default values.
+pub const PARAMS: &[&str] = &["input", "path", "outer", "recursive", "mode"];
+
+/// The columns generated by `flatten`.
+pub const COLUMNS: &[&str] = &["seq", "key", "path", "index", "value", "this"];
+
+/// Which kind of containers are expanded by `flatten`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FlattenMode {
+    Object,
+    Array,
+    Both,
+}
+
+/// `flatten(input [, path, outer, recursive, mode])` expands the elements of the variant
+/// arrays and objects into rows, such as:
+/// `flatten(input => v, path => 'a', outer => true, recursive => false, mode => 'both')`
+///
+/// - `path` is the path of the value to expand in `input`, defaults to the whole input.
+/// - `outer` generates a row of NULL values for the input generating no rows.
+/// - `recursive` also expands the elements of the nested containers.
+/// - `mode` is one of `'object'`, `'array'` and `'both'`, defaults to `'both'`.
+///
+/// The `seq` column is the number of the input row, counted from 1.
+pub fn return_type(name: &str, args_type: &[DataType]) -> Result<DataType> {
+    check_args_len(name, args_type, 1, PARAMS.len())?;
+    for (param, ty) in PARAMS.iter().zip(args_type.iter()) {
+        let ty = ty.remove_nullable();
+        let valid = match *param {
+            "input" => matches!(ty, DataType::Variant | DataType::String | DataType::Null),
+            "path" | "mode" => matches!(ty, DataType::String | DataType::Null),
+            _ => matches!(ty, DataType::Boolean | DataType::Null),
+        };
+        if !valid {
+            return Err(ErrorCode::BadArguments(format!(
+                "Invalid type {} of the argument {} of {}",
+                ty, param, name
+            )));
+        }
+    }
+
+    Ok(DataType::Tuple(vec![
+        DataType::Number(NumberDataType::UInt64),
+        DataType::String.wrap_nullable(),
+        DataType::String.wrap_nullable(),
+        DataType::Number(NumberDataType::UInt64).wrap_nullable(),
+        DataType::Variant.wrap_nullable(),
+        DataType::Variant.wrap_nullable(),
+    ]))
+}
+
+pub fn eval(
+    name: &str,
+    args: &[ScalarRef],
+    row: usize,
+    builder: &mut ColumnBuilder,
+) -> Result<usize> {
+    let arg = |i: usize| args.get(i).cloned().unwrap_or_default();
+    let path = match arg(1) {
+        ScalarRef::String(s) => String::from_utf8(s.to_vec())?,
+        _ => String::new(),
+    };
+    let outer = matches!(arg(2), ScalarRef::Boolean(true));
+    let recursive = matches!(arg(3), ScalarRef::Boolean(true));
+    let mode = match arg(4) {
+        ScalarRef::String(s) => match String::from_utf8_lossy(s).to_lowercase().as_str() {
+            "object" => FlattenMode::Object,
+            "array" => FlattenMode::Array,
+            "both" => FlattenMode::Both,
+            other => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "{} mode must be one of 'object', 'array' or 'both', but got '{}'",
+                    name, other
+                )));
+            }
+        },
+        _ => FlattenMode::Both,
+    };
+
+    let input = match arg(0) {
+        ScalarRef::Variant(v) => Some(v.to_vec()),
+        ScalarRef::String(s) => Some(
+            parse_value(s)
+                .map_err(|err| {
+                    ErrorCode::BadArguments(format!("{} input is not a valid JSON: {}", name, err))
+                })?
+                .to_vec(),
+        ),
+        _ => None,
+    };
+    let input = match input {
+        Some(input) if !path.is_empty() => {
+            let json_paths = parse_json_path(path.as_bytes()).map_err(|err| {
+                ErrorCode::BadArguments(format!("Invalid {} path '{}': {}", name, path, err))
+            })?;
+            get_by_path(&input, json_paths)
+        }
+        input => input,
+    };
+    let value = match &input {
+        Some(input) => Some(from_slice(input).map_err(|err| {
+            ErrorCode::BadBytes(format!("{} input is not a valid JSONB: {}", name, err))
+        })?),
+        None => None,
+    };
+
+    let mut rows = FlattenRows {
+        seq: row as u64 + 1,
+        recursive,
+        mode,
+        builder,
+        count: 0,
+    };
+    if let Some(value) = &value {
+        rows.flatten(value, &path);
+    }
+    if rows.count == 0 && outer {
+        rows.push(None, &path, None, None, value.as_ref());
+    }
+    Ok(rows.count)
+}
+
+struct FlattenRows<'a> {
+    seq: u64,
+    recursive: bool,
+    mode: FlattenMode,
+    builder: &'a mut ColumnBuilder,
+    count: usize,
+}
+
+impl FlattenRows<'_> {
+    fn push(
+        &mut self,
+        key: Option<&str>,
+        path: &str,
+        index: Option<u64>,
+        value: Option<&JsonbValue>,
+        this: Option<&JsonbValue>,
+    ) {
+        let value = value.map(|v| v.to_vec());
+        let this = this.map(|v| v.to_vec());
+        self.builder.push(ScalarRef::Tuple(vec![
+            ScalarRef::Number(NumberScalar::UInt64(self.seq)),
+            key.map(|k| ScalarRef::String(k.as_bytes()))
+                .unwrap_or_default(),
+            ScalarRef::String(path.as_bytes()),
+            index
+                .map(|i| ScalarRef::Number(NumberScalar::UInt64(i)))
+                .unwrap_or_default(),
+            value
+                .as_ref()
+                .map(|v| ScalarRef::Variant(v.as_slice()))
+                .unwrap_or_default(),
+            this.as_ref()
+                .map(|v| ScalarRef::Variant(v.as_slice()))
+                .unwrap_or_default(),
+        ]));
+        self.count += 1;
+    }
+
+    fn flatten(&mut self, value: &JsonbValue, path: &str) {
+        match value {
+            JsonbValue::Array(vals) if self.mode != FlattenMode::Object => {
+                for (i, val) in vals.iter().enumerate() {
+                    let inner_path = format!("{}[{}]", path, i);
+                    self.push(None, &inner_path, Some(i as u64), Some(val), Some(value));
+                    if self.recursive {
+                        self.flatten(val, &inner_path);
+                    }
+                }
+            }
+            JsonbValue::Object(obj) if self.mode != FlattenMode::Array => {
+                for (key, val) in obj.iter() {
+                    let inner_path = if path.is_empty() {
+                        key.to_string()
+                    } else {
+                        format!("{}.{}", path, key)
+                    };
+                    self.push(Some(key), &inner_path, None, Some(val), Some(value));
+                    if self.recursive {
+                        self.flatten(val, &inner_path);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono_tz::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::utils::date_helper::AddMonthsImpl;
+use common_expression::ColumnBuilder;
+use common_expression::ScalarRef;
+
+use super::check_args_len;
+use super::scalar_to_i64;
+
+const MICROS_IN_A_DAY: i64 = 24 * 3600 * 1_000_000;
+
+/// `generate_series(start, stop [, step])` generates the values from `start` to `stop`
+/// (both inclusive) with the increment `step`.
+/// - For integers, `step` is an integer and defaults to 1.
+/// - For dates and timestamps, `step` is an interval such as `INTERVAL 1 HOUR` and
+///   defaults to 1 day, an integer step of dates is the number of days.
+///
+/// The interval is passed as a tuple of `(months, microseconds)` by the binder.
+pub fn return_type(name: &str, args_type: &[DataType]) -> Result<DataType> {
+    check_args_len(name, args_type, 2, 3)?;
+    let start = args_type[0].remove_nullable();
+    let stop = args_type[1].remove_nullable();
+    let step = args_type.get(2).map(|ty| ty.remove_nullable());
+    let is_integer = |ty: &DataType| ty.is_integer() || *ty == DataType::Null;
+    let is_interval = |ty: &DataType| match ty {
+        DataType::Tuple(fields) => fields.len() == 2 && fields.iter().all(is_integer),
+        _ => false,
+    };
+
+    let return_type = if is_integer(&start) && is_integer(&stop) {
+        DataType::Number(NumberDataType::Int64)
+    } else if start.is_date_or_date_time() && stop.is_date_or_date_time() {
+        if start == DataType::Timestamp || stop == DataType::Timestamp {
+            DataType::Timestamp
+        } else {
+            DataType::Date
+        }
+    } else {
+        return Err(ErrorCode::BadArguments(format!(
+            "{} expects integer, date or timestamp arguments, but got {} and {}",
+            name, start, stop
+        )));
+    };
+
+    if let Some(step) = step {
+        let valid = match return_type {
+            DataType::Timestamp => is_interval(&step),
+            DataType::Date => is_interval(&step) || is_integer(&step),
+            _ => is_integer(&step),
+        };
+        if !valid {
+            return Err(ErrorCode::BadArguments(format!(
+                "Invalid step type {} of {} over {}",
+                step, name, return_type
+            )));
+        }
+    }
+    Ok(return_type)
+}
+
+pub fn eval(
+    name: &str,
+    args: &[ScalarRef],
+    _: usize,
+    builder: &mut ColumnBuilder,
+) -> Result<usize> {
+    if args.iter().any(|arg| matches!(arg, ScalarRef::Null)) {
+        return Ok(0);
+    }
+    match builder {
+        ColumnBuilder::Number(_) => {
+            let start = scalar_to_i64(name, &args[0])?;
+            let stop = scalar_to_i64(name, &args[1])?;
+            let step = match args.get(2) {
+                Some(step) => scalar_to_i64(name, step)?,
+                None => 1,
+            };
+            if step == 0 {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The step of {} must not be zero",
+                    name
+                )));
+            }
+            let mut count = 0;
+            let mut value = Some(start);
+            while let Some(v) = value {
+                if (step > 0 && v > stop) || (step < 0 && v < stop) {
+                    break;
+                }
+                builder.push(ScalarRef::Number(NumberScalar::Int64(v)));
+                count += 1;
+                value = v.checked_add(step);
+            }
+            Ok(count)
+        }
+        ColumnBuilder::Date(_) => {
+            let start = to_micros(name, &args[0])?;
+            let stop = to_micros(name, &args[1])?;
+            let (months, micros) = match args.get(2) {
+                Some(ScalarRef::Tuple(fields)) => interval_step(name, fields)?,
+                Some(days) => {
+                    let micros = scalar_to_i64(name, days)?
+                        .checked_mul(MICROS_IN_A_DAY)
+                        .ok_or_else(|| {
+                            ErrorCode::Overflow(format!("The step of {} is out of range", name))
+                        })?;
+                    (0, micros)
+                }
+                None => (0, MICROS_IN_A_DAY),
+            };
+            if micros % MICROS_IN_A_DAY != 0 {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The step of {} over dates must be whole days",
+                    name
+                )));
+            }
+            generate_datetime_series(name, start, stop, months, micros, |micros| {
+                builder.push(ScalarRef::Date((micros / MICROS_IN_A_DAY) as i32))
+            })
+        }
+        ColumnBuilder::Timestamp(_) => {
+            let start = to_micros(name, &args[0])?;
+            let stop = to_micros(name, &args[1])?;
+            let (months, micros) = match args.get(2) {
+                Some(ScalarRef::Tuple(fields)) => interval_step(name, fields)?,
+                _ => (0, MICROS_IN_A_DAY),
+            };
+            generate_datetime_series(name, start, stop, months, micros, |micros| {
+                builder.push(ScalarRef::Timestamp(micros))
+            })
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn to_micros(name: &str, scalar: &ScalarRef) -> Result<i64> {
+    match scalar {
+        ScalarRef::Date(days) => Ok(*days as i64 * MICROS_IN_A_DAY),
+        ScalarRef::Timestamp(micros) => Ok(*micros),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "{} expects date or timestamp arguments, but got {}",
+            name, scalar
+        ))),
+    }
+}
+
+fn interval_step(name: &str, fields: &[ScalarRef]) -> Result<(i64, i64)> {
+    Ok((
+        scalar_to_i64(name, &fields[0])?,
+        scalar_to_i64(name, &fields[1])?,
+    ))
+}
+
+// The n-th value is computed from the start instead of the previous value,
+// so that the day of month is kept after a shorter month, like `2023-01-31 + 2 months`.
+// The months are added in UTC, the same as the days of dates.
+fn generate_datetime_series(
+    name: &str,
+    start: i64,
+    stop: i64,
+    months: i64,
+    micros: i64,
+    mut push: impl FnMut(i64),
+) -> Result<usize> {
+    let ascending = months >= 0 && micros >= 0;
+    let descending = months <= 0 && micros <= 0;
+    if ascending == descending {
+        return Err(ErrorCode::BadArguments(format!(
+            "The step of {} must not be zero or mix positive and negative parts",
+            name
+        )));
+    }
+
+    let mut count = 0;
+    loop {
+        let n = count as i64;
+        let value = if months == 0 {
+            start
+        } else {
+            AddMonthsImpl::eval_timestamp(start, Tz::UTC, n * months)
+                .map_err(ErrorCode::BadArguments)?
+        }
+        .checked_add(n.saturating_mul(micros));
+        match value {
+            Some(v) if (ascending && v <= stop) || (descending && v >= stop) => {
+                push(v);
+                count += 1;
+            }
+            _ => break,
+        }
+    }
+    Ok(count)
+}
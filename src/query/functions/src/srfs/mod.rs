@@ -0,0 +1,189 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Set-returning functions generate a set of rows for each row of the arguments,
+//! they can be used in the `FROM` clause and reference the columns of the preceding tables.
+
+mod flatten;
+mod generate_series;
+mod unnest;
+
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::AnyType;
+use common_expression::types::DataType;
+use common_expression::with_integer_mapped_type;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::ScalarRef;
+use common_expression::ValueRef;
+use once_cell::sync::Lazy;
+
+pub static BUILTIN_SRFS: Lazy<SetReturningFunctions> = Lazy::new(builtin_srfs);
+
+fn builtin_srfs() -> SetReturningFunctions {
+    let mut srfs = SetReturningFunctions::default();
+
+    srfs.register(
+        "generate_series",
+        generate_series::return_type,
+        generate_series::eval,
+    );
+    srfs.register("unnest", unnest::return_type, unnest::eval);
+    srfs.register_with_columns(
+        "flatten",
+        flatten::PARAMS,
+        flatten::COLUMNS,
+        flatten::return_type,
+        flatten::eval,
+    );
+
+    srfs.register_aliases("generate_series", &["range"]);
+
+    srfs
+}
+
+/// Infers the type of the generated values from the argument types.
+pub type SrfReturnType = fn(&str, &[DataType]) -> Result<DataType>;
+/// Pushes the values generated for one row of the arguments into the builder
+/// and returns the number of the values. The row is numbered from 0 in the input
+/// of the function.
+pub type SrfEval = fn(&str, &[ScalarRef], usize, &mut ColumnBuilder) -> Result<usize>;
+
+pub struct SetReturningFunction {
+    pub name: &'static str,
+    /// The names of the arguments that can be passed by name, in the order of the
+    /// positional arguments. Empty if the function only accepts positional arguments.
+    pub params: &'static [&'static str],
+    /// The names of the columns generated by the function, the generated values are
+    /// tuples of the columns. Empty if the function generates a single column.
+    pub columns: &'static [&'static str],
+    return_type: SrfReturnType,
+    eval: SrfEval,
+}
+
+impl SetReturningFunction {
+    pub fn return_type(&self, args_type: &[DataType]) -> Result<DataType> {
+        (self.return_type)(self.name, args_type)
+    }
+
+    /// Evaluates the function over `num_rows` rows of the arguments, returns the generated values
+    /// of all rows and the number of values generated for each row. `first_row` is the number
+    /// of the first row in the input of the function.
+    pub fn eval(
+        &self,
+        args: &[ValueRef<AnyType>],
+        num_rows: usize,
+        first_row: usize,
+        return_type: &DataType,
+    ) -> Result<(Column, Vec<usize>)> {
+        let mut builder = ColumnBuilder::with_capacity(return_type, num_rows);
+        let mut counts = Vec::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let row_args = args
+                .iter()
+                .map(|arg| arg.index(row).unwrap())
+                .collect::<Vec<_>>();
+            counts.push((self.eval)(
+                self.name,
+                &row_args,
+                first_row + row,
+                &mut builder,
+            )?);
+        }
+        Ok((builder.build(), counts))
+    }
+}
+
+#[derive(Default)]
+pub struct SetReturningFunctions {
+    funcs: HashMap<&'static str, SetReturningFunction>,
+    aliases: HashMap<&'static str, &'static str>,
+}
+
+impl SetReturningFunctions {
+    fn register(&mut self, name: &'static str, return_type: SrfReturnType, eval: SrfEval) {
+        self.register_with_columns(name, &[], &[], return_type, eval);
+    }
+
+    fn register_with_columns(
+        &mut self,
+        name: &'static str,
+        params: &'static [&'static str],
+        columns: &'static [&'static str],
+        return_type: SrfReturnType,
+        eval: SrfEval,
+    ) {
+        self.funcs.insert(name, SetReturningFunction {
+            name,
+            params,
+            columns,
+            return_type,
+            eval,
+        });
+    }
+
+    fn register_aliases(&mut self, name: &'static str, aliases: &[&'static str]) {
+        for alias in aliases {
+            self.aliases.insert(alias, name);
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SetReturningFunction> {
+        let name = self.aliases.get(name).copied().unwrap_or(name);
+        self.funcs.get(name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+}
+
+fn check_args_len(name: &str, args_type: &[DataType], min: usize, max: usize) -> Result<()> {
+    if args_type.len() < min || args_type.len() > max {
+        let expected = if min == max {
+            min.to_string()
+        } else {
+            format!("{} to {}", min, max)
+        };
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expects {} arguments, but got {}",
+            name,
+            expected,
+            args_type.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Reads an integer argument as i64, the values out of the range of i64 are rejected
+/// instead of being wrapped or replaced by a default.
+fn scalar_to_i64(name: &str, scalar: &ScalarRef) -> Result<i64> {
+    let value = match scalar {
+        ScalarRef::Number(num) => with_integer_mapped_type!(|NUM_TYPE| match num {
+            NumberScalar::NUM_TYPE(v) => i64::try_from(*v).ok(),
+            _ => None,
+        }),
+        _ => None,
+    };
+    value.ok_or_else(|| {
+        ErrorCode::Overflow(format!(
+            "The argument {} of {} is out of the range of Int64",
+            scalar, name
+        ))
+    })
+}
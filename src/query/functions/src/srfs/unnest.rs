@@ -0,0 +1,64 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ColumnBuilder;
+use common_expression::ScalarRef;
+use common_jsonb::from_slice;
+use common_jsonb::Value as JsonbValue;
+
+use super::check_args_len;
+
+/// `unnest(array)` expands the elements of the array into rows,
+/// the variant arrays are also supported. NULL and empty arrays generate no rows.
+pub fn return_type(name: &str, args_type: &[DataType]) -> Result<DataType> {
+    check_args_len(name, args_type, 1, 1)?;
+    match args_type[0].remove_nullable() {
+        DataType::Array(ty) => Ok(*ty),
+        DataType::EmptyArray | DataType::Null => Ok(DataType::Null),
+        DataType::Variant => Ok(DataType::Variant),
+        ty => Err(ErrorCode::BadArguments(format!(
+            "{} expects an array argument, but got {}",
+            name, ty
+        ))),
+    }
+}
+
+pub fn eval(_: &str, args: &[ScalarRef], _: usize, builder: &mut ColumnBuilder) -> Result<usize> {
+    match &args[0] {
+        ScalarRef::Array(col) => {
+            for i in 0..col.len() {
+                builder.push(col.index(i).unwrap());
+            }
+            Ok(col.len())
+        }
+        ScalarRef::Variant(v) => {
+            let value = from_slice(v).map_err(|err| {
+                ErrorCode::BadBytes(format!("unnest input is not a valid JSONB: {}", err))
+            })?;
+            match value {
+                JsonbValue::Array(vals) => {
+                    for val in vals.iter() {
+                        builder.push(ScalarRef::Variant(&val.to_vec()));
+                    }
+                    Ok(vals.len())
+                }
+                _ => Ok(0),
+            }
+        }
+        _ => Ok(0),
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use goldenfile::Mint;
+
+use super::run_ast;
+
+#[test]
+fn test_map() {
+    let mut mint = Mint::new("tests/it/scalars/testdata");
+    let file = &mut mint.new_goldenfile("map.txt").unwrap();
+
+    test_create(file);
+    test_get(file);
+    test_map_keys(file);
+    test_map_values(file);
+    test_map_size(file);
+    test_map_contains_key(file);
+    test_map_from_arrays(file);
+    test_map_cat(file);
+}
+
+fn test_create(file: &mut impl Write) {
+    run_ast(file, "{}", &[]);
+    run_ast(file, "{'k1': 1, 'k2': 2}", &[]);
+    run_ast(file, "{'k1': 1, 'k2': NULL}", &[]);
+    run_ast(file, "{'k1': 1, 'k1': 2}", &[]);
+}
+
+fn test_get(file: &mut impl Write) {
+    run_ast(file, "{'k1': 1, 'k2': 2}['k1']", &[]);
+    run_ast(file, "{'k1': 1, 'k2': 2}['k3']", &[]);
+    run_ast(file, "{'k1': 1, 'k2': NULL}['k2']", &[]);
+}
+
+fn test_map_keys(file: &mut impl Write) {
+    run_ast(file, "map_keys({})", &[]);
+    run_ast(file, "map_keys({'k1': 1, 'k2': 2})", &[]);
+}
+
+fn test_map_values(file: &mut impl Write) {
+    run_ast(file, "map_values({'k1': 1, 'k2': 2})", &[]);
+    run_ast(file, "map_values({'k1': 1, 'k2': NULL})", &[]);
+}
+
+fn test_map_size(file: &mut impl Write) {
+    run_ast(file, "map_size({})", &[]);
+    run_ast(file, "map_size({'k1': 1, 'k2': 2})", &[]);
+}
+
+fn test_map_contains_key(file: &mut impl Write) {
+    run_ast(file, "map_contains_key({'k1': 1, 'k2': 2}, 'k1')", &[]);
+    run_ast(file, "map_contains_key({'k1': 1, 'k2': 2}, 'k3')", &[]);
+}
+
+fn test_map_from_arrays(file: &mut impl Write) {
+    run_ast(file, "map_from_arrays(['k1', 'k2'], [1, 2])", &[]);
+    run_ast(file, "map_from_arrays(['k1', 'k1'], [1, 2])", &[]);
+    run_ast(file, "map_from_arrays(['k1', 'k2'], [1])", &[]);
+}
+
+fn test_map_cat(file: &mut impl Write) {
+    run_ast(file, "map_cat({'k1': 1, 'k2': 2}, {'k2': 3, 'k3': 4})", &[]);
+}
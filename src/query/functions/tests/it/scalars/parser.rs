@@ -277,6 +277,16 @@ pub fn transform_expr(ast: AExpr, columns: &[(&str, DataType)]) -> RawExpr {
                 .map(|expr| transform_expr(expr, columns))
                 .collect(),
         },
+        AExpr::Map { span, kvs } => RawExpr::FunctionCall {
+            span,
+            name: "map".to_string(),
+            params: vec![],
+            args: kvs
+                .into_iter()
+                .flat_map(|(key, val)| [key, val])
+                .map(|expr| transform_expr(expr, columns))
+                .collect(),
+        },
         AExpr::Tuple { span, exprs } => RawExpr::FunctionCall {
             span,
             name: "tuple".to_string(),
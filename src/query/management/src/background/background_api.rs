@@ -0,0 +1,25 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+#[async_trait::async_trait]
+pub trait BackgroundApi: Sync + Send {
+    // Acquire or renew the leadership of the tenant's cluster background services for the node,
+    // return whether the node is the leader.
+    async fn try_lead(&self, node_id: &str) -> Result<bool>;
+
+    // Get the node leading the tenant's cluster background services.
+    async fn get_leader(&self) -> Result<Option<String>>;
+}
@@ -0,0 +1,116 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Add;
+use std::time::Duration;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_store::MetaStore;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKVReq;
+
+use crate::background::BackgroundApi;
+
+pub static BACKGROUND_API_KEY_PREFIX: &str = "__fd_background";
+
+/// Elects the node running the background services of a cluster.
+///
+/// The leader holds a key which expires after the lease, it has to renew the key before that
+/// to keep the leadership. Once the key expired, any node of the cluster may take it over.
+pub struct BackgroundMgr {
+    metastore: MetaStore,
+    lease: Duration,
+    leader_key: String,
+}
+
+impl BackgroundMgr {
+    pub fn create(
+        metastore: MetaStore,
+        tenant: &str,
+        cluster_id: &str,
+        lease: Duration,
+    ) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while background mgr create)",
+            ));
+        }
+
+        Ok(BackgroundMgr {
+            metastore,
+            lease,
+            leader_key: format!(
+                "{}/{}/{}/leader",
+                BACKGROUND_API_KEY_PREFIX,
+                escape_for_key(tenant)?,
+                escape_for_key(cluster_id)?
+            ),
+        })
+    }
+
+    fn new_lease(&self) -> KVMeta {
+        let now = std::time::SystemTime::now();
+        let expire_at = now
+            .add(self.lease)
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+
+        KVMeta {
+            expire_at: Some(expire_at.as_secs()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl BackgroundApi for BackgroundMgr {
+    async fn try_lead(&self, node_id: &str) -> Result<bool> {
+        let (seq, value) = match self.metastore.get_kv(&self.leader_key).await? {
+            // No leader, try to become the leader.
+            None => (
+                MatchSeq::Exact(0),
+                Operation::Update(node_id.as_bytes().to_vec()),
+            ),
+            // The leader renews its lease.
+            Some(leader) if leader.data == node_id.as_bytes() => {
+                (MatchSeq::Exact(leader.seq), Operation::AsIs)
+            }
+            Some(_) => return Ok(false),
+        };
+
+        let reply = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(
+                &self.leader_key,
+                seq,
+                value,
+                Some(self.new_lease()),
+            ))
+            .await?;
+        // Another node has taken the leadership meanwhile if the seq does not match.
+        Ok(reply.changed())
+    }
+
+    async fn get_leader(&self) -> Result<Option<String>> {
+        match self.metastore.get_kv(&self.leader_key).await? {
+            None => Ok(None),
+            Some(leader) => Ok(Some(String::from_utf8(leader.data)?)),
+        }
+    }
+}
@@ -14,6 +14,7 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod background;
 mod cluster;
 mod quota;
 mod role;
@@ -23,6 +24,8 @@ mod stage;
 mod udf;
 mod user;
 
+pub use background::BackgroundApi;
+pub use background::BackgroundMgr;
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
 pub use quota::QuotaApi;
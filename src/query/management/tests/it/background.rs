@@ -0,0 +1,61 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_store::MetaStore;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_lead() -> Result<()> {
+    let background_api = new_background_api().await?;
+
+    assert_eq!(background_api.get_leader().await?, None);
+    assert!(background_api.try_lead("node_1").await?);
+    assert_eq!(
+        background_api.get_leader().await?,
+        Some("node_1".to_string())
+    );
+
+    // The leader renews its lease.
+    assert!(background_api.try_lead("node_1").await?);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_only_one_leader() -> Result<()> {
+    let background_api = new_background_api().await?;
+
+    assert!(background_api.try_lead("node_1").await?);
+    assert!(!background_api.try_lead("node_2").await?);
+    assert_eq!(
+        background_api.get_leader().await?,
+        Some("node_1".to_string())
+    );
+    Ok(())
+}
+
+async fn new_background_api() -> Result<BackgroundMgr> {
+    let test_api = MetaStore::L(Arc::new(MetaEmbedded::new_temp().await?));
+    BackgroundMgr::create(
+        test_api,
+        "test-tenant-id",
+        "test-cluster-id",
+        Duration::from_secs(60),
+    )
+}
@@ -14,6 +14,7 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod background;
 mod cluster;
 mod setting;
 mod stage;
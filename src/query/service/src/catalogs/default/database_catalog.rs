@@ -28,6 +28,8 @@ use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::DropTableReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::RemoveDroppedTableReply;
+use common_meta_app::schema::RemoveDroppedTableReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
@@ -306,6 +308,37 @@ impl Catalog for DatabaseCatalog {
         }
     }
 
+    async fn list_dropped_tables(
+        &self,
+        tenant: &str,
+        db_name: &str,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while list dropped tables)",
+            ));
+        }
+
+        // The tables of the system databases are never dropped.
+        if self
+            .immutable_catalog
+            .exists_database(tenant, db_name)
+            .await?
+        {
+            return Ok(vec![]);
+        }
+        self.mutable_catalog
+            .list_dropped_tables(tenant, db_name)
+            .await
+    }
+
+    async fn remove_dropped_table(
+        &self,
+        req: RemoveDroppedTableReq,
+    ) -> Result<RemoveDroppedTableReply> {
+        self.mutable_catalog.remove_dropped_table(req).await
+    }
+
     async fn create_table(&self, req: CreateTableReq) -> Result<()> {
         if req.tenant().is_empty() {
             return Err(ErrorCode::TenantIsEmpty(
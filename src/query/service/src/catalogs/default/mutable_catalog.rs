@@ -35,6 +35,9 @@ use common_meta_app::schema::GetDatabaseReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::ListDatabaseReq;
+use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::RemoveDroppedTableReply;
+use common_meta_app::schema::RemoveDroppedTableReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
@@ -242,6 +245,30 @@ impl Catalog for MutableCatalog {
         db.list_tables_history().await
     }
 
+    async fn list_dropped_tables(
+        &self,
+        tenant: &str,
+        db_name: &str,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        let table_infos = self
+            .ctx
+            .meta
+            .get_drop_table_infos(ListTableReq::new(tenant, db_name))
+            .await?;
+        table_infos
+            .iter()
+            .map(|info| self.get_table_by_info(info.as_ref()))
+            .collect()
+    }
+
+    async fn remove_dropped_table(
+        &self,
+        req: RemoveDroppedTableReq,
+    ) -> Result<RemoveDroppedTableReply> {
+        let res = self.ctx.meta.remove_dropped_table(req).await?;
+        Ok(res)
+    }
+
     async fn create_table(&self, req: CreateTableReq) -> Result<()> {
         let db = self
             .get_database(&req.name_ident.tenant, &req.name_ident.db_name)
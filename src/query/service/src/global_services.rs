@@ -29,6 +29,8 @@ use storages_common_cache_manager::CacheManager;
 use crate::api::DataExchangeManager;
 use crate::catalogs::CatalogManagerHelper;
 use crate::clusters::ClusterDiscovery;
+use crate::interpreters::QueryResultCache;
+use crate::maintenance::BackgroundMaintenance;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
 
@@ -62,6 +64,7 @@ impl GlobalServices {
         )?;
 
         CacheManager::init(&config.query)?;
+        QueryResultCache::init()?;
         CatalogManager::init(&config).await?;
         HttpQueryManager::init(&config).await?;
         DataExchangeManager::init()?;
@@ -74,6 +77,7 @@ impl GlobalServices {
         )
         .await?;
         RoleCacheManager::init()?;
+        BackgroundMaintenance::init(&config)?;
 
         Ok(())
     }
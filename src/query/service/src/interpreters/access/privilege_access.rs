@@ -160,6 +160,27 @@ impl AccessChecker for PrivilegeAccess {
                     .await?;
             }
             Plan::AnalyzeTable(_) => {}
+            Plan::VacuumTable(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Super,
+                    )
+                    .await?;
+            }
+            Plan::VacuumDropTable(plan) => {
+                let object = match &plan.database {
+                    Some(database) => GrantObject::Database(plan.catalog.clone(), database.clone()),
+                    None => GrantObject::Global,
+                };
+                session
+                    .validate_privilege(&object, UserPrivilegeType::Super)
+                    .await?;
+            }
             Plan::ExistsTable(_) => {}
 
             // Others.
@@ -223,6 +244,90 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        UserPrivilegeType::Create,
+                    )
+                    .await?;
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.viewname.clone(),
+                        ),
+                        UserPrivilegeType::Insert,
+                    )
+                    .await?;
+            }
+            Plan::CreateInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::CreateVirtualColumn(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::DropVirtualColumn(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::DropInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::CreateStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        UserPrivilegeType::Create,
+                    )
+                    .await?;
+            }
+            Plan::DropStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        UserPrivilegeType::Drop,
+                    )
+                    .await?;
+            }
             Plan::AlterUser(_) => {}
             Plan::CreateUser(_) => {}
             Plan::DropUser(_) => {}
@@ -266,6 +371,42 @@ impl AccessChecker for PrivilegeAccess {
                     .validate_privilege(&GrantObject::Global, UserPrivilegeType::Alter)
                     .await?;
             }
+            Plan::CreateSnapshotRef(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::DropSnapshotRef(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::FastForwardTable(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
         }
 
         Ok(())
@@ -13,7 +13,13 @@
 // limitations under the License.
 
 mod grant;
+mod query_result_cache;
 mod table;
 
 pub use grant::validate_grant_object_exists;
+pub use query_result_cache::QueryResult;
+pub use query_result_cache::QueryResultCache;
+pub use query_result_cache::QueryResultCacheWriter;
+pub use query_result_cache::MAX_QUERY_RESULT_CACHE_BYTES;
 pub use table::append2table;
+pub use table::hook_maintenance;
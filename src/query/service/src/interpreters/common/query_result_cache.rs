@@ -0,0 +1,124 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::GlobalInstance;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache::InMemoryCacheBuilder;
+use storages_common_cache::InMemoryItemCacheHolder;
+
+/// The max number of results kept in the query result cache.
+static DEFAULT_QUERY_RESULT_CACHE_ITEMS: u64 = 1024;
+
+/// The max size in bytes of a cacheable query result.
+pub static MAX_QUERY_RESULT_CACHE_BYTES: usize = 16 * 1024 * 1024;
+
+/// The result of a query kept in the query result cache.
+pub struct QueryResult {
+    pub tenant: String,
+    /// The identity of the user who ran the query, only the same user can scan the result.
+    pub user: String,
+    pub schema: DataSchemaRef,
+    pub blocks: Vec<DataBlock>,
+    pub expire_at: Instant,
+}
+
+impl QueryResult {
+    fn is_expired(&self) -> bool {
+        Instant::now() >= self.expire_at
+    }
+}
+
+/// In memory cache of the results of queries.
+///
+/// A result is keyed by the normalized SQL, the changed settings and the snapshots of the
+/// tables read by the query, so it is never reused after any of the tables change. It can
+/// also be read back by the id of the query with `RESULT_SCAN(query_id)`.
+pub struct QueryResultCache {
+    results: InMemoryItemCacheHolder<QueryResult>,
+    /// The results keyed by the ids of the queries which produced or reused them.
+    query_results: InMemoryItemCacheHolder<QueryResult>,
+}
+
+impl QueryResultCache {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(QueryResultCache {
+            results: InMemoryCacheBuilder::new_item_cache(DEFAULT_QUERY_RESULT_CACHE_ITEMS),
+            query_results: InMemoryCacheBuilder::new_item_cache(DEFAULT_QUERY_RESULT_CACHE_ITEMS),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<QueryResultCache> {
+        GlobalInstance::get()
+    }
+
+    pub fn get(&self, key: &str) -> Option<Arc<QueryResult>> {
+        let result = self.results.get(key)?;
+        if result.is_expired() {
+            self.results.evict(key);
+            return None;
+        }
+        Some(result)
+    }
+
+    pub fn get_by_query_id(&self, query_id: &str) -> Option<Arc<QueryResult>> {
+        let result = self.query_results.get(query_id)?;
+        if result.is_expired() {
+            self.query_results.evict(query_id);
+            return None;
+        }
+        Some(result)
+    }
+
+    pub fn put(&self, key: String, query_id: String, result: Arc<QueryResult>) {
+        self.query_results.put(query_id, result.clone());
+        self.results.put(key, result);
+    }
+
+    /// Record that the query `query_id` reused `result`, so it can be scanned by the id.
+    pub fn put_query_result(&self, query_id: String, result: Arc<QueryResult>) {
+        self.query_results.put(query_id, result);
+    }
+}
+
+/// Puts the result of a query into the cache once it is complete.
+#[derive(Clone)]
+pub struct QueryResultCacheWriter {
+    pub key: String,
+    pub query_id: String,
+    pub tenant: String,
+    pub user: String,
+    pub schema: DataSchemaRef,
+    pub ttl: Duration,
+}
+
+impl QueryResultCacheWriter {
+    pub fn write(self, blocks: Vec<DataBlock>) {
+        let result = QueryResult {
+            tenant: self.tenant,
+            user: self.user,
+            schema: self.schema,
+            blocks,
+            expire_at: Instant::now() + self.ttl,
+        };
+        QueryResultCache::instance().put(self.key, self.query_id, Arc::new(result));
+    }
+}
@@ -22,6 +22,8 @@ use common_exception::Result;
 use common_expression::DataSchemaRef;
 use common_pipeline_core::Pipeline;
 
+use crate::maintenance::BackgroundMaintenance;
+use crate::maintenance::MaintenanceTarget;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -88,3 +90,18 @@ pub fn append2table(
 
     Ok(())
 }
+
+/// Notifies the background maintenance of the table once the pipeline committed to it.
+pub fn hook_maintenance(catalog: &str, database: &str, table: &str, pipeline: &mut Pipeline) {
+    let target = MaintenanceTarget {
+        catalog: catalog.to_string(),
+        database: database.to_string(),
+        table: table.to_string(),
+    };
+    pipeline.set_on_finished(move |may_error| {
+        if may_error.is_none() {
+            BackgroundMaintenance::instance().notify_commit(target.clone());
+        }
+        Ok(())
+    });
+}
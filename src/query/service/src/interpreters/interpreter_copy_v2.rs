@@ -27,6 +27,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::infer_table_schema;
 use common_expression::DataField;
+use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::TableCopiedFileInfo;
@@ -40,8 +41,10 @@ use tracing::error;
 use tracing::info;
 
 use crate::interpreters::common::append2table;
+use crate::interpreters::common::hook_maintenance;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreterV2;
+use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -343,6 +346,27 @@ impl CopyInterpreterV2 {
             )?;
         }
 
+        // Compute the computed columns, which are not in the loaded files.
+        if to_table
+            .schema()
+            .fields()
+            .iter()
+            .any(|f| f.computed_expr().is_some())
+        {
+            let source_schema: DataSchemaRef = Arc::new(stage_table_info.schema.as_ref().into());
+            build_res.main_pipeline.add_transform(
+                |transform_input_port, transform_output_port| {
+                    TransformResortAddOn::try_create(
+                        ctx.clone(),
+                        transform_input_port,
+                        transform_output_port,
+                        source_schema.clone(),
+                        to_table.clone(),
+                    )
+                },
+            )?;
+        }
+
         // Build append data pipeline.
         to_table.append_data(
             ctx.clone(),
@@ -454,6 +478,12 @@ impl CopyInterpreterV2 {
             Err(may_error.as_ref().unwrap().clone())
         });
 
+        hook_maintenance(
+            catalog_name,
+            database_name,
+            table_name,
+            &mut build_res.main_pipeline,
+        );
         Ok(build_res)
     }
 }
@@ -29,7 +29,10 @@ use crate::interpreters::interpreter_copy_v2::CopyInterpreterV2;
 use crate::interpreters::interpreter_presign::PresignInterpreter;
 use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
 use crate::interpreters::interpreter_table_create_v2::CreateTableInterpreterV2;
+use crate::interpreters::interpreter_table_fast_forward::FastForwardTableInterpreter;
 use crate::interpreters::interpreter_table_revert::RevertTableInterpreter;
+use crate::interpreters::interpreter_table_snapshot_ref_create::CreateSnapshotRefInterpreter;
+use crate::interpreters::interpreter_table_snapshot_ref_drop::DropSnapshotRefInterpreter;
 use crate::interpreters::AlterUserInterpreter;
 use crate::interpreters::CreateShareInterpreter;
 use crate::interpreters::DropShareInterpreter;
@@ -177,6 +180,13 @@ impl InterpreterFactory {
                 ctx,
                 *analyze_table.clone(),
             )?)),
+            Plan::VacuumTable(vacuum_table) => Ok(Arc::new(VacuumTableInterpreter::try_create(
+                ctx,
+                *vacuum_table.clone(),
+            )?)),
+            Plan::VacuumDropTable(vacuum_drop_table) => Ok(Arc::new(
+                VacuumDropTableInterpreter::try_create(ctx, *vacuum_drop_table.clone())?,
+            )),
             Plan::ExistsTable(exists_table) => Ok(Arc::new(ExistsTableInterpreter::try_create(
                 ctx,
                 *exists_table.clone(),
@@ -195,6 +205,38 @@ impl InterpreterFactory {
                 ctx,
                 *drop_view.clone(),
             )?)),
+            Plan::CreateMaterializedView(create_view) => Ok(Arc::new(
+                CreateMaterializedViewInterpreter::try_create(ctx, *create_view.clone())?,
+            )),
+            Plan::RefreshMaterializedView(refresh_view) => Ok(Arc::new(
+                RefreshMaterializedViewInterpreter::try_create(ctx, *refresh_view.clone())?,
+            )),
+
+            // Indexes
+            Plan::CreateInvertedIndex(create_index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *create_index.clone())?,
+            )),
+            Plan::DropInvertedIndex(drop_index) => Ok(Arc::new(
+                DropInvertedIndexInterpreter::try_create(ctx, *drop_index.clone())?,
+            )),
+
+            // Virtual columns
+            Plan::CreateVirtualColumn(create_virtual_column) => Ok(Arc::new(
+                CreateVirtualColumnInterpreter::try_create(ctx, *create_virtual_column.clone())?,
+            )),
+            Plan::DropVirtualColumn(drop_virtual_column) => Ok(Arc::new(
+                DropVirtualColumnInterpreter::try_create(ctx, *drop_virtual_column.clone())?,
+            )),
+
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
+                ctx,
+                *create_stream.clone(),
+            )?)),
+            Plan::DropStream(drop_stream) => Ok(Arc::new(DropStreamInterpreter::try_create(
+                ctx,
+                *drop_stream.clone(),
+            )?)),
 
             // Users
             Plan::CreateUser(create_user) => Ok(Arc::new(CreateUserInterpreter::try_create(
@@ -340,6 +382,18 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateSnapshotRef(p) => Ok(Arc::new(CreateSnapshotRefInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropSnapshotRef(p) => Ok(Arc::new(DropSnapshotRefInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::FastForwardTable(p) => Ok(Arc::new(FastForwardTableInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
         }
     }
 }
@@ -76,6 +76,7 @@ use common_sql::NameResolutionContext;
 use common_sql::ScalarBinder;
 use common_storages_factory::Table;
 use common_storages_fuse::io::Files;
+use common_storages_fuse::FuseTable;
 use common_storages_stage::StageTable;
 use common_users::UserApiProvider;
 use parking_lot::Mutex;
@@ -84,6 +85,7 @@ use tracing::error;
 use tracing::info;
 
 use crate::interpreters::common::append2table;
+use crate::interpreters::common::hook_maintenance;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
@@ -352,10 +354,13 @@ impl Interpreter for InsertInterpreterV2 {
 
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let plan = &self.plan;
-        let table = self
+        let mut table = self
             .ctx
             .get_table(&plan.catalog, &plan.database, &plan.table)
             .await?;
+        if let Some(branch) = &plan.branch {
+            table = FuseTable::try_from_table(table.as_ref())?.with_branch(branch)?;
+        }
 
         let mut build_res = PipelineBuildResult::create();
 
@@ -405,6 +410,12 @@ impl Interpreter for InsertInterpreterV2 {
                         &mut build_res.main_pipeline,
                     )
                     .await?;
+                    hook_maintenance(
+                        &self.plan.catalog,
+                        &self.plan.database,
+                        &self.plan.table,
+                        &mut build_res.main_pipeline,
+                    );
                     return Ok(build_res);
                 }
                 InsertInputSource::SelectPlan(plan) => {
@@ -480,6 +491,12 @@ impl Interpreter for InsertInterpreterV2 {
                         Err(may_error.as_ref().unwrap().clone())
                     });
 
+                    hook_maintenance(
+                        &self.plan.catalog,
+                        &self.plan.database,
+                        &self.plan.table,
+                        &mut build_res.main_pipeline,
+                    );
                     return Ok(build_res);
                 }
             };
@@ -501,6 +518,12 @@ impl Interpreter for InsertInterpreterV2 {
             append_mode,
         )?;
 
+        hook_maintenance(
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.table,
+            &mut build_res.main_pipeline,
+        );
         Ok(build_res)
     }
 
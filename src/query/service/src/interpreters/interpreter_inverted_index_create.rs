@@ -0,0 +1,96 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateInvertedIndexPlan;
+use storages_common_table_meta::table::InvertedIndexDefinition;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEXES;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateInvertedIndexPlan,
+}
+
+impl CreateInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateInvertedIndexPlan) -> Result<Self> {
+        Ok(CreateInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateInvertedIndexInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+
+        let mut indexes = InvertedIndexDefinition::from_table_options(table.options())?;
+        if indexes.iter().any(|index| index.name == plan.index_name) {
+            return if plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::IndexAlreadyExists(format!(
+                    "inverted index {} already exists on {}.{}",
+                    plan.index_name, plan.database, plan.table
+                )))
+            };
+        }
+        // A column is tokenized by at most one tokenizer, so that its terms are unambiguous.
+        for column in &plan.columns {
+            if let Some(index) = indexes.iter().find(|index| index.columns.contains(column)) {
+                return Err(ErrorCode::IndexAlreadyExists(format!(
+                    "column {} is already indexed by inverted index {}",
+                    column, index.name
+                )));
+            }
+        }
+        indexes.push(InvertedIndexDefinition {
+            name: plan.index_name.clone(),
+            columns: plan.columns.clone(),
+            tokenizer: plan.tokenizer.clone(),
+        });
+
+        let mut options = HashMap::with_capacity(1);
+        options.insert(
+            OPT_KEY_INVERTED_INDEXES.to_string(),
+            Some(InvertedIndexDefinition::to_table_option(&indexes)?),
+        );
+        catalog
+            .upsert_table_option(&plan.tenant, &plan.database, UpsertTableOptionReq {
+                table_id: table.get_id(),
+                seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+                options,
+            })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CreateMaterializedViewPlan;
+
+use crate::interpreters::refresh_materialized_view;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        // check whether view has exists
+        if catalog
+            .list_tables(&self.plan.tenant, &self.plan.database)
+            .await?
+            .iter()
+            .any(|table| table.name() == self.plan.viewname.as_str())
+        {
+            if self.plan.if_not_exists {
+                return Ok(PipelineBuildResult::create());
+            }
+            return Err(ErrorCode::ViewAlreadyExists(format!(
+                "{}.{} as view Already Exists",
+                self.plan.database, self.plan.viewname
+            )));
+        }
+
+        let req = CreateTableReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.viewname.clone(),
+            },
+            table_meta: TableMeta {
+                schema: self.plan.schema.clone(),
+                engine: "FUSE".to_string(),
+                options: self.plan.options.clone(),
+                ..Default::default()
+            },
+            dependency: None,
+        };
+        catalog.create_table(req).await?;
+
+        // Populate the view with the rows of the current snapshot of the source table.
+        refresh_materialized_view(
+            &self.ctx,
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.viewname,
+            &self.plan.definition,
+        )
+        .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
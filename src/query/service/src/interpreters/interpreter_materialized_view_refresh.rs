@@ -0,0 +1,279 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_meta_types::MetaId;
+use common_sql::plans::MaterializedViewDefinition;
+use common_sql::plans::Plan;
+use common_sql::plans::RefreshMaterializedViewPlan;
+use common_sql::Planner;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::interpreters::common::append2table;
+use crate::interpreters::Interpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::QueryContextShared;
+use crate::sessions::TableContext;
+use crate::sql::executor::PhysicalPlan;
+use crate::sql::executor::PhysicalPlanBuilder;
+use crate::sql::executor::PhysicalPlanReplacer;
+use crate::sql::executor::TableScan;
+use crate::sql::BindContext;
+
+pub struct RefreshMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+}
+
+impl RefreshMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshMaterializedViewPlan) -> Result<Self> {
+        Ok(RefreshMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "RefreshMaterializedViewInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        refresh_materialized_view(
+            &self.ctx,
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.viewname,
+            &self.plan.definition,
+        )
+        .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+/// Refresh the rows of the materialized view `catalog.database.viewname` to the latest snapshot
+/// of its source table.
+///
+/// If the source table has only been appended to since the last refresh, the rows of the new
+/// segments are aggregated and merged into the view, otherwise the view is recomputed.
+pub async fn refresh_materialized_view(
+    ctx: &Arc<QueryContext>,
+    catalog: &str,
+    database: &str,
+    viewname: &str,
+    definition: &MaterializedViewDefinition,
+) -> Result<()> {
+    // The tables are cached by the query context, use a new one to read the latest versions.
+    let shared = QueryContextShared::try_create(
+        &GlobalConfig::instance(),
+        ctx.get_current_session(),
+        ctx.get_cluster(),
+    )?;
+    let ctx = QueryContext::create_from_shared(shared);
+
+    let view = ctx.get_table(catalog, database, viewname).await?;
+    let source = ctx
+        .get_table(&definition.catalog, &definition.database, &definition.table)
+        .await?;
+    let source_id = source.get_id();
+    if view
+        .options()
+        .get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID)
+        != Some(&source_id.to_string())
+    {
+        return Err(ErrorCode::UnknownTable(format!(
+            "the source table {}.{} of materialized view {}.{} has been dropped or replaced",
+            definition.database, definition.table, database, viewname
+        )));
+    }
+
+    let source_snapshot = source.options().get(OPT_KEY_SNAPSHOT_LOCATION).cloned();
+    let view_snapshot = view
+        .options()
+        .get(OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT_LOCATION)
+        .cloned();
+    if source_snapshot == view_snapshot {
+        return Ok(());
+    }
+
+    let column_names = view
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.name().clone())
+        .collect::<Vec<_>>();
+
+    let mut refreshed = None;
+    if let (Some(_), Some(since_location)) = (&source_snapshot, &view_snapshot) {
+        let sql = definition.merge_query(catalog, database, viewname, &column_names);
+        refreshed = build_incremental_refresh(&ctx, source.as_ref(), since_location, &sql).await?;
+    }
+    let mut build_res = match refreshed {
+        Some(build_res) => build_res,
+        None => {
+            let sql = definition.query(&column_names);
+            let (plan, bind_context) = build_physical_plan(&ctx, &sql).await?;
+            build_query_pipeline(&ctx, &bind_context.columns, &plan, false).await?
+        }
+    };
+
+    // The snapshot of the source table is recorded in the same table meta as the rows refreshed.
+    let mut commit_options = BTreeMap::new();
+    if let Some(source_snapshot) = source_snapshot {
+        commit_options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT_LOCATION.to_string(),
+            source_snapshot,
+        );
+    }
+    let view = FuseTable::try_from_table(view.as_ref())?.with_commit_options(commit_options)?;
+    append2table(
+        ctx.clone(),
+        view.clone(),
+        Arc::new(DataSchema::from(view.schema())),
+        &mut build_res,
+        true,
+        true,
+        AppendMode::Normal,
+    )?;
+    execute_pipeline(&ctx, build_res).await
+}
+
+/// Build the pipeline of the merge query of the view, with the scan of the source table
+/// restricted to the segments appended since `since_location`.
+///
+/// Returns `None` if the source table has been changed other than appended to.
+async fn build_incremental_refresh(
+    ctx: &Arc<QueryContext>,
+    source: &dyn Table,
+    since_location: &str,
+    sql: &str,
+) -> Result<Option<PipelineBuildResult>> {
+    let (plan, bind_context) = build_physical_plan(ctx, sql).await?;
+
+    let table_id = source.get_id();
+    let mut push_downs = None;
+    PhysicalPlan::traverse(
+        &plan,
+        &mut |_| true,
+        &mut |plan| {
+            if let PhysicalPlan::TableScan(scan) = plan {
+                if scan_table_id(scan) == Some(table_id) {
+                    push_downs = scan.source.push_downs.clone();
+                }
+            }
+        },
+        &mut |_| {},
+    );
+
+    let fuse_table = FuseTable::try_from_table(source)?;
+    let (statistics, parts) = match fuse_table
+        .read_appended_partitions(ctx.clone(), push_downs, since_location)
+        .await?
+    {
+        Some(appended) => appended,
+        None => return Ok(None),
+    };
+
+    let mut replacer = ReplaceSourcePartitions {
+        table_id,
+        statistics,
+        parts,
+    };
+    let plan = replacer.replace(&plan)?;
+    let build_res = build_query_pipeline(ctx, &bind_context.columns, &plan, false).await?;
+    Ok(Some(build_res))
+}
+
+async fn build_physical_plan(
+    ctx: &Arc<QueryContext>,
+    sql: &str,
+) -> Result<(PhysicalPlan, BindContext)> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner.plan_sql(sql).await?;
+    match plan {
+        Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } => {
+            let builder = PhysicalPlanBuilder::new(metadata, ctx.clone());
+            let physical_plan = builder.build(&s_expr).await?;
+            Ok((physical_plan, *bind_context))
+        }
+        _ => Err(ErrorCode::Internal(format!(
+            "the query of materialized view is not a query: {}",
+            sql
+        ))),
+    }
+}
+
+async fn execute_pipeline(
+    ctx: &Arc<QueryContext>,
+    mut build_res: PipelineBuildResult,
+) -> Result<()> {
+    let settings = ctx.get_settings();
+    build_res.set_max_threads(settings.get_max_threads()? as usize);
+    let executor_settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+
+    let mut pipelines = build_res.sources_pipelines;
+    pipelines.push(build_res.main_pipeline);
+    let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+
+    ctx.set_executor(Arc::downgrade(&executor.get_inner()));
+    executor.execute_async().await
+}
+
+fn scan_table_id(scan: &TableScan) -> Option<MetaId> {
+    match &scan.source.source_info {
+        DataSourceInfo::TableSource(table_info) => Some(table_info.ident.table_id),
+        _ => None,
+    }
+}
+
+/// Replace the partitions of the scans of the source table.
+struct ReplaceSourcePartitions {
+    table_id: MetaId,
+    statistics: PartStatistics,
+    parts: Partitions,
+}
+
+impl PhysicalPlanReplacer for ReplaceSourcePartitions {
+    fn replace_table_scan(&mut self, plan: &TableScan) -> Result<PhysicalPlan> {
+        let mut plan = plan.clone();
+        if scan_table_id(&plan) == Some(self.table_id) {
+            plan.source.parts = self.parts.clone();
+            plan.source.statistics = self.statistics.clone();
+        }
+        Ok(PhysicalPlan::TableScan(plan))
+    }
+}
@@ -12,13 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::sync::Arc;
+use std::time::Duration;
 
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::tokenize_sql;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 use common_sql::MetadataRef;
+use common_storages_fuse::FuseTable;
+use parking_lot::Mutex;
 
 use crate::interpreters::Interpreter;
+use crate::interpreters::QueryResultCache;
+use crate::interpreters::QueryResultCacheWriter;
+use crate::pipelines::processors::BlocksSource;
+use crate::pipelines::processors::TransformWriteResultCache;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline;
 use crate::sessions::QueryContext;
@@ -64,6 +74,118 @@ impl SelectInterpreterV2 {
         )
         .await
     }
+
+    /// Build the pipeline which reads the result from the query result cache if it is cached,
+    /// otherwise puts the result into the cache after the query finishes.
+    async fn build_pipeline_with_result_cache(&self, key: String) -> Result<PipelineBuildResult> {
+        let cache = QueryResultCache::instance();
+        if let Some(result) = cache.get(&key) {
+            cache.put_query_result(self.ctx.get_id(), result.clone());
+
+            let blocks = Arc::new(Mutex::new(VecDeque::from(result.blocks.clone())));
+            let mut build_res = PipelineBuildResult::create();
+            build_res.main_pipeline.add_source(
+                |output| BlocksSource::create(self.ctx.clone(), output, blocks.clone()),
+                1,
+            )?;
+            return Ok(build_res);
+        }
+
+        let mut build_res = self.build_pipeline().await?;
+        let settings = self.ctx.get_settings();
+        let writer = QueryResultCacheWriter {
+            key,
+            query_id: self.ctx.get_id(),
+            tenant: self.ctx.get_tenant(),
+            user: self.ctx.get_current_user()?.identity().to_string(),
+            schema: self.schema(),
+            ttl: Duration::from_secs(settings.get_query_result_cache_ttl()?),
+        };
+        build_res.main_pipeline.resize(1)?;
+        build_res.main_pipeline.add_transform(|input, output| {
+            Ok(TransformWriteResultCache::create(
+                input,
+                output,
+                writer.clone(),
+            ))
+        })?;
+        Ok(build_res)
+    }
+
+    /// The key of the query in the query result cache, or `None` if its result can't be cached,
+    /// e.g. it reads tables other than fuse tables.
+    async fn result_cache_key(&self) -> Result<Option<String>> {
+        let tables = {
+            let metadata = self.metadata.read();
+            if metadata.is_non_deterministic() {
+                return Ok(None);
+            }
+            metadata
+                .tables()
+                .iter()
+                .map(|entry| entry.table())
+                .collect::<Vec<_>>()
+        };
+
+        let mut snapshots = Vec::with_capacity(tables.len());
+        for table in tables {
+            let fuse_table = match table.as_any().downcast_ref::<FuseTable>() {
+                Some(fuse_table) => fuse_table,
+                None => return Ok(None),
+            };
+            let snapshot_id = fuse_table
+                .read_table_snapshot()
+                .await?
+                .map(|snapshot| snapshot.snapshot_id.simple().to_string())
+                .unwrap_or_default();
+            snapshots.push(format!("{}:{}", table.get_id(), snapshot_id));
+        }
+        snapshots.sort();
+        snapshots.dedup();
+
+        // Normalize the SQL by the tokens, regardless of the whitespaces and comments.
+        let sql = match tokenize_sql(&self.ctx.get_query_str()) {
+            Ok(tokens) => tokens
+                .iter()
+                .filter(|token| token.kind != TokenKind::EOI)
+                .map(|token| token.text())
+                .collect::<Vec<_>>()
+                .join(" "),
+            Err(_) => return Ok(None),
+        };
+        if sql.is_empty() {
+            return Ok(None);
+        }
+
+        let settings = self
+            .ctx
+            .get_settings()
+            .get_setting_values()
+            .into_iter()
+            .filter(|(name, value, default_value, _, _)| {
+                value != default_value
+                    && name != "enable_query_result_cache"
+                    && name != "query_result_cache_ttl"
+            })
+            .map(|(name, value, _, _, _)| format!("{}={}", name, value))
+            .collect::<Vec<_>>();
+
+        let role = self
+            .ctx
+            .get_current_role()
+            .map(|role| role.name)
+            .unwrap_or_default();
+        Ok(Some(format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            self.ctx.get_tenant(),
+            self.ctx.get_current_user()?.identity(),
+            role,
+            self.ctx.get_current_database(),
+            settings.join(","),
+            snapshots.join(","),
+            sql
+        )))
+    }
 }
 
 #[async_trait::async_trait]
@@ -80,6 +202,12 @@ impl Interpreter for SelectInterpreterV2 {
     /// The QueryPipelineBuilder will use the optimized plan to generate a Pipeline
     #[tracing::instrument(level = "debug", name = "select_interpreter_v2_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if !self.ignore_result && self.ctx.get_settings().get_enable_query_result_cache()? {
+            if let Some(key) = self.result_cache_key().await? {
+                return self.build_pipeline_with_result_cache(key).await;
+            }
+        }
+
         let build_res = self.build_pipeline().await?;
         Ok(build_res)
     }
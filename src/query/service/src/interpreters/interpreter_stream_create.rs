@@ -0,0 +1,87 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableDependency;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CreateStreamPlan;
+use storages_common_table_meta::table::OPT_KEY_DEPENDENT_TABLE_IDS;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_TABLE_ID;
+use storages_common_table_meta::table::STREAM_ENGINE;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateStreamInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateStreamPlan,
+}
+
+impl CreateStreamInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateStreamPlan) -> Result<Self> {
+        Ok(CreateStreamInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateStreamInterpreter {
+    fn name(&self) -> &str {
+        "CreateStreamInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        // The stream is registered on its source table, whose purge keeps the snapshots since
+        // the offset of the stream.
+        let source_table_id = self
+            .plan
+            .options
+            .get(OPT_KEY_STREAM_SOURCE_TABLE_ID)
+            .and_then(|id| id.parse::<u64>().ok())
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "stream {} has no valid source table id",
+                    self.plan.stream_name
+                ))
+            })?;
+        let req = CreateTableReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.stream_name.clone(),
+            },
+            table_meta: TableMeta {
+                schema: self.plan.schema.clone(),
+                engine: STREAM_ENGINE.to_string(),
+                options: self.plan.options.clone(),
+                ..Default::default()
+            },
+            dependency: Some(TableDependency {
+                table_ids: vec![source_table_id],
+                option_key: OPT_KEY_DEPENDENT_TABLE_IDS.to_owned(),
+            }),
+        };
+        catalog.create_table(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
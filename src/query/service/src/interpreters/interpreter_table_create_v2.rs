@@ -19,10 +19,16 @@ use common_exception::Result;
 use common_expression::TableDataType;
 use common_expression::TableSchemaRefExt;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableDependency;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CloneTableSource;
 use common_sql::plans::CreateTablePlanV2;
+use common_storages_fuse::FuseTable;
 use common_users::UserApiProvider;
+use storages_common_table_meta::table::OPT_KEY_CLONE_SOURCE_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_CLONE_SOURCE_TABLE_IDS;
+use storages_common_table_meta::table::OPT_KEY_DEPENDENT_TABLE_IDS;
 
 use crate::interpreters::InsertInterpreterV2;
 use crate::interpreters::Interpreter;
@@ -99,6 +105,10 @@ impl Interpreter for CreateTableInterpreterV2 {
             }
         }
 
+        if let Some(clone_source) = &self.plan.clone_source {
+            return self.create_table_clone(clone_source).await;
+        }
+
         match &self.plan.as_select {
             Some(select_plan_node) => self.create_table_as_select(select_plan_node.clone()).await,
             None => self.create_table().await,
@@ -133,6 +143,7 @@ impl CreateTableInterpreterV2 {
             table_id: table.get_id(),
             schema: self.plan.schema.clone(),
             overwrite: false,
+            branch: None,
             source: InsertInputSource::SelectPlan(select_plan),
         };
 
@@ -148,6 +159,92 @@ impl CreateTableInterpreterV2 {
         Ok(PipelineBuildResult::create())
     }
 
+    /// `CREATE TABLE ... CLONE`: the first snapshot of the new table refers to the segments of
+    /// the source table at the cloned point, no data is copied.
+    async fn create_table_clone(
+        &self,
+        clone_source: &CloneTableSource,
+    ) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        if self.plan.if_not_exists
+            && catalog
+                .exists_table(&tenant, &self.plan.database, &self.plan.table)
+                .await?
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let source_table = self
+            .ctx
+            .get_table(
+                &clone_source.catalog,
+                &clone_source.database,
+                &clone_source.table,
+            )
+            .await?;
+        let source_table = match &clone_source.point {
+            Some(point) => source_table.navigate_to(point).await?,
+            None => source_table,
+        };
+
+        let source_table = FuseTable::try_from_table(source_table.as_ref())?;
+
+        // The clone is registered on the tables it shares the files of, so that their purge
+        // keeps the files, even before the first snapshot of the clone is committed.
+        let mut req = self.build_request()?;
+        if let Some(location) = source_table.snapshot_loc().await? {
+            req.table_meta
+                .options
+                .insert(OPT_KEY_CLONE_SOURCE_SNAPSHOT_LOCATION.to_owned(), location);
+        }
+        req.dependency = Some(TableDependency {
+            table_ids: self.clone_dependency_table_ids(&req.table_meta).await?,
+            option_key: OPT_KEY_DEPENDENT_TABLE_IDS.to_owned(),
+        });
+        catalog.create_table(req).await?;
+
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+            .await?;
+        FuseTable::try_from_table(table.as_ref())?
+            .do_clone(self.ctx.as_ref(), source_table)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+
+    /// The tables a clone shares the files of: the source table, and the tables the source
+    /// table was cloned from, if they are not removed yet.
+    async fn clone_dependency_table_ids(&self, table_meta: &TableMeta) -> Result<Vec<u64>> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let ids = table_meta
+            .options
+            .get(OPT_KEY_CLONE_SOURCE_TABLE_IDS)
+            .map(|ids| ids.split(',').collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let mut table_ids = Vec::with_capacity(ids.len());
+        for (i, id) in ids.into_iter().enumerate() {
+            let table_id = id.parse::<u64>().map_err(|e| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "invalid table id '{id}' in {OPT_KEY_CLONE_SOURCE_TABLE_IDS}: {e}"
+                ))
+            })?;
+            // the source table is required, while the tables it was cloned from may have
+            // been dropped and removed.
+            if i > 0 {
+                match catalog.get_table_meta_by_id(table_id).await {
+                    Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => continue,
+                    Err(e) => return Err(e),
+                    Ok(_) => {}
+                }
+            }
+            table_ids.push(table_id);
+        }
+        Ok(table_ids)
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
@@ -189,6 +286,7 @@ impl CreateTableInterpreterV2 {
                 table_name: self.plan.table.to_string(),
             },
             table_meta,
+            dependency: None,
         };
 
         Ok(req)
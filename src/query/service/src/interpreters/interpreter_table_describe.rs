@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::StringType;
+use common_expression::ComputedExpr;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
 use common_expression::FromData;
@@ -98,7 +99,12 @@ impl Interpreter for DescribeTableInterpreter {
                     default_exprs.push(value.to_string().as_bytes().to_vec());
                 }
             }
-            extras.push("".to_string().as_bytes().to_vec());
+            let extra = match field.computed_expr() {
+                Some(ComputedExpr::Virtual(_)) => "VIRTUAL GENERATED",
+                Some(ComputedExpr::Stored(_)) => "STORED GENERATED",
+                None => "",
+            };
+            extras.push(extra.as_bytes().to_vec());
         }
 
         PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
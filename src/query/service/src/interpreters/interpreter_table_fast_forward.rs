@@ -0,0 +1,56 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::FastForwardTablePlan;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct FastForwardTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: FastForwardTablePlan,
+}
+
+impl FastForwardTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: FastForwardTablePlan) -> Result<Self> {
+        Ok(FastForwardTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for FastForwardTableInterpreter {
+    fn name(&self) -> &str {
+        "FastForwardTableInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+
+        FuseTable::try_from_table(table.as_ref())?
+            .do_fast_forward(self.ctx.as_ref(), &plan.branch)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
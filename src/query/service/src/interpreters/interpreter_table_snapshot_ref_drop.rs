@@ -0,0 +1,76 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::DropSnapshotRefPlan;
+use storages_common_table_meta::table::SnapshotRef;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_REFS;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropSnapshotRefInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropSnapshotRefPlan,
+}
+
+impl DropSnapshotRefInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropSnapshotRefPlan) -> Result<Self> {
+        Ok(DropSnapshotRefInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropSnapshotRefInterpreter {
+    fn name(&self) -> &str {
+        "DropSnapshotRefInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+
+        let mut refs = SnapshotRef::from_table_options(table.options())?;
+        SnapshotRef::find(&refs, &plan.name, plan.kind)?;
+        refs.retain(|r| r.name != plan.name);
+
+        // The snapshot is no longer pinned, and will be purged as any other historical one.
+        let value = if refs.is_empty() {
+            None
+        } else {
+            Some(SnapshotRef::to_table_option(&refs)?)
+        };
+        let mut options = HashMap::with_capacity(1);
+        options.insert(OPT_KEY_SNAPSHOT_REFS.to_string(), value);
+        catalog
+            .upsert_table_option(&plan.tenant, &plan.database, UpsertTableOptionReq {
+                table_id: table.get_id(),
+                seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+                options,
+            })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Duration;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_sql::plans::VacuumTablePlan;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct VacuumTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: VacuumTablePlan,
+}
+
+impl VacuumTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: VacuumTablePlan) -> Result<Self> {
+        Ok(VacuumTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for VacuumTableInterpreter {
+    fn name(&self) -> &str {
+        "VacuumTableInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+        let retain_hours = match plan.option.retain_hours {
+            Some(hours) => hours,
+            None => self.ctx.get_settings().get_retention_period()?,
+        };
+
+        let ctx: Arc<dyn TableContext> = self.ctx.clone();
+        let files = FuseTable::try_from_table(table.as_ref())?
+            .do_vacuum(
+                &ctx,
+                Duration::hours(retain_hours as i64),
+                plan.option.dry_run,
+            )
+            .await?;
+
+        if !plan.option.dry_run {
+            return Ok(PipelineBuildResult::create());
+        }
+        let files: Vec<Vec<u8>> = files.into_iter().map(String::into_bytes).collect();
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(files),
+        ])])
+    }
+}
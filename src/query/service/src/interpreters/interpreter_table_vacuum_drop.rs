@@ -0,0 +1,105 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Duration;
+use chrono::Utc;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_sql::plans::VacuumDropTablePlan;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct VacuumDropTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: VacuumDropTablePlan,
+}
+
+impl VacuumDropTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: VacuumDropTablePlan) -> Result<Self> {
+        Ok(VacuumDropTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for VacuumDropTableInterpreter {
+    fn name(&self) -> &str {
+        "VacuumDropTableInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let databases = match &plan.database {
+            Some(database) => vec![database.clone()],
+            None => catalog
+                .list_databases(&tenant)
+                .await?
+                .iter()
+                .map(|database| database.name().to_string())
+                .collect(),
+        };
+        let retain_hours = match plan.option.retain_hours {
+            Some(hours) => hours,
+            None => self.ctx.get_settings().get_retention_period()?,
+        };
+        let retention_point = Utc::now() - Duration::hours(retain_hours as i64);
+
+        let ctx: Arc<dyn TableContext> = self.ctx.clone();
+        let mut database_names = vec![];
+        let mut table_names = vec![];
+        let mut files = vec![];
+        for database in databases {
+            for table in catalog.list_dropped_tables(&tenant, &database).await? {
+                let drop_on = table.get_table_info().meta.drop_on;
+                if !table.engine().eq_ignore_ascii_case("FUSE")
+                    || !drop_on.map_or(false, |drop_on| drop_on < retention_point)
+                {
+                    continue;
+                }
+                let removed = FuseTable::try_from_table(table.as_ref())?
+                    .do_vacuum_drop(&ctx, plan.option.dry_run)
+                    .await?;
+                database_names
+                    .extend(std::iter::repeat(database.clone().into_bytes()).take(removed.len()));
+                table_names.extend(
+                    std::iter::repeat(table.name().to_string().into_bytes()).take(removed.len()),
+                );
+                files.extend(removed.into_iter().map(String::into_bytes));
+            }
+        }
+
+        if !plan.option.dry_run {
+            return Ok(PipelineBuildResult::create());
+        }
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(database_names),
+            StringType::from_data(table_names),
+            StringType::from_data(files),
+        ])])
+    }
+}
@@ -87,7 +87,7 @@ impl Interpreter for UpdateInterpreter {
             },
         });
 
-        let schema: DataSchema = tbl.schema().into();
+        let schema: DataSchema = tbl.schema().remove_virtual_computed_fields().into();
         let update_list = self.plan.update_list.iter().try_fold(
             Vec::with_capacity(self.plan.update_list.len()),
             |mut acc, (id, scalar)| {
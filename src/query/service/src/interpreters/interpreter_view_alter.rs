@@ -120,6 +120,7 @@ impl AlterViewInterpreter {
                 options,
                 ..Default::default()
             },
+            dependency: None,
         };
         catalog.create_table(plan).await?;
 
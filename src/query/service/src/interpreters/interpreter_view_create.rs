@@ -104,6 +104,7 @@ impl CreateViewInterpreter {
                 options,
                 ..Default::default()
             },
+            dependency: None,
         };
         catalog.create_table(plan).await?;
 
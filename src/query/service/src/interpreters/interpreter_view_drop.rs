@@ -20,6 +20,7 @@ use common_meta_app::schema::DropTableReq;
 use common_meta_app::schema::TableNameIdent;
 use common_sql::plans::DropViewPlan;
 use common_storages_view::view_table::VIEW_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -54,7 +55,12 @@ impl Interpreter for DropViewInterpreter {
             .ok();
 
         if let Some(table) = &tbl {
-            if table.get_table_info().engine() != VIEW_ENGINE {
+            // A materialized view is stored as a fuse table with its defining query.
+            if table.get_table_info().engine() != VIEW_ENGINE
+                && !table
+                    .options()
+                    .contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+            {
                 return Err(ErrorCode::Internal(format!(
                     "{}.{} is not VIEW, please use `DROP TABLE {}.{}`",
                     &self.plan.database,
@@ -0,0 +1,95 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateVirtualColumnPlan;
+use storages_common_table_meta::table::VirtualColumnDefinition;
+use storages_common_table_meta::table::OPT_KEY_VIRTUAL_COLUMNS;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateVirtualColumnInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateVirtualColumnPlan,
+}
+
+impl CreateVirtualColumnInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateVirtualColumnPlan) -> Result<Self> {
+        Ok(CreateVirtualColumnInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateVirtualColumnInterpreter {
+    fn name(&self) -> &str {
+        "CreateVirtualColumnInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+
+        let mut virtual_columns = VirtualColumnDefinition::from_table_options(table.options())?;
+        let mut changed = false;
+        for definition in &plan.virtual_columns {
+            // A path has at most one virtual column, whatever type it is cast to.
+            if virtual_columns
+                .iter()
+                .any(|c| c.name() == definition.name())
+            {
+                if plan.if_not_exists {
+                    continue;
+                }
+                return Err(ErrorCode::VirtualColumnAlreadyExists(format!(
+                    "virtual column {} already exists on {}.{}",
+                    definition.name(),
+                    plan.database,
+                    plan.table
+                )));
+            }
+            virtual_columns.push(definition.clone());
+            changed = true;
+        }
+        if !changed {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let mut options = HashMap::with_capacity(1);
+        options.insert(
+            OPT_KEY_VIRTUAL_COLUMNS.to_string(),
+            Some(VirtualColumnDefinition::to_table_option(&virtual_columns)?),
+        );
+        catalog
+            .upsert_table_option(&plan.tenant, &plan.database, UpsertTableOptionReq {
+                table_id: table.get_id(),
+                seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+                options,
+            })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -32,8 +32,12 @@ mod interpreter_delete;
 mod interpreter_explain_v2;
 mod interpreter_factory;
 mod interpreter_insert_v2;
+mod interpreter_inverted_index_create;
+mod interpreter_inverted_index_drop;
 mod interpreter_kill;
 mod interpreter_list;
+mod interpreter_materialized_view_create;
+mod interpreter_materialized_view_refresh;
 mod interpreter_metrics;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
@@ -57,18 +61,25 @@ mod interpreter_share_show;
 mod interpreter_share_show_grant_tenants;
 mod interpreter_show_grants;
 mod interpreter_show_object_grant_privileges;
+mod interpreter_stream_create;
+mod interpreter_stream_drop;
 mod interpreter_table_analyze;
 mod interpreter_table_create_v2;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_exists;
+mod interpreter_table_fast_forward;
 mod interpreter_table_optimize;
 mod interpreter_table_recluster;
 mod interpreter_table_rename;
 mod interpreter_table_revert;
 mod interpreter_table_show_create;
+mod interpreter_table_snapshot_ref_create;
+mod interpreter_table_snapshot_ref_drop;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
+mod interpreter_table_vacuum;
+mod interpreter_table_vacuum_drop;
 mod interpreter_unsetting;
 mod interpreter_update;
 mod interpreter_use_database;
@@ -84,9 +95,15 @@ mod interpreter_user_udf_drop;
 mod interpreter_view_alter;
 mod interpreter_view_create;
 mod interpreter_view_drop;
+mod interpreter_virtual_column_create;
+mod interpreter_virtual_column_drop;
 
 pub use access::ManagementModeAccess;
 pub use common::append2table;
+pub use common::QueryResult;
+pub use common::QueryResultCache;
+pub use common::QueryResultCacheWriter;
+pub use common::MAX_QUERY_RESULT_CACHE_BYTES;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
 pub use interpreter_call::CallInterpreter;
@@ -102,8 +119,13 @@ pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_explain_v2::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_insert_v2::InsertInterpreterV2;
+pub use interpreter_inverted_index_create::CreateInvertedIndexInterpreter;
+pub use interpreter_inverted_index_drop::DropInvertedIndexInterpreter;
 pub use interpreter_kill::KillInterpreter;
 pub use interpreter_list::ListInterpreter;
+pub use interpreter_materialized_view_create::CreateMaterializedViewInterpreter;
+pub use interpreter_materialized_view_refresh::refresh_materialized_view;
+pub use interpreter_materialized_view_refresh::RefreshMaterializedViewInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
@@ -124,6 +146,8 @@ pub use interpreter_share_show::ShowSharesInterpreter;
 pub use interpreter_share_show_grant_tenants::ShowGrantTenantsOfShareInterpreter;
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInterpreter;
+pub use interpreter_stream_create::CreateStreamInterpreter;
+pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
 pub use interpreter_table_create_v2::CreateTableInterpreterV2;
 pub use interpreter_table_describe::DescribeTableInterpreter;
@@ -135,6 +159,8 @@ pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
+pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_table_vacuum_drop::VacuumDropTableInterpreter;
 pub use interpreter_unsetting::UnSettingInterpreter;
 pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
@@ -150,3 +176,5 @@ pub use interpreter_user_udf_drop::DropUserUDFInterpreter;
 pub use interpreter_view_alter::AlterViewInterpreter;
 pub use interpreter_view_create::CreateViewInterpreter;
 pub use interpreter_view_drop::DropViewInterpreter;
+pub use interpreter_virtual_column_create::CreateVirtualColumnInterpreter;
+pub use interpreter_virtual_column_drop::DropVirtualColumnInterpreter;
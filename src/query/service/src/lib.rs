@@ -35,6 +35,7 @@ pub mod catalogs;
 pub mod clusters;
 pub mod databases;
 pub mod interpreters;
+pub mod maintenance;
 pub mod metrics;
 pub mod pipelines;
 pub mod procedures;
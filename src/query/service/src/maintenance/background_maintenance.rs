@@ -0,0 +1,205 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio::sync::Semaphore;
+use common_base::base::tokio::time::sleep as tokio_async_sleep;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_config::Config;
+use common_exception::Result;
+use common_management::BackgroundApi;
+use common_management::BackgroundMgr;
+use parking_lot::Mutex;
+use tracing::error;
+use tracing::info;
+
+use crate::clusters::ClusterDiscovery;
+use crate::maintenance::table_maintenance::maintain_table;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// A table to be maintained.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MaintenanceTarget {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+/// Compacts, reclusters and purges the fuse tables in background.
+///
+/// Only the leader of the cluster, elected through the meta service, maintains the tables.
+/// It checks all the tables periodically, and the tables committed on it right after the
+/// commits. The tables are maintained by at most `background_maintenance_concurrency` tasks,
+/// and every action is logged to `system.background_tasks`.
+pub struct BackgroundMaintenance {
+    config: Config,
+    is_leader: AtomicBool,
+    permits: Arc<Semaphore>,
+    /// The tables waiting for or under maintenance, a table is never maintained twice at the same time.
+    scheduled: Mutex<HashSet<MaintenanceTarget>>,
+    /// The leader election and the id of this node, once started.
+    election: Mutex<Option<(Arc<BackgroundMgr>, String)>>,
+}
+
+impl BackgroundMaintenance {
+    pub fn init(config: &Config) -> Result<()> {
+        let concurrency = std::cmp::max(config.query.background_maintenance_concurrency, 1);
+        GlobalInstance::set(Arc::new(BackgroundMaintenance {
+            config: config.clone(),
+            is_leader: AtomicBool::new(false),
+            permits: Arc::new(Semaphore::new(concurrency as usize)),
+            scheduled: Mutex::new(HashSet::new()),
+            election: Mutex::new(None),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<BackgroundMaintenance> {
+        GlobalInstance::get()
+    }
+
+    /// Starts to check the tables periodically, if it is enabled.
+    pub async fn start(self: &Arc<Self>) -> Result<()> {
+        if !self.config.query.background_maintenance_enabled {
+            return Ok(());
+        }
+
+        let interval = Duration::from_secs(std::cmp::max(
+            self.config.query.background_maintenance_interval_secs,
+            1,
+        ));
+        let metastore = ClusterDiscovery::create_meta_client(&self.config).await?;
+        // The leadership is kept for a few intervals, so that it survives a slow round.
+        let background_api = Arc::new(BackgroundMgr::create(
+            metastore,
+            &self.config.query.tenant_id,
+            &self.config.query.cluster_id,
+            interval * 3,
+        )?);
+        let node_id = ClusterDiscovery::instance()
+            .discover(&self.config)
+            .await?
+            .local_id();
+        *self.election.lock() = Some((background_api.clone(), node_id.clone()));
+
+        let this = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                match background_api.try_lead(&node_id).await {
+                    Ok(is_leader) => {
+                        if is_leader && !this.is_leader.load(Ordering::Relaxed) {
+                            info!(
+                                "background maintenance: node {} becomes the leader",
+                                node_id
+                            );
+                        }
+                        this.is_leader.store(is_leader, Ordering::Relaxed);
+                        if is_leader {
+                            if let Err(cause) = this.check_all_tables().await {
+                                error!("background maintenance: check tables failure: {:?}", cause);
+                            }
+                        }
+                    }
+                    Err(cause) => {
+                        this.is_leader.store(false, Ordering::Relaxed);
+                        error!(
+                            "background maintenance: leader election failure: {:?}",
+                            cause
+                        );
+                    }
+                }
+                tokio_async_sleep(interval).await;
+            }
+        });
+        Ok(())
+    }
+
+    /// Schedules the maintenance of a table which has just been committed.
+    pub fn notify_commit(self: &Arc<Self>, target: MaintenanceTarget) {
+        if self.is_leader.load(Ordering::Relaxed) {
+            self.schedule(target, "commit");
+        }
+    }
+
+    /// Checks that this node still holds the leader lease, the leadership may have moved to
+    /// another node since a task was scheduled.
+    pub async fn holds_lease(&self) -> Result<bool> {
+        let election = self.election.lock().clone();
+        let holds_lease = match election {
+            Some((background_api, node_id)) => {
+                background_api.get_leader().await?.as_deref() == Some(node_id.as_str())
+            }
+            None => false,
+        };
+        if !holds_lease {
+            self.is_leader.store(false, Ordering::Relaxed);
+        }
+        Ok(holds_lease)
+    }
+
+    async fn check_all_tables(self: &Arc<Self>) -> Result<()> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        let ctx = session.create_query_context().await?;
+        let tenant = ctx.get_tenant();
+        let catalog_name = ctx.get_current_catalog();
+        let catalog = ctx.get_catalog(&catalog_name)?;
+        for database in catalog.list_databases(&tenant).await? {
+            for table in catalog.list_tables(&tenant, database.name()).await? {
+                if !table.engine().eq_ignore_ascii_case("FUSE") {
+                    continue;
+                }
+                self.schedule(
+                    MaintenanceTarget {
+                        catalog: catalog_name.clone(),
+                        database: database.name().to_string(),
+                        table: table.name().to_string(),
+                    },
+                    "check",
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn schedule(self: &Arc<Self>, target: MaintenanceTarget, trigger: &'static str) {
+        if !self.scheduled.lock().insert(target.clone()) {
+            return;
+        }
+
+        let this = self.clone();
+        let max_snapshots = self.config.query.background_maintenance_max_snapshots as usize;
+        GlobalIORuntime::instance().spawn(async move {
+            if let Ok(_permit) = this.permits.acquire().await {
+                if let Err(cause) = maintain_table(&target, trigger, max_snapshots).await {
+                    error!(
+                        "background maintenance: maintain table {:?} failure: {:?}",
+                        target, cause
+                    );
+                }
+            }
+            this.scheduled.lock().remove(&target);
+        });
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_exception::Result;
+use common_sql::plans::OptimizeTableAction;
+use common_sql::plans::OptimizeTablePlan;
+use common_sql::plans::ReclusterTablePlan;
+use common_sql::Metadata;
+use common_storages_fuse::operations::MaintenanceAction;
+use common_storages_fuse::FuseTable;
+use common_storages_system::BackgroundTaskLogElement;
+use common_storages_system::BackgroundTasksQueue;
+use parking_lot::RwLock;
+use tracing::info;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::OptimizeTableInterpreter;
+use crate::interpreters::ReclusterTableInterpreter;
+use crate::maintenance::BackgroundMaintenance;
+use crate::maintenance::MaintenanceTarget;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// Runs the maintenance actions the table needs, stops at the first failed one or once this
+/// node is not the leader anymore.
+pub(crate) async fn maintain_table(
+    target: &MaintenanceTarget,
+    trigger: &str,
+    max_snapshots: usize,
+) -> Result<()> {
+    let session = SessionManager::instance()
+        .create_session(SessionType::Dummy)
+        .await?;
+    let ctx = session.create_query_context().await?;
+    let table = ctx
+        .get_table(&target.catalog, &target.database, &target.table)
+        .await?;
+    if !table.engine().eq_ignore_ascii_case("FUSE") {
+        return Ok(());
+    }
+    let table_ctx: Arc<dyn TableContext> = ctx.clone();
+    let actions = FuseTable::try_from_table(table.as_ref())?
+        .pending_maintenance(&table_ctx, max_snapshots)
+        .await?;
+
+    for action in actions {
+        if !BackgroundMaintenance::instance().holds_lease().await? {
+            info!(
+                "background maintenance: not the leader anymore, stop maintaining table {}.{}",
+                target.database, target.table
+            );
+            return Ok(());
+        }
+        info!(
+            "background maintenance: {} table {}.{}",
+            action, target.database, target.table
+        );
+        let start = SystemTime::now();
+        // The tables are cached by the query context, every action reads the latest version.
+        let ctx = session.create_query_context().await?;
+        let res = run_action(ctx, target, action).await;
+        write_log(target, action, trigger, start, &res)?;
+        res?;
+    }
+    Ok(())
+}
+
+async fn run_action(
+    ctx: Arc<QueryContext>,
+    target: &MaintenanceTarget,
+    action: MaintenanceAction,
+) -> Result<()> {
+    let optimize_action = match action {
+        MaintenanceAction::CompactSegments => OptimizeTableAction::CompactSegments(None),
+        MaintenanceAction::CompactBlocks => OptimizeTableAction::CompactBlocks(None),
+        MaintenanceAction::Purge => OptimizeTableAction::Purge(None),
+        MaintenanceAction::Recluster => {
+            let plan = ReclusterTablePlan {
+                tenant: ctx.get_tenant(),
+                catalog: target.catalog.clone(),
+                database: target.database.clone(),
+                table: target.table.clone(),
+                is_final: false,
+                metadata: Arc::new(RwLock::new(Metadata::default())),
+                push_downs: None,
+            };
+            ReclusterTableInterpreter::try_create(ctx, plan)?
+                .execute2()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let plan = OptimizeTablePlan {
+        catalog: target.catalog.clone(),
+        database: target.database.clone(),
+        table: target.table.clone(),
+        action: optimize_action,
+    };
+    OptimizeTableInterpreter::try_create(ctx, plan)?
+        .execute2()
+        .await?;
+    Ok(())
+}
+
+fn write_log(
+    target: &MaintenanceTarget,
+    action: MaintenanceAction,
+    trigger: &str,
+    start: SystemTime,
+    res: &Result<()>,
+) -> Result<()> {
+    BackgroundTasksQueue::instance()?.append_data(BackgroundTaskLogElement {
+        start_time: start
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_micros() as i64,
+        end_time: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_micros() as i64,
+        database: target.database.clone(),
+        table: target.table.clone(),
+        action: action.to_string(),
+        trigger: trigger.to_string(),
+        error: match res {
+            Ok(_) => "".to_string(),
+            Err(cause) => cause.message(),
+        },
+    })
+}
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_base::base::tokio::sync::oneshot;
 use common_base::runtime::Thread;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -76,6 +77,20 @@ impl PipelineCompleteExecutor {
 
         execute_thread.join().flatten()
     }
+
+    /// Execute the pipelines without blocking the async runtime of the caller, the
+    /// returned future is ready when the pipelines in the executor thread finish.
+    pub async fn execute_async(&self) -> Result<()> {
+        let executor = self.executor.clone();
+        let (tx, rx) = oneshot::channel();
+        Thread::named_spawn(Some(String::from("CompleteExecutor")), move || {
+            let _ = tx.send(executor.execute());
+        });
+
+        rx.await.map_err(|_| {
+            ErrorCode::Internal("CompleteExecutor exited without sending the result")
+        })?
+    }
 }
 
 impl Drop for PipelineCompleteExecutor {
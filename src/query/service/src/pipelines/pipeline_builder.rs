@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_channel::Receiver;
@@ -32,9 +33,11 @@ use common_pipeline_sinks::processors::sinks::UnionReceiveSink;
 use common_pipeline_transforms::processors::transforms::try_add_multi_sort_merge;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
+use common_sql::evaluator::ProjectSetOperator;
 use common_sql::executor::AggregateFinal;
 use common_sql::executor::AggregateFunctionDesc;
 use common_sql::executor::AggregatePartial;
+use common_sql::executor::CteScan;
 use common_sql::executor::DistributedInsertSelect;
 use common_sql::executor::EvalScalar;
 use common_sql::executor::ExchangeSink;
@@ -42,8 +45,11 @@ use common_sql::executor::ExchangeSource;
 use common_sql::executor::Filter;
 use common_sql::executor::HashJoin;
 use common_sql::executor::Limit;
+use common_sql::executor::MaterializedCte;
 use common_sql::executor::PhysicalPlan;
 use common_sql::executor::Project;
+use common_sql::executor::ProjectSet;
+use common_sql::executor::RecursiveCte;
 use common_sql::executor::Sort;
 use common_sql::executor::TableScan;
 use common_sql::executor::UnionAll;
@@ -61,9 +67,15 @@ use crate::pipelines::processors::transforms::TransformRightJoin;
 use crate::pipelines::processors::transforms::TransformRightSemiAntiJoin;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::AggregatorTransformParams;
+use crate::pipelines::processors::AsyncSinker;
+use crate::pipelines::processors::AsyncSourcer;
 use crate::pipelines::processors::JoinHashTable;
 use crate::pipelines::processors::LeftJoinCompactor;
 use crate::pipelines::processors::MarkJoinCompactor;
+use crate::pipelines::processors::MaterializedCteSink;
+use crate::pipelines::processors::MaterializedCteSource;
+use crate::pipelines::processors::MaterializedCteState;
+use crate::pipelines::processors::RecursiveCteSource;
 use crate::pipelines::processors::RightJoinCompactor;
 use crate::pipelines::processors::SinkBuildHashTable;
 use crate::pipelines::processors::Sinker;
@@ -84,14 +96,25 @@ pub struct PipelineBuilder {
     ctx: Arc<QueryContext>,
     main_pipeline: Pipeline,
     pub pipelines: Vec<Pipeline>,
+    /// Buffers of the materialized CTEs, shared by all the `CteScan`s reading them.
+    cte_states: HashMap<IndexType, Arc<MaterializedCteState>>,
 }
 
 impl PipelineBuilder {
     pub fn create(ctx: Arc<QueryContext>) -> PipelineBuilder {
+        Self::create_with_cte_states(ctx, HashMap::new())
+    }
+
+    /// Create a builder for a plan reading the materialized CTEs of an enclosing plan.
+    pub fn create_with_cte_states(
+        ctx: Arc<QueryContext>,
+        cte_states: HashMap<IndexType, Arc<MaterializedCteState>>,
+    ) -> PipelineBuilder {
         PipelineBuilder {
             ctx,
             pipelines: vec![],
             main_pipeline: Pipeline::create(),
+            cte_states,
         }
     }
 
@@ -118,6 +141,7 @@ impl PipelineBuilder {
             PhysicalPlan::Filter(filter) => self.build_filter(filter),
             PhysicalPlan::Project(project) => self.build_project(project),
             PhysicalPlan::EvalScalar(eval_scalar) => self.build_eval_scalar(eval_scalar),
+            PhysicalPlan::ProjectSet(project_set) => self.build_project_set(project_set),
             PhysicalPlan::AggregatePartial(aggregate) => self.build_aggregate_partial(aggregate),
             PhysicalPlan::AggregateFinal(aggregate) => self.build_aggregate_final(aggregate),
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
@@ -126,6 +150,9 @@ impl PipelineBuilder {
             PhysicalPlan::ExchangeSink(sink) => self.build_exchange_sink(sink),
             PhysicalPlan::ExchangeSource(source) => self.build_exchange_source(source),
             PhysicalPlan::UnionAll(union_all) => self.build_union_all(union_all),
+            PhysicalPlan::CteScan(cte_scan) => self.build_cte_scan(cte_scan),
+            PhysicalPlan::MaterializedCte(cte) => self.build_materialized_cte(cte),
+            PhysicalPlan::RecursiveCte(cte) => self.build_recursive_cte(cte),
             PhysicalPlan::DistributedInsertSelect(insert_select) => {
                 self.build_distributed_insert_select(insert_select)
             }
@@ -157,7 +184,8 @@ impl PipelineBuilder {
         join_state: Arc<JoinHashTable>,
     ) -> Result<()> {
         let build_side_context = QueryContext::create_from(self.ctx.clone());
-        let build_side_builder = PipelineBuilder::create(build_side_context);
+        let mut build_side_builder = PipelineBuilder::create(build_side_context);
+        build_side_builder.cte_states = self.cte_states.clone();
         let mut build_res = build_side_builder.finalize(build)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -313,6 +341,30 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_project_set(&mut self, project_set: &ProjectSet) -> Result<()> {
+        self.build_pipeline(&project_set.input)?;
+
+        let args = project_set
+            .args
+            .iter()
+            .map(|arg| arg.as_expr(&BUILTIN_FUNCTIONS))
+            .collect::<Vec<_>>();
+        let func_ctx = self.ctx.get_function_context()?;
+
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProjectSetOperator::create(
+                input,
+                output,
+                func_ctx,
+                project_set.func_name.clone(),
+                args.clone(),
+                project_set.return_type.clone(),
+            ))
+        })?;
+
+        Ok(())
+    }
+
     fn build_aggregate_partial(&mut self, aggregate: &AggregatePartial) -> Result<()> {
         self.build_pipeline(&aggregate.input)?;
         let params = Self::build_aggregator_params(
@@ -556,7 +608,8 @@ impl PipelineBuilder {
 
     fn expand_union_all(&mut self, plan: &PhysicalPlan) -> Result<Receiver<DataBlock>> {
         let union_ctx = QueryContext::create_from(self.ctx.clone());
-        let pipeline_builder = PipelineBuilder::create(union_ctx);
+        let mut pipeline_builder = PipelineBuilder::create(union_ctx);
+        pipeline_builder.cte_states = self.cte_states.clone();
         let mut build_res = pipeline_builder.finalize(plan)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -590,6 +643,86 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_materialized_cte(&mut self, cte: &MaterializedCte) -> Result<()> {
+        let state = MaterializedCteState::create(self.ctx.clone())?;
+
+        let cte_context = QueryContext::create_from(self.ctx.clone());
+        let mut cte_builder = PipelineBuilder::create(cte_context);
+        cte_builder.cte_states = self.cte_states.clone();
+        let mut cte_res = cte_builder.finalize(&cte.left)?;
+
+        // Keep the columns of the CTE in order, so that the blocks can be read by position.
+        let left_schema = cte.left.output_schema()?;
+        let projection = cte
+            .left_output_columns
+            .iter()
+            .map(|index| left_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        let func_ctx = self.ctx.get_function_context()?;
+        cte_res.main_pipeline.add_transform(|input, output| {
+            Ok(CompoundBlockOperator::create(
+                input,
+                output,
+                func_ctx,
+                vec![BlockOperator::Project {
+                    projection: projection.clone(),
+                }],
+            ))
+        })?;
+
+        assert!(cte_res.main_pipeline.is_pulling_pipeline()?);
+        cte_res.main_pipeline.add_sink(|input| {
+            Ok(AsyncSinker::create(
+                input,
+                MaterializedCteSink::create(state.clone()),
+            ))
+        })?;
+        self.pipelines.push(cte_res.main_pipeline);
+        self.pipelines.extend(cte_res.sources_pipelines.into_iter());
+
+        self.cte_states.insert(cte.cte_idx, state);
+        self.build_pipeline(&cte.right)
+    }
+
+    fn build_cte_scan(&mut self, cte_scan: &CteScan) -> Result<()> {
+        let state = self
+            .cte_states
+            .get(&cte_scan.cte_idx)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Materialized CTE {} is not found",
+                    cte_scan.cte_idx
+                ))
+            })?;
+        let ctx = self.ctx.clone();
+        self.main_pipeline.add_source(
+            |output| {
+                AsyncSourcer::create(
+                    ctx.clone(),
+                    output,
+                    MaterializedCteSource::create(state.clone()),
+                )
+            },
+            1,
+        )
+    }
+
+    fn build_recursive_cte(&mut self, cte: &RecursiveCte) -> Result<()> {
+        let ctx = self.ctx.clone();
+        let cte_states = self.cte_states.clone();
+        self.main_pipeline.add_source(
+            |output| {
+                AsyncSourcer::create(
+                    ctx.clone(),
+                    output,
+                    RecursiveCteSource::create(ctx.clone(), cte.clone(), cte_states.clone())?,
+                )
+            },
+            1,
+        )
+    }
+
     pub fn build_distributed_insert_select(
         &mut self,
         insert_select: &DistributedInsertSelect,
@@ -40,6 +40,10 @@ pub use transforms::HashTable;
 pub use transforms::JoinHashTable;
 pub use transforms::LeftJoinCompactor;
 pub use transforms::MarkJoinCompactor;
+pub use transforms::MaterializedCteSink;
+pub use transforms::MaterializedCteSource;
+pub use transforms::MaterializedCteState;
+pub use transforms::RecursiveCteSource;
 pub use transforms::RightJoinCompactor;
 pub use transforms::SerializerHashTable;
 pub use transforms::SinkBuildHashTable;
@@ -55,3 +59,4 @@ pub use transforms::TransformLimit;
 pub use transforms::TransformResortAddOn;
 pub use transforms::TransformSortMerge;
 pub use transforms::TransformSortPartial;
+pub use transforms::TransformWriteResultCache;
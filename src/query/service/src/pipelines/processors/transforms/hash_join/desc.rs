@@ -20,6 +20,7 @@ use common_expression::Expr;
 use common_expression::RemoteExpr;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 use common_sql::executor::HashJoin;
+use common_sql::executor::RuntimeFilterTarget;
 use parking_lot::RwLock;
 
 use crate::pipelines::processors::transforms::hash_join::row::RowPtr;
@@ -68,6 +69,8 @@ pub struct HashJoinDesc {
     /// Whether the Join are derived from correlated subquery.
     pub(crate) from_correlated_subquery: bool,
     pub(crate) join_state: JoinState,
+    /// The probe side table scans to push the runtime filters down to.
+    pub(crate) runtime_filter_targets: Vec<RuntimeFilterTarget>,
 }
 
 impl HashJoinDesc {
@@ -96,6 +99,7 @@ impl HashJoinDesc {
             },
             from_correlated_subquery: join.from_correlated_subquery,
             join_state: JoinState::create()?,
+            runtime_filter_targets: join.runtime_filter_targets.clone(),
         })
     }
 
@@ -83,6 +83,7 @@ impl HashJoinState for JoinHashTable {
         *count -= 1;
         if *count == 0 {
             self.finish()?;
+            self.build_runtime_filters()?;
             let mut is_finished = self.is_finished.lock().unwrap();
             *is_finished = true;
             self.finished_notify.notify_waiters();
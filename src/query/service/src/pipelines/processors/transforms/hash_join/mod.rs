@@ -21,6 +21,7 @@ mod probe_join;
 mod probe_state;
 mod result_blocks;
 pub(crate) mod row;
+mod runtime_filter;
 mod util;
 
 pub use desc::HashJoinDesc;
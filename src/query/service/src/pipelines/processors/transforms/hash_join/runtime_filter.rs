@@ -0,0 +1,194 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::nullable::NullableColumn;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::Expr;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_sql::executor::RuntimeFilterTarget;
+use storages_common_index::filters::Xor8Filter;
+use storages_common_index::BloomIndex;
+use storages_common_index::Index;
+
+use crate::pipelines::processors::JoinHashTable;
+
+/// If the number of distinct build keys doesn't exceed it, an in-list filter is built,
+/// otherwise a bloom filter is built.
+const INLIST_MAX_VALUES: usize = 1024;
+
+impl JoinHashTable {
+    /// Build the runtime filters from the build keys and push them down to the probe side
+    /// table scans, it's called once all the rows of the build side are collected.
+    pub(crate) fn build_runtime_filters(&self) -> Result<()> {
+        let targets = &self.hash_join_desc.runtime_filter_targets;
+        if targets.is_empty() {
+            return Ok(());
+        }
+
+        let chunks = self.row_space.chunks.read().unwrap();
+        for target in targets.iter() {
+            let data_type = self.hash_join_desc.build_keys[target.build_key].data_type();
+            let columns = chunks
+                .iter()
+                .map(|chunk| chunk.cols[target.build_key].0.clone())
+                .collect::<Vec<_>>();
+            let filters = self.build_runtime_filter(target, &columns, data_type)?;
+            self.ctx.set_runtime_filter((target.scan_id, filters));
+        }
+        Ok(())
+    }
+
+    fn build_runtime_filter(
+        &self,
+        target: &RuntimeFilterTarget,
+        columns: &[Column],
+        data_type: &DataType,
+    ) -> Result<RuntimeFilterInfo> {
+        let probe_key = target.probe_key.as_expr(&BUILTIN_FUNCTIONS);
+        let mut filters = RuntimeFilterInfo::default();
+
+        let mut min_max: Option<(ScalarRef, ScalarRef)> = None;
+        let mut values = BTreeSet::new();
+        let mut too_many_values = false;
+        for value in columns.iter().flat_map(|column| column.iter()) {
+            if value == ScalarRef::Null {
+                continue;
+            }
+            min_max = match min_max {
+                None => Some((value.clone(), value.clone())),
+                Some((min, max)) => Some((min.min(value.clone()), max.max(value.clone()))),
+            };
+            if !too_many_values {
+                values.insert(value);
+                too_many_values = values.len() > INLIST_MAX_VALUES;
+            }
+        }
+
+        let value_type = data_type.remove_nullable();
+        let constant = |value: &ScalarRef| Expr::Constant {
+            span: None,
+            scalar: value.to_owned(),
+            data_type: value_type.clone(),
+        };
+        let Some((min, max)) = min_max else {
+            // No build key can match, all the probe rows can be skipped.
+            filters.add_min_max(Expr::Constant {
+                span: None,
+                scalar: Scalar::Boolean(false),
+                data_type: DataType::Boolean,
+            });
+            return Ok(filters);
+        };
+
+        let min_max = check_function(
+            None,
+            "and",
+            &[],
+            &[
+                check_function(
+                    None,
+                    "gte",
+                    &[],
+                    &[probe_key.clone(), constant(&min)],
+                    &BUILTIN_FUNCTIONS,
+                )?,
+                check_function(
+                    None,
+                    "lte",
+                    &[],
+                    &[probe_key.clone(), constant(&max)],
+                    &BUILTIN_FUNCTIONS,
+                )?,
+            ],
+            &BUILTIN_FUNCTIONS,
+        )?;
+        filters.add_min_max(min_max);
+
+        if !too_many_values {
+            let equals = values
+                .iter()
+                .map(|value| {
+                    check_function(
+                        None,
+                        "eq",
+                        &[],
+                        &[probe_key.clone(), constant(value)],
+                        &BUILTIN_FUNCTIONS,
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?;
+            filters.add_inlist(disjunction(equals)?);
+        } else if Xor8Filter::supported_type(data_type) {
+            let func_ctx = self.ctx.get_function_context()?;
+            let digest_type = if data_type.is_nullable() {
+                DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64)))
+            } else {
+                DataType::Number(NumberDataType::UInt64)
+            };
+            let mut builder = xorfilter::Xor8Builder::default();
+            for column in columns.iter() {
+                let digests =
+                    BloomIndex::calculate_column_digest(func_ctx, column, data_type, &digest_type)?;
+                match digests {
+                    Column::Nullable(box NullableColumn { column, validity }) => {
+                        let column = UInt64Type::try_downcast_column(&column).unwrap();
+                        let digests = column
+                            .iter()
+                            .zip(validity.iter())
+                            .filter_map(|(digest, valid)| valid.then_some(digest));
+                        builder.populate_digests(digests);
+                    }
+                    column => {
+                        let column = UInt64Type::try_downcast_column(&column).unwrap();
+                        builder.populate_digests(column.iter());
+                    }
+                }
+            }
+            let bloom = builder.build().map_err(|e| {
+                ErrorCode::Internal(format!("Failed to build runtime bloom filter: {}", e))
+            })?;
+            filters.add_bloom(probe_key, Arc::new(bloom));
+        }
+        Ok(filters)
+    }
+}
+
+/// Combine the predicates with `or` as a balanced tree to keep the expression shallow.
+fn disjunction(mut predicates: Vec<Expr<String>>) -> Result<Expr<String>> {
+    if predicates.len() == 1 {
+        return Ok(predicates.pop().unwrap());
+    }
+    let right = predicates.split_off(predicates.len() / 2);
+    check_function(
+        None,
+        "or",
+        &[],
+        &[disjunction(predicates)?, disjunction(right)?],
+        &BUILTIN_FUNCTIONS,
+    )
+}
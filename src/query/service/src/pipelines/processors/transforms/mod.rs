@@ -23,6 +23,9 @@ mod transform_hash_join;
 mod transform_left_join;
 mod transform_limit;
 mod transform_mark_join;
+mod transform_materialized_cte;
+mod transform_recursive_cte;
+mod transform_write_result_cache;
 
 mod transform_add_const_columns;
 mod transform_convert_grouping;
@@ -63,7 +66,11 @@ pub use transform_left_join::TransformLeftJoin;
 pub use transform_limit::TransformLimit;
 pub use transform_mark_join::MarkJoinCompactor;
 pub use transform_mark_join::TransformMarkJoin;
+pub use transform_materialized_cte::MaterializedCteSink;
+pub use transform_materialized_cte::MaterializedCteSource;
+pub use transform_materialized_cte::MaterializedCteState;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_recursive_cte::RecursiveCteSource;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_right_join::RightJoinCompactor;
 pub use transform_right_join::TransformRightJoin;
@@ -72,3 +79,4 @@ pub use transform_right_semi_anti_join::TransformRightSemiAntiJoin;
 pub use transform_sort_merge::SortMergeCompactor;
 pub use transform_sort_merge::TransformSortMerge;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_write_result_cache::TransformWriteResultCache;
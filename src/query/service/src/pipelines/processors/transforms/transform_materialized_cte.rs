@@ -0,0 +1,312 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::Schema;
+use common_arrow::arrow::io::ipc::read::read_file_metadata;
+use common_arrow::arrow::io::ipc::read::FileReader;
+use common_arrow::arrow::io::ipc::write::FileWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_base::base::tokio::sync::Notify;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::DataBlock;
+use opendal::Operator;
+use tracing::warn;
+
+use crate::pipelines::processors::AsyncSink;
+use crate::pipelines::processors::AsyncSource;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// A block of a materialized CTE, kept in memory or spilled to the storage.
+enum CteBlock {
+    Memory(DataBlock),
+    Spilled {
+        location: String,
+        data_types: Vec<DataType>,
+    },
+}
+
+/// Where the blocks beyond the memory threshold are spilled.
+struct CteSpiller {
+    operator: Operator,
+    /// Prefix of the spilled blocks, unique for each query.
+    prefix: String,
+    threshold: usize,
+}
+
+/// The shared buffer of a materialized common table expression. The CTE is computed
+/// once by the sinks of its own pipeline, then every `CteScan` of the query reads
+/// all the buffered blocks. Once the buffered blocks exceed the memory threshold,
+/// the following blocks are spilled to the storage and read back by each `CteScan`.
+pub struct MaterializedCteState {
+    blocks: Mutex<Vec<CteBlock>>,
+    memory_usage: Mutex<usize>,
+    spiller: Option<CteSpiller>,
+    /// Count of the attached sinks, the CTE is finished when all of them are detached.
+    ref_count: Mutex<usize>,
+    /// The blocks shared by the readers after the CTE is finished.
+    finished: Mutex<Option<Arc<Vec<CteBlock>>>>,
+    finished_notify: Arc<Notify>,
+}
+
+impl MaterializedCteState {
+    pub fn create(ctx: Arc<QueryContext>) -> Result<Arc<Self>> {
+        let spiller = CteSpiller {
+            operator: ctx.get_data_operator()?.operator(),
+            prefix: format!("_query_spill/{}/", ctx.get_id()),
+            threshold: ctx.get_settings().get_materialized_cte_spill_threshold()? as usize,
+        };
+        Ok(Arc::new(MaterializedCteState {
+            blocks: Mutex::new(vec![]),
+            memory_usage: Mutex::new(0),
+            spiller: Some(spiller),
+            ref_count: Mutex::new(0),
+            finished: Mutex::new(None),
+            finished_notify: Arc::new(Notify::new()),
+        }))
+    }
+
+    /// Create a finished buffer of the given blocks, e.g. the working table of a recursive CTE.
+    pub fn create_with_blocks(blocks: Vec<DataBlock>) -> Arc<Self> {
+        let blocks = blocks.into_iter().map(CteBlock::Memory).collect();
+        Arc::new(MaterializedCteState {
+            blocks: Mutex::new(vec![]),
+            memory_usage: Mutex::new(0),
+            spiller: None,
+            ref_count: Mutex::new(0),
+            finished: Mutex::new(Some(Arc::new(blocks))),
+            finished_notify: Arc::new(Notify::new()),
+        })
+    }
+
+    fn attach(&self) {
+        let mut count = self.ref_count.lock().unwrap();
+        *count += 1;
+    }
+
+    fn detach(&self) {
+        let mut count = self.ref_count.lock().unwrap();
+        *count -= 1;
+        if *count == 0 {
+            let blocks = std::mem::take(&mut *self.blocks.lock().unwrap());
+            *self.finished.lock().unwrap() = Some(Arc::new(blocks));
+            self.finished_notify.notify_waiters();
+        }
+    }
+
+    async fn push(&self, data_block: DataBlock) -> Result<()> {
+        let block = match &self.spiller {
+            Some(spiller) if data_block.num_columns() > 0 && self.reserve(&data_block, spiller) => {
+                let location = format!("{}{}", spiller.prefix, uuid::Uuid::new_v4());
+                let data_types = data_block
+                    .columns()
+                    .iter()
+                    .map(|entry| entry.data_type.clone())
+                    .collect();
+                let data = serialize_block(&data_block)?;
+                spiller.operator.object(&location).write(data).await?;
+                CteBlock::Spilled {
+                    location,
+                    data_types,
+                }
+            }
+            _ => CteBlock::Memory(data_block),
+        };
+        self.blocks.lock().unwrap().push(block);
+        Ok(())
+    }
+
+    /// Account the memory of the block, returns true if it should be spilled.
+    fn reserve(&self, data_block: &DataBlock, spiller: &CteSpiller) -> bool {
+        let mut memory_usage = self.memory_usage.lock().unwrap();
+        if *memory_usage + data_block.memory_size() > spiller.threshold {
+            return true;
+        }
+        *memory_usage += data_block.memory_size();
+        false
+    }
+
+    async fn wait_finish(&self) -> Result<Arc<Vec<CteBlock>>> {
+        // Register the waiter before checking the blocks, so that the notification
+        // can't be missed if the sinks finish in between.
+        let notified = self.finished_notify.notified();
+        let finished = self.finished.lock().unwrap().clone();
+        match finished {
+            Some(blocks) => Ok(blocks),
+            None => {
+                notified.await;
+                self.finished.lock().unwrap().clone().ok_or_else(|| {
+                    ErrorCode::Internal("Materialized CTE is notified before it's finished")
+                })
+            }
+        }
+    }
+
+    async fn read_block(&self, block: &CteBlock) -> Result<DataBlock> {
+        match (block, &self.spiller) {
+            (CteBlock::Memory(data_block), _) => Ok(data_block.clone()),
+            (
+                CteBlock::Spilled {
+                    location,
+                    data_types,
+                },
+                Some(spiller),
+            ) => {
+                let data = spiller.operator.object(location).read().await?;
+                deserialize_block(data, data_types)
+            }
+            (CteBlock::Spilled { location, .. }, None) => Err(ErrorCode::Internal(format!(
+                "Spilled block {location} of materialized CTE can't be read"
+            ))),
+        }
+    }
+}
+
+impl Drop for MaterializedCteState {
+    fn drop(&mut self) {
+        let spiller = match &self.spiller {
+            Some(spiller) => spiller,
+            None => return,
+        };
+        let blocks = std::mem::take(&mut *self.blocks.lock().unwrap());
+        let finished = self.finished.lock().unwrap().take();
+        let locations = blocks
+            .iter()
+            .chain(finished.iter().flat_map(|blocks| blocks.iter()))
+            .filter_map(|block| match block {
+                CteBlock::Spilled { location, .. } => Some(location.clone()),
+                CteBlock::Memory(_) => None,
+            })
+            .collect::<Vec<_>>();
+        if locations.is_empty() {
+            return;
+        }
+
+        let operator = spiller.operator.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            for location in locations {
+                if let Err(cause) = operator.object(&location).delete().await {
+                    warn!("failed to remove spilled block {location} of materialized CTE: {cause}");
+                }
+            }
+        });
+    }
+}
+
+fn serialize_block(data_block: &DataBlock) -> Result<Vec<u8>> {
+    let columns = data_block
+        .columns()
+        .iter()
+        .map(|entry| {
+            entry
+                .value
+                .convert_to_full_column(&entry.data_type, data_block.num_rows())
+        })
+        .collect::<Vec<_>>();
+    let schema = Schema::from(
+        columns
+            .iter()
+            .map(|column| column.arrow_field())
+            .collect::<Vec<_>>(),
+    );
+    let chunk = Chunk::new(columns.iter().map(|column| column.as_arrow()).collect());
+
+    let mut buffer = vec![];
+    let mut writer = FileWriter::new(&mut buffer, schema, None, WriteOptions::default());
+    writer.start()?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    Ok(buffer)
+}
+
+fn deserialize_block(data: Vec<u8>, data_types: &[DataType]) -> Result<DataBlock> {
+    let mut cursor = Cursor::new(data);
+    let metadata = read_file_metadata(&mut cursor)?;
+    let mut reader = FileReader::new(cursor, metadata, None, None);
+    let chunk = reader
+        .next()
+        .ok_or_else(|| ErrorCode::Internal("Spilled block of materialized CTE is empty"))??;
+    DataBlock::from_arrow_chunk_with_types(&chunk, data_types)
+}
+
+pub struct MaterializedCteSink {
+    state: Arc<MaterializedCteState>,
+}
+
+impl MaterializedCteSink {
+    pub fn create(state: Arc<MaterializedCteState>) -> Self {
+        state.attach();
+        MaterializedCteSink { state }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSink for MaterializedCteSink {
+    const NAME: &'static str = "MaterializedCteSink";
+
+    async fn on_finish(&mut self) -> Result<()> {
+        self.state.detach();
+        Ok(())
+    }
+
+    #[async_trait::unboxed_simple]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<()> {
+        self.state.push(data_block).await
+    }
+}
+
+pub struct MaterializedCteSource {
+    state: Arc<MaterializedCteState>,
+    /// The blocks shared with the other readers and the position of the next one.
+    blocks: Option<(Arc<Vec<CteBlock>>, usize)>,
+}
+
+impl MaterializedCteSource {
+    pub fn create(state: Arc<MaterializedCteState>) -> Self {
+        MaterializedCteSource {
+            state,
+            blocks: None,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for MaterializedCteSource {
+    const NAME: &'static str = "CteScan";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.blocks.is_none() {
+            let blocks = self.state.wait_finish().await?;
+            self.blocks = Some((blocks, 0));
+        }
+        let (blocks, next) = self.blocks.as_mut().unwrap();
+        match blocks.get(*next) {
+            Some(block) => {
+                *next += 1;
+                Ok(Some(self.state.read_block(block).await?))
+            }
+            None => Ok(None),
+        }
+    }
+}
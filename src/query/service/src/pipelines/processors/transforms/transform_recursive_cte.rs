@@ -0,0 +1,227 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_pipeline_sinks::processors::sinks::UnionReceiveSink;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::RecursiveCte;
+use common_sql::IndexType;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::processors::AsyncSource;
+use crate::pipelines::processors::MaterializedCteState;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Evaluate a recursive CTE iteratively.
+///
+/// The anchor term is evaluated first, then the recursive term is evaluated again and
+/// again, each time with the output of the previous iteration as the working table read
+/// by its `CteScan`, until an iteration produces no rows. Each term is built and executed
+/// as a separate pipeline, since the number of iterations is only known at runtime.
+///
+/// For `UNION` without `ALL`, the produced rows are kept to remove the duplicates, the
+/// query fails if they take more memory than the setting `max_cte_union_memory_usage`.
+pub struct RecursiveCteSource {
+    ctx: Arc<QueryContext>,
+    plan: RecursiveCte,
+    /// Buffers of the materialized CTEs that the terms may read.
+    cte_states: HashMap<IndexType, Arc<MaterializedCteState>>,
+    max_depth: u64,
+    depth: u64,
+    /// Output of the last iteration, `None` before the anchor term is evaluated.
+    working_table: Option<Vec<DataBlock>>,
+    pending: VecDeque<DataBlock>,
+    /// Rows that have been produced, only used for `UNION` without `ALL`.
+    seen_rows: HashSet<Vec<Scalar>>,
+    seen_rows_memory: usize,
+    max_seen_rows_memory: usize,
+}
+
+impl RecursiveCteSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        plan: RecursiveCte,
+        cte_states: HashMap<IndexType, Arc<MaterializedCteState>>,
+    ) -> Result<Self> {
+        let settings = ctx.get_settings();
+        let max_depth = settings.get_max_cte_recursion_depth()?;
+        let max_seen_rows_memory = settings.get_max_cte_union_memory_usage()? as usize;
+        Ok(RecursiveCteSource {
+            ctx,
+            plan,
+            cte_states,
+            max_depth,
+            depth: 0,
+            working_table: None,
+            pending: VecDeque::new(),
+            seen_rows: HashSet::new(),
+            seen_rows_memory: 0,
+            max_seen_rows_memory,
+        })
+    }
+
+    /// Execute a term of the CTE and collect its output, with the columns of the CTE in order.
+    async fn execute(
+        &self,
+        plan: &PhysicalPlan,
+        output_columns: &[IndexType],
+        cte_states: HashMap<IndexType, Arc<MaterializedCteState>>,
+    ) -> Result<Vec<DataBlock>> {
+        let schema = plan.output_schema()?;
+        let projection = output_columns
+            .iter()
+            .map(|index| schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let builder = PipelineBuilder::create_with_cte_states(self.ctx.clone(), cte_states);
+        let mut build_res = builder.finalize(plan)?;
+        let settings = self.ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+
+        let (tx, rx) = async_channel::unbounded();
+        build_res
+            .main_pipeline
+            .add_sink(|input_port| Ok(UnionReceiveSink::create(Some(tx.clone()), input_port)))?;
+        drop(tx);
+
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor_settings = ExecutorSettings::try_create(&settings, self.ctx.get_id())?;
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        executor.execute_async().await?;
+
+        let mut blocks = vec![];
+        while let Ok(block) = rx.try_recv() {
+            if block.num_rows() == 0 {
+                continue;
+            }
+            let columns = projection
+                .iter()
+                .map(|offset| block.get_by_offset(*offset).clone())
+                .collect();
+            blocks.push(DataBlock::new(columns, block.num_rows()));
+        }
+        Ok(blocks)
+    }
+
+    /// Remove the rows that have been produced by the previous iterations, or that are
+    /// duplicated in the current one.
+    fn remove_seen_rows(&mut self, blocks: Vec<DataBlock>) -> Result<Vec<DataBlock>> {
+        let mut result = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            let mut indices = Vec::with_capacity(block.num_rows());
+            for row in 0..block.num_rows() {
+                let key = block
+                    .columns()
+                    .iter()
+                    .map(|entry| {
+                        entry
+                            .value
+                            .as_ref()
+                            .index(row)
+                            .map(|scalar| scalar.to_owned())
+                            .unwrap_or_default()
+                    })
+                    .collect::<Vec<_>>();
+                let key_memory = key
+                    .iter()
+                    .map(|scalar| scalar.as_ref().memory_size())
+                    .sum::<usize>();
+                if self.seen_rows.insert(key) {
+                    self.seen_rows_memory += key_memory;
+                    if self.seen_rows_memory > self.max_seen_rows_memory {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Recursive CTE with UNION exceeds the max memory usage {} bytes to remove the duplicated rows, which can be changed by the setting max_cte_union_memory_usage, or use UNION ALL instead",
+                            self.max_seen_rows_memory
+                        )));
+                    }
+                    indices.push(row as u32);
+                }
+            }
+            if indices.len() == block.num_rows() {
+                result.push(block);
+            } else if !indices.is_empty() {
+                result.push(block.take(&indices)?);
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for RecursiveCteSource {
+    const NAME: &'static str = "RecursiveCte";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.pending.pop_front() {
+                return Ok(Some(block));
+            }
+
+            let blocks = match self.working_table.take() {
+                None => {
+                    self.execute(
+                        &self.plan.left,
+                        &self.plan.left_output_columns,
+                        self.cte_states.clone(),
+                    )
+                    .await?
+                }
+                Some(working_table) => {
+                    if working_table.is_empty() {
+                        return Ok(None);
+                    }
+                    self.depth += 1;
+                    if self.depth > self.max_depth {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Recursive CTE exceeds the max recursion depth {}, which can be changed by the setting max_cte_recursion_depth",
+                            self.max_depth
+                        )));
+                    }
+                    let mut cte_states = self.cte_states.clone();
+                    cte_states.insert(
+                        self.plan.cte_idx,
+                        MaterializedCteState::create_with_blocks(working_table),
+                    );
+                    self.execute(
+                        &self.plan.right,
+                        &self.plan.right_output_columns,
+                        cte_states,
+                    )
+                    .await?
+                }
+            };
+
+            let blocks = if self.plan.distinct {
+                self.remove_seen_rows(blocks)?
+            } else {
+                blocks
+            };
+            self.pending.extend(blocks.iter().cloned());
+            self.working_table = Some(blocks);
+        }
+    }
+}
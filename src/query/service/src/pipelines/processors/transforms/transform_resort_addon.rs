@@ -16,12 +16,15 @@ use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::DataBlock;
 use common_expression::DataField;
+use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::Expr;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
+use common_sql::parse_computed_expr;
 use common_sql::parse_exprs;
 use common_storages_factory::Table;
 
@@ -34,7 +37,6 @@ use crate::sessions::QueryContext;
 
 pub struct TransformResortAddOn {
     expression_transform: CompoundBlockOperator,
-    input_len: usize,
 }
 
 impl TransformResortAddOn
@@ -47,15 +49,18 @@ where Self: Transform
         input_schema: DataSchemaRef,
         table: Arc<dyn Table>,
     ) -> Result<ProcessorPtr> {
-        let fields = table
-            .schema()
-            .fields()
-            .iter()
-            .map(DataField::from)
-            .collect::<Vec<_>>();
+        // The virtual computed columns are not stored.
+        let schema = table.schema().remove_virtual_computed_fields();
+        let fields = schema.fields();
 
-        let mut ops = Vec::with_capacity(fields.len());
-        for f in fields.iter() {
+        let mut ops = Vec::with_capacity(fields.len() + 1);
+        let mut offsets = vec![0; fields.len()];
+        let mut offset = input_schema.num_fields();
+        for (i, f) in fields.iter().enumerate() {
+            if f.computed_expr().is_some() {
+                continue;
+            }
+            let f = DataField::from(f);
             let expr = if !input_schema.has_field(f.name()) {
                 if let Some(default_expr) = f.default_expr() {
                     let mut expr = parse_exprs(ctx.clone(), table.clone(), false, default_expr)?;
@@ -79,8 +84,38 @@ where Self: Transform
                 }
             };
             ops.push(BlockOperator::Map { expr });
+            offsets[i] = offset;
+            offset += 1;
         }
 
+        // The stored computed columns are computed from the other columns of the table,
+        // which have been resorted above.
+        let data_schema = Arc::new(DataSchema::from(&schema));
+        for (i, f) in fields.iter().enumerate() {
+            let Some(computed_expr) = f.computed_expr() else {
+                continue;
+            };
+            let expr = parse_computed_expr(ctx.clone(), data_schema.clone(), computed_expr.expr())?
+                .project_column_ref(|index| offsets[*index]);
+            let data_type = DataType::from(f.data_type());
+            let expr = if expr.data_type() != &data_type {
+                Expr::Cast {
+                    span: None,
+                    is_try: false,
+                    expr: Box::new(expr),
+                    dest_type: data_type,
+                }
+            } else {
+                expr
+            };
+            ops.push(BlockOperator::Map { expr });
+            offsets[i] = offset;
+            offset += 1;
+        }
+        ops.push(BlockOperator::Project {
+            projection: offsets,
+        });
+
         let func_ctx = ctx.get_function_context()?;
         let expression_transform = CompoundBlockOperator {
             ctx: func_ctx,
@@ -89,7 +124,6 @@ where Self: Transform
 
         Ok(Transformer::create(input, output, Self {
             expression_transform,
-            input_len: input_schema.num_fields(),
         }))
     }
 }
@@ -97,9 +131,7 @@ where Self: Transform
 impl Transform for TransformResortAddOn {
     const NAME: &'static str = "AddOnTransform";
 
-    fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
-        block = self.expression_transform.transform(block)?;
-        let columns = block.columns()[self.input_len..].to_owned();
-        Ok(DataBlock::new(columns, block.num_rows()))
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        self.expression_transform.transform(block)
     }
 }
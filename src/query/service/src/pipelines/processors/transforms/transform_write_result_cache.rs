@@ -0,0 +1,108 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::DataBlock;
+
+use crate::interpreters::QueryResultCacheWriter;
+use crate::interpreters::MAX_QUERY_RESULT_CACHE_BYTES;
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::Processor;
+
+/// Pass the result blocks of a query through, and put them into the query result cache
+/// once the result is complete.
+pub struct TransformWriteResultCache {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    blocks: Vec<DataBlock>,
+    bytes: usize,
+    /// `None` if the result is too large to be cached.
+    writer: Option<QueryResultCacheWriter>,
+}
+
+impl TransformWriteResultCache {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        writer: QueryResultCacheWriter,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(Box::new(TransformWriteResultCache {
+            input,
+            output,
+            blocks: vec![],
+            bytes: 0,
+            writer: Some(writer),
+        }))
+    }
+
+    fn collect(&mut self, data_block: &DataBlock) {
+        if self.writer.is_none() {
+            return;
+        }
+        self.bytes += data_block.memory_size();
+        if self.bytes > MAX_QUERY_RESULT_CACHE_BYTES {
+            self.writer = None;
+            self.blocks.clear();
+            return;
+        }
+        self.blocks.push(data_block.clone());
+    }
+}
+
+impl Processor for TransformWriteResultCache {
+    fn name(&self) -> String {
+        "WriteResultCache".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            // The result is not consumed completely, so it can't be cached.
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input.has_data() {
+            let data_block = self.input.pull_data().unwrap()?;
+            self.collect(&data_block);
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input.is_finished() {
+            if let Some(writer) = self.writer.take() {
+                writer.write(std::mem::take(&mut self.blocks));
+            }
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+}
@@ -24,6 +24,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
+use common_expression::Literal;
+use common_sql::plans::Plan;
 use common_sql::Planner;
 use futures::StreamExt;
 use futures_util::FutureExt;
@@ -204,22 +206,46 @@ impl Executor {
 }
 
 impl ExecuteState {
-    pub(crate) async fn get_schema(sql: &str, ctx: Arc<QueryContext>) -> Result<DataSchemaRef> {
-        let mut planner = Planner::new(ctx.clone());
-        let (plan, _, _) = planner.plan_sql(sql).await?;
+    /// Plan the SQL, which is a prepared statement if the parameters are given.
+    /// Returns the plan and the SQL with the parameters substituted.
+    async fn plan_sql(
+        sql: &str,
+        params: Option<&[Literal]>,
+        ctx: &Arc<QueryContext>,
+    ) -> Result<(Plan, String)> {
+        match params {
+            Some(params) => {
+                let statement = ctx.get_current_session().get_prepared_statement(sql)?;
+                let plan = statement.plan(ctx, params).await?;
+                Ok((plan, statement.render(params)?))
+            }
+            None => {
+                let mut planner = Planner::new(ctx.clone());
+                let (plan, _, _) = planner.plan_sql(sql).await?;
+                Ok((plan, sql.to_string()))
+            }
+        }
+    }
+
+    pub(crate) async fn get_schema(
+        sql: &str,
+        params: Option<&[Literal]>,
+        ctx: Arc<QueryContext>,
+    ) -> Result<DataSchemaRef> {
+        let (plan, _) = Self::plan_sql(sql, params, &ctx).await?;
         Ok(InterpreterFactory::get_schema(ctx, &plan))
     }
 
     pub(crate) async fn try_start_query(
         executor: Arc<RwLock<Executor>>,
         sql: &str,
+        params: Option<&[Literal]>,
         session: Arc<Session>,
         ctx: Arc<QueryContext>,
         block_sender: SizedChannelSender<DataBlock>,
     ) -> Result<()> {
-        let mut planner = Planner::new(ctx.clone());
-        let (plan, _, _) = planner.plan_sql(sql).await?;
-        ctx.attach_query_str(plan.to_string(), sql);
+        let (plan, sql) = Self::plan_sql(sql, params, &ctx).await?;
+        ctx.attach_query_str(plan.to_string(), &sql);
 
         let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
         let running_state = ExecuteRunning {
@@ -24,6 +24,7 @@ use common_base::runtime::TrySpawn;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::Literal;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -51,6 +52,21 @@ fn default_as_true() -> bool {
     true
 }
 
+fn json_to_literal(value: &serde_json::Value) -> Literal {
+    match value {
+        serde_json::Value::Null => Literal::Null,
+        serde_json::Value::Bool(v) => Literal::Boolean(*v),
+        serde_json::Value::Number(v) => match (v.as_u64(), v.as_i64()) {
+            (Some(v), _) => Literal::UInt64(v),
+            (_, Some(v)) => Literal::Int64(v),
+            _ => Literal::Float64(v.as_f64().unwrap_or_default().into()),
+        },
+        serde_json::Value::String(v) => Literal::String(v.as_bytes().to_vec()),
+        // Arrays and objects are bound as JSON strings.
+        other => Literal::String(other.to_string().into_bytes()),
+    }
+}
+
 #[derive(Deserialize, Debug)]
 pub struct HttpQueryRequest {
     pub session_id: Option<String>,
@@ -61,6 +77,8 @@ pub struct HttpQueryRequest {
     #[serde(default = "default_as_true")]
     pub string_fields: bool,
     pub stage_attachment: Option<StageAttachmentConf>,
+    /// The parameters bound to the placeholders `?` or `$n` of the SQL.
+    pub params: Option<Vec<serde_json::Value>>,
 }
 
 const DEFAULT_MAX_ROWS_IN_BUFFER: usize = 5 * 1000 * 1000;
@@ -274,15 +292,25 @@ impl HttpQuery {
         let ctx_clone = ctx.clone();
         let ctx_clone2 = ctx.clone();
         let sql = request.sql.clone();
+        let params = request
+            .params
+            .as_ref()
+            .map(|params| params.iter().map(json_to_literal).collect::<Vec<_>>());
         let query_id = id.clone();
         let query_id_clone = id.clone();
 
-        let schema = ExecuteState::get_schema(&sql, ctx.clone()).await?;
+        let schema = ExecuteState::get_schema(&sql, params.as_deref(), ctx.clone()).await?;
         ctx.try_spawn(async move {
             let state = state_clone.clone();
-            if let Err(e) =
-                ExecuteState::try_start_query(state, &sql, session, ctx_clone.clone(), block_sender)
-                    .await
+            if let Err(e) = ExecuteState::try_start_query(
+                state,
+                &sql,
+                params.as_deref(),
+                session,
+                ctx_clone.clone(),
+                block_sender,
+            )
+            .await
             {
                 InterpreterQueryLog::fail_to_start(ctx_clone.clone(), e.clone());
                 let state = ExecuteStopped {
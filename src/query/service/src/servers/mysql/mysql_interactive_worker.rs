@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Arc;
 use std::time::Instant;
 
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
 use common_base::base::convert_byte_size;
 use common_base::base::convert_number_size;
 use common_base::base::tokio::io::AsyncWrite;
@@ -25,7 +28,10 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use common_expression::DataBlock;
+use common_expression::DataField;
 use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_expression::Literal;
 use common_expression::SendableDataBlockStream;
 use common_sql::plans::Plan;
 use common_sql::Planner;
@@ -34,11 +40,14 @@ use common_users::UserApiProvider;
 use futures_util::StreamExt;
 use metrics::histogram;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
+use opensrv_mysql::ParamValue;
 use opensrv_mysql::QueryResultWriter;
 use opensrv_mysql::StatementMetaWriter;
+use opensrv_mysql::ValueInner;
 use rand::RngCore;
 use tracing::error;
 use tracing::info;
@@ -47,12 +56,14 @@ use tracing::Instrument;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
+use crate::servers::mysql::writers::convert_schema;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
 use crate::servers::mysql::writers::QueryResult;
 use crate::servers::mysql::MySQLFederated;
 use crate::servers::mysql::MYSQL_VERSION;
+use crate::sessions::PreparedStatement;
 use crate::sessions::QueryContext;
 use crate::sessions::Session;
 use crate::sessions::TableContext;
@@ -80,8 +91,36 @@ fn has_result_set_by_plan(plan: &Plan) -> bool {
     )
 }
 
+/// Convert a parameter of `COM_STMT_EXECUTE` to a literal, the temporal values are bound
+/// as strings which are cast to the temporal types implicitly.
+fn param_to_literal(param: ParamValue<'_>) -> Result<Literal> {
+    let value = param.value;
+    Ok(match value.into_inner() {
+        ValueInner::NULL => Literal::Null,
+        ValueInner::Int(v) => Literal::Int64(v),
+        ValueInner::UInt(v) => Literal::UInt64(v),
+        ValueInner::Double(v) => Literal::Float64(v.into()),
+        ValueInner::Bytes(v) => Literal::String(v.to_vec()),
+        ValueInner::Date(_) => Literal::String(NaiveDate::from(value).to_string().into_bytes()),
+        ValueInner::Datetime(_) => Literal::String(
+            NaiveDateTime::from(value)
+                .format("%Y-%m-%d %H:%M:%S%.f")
+                .to_string()
+                .into_bytes(),
+        ),
+        ValueInner::Time(_) => {
+            return Err(ErrorCode::BadArguments(
+                "TIME parameters of prepared statements are not supported",
+            ));
+        }
+    })
+}
+
 struct InteractiveWorkerBase<W: AsyncWrite + Send + Unpin> {
     session: Arc<Session>,
+    /// The statements prepared by `COM_STMT_PREPARE` on the connection, keyed by the statement ids.
+    prepared_statements: HashMap<u32, Arc<PreparedStatement>>,
+    next_statement_id: u32,
     generic_hold: PhantomData<W>,
 }
 
@@ -282,32 +321,95 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
         Ok(authed)
     }
 
-    async fn do_prepare(&mut self, _: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+    async fn do_prepare(&mut self, query: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
+        let (statement, params, columns) = match self.prepare_statement(query).await {
+            Ok(prepared) => prepared,
+            Err(error) => {
+                writer
+                    .error(
+                        ErrorKind::ER_UNKNOWN_ERROR,
+                        error.display_with_sql(query).message().as_bytes(),
+                    )
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        self.next_statement_id = self.next_statement_id.wrapping_add(1);
+        let id = self.next_statement_id;
+        self.prepared_statements.insert(id, statement);
+        writer.reply(id, &params, &columns).await?;
         Ok(())
     }
 
+    /// Prepare the statement, returns it with the column definitions of its parameters and
+    /// its result.
+    async fn prepare_statement(
+        &mut self,
+        query: &str,
+    ) -> Result<(Arc<PreparedStatement>, Vec<Column>, Vec<Column>)> {
+        let statement = self.session.get_prepared_statement(query)?;
+        let context = self.session.create_query_context().await?;
+        let (parameter_types, schema) = statement.describe(&context).await?;
+        let parameter_fields = parameter_types
+            .into_iter()
+            .map(|data_type| DataField::new("?", data_type))
+            .collect::<Vec<_>>();
+        let params = convert_schema(&DataSchemaRefExt::create(parameter_fields))?;
+        let columns = convert_schema(&schema)?;
+        Ok((statement, params, columns))
+    }
+
     async fn do_execute(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        id: u32,
+        params: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        let statement = match self.prepared_statements.get(&id) {
+            Some(statement) => statement.clone(),
+            None => {
+                writer
+                    .error(
+                        ErrorKind::ER_UNKNOWN_STMT_HANDLER,
+                        format!("Unknown prepared statement handler ({id}) given to EXECUTE")
+                            .as_bytes(),
+                    )
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        let mut writer = DFQueryResultWriter::create(writer);
+        let instant = Instant::now();
+        let query_result = match params
+            .into_iter()
+            .map(param_to_literal)
+            .collect::<Result<Vec<_>>>()
+        {
+            Ok(params) => self.do_execute_statement(&statement, &params).await,
+            Err(error) => Err(error),
+        }
+        .map_err(|err| err.display_with_sql(statement.sql()));
+
+        let format = self.session.get_format_settings()?;
+        let mut write_result = writer.write(query_result, &format).await;
+        if let Err(cause) = write_result {
+            let suffix = format!("(while in query {})", statement.sql());
+            write_result = Err(cause.add_message_back(suffix));
+        }
+
+        histogram!(
+            super::mysql_metrics::METRIC_MYSQL_PROCESSOR_REQUEST_DURATION,
+            instant.elapsed()
+        );
+
+        write_result
     }
 
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.prepared_statements.remove(&id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -344,26 +446,43 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
                 let (plan, _, _) = planner.plan_sql(query).await?;
 
                 context.attach_query_str(plan.to_string(), query);
-                let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
-                let has_result_set = has_result_set_by_plan(&plan);
-
-                match interpreter {
-                    Ok(interpreter) => {
-                        let (blocks, extra_info) =
-                            Self::exec_query(interpreter.clone(), &context).await?;
-                        let schema = interpreter.schema();
-                        Ok(QueryResult::create(
-                            blocks,
-                            extra_info,
-                            has_result_set,
-                            schema,
-                        ))
-                    }
-                    Err(e) => {
-                        InterpreterQueryLog::fail_to_start(context, e.clone());
-                        Err(e)
-                    }
-                }
+                Self::exec_plan(context, plan).await
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, statement))]
+    async fn do_execute_statement(
+        &mut self,
+        statement: &PreparedStatement,
+        params: &[Literal],
+    ) -> Result<QueryResult> {
+        info!("Prepared statement: {}", statement.sql());
+        let context = self.session.create_query_context().await?;
+        let plan = statement.plan(&context, params).await?;
+
+        context.attach_query_str(plan.to_string(), &statement.render(params)?);
+        Self::exec_plan(context, plan).await
+    }
+
+    async fn exec_plan(context: Arc<QueryContext>, plan: Plan) -> Result<QueryResult> {
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
+        let has_result_set = has_result_set_by_plan(&plan);
+
+        match interpreter {
+            Ok(interpreter) => {
+                let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
+                let schema = interpreter.schema();
+                Ok(QueryResult::create(
+                    blocks,
+                    extra_info,
+                    has_result_set,
+                    schema,
+                ))
+            }
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                Err(e)
             }
         }
     }
@@ -440,6 +559,8 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorker<W> {
         InteractiveWorker::<W> {
             base: InteractiveWorkerBase::<W> {
                 session,
+                prepared_statements: HashMap::new(),
+                next_statement_id: 0,
                 generic_hold: PhantomData::default(),
             },
             salt: scramble,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod prepared_statement;
 mod query_affect;
 pub mod query_ctx;
 mod query_ctx_shared;
@@ -24,6 +25,7 @@ mod session_status;
 mod session_type;
 
 pub use common_catalog::table_context::TableContext;
+pub use prepared_statement::PreparedStatement;
 pub use query_affect::QueryAffect;
 pub use query_ctx::QueryContext;
 pub use query_ctx_shared::QueryContextShared;
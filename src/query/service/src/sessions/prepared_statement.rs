@@ -0,0 +1,256 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::ast::Statement;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::Backtrace;
+use common_ast::Dialect;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_literal;
+use common_expression::types::DataType;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::Literal;
+use common_sql::bind_parameters;
+use common_sql::parameter_count;
+use common_sql::plans::Plan;
+use common_sql::substitute_parameters;
+use common_sql::Planner;
+use parking_lot::RwLock;
+
+use crate::sessions::QueryContext;
+
+/// A statement with the parameter placeholders `?` or `$n`.
+///
+/// The optimized plans of a query are cached by the types of the parameters, so executing
+/// it again skips parsing, binding and optimizing, only the parameters are bound to the
+/// cached plan. The parameters of the other statements are substituted into the SQL text.
+pub struct PreparedStatement {
+    sql: String,
+    param_count: usize,
+    is_query: bool,
+    /// The plans keyed by the current database, the changed settings and the types of
+    /// the parameters, which all affect planning.
+    plans: RwLock<HashMap<(String, Vec<DataType>), Plan>>,
+}
+
+impl PreparedStatement {
+    pub fn try_create(sql: &str, sql_dialect: Dialect) -> Result<Self> {
+        // Values inputs of `INSERT` (e.g. CSV) may not be valid for the tokenizer, they are
+        // neither queries nor counted for the placeholders.
+        let (param_count, is_query) = match tokenize_sql(sql) {
+            Ok(tokens) => {
+                let param_count = parameter_count(&tokens)?;
+                let is_query = matches!(
+                    parse_sql(&tokens, sql_dialect, &Backtrace::new()),
+                    Ok((Statement::Query(_), _))
+                );
+                (param_count, is_query)
+            }
+            Err(_) => (0, false),
+        };
+        Ok(PreparedStatement {
+            sql: sql.to_string(),
+            param_count,
+            is_query,
+            plans: Default::default(),
+        })
+    }
+
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    pub fn param_count(&self) -> usize {
+        self.param_count
+    }
+
+    /// The SQL text with the parameters substituted, which is recorded as the query text.
+    pub fn render(&self, params: &[Literal]) -> Result<String> {
+        self.check_params(params)?;
+        substitute_parameters(&self.sql, params)
+    }
+
+    pub async fn plan(&self, ctx: &Arc<QueryContext>, params: &[Literal]) -> Result<Plan> {
+        self.check_params(params)?;
+        if !self.is_query {
+            let sql = substitute_parameters(&self.sql, params)?;
+            let (plan, _, _) = Planner::new(ctx.clone()).plan_sql(&sql).await?;
+            return Ok(plan);
+        }
+
+        let parameter_types = params
+            .iter()
+            .map(|param| check_literal(param).1)
+            .collect::<Vec<_>>();
+        let key = (plan_context(ctx), parameter_types.clone());
+        let cached = self.plans.read().get(&key).cloned();
+        if let Some(plan) = cached {
+            if let Some(plan) = refresh_tables(ctx, &plan).await? {
+                return bind_plan_parameters(&plan, params);
+            }
+            self.plans.write().remove(&key);
+        }
+
+        let mut planner = Planner::new(ctx.clone()).with_parameter_types(parameter_types);
+        let plan = match planner.plan_sql(&self.sql).await {
+            Ok((plan, _, _)) => plan,
+            // Some clauses only accept literals, e.g. `LIMIT ?`.
+            Err(e) if e.code() == ErrorCode::UNSUPPORTED_PLACEHOLDER => {
+                let sql = substitute_parameters(&self.sql, params)?;
+                let (plan, _, _) = Planner::new(ctx.clone()).plan_sql(&sql).await?;
+                return Ok(plan);
+            }
+            Err(e) => return Err(e),
+        };
+        if is_cacheable(ctx, &plan).await {
+            self.plans.write().insert(key, plan.clone());
+        }
+        bind_plan_parameters(&plan, params)
+    }
+
+    /// Describe the statement before the parameters are bound, returns the types of the
+    /// parameters inferred from the placeholders and the schema of the result.
+    ///
+    /// The statements other than queries aren't planned, their parameters are described as
+    /// strings and their results as unknown.
+    pub async fn describe(
+        &self,
+        ctx: &Arc<QueryContext>,
+    ) -> Result<(Vec<DataType>, DataSchemaRef)> {
+        let unknown = || {
+            let parameter_types = vec![DataType::String; self.param_count];
+            (parameter_types, Arc::new(DataSchema::empty()))
+        };
+        if !self.is_query {
+            return Ok(unknown());
+        }
+
+        let mut planner = Planner::new(ctx.clone()).with_inferred_parameter_types();
+        match planner.plan_sql(&self.sql).await {
+            Ok((plan, metadata, _)) => {
+                let parameter_types = metadata.read().inferred_parameter_types(self.param_count);
+                Ok((parameter_types, plan.schema()))
+            }
+            Err(e) if e.code() == ErrorCode::UNSUPPORTED_PLACEHOLDER => Ok(unknown()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn check_params(&self, params: &[Literal]) -> Result<()> {
+        if params.len() != self.param_count {
+            return Err(ErrorCode::BadArguments(format!(
+                "The statement expects {} parameters, but got {}",
+                self.param_count,
+                params.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn plan_context(ctx: &Arc<QueryContext>) -> String {
+    let settings = ctx
+        .get_settings()
+        .get_setting_values()
+        .into_iter()
+        .filter(|(_, value, default_value, _, _)| value != default_value)
+        .map(|(name, value, _, _, _)| format!("{}={}", name, value))
+        .collect::<Vec<_>>();
+    format!(
+        "{}.{}\n{}",
+        ctx.get_current_catalog(),
+        ctx.get_current_database(),
+        settings.join(",")
+    )
+}
+
+/// A plan can be cached if its tables can be resolved again by their names, and it
+/// isn't rewritten to read the materialized views, which depend on the snapshots.
+async fn is_cacheable(ctx: &Arc<QueryContext>, plan: &Plan) -> bool {
+    match plan {
+        Plan::Query { metadata, .. } if !metadata.read().has_materialized_views() => {
+            matches!(refresh_tables(ctx, plan).await, Ok(Some(_)))
+        }
+        _ => false,
+    }
+}
+
+/// Resolve the tables of a cached plan again to read their latest snapshots, or the ones at
+/// the points they are navigated to, returns `None` if any of the tables is dropped or
+/// altered after the plan is cached.
+async fn refresh_tables(ctx: &Arc<QueryContext>, plan: &Plan) -> Result<Option<Plan>> {
+    let Plan::Query { s_expr, metadata, bind_context, rewrite_kind, ignore_result } = plan else {
+        return Ok(None);
+    };
+
+    let mut metadata = metadata.read().clone();
+    let entries = metadata.tables().to_vec();
+    for entry in entries {
+        let table = match ctx
+            .get_table(entry.catalog(), entry.database(), entry.name())
+            .await
+        {
+            Ok(table) => table,
+            Err(_) => return Ok(None),
+        };
+        // e.g. `t AT (SNAPSHOT => '...')` keeps reading the snapshot, and `t AT (BRANCH => b)`
+        // reads the latest head of the branch.
+        let table = match entry.navigation_point() {
+            Some(navigation_point) => match table.navigate_to(navigation_point).await {
+                Ok(table) => table,
+                Err(_) => return Ok(None),
+            },
+            None => table,
+        };
+        let cached = entry.table();
+        if table.get_id() != cached.get_id() || table.schema() != cached.schema() {
+            return Ok(None);
+        }
+        metadata.replace_table(entry.index(), table);
+    }
+
+    Ok(Some(Plan::Query {
+        s_expr: s_expr.clone(),
+        metadata: Arc::new(RwLock::new(metadata)),
+        bind_context: bind_context.clone(),
+        rewrite_kind: rewrite_kind.clone(),
+        ignore_result: *ignore_result,
+    }))
+}
+
+fn bind_plan_parameters(plan: &Plan, params: &[Literal]) -> Result<Plan> {
+    match plan {
+        Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            rewrite_kind,
+            ignore_result,
+        } => Ok(Plan::Query {
+            s_expr: Box::new(bind_parameters(s_expr, params)?),
+            metadata: metadata.clone(),
+            bind_context: bind_context.clone(),
+            rewrite_kind: rewrite_kind.clone(),
+            ignore_result: *ignore_result,
+        }),
+        _ => Ok(plan.clone()),
+    }
+}
@@ -33,6 +33,7 @@ use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::StageTableInfo;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table_context::StageAttachment;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
@@ -42,6 +43,7 @@ use common_expression::FunctionContext;
 use common_expression::Scalar;
 use common_io::prelude::FormatSettings;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_meta_types::RoleInfo;
 use common_meta_types::UserInfo;
 use common_settings::Settings;
@@ -386,6 +388,26 @@ impl TableContext for QueryContext {
         self.shared.consume_precommit_blocks()
     }
 
+    fn set_runtime_filter(&self, filters: (usize, RuntimeFilterInfo)) {
+        self.shared.set_runtime_filter(filters)
+    }
+
+    fn get_runtime_filter_with_id(&self, scan_id: usize) -> Option<Arc<RuntimeFilterInfo>> {
+        self.shared.get_runtime_filter_with_id(scan_id)
+    }
+
+    fn add_consumed_stream(&self, req: UpdateStreamMetaReq) {
+        self.shared.add_consumed_stream(req)
+    }
+
+    fn get_consumed_streams(&self) -> Vec<UpdateStreamMetaReq> {
+        self.shared.get_consumed_streams()
+    }
+
+    fn clear_consumed_streams(&self) {
+        self.shared.clear_consumed_streams()
+    }
+
     /// Fetch a Table by db and table name.
     ///
     /// It guaranteed to return a consistent result for multiple calls, in a same query.
@@ -22,11 +22,13 @@ use std::time::SystemTime;
 
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table_context::StageAttachment;
 use common_config::Config;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_meta_types::RoleInfo;
 use common_meta_types::UserInfo;
 use common_settings::Settings;
@@ -80,6 +82,10 @@ pub struct QueryContextShared {
     pub(in crate::sessions) stage_attachment: Arc<RwLock<Option<StageAttachment>>>,
     pub(in crate::sessions) created_time: SystemTime,
     pub(in crate::sessions) on_error_map: Arc<RwLock<Option<HashMap<String, ErrorCode>>>>,
+    /// Runtime filters pushed down from the hash join build side, keyed by the scan id.
+    pub(in crate::sessions) runtime_filters: Arc<RwLock<HashMap<usize, Arc<RuntimeFilterInfo>>>>,
+    /// New offsets of the streams read by the query, keyed by the stream id.
+    pub(in crate::sessions) consumed_streams: Arc<RwLock<HashMap<u64, UpdateStreamMetaReq>>>,
 }
 
 impl QueryContextShared {
@@ -110,6 +116,8 @@ impl QueryContextShared {
             stage_attachment: Arc::new(RwLock::new(None)),
             created_time: SystemTime::now(),
             on_error_map: Arc::new(RwLock::new(None)),
+            runtime_filters: Arc::new(RwLock::new(HashMap::new())),
+            consumed_streams: Arc::new(RwLock::new(HashMap::new())),
         }))
     }
 
@@ -319,6 +327,35 @@ impl QueryContextShared {
         swaped_precommit_blocks
     }
 
+    pub fn set_runtime_filter(&self, filters: (usize, RuntimeFilterInfo)) {
+        let mut runtime_filters = self.runtime_filters.write();
+        match runtime_filters.entry(filters.0) {
+            Entry::Vacant(v) => {
+                v.insert(Arc::new(filters.1));
+            }
+            Entry::Occupied(mut v) => {
+                // The readers keep their own `Arc`, so the filters are copied only if still in use.
+                Arc::make_mut(v.get_mut()).merge(filters.1);
+            }
+        }
+    }
+
+    pub fn get_runtime_filter_with_id(&self, scan_id: usize) -> Option<Arc<RuntimeFilterInfo>> {
+        self.runtime_filters.read().get(&scan_id).cloned()
+    }
+
+    pub fn add_consumed_stream(&self, req: UpdateStreamMetaReq) {
+        self.consumed_streams.write().insert(req.stream_id, req);
+    }
+
+    pub fn get_consumed_streams(&self) -> Vec<UpdateStreamMetaReq> {
+        self.consumed_streams.read().values().cloned().collect()
+    }
+
+    pub fn clear_consumed_streams(&self) {
+        self.consumed_streams.write().clear();
+    }
+
     pub fn get_stage_attachment(&self) -> Option<StageAttachment> {
         self.stage_attachment.read().clone()
     }
@@ -32,6 +32,7 @@ use parking_lot::RwLock;
 
 use crate::clusters::ClusterDiscovery;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::sessions::PreparedStatement;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryContextShared;
 use crate::sessions::SessionContext;
@@ -344,6 +345,11 @@ impl Session {
         self.session_ctx.apply_changed_settings(changed_settings)
     }
 
+    /// Get the prepared statement of the SQL, the plans of which are cached in the session.
+    pub fn get_prepared_statement(self: &Arc<Self>, sql: &str) -> Result<Arc<PreparedStatement>> {
+        self.session_ctx.get_prepared_statement(sql)
+    }
+
     pub fn get_memory_usage(self: &Arc<Self>) -> usize {
         // TODO(winter): use thread memory tracker
         0
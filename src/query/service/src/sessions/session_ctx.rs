@@ -25,9 +25,16 @@ use common_meta_types::UserInfo;
 use common_settings::Settings;
 use futures::channel::oneshot::Sender;
 use parking_lot::RwLock;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache::InMemoryCacheBuilder;
+use storages_common_cache::InMemoryItemCacheHolder;
 
+use crate::sessions::PreparedStatement;
 use crate::sessions::QueryContextShared;
 
+/// The max number of prepared statements kept in a session.
+static MAX_PREPARED_STATEMENTS: u64 = 256;
+
 pub struct SessionContext {
     abort: AtomicBool,
     settings: Arc<Settings>,
@@ -51,6 +58,9 @@ pub struct SessionContext {
     client_host: RwLock<Option<SocketAddr>>,
     io_shutdown_tx: RwLock<Option<Sender<Sender<()>>>>,
     query_context_shared: RwLock<Weak<QueryContextShared>>,
+    // The prepared statements keyed by the SQL dialect and text, which cache the plans of the
+    // statements.
+    prepared_statements: InMemoryItemCacheHolder<PreparedStatement>,
 }
 
 impl SessionContext {
@@ -67,6 +77,7 @@ impl SessionContext {
             current_database: RwLock::new("default".to_string()),
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
+            prepared_statements: InMemoryCacheBuilder::new_item_cache(MAX_PREPARED_STATEMENTS),
         }))
     }
 
@@ -212,4 +223,16 @@ impl SessionContext {
         let mut lock = self.query_context_shared.write();
         *lock = ctx
     }
+
+    pub fn get_prepared_statement(&self, sql: &str) -> Result<Arc<PreparedStatement>> {
+        // The statement is parsed by the dialect, which is a part of the key.
+        let sql_dialect = self.settings.get_sql_dialect()?;
+        let key = format!("{:?}\n{}", sql_dialect, sql);
+        if let Some(statement) = self.prepared_statements.get(&key) {
+            return Ok(statement);
+        }
+        let statement = Arc::new(PreparedStatement::try_create(sql, sql_dialect)?);
+        self.prepared_statements.put(key, statement.clone());
+        Ok(statement)
+    }
 }
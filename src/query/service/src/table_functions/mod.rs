@@ -14,6 +14,7 @@
 
 mod async_crash_me;
 mod numbers;
+mod result_scan;
 mod sync_crash_me;
 mod table_function;
 mod table_function_factory;
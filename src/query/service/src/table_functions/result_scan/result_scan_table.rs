@@ -0,0 +1,174 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::infer_table_schema;
+use common_expression::Scalar;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use parking_lot::Mutex;
+
+use crate::interpreters::QueryResult;
+use crate::interpreters::QueryResultCache;
+use crate::pipelines::processors::BlocksSource;
+use crate::pipelines::Pipeline;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+use crate::table_functions::table_function_factory::TableArgs;
+use crate::table_functions::TableFunction;
+
+/// `RESULT_SCAN(query_id)` reads back the result of a prior query from the query result cache.
+pub struct ResultScanTable {
+    table_info: TableInfo,
+    query_id: String,
+    result: Arc<QueryResult>,
+}
+
+impl ResultScanTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let query_id = match table_args.as_deref() {
+            Some([Scalar::String(query_id)]) => String::from_utf8(query_id.clone())?,
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "result_scan only accepts a query id string",
+                ));
+            }
+        };
+        let result = QueryResultCache::instance()
+            .get_by_query_id(&query_id)
+            .ok_or_else(|| result_not_found(&query_id))?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: infer_table_schema(&result.schema)?,
+                engine: "ResultScan".to_string(),
+                // Assuming that created_on is unnecessary for function table,
+                // we could make created_on fixed to pass test_shuffle_action_try_into.
+                created_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp(0, 0)),
+                updated_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp(0, 0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(ResultScanTable {
+            table_info,
+            query_id,
+            result,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ResultScanTable {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _: Arc<dyn TableContext>,
+        _: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let num_rows = self
+            .result
+            .blocks
+            .iter()
+            .map(|block| block.num_rows())
+            .sum::<usize>();
+        let num_bytes = self
+            .result
+            .blocks
+            .iter()
+            .map(|block| block.memory_size())
+            .sum::<usize>();
+        Ok((
+            PartStatistics::new_exact(num_rows, num_bytes, 0, 0),
+            Partitions::default(),
+        ))
+    }
+
+    fn table_args(&self) -> Option<Vec<Scalar>> {
+        Some(vec![Scalar::String(self.query_id.as_bytes().to_vec())])
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        // Only the user who ran the query can read its result.
+        if self.result.tenant != ctx.get_tenant()
+            || self.result.user != ctx.get_current_user()?.identity().to_string()
+        {
+            return Err(result_not_found(&self.query_id));
+        }
+
+        let blocks = Arc::new(Mutex::new(VecDeque::from(self.result.blocks.clone())));
+        pipeline.add_source(
+            |output| BlocksSource::create(ctx.clone(), output, blocks.clone()),
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl TableFunction for ResultScanTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+fn result_not_found(query_id: &str) -> ErrorCode {
+    ErrorCode::BadArguments(format!(
+        "The result of query '{}' is not found in the query result cache",
+        query_id
+    ))
+}
@@ -31,6 +31,7 @@ use crate::storages::fuse::table_functions::FuseSnapshotTable;
 use crate::storages::fuse::table_functions::FuseStatisticTable;
 use crate::table_functions::async_crash_me::AsyncCrashMeTable;
 use crate::table_functions::numbers::NumbersTable;
+use crate::table_functions::result_scan::ResultScanTable;
 use crate::table_functions::sync_crash_me::SyncCrashMeTable;
 use crate::table_functions::TableFunction;
 
@@ -137,6 +138,11 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(ParquetTable::create_table_function)),
         );
 
+        creators.insert(
+            "result_scan".to_string(),
+            (next_id(), Arc::new(ResultScanTable::create)),
+        );
+
         TableFunctionFactory {
             creators: RwLock::new(creators),
         }
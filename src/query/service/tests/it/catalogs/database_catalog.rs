@@ -192,6 +192,7 @@ async fn test_catalogs_table() -> Result<()> {
                 created_on,
                 ..TableMeta::default()
             },
+            dependency: None,
         };
         let res = catalog.create_table(req.clone()).await;
         assert!(res.is_ok());
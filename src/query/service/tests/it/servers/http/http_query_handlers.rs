@@ -534,6 +534,25 @@ async fn test_insert() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_query_with_params() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let json = serde_json::json!({"sql": "select $1 + 1, $2", "params": [1, "a"], "pagination": {"wait_time_secs": 3}});
+    let (status, result) = post_json(&json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.data.len(), 1, "{:?}", result);
+    assert_eq!(result.data[0][0], "2", "{:?}", result);
+    assert_eq!(result.data[0][1], "a", "{:?}", result);
+
+    let json = serde_json::json!({"sql": "select ? + ?", "params": [1], "pagination": {"wait_time_secs": 3}});
+    let (status, result) = post_json(&json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_some(), "{:?}", result);
+    Ok(())
+}
+
 // Wait for https://github.com/datafuselabs/databend/issues/7831 to be fixed, then remove ignore
 #[ignore]
 #[tokio::test(flavor = "current_thread")]
@@ -23,6 +23,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use databend_query::servers::MySQLHandler;
+use mysql_async::consts::ColumnType;
 use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
@@ -49,6 +50,114 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = MySQLHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+    let statement = connection
+        .prep("SELECT number + ? FROM numbers(3) WHERE number > ? ORDER BY number")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    assert_eq!(statement.num_params(), 2);
+    // The types of the parameters are inferred from the columns compared with.
+    for param in statement.params() {
+        assert_eq!(param.column_type(), ColumnType::MYSQL_TYPE_LONGLONG);
+    }
+    assert_eq!(statement.columns().len(), 1);
+
+    // Execute the statement again with other parameters.
+    let rows: Vec<u64> = connection
+        .exec(&statement, (10u64, 0u64))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(rows, vec![11, 12]);
+    let rows: Vec<u64> = connection
+        .exec(&statement, (20u64, 1u64))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(rows, vec![22]);
+
+    // Wrong number of parameters.
+    let result: std::result::Result<Vec<u64>, _> = connection.exec(&statement, (1u64,)).await;
+    assert!(result.is_err());
+
+    // The parameters of the clauses only accepting constants are substituted.
+    let statement = connection
+        .prep("SELECT number FROM numbers(10) ORDER BY number LIMIT ?")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    let rows: Vec<u64> = connection
+        .exec(&statement, (2u64,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(rows, vec![0, 1]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement_with_time_travel() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = MySQLHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+    for query in [
+        "CREATE TABLE t_travel(a INT)",
+        "INSERT INTO t_travel VALUES (1)",
+    ] {
+        connection
+            .query_drop(query)
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    }
+    let snapshot_id: Option<String> = connection
+        .query_first("SELECT snapshot_id FROM fuse_snapshot('default', 't_travel')")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    let snapshot_id = snapshot_id.unwrap();
+
+    let statement = connection
+        .prep(format!(
+            "SELECT a FROM t_travel AT (SNAPSHOT => '{}') WHERE a > ? ORDER BY a",
+            snapshot_id
+        ))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    let latest = connection
+        .prep("SELECT a FROM t_travel WHERE a > ? ORDER BY a")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    for expected in [vec![1], vec![1, 2]] {
+        let rows: Vec<i32> = connection
+            .exec(&latest, (0u64,))
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+        assert_eq!(rows, expected);
+
+        // The cached plan keeps reading the snapshot it's navigated to.
+        let rows: Vec<i32> = connection
+            .exec(&statement, (0u64,))
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+        assert_eq!(rows, vec![1]);
+
+        connection
+            .query_drop("INSERT INTO t_travel VALUES (2)")
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     let _guard =
@@ -26,6 +26,7 @@ use common_storages_fuse::FuseStorageFormat;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
 use storages_common_index::BloomIndex;
+use storages_common_index::TextIndexOptions;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::Compression;
@@ -87,6 +88,8 @@ impl<'a> BlockWriter<'a> {
             bloom_filter_index_location,
             bloom_filter_index_size,
             Compression::Lz4Raw,
+            None,
+            0,
         );
         Ok(block_meta)
     }
@@ -102,8 +105,13 @@ impl<'a> BlockWriter<'a> {
             .location_generator
             .block_bloom_index_location(&block_id);
 
-        let maybe_bloom_index =
-            BloomIndex::try_create(FunctionContext::default(), schema, location.1, &[block])?;
+        let maybe_bloom_index = BloomIndex::try_create(
+            FunctionContext::default(),
+            schema,
+            location.1,
+            &[block],
+            &TextIndexOptions::default(),
+        )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
             let filter_schema = bloom_index.filter_schema;
@@ -26,6 +26,7 @@ use common_catalog::database::Database;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table::Table;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
@@ -58,6 +59,7 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateStreamMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReply;
@@ -459,6 +461,26 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn set_runtime_filter(&self, _filters: (usize, RuntimeFilterInfo)) {
+        todo!()
+    }
+
+    fn get_runtime_filter_with_id(&self, _scan_id: usize) -> Option<Arc<RuntimeFilterInfo>> {
+        todo!()
+    }
+
+    fn add_consumed_stream(&self, req: UpdateStreamMetaReq) {
+        self.ctx.add_consumed_stream(req)
+    }
+
+    fn get_consumed_streams(&self) -> Vec<UpdateStreamMetaReq> {
+        self.ctx.get_consumed_streams()
+    }
+
+    fn clear_consumed_streams(&self) {
+        self.ctx.clear_consumed_streams()
+    }
+
     async fn get_table(
         &self,
         _catalog: &str,
@@ -30,6 +30,7 @@ use databend_query::sessions::TableContext;
 use databend_query::storages::fuse::io::SegmentWriter;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
 use databend_query::storages::fuse::operations::ReclusterMutator;
+use storages_common_index::TextIndexOptions;
 use storages_common_table_meta::meta;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
@@ -64,6 +65,8 @@ async fn test_recluster_mutator_block_select() -> Result<()> {
             None,
             0,
             meta::Compression::Lz4Raw,
+            None,
+            0,
         ));
         let segment = SegmentInfo::new(vec![test_block_meta], Statistics::default());
         Ok::<_, ErrorCode>((seg_writer.write_segment(segment).await?, location))
@@ -119,9 +122,15 @@ async fn test_recluster_mutator_block_select() -> Result<()> {
     let schema = TableSchemaRef::new(TableSchema::empty());
     let ctx: Arc<dyn TableContext> = ctx.clone();
     let segment_locations = base_snapshot.segments.clone();
-    let block_metas = FusePruner::create(&ctx, data_accessor.clone(), schema, &None)?
-        .pruning(segment_locations)
-        .await?;
+    let block_metas = FusePruner::create(
+        &ctx,
+        data_accessor.clone(),
+        schema,
+        &None,
+        &TextIndexOptions::default(),
+    )?
+    .pruning(segment_locations)
+    .await?;
     let mut blocks_map: BTreeMap<i32, Vec<(usize, Arc<BlockMeta>)>> = BTreeMap::new();
     block_metas.iter().for_each(|(idx, b)| {
         if let Some(stats) = &b.cluster_stats {
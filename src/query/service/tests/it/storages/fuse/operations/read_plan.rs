@@ -94,6 +94,8 @@ fn test_to_partitions() -> Result<()> {
         bloom_filter_location,
         bloom_filter_size,
         meta::Compression::Lz4Raw,
+        None,
+        0,
     ));
 
     let blocks_metas = (0..num_of_block)
@@ -139,6 +141,7 @@ fn test_to_partitions() -> Result<()> {
         limit: None,
         order_by: vec![],
         prewhere: None,
+        virtual_columns: None,
     });
 
     let (stats, parts) = FuseTable::to_partitions(&blocks_metas, &column_nodes, None, push_down);
@@ -179,6 +182,7 @@ async fn test_fuse_table_exact_statistic() -> Result<()> {
             prewhere: None,
             limit: None,
             order_by: vec![],
+            virtual_columns: None,
         };
         let (stats, parts) = table.read_partitions(ctx.clone(), Some(push_downs)).await?;
         assert_eq!(stats.read_rows, num_blocks * rows_per_block);
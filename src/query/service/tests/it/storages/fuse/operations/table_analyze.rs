@@ -20,6 +20,7 @@ use common_catalog::table::Table;
 use common_exception::Result;
 use common_expression::types::number::NumberScalar;
 use common_expression::DataBlock;
+use common_expression::Scalar;
 use common_expression::ScalarRef;
 use common_expression::SendableDataBlockStream;
 use databend_query::sessions::QueryContext;
@@ -87,6 +88,83 @@ async fn test_table_modify_column_ndv_statistics() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_analyze_column_histogram() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    // setup
+    let create_tbl_command = "create table h(c int, s string)";
+    execute_command(ctx.clone(), create_tbl_command).await?;
+    let insert_command =
+        "insert into h values(1, 'a'), (1, 'a'), (1, 'a'), (2, 'b'), (3, null), (null, 'c')";
+    execute_command(ctx.clone(), insert_command).await?;
+    execute_command(ctx.clone(), "analyze table default.h").await?;
+
+    let catalog = ctx.get_catalog("default")?;
+    let table = catalog
+        .get_table(ctx.get_tenant().as_str(), "default", "h")
+        .await?;
+    let provider = table.column_statistics_provider().await?;
+
+    // the skewed value gets its own bucket
+    let stat = provider.column_statistics(0).unwrap();
+    assert_eq!(stat.null_count, 1);
+    let histogram = stat.histogram.unwrap();
+    assert_eq!(histogram.len(), 3);
+    assert_eq!(
+        histogram[0].upper_bound,
+        Scalar::Number(NumberScalar::Int32(1))
+    );
+    assert_eq!(histogram[0].num_values, 3.0);
+    assert_eq!(
+        histogram[2].upper_bound,
+        Scalar::Number(NumberScalar::Int32(3))
+    );
+    let num_values: f64 = histogram.iter().map(|bucket| bucket.num_values).sum();
+    assert_eq!(num_values, 5.0);
+
+    let stat = provider.column_statistics(1).unwrap();
+    assert_eq!(stat.null_count, 1);
+    let histogram = stat.histogram.unwrap();
+    assert_eq!(histogram.len(), 3);
+    assert_eq!(histogram[0].upper_bound, Scalar::String(b"a".to_vec()));
+    assert_eq!(histogram[0].num_values, 3.0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_analyze_native_column_histogram() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    // setup
+    let create_tbl_command = "create table hn(c int) storage_format = 'native'";
+    execute_command(ctx.clone(), create_tbl_command).await?;
+    let insert_command = "insert into hn values(1), (1), (1), (2), (3), (null)";
+    execute_command(ctx.clone(), insert_command).await?;
+    execute_command(ctx.clone(), "analyze table default.hn").await?;
+
+    let catalog = ctx.get_catalog("default")?;
+    let table = catalog
+        .get_table(ctx.get_tenant().as_str(), "default", "hn")
+        .await?;
+    let provider = table.column_statistics_provider().await?;
+
+    let stat = provider.column_statistics(0).unwrap();
+    assert_eq!(stat.null_count, 1);
+    let histogram = stat.histogram.unwrap();
+    assert_eq!(histogram.len(), 3);
+    assert_eq!(
+        histogram[0].upper_bound,
+        Scalar::Number(NumberScalar::Int32(1))
+    );
+    assert_eq!(histogram[0].num_values, 3.0);
+
+    Ok(())
+}
+
 async fn check_column_ndv_statistics(
     table: Arc<dyn Table>,
     expected: HashMap<u32, u64>,
@@ -42,6 +42,7 @@ use databend_query::storages::fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_query::storages::fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
+use storages_common_index::TextIndexOptions;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
@@ -59,7 +60,7 @@ async fn apply_block_pruning(
 ) -> Result<Vec<Arc<BlockMeta>>> {
     let ctx: Arc<dyn TableContext> = ctx;
     let segment_locs = table_snapshot.segments.clone();
-    FusePruner::create(&ctx, op, schema, push_down)?
+    FusePruner::create(&ctx, op, schema, push_down, &TextIndexOptions::default())?
         .pruning(segment_locs)
         .await
         .map(|v| v.into_iter().map(|(_, v)| v).collect())
@@ -100,6 +101,7 @@ async fn test_block_pruner() -> Result<()> {
         field_default_exprs: vec![],
         field_comments: vec![],
         as_select: None,
+        clone_source: None,
         cluster_key: None,
     };
 
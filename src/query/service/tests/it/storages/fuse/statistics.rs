@@ -518,6 +518,8 @@ fn test_reduce_block_meta() -> common_exception::Result<()> {
             None,
             bloom_filter_index_size,
             Compression::Lz4Raw,
+            None,
+            0,
         );
         blocks.push(block_meta);
     }
@@ -68,6 +68,7 @@ async fn test_fuse_table_normal_case() -> Result<()> {
                 description: "".to_string(),
                 tbl_args: None,
                 push_downs: None,
+                scan_id: 0,
             })
             .await?;
         let blocks = stream.try_collect::<Vec<_>>().await?;
@@ -128,6 +129,7 @@ async fn test_fuse_table_normal_case() -> Result<()> {
                 description: "".to_string(),
                 tbl_args: None,
                 push_downs: None,
+                scan_id: 0,
             })
             .await?;
         let blocks = stream.try_collect::<Vec<_>>().await?;
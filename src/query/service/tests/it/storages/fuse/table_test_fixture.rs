@@ -175,6 +175,7 @@ impl TestFixture {
             field_default_exprs: vec![],
             field_comments: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: Some("(id)".to_string()),
         }
     }
@@ -199,6 +200,7 @@ impl TestFixture {
             field_default_exprs: vec![],
             field_comments: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
         }
     }
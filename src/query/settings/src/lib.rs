@@ -463,6 +463,106 @@ impl Settings {
                 desc: "Enable generating bushy join plan in optimizer",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1),
+                user_setting: UserSetting::create(
+                    "enable_runtime_filter",
+                    UserSettingValue::UInt64(1),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Enable pushing the filters built from the hash join build side down to the probe side table scans, default value: 1.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1),
+                user_setting: UserSetting::create(
+                    "enable_dp_join_reorder",
+                    UserSettingValue::UInt64(1),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Enable reordering the inner joins by dynamic programming before the cascades optimizer, default value: 1.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(10),
+                user_setting: UserSetting::create(
+                    "join_reorder_dp_threshold",
+                    UserSettingValue::UInt64(10),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The max number of relations to reorder the joins by dynamic programming, the joins with more relations are reordered greedily, default value: 10.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1),
+                user_setting: UserSetting::create(
+                    "enable_materialized_cte",
+                    UserSettingValue::UInt64(1),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Enable materializing the common table expressions referenced more than once, instead of computing them for each reference, default value: 1.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(256 * 1024 * 1024),
+                user_setting: UserSetting::create(
+                    "materialized_cte_spill_threshold",
+                    UserSettingValue::UInt64(256 * 1024 * 1024),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The max bytes of a materialized common table expression kept in memory, the blocks beyond it are spilled to the storage, default value: 268435456.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_materialized_view_rewrite",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Enable answering aggregate queries from fresh materialized views over the same table, default value: 0.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1000),
+                user_setting: UserSetting::create(
+                    "max_cte_recursion_depth",
+                    UserSettingValue::UInt64(1000),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The max number of iterations of the recursive term of a recursive CTE, default value: 1000.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1024 * 1024 * 1024),
+                user_setting: UserSetting::create(
+                    "max_cte_union_memory_usage",
+                    UserSettingValue::UInt64(1024 * 1024 * 1024),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The max bytes of the rows kept to remove the duplicates of a recursive CTE with UNION, default value: 1073741824.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_query_result_cache",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Enable caching the results of queries over fuse tables, which are reused until the tables change, default value: 0.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(300),
+                user_setting: UserSetting::create(
+                    "query_result_cache_ttl",
+                    UserSettingValue::UInt64(300),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The time in seconds for which a cached query result can be reused, default value: 300.",
+                possible_values: None,
+            },
         ];
 
         let settings: Arc<DashMap<String, SettingValue>> = Arc::new(DashMap::default());
@@ -622,6 +722,61 @@ impl Settings {
         self.try_get_u64(KEY)
     }
 
+    pub fn get_enable_runtime_filter(&self) -> Result<bool> {
+        static KEY: &str = "enable_runtime_filter";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_enable_dp_join_reorder(&self) -> Result<bool> {
+        static KEY: &str = "enable_dp_join_reorder";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_enable_materialized_cte(&self) -> Result<bool> {
+        static KEY: &str = "enable_materialized_cte";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_materialized_cte_spill_threshold(&self) -> Result<u64> {
+        static KEY: &str = "materialized_cte_spill_threshold";
+        self.try_get_u64(KEY)
+    }
+
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        static KEY: &str = "enable_materialized_view_rewrite";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_max_cte_recursion_depth(&self) -> Result<u64> {
+        static KEY: &str = "max_cte_recursion_depth";
+        self.try_get_u64(KEY)
+    }
+
+    pub fn get_max_cte_union_memory_usage(&self) -> Result<u64> {
+        static KEY: &str = "max_cte_union_memory_usage";
+        self.try_get_u64(KEY)
+    }
+
+    pub fn get_enable_query_result_cache(&self) -> Result<bool> {
+        static KEY: &str = "enable_query_result_cache";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_query_result_cache_ttl(&self) -> Result<u64> {
+        static KEY: &str = "query_result_cache_ttl";
+        self.try_get_u64(KEY)
+    }
+
+    pub fn get_join_reorder_dp_threshold(&self) -> Result<u64> {
+        static KEY: &str = "join_reorder_dp_threshold";
+        self.try_get_u64(KEY)
+    }
+
     pub fn get_timezone(&self) -> Result<String> {
         let key = "timezone";
         self.check_and_get_setting_value(key)
@@ -15,12 +15,15 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::BlockEntry;
 use common_expression::DataBlock;
 use common_expression::Evaluator;
 use common_expression::Expr;
 use common_expression::FunctionContext;
+use common_expression::Value;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_functions::srfs::BUILTIN_SRFS;
 use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
@@ -136,3 +139,69 @@ impl Transform for CompoundBlockOperator {
         )
     }
 }
+
+/// `ProjectSetOperator` evaluates a set-returning function, repeats each input row for the
+/// generated values and appends the values to the end. The input rows are numbered in the
+/// order they arrive at the processor.
+pub struct ProjectSetOperator {
+    ctx: FunctionContext,
+    func_name: String,
+    args: Vec<Expr>,
+    return_type: DataType,
+    num_input_rows: usize,
+}
+
+impl ProjectSetOperator {
+    pub fn create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        ctx: FunctionContext,
+        func_name: String,
+        args: Vec<Expr>,
+        return_type: DataType,
+    ) -> ProcessorPtr {
+        Transformer::<Self>::create(input_port, output_port, Self {
+            ctx,
+            func_name,
+            args,
+            return_type,
+            num_input_rows: 0,
+        })
+    }
+}
+
+impl Transform for ProjectSetOperator {
+    const NAME: &'static str = "ProjectSetOperator";
+
+    const SKIP_EMPTY_DATA_BLOCK: bool = true;
+
+    fn transform(&mut self, input: DataBlock) -> Result<DataBlock> {
+        let evaluator = Evaluator::new(&input, self.ctx, &BUILTIN_FUNCTIONS);
+        let args = self
+            .args
+            .iter()
+            .map(|arg| evaluator.run(arg))
+            .collect::<Result<Vec<_>>>()?;
+        let args = args.iter().map(|arg| arg.as_ref()).collect::<Vec<_>>();
+        let srf = BUILTIN_SRFS.get(&self.func_name).unwrap();
+        let (column, counts) = srf.eval(
+            &args,
+            input.num_rows(),
+            self.num_input_rows,
+            &self.return_type,
+        )?;
+        self.num_input_rows += input.num_rows();
+
+        let indices = counts
+            .iter()
+            .enumerate()
+            .flat_map(|(row, count)| std::iter::repeat(row as u32).take(*count))
+            .collect::<Vec<_>>();
+        let mut result = DataBlock::take(&input, &indices)?;
+        result.add_column(BlockEntry {
+            data_type: self.return_type.clone(),
+            value: Value::Column(column),
+        });
+        Ok(result)
+    }
+}
@@ -18,3 +18,4 @@ mod block_operator;
 
 pub use block_operator::BlockOperator;
 pub use block_operator::CompoundBlockOperator;
+pub use block_operator::ProjectSetOperator;
@@ -23,13 +23,17 @@ use itertools::Itertools;
 use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregatePartial;
+use super::CteScan;
 use super::EvalScalar;
 use super::Exchange;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MaterializedCte;
 use super::PhysicalPlan;
 use super::Project;
+use super::ProjectSet;
+use super::RecursiveCte;
 use super::Sort;
 use super::TableScan;
 use super::UnionAll;
@@ -54,6 +58,7 @@ fn to_format_tree(plan: &PhysicalPlan, metadata: &MetadataRef) -> Result<FormatT
         PhysicalPlan::Filter(plan) => filter_to_format_tree(plan, metadata),
         PhysicalPlan::Project(plan) => project_to_format_tree(plan, metadata),
         PhysicalPlan::EvalScalar(plan) => eval_scalar_to_format_tree(plan, metadata),
+        PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata),
         PhysicalPlan::AggregatePartial(plan) => aggregate_partial_to_format_tree(plan, metadata),
         PhysicalPlan::AggregateFinal(plan) => aggregate_final_to_format_tree(plan, metadata),
         PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata),
@@ -61,6 +66,9 @@ fn to_format_tree(plan: &PhysicalPlan, metadata: &MetadataRef) -> Result<FormatT
         PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata),
         PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata),
         PhysicalPlan::UnionAll(plan) => union_all_to_format_tree(plan, metadata),
+        PhysicalPlan::CteScan(plan) => cte_scan_to_format_tree(plan),
+        PhysicalPlan::MaterializedCte(plan) => materialized_cte_to_format_tree(plan, metadata),
+        PhysicalPlan::RecursiveCte(plan) => recursive_cte_to_format_tree(plan, metadata),
         PhysicalPlan::ExchangeSource(plan) => exchange_source_to_format_tree(plan),
         PhysicalPlan::ExchangeSink(plan) => exchange_sink_to_format_tree(plan, metadata),
         PhysicalPlan::DistributedInsertSelect(plan) => {
@@ -174,6 +182,7 @@ fn project_to_format_tree(
                 "{} (#{})",
                 match metadata.read().column(*column) {
                     ColumnEntry::BaseTableColumn { column_name, .. } => column_name,
+                    ColumnEntry::VirtualColumn { column_name, .. } => column_name,
                     ColumnEntry::DerivedColumn { alias, .. } => alias,
                 },
                 column
@@ -221,6 +230,34 @@ fn eval_scalar_to_format_tree(
     ))
 }
 
+fn project_set_to_format_tree(
+    plan: &ProjectSet,
+    metadata: &MetadataRef,
+) -> Result<FormatTreeNode<String>> {
+    let args = plan
+        .args
+        .iter()
+        .map(|arg| arg.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut children = vec![FormatTreeNode::new(format!(
+        "set returning function: {}({args})",
+        plan.func_name
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    children.push(to_format_tree(&plan.input, metadata)?);
+
+    Ok(FormatTreeNode::with_children(
+        "ProjectSet".to_string(),
+        children,
+    ))
+}
+
 pub fn pretty_display_agg_desc(desc: &AggregateFunctionDesc, metadata: &MetadataRef) -> String {
     format!(
         "{}({})",
@@ -231,6 +268,7 @@ pub fn pretty_display_agg_desc(desc: &AggregateFunctionDesc, metadata: &Metadata
                 let column = metadata.read().column(index).clone();
                 match column {
                     ColumnEntry::BaseTableColumn { column_name, .. } => column_name,
+                    ColumnEntry::VirtualColumn { column_name, .. } => column_name,
                     ColumnEntry::DerivedColumn { alias, .. } => alias,
                 }
             })
@@ -250,6 +288,7 @@ fn aggregate_partial_to_format_tree(
             let column = metadata.read().column(*column).clone();
             let name = match column {
                 ColumnEntry::BaseTableColumn { column_name, .. } => column_name,
+                ColumnEntry::VirtualColumn { column_name, .. } => column_name,
                 ColumnEntry::DerivedColumn { alias, .. } => alias,
             };
             Ok(name)
@@ -292,6 +331,7 @@ fn aggregate_final_to_format_tree(
             let column = metadata.read().column(*column).clone();
             let name = match column {
                 ColumnEntry::BaseTableColumn { column_name, .. } => column_name,
+                ColumnEntry::VirtualColumn { column_name, .. } => column_name,
                 ColumnEntry::DerivedColumn { alias, .. } => alias,
             };
             Ok(name)
@@ -340,6 +380,7 @@ fn sort_to_format_tree(plan: &Sort, metadata: &MetadataRef) -> Result<FormatTree
                 "{} {} {}",
                 match column {
                     ColumnEntry::BaseTableColumn { column_name, .. } => column_name,
+                    ColumnEntry::VirtualColumn { column_name, .. } => column_name,
                     ColumnEntry::DerivedColumn { alias, .. } => alias,
                 },
                 if sort_key.asc { "ASC" } else { "DESC" },
@@ -479,6 +520,53 @@ fn union_all_to_format_tree(
     ))
 }
 
+fn cte_scan_to_format_tree(plan: &CteScan) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!("CTE index: {}", plan.cte_idx))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    Ok(FormatTreeNode::with_children(
+        "CteScan".to_string(),
+        children,
+    ))
+}
+
+fn materialized_cte_to_format_tree(
+    plan: &MaterializedCte,
+    metadata: &MetadataRef,
+) -> Result<FormatTreeNode<String>> {
+    let children = vec![
+        FormatTreeNode::new(format!("CTE index: {}", plan.cte_idx)),
+        to_format_tree(&plan.left, metadata)?,
+        to_format_tree(&plan.right, metadata)?,
+    ];
+
+    Ok(FormatTreeNode::with_children(
+        "MaterializedCte".to_string(),
+        children,
+    ))
+}
+
+fn recursive_cte_to_format_tree(
+    plan: &RecursiveCte,
+    metadata: &MetadataRef,
+) -> Result<FormatTreeNode<String>> {
+    let children = vec![
+        FormatTreeNode::new(format!("CTE index: {}", plan.cte_idx)),
+        FormatTreeNode::new(format!("distinct: {}", plan.distinct)),
+        to_format_tree(&plan.left, metadata)?,
+        to_format_tree(&plan.right, metadata)?,
+    ];
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCte".to_string(),
+        children,
+    ))
+}
+
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
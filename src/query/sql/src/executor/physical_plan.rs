@@ -115,6 +115,30 @@ impl EvalScalar {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ProjectSet {
+    pub input: Box<PhysicalPlan>,
+    pub func_name: String,
+    pub args: Vec<RemoteExpr>,
+    pub return_type: DataType,
+    pub index: IndexType,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl ProjectSet {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        fields.push(DataField::new(
+            &self.index.to_string(),
+            self.return_type.clone(),
+        ));
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct AggregatePartial {
     pub input: Box<PhysicalPlan>,
@@ -227,11 +251,24 @@ pub struct HashJoin {
     pub join_type: JoinType,
     pub marker_index: Option<IndexType>,
     pub from_correlated_subquery: bool,
+    /// The probe side table scans which can be filtered by the build keys.
+    pub runtime_filter_targets: Vec<RuntimeFilterTarget>,
 
     /// Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
 }
 
+/// A probe side table scan to which the runtime filter built from a build key is pushed down.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RuntimeFilterTarget {
+    /// The offset of the build key in `HashJoin::build_keys`.
+    pub build_key: usize,
+    /// The `scan_id` of the `DataSourcePlan` of the probe side table scan.
+    pub scan_id: usize,
+    /// The probe key, whose column reference is the column name of the scanned table.
+    pub probe_key: RemoteExpr<String>,
+}
+
 impl HashJoin {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         let mut fields = self.probe.output_schema()?.fields().clone();
@@ -391,6 +428,60 @@ impl UnionAll {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CteScan {
+    pub cte_idx: IndexType,
+    /// Same columns as the materialized CTE, renamed to the output columns of the scan.
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl CteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MaterializedCte {
+    /// The definition of the CTE
+    pub left: Box<PhysicalPlan>,
+    /// The query reading the CTE
+    pub right: Box<PhysicalPlan>,
+    pub cte_idx: IndexType,
+    /// Columns of `left` that make up the CTE, in order.
+    pub left_output_columns: Vec<IndexType>,
+}
+
+impl MaterializedCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.right.output_schema()
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCte {
+    /// The anchor term
+    pub left: Box<PhysicalPlan>,
+    /// The recursive term, reading the working table with `CteScan`
+    pub right: Box<PhysicalPlan>,
+    pub cte_idx: IndexType,
+    pub distinct: bool,
+    /// Columns of `left` that make up the result, in order.
+    pub left_output_columns: Vec<IndexType>,
+    /// Columns of `right` that make up the result, in order.
+    pub right_output_columns: Vec<IndexType>,
+    pub schema: DataSchemaRef,
+}
+
+impl RecursiveCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct DistributedInsertSelect {
     pub input: Box<PhysicalPlan>,
@@ -417,6 +508,7 @@ pub enum PhysicalPlan {
     Filter(Filter),
     Project(Project),
     EvalScalar(EvalScalar),
+    ProjectSet(ProjectSet),
     AggregatePartial(AggregatePartial),
     AggregateFinal(AggregateFinal),
     Sort(Sort),
@@ -424,6 +516,9 @@ pub enum PhysicalPlan {
     HashJoin(HashJoin),
     Exchange(Exchange),
     UnionAll(UnionAll),
+    CteScan(CteScan),
+    MaterializedCte(MaterializedCte),
+    RecursiveCte(RecursiveCte),
 
     /// For insert into ... select ... in cluster
     DistributedInsertSelect(Box<DistributedInsertSelect>),
@@ -448,6 +543,7 @@ impl PhysicalPlan {
             PhysicalPlan::Filter(plan) => plan.output_schema(),
             PhysicalPlan::Project(plan) => plan.output_schema(),
             PhysicalPlan::EvalScalar(plan) => plan.output_schema(),
+            PhysicalPlan::ProjectSet(plan) => plan.output_schema(),
             PhysicalPlan::AggregatePartial(plan) => plan.output_schema(),
             PhysicalPlan::AggregateFinal(plan) => plan.output_schema(),
             PhysicalPlan::Sort(plan) => plan.output_schema(),
@@ -457,6 +553,9 @@ impl PhysicalPlan {
             PhysicalPlan::ExchangeSource(plan) => plan.output_schema(),
             PhysicalPlan::ExchangeSink(plan) => plan.output_schema(),
             PhysicalPlan::UnionAll(plan) => plan.output_schema(),
+            PhysicalPlan::CteScan(plan) => plan.output_schema(),
+            PhysicalPlan::MaterializedCte(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCte(plan) => plan.output_schema(),
             PhysicalPlan::DistributedInsertSelect(plan) => plan.output_schema(),
         }
     }
@@ -467,6 +566,7 @@ impl PhysicalPlan {
             PhysicalPlan::Filter(_) => "Filter".to_string(),
             PhysicalPlan::Project(_) => "Project".to_string(),
             PhysicalPlan::EvalScalar(_) => "EvalScalar".to_string(),
+            PhysicalPlan::ProjectSet(_) => "ProjectSet".to_string(),
             PhysicalPlan::AggregatePartial(_) => "AggregatePartial".to_string(),
             PhysicalPlan::AggregateFinal(_) => "AggregateFinal".to_string(),
             PhysicalPlan::Sort(_) => "Sort".to_string(),
@@ -474,6 +574,9 @@ impl PhysicalPlan {
             PhysicalPlan::HashJoin(_) => "HashJoin".to_string(),
             PhysicalPlan::Exchange(_) => "Exchange".to_string(),
             PhysicalPlan::UnionAll(_) => "UnionAll".to_string(),
+            PhysicalPlan::CteScan(_) => "CteScan".to_string(),
+            PhysicalPlan::MaterializedCte(_) => "MaterializedCte".to_string(),
+            PhysicalPlan::RecursiveCte(_) => "RecursiveCte".to_string(),
             PhysicalPlan::DistributedInsertSelect(_) => "DistributedInsertSelect".to_string(),
             PhysicalPlan::ExchangeSource(_) => "Exchange Source".to_string(),
             PhysicalPlan::ExchangeSink(_) => "Exchange Sink".to_string(),
@@ -486,6 +589,7 @@ impl PhysicalPlan {
             PhysicalPlan::Filter(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Project(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::EvalScalar(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::ProjectSet(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregatePartial(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregateFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Sort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
@@ -499,6 +603,13 @@ impl PhysicalPlan {
             PhysicalPlan::UnionAll(plan) => Box::new(
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
             ),
+            PhysicalPlan::CteScan(_) => Box::new(std::iter::empty()),
+            PhysicalPlan::MaterializedCte(plan) => Box::new(
+                std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
+            ),
+            PhysicalPlan::RecursiveCte(plan) => Box::new(
+                std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
+            ),
             PhysicalPlan::DistributedInsertSelect(plan) => {
                 Box::new(std::iter::once(plan.input.as_ref()))
             }
@@ -21,11 +21,13 @@ use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::PrewhereInfo;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::VirtualColumnInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::DataBlock;
+use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
 use common_expression::RemoteExpr;
 use common_expression::TableSchema;
@@ -35,10 +37,15 @@ use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregateFunctionSignature;
 use super::AggregatePartial;
+use super::CteScan;
 use super::Exchange as PhysicalExchange;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MaterializedCte;
+use super::ProjectSet;
+use super::RecursiveCte;
+use super::RuntimeFilterTarget;
 use super::Sort;
 use super::TableScan;
 use crate::executor::explain::PlanStatsInfo;
@@ -54,6 +61,8 @@ use crate::optimizer::SExpr;
 use crate::plans::AggregateMode;
 use crate::plans::AndExpr;
 use crate::plans::Exchange;
+use crate::plans::Join;
+use crate::plans::JoinType;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::Scan;
@@ -83,12 +92,14 @@ impl PhysicalPlanBuilder {
         if !has_inner_column {
             let col_indices = columns
                 .iter()
-                .map(|index| {
+                .filter_map(|index| {
                     let name = match metadata.column(*index) {
                         ColumnEntry::BaseTableColumn { column_name, .. } => column_name,
                         ColumnEntry::DerivedColumn { alias, .. } => alias,
+                        // Virtual columns are read beside the projection.
+                        ColumnEntry::VirtualColumn { .. } => return None,
                     };
-                    schema.index_of(name).unwrap()
+                    Some(schema.index_of(name).unwrap())
                 })
                 .sorted()
                 .collect::<Vec<_>>();
@@ -96,9 +107,9 @@ impl PhysicalPlanBuilder {
         } else {
             let col_indices = columns
                 .iter()
-                .map(|index| {
+                .filter_map(|index| {
                     let column = metadata.column(*index);
-                    match column {
+                    Some(match column {
                         ColumnEntry::BaseTableColumn {
                             column_name,
                             path_indices,
@@ -114,7 +125,8 @@ impl PhysicalPlanBuilder {
                             let idx = schema.index_of(alias).unwrap();
                             (column.index(), vec![idx])
                         }
-                    }
+                        ColumnEntry::VirtualColumn { .. } => return None,
+                    })
                 })
                 .sorted()
                 .collect::<BTreeMap<_, Vec<IndexType>>>();
@@ -142,6 +154,7 @@ impl PhysicalPlanBuilder {
 
                     let name = match column {
                         ColumnEntry::BaseTableColumn { column_name, .. } => column_name,
+                        ColumnEntry::VirtualColumn { column_name, .. } => column_name,
                         ColumnEntry::DerivedColumn { alias, .. } => alias,
                     };
                     if let Some(prewhere) = &scan.prewhere {
@@ -157,17 +170,18 @@ impl PhysicalPlanBuilder {
 
                 let table_entry = metadata.table(scan.table_index);
                 let table = table_entry.table();
-                let table_schema = table.schema();
+                let table_schema = table.schema().remove_virtual_computed_fields();
 
                 let push_downs = self.push_downs(scan, &table_schema, has_inner_column)?;
 
-                let source = table
+                let mut source = table
                     .read_plan_with_catalog(
                         self.ctx.clone(),
                         table_entry.catalog().to_string(),
                         Some(push_downs),
                     )
                     .await?;
+                source.scan_id = scan.table_index;
 
                 Ok(PhysicalPlan::TableScan(TableScan {
                     name_mapping,
@@ -209,6 +223,8 @@ impl PhysicalPlanBuilder {
                         .cloned()
                         .collect::<Vec<_>>(),
                 );
+                let runtime_filter_targets =
+                    self.build_runtime_filter_targets(join, &build_side, &probe_side)?;
                 Ok(PhysicalPlan::HashJoin(HashJoin {
                     build: Box::new(build_side),
                     probe: Box::new(probe_side),
@@ -251,6 +267,7 @@ impl PhysicalPlanBuilder {
                         .collect::<Result<_>>()?,
                     marker_index: join.marker_index,
                     from_correlated_subquery: join.from_correlated_subquery,
+                    runtime_filter_targets,
 
                     stat_info: Some(stat_info),
                 }))
@@ -281,6 +298,31 @@ impl PhysicalPlanBuilder {
                 }))
             }
 
+            RelOperator::ProjectSet(project_set) => {
+                let input = Box::new(self.build(s_expr.child(0)?).await?);
+                let input_schema = input.output_schema()?;
+                Ok(PhysicalPlan::ProjectSet(ProjectSet {
+                    input,
+                    func_name: project_set.func_name.clone(),
+                    args: project_set
+                        .args
+                        .iter()
+                        .map(|arg| {
+                            Ok(arg
+                                .as_expr_with_col_index()?
+                                .project_column_ref(|index| {
+                                    input_schema.index_of(&index.to_string()).unwrap()
+                                })
+                                .as_remote_expr())
+                        })
+                        .collect::<Result<_>>()?,
+                    return_type: *project_set.return_type.clone(),
+                    index: project_set.index,
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+
             RelOperator::Filter(filter) => {
                 let input = Box::new(self.build(s_expr.child(0)?).await?);
                 let input_schema = input.output_schema()?;
@@ -562,6 +604,58 @@ impl PhysicalPlanBuilder {
                     stat_info: Some(stat_info),
                 }))
             }
+            RelOperator::CteScan(cte_scan) => {
+                let metadata = self.metadata.read();
+                let fields = cte_scan
+                    .columns
+                    .iter()
+                    .map(|index| {
+                        let data_type = match metadata.column(*index) {
+                            ColumnEntry::BaseTableColumn { data_type, .. }
+                            | ColumnEntry::VirtualColumn { data_type, .. } => {
+                                DataType::from(data_type)
+                            }
+                            ColumnEntry::DerivedColumn { data_type, .. } => data_type.clone(),
+                        };
+                        DataField::new(&index.to_string(), data_type)
+                    })
+                    .collect::<Vec<_>>();
+                Ok(PhysicalPlan::CteScan(CteScan {
+                    cte_idx: cte_scan.cte_idx,
+                    schema: DataSchemaRefExt::create(fields),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+            RelOperator::MaterializedCte(cte) => {
+                let left = self.build(s_expr.child(0)?).await?;
+                let right = self.build(s_expr.child(1)?).await?;
+                Ok(PhysicalPlan::MaterializedCte(MaterializedCte {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    cte_idx: cte.cte_idx,
+                    left_output_columns: cte.left_output_columns.clone(),
+                }))
+            }
+            RelOperator::RecursiveCte(cte) => {
+                let left = self.build(s_expr.child(0)?).await?;
+                let right = self.build(s_expr.child(1)?).await?;
+                let left_schema = left.output_schema()?;
+                let fields = cte
+                    .left_output_columns
+                    .iter()
+                    .map(|index| Ok(left_schema.field_with_name(&index.to_string())?.clone()))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    cte_idx: cte.cte_idx,
+                    distinct: cte.distinct,
+                    left_output_columns: cte.left_output_columns.clone(),
+                    right_output_columns: cte.right_output_columns.clone(),
+                    schema: DataSchemaRefExt::create(fields),
+                }))
+            }
             _ => Err(ErrorCode::Internal(format!(
                 "Unsupported physical plan: {:?}",
                 s_expr.plan()
@@ -650,9 +744,19 @@ impl PhysicalPlanBuilder {
             })
             .transpose()?;
 
+        // The top-k pruning looks up the sort column in the table schema, which has no
+        // virtual columns, so the order by of them is not pushed down.
         let order_by = scan
             .order_by
             .clone()
+            .filter(|items| {
+                !items.iter().any(|item| {
+                    matches!(
+                        metadata.column(item.index),
+                        ColumnEntry::VirtualColumn { .. }
+                    )
+                })
+            })
             .map(|items| {
                 items
                     .into_iter()
@@ -664,6 +768,11 @@ impl PhysicalPlanBuilder {
                                 column_name,
                                 data_type,
                                 ..
+                            }
+                            | ColumnEntry::VirtualColumn {
+                                column_name,
+                                data_type,
+                                ..
                             } => (column_name.clone(), DataType::from(data_type)),
                             ColumnEntry::DerivedColumn {
                                 alias, data_type, ..
@@ -684,15 +793,97 @@ impl PhysicalPlanBuilder {
             })
             .transpose()?;
 
+        let virtual_columns = scan
+            .columns
+            .iter()
+            .sorted()
+            .filter_map(|index| match metadata.column(*index) {
+                ColumnEntry::VirtualColumn {
+                    column_name,
+                    source_column_name,
+                    path,
+                    cast_type,
+                    data_type,
+                    ..
+                } => Some(VirtualColumnInfo {
+                    name: column_name.clone(),
+                    source_column_name: source_column_name.clone(),
+                    path: path.clone(),
+                    cast_type: cast_type.clone(),
+                    data_type: data_type.clone(),
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
         Ok(PushDownInfo {
             projection: Some(projection),
             filters: push_down_filters.unwrap_or_default(),
             prewhere: prewhere_info,
             limit: scan.limit,
             order_by: order_by.unwrap_or_default(),
+            virtual_columns: (!virtual_columns.is_empty()).then_some(virtual_columns),
         })
     }
 
+    /// Find the probe side table scans which can be filtered by the build keys of the join,
+    /// the filters are built from the build keys once the hash table is built.
+    fn build_runtime_filter_targets(
+        &self,
+        join: &Join,
+        build_side: &PhysicalPlan,
+        probe_side: &PhysicalPlan,
+    ) -> Result<Vec<RuntimeFilterTarget>> {
+        // Only the joins which discard the probe rows without matches can be filtered.
+        if !self.ctx.get_settings().get_enable_runtime_filter()?
+            || !matches!(
+                join.join_type,
+                JoinType::Inner
+                    | JoinType::Right
+                    | JoinType::LeftSemi
+                    | JoinType::RightSemi
+                    | JoinType::RightAnti
+            )
+        {
+            return Ok(vec![]);
+        }
+
+        // In a distributed plan, every node running the join has all the build rows only if
+        // the build side is broadcast or merged. Then the filters built on a node are also valid
+        // for the probe side scans on this node whose rows are exchanged to other nodes.
+        // A hash shuffled build side only has a part of the keys on each node, so its filters
+        // can't be pushed through exchanges.
+        let cross_exchange = matches!(
+            build_side,
+            PhysicalPlan::Exchange(PhysicalExchange {
+                kind: FragmentKind::Expansive | FragmentKind::Merge,
+                ..
+            })
+        );
+
+        let mut targets = vec![];
+        for (build_key, probe_key) in join.left_conditions.iter().enumerate() {
+            let used_columns = probe_key.used_columns();
+            if used_columns.len() != 1 {
+                continue;
+            }
+            let index = *used_columns.iter().next().unwrap();
+            if let Some((scan_id, column_name)) =
+                find_scan_column(probe_side, index, cross_exchange)
+            {
+                let probe_key = probe_key
+                    .as_expr_with_col_index()?
+                    .project_column_ref(|_| column_name.clone());
+                targets.push(RuntimeFilterTarget {
+                    build_key,
+                    scan_id,
+                    probe_key: probe_key.as_remote_expr(),
+                });
+            }
+        }
+        Ok(targets)
+    }
+
     fn build_plan_stat_info(&self, s_expr: &SExpr) -> Result<PlanStatsInfo> {
         let rel_expr = RelExpr::with_s_expr(s_expr);
         let prop = rel_expr.derive_relational_prop()?;
@@ -702,3 +893,42 @@ impl PhysicalPlanBuilder {
         })
     }
 }
+
+/// Find the table scan which produces the column `index`, only looking through the operators
+/// which keep the values of the column and never turn a discarded row into an output row.
+/// Exchanges are crossed only if `cross_exchange` is set, the filters are delivered to the scans
+/// through the query context of each node, so each node filters its scans with its own filters.
+fn find_scan_column(
+    plan: &PhysicalPlan,
+    index: IndexType,
+    cross_exchange: bool,
+) -> Option<(usize, String)> {
+    let find = |plan: &PhysicalPlan| find_scan_column(plan, index, cross_exchange);
+    match plan {
+        PhysicalPlan::TableScan(scan) => {
+            if scan.table_index == DUMMY_TABLE_INDEX {
+                return None;
+            }
+            let schema = scan.source.source_info.schema();
+            scan.name_mapping
+                .iter()
+                .find(|(name, column)| **column == index && schema.field_with_name(name).is_ok())
+                .map(|(name, _)| (scan.source.scan_id, name.clone()))
+        }
+        PhysicalPlan::Filter(plan) => find(&plan.input),
+        PhysicalPlan::Project(plan) => find(&plan.input),
+        PhysicalPlan::EvalScalar(plan) => find(&plan.input),
+        PhysicalPlan::ProjectSet(plan) => find(&plan.input),
+        PhysicalPlan::AggregatePartial(plan) => find(&plan.input),
+        PhysicalPlan::AggregateFinal(plan) => find(&plan.input),
+        PhysicalPlan::Exchange(plan) if cross_exchange => find(&plan.input),
+        PhysicalPlan::HashJoin(join) => match join.join_type {
+            JoinType::Inner | JoinType::Cross => find(&join.probe).or_else(|| find(&join.build)),
+            JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti | JoinType::Single => {
+                find(&join.probe)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -21,6 +21,7 @@ use itertools::Itertools;
 use super::DistributedInsertSelect;
 use crate::executor::AggregateFinal;
 use crate::executor::AggregatePartial;
+use crate::executor::CteScan;
 use crate::executor::EvalScalar;
 use crate::executor::Exchange;
 use crate::executor::ExchangeSink;
@@ -28,8 +29,11 @@ use crate::executor::ExchangeSource;
 use crate::executor::Filter;
 use crate::executor::HashJoin;
 use crate::executor::Limit;
+use crate::executor::MaterializedCte;
 use crate::executor::PhysicalPlan;
 use crate::executor::Project;
+use crate::executor::ProjectSet;
+use crate::executor::RecursiveCte;
 use crate::executor::Sort;
 use crate::executor::TableScan;
 use crate::executor::UnionAll;
@@ -55,6 +59,7 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::Filter(filter) => write!(f, "{}", filter)?,
             PhysicalPlan::Project(project) => write!(f, "{}", project)?,
             PhysicalPlan::EvalScalar(eval_scalar) => write!(f, "{}", eval_scalar)?,
+            PhysicalPlan::ProjectSet(project_set) => write!(f, "{}", project_set)?,
             PhysicalPlan::AggregatePartial(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::AggregateFinal(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::Sort(sort) => write!(f, "{}", sort)?,
@@ -64,6 +69,9 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::ExchangeSource(source) => write!(f, "{}", source)?,
             PhysicalPlan::ExchangeSink(sink) => write!(f, "{}", sink)?,
             PhysicalPlan::UnionAll(union_all) => write!(f, "{}", union_all)?,
+            PhysicalPlan::CteScan(cte_scan) => write!(f, "{}", cte_scan)?,
+            PhysicalPlan::MaterializedCte(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::RecursiveCte(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::DistributedInsertSelect(insert_select) => write!(f, "{}", insert_select)?,
         }
 
@@ -142,6 +150,18 @@ impl Display for EvalScalar {
     }
 }
 
+impl Display for ProjectSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| arg.as_expr(&BUILTIN_FUNCTIONS).to_string())
+            .collect::<Vec<String>>();
+
+        write!(f, "ProjectSet: [{}({})]", self.func_name, args.join(", "))
+    }
+}
+
 impl Display for AggregateFinal {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let group_items = self
@@ -291,6 +311,28 @@ impl Display for UnionAll {
     }
 }
 
+impl Display for CteScan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CteScan: [cte_idx: {}]", self.cte_idx)
+    }
+}
+
+impl Display for MaterializedCte {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MaterializedCte: [cte_idx: {}]", self.cte_idx)
+    }
+}
+
+impl Display for RecursiveCte {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RecursiveCte: [cte_idx: {}, distinct: {}]",
+            self.cte_idx, self.distinct
+        )
+    }
+}
+
 impl Display for DistributedInsertSelect {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DistributedInsertSelect")
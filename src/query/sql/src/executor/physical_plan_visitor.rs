@@ -16,6 +16,7 @@ use common_exception::Result;
 
 use super::AggregateFinal;
 use super::AggregatePartial;
+use super::CteScan;
 use super::DistributedInsertSelect;
 use super::EvalScalar;
 use super::Exchange;
@@ -24,8 +25,11 @@ use super::ExchangeSource;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MaterializedCte;
 use super::PhysicalPlan;
 use super::Project;
+use super::ProjectSet;
+use super::RecursiveCte;
 use super::Sort;
 use super::TableScan;
 use crate::executor::UnionAll;
@@ -37,6 +41,7 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::Filter(plan) => self.replace_filter(plan),
             PhysicalPlan::Project(plan) => self.replace_project(plan),
             PhysicalPlan::EvalScalar(plan) => self.replace_eval_scalar(plan),
+            PhysicalPlan::ProjectSet(plan) => self.replace_project_set(plan),
             PhysicalPlan::AggregatePartial(plan) => self.replace_aggregate_partial(plan),
             PhysicalPlan::AggregateFinal(plan) => self.replace_aggregate_final(plan),
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
@@ -46,6 +51,9 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::ExchangeSource(plan) => self.replace_exchange_source(plan),
             PhysicalPlan::ExchangeSink(plan) => self.replace_exchange_sink(plan),
             PhysicalPlan::UnionAll(plan) => self.replace_union(plan),
+            PhysicalPlan::CteScan(plan) => self.replace_cte_scan(plan),
+            PhysicalPlan::MaterializedCte(plan) => self.replace_materialized_cte(plan),
+            PhysicalPlan::RecursiveCte(plan) => self.replace_recursive_cte(plan),
             PhysicalPlan::DistributedInsertSelect(plan) => self.replace_insert_select(plan),
         }
     }
@@ -85,6 +93,19 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_project_set(&mut self, plan: &ProjectSet) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::ProjectSet(ProjectSet {
+            input: Box::new(input),
+            func_name: plan.func_name.clone(),
+            args: plan.args.clone(),
+            return_type: plan.return_type.clone(),
+            index: plan.index,
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_aggregate_partial(&mut self, plan: &AggregatePartial) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -122,6 +143,7 @@ pub trait PhysicalPlanReplacer {
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
             from_correlated_subquery: plan.from_correlated_subquery,
+            runtime_filter_targets: plan.runtime_filter_targets.clone(),
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -188,6 +210,31 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_cte_scan(&mut self, plan: &CteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::CteScan(plan.clone()))
+    }
+
+    fn replace_materialized_cte(&mut self, plan: &MaterializedCte) -> Result<PhysicalPlan> {
+        let left = self.replace(&plan.left)?;
+        let right = self.replace(&plan.right)?;
+        Ok(PhysicalPlan::MaterializedCte(MaterializedCte {
+            left: Box::new(left),
+            right: Box::new(right),
+            cte_idx: plan.cte_idx,
+            left_output_columns: plan.left_output_columns.clone(),
+        }))
+    }
+
+    fn replace_recursive_cte(&mut self, plan: &RecursiveCte) -> Result<PhysicalPlan> {
+        let left = self.replace(&plan.left)?;
+        let right = self.replace(&plan.right)?;
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            left: Box::new(left),
+            right: Box::new(right),
+            ..plan.clone()
+        }))
+    }
+
     fn replace_insert_select(&mut self, plan: &DistributedInsertSelect) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -225,6 +272,9 @@ impl PhysicalPlan {
                 PhysicalPlan::EvalScalar(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::ProjectSet(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::AggregatePartial(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -252,6 +302,15 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::CteScan(_) => {}
+                PhysicalPlan::MaterializedCte(plan) => {
+                    Self::traverse(&plan.left, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.right, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::RecursiveCte(plan) => {
+                    Self::traverse(&plan.left, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.right, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::DistributedInsertSelect(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -83,6 +83,7 @@ impl ToReadDataSourcePlan for dyn Table {
             description,
             tbl_args: self.table_args(),
             push_downs,
+            scan_id: 0,
         })
     }
 }
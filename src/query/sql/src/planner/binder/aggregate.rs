@@ -91,7 +91,7 @@ impl<'a> AggregateRewriter<'a> {
     pub fn visit(&mut self, scalar: &ScalarExpr) -> Result<ScalarExpr> {
         match scalar {
             ScalarExpr::BoundColumnRef(_) => Ok(scalar.clone()),
-            ScalarExpr::ConstantExpr(_) => Ok(scalar.clone()),
+            ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => Ok(scalar.clone()),
             ScalarExpr::AndExpr(scalar) => Ok(AndExpr {
                 left: Box::new(self.visit(&scalar.left)?),
                 right: Box::new(self.visit(&scalar.right)?),
@@ -14,6 +14,7 @@
 
 use std::hash::Hash;
 
+use common_ast::ast::Expr;
 use common_ast::ast::TableAlias;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -94,6 +95,10 @@ pub struct BindContext {
     pub in_grouping: bool,
 
     pub ctes_map: Box<DashMap<String, CteInfo>>,
+
+    /// The `match(column, query[, tokenizer])` conjunct of the WHERE clause of the current
+    /// query with its tokenizer, which `score()` ranks the matching rows by.
+    pub full_text_query: Option<(Expr, String, String)>,
 }
 
 #[derive(Clone, Debug)]
@@ -101,6 +106,9 @@ pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub s_expr: SExpr,
     pub bind_context: BindContext,
+    /// Set if the CTE is referenced more than once and materialized, the references
+    /// are bound to `CteScan`s reading its buffered result.
+    pub cte_idx: Option<IndexType>,
 }
 
 impl BindContext {
@@ -111,6 +119,7 @@ impl BindContext {
             aggregate_info: AggregateInfo::default(),
             in_grouping: false,
             ctes_map: Box::new(DashMap::new()),
+            full_text_query: None,
         }
     }
 
@@ -121,6 +130,7 @@ impl BindContext {
             aggregate_info: Default::default(),
             in_grouping: false,
             ctes_map: parent.ctes_map.clone(),
+            full_text_query: None,
         }
     }
 
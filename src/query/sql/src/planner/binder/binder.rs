@@ -89,6 +89,9 @@ impl<'a> Binder {
         let plan = match stmt {
             Statement::Query(query) => {
                 let (s_expr, bind_context) = self.bind_query(bind_context, query).await?;
+                if self.ctx.get_settings().get_enable_materialized_view_rewrite()? {
+                    self.bind_materialized_views(&s_expr).await?;
+                }
                 Plan::Query {
                     s_expr: Box::new(s_expr),
                     metadata: self.metadata.clone(),
@@ -162,12 +165,23 @@ impl<'a> Binder {
             Statement::TruncateTable(stmt) => self.bind_truncate_table(stmt).await?,
             Statement::OptimizeTable(stmt) => self.bind_optimize_table(bind_context, stmt).await?,
             Statement::AnalyzeTable(stmt) => self.bind_analyze_table(stmt).await?,
+            Statement::VacuumTable(stmt) => self.bind_vacuum_table(stmt).await?,
+            Statement::VacuumDropTable(stmt) => self.bind_vacuum_drop_table(stmt).await?,
             Statement::ExistsTable(stmt) => self.bind_exists_table(stmt).await?,
 
             // Views
             Statement::CreateView(stmt) => self.bind_create_view(stmt).await?,
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
+            Statement::RefreshMaterializedView(stmt) => self.bind_refresh_materialized_view(stmt).await?,
+
+            // Indexes
+            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(stmt).await?,
+            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(stmt).await?,
+            Statement::CreateVirtualColumn(stmt) => self.bind_create_virtual_column(stmt).await?,
+            Statement::DropVirtualColumn(stmt) => self.bind_drop_virtual_column(stmt).await?,
+            Statement::CreateStream(stmt) => self.bind_create_stream(stmt).await?,
+            Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
 
             // Users
             Statement::CreateUser(stmt) => self.bind_create_user(stmt).await?,
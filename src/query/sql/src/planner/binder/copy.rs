@@ -240,7 +240,8 @@ impl<'a> Binder {
         let from = DataSourcePlan {
             catalog: dst_catalog_name.to_string(),
             source_info: DataSourceInfo::StageSource(StageTableInfo {
-                schema: table.schema(),
+                // The computed columns are computed from the loaded columns.
+                schema: Arc::new(table.schema().remove_computed_fields()),
                 user_stage_info: stage_info,
                 path,
                 files: stmt.files.clone(),
@@ -253,6 +254,7 @@ impl<'a> Binder {
             description: "".to_string(),
             tbl_args: None,
             push_downs: None,
+            scan_id: 0,
         };
 
         Ok(Plan::Copy(Box::new(CopyPlanV2::IntoTable {
@@ -298,7 +300,8 @@ impl<'a> Binder {
         let from = DataSourcePlan {
             catalog: dst_catalog_name.to_string(),
             source_info: DataSourceInfo::StageSource(StageTableInfo {
-                schema: table.schema(),
+                // The computed columns are computed from the loaded columns.
+                schema: Arc::new(table.schema().remove_computed_fields()),
                 user_stage_info: stage_info,
                 path,
                 files: stmt.files.clone(),
@@ -311,6 +314,7 @@ impl<'a> Binder {
             description: "".to_string(),
             tbl_args: None,
             push_downs: None,
+            scan_id: 0,
         };
 
         Ok(Plan::Copy(Box::new(CopyPlanV2::IntoTable {
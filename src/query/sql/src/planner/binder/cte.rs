@@ -0,0 +1,379 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use common_ast::ast::Query;
+use common_ast::ast::SetExpr;
+use common_ast::ast::SetOperator;
+use common_ast::ast::TableAlias;
+use common_ast::ast::TableReference;
+use common_ast::ast::CTE;
+use common_ast::walk_set_expr;
+use common_ast::walk_table_reference;
+use common_ast::Visitor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::common_super_type;
+use common_expression::types::DataType;
+
+use crate::binder::Binder;
+use crate::binder::ColumnBinding;
+use crate::binder::CteInfo;
+use crate::normalize_identifier;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::CteScan;
+use crate::plans::EvalScalar;
+use crate::plans::MaterializedCte;
+use crate::plans::RecursiveCte;
+use crate::plans::ScalarItem;
+use crate::BindContext;
+use crate::NameResolutionContext;
+
+/// Count the references of the CTEs in a query, which are used to decide whether a CTE
+/// is worth materializing. Only the table references resolved to the CTEs are counted,
+/// not the tables of the same name referenced before a CTE is defined, or the ones
+/// shadowed by the CTEs of a subquery.
+struct CteReferenceCounter<'a> {
+    name_resolution_ctx: &'a NameResolutionContext,
+    /// The counted CTEs visible to the current table reference.
+    visible: HashSet<String>,
+    /// The CTEs defined by the enclosing subqueries, which shadow the counted ones.
+    shadowed: Vec<String>,
+    counts: HashMap<String, usize>,
+}
+
+impl<'a> CteReferenceCounter<'a> {
+    fn new(name_resolution_ctx: &'a NameResolutionContext) -> Self {
+        CteReferenceCounter {
+            name_resolution_ctx,
+            visible: HashSet::new(),
+            shadowed: vec![],
+            counts: HashMap::new(),
+        }
+    }
+
+    fn visit_query_body(&mut self, query: &Query) {
+        self.visit_set_expr(&query.body);
+        for order_by in query.order_by.iter() {
+            self.visit_order_by(order_by);
+        }
+        for limit in query.limit.iter() {
+            self.visit_expr(limit);
+        }
+        if let Some(offset) = &query.offset {
+            self.visit_expr(offset);
+        }
+    }
+}
+
+impl<'a, 'ast> Visitor<'ast> for CteReferenceCounter<'a> {
+    fn visit_query(&mut self, query: &'ast Query) {
+        let depth = self.shadowed.len();
+        if let Some(with) = &query.with {
+            // A CTE is visible to the CTEs defined after it, and to itself if it's recursive.
+            for cte in with.ctes.iter() {
+                if with.recursive {
+                    self.shadowed.push(cte.alias.name.name.clone());
+                }
+                self.visit_query(&cte.query);
+                if !with.recursive {
+                    self.shadowed.push(cte.alias.name.name.clone());
+                }
+            }
+        }
+        self.visit_query_body(query);
+        self.shadowed.truncate(depth);
+    }
+
+    fn visit_table_reference(&mut self, table: &'ast TableReference) {
+        if let TableReference::Table {
+            catalog: None,
+            database: None,
+            table,
+            ..
+        } = table
+        {
+            let name = normalize_identifier(table, self.name_resolution_ctx).name;
+            if self.visible.contains(&name) && !self.shadowed.contains(&name) {
+                *self.counts.entry(name).or_default() += 1;
+            }
+        }
+        walk_table_reference(self, table);
+    }
+}
+
+/// Count the references of each CTE defined by the `WITH` clause of the query.
+pub(crate) fn count_cte_references(
+    query: &Query,
+    name_resolution_ctx: &NameResolutionContext,
+) -> HashMap<String, usize> {
+    let mut counter = CteReferenceCounter::new(name_resolution_ctx);
+    if let Some(with) = &query.with {
+        for cte in with.ctes.iter() {
+            if with.recursive {
+                counter.visible.insert(cte.alias.name.name.clone());
+            }
+            counter.visit_query(&cte.query);
+            counter.visible.insert(cte.alias.name.name.clone());
+        }
+    }
+    counter.visit_query_body(query);
+    counter.counts
+}
+
+fn references_table(
+    set_expr: &SetExpr,
+    table_name: &str,
+    name_resolution_ctx: &NameResolutionContext,
+) -> bool {
+    let mut counter = CteReferenceCounter::new(name_resolution_ctx);
+    counter.visible.insert(table_name.to_string());
+    walk_set_expr(&mut counter, set_expr);
+    counter.counts.contains_key(table_name)
+}
+
+/// The column types of a recursive CTE are widened until both terms fit, e.g. a counter
+/// incremented by the recursive term. Give up if they don't settle after a few rounds.
+const MAX_RECURSIVE_CTE_TYPE_COERCIONS: usize = 8;
+
+impl Binder {
+    /// Bind a reference of a materialized CTE, every reference gets its own output columns.
+    pub(crate) fn bind_cte_scan(
+        &mut self,
+        bind_context: &BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let cte_idx = cte_info
+            .cte_idx
+            .ok_or_else(|| ErrorCode::Internal(format!("CTE {table_name} is not materialized")))?;
+        let (s_expr, mut new_bind_context) =
+            self.bind_cte(bind_context, table_name, alias, cte_info)?;
+        let estimated_rows = RelExpr::with_s_expr(&s_expr)
+            .derive_relational_prop()
+            .map_or(0, |prop| prop.cardinality as u64);
+
+        let mut columns = Vec::with_capacity(new_bind_context.columns.len());
+        let mut metadata = self.metadata.write();
+        for column in new_bind_context.columns.iter_mut() {
+            column.index =
+                metadata.add_derived_column(column.column_name.clone(), *column.data_type.clone());
+            columns.push(column.index);
+        }
+        let cte_scan = CteScan {
+            cte_idx,
+            columns,
+            estimated_rows,
+        };
+        Ok((SExpr::create_leaf(cte_scan.into()), new_bind_context))
+    }
+
+    /// Whether a CTE of `WITH RECURSIVE` references itself.
+    pub(crate) fn is_recursive_cte(&self, cte: &CTE, table_name: &str) -> bool {
+        references_table(&cte.query.body, table_name, &self.name_resolution_ctx)
+    }
+
+    /// Bind a recursive CTE of the form `anchor_term UNION [ALL] recursive_term`, where
+    /// the recursive term reads the rows produced by the previous iteration through
+    /// references of the CTE itself.
+    pub(crate) async fn bind_recursive_cte(
+        &mut self,
+        bind_context: &BindContext,
+        cte: &CTE,
+        table_name: &str,
+    ) -> Result<(SExpr, BindContext)> {
+        let set_operation = match &cte.query.body {
+            SetExpr::SetOperation(set_operation)
+                if set_operation.op == SetOperator::Union
+                    && cte.query.order_by.is_empty()
+                    && cte.query.limit.is_empty()
+                    && cte.query.offset.is_none() =>
+            {
+                set_operation
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "recursive CTE {table_name} must be an anchor term and a recursive term combined by UNION or UNION ALL, without ORDER BY or LIMIT"
+                )));
+            }
+        };
+        if references_table(&set_operation.left, table_name, &self.name_resolution_ctx) {
+            return Err(ErrorCode::SemanticError(format!(
+                "the anchor term of recursive CTE {table_name} can't reference itself"
+            )));
+        }
+
+        let (anchor_expr, anchor_context) = self
+            .bind_set_expr(bind_context, &set_operation.left, &[])
+            .await?;
+        let working_table_idx = self.metadata.write().add_materialized_cte();
+        let columns_alias: Vec<String> = cte.alias.columns.iter().map(|c| c.name.clone()).collect();
+
+        let mut column_types: Vec<DataType> = anchor_context
+            .columns
+            .iter()
+            .map(|column| *column.data_type.clone())
+            .collect();
+        let mut coercions = 0;
+        let (recursive_expr, recursive_context) = loop {
+            // The references of the CTE in the recursive term read the working table.
+            let mut working_table_context = anchor_context.clone();
+            for (column, data_type) in working_table_context
+                .columns
+                .iter_mut()
+                .zip(column_types.iter())
+            {
+                column.data_type = Box::new(data_type.clone());
+            }
+            bind_context
+                .ctes_map
+                .insert(table_name.to_string(), CteInfo {
+                    columns_alias: columns_alias.clone(),
+                    s_expr: anchor_expr.clone(),
+                    bind_context: working_table_context,
+                    cte_idx: Some(working_table_idx),
+                });
+            let (recursive_expr, recursive_context) = self
+                .bind_set_expr(bind_context, &set_operation.right, &[])
+                .await?;
+            if recursive_context.columns.len() != column_types.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "the anchor term and the recursive term of recursive CTE {table_name} must have the same number of columns"
+                )));
+            }
+
+            let mut coercion_types = Vec::with_capacity(column_types.len());
+            for (data_type, column) in column_types.iter().zip(recursive_context.columns.iter()) {
+                let coercion_type = common_super_type(data_type.clone(), *column.data_type.clone())
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(format!(
+                            "the column {} of recursive CTE {table_name} has type {} in the anchor term, which can't be matched with {} in the recursive term",
+                            column.column_name, data_type, column.data_type
+                        ))
+                    })?;
+                coercion_types.push(coercion_type);
+            }
+            if coercion_types == column_types {
+                break (recursive_expr, recursive_context);
+            }
+            coercions += 1;
+            if coercions > MAX_RECURSIVE_CTE_TYPE_COERCIONS {
+                return Err(ErrorCode::SemanticError(format!(
+                    "the column types of recursive CTE {table_name} can't be decided, try to cast the columns of the anchor term explicitly"
+                )));
+            }
+            column_types = coercion_types;
+        };
+
+        let (anchor_expr, anchor_columns) =
+            self.coerce_columns(&anchor_context, anchor_expr, &column_types);
+        let (recursive_expr, recursive_columns) =
+            self.coerce_columns(&recursive_context, recursive_expr, &column_types);
+        let recursive_cte = RecursiveCte {
+            cte_idx: working_table_idx,
+            distinct: !set_operation.all,
+            left_output_columns: anchor_columns.iter().map(|column| column.index).collect(),
+            right_output_columns: recursive_columns
+                .iter()
+                .map(|column| column.index)
+                .collect(),
+        };
+        let s_expr = SExpr::create_binary(recursive_cte.into(), anchor_expr, recursive_expr);
+
+        let mut new_bind_context = anchor_context;
+        new_bind_context.columns = anchor_columns;
+        Ok((s_expr, new_bind_context))
+    }
+
+    /// Cast the output columns of `s_expr` to `data_types`, returning the casted columns.
+    fn coerce_columns(
+        &self,
+        bind_context: &BindContext,
+        s_expr: SExpr,
+        data_types: &[DataType],
+    ) -> (SExpr, Vec<ColumnBinding>) {
+        let mut items = vec![];
+        let mut columns = Vec::with_capacity(data_types.len());
+        for (column, data_type) in bind_context.columns.iter().zip(data_types.iter()) {
+            if *column.data_type == *data_type {
+                columns.push(column.clone());
+                continue;
+            }
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column.column_name.clone(), data_type.clone());
+            items.push(ScalarItem {
+                scalar: CastExpr {
+                    is_try: false,
+                    argument: Box::new(
+                        BoundColumnRef {
+                            column: column.clone(),
+                        }
+                        .into(),
+                    ),
+                    from_type: column.data_type.clone(),
+                    target_type: Box::new(data_type.clone()),
+                }
+                .into(),
+                index,
+            });
+            columns.push(ColumnBinding {
+                index,
+                data_type: Box::new(data_type.clone()),
+                ..column.clone()
+            });
+        }
+        if items.is_empty() {
+            (s_expr, columns)
+        } else {
+            (
+                SExpr::create_unary(EvalScalar { items }.into(), s_expr),
+                columns,
+            )
+        }
+    }
+
+    /// Compute the materialized CTEs of a query before the query itself.
+    pub(crate) fn bind_materialized_ctes(
+        &self,
+        materialized_ctes: &[CteInfo],
+        mut s_expr: SExpr,
+    ) -> SExpr {
+        // The CTEs are wrapped in reverse order, so that a CTE is always materialized
+        // before the CTEs defined after it, which may read it.
+        for cte_info in materialized_ctes.iter().rev() {
+            if let Some(cte_idx) = cte_info.cte_idx {
+                let materialized_cte = MaterializedCte {
+                    cte_idx,
+                    left_output_columns: cte_info
+                        .bind_context
+                        .columns
+                        .iter()
+                        .map(|column| column.index)
+                        .collect(),
+                };
+                s_expr =
+                    SExpr::create_binary(materialized_cte.into(), cte_info.s_expr.clone(), s_expr);
+            }
+        }
+        s_expr
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateInvertedIndexStmt;
+use common_ast::ast::DropInvertedIndexStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+use common_functions::scalars::TextTokenizer;
+
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::plans::CreateInvertedIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+            tokenizer,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        let tokenizer = tokenizer.as_deref().unwrap_or("default").to_lowercase();
+        TextTokenizer::try_from(tokenizer.as_str())?;
+
+        let schema = self
+            .ctx
+            .get_table(&catalog, &database, &table)
+            .await?
+            .schema();
+        let mut index_columns = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+            let field = schema.field_with_name(&column)?;
+            if field.data_type().remove_nullable() != TableDataType::String {
+                return Err(ErrorCode::SemanticError(format!(
+                    "inverted index column `{}` must be of type String, but got {}",
+                    column,
+                    field.data_type()
+                )));
+            }
+            if index_columns.contains(&column) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicated column `{}` in inverted index",
+                    column
+                )));
+            }
+            index_columns.push(column);
+        }
+
+        let plan = CreateInvertedIndexPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog,
+            database,
+            table,
+            index_name,
+            columns: index_columns,
+            tokenizer,
+        };
+        Ok(Plan::CreateInvertedIndex(Box::new(plan)))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        let plan = DropInvertedIndexPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            table,
+            index_name,
+        };
+        Ok(Plan::DropInvertedIndex(Box::new(plan)))
+    }
+}
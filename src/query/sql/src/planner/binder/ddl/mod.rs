@@ -15,8 +15,11 @@
 mod account;
 mod catalog;
 mod database;
+mod index;
 mod role;
 mod share;
 mod stage;
+mod stream;
 mod table;
 mod view;
+mod virtual_column;
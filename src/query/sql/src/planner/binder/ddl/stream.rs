@@ -0,0 +1,163 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_ast::ast::CreateStreamStmt;
+use common_ast::ast::DropStreamStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableSchemaRefExt;
+use storages_common_table_meta::table::stream_change_fields;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_STREAM_OFFSET;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_DATABASE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_TABLE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_TABLE_ID;
+
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::plans::CreateStreamPlan;
+use crate::plans::DropStreamPlan;
+use crate::plans::Plan;
+use crate::BindContext;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_create_stream(
+        &mut self,
+        stmt: &CreateStreamStmt,
+    ) -> Result<Plan> {
+        let CreateStreamStmt {
+            if_not_exists,
+            catalog,
+            database,
+            stream,
+            table_database,
+            table,
+            travel_point,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let stream_name = normalize_identifier(stream, &self.name_resolution_ctx).name;
+        let table_database = table_database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table_name = normalize_identifier(table, &self.name_resolution_ctx).name;
+
+        let source = self
+            .ctx
+            .get_table(&catalog, &table_database, &table_name)
+            .await?;
+        if !source.engine().eq_ignore_ascii_case("FUSE") {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "can not create stream on table {}.{}, only FUSE tables are supported",
+                table_database, table_name
+            )));
+        }
+
+        // The virtual computed columns are not stored in the blocks the stream reads.
+        let mut fields = source
+            .schema()
+            .remove_virtual_computed_fields()
+            .fields()
+            .iter()
+            .map(|field| field.clone().with_computed_expr(None))
+            .collect::<Vec<_>>();
+        for field in stream_change_fields() {
+            if source.schema().field_with_name(field.name()).is_ok() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "can not create stream on table {}.{}, which has a column named `{}`",
+                    table_database,
+                    table_name,
+                    field.name()
+                )));
+            }
+            fields.push(field);
+        }
+
+        // The offset is the current snapshot of the table, or the one at the travel point.
+        let offset_table = match travel_point {
+            Some(travel_point) => {
+                let point = self
+                    .resolve_data_travel_point(&BindContext::new(), travel_point)
+                    .await?;
+                source.navigate_to(&point).await?
+            }
+            None => source.clone(),
+        };
+
+        let mut options = BTreeMap::new();
+        options.insert(OPT_KEY_STREAM_SOURCE_DATABASE.to_owned(), table_database);
+        options.insert(OPT_KEY_STREAM_SOURCE_TABLE.to_owned(), table_name);
+        options.insert(
+            OPT_KEY_STREAM_SOURCE_TABLE_ID.to_owned(),
+            source.get_id().to_string(),
+        );
+        if let Some(location) = offset_table.options().get(OPT_KEY_SNAPSHOT_LOCATION) {
+            options.insert(OPT_KEY_STREAM_OFFSET.to_owned(), location.clone());
+        }
+
+        let plan = CreateStreamPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog,
+            database,
+            stream_name,
+            schema: TableSchemaRefExt::create(fields),
+            options,
+        };
+        Ok(Plan::CreateStream(Box::new(plan)))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_stream(
+        &mut self,
+        stmt: &DropStreamStmt,
+    ) -> Result<Plan> {
+        let DropStreamStmt {
+            if_exists,
+            catalog,
+            database,
+            stream,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let stream_name = normalize_identifier(stream, &self.name_resolution_ctx).name;
+
+        let plan = DropStreamPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            stream_name,
+        };
+        Ok(Plan::DropStream(Box::new(plan)))
+    }
+}
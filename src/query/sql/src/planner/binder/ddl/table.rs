@@ -19,7 +19,9 @@ use std::sync::Arc;
 use common_ast::ast::AlterTableAction;
 use common_ast::ast::AlterTableStmt;
 use common_ast::ast::AnalyzeTableStmt;
+use common_ast::ast::ColumnDefinition;
 use common_ast::ast::CompactTarget;
+use common_ast::ast::ComputedExpr as AstComputedExpr;
 use common_ast::ast::CreateTableSource;
 use common_ast::ast::CreateTableStmt;
 use common_ast::ast::DescribeTableStmt;
@@ -38,20 +40,27 @@ use common_ast::ast::ShowTablesStatusStmt;
 use common_ast::ast::ShowTablesStmt;
 use common_ast::ast::Statement;
 use common_ast::ast::TableReference;
+use common_ast::ast::TimeTravelPoint;
 use common_ast::ast::TruncateTableStmt;
 use common_ast::ast::UndropTableStmt;
 use common_ast::ast::UriLocation;
+use common_ast::ast::VacuumDropTableStmt;
+use common_ast::ast::VacuumTableOption as AstVacuumTableOption;
+use common_ast::ast::VacuumTableStmt;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
 use common_ast::Backtrace;
 use common_ast::Dialect;
+use common_catalog::table::NavigationPoint;
+use common_catalog::table::Table;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::infer_schema_type;
 use common_expression::infer_table_schema;
 use common_expression::type_check::common_super_type;
 use common_expression::types::DataType;
+use common_expression::ComputedExpr;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
 use common_expression::TableField;
@@ -61,7 +70,12 @@ use common_storage::DataOperator;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::is_reserved_opt_key;
+use storages_common_table_meta::table::SnapshotRefKind;
+use storages_common_table_meta::table::OPT_KEY_CLONE_SOURCE_TABLE_IDS;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEXES;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use tracing::debug;
 
 use crate::binder::location::parse_uri_location;
@@ -76,11 +90,15 @@ use crate::planner::semantic::IdentifierNormalizer;
 use crate::planner::semantic::TypeChecker;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTableSource;
+use crate::plans::CreateSnapshotRefPlan;
 use crate::plans::CreateTablePlanV2;
 use crate::plans::DescribeTablePlan;
+use crate::plans::DropSnapshotRefPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::ExistsTablePlan;
+use crate::plans::FastForwardTablePlan;
 use crate::plans::OptimizeTableAction;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::Plan;
@@ -89,9 +107,13 @@ use crate::plans::RenameTableEntity;
 use crate::plans::RenameTablePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RewriteKind;
+use crate::plans::ScalarExpr;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UndropTablePlan;
+use crate::plans::VacuumDropTablePlan;
+use crate::plans::VacuumTableOption;
+use crate::plans::VacuumTablePlan;
 use crate::BindContext;
 use crate::ColumnBinding;
 use crate::Planner;
@@ -367,8 +389,41 @@ impl Binder {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
         }
 
+        let clone = match source {
+            Some(CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            }) => {
+                if engine != Engine::Fuse || uri_location.is_some() {
+                    return Err(ErrorCode::TableEngineNotSupported(
+                        "CREATE TABLE ... CLONE only supports FUSE tables in the default storage",
+                    ));
+                }
+                let (clone_source, source_table) = self
+                    .resolve_clone_source(catalog, database, table, travel_point)
+                    .await?;
+                Self::inherit_clone_source_options(&mut options, source_table.as_ref())?;
+                Some((clone_source, source_table))
+            }
+            _ => None,
+        };
+
         // Build table schema
         let (schema, field_default_exprs, field_comments) = match (&source, &as_query) {
+            (Some(CreateTableSource::Clone { .. }), None) => {
+                // The data of the source table is shared, so is its schema at the cloned point.
+                let (_, source_table) = clone.as_ref().unwrap();
+                (
+                    source_table.schema(),
+                    vec![],
+                    source_table.field_comments().clone(),
+                )
+            }
+            (Some(CreateTableSource::Clone { .. }), Some(_)) => Err(ErrorCode::BadArguments(
+                "CREATE TABLE ... CLONE can not be used with AS SELECT",
+            ))?,
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
                 self.analyze_create_table_schema(source).await?
@@ -418,6 +473,19 @@ impl Binder {
             ))?,
         };
 
+        if schema.fields().iter().any(|f| f.computed_expr().is_some()) {
+            if as_query.is_some() {
+                return Err(ErrorCode::BadArguments(
+                    "CREATE TABLE ... AS SELECT can not have computed columns",
+                ));
+            }
+            if engine != Engine::Fuse {
+                return Err(ErrorCode::BadArguments(format!(
+                    "computed columns are not supported by the {engine} engine"
+                )));
+            }
+        }
+
         if engine == Engine::Fuse {
             // Currently, [Table] can not accesses its database id yet, thus
             // here we keep the db id AS an entry of `table_meta.options`.
@@ -445,6 +513,14 @@ impl Binder {
                 Some(format!("({})", keys.join(", ")))
             }
         };
+        let cluster_key = match &clone {
+            Some((_, source_table)) if cluster_key.is_none() => source_table
+                .get_table_info()
+                .meta
+                .default_cluster_key
+                .clone(),
+            _ => cluster_key,
+        };
 
         let plan = CreateTablePlanV2 {
             if_not_exists: *if_not_exists,
@@ -471,10 +547,93 @@ impl Binder {
             } else {
                 None
             },
+            clone_source: clone.map(|(clone_source, _)| clone_source),
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
 
+    /// Resolve the source table of `CREATE TABLE ... CLONE` at the point of its history to clone.
+    async fn resolve_clone_source(
+        &self,
+        catalog: &Option<Identifier>,
+        database: &Option<Identifier>,
+        table: &Identifier,
+        travel_point: &Option<TimeTravelPoint>,
+    ) -> Result<(CloneTableSource, Arc<dyn Table>)> {
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database.as_ref().map_or_else(
+            || self.ctx.get_current_database(),
+            |ident| normalize_identifier(ident, &self.name_resolution_ctx).name,
+        );
+        let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+        let mut source_table = self.ctx.get_table(&catalog, &database, &table).await?;
+
+        let source_info = source_table.get_table_info();
+        if !source_table
+            .engine()
+            .eq_ignore_ascii_case(&Engine::Fuse.to_string())
+            || source_info.meta.storage_params.is_some()
+        {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "table {} can not be cloned, only FUSE tables in the default storage can",
+                source_info.desc
+            )));
+        }
+
+        let point = match travel_point {
+            Some(travel_point) => {
+                let point = self
+                    .resolve_data_travel_point(&BindContext::new(), travel_point)
+                    .await?;
+                source_table = source_table.navigate_to(&point).await?;
+                Some(point)
+            }
+            None => None,
+        };
+
+        let clone_source = CloneTableSource {
+            catalog,
+            database,
+            table,
+            point,
+        };
+        Ok((clone_source, source_table))
+    }
+
+    /// The clone reads the files of the source table, so it keeps the options deciding how they
+    /// are read, and records the tables it shares the files of.
+    fn inherit_clone_source_options(
+        options: &mut BTreeMap<String, String>,
+        source_table: &dyn Table,
+    ) -> Result<()> {
+        let source_options = source_table.options();
+        for (key, value) in source_options.iter() {
+            if (is_reserved_opt_key(key) && key != OPT_KEY_INVERTED_INDEXES)
+                || key == OPT_KEY_SNAPSHOT_LOCATION
+                || key == "TRANSIENT"
+            {
+                continue;
+            }
+            options.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+        if options.get(OPT_KEY_STORAGE_FORMAT) != source_options.get(OPT_KEY_STORAGE_FORMAT) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "table option {OPT_KEY_STORAGE_FORMAT} of a clone must be the same as the source table",
+            )));
+        }
+
+        let mut source_table_ids = source_table.get_id().to_string();
+        if let Some(ids) = source_options.get(OPT_KEY_CLONE_SOURCE_TABLE_IDS) {
+            source_table_ids.push(',');
+            source_table_ids.push_str(ids);
+        }
+        options.insert(OPT_KEY_CLONE_SOURCE_TABLE_IDS.to_owned(), source_table_ids);
+        Ok(())
+    }
+
     pub(in crate::planner::binder) async fn bind_drop_table(
         &mut self,
         stmt: &DropTableStmt,
@@ -659,6 +818,80 @@ impl Binder {
                     point,
                 })))
             }
+            AlterTableAction::CreateTag { tag, travel_point } => {
+                let point = self
+                    .resolve_snapshot_ref_point(bind_context, travel_point)
+                    .await?;
+                Ok(Plan::CreateSnapshotRef(Box::new(CreateSnapshotRefPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    name: normalize_identifier(tag, &self.name_resolution_ctx).name,
+                    kind: SnapshotRefKind::Tag,
+                    point,
+                })))
+            }
+            AlterTableAction::CreateBranch {
+                branch,
+                travel_point,
+            } => {
+                let point = self
+                    .resolve_snapshot_ref_point(bind_context, travel_point)
+                    .await?;
+                Ok(Plan::CreateSnapshotRef(Box::new(CreateSnapshotRefPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    name: normalize_identifier(branch, &self.name_resolution_ctx).name,
+                    kind: SnapshotRefKind::Branch,
+                    point,
+                })))
+            }
+            AlterTableAction::DropTag { tag } => {
+                Ok(Plan::DropSnapshotRef(Box::new(DropSnapshotRefPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    name: normalize_identifier(tag, &self.name_resolution_ctx).name,
+                    kind: SnapshotRefKind::Tag,
+                })))
+            }
+            AlterTableAction::DropBranch { branch } => {
+                Ok(Plan::DropSnapshotRef(Box::new(DropSnapshotRefPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    name: normalize_identifier(branch, &self.name_resolution_ctx).name,
+                    kind: SnapshotRefKind::Branch,
+                })))
+            }
+            AlterTableAction::FastForward { branch } => {
+                Ok(Plan::FastForwardTable(Box::new(FastForwardTablePlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    branch: normalize_identifier(branch, &self.name_resolution_ctx).name,
+                })))
+            }
+        }
+    }
+
+    async fn resolve_snapshot_ref_point(
+        &self,
+        bind_context: &BindContext,
+        travel_point: &Option<TimeTravelPoint>,
+    ) -> Result<Option<NavigationPoint>> {
+        match travel_point {
+            Some(travel_point) => Ok(Some(
+                self.resolve_data_travel_point(bind_context, travel_point)
+                    .await?,
+            )),
+            None => Ok(None),
         }
     }
 
@@ -831,6 +1064,67 @@ impl Binder {
         })))
     }
 
+    pub(in crate::planner::binder) async fn bind_vacuum_table(
+        &mut self,
+        stmt: &VacuumTableStmt,
+    ) -> Result<Plan> {
+        let VacuumTableStmt {
+            catalog,
+            database,
+            table,
+            option,
+        } = stmt;
+
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+
+        Ok(Plan::VacuumTable(Box::new(VacuumTablePlan {
+            catalog,
+            database,
+            table,
+            option: Self::vacuum_table_option(option),
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_vacuum_drop_table(
+        &mut self,
+        stmt: &VacuumDropTableStmt,
+    ) -> Result<Plan> {
+        let VacuumDropTableStmt {
+            catalog,
+            database,
+            option,
+        } = stmt;
+
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name);
+
+        Ok(Plan::VacuumDropTable(Box::new(VacuumDropTablePlan {
+            catalog,
+            database,
+            option: Self::vacuum_table_option(option),
+        })))
+    }
+
+    fn vacuum_table_option(option: &AstVacuumTableOption) -> VacuumTableOption {
+        VacuumTableOption {
+            retain_hours: option.retain_hours,
+            dry_run: option.dry_run,
+        }
+    }
+
     pub(in crate::planner::binder) async fn bind_exists_table(
         &mut self,
         stmt: &ExistsTableStmt,
@@ -878,6 +1172,11 @@ impl Binder {
                 for column in columns.iter() {
                     let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
                     let schema_data_type = TypeChecker::resolve_type_name(&column.data_type)?;
+                    if column.default_expr.is_some() && column.computed_expr.is_some() {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "column {name} can't have both default and computed expressions"
+                        )));
+                    }
 
                     fields.push(TableField::new(&name, schema_data_type.clone()));
                     fields_default_expr.push({
@@ -894,6 +1193,7 @@ impl Binder {
                     });
                     fields_comments.push(column.comment.clone().unwrap_or_default());
                 }
+                self.analyze_computed_exprs(columns, &mut fields).await?;
                 let schema = TableSchemaRefExt::create(fields);
                 Self::validate_create_table_schema(&schema)?;
                 Ok((schema, fields_default_expr, fields_comments))
@@ -961,13 +1261,89 @@ impl Binder {
         }
     }
 
+    /// Binds the expressions of the computed columns to the other columns of the table, and
+    /// keeps them in the fields of the computed columns.
+    async fn analyze_computed_exprs(
+        &self,
+        columns: &[ColumnDefinition],
+        fields: &mut [TableField],
+    ) -> Result<()> {
+        if columns.iter().all(|c| c.computed_expr.is_none()) {
+            return Ok(());
+        }
+
+        // The computed columns can only be computed from the other columns.
+        let mut bind_context = BindContext::new();
+        for (index, (column, field)) in columns.iter().zip(fields.iter()).enumerate() {
+            if column.computed_expr.is_none() {
+                bind_context.columns.push(ColumnBinding {
+                    database_name: None,
+                    table_name: None,
+                    column_name: field.name().clone(),
+                    index,
+                    data_type: Box::new(DataType::from(field.data_type())),
+                    visibility: Visibility::Visible,
+                });
+            }
+        }
+        let mut scalar_binder = ScalarBinder::new(
+            &bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+
+        for (column, field) in columns.iter().zip(fields.iter_mut()) {
+            let (expr, is_stored) = match &column.computed_expr {
+                Some(AstComputedExpr::Virtual(expr)) => (expr, false),
+                Some(AstComputedExpr::Stored(expr)) => (expr, true),
+                None => continue,
+            };
+            let name = field.name().clone();
+            let (scalar, expr_type) = scalar_binder.bind(expr).await?;
+            if !is_row_scalar(&scalar)
+                || is_expr_non_deterministic(&scalar.as_expr_with_col_index()?)
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "computed expression `{expr}` of column {name} must be a deterministic expression of the other columns"
+                ))
+                .set_span(expr.span()));
+            }
+            let data_type = DataType::from(field.data_type());
+            if common_super_type(data_type.clone(), expr_type.clone()).is_none() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "column {name} is of type {} but computed expression is of type {}",
+                    data_type, expr_type
+                )));
+            }
+
+            let mut expr = expr.clone();
+            walk_expr_mut(
+                &mut IdentifierNormalizer {
+                    ctx: &self.name_resolution_ctx,
+                },
+                &mut expr,
+            );
+            let expr = format!("{:#}", expr);
+            *field = field.clone().with_computed_expr(Some(if is_stored {
+                ComputedExpr::Stored(expr)
+            } else {
+                ComputedExpr::Virtual(expr)
+            }));
+        }
+        Ok(())
+    }
+
     async fn analyze_cluster_keys(
         &mut self,
         cluster_by: &[Expr],
         schema: TableSchemaRef,
     ) -> Result<Vec<String>> {
-        // Build a temporary BindContext to resolve the expr
+        // Build a temporary BindContext to resolve the expr, the virtual computed columns
+        // are not stored, so the table can't be clustered by them.
         let mut bind_context = BindContext::new();
+        let schema = schema.remove_virtual_computed_fields();
         for (index, field) in schema.fields().iter().enumerate() {
             let column = ColumnBinding {
                 database_name: None,
@@ -1011,6 +1387,22 @@ impl Binder {
     }
 }
 
+// Whether the scalar is computed from the values of a single row.
+fn is_row_scalar(scalar: &ScalarExpr) -> bool {
+    match scalar {
+        ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_) => true,
+        ScalarExpr::AndExpr(expr) => is_row_scalar(&expr.left) && is_row_scalar(&expr.right),
+        ScalarExpr::OrExpr(expr) => is_row_scalar(&expr.left) && is_row_scalar(&expr.right),
+        ScalarExpr::NotExpr(expr) => is_row_scalar(&expr.argument),
+        ScalarExpr::ComparisonExpr(expr) => is_row_scalar(&expr.left) && is_row_scalar(&expr.right),
+        ScalarExpr::FunctionCall(expr) => expr.arguments.iter().all(is_row_scalar),
+        ScalarExpr::CastExpr(expr) => is_row_scalar(&expr.argument),
+        ScalarExpr::AggregateFunction(_)
+        | ScalarExpr::SubqueryExpr(_)
+        | ScalarExpr::PlaceholderExpr(_) => false,
+    }
+}
+
 fn is_expr_non_deterministic(expr: &common_expression::Expr) -> bool {
     match expr {
         common_expression::Expr::Constant { .. } => false,
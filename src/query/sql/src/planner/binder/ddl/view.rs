@@ -12,17 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_ast::ast::AlterViewStmt;
 use common_ast::ast::CreateViewStmt;
 use common_ast::ast::DropViewStmt;
+use common_ast::ast::Query;
+use common_ast::ast::RefreshMaterializedViewStmt;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::infer_schema_type;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
 
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::AlterViewPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::BindContext;
 
 impl Binder {
     pub(in crate::planner::binder) async fn bind_create_view(
@@ -31,6 +45,7 @@ impl Binder {
     ) -> Result<Plan> {
         let CreateViewStmt {
             if_not_exists,
+            materialized,
             catalog,
             database,
             view,
@@ -52,6 +67,19 @@ impl Binder {
             .iter()
             .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
             .collect::<Vec<_>>();
+        if *materialized {
+            return self
+                .bind_create_materialized_view(
+                    *if_not_exists,
+                    tenant,
+                    catalog,
+                    database,
+                    viewname,
+                    column_names,
+                    query,
+                )
+                .await;
+        }
         let subquery = format!("{}", query);
 
         let plan = CreateViewPlan {
@@ -66,6 +94,83 @@ impl Binder {
         Ok(Plan::CreateView(Box::new(plan)))
     }
 
+    #[allow(clippy::too_many_arguments)]
+    async fn bind_create_materialized_view(
+        &mut self,
+        if_not_exists: bool,
+        tenant: String,
+        catalog: String,
+        database: String,
+        viewname: String,
+        column_names: Vec<String>,
+        query: &Query,
+    ) -> Result<Plan> {
+        let definition = self.analyze_materialized_view_query(query)?;
+        let source = self.resolve_materialized_view_source(&definition).await?;
+
+        let init_bind_context = BindContext::new();
+        let (_, bind_context) = self.bind_query(&init_bind_context, query).await?;
+        if !column_names.is_empty() && column_names.len() != bind_context.columns.len() {
+            return Err(ErrorCode::BadDataArrayLength(format!(
+                "column name length mismatch, expect {}, got {}",
+                bind_context.columns.len(),
+                column_names.len(),
+            )));
+        }
+        let fields = bind_context
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, column_binding)| {
+                let name = column_names.get(idx).unwrap_or(&column_binding.column_name);
+                if name.contains('`') {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Materialized view does not support identifier containing backticks: {name}"
+                    )));
+                }
+                Ok(TableField::new(
+                    name,
+                    infer_schema_type(&column_binding.data_type)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let names = fields
+            .iter()
+            .map(|field| field.name().clone())
+            .collect::<Vec<_>>();
+
+        let db_id = self
+            .ctx
+            .get_catalog(&catalog)?
+            .get_database(&tenant, &database)
+            .await?
+            .get_db_info()
+            .ident
+            .db_id;
+        let mut options = BTreeMap::new();
+        options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_owned(),
+            definition.query(&names),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID.to_owned(),
+            source.get_id().to_string(),
+        );
+
+        let plan = CreateMaterializedViewPlan {
+            if_not_exists,
+            tenant,
+            catalog,
+            database,
+            viewname,
+            schema: TableSchemaRefExt::create(fields),
+            options,
+            definition,
+        };
+        Ok(Plan::CreateMaterializedView(Box::new(plan)))
+    }
+
     pub(in crate::planner::binder) async fn bind_alter_view(
         &mut self,
         stmt: &AlterViewStmt,
@@ -136,4 +241,45 @@ impl Binder {
         };
         Ok(Plan::DropView(Box::new(plan)))
     }
+
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let viewname = normalize_identifier(view, &self.name_resolution_ctx).name;
+
+        let table = self.ctx.get_table(&catalog, &database, &viewname).await?;
+        let definition = self
+            .parse_materialized_view(table.as_ref())?
+            .ok_or_else(|| {
+                ErrorCode::SemanticError(format!(
+                    "{}.{} is not a materialized view",
+                    database, viewname
+                ))
+            })?;
+
+        let plan = RefreshMaterializedViewPlan {
+            tenant,
+            catalog,
+            database,
+            viewname,
+            definition,
+        };
+        Ok(Plan::RefreshMaterializedView(Box::new(plan)))
+    }
 }
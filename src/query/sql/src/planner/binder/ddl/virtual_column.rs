@@ -0,0 +1,238 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateVirtualColumnStmt;
+use common_ast::ast::DropVirtualColumnStmt;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Literal;
+use common_ast::ast::MapAccessor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+use common_expression::TableSchemaRef;
+use storages_common_table_meta::table::VirtualColumnDefinition;
+use storages_common_table_meta::table::VirtualColumnKey;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::TypeChecker;
+use crate::plans::CreateVirtualColumnPlan;
+use crate::plans::DropVirtualColumnPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_create_virtual_column(
+        &mut self,
+        stmt: &CreateVirtualColumnStmt,
+    ) -> Result<Plan> {
+        let CreateVirtualColumnStmt {
+            if_not_exists,
+            virtual_columns,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, table) =
+            self.normalize_virtual_column_table(catalog, database, table);
+        let table_ref = self.ctx.get_table(&catalog, &database, &table).await?;
+        // virtual columns are stored by the blocks of parquet format only
+        if !table_ref.engine().eq_ignore_ascii_case("FUSE")
+            || table_ref
+                .options()
+                .get(OPT_KEY_STORAGE_FORMAT)
+                .map_or(false, |format| format.eq_ignore_ascii_case("native"))
+        {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "virtual columns can not be created for table {}, only FUSE tables of parquet storage format support them",
+                table
+            )));
+        }
+        let schema = table_ref.schema();
+
+        let mut definitions: Vec<VirtualColumnDefinition> =
+            Vec::with_capacity(virtual_columns.len());
+        for expr in virtual_columns {
+            let definition = self.resolve_virtual_column(expr, &schema)?;
+            if definitions.iter().any(|d| d.name() == definition.name()) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicated virtual column `{}`",
+                    definition.name()
+                )));
+            }
+            definitions.push(definition);
+        }
+
+        let plan = CreateVirtualColumnPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog,
+            database,
+            table,
+            virtual_columns: definitions,
+        };
+        Ok(Plan::CreateVirtualColumn(Box::new(plan)))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_virtual_column(
+        &mut self,
+        stmt: &DropVirtualColumnStmt,
+    ) -> Result<Plan> {
+        let DropVirtualColumnStmt {
+            if_exists,
+            virtual_columns,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, table) =
+            self.normalize_virtual_column_table(catalog, database, table);
+        let schema = self
+            .ctx
+            .get_table(&catalog, &database, &table)
+            .await?
+            .schema();
+
+        let names = virtual_columns
+            .iter()
+            .map(|expr| Ok(self.resolve_virtual_column(expr, &schema)?.name()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let plan = DropVirtualColumnPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            table,
+            virtual_columns: names,
+        };
+        Ok(Plan::DropVirtualColumn(Box::new(plan)))
+    }
+
+    fn normalize_virtual_column_table(
+        &self,
+        catalog: &Option<Identifier>,
+        database: &Option<Identifier>,
+        table: &Identifier,
+    ) -> (String, String, String) {
+        let catalog = catalog
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+        (catalog, database, table)
+    }
+
+    /// Resolve the access to a path of a variant column, like `v:user.id` or
+    /// `v['items'][0]::BIGINT`, into the definition of a virtual column.
+    fn resolve_virtual_column(
+        &self,
+        expr: &Expr,
+        schema: &TableSchemaRef,
+    ) -> Result<VirtualColumnDefinition> {
+        let invalid = || {
+            ErrorCode::SemanticError(format!(
+                "virtual column `{}` must be a path of a variant column with constant keys, optionally cast to a scalar type",
+                expr
+            ))
+            .set_span(expr.span())
+        };
+
+        let (mut access, cast_type) = match expr {
+            Expr::Cast {
+                expr, target_type, ..
+            } => {
+                let cast_type = TypeChecker::resolve_type_name(target_type)?;
+                match cast_type {
+                    TableDataType::Boolean
+                    | TableDataType::Number(_)
+                    | TableDataType::Decimal(_)
+                    | TableDataType::String
+                    | TableDataType::Date
+                    | TableDataType::Timestamp
+                    | TableDataType::Variant => {}
+                    _ => {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "virtual column can't be cast to {}",
+                            cast_type
+                        ))
+                        .set_span(expr.span()));
+                    }
+                }
+                (&**expr, cast_type)
+            }
+            _ => (expr, TableDataType::Variant),
+        };
+
+        let mut keys = vec![];
+        while let Expr::MapAccess {
+            expr: inner_expr,
+            accessor,
+            ..
+        } = access
+        {
+            let key = match accessor {
+                MapAccessor::Period { key } | MapAccessor::Colon { key } => {
+                    VirtualColumnKey::Name(key.name.clone())
+                }
+                MapAccessor::PeriodNumber { key } => VirtualColumnKey::Index(*key),
+                MapAccessor::Bracket {
+                    key: box Expr::Literal { lit, .. },
+                } => match lit {
+                    Literal::String(name) => VirtualColumnKey::Name(name.clone()),
+                    Literal::Integer(index) => VirtualColumnKey::Index(*index),
+                    _ => return Err(invalid()),
+                },
+                _ => return Err(invalid()),
+            };
+            keys.push(key);
+            access = &**inner_expr;
+        }
+        keys.reverse();
+
+        let source_column = match access {
+            Expr::ColumnRef {
+                database: None,
+                table: None,
+                column,
+                ..
+            } if !keys.is_empty() => normalize_identifier(column, &self.name_resolution_ctx).name,
+            _ => return Err(invalid()),
+        };
+        let field = schema.field_with_name(&source_column)?;
+        if field.data_type().remove_nullable() != TableDataType::Variant {
+            return Err(ErrorCode::SemanticError(format!(
+                "virtual column source `{}` must be of type Variant, but got {}",
+                source_column,
+                field.data_type()
+            ))
+            .set_span(access.span()));
+        }
+
+        Ok(VirtualColumnDefinition {
+            source_column,
+            path: VirtualColumnKey::format_path(&keys),
+            cast_type,
+        })
+    }
+}
@@ -67,6 +67,7 @@ impl<'a> Binder {
 
         let selection = if let Some(expr) = filter {
             let (scalar, _) = scalar_binder.bind(expr).await?;
+            self.check_no_virtual_computed_column(&scalar)?;
             Some(scalar)
         } else {
             None
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_ast::ast::InsertSource;
 use common_ast::ast::InsertStmt;
 use common_ast::ast::Statement;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRefExt;
 use common_meta_types::FileFormatOptions;
@@ -44,6 +45,7 @@ impl Binder {
             columns,
             source,
             overwrite,
+            branch,
         } = stmt;
         let catalog_name = catalog.as_ref().map_or_else(
             || self.ctx.get_current_catalog(),
@@ -60,18 +62,25 @@ impl Binder {
             .await?;
         let table_id = table.get_id();
 
+        // The computed columns can't be inserted into, they are computed from the others.
         let schema = if columns.is_empty() {
-            table.schema()
+            Arc::new(table.schema().remove_computed_fields())
         } else {
             let schema = table.schema();
             let fields = columns
                 .iter()
                 .map(|ident| {
-                    schema
-                        .field_with_name(
-                            &normalize_identifier(ident, &self.name_resolution_ctx).name,
-                        )
-                        .map(|v| v.clone())
+                    let field = schema.field_with_name(
+                        &normalize_identifier(ident, &self.name_resolution_ctx).name,
+                    )?;
+                    if field.computed_expr().is_some() {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "can not insert into computed column {}",
+                            field.name()
+                        ))
+                        .set_span(ident.span));
+                    }
+                    Ok(field.clone())
                 })
                 .collect::<Result<Vec<_>>>()?;
             TableSchemaRefExt::create(fields)
@@ -124,6 +133,9 @@ impl Binder {
             table_id,
             schema,
             overwrite: *overwrite,
+            branch: branch
+                .as_ref()
+                .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name),
             source: input_source?,
         };
 
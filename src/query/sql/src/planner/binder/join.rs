@@ -18,12 +18,16 @@ use async_recursion::async_recursion;
 use common_ast::ast::split_conjunctions_expr;
 use common_ast::ast::split_equivalent_predicate_expr;
 use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
 use common_ast::ast::JoinCondition;
 use common_ast::ast::JoinOperator;
+use common_ast::ast::TableAlias;
+use common_ast::ast::TableReference;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::type_check::common_super_type;
+use common_functions::srfs::BUILTIN_SRFS;
 
 use crate::binder::JoinPredicate;
 use crate::binder::Visibility;
@@ -59,6 +63,32 @@ impl Binder {
     ) -> Result<(SExpr, BindContext)> {
         let (left_child, left_context) =
             self.bind_table_reference(bind_context, &join.left).await?;
+
+        // The set-returning table functions are evaluated for each row of the left side,
+        // so that their arguments can reference the columns of the left side.
+        if let TableReference::TableFunction {
+            name,
+            params,
+            named_params,
+            alias,
+            ..
+        } = join.right.as_ref()
+        {
+            if BUILTIN_SRFS.contains(&normalize_identifier(name, &self.name_resolution_ctx).name) {
+                return self
+                    .bind_lateral_srf(
+                        join,
+                        left_child,
+                        left_context,
+                        name,
+                        params,
+                        named_params,
+                        alias,
+                    )
+                    .await;
+            }
+        }
+
         let (right_child, right_context) =
             self.bind_table_reference(bind_context, &join.right).await?;
 
@@ -168,6 +198,36 @@ impl Binder {
         Ok((s_expr, bind_context))
     }
 
+    #[allow(clippy::too_many_arguments)]
+    async fn bind_lateral_srf(
+        &mut self,
+        join: &common_ast::ast::Join,
+        left_child: SExpr,
+        left_context: BindContext,
+        name: &Identifier,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let (s_expr, bind_context) = self
+            .bind_project_set(left_context, left_child, name, params, named_params, alias)
+            .await?;
+        match (&join.op, &join.condition) {
+            (JoinOperator::Inner | JoinOperator::CrossJoin, JoinCondition::None) => {
+                Ok((s_expr, bind_context))
+            }
+            (JoinOperator::Inner, JoinCondition::On(cond)) => {
+                let s_expr = self.bind_where(&bind_context, cond, s_expr).await?;
+                Ok((s_expr, bind_context))
+            }
+            _ => Err(ErrorCode::SemanticError(format!(
+                "Only cross join and inner join with ON condition are supported for set-returning function {}",
+                name
+            ))
+            .set_span(name.span)),
+        }
+    }
+
     pub fn bind_join_with_type(
         &mut self,
         join_type: JoinType,
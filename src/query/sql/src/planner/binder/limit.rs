@@ -19,6 +19,7 @@ use common_exception::Result;
 
 use crate::binder::Binder;
 use crate::optimizer::SExpr;
+use crate::planner::semantic::unsupported_placeholder;
 use crate::planner::semantic::TypeChecker;
 use crate::plans::Limit;
 use crate::BindContext;
@@ -40,18 +41,12 @@ impl Binder {
         );
 
         let limit_cnt = match limit {
-            Some(limit) => Some(
-                Self::bind_limit_argument(limit)
-                    .ok_or_else(|| ErrorCode::SemanticError("Invalid LIMIT expression"))?
-                    as usize,
-            ),
+            Some(limit) => Some(Self::bind_limit_argument(limit, "LIMIT")? as usize),
             None => None,
         };
 
         let offset_cnt = if let Some(offset) = offset {
-            Self::bind_limit_argument(offset)
-                .ok_or_else(|| ErrorCode::SemanticError("Invalid OFFSET expression"))?
-                as usize
+            Self::bind_limit_argument(offset, "OFFSET")? as usize
         } else {
             0
         };
@@ -67,13 +62,16 @@ impl Binder {
     /// So far, we only support integer literal as limit argument.
     /// So we will try to extract the integer value from the AST directly.
     /// In the future it's possible to treat the argument as an expression.
-    fn bind_limit_argument(expr: &Expr) -> Option<u64> {
+    fn bind_limit_argument(expr: &Expr, clause: &str) -> Result<u64> {
         match expr {
             Expr::Literal {
                 lit: Literal::Integer(value),
                 ..
-            } => Some(*value),
-            _ => None,
+            } => Ok(*value),
+            Expr::Placeholder { span, .. } => Err(unsupported_placeholder(*span, clause)),
+            _ => Err(ErrorCode::SemanticError(format!(
+                "Invalid {clause} expression"
+            ))),
         }
     }
 }
@@ -0,0 +1,309 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::Query;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
+use common_ast::ast::Statement;
+use common_ast::ast::TableReference;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::Backtrace;
+use common_ast::Dialect;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::optimizer::match_aggregate_scan;
+use crate::optimizer::SExpr;
+use crate::plans::MaterializedViewColumn;
+use crate::plans::MaterializedViewDefinition;
+use crate::IndexType;
+use crate::MaterializedViewEntry;
+
+impl Binder {
+    /// Analyze the defining query of a materialized view, which must be an aggregation
+    /// `SELECT <group keys and aggregates> FROM <table> GROUP BY <group keys>`, with
+    /// the aggregates being `SUM`, `COUNT`, `MIN` or `MAX` of columns.
+    pub(in crate::planner::binder) fn analyze_materialized_view_query(
+        &self,
+        query: &Query,
+    ) -> Result<MaterializedViewDefinition> {
+        let select = match &query.body {
+            SetExpr::Select(select)
+                if query.with.is_none()
+                    && query.order_by.is_empty()
+                    && query.limit.is_empty()
+                    && query.offset.is_none() =>
+            {
+                select
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "Materialized view must be defined by a single SELECT without WITH, ORDER BY or LIMIT",
+                ));
+            }
+        };
+        if select.distinct || select.selection.is_some() || select.having.is_some() {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view does not support DISTINCT, WHERE or HAVING",
+            ));
+        }
+        if select.group_by.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view must be defined by an aggregation with GROUP BY",
+            ));
+        }
+
+        let (catalog, database, table) = match select.from.as_slice() {
+            [
+                TableReference::Table {
+                    catalog,
+                    database,
+                    table,
+                    travel_point: None,
+                    ..
+                },
+            ] => (
+                catalog
+                    .as_ref()
+                    .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                    .unwrap_or_else(|| self.ctx.get_current_catalog()),
+                database
+                    .as_ref()
+                    .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                    .unwrap_or_else(|| self.ctx.get_current_database()),
+                normalize_identifier(table, &self.name_resolution_ctx).name,
+            ),
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "Materialized view must be defined over a single table",
+                ));
+            }
+        };
+
+        let mut group_keys = Vec::with_capacity(select.group_by.len());
+        for expr in select.group_by.iter() {
+            match expr {
+                Expr::ColumnRef { column, .. } => {
+                    group_keys.push(normalize_identifier(column, &self.name_resolution_ctx).name)
+                }
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Materialized view only supports grouping by columns, but got {expr}"
+                    )));
+                }
+            }
+        }
+
+        let mut columns = Vec::with_capacity(select.select_list.len());
+        for target in select.select_list.iter() {
+            let expr = match target {
+                SelectTarget::AliasedExpr { expr, .. } => expr.as_ref(),
+                SelectTarget::QualifiedName { .. } => {
+                    return Err(ErrorCode::SemanticError(
+                        "Materialized view does not support wildcard in the select list",
+                    ));
+                }
+            };
+            let column = match expr {
+                Expr::ColumnRef { column, .. } => {
+                    let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+                    if !group_keys.contains(&column) {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "Column {column} of materialized view must be a group key"
+                        )));
+                    }
+                    MaterializedViewColumn::GroupKey { column }
+                }
+                Expr::CountAll { .. } => MaterializedViewColumn::Aggregate {
+                    func_name: "count".to_string(),
+                    arg: None,
+                },
+                Expr::FunctionCall {
+                    distinct: false,
+                    name,
+                    args,
+                    params,
+                    ..
+                } if params.is_empty()
+                    && MaterializedViewColumn::merge_func_name(&name.name.to_lowercase())
+                        .is_some() =>
+                {
+                    match args.as_slice() {
+                        [Expr::ColumnRef { column, .. }] => MaterializedViewColumn::Aggregate {
+                            func_name: name.name.to_lowercase(),
+                            arg: Some(normalize_identifier(column, &self.name_resolution_ctx).name),
+                        },
+                        _ => {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "Materialized view only supports aggregating columns, but got {expr}"
+                            )));
+                        }
+                    }
+                }
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Materialized view only supports group keys and SUM, COUNT, MIN, MAX of columns, but got {expr}"
+                    )));
+                }
+            };
+            columns.push(column);
+        }
+
+        let names = [&catalog, &database, &table]
+            .into_iter()
+            .chain(group_keys.iter())
+            .chain(columns.iter().filter_map(|column| match column {
+                MaterializedViewColumn::Aggregate { arg, .. } => arg.as_ref(),
+                MaterializedViewColumn::GroupKey { .. } => None,
+            }));
+        for name in names {
+            if name.contains('`') {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Materialized view does not support identifier containing backticks: {name}"
+                )));
+            }
+        }
+
+        Ok(MaterializedViewDefinition {
+            catalog,
+            database,
+            table,
+            columns,
+        })
+    }
+
+    /// Resolve the base table of a materialized view, which must be a fuse table.
+    pub(in crate::planner::binder) async fn resolve_materialized_view_source(
+        &self,
+        definition: &MaterializedViewDefinition,
+    ) -> Result<Arc<dyn Table>> {
+        let table = self
+            .ctx
+            .get_table(&definition.catalog, &definition.database, &definition.table)
+            .await?;
+        if !table.engine().eq_ignore_ascii_case("FUSE") {
+            return Err(ErrorCode::SemanticError(format!(
+                "Materialized view only supports fuse tables, but {}.{} is of engine {}",
+                definition.database,
+                definition.table,
+                table.engine()
+            )));
+        }
+
+        let schema = table.schema();
+        for column in definition.columns.iter() {
+            match column {
+                MaterializedViewColumn::GroupKey { column } => {
+                    schema.field_with_name(column)?;
+                }
+                MaterializedViewColumn::Aggregate { arg: Some(arg), .. } => {
+                    schema.field_with_name(arg)?;
+                }
+                MaterializedViewColumn::Aggregate { arg: None, .. } => (),
+            }
+        }
+
+        Ok(table)
+    }
+
+    /// Parse the definition of a materialized view from the table storing its rows,
+    /// returns `None` if the table is not a materialized view.
+    pub(in crate::planner::binder) fn parse_materialized_view(
+        &self,
+        view: &dyn Table,
+    ) -> Result<Option<MaterializedViewDefinition>> {
+        let query = match view.options().get(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+            Some(query) => query,
+            None => return Ok(None),
+        };
+        let tokens = tokenize_sql(query.as_str())?;
+        let backtrace = Backtrace::new();
+        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL, &backtrace)?;
+        match &stmt {
+            Statement::Query(query) => Ok(Some(self.analyze_materialized_view_query(query)?)),
+            _ => Err(ErrorCode::Internal("Invalid MATERIALIZED VIEW object")),
+        }
+    }
+
+    /// Collect the fresh materialized views of the tables aggregated by the query,
+    /// which the optimizer can answer the aggregations from.
+    pub(in crate::planner::binder) async fn bind_materialized_views(
+        &mut self,
+        s_expr: &SExpr,
+    ) -> Result<()> {
+        let mut table_indexes = vec![];
+        collect_aggregated_tables(s_expr, &mut table_indexes);
+
+        let tenant = self.ctx.get_tenant();
+        for table_index in table_indexes {
+            let entry = self.metadata.read().table(table_index).clone();
+            let table = entry.table();
+            if !table.engine().eq_ignore_ascii_case("FUSE") {
+                continue;
+            }
+            let snapshot_location = match table.options().get(OPT_KEY_SNAPSHOT_LOCATION) {
+                Some(snapshot_location) => snapshot_location,
+                None => continue,
+            };
+            let table_id = table.get_id().to_string();
+
+            let catalog = self.catalogs.get_catalog(entry.catalog())?;
+            for view in catalog.list_tables(&tenant, entry.database()).await? {
+                let options = view.options();
+                if options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID) != Some(&table_id)
+                    || options.get(OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT_LOCATION)
+                        != Some(snapshot_location)
+                {
+                    continue;
+                }
+                if let Some(definition) = self.parse_materialized_view(view.as_ref())? {
+                    let mut metadata = self.metadata.write();
+                    let view_index = metadata.add_table(
+                        entry.catalog().to_string(),
+                        entry.database().to_string(),
+                        view,
+                        None,
+                    );
+                    metadata.add_materialized_view(table_index, MaterializedViewEntry {
+                        table_index: view_index,
+                        definition,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn collect_aggregated_tables(s_expr: &SExpr, table_indexes: &mut Vec<IndexType>) {
+    if let Some((_, _, scan)) = match_aggregate_scan(s_expr) {
+        if !table_indexes.contains(&scan.table_index) {
+            table_indexes.push(scan.table_index);
+        }
+    }
+    for child in s_expr.children() {
+        collect_aggregated_tables(child, table_indexes);
+    }
+}
@@ -19,6 +19,7 @@ mod binder;
 /// SQL builders;
 mod builders;
 mod copy;
+mod cte;
 mod ddl;
 mod delete;
 mod distinct;
@@ -28,8 +29,10 @@ mod join;
 mod kill;
 mod limit;
 mod location;
+mod materialized_view;
 mod presign;
 mod project;
+mod project_set;
 mod scalar;
 mod scalar_common;
 mod scalar_visitor;
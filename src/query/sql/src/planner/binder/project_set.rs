@@ -0,0 +1,275 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::BinaryOperator;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::IntervalKind;
+use common_ast::ast::Literal;
+use common_ast::ast::TableAlias;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::DataType;
+use common_functions::srfs::BUILTIN_SRFS;
+
+use crate::binder::scalar::ScalarBinder;
+use crate::binder::Binder;
+use crate::binder::ColumnBinding;
+use crate::binder::Visibility;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::DummyTableScan;
+use crate::plans::EvalScalar;
+use crate::plans::FunctionCall;
+use crate::plans::ProjectSet;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::BindContext;
+
+impl Binder {
+    /// Bind a set-returning table function such as `generate_series(1, 10)` without
+    /// preceding tables, it's evaluated over a single dummy row.
+    pub(super) async fn bind_srf_table(
+        &mut self,
+        bind_context: &BindContext,
+        name: &Identifier,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let input = SExpr::create_leaf(DummyTableScan.into());
+        self.bind_project_set(
+            bind_context.replace(),
+            input,
+            name,
+            params,
+            named_params,
+            alias,
+        )
+        .await
+    }
+
+    /// Bind a set-returning table function whose arguments can reference the columns of
+    /// `input`, e.g. `SELECT * FROM t, unnest(t.arr)`. The rows of `input` are repeated
+    /// for each generated value, which is appended as a new column.
+    pub(super) async fn bind_project_set(
+        &mut self,
+        input_context: BindContext,
+        input: SExpr,
+        name: &Identifier,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let func_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let srf = BUILTIN_SRFS.get(&func_name).ok_or_else(|| {
+            ErrorCode::UnknownFunction(format!("Unknown set-returning function {}", func_name))
+        })?;
+        let params = if srf.params.is_empty() {
+            if !named_params.is_empty() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "{} doesn't accept named arguments",
+                    func_name
+                ))
+                .set_span(name.span));
+            }
+            params.to_vec()
+        } else {
+            arrange_named_args(name.span, &func_name, srf.params, params, named_params)?
+        };
+
+        let mut scalar_binder = ScalarBinder::new(
+            &input_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let mut args = Vec::with_capacity(params.len());
+        let mut args_type = Vec::with_capacity(params.len());
+        for param in params.iter() {
+            let param = match param {
+                Expr::Interval { span, expr, unit } => interval_to_tuple(*span, expr, unit)?,
+                _ => param.clone(),
+            };
+            let (arg, arg_type) = scalar_binder.bind(&param).await?;
+            args.push(arg);
+            args_type.push(arg_type);
+        }
+        let return_type = srf.return_type(&args_type)?;
+
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column(func_name.clone(), return_type.clone());
+        let project_set = ProjectSet {
+            func_name: srf.name.to_string(),
+            args,
+            return_type: Box::new(return_type.clone()),
+            index,
+        };
+        let mut s_expr = SExpr::create_unary(project_set.into(), input);
+
+        let mut srf_context = input_context.replace();
+        let column = ColumnBinding {
+            database_name: None,
+            table_name: None,
+            column_name: func_name,
+            index,
+            data_type: Box::new(return_type.clone()),
+            visibility: Visibility::Visible,
+        };
+        if srf.columns.is_empty() {
+            srf_context.columns.push(column);
+        } else {
+            // The function generates tuples of the columns, which are extracted into
+            // the columns of the function.
+            let DataType::Tuple(fields_type) = return_type else {
+                return Err(ErrorCode::Internal(format!(
+                    "{} should generate tuples, but got {}",
+                    column.column_name, return_type
+                )));
+            };
+            let mut items = Vec::with_capacity(fields_type.len());
+            for (i, (column_name, data_type)) in
+                srf.columns.iter().zip(fields_type.into_iter()).enumerate()
+            {
+                let index = self
+                    .metadata
+                    .write()
+                    .add_derived_column(column_name.to_string(), data_type.clone());
+                items.push(ScalarItem {
+                    scalar: FunctionCall {
+                        params: vec![i + 1],
+                        arguments: vec![ScalarExpr::BoundColumnRef(BoundColumnRef {
+                            column: ColumnBinding {
+                                visibility: Visibility::InVisible,
+                                ..column.clone()
+                            },
+                        })],
+                        func_name: "get".to_string(),
+                        return_type: Box::new(data_type.clone()),
+                    }
+                    .into(),
+                    index,
+                });
+                srf_context.columns.push(ColumnBinding {
+                    database_name: None,
+                    table_name: None,
+                    column_name: column_name.to_string(),
+                    index,
+                    data_type: Box::new(data_type),
+                    visibility: Visibility::Visible,
+                });
+            }
+            s_expr = SExpr::create_unary(EvalScalar { items }.into(), s_expr);
+        }
+        if let Some(alias) = alias {
+            srf_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+
+        let mut bind_context = input_context;
+        bind_context.columns.extend(srf_context.columns);
+        Ok((s_expr, bind_context))
+    }
+}
+
+/// Puts the named arguments at the positions of their names in `params` after the
+/// positional arguments, the arguments not given are NULL.
+fn arrange_named_args(
+    span: Span,
+    func_name: &str,
+    params: &[&str],
+    args: &[Expr],
+    named_args: &[(String, Expr)],
+) -> Result<Vec<Expr>> {
+    if args.len() > params.len() {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expects at most {} arguments, but got {}",
+            func_name,
+            params.len(),
+            args.len()
+        ))
+        .set_span(span));
+    }
+    let mut arranged = args.iter().cloned().map(Some).collect::<Vec<_>>();
+    arranged.resize(params.len(), None);
+    for (name, arg) in named_args.iter() {
+        let name = name.to_lowercase();
+        let Some(position) = params.iter().position(|param| *param == name) else {
+            return Err(ErrorCode::SemanticError(format!(
+                "Unknown argument '{}' of {}",
+                name, func_name
+            ))
+            .set_span(span));
+        };
+        if arranged[position].is_some() {
+            return Err(ErrorCode::SemanticError(format!(
+                "The argument '{}' of {} is given more than once",
+                name, func_name
+            ))
+            .set_span(span));
+        }
+        arranged[position] = Some(arg.clone());
+    }
+
+    while let Some(None) = arranged.last() {
+        arranged.pop();
+    }
+    Ok(arranged
+        .into_iter()
+        .map(|arg| {
+            arg.unwrap_or(Expr::Literal {
+                span,
+                lit: Literal::Null,
+            })
+        })
+        .collect())
+}
+
+/// The interval step of `generate_series` is passed as a tuple of `(months, microseconds)`,
+/// e.g. `INTERVAL 2 HOUR` is rewritten to `(0, 2 * 3600000000)`.
+fn interval_to_tuple(span: Span, expr: &Expr, unit: &IntervalKind) -> Result<Expr> {
+    let (months, micros) = match unit {
+        IntervalKind::Year => (12, 0),
+        IntervalKind::Quarter => (3, 0),
+        IntervalKind::Month => (1, 0),
+        IntervalKind::Day => (0, 24 * 3600 * 1_000_000),
+        IntervalKind::Hour => (0, 3600 * 1_000_000),
+        IntervalKind::Minute => (0, 60 * 1_000_000),
+        IntervalKind::Second => (0, 1_000_000),
+        _ => {
+            return Err(ErrorCode::SemanticError(format!(
+                "Unsupported interval unit {} of set-returning function",
+                unit
+            ))
+            .set_span(span));
+        }
+    };
+    let multiply = |factor: u64| Expr::BinaryOp {
+        span,
+        op: BinaryOperator::Multiply,
+        left: Box::new(expr.clone()),
+        right: Box::new(Expr::Literal {
+            span,
+            lit: Literal::Integer(factor),
+        }),
+    };
+    Ok(Expr::Tuple {
+        span,
+        exprs: vec![multiply(months), multiply(micros)],
+    })
+}
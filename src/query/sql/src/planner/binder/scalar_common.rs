@@ -183,7 +183,7 @@ pub fn prune_by_children(scalar: &ScalarExpr, columns: &HashSet<ScalarExpr>) ->
 
     match scalar {
         ScalarExpr::BoundColumnRef(_) => false,
-        ScalarExpr::ConstantExpr(_) => true,
+        ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => true,
         ScalarExpr::AndExpr(scalar) => {
             prune_by_children(&scalar.left, columns) && prune_by_children(&scalar.right, columns)
         }
@@ -86,7 +86,7 @@ pub trait ScalarVisitor: Sized {
                                 ScalarExpr::CastExpr(CastExpr { argument, .. }) => {
                                     stack.push(RecursionProcessing::Call(argument))
                                 }
-                                ScalarExpr::SubqueryExpr(_) => {}
+                                ScalarExpr::SubqueryExpr(_) | ScalarExpr::PlaceholderExpr(_) => {}
                             }
 
                             visitor
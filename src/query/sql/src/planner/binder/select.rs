@@ -15,10 +15,12 @@
 use std::collections::HashMap;
 
 use async_recursion::async_recursion;
+use common_ast::ast::BinaryOperator;
 use common_ast::ast::Expr;
 use common_ast::ast::Join;
 use common_ast::ast::JoinCondition;
 use common_ast::ast::JoinOperator;
+use common_ast::ast::Literal;
 use common_ast::ast::OrderByExpr;
 use common_ast::ast::Query;
 use common_ast::ast::SelectStmt;
@@ -31,6 +33,7 @@ use common_exception::Result;
 use common_expression::type_check::common_super_type;
 use common_expression::types::DataType;
 
+use crate::binder::cte::count_cte_references;
 use crate::binder::join::JoinConditions;
 use crate::binder::scalar_common::split_conjunctions;
 use crate::binder::CteInfo;
@@ -92,6 +95,7 @@ impl Binder {
         };
 
         if let Some(expr) = &stmt.selection {
+            from_context.full_text_query = find_full_text_query(expr);
             s_expr = self.bind_where(&from_context, expr, s_expr).await?;
         }
 
@@ -194,7 +198,10 @@ impl Binder {
         bind_context: &BindContext,
         query: &Query,
     ) -> Result<(SExpr, BindContext)> {
+        let mut materialized_ctes = vec![];
         if let Some(with) = &query.with {
+            let enable_materialized_cte = self.ctx.get_settings().get_enable_materialized_cte()?;
+            let reference_counts = count_cte_references(query, &self.name_resolution_ctx);
             for cte in with.ctes.iter() {
                 let table_name = cte.alias.name.name.clone();
                 if bind_context.ctes_map.contains_key(&table_name) {
@@ -202,12 +209,34 @@ impl Binder {
                         "duplicate cte {table_name}"
                     )));
                 }
-                let (s_expr, cte_bind_context) = self.bind_query(bind_context, &cte.query).await?;
+                let is_recursive = with.recursive && self.is_recursive_cte(cte, &table_name);
+                let (s_expr, cte_bind_context) = if is_recursive {
+                    self.bind_recursive_cte(bind_context, cte, &table_name)
+                        .await?
+                } else {
+                    self.bind_query(bind_context, &cte.query).await?
+                };
+                // Materialize the CTE if it's read more than once, instead of computing it for each reference.
+                // A recursive CTE is always materialized, since it's evaluated iteratively.
+                let cte_idx = if is_recursive
+                    || enable_materialized_cte
+                        && reference_counts
+                            .get(&table_name)
+                            .map_or(false, |count| *count > 1)
+                {
+                    Some(self.metadata.write().add_materialized_cte())
+                } else {
+                    None
+                };
                 let cte_info = CteInfo {
                     columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
                     s_expr,
                     bind_context: cte_bind_context.clone(),
+                    cte_idx,
                 };
+                if cte_idx.is_some() {
+                    materialized_ctes.push(cte_info.clone());
+                }
                 bind_context.ctes_map.insert(table_name, cte_info);
             }
         }
@@ -249,6 +278,7 @@ impl Binder {
                 .await?;
         }
 
+        let s_expr = self.bind_materialized_ctes(&materialized_ctes, s_expr);
         Ok((s_expr, bind_context))
     }
 
@@ -546,3 +576,40 @@ impl Binder {
         Ok((new_bind_context, pairs, left_expr, right_expr))
     }
 }
+
+/// Find the `match(column, 'query'[, 'tokenizer'])` predicate among the conjunctions of a
+/// WHERE clause, with the tokenizer of the predicate.
+fn find_full_text_query(expr: &Expr) -> Option<(Expr, String, String)> {
+    match expr {
+        Expr::BinaryOp {
+            op: BinaryOperator::And,
+            left,
+            right,
+            ..
+        } => find_full_text_query(left).or_else(|| find_full_text_query(right)),
+        Expr::FunctionCall { name, args, .. } if name.name.eq_ignore_ascii_case("match") => {
+            match args.as_slice() {
+                [
+                    column,
+                    Expr::Literal {
+                        lit: Literal::String(query),
+                        ..
+                    },
+                ] => Some((column.clone(), query.clone(), "default".to_string())),
+                [
+                    column,
+                    Expr::Literal {
+                        lit: Literal::String(query),
+                        ..
+                    },
+                    Expr::Literal {
+                        lit: Literal::String(tokenizer),
+                        ..
+                    },
+                ] => Some((column.clone(), query.clone(), tokenizer.clone())),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -19,6 +19,7 @@ use std::sync::Arc;
 
 use chrono::TimeZone;
 use chrono::Utc;
+use common_ast::ast::Expr;
 use common_ast::ast::FileLocation;
 use common_ast::ast::Indirection;
 use common_ast::ast::SelectStmt;
@@ -27,6 +28,7 @@ use common_ast::ast::Statement;
 use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
 use common_ast::ast::TimeTravelPoint;
+use common_ast::parser::parse_expr;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
@@ -43,9 +45,11 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::type_check::check_literal;
 use common_expression::types::DataType;
+use common_expression::ComputedExpr;
 use common_expression::ConstantFolder;
 use common_expression::Scalar;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_functions::srfs::BUILTIN_SRFS;
 use common_meta_types::FileFormatOptions;
 use common_meta_types::StageFileCompression;
 use common_meta_types::StageFileFormatType;
@@ -58,15 +62,20 @@ use common_storages_view::view_table::QUERY;
 use crate::binder::copy::parse_stage_location_v2;
 use crate::binder::location::parse_uri_location;
 use crate::binder::scalar::ScalarBinder;
+use crate::binder::wrap_cast_if_needed;
 use crate::binder::Binder;
 use crate::binder::ColumnBinding;
 use crate::binder::CteInfo;
 use crate::binder::Visibility;
 use crate::optimizer::SExpr;
+use crate::planner::semantic::contain_placeholder;
 use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::unsupported_placeholder;
 use crate::planner::semantic::TypeChecker;
 use crate::plans::ConstantExpr;
+use crate::plans::EvalScalar;
 use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Statistics;
 use crate::BindContext;
@@ -133,7 +142,11 @@ impl Binder {
                 };
                 // Check and bind common table expression
                 if let Some(cte_info) = bind_context.ctes_map.get(&table_name) {
-                    return self.bind_cte(bind_context, &table_name, alias, &cte_info);
+                    return if cte_info.cte_idx.is_some() {
+                        self.bind_cte_scan(bind_context, &table_name, alias, &cte_info)
+                    } else {
+                        self.bind_cte(bind_context, &table_name, alias, &cte_info)
+                    };
                 }
                 // Get catalog name
                 let catalog = catalog
@@ -207,6 +220,11 @@ impl Binder {
                             table_meta,
                             table_alias_name,
                         );
+                        if let Some(navigation_point) = navigation_point {
+                            self.metadata
+                                .write()
+                                .set_navigation_point(table_index, navigation_point);
+                        }
 
                         let (s_expr, mut bind_context) = self
                             .bind_base_table(bind_context, database.as_str(), table_index)
@@ -225,6 +243,14 @@ impl Binder {
                 named_params,
                 alias,
             } => {
+                if BUILTIN_SRFS
+                    .contains(&normalize_identifier(name, &self.name_resolution_ctx).name)
+                {
+                    return self
+                        .bind_srf_table(bind_context, name, params, named_params, alias)
+                        .await;
+                }
+
                 let mut scalar_binder = ScalarBinder::new(
                     bind_context,
                     self.ctx.clone(),
@@ -234,44 +260,40 @@ impl Binder {
                 );
                 let mut args = Vec::with_capacity(params.len());
                 for arg in params.iter() {
-                    args.push(scalar_binder.bind(arg).await?.0);
+                    args.push(self.bind_table_arg(&mut scalar_binder, arg).await?);
                 }
 
                 let mut named_args = Vec::with_capacity(named_params.len());
                 for (name, arg) in named_params.iter() {
-                    named_args.push((name.clone(), scalar_binder.bind(arg).await?.0));
+                    let scalar = self.bind_table_arg(&mut scalar_binder, arg).await?;
+                    named_args.push((name.clone(), scalar));
                 }
 
                 let mut args = args
                     .into_iter()
-                    .map(|scalar| match scalar {
-                        ScalarExpr::ConstantExpr(ConstantExpr { value, .. }) => {
-                            Ok(check_literal(&value).0)
-                        }
-                        _ => Err(ErrorCode::Unimplemented(format!(
-                            "Unsupported table argument type: {:?}",
-                            scalar
-                        ))),
+                    .map(|scalar| {
+                        self.fold_table_arg(&scalar).ok_or_else(|| {
+                            ErrorCode::Unimplemented(format!(
+                                "Unsupported table argument type: {:?}",
+                                scalar
+                            ))
+                        })
                     })
                     .collect::<Result<Vec<_>>>()?;
 
                 // Convert named params into Tuple(String, Scalar) and append to `args`.
                 args.reserve(named_args.len());
                 for (name, scalar) in named_args.into_iter() {
-                    match scalar {
-                        ScalarExpr::ConstantExpr(ConstantExpr { value, .. }) => {
-                            args.push(Scalar::Tuple(vec![
-                                Scalar::String(name.into_bytes()),
-                                check_literal(&value).0,
-                            ]))
-                        }
-                        _ => {
-                            return Err(ErrorCode::Unimplemented(format!(
-                                "Unsupported table named argument type: {:?}",
-                                scalar
-                            )));
-                        }
-                    }
+                    let value = self.fold_table_arg(&scalar).ok_or_else(|| {
+                        ErrorCode::Unimplemented(format!(
+                            "Unsupported table named argument type: {:?}",
+                            scalar
+                        ))
+                    })?;
+                    args.push(Scalar::Tuple(vec![
+                        Scalar::String(name.into_bytes()),
+                        value,
+                    ]));
                 }
 
                 let table_args = Some(args);
@@ -413,7 +435,7 @@ impl Binder {
         }
     }
 
-    fn bind_cte(
+    pub(super) fn bind_cte(
         &mut self,
         bind_context: &BindContext,
         table_name: &str,
@@ -507,31 +529,142 @@ impl Binder {
 
         let is_accurate = table.table().engine().to_lowercase() == "fuse";
         let stat = table.table().table_statistics()?;
-        Ok((
-            SExpr::create_leaf(
-                Scan {
-                    table_index,
-                    columns: columns
-                        .into_iter()
-                        .map(|col| match col {
-                            ColumnEntry::BaseTableColumn { column_index, .. } => column_index,
-                            ColumnEntry::DerivedColumn { column_index, .. } => column_index,
-                        })
-                        .collect(),
-                    push_down_predicates: None,
-                    limit: None,
-                    order_by: None,
-                    statistics: Statistics {
-                        statistics: stat,
-                        col_stats,
-                        is_accurate,
-                    },
-                    prewhere: None,
-                }
-                .into(),
-            ),
-            bind_context,
-        ))
+        let scan = SExpr::create_leaf(
+            Scan {
+                table_index,
+                columns: columns
+                    .into_iter()
+                    .map(|col| match col {
+                        ColumnEntry::BaseTableColumn { column_index, .. } => column_index,
+                        ColumnEntry::DerivedColumn { column_index, .. } => column_index,
+                        ColumnEntry::VirtualColumn { column_index, .. } => column_index,
+                    })
+                    .collect(),
+                push_down_predicates: None,
+                limit: None,
+                order_by: None,
+                statistics: Statistics {
+                    statistics: stat,
+                    col_stats,
+                    is_accurate,
+                },
+                prewhere: None,
+            }
+            .into(),
+        );
+        let s_expr = self
+            .bind_computed_columns(&mut bind_context, database_name, table_index, scan)
+            .await?;
+        Ok((s_expr, bind_context))
+    }
+
+    /// Binds the expressions of the computed columns of the table over its stored columns.
+    ///
+    /// The virtual computed columns are evaluated over the scan, and the expressions of the
+    /// stored computed columns are kept in the metadata, so that the optimizer can replace
+    /// the same expressions in the query with the reads of the stored columns.
+    async fn bind_computed_columns(
+        &mut self,
+        bind_context: &mut BindContext,
+        database_name: &str,
+        table_index: IndexType,
+        scan: SExpr,
+    ) -> Result<SExpr> {
+        let table = self.metadata.read().table(table_index).table();
+        let schema = table.schema();
+        if schema.fields().iter().all(|f| f.computed_expr().is_none()) {
+            return Ok(scan);
+        }
+
+        let mut items = vec![];
+        for (index, field) in schema.fields().iter().enumerate() {
+            let (expr, is_stored) = match field.computed_expr() {
+                Some(ComputedExpr::Virtual(expr)) => (expr, false),
+                Some(ComputedExpr::Stored(expr)) => (expr, true),
+                None => continue,
+            };
+            let tokens = tokenize_sql(expr)?;
+            let backtrace = Backtrace::new();
+            let ast = parse_expr(&tokens, Dialect::MySQL, &backtrace)?;
+            let mut scalar_binder = ScalarBinder::new(
+                bind_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let (scalar, _) = scalar_binder.bind(&ast).await?;
+
+            if is_stored {
+                let column = bind_context
+                    .columns
+                    .iter()
+                    .find(|c| c.column_name == *field.name() && c.visibility == Visibility::Visible)
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "stored computed column {} not found",
+                            field.name()
+                        ))
+                    })?;
+                self.metadata
+                    .write()
+                    .add_stored_computed_column(table_index, column.index, scalar);
+                continue;
+            }
+
+            let data_type = DataType::from(field.data_type());
+            let column_index = self
+                .metadata
+                .write()
+                .add_derived_column(field.name().clone(), data_type.clone());
+            items.push(ScalarItem {
+                scalar: wrap_cast_if_needed(&scalar, &data_type),
+                index: column_index,
+            });
+
+            // Keep the columns in the order of the schema, the virtual computed column is put
+            // before the binding of the next stored column.
+            let position = schema.fields()[index + 1..]
+                .iter()
+                .filter(|f| !matches!(f.computed_expr(), Some(ComputedExpr::Virtual(_))))
+                .find_map(|f| {
+                    bind_context
+                        .columns
+                        .iter()
+                        .position(|c| c.column_name == *f.name())
+                })
+                .unwrap_or(bind_context.columns.len());
+            bind_context.columns.insert(position, ColumnBinding {
+                database_name: Some(database_name.to_string()),
+                table_name: Some(table.name().to_string()),
+                column_name: field.name().clone(),
+                index: column_index,
+                data_type: Box::new(data_type),
+                visibility: Visibility::Visible,
+            });
+        }
+
+        if items.is_empty() {
+            return Ok(scan);
+        }
+        Ok(SExpr::create_unary(EvalScalar { items }.into(), scan))
+    }
+
+    /// The mutations of a table only read the stored columns, so the virtual computed columns
+    /// can't be used in them.
+    pub(in crate::planner::binder) fn check_no_virtual_computed_column(
+        &self,
+        scalar: &ScalarExpr,
+    ) -> Result<()> {
+        let metadata = self.metadata.read();
+        for index in scalar.used_columns() {
+            if let ColumnEntry::DerivedColumn { alias, .. } = metadata.column(index) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "virtual computed column {alias} can not be used in the mutation of the table"
+                )));
+            }
+        }
+        Ok(())
     }
 
     async fn resolve_data_source(
@@ -552,6 +685,35 @@ impl Binder {
         Ok(table_meta)
     }
 
+    async fn bind_table_arg(
+        &self,
+        scalar_binder: &mut ScalarBinder<'_>,
+        arg: &Expr,
+    ) -> Result<ScalarExpr> {
+        let (scalar, _) = scalar_binder.bind(arg).await?;
+        if contain_placeholder(&scalar) {
+            return Err(unsupported_placeholder(
+                arg.span(),
+                "table function arguments",
+            ));
+        }
+        Ok(scalar)
+    }
+
+    /// Table arguments must be constants, the expressions such as
+    /// `parse_json('[1, 2]')` are folded into constants.
+    fn fold_table_arg(&self, scalar: &ScalarExpr) -> Option<Scalar> {
+        if let ScalarExpr::ConstantExpr(ConstantExpr { value, .. }) = scalar {
+            return Some(check_literal(value).0);
+        }
+        let expr = scalar.as_expr_with_col_name().ok()?;
+        let func_ctx = self.ctx.get_function_context().ok()?;
+        match ConstantFolder::fold(&expr, func_ctx, &BUILTIN_FUNCTIONS).0 {
+            common_expression::Expr::Constant { scalar, .. } => Some(scalar),
+            _ => None,
+        }
+    }
+
     pub(crate) async fn resolve_data_travel_point(
         &self,
         bind_context: &BindContext,
@@ -568,6 +730,9 @@ impl Binder {
                     &[],
                 );
                 let box (scalar, _) = type_checker.resolve(expr, None).await?;
+                if contain_placeholder(&scalar) {
+                    return Err(unsupported_placeholder(expr.span(), "time travel points"));
+                }
                 let scalar_expr = scalar.as_expr_with_col_name()?;
 
                 let (new_expr, _) = ConstantFolder::fold(
@@ -591,6 +756,12 @@ impl Binder {
                     )),
                 }
             }
+            TimeTravelPoint::Tag(tag) => Ok(NavigationPoint::Tag(
+                normalize_identifier(tag, &self.name_resolution_ctx).name,
+            )),
+            TimeTravelPoint::Branch(branch) => Ok(NavigationPoint::Branch(
+                normalize_identifier(branch, &self.name_resolution_ctx).name,
+            )),
         }
     }
 }
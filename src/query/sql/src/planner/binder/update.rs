@@ -76,10 +76,20 @@ impl Binder {
             self.metadata.clone(),
             &[],
         );
-        let schema = table.schema();
+        // The stored computed columns are computed again by the table from the updated columns.
+        let schema = table.schema().remove_virtual_computed_fields();
         let mut update_columns = HashMap::with_capacity(update_list.len());
         for update_expr in update_list {
             let col_name = normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name;
+            if let Ok(field) = table.schema().field_with_name(&col_name) {
+                if field.computed_expr().is_some() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "can not update computed column {}",
+                        col_name
+                    ))
+                    .set_span(update_expr.name.span));
+                }
+            }
             let index = schema.index_of(&col_name)?;
             if update_columns.contains_key(&index) {
                 return Err(ErrorCode::BadArguments(format!(
@@ -95,6 +105,7 @@ impl Binder {
                     "Update does not support subquery temporarily",
                 ));
             }
+            self.check_no_virtual_computed_column(&scalar)?;
             update_columns.insert(index, scalar);
         }
 
@@ -105,6 +116,7 @@ impl Binder {
                     "Update does not support subquery temporarily",
                 ));
             }
+            self.check_no_virtual_computed_column(&scalar)?;
             Some(scalar)
         } else {
             None
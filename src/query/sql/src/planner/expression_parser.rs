@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_ast::parser::parse_comma_separated_exprs;
+use common_ast::parser::parse_expr;
 use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
 use common_ast::Dialect;
@@ -26,6 +27,7 @@ use common_catalog::table_context::TableContext;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataSchemaRef;
 use common_expression::Expr;
 use common_expression::RemoteExpr;
 use common_settings::Settings;
@@ -117,7 +119,7 @@ pub fn parse_to_remote_string_exprs(
     unwrap_tuple: bool,
     sql: &str,
 ) -> Result<Vec<RemoteExpr<String>>> {
-    let schema = table_meta.schema();
+    let schema = table_meta.schema().remove_virtual_computed_fields();
     let exprs = parse_exprs(ctx, table_meta, unwrap_tuple, sql)?;
     let exprs = exprs
         .iter()
@@ -129,3 +131,40 @@ pub fn parse_to_remote_string_exprs(
 
     Ok(exprs)
 }
+
+/// Parses the expression of a computed column, the column references of the returned
+/// expression are the positions of the fields in `schema`.
+pub fn parse_computed_expr(
+    ctx: Arc<dyn TableContext>,
+    schema: DataSchemaRef,
+    sql: &str,
+) -> Result<Expr> {
+    let settings = Settings::default_settings("", GlobalConfig::instance())?;
+    let mut bind_context = BindContext::new();
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+
+    // Tuple columns are bound as a whole, so the indices of the columns are the positions
+    // of the fields rather than the indices of the leaf columns.
+    for (index, field) in schema.fields().iter().enumerate() {
+        bind_context.add_column_binding(ColumnBinding {
+            database_name: None,
+            table_name: None,
+            column_name: field.name().clone(),
+            index,
+            data_type: Box::new(field.data_type().clone()),
+            visibility: Visibility::Visible,
+        });
+    }
+
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let mut type_checker =
+        TypeChecker::new(&bind_context, ctx, &name_resolution_ctx, metadata, &[]);
+
+    let sql_dialect = Dialect::MySQL;
+    let tokens = tokenize_sql(sql)?;
+    let backtrace = Backtrace::new();
+    let ast = parse_expr(&tokens, sql_dialect, &backtrace)?;
+    let (scalar, _) =
+        *block_in_place(|| Handle::current().block_on(type_checker.resolve(&ast, None)))?;
+    scalar.as_expr_with_col_index()
+}
@@ -66,12 +66,28 @@ impl Plan {
             Plan::TruncateTable(truncate_table) => Ok(format!("{:?}", truncate_table)),
             Plan::OptimizeTable(optimize_table) => Ok(format!("{:?}", optimize_table)),
             Plan::AnalyzeTable(analyze_table) => Ok(format!("{:?}", analyze_table)),
+            Plan::VacuumTable(vacuum_table) => Ok(format!("{:?}", vacuum_table)),
+            Plan::VacuumDropTable(vacuum_drop_table) => Ok(format!("{:?}", vacuum_drop_table)),
             Plan::ExistsTable(exists_table) => Ok(format!("{:?}", exists_table)),
 
             // Views
             Plan::CreateView(create_view) => Ok(format!("{:?}", create_view)),
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
+            Plan::CreateMaterializedView(create_view) => Ok(format!("{:?}", create_view)),
+            Plan::RefreshMaterializedView(refresh_view) => Ok(format!("{:?}", refresh_view)),
+
+            // Indexes
+            Plan::CreateInvertedIndex(create_index) => Ok(format!("{:?}", create_index)),
+            Plan::DropInvertedIndex(drop_index) => Ok(format!("{:?}", drop_index)),
+            Plan::CreateVirtualColumn(create_virtual_column) => {
+                Ok(format!("{:?}", create_virtual_column))
+            }
+            Plan::DropVirtualColumn(drop_virtual_column) => {
+                Ok(format!("{:?}", drop_virtual_column))
+            }
+            Plan::CreateStream(create_stream) => Ok(format!("{:?}", create_stream)),
+            Plan::DropStream(drop_stream) => Ok(format!("{:?}", drop_stream)),
 
             // Insert
             Plan::Insert(insert) => Ok(format!("{:?}", insert)),
@@ -118,6 +134,9 @@ impl Plan {
             Plan::ShowObjectGrantPrivileges(p) => Ok(format!("{:?}", p)),
             Plan::ShowGrantTenantsOfShare(p) => Ok(format!("{:?}", p)),
             Plan::RevertTable(p) => Ok(format!("{:?}", p)),
+            Plan::CreateSnapshotRef(p) => Ok(format!("{:?}", p)),
+            Plan::DropSnapshotRef(p) => Ok(format!("{:?}", p)),
+            Plan::FastForwardTable(p) => Ok(format!("{:?}", p)),
         }
     }
 }
@@ -76,6 +76,10 @@ impl Display for FormatContext {
                 RelOperator::UnionAll(_) => write!(f, "Union"),
                 RelOperator::Pattern(_) => write!(f, "Pattern"),
                 RelOperator::DummyTableScan(_) => write!(f, "DummyTableScan"),
+                RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
+                RelOperator::CteScan(_) => write!(f, "CteScan"),
+                RelOperator::MaterializedCte(_) => write!(f, "MaterializedCte"),
+                RelOperator::RecursiveCte(_) => write!(f, "RecursiveCte"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -135,6 +139,7 @@ pub fn format_scalar(_metadata: &MetadataRef, scalar: &ScalarExpr) -> String {
             )
         }
         ScalarExpr::SubqueryExpr(_) => "SUBQUERY".to_string(),
+        ScalarExpr::PlaceholderExpr(placeholder) => format!("${}", placeholder.index),
     }
 }
 
@@ -255,6 +260,7 @@ fn scan_to_format_tree(
                                 "{} (#{}) {}",
                                 match metadata.read().column(item.index) {
                                     ColumnEntry::BaseTableColumn { column_name, .. } => column_name,
+                                    ColumnEntry::VirtualColumn { column_name, .. } => column_name,
                                     ColumnEntry::DerivedColumn { alias, .. } => alias,
                                 },
                                 item.index,
@@ -316,6 +322,7 @@ fn logical_get_to_format_tree(
                                 "{} (#{}) {}",
                                 match metadata.read().column(item.index) {
                                     ColumnEntry::BaseTableColumn { column_name, .. } => column_name,
+                                    ColumnEntry::VirtualColumn { column_name, .. } => column_name,
                                     ColumnEntry::DerivedColumn { alias, .. } => alias,
                                 },
                                 item.index,
@@ -547,6 +554,7 @@ fn sort_to_format_tree(
             let metadata = metadata.read();
             let name = match metadata.column(item.index) {
                 ColumnEntry::BaseTableColumn { column_name, .. } => column_name,
+                ColumnEntry::VirtualColumn { column_name, .. } => column_name,
                 ColumnEntry::DerivedColumn { alias, .. } => alias,
             };
             format!(
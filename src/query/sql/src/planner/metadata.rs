@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::fmt::Debug;
@@ -20,12 +21,17 @@ use std::sync::Arc;
 
 use common_ast::ast::Expr;
 use common_ast::ast::Literal;
+use common_catalog::plan::VirtualColumnInfo;
+use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
 use common_expression::types::DataType;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use parking_lot::RwLock;
 
+use crate::plans::MaterializedViewDefinition;
+use crate::plans::ScalarExpr;
+
 /// Planner use [`usize`] as it's index type.
 ///
 /// This type will be used across the whole planner.
@@ -56,6 +62,22 @@ pub type MetadataRef = Arc<RwLock<Metadata>>;
 pub struct Metadata {
     tables: Vec<TableEntry>,
     columns: Vec<ColumnEntry>,
+    /// Number of the materialized CTEs, used to allocate their indexes.
+    materialized_ctes: usize,
+    /// Fresh materialized views of the base tables, keyed by the index of the base table.
+    materialized_views: HashMap<IndexType, Vec<MaterializedViewEntry>>,
+    /// Whether the query calls non-deterministic or time-dependent functions,
+    /// whose result can't be reused by another query.
+    non_deterministic: bool,
+    /// Types of the parameters bound to the placeholders of a prepared statement.
+    parameter_types: Vec<DataType>,
+    /// Whether the types of the parameters not bound are inferred from the placeholders,
+    /// which describes a prepared statement before its parameters are bound.
+    infer_parameter_types: bool,
+    /// Types of the parameters inferred, keyed by the indexes of the placeholders.
+    inferred_parameter_types: HashMap<usize, DataType>,
+    /// Bound expressions of the stored computed columns, keyed by the index of the table.
+    stored_computed_columns: HashMap<IndexType, Vec<(IndexType, ScalarExpr)>>,
 }
 
 impl Metadata {
@@ -117,6 +139,40 @@ impl Metadata {
         column_index
     }
 
+    pub fn add_virtual_column(
+        &mut self,
+        table_index: IndexType,
+        info: VirtualColumnInfo,
+    ) -> IndexType {
+        let column_index = self.columns.len();
+        let column_entry = ColumnEntry::VirtualColumn {
+            table_index,
+            column_index,
+            column_name: info.name,
+            source_column_name: info.source_column_name,
+            path: info.path,
+            cast_type: info.cast_type,
+            data_type: info.data_type,
+        };
+        self.columns.push(column_entry);
+        column_index
+    }
+
+    /// Virtual columns of the table with index `table_index`.
+    pub fn virtual_columns_by_table_index(&self, index: IndexType) -> Vec<ColumnEntry> {
+        self.columns
+            .iter()
+            .filter(|v| matches!(v, ColumnEntry::VirtualColumn { table_index, .. } if index == *table_index))
+            .cloned()
+            .collect()
+    }
+
+    pub fn has_virtual_columns(&self) -> bool {
+        self.columns
+            .iter()
+            .any(|v| matches!(v, ColumnEntry::VirtualColumn { .. }))
+    }
+
     pub fn add_derived_column(&mut self, alias: String, data_type: DataType) -> IndexType {
         let column_index = self.columns.len();
         let column_entry = ColumnEntry::DerivedColumn {
@@ -128,6 +184,118 @@ impl Metadata {
         column_index
     }
 
+    pub fn add_stored_computed_column(
+        &mut self,
+        table_index: IndexType,
+        column_index: IndexType,
+        expr: ScalarExpr,
+    ) {
+        self.stored_computed_columns
+            .entry(table_index)
+            .or_default()
+            .push((column_index, expr));
+    }
+
+    /// Stored computed columns of the table with index `table_index`, with the expressions
+    /// computing them.
+    pub fn stored_computed_columns(&self, table_index: IndexType) -> &[(IndexType, ScalarExpr)] {
+        self.stored_computed_columns
+            .get(&table_index)
+            .map_or(&[], |columns| columns.as_slice())
+    }
+
+    pub fn has_stored_computed_columns(&self) -> bool {
+        !self.stored_computed_columns.is_empty()
+    }
+
+    pub fn add_materialized_cte(&mut self) -> IndexType {
+        let cte_idx = self.materialized_ctes;
+        self.materialized_ctes += 1;
+        cte_idx
+    }
+
+    pub fn add_materialized_view(&mut self, table_index: IndexType, view: MaterializedViewEntry) {
+        self.materialized_views
+            .entry(table_index)
+            .or_default()
+            .push(view);
+    }
+
+    pub fn materialized_views(&self, table_index: IndexType) -> &[MaterializedViewEntry] {
+        self.materialized_views
+            .get(&table_index)
+            .map_or(&[], |views| views.as_slice())
+    }
+
+    pub fn has_materialized_views(&self) -> bool {
+        !self.materialized_views.is_empty()
+    }
+
+    pub fn set_non_deterministic(&mut self) {
+        self.non_deterministic = true;
+    }
+
+    pub fn is_non_deterministic(&self) -> bool {
+        self.non_deterministic
+    }
+
+    pub fn set_parameter_types(&mut self, parameter_types: Vec<DataType>) {
+        self.parameter_types = parameter_types;
+    }
+
+    /// Get the type of the parameter bound to placeholder `$index`, which is numbered from 1.
+    pub fn parameter_type(&self, index: usize) -> Option<&DataType> {
+        index
+            .checked_sub(1)
+            .and_then(|index| self.parameter_types.get(index))
+    }
+
+    pub fn set_infer_parameter_types(&mut self) {
+        self.infer_parameter_types = true;
+    }
+
+    /// Get the type of the parameter of placeholder `$index`. If it's not bound, the type is
+    /// inferred from the type required by the expression around the placeholder, or `String`.
+    pub fn infer_parameter_type(
+        &mut self,
+        index: usize,
+        required_type: Option<DataType>,
+    ) -> Option<DataType> {
+        if let Some(data_type) = self.parameter_type(index) {
+            return Some(data_type.clone());
+        }
+        if !self.infer_parameter_types || index == 0 {
+            return None;
+        }
+        let data_type = self
+            .inferred_parameter_types
+            .entry(index)
+            .or_insert_with(|| required_type.unwrap_or(DataType::String));
+        Some(data_type.clone())
+    }
+
+    /// The types of the parameters inferred, the ones of the placeholders not bound are `String`.
+    pub fn inferred_parameter_types(&self, count: usize) -> Vec<DataType> {
+        (1..=count)
+            .map(|index| {
+                self.inferred_parameter_types
+                    .get(&index)
+                    .cloned()
+                    .unwrap_or(DataType::String)
+            })
+            .collect()
+    }
+
+    /// Replace the table of an entry with a newer version of it, used by the
+    /// re-execution of a cached plan.
+    pub fn replace_table(&mut self, index: IndexType, table: Arc<dyn Table>) {
+        self.tables[index].table = table;
+    }
+
+    pub fn set_navigation_point(&mut self, index: IndexType, navigation_point: NavigationPoint) {
+        self.tables[index].navigation_point = Some(navigation_point);
+    }
+
     pub fn add_table(
         &mut self,
         catalog: String,
@@ -145,10 +313,14 @@ impl Metadata {
             catalog,
             table: table_meta.clone(),
             alias_name: table_alias_name,
+            navigation_point: None,
         };
         self.tables.push(table_entry);
         let mut fields = VecDeque::new();
-        for (i, field) in table_meta.schema().fields().iter().enumerate() {
+        // The virtual computed columns are not stored, they are bound as derived columns
+        // evaluated over the scan of the table.
+        let schema = table_meta.schema().remove_virtual_computed_fields();
+        for (i, field) in schema.fields().iter().enumerate() {
             fields.push_back((vec![i], field.clone()));
         }
 
@@ -198,6 +370,10 @@ impl Metadata {
                 leaf_index += 1;
             }
         }
+
+        for info in table_meta.virtual_columns() {
+            self.add_virtual_column(table_index, info);
+        }
         table_index
     }
 }
@@ -209,6 +385,8 @@ pub struct TableEntry {
     name: String,
     alias_name: Option<String>,
     index: IndexType,
+    /// The point the table is navigated to, e.g. `t AT (SNAPSHOT => '...')`.
+    navigation_point: Option<NavigationPoint>,
 
     table: Arc<dyn Table>,
 }
@@ -240,6 +418,7 @@ impl TableEntry {
             database,
             table,
             alias_name,
+            navigation_point: None,
         }
     }
 
@@ -272,6 +451,20 @@ impl TableEntry {
     pub fn table(&self) -> Arc<dyn Table> {
         self.table.clone()
     }
+
+    /// Get the point the table of this table entry is navigated to.
+    pub fn navigation_point(&self) -> Option<&NavigationPoint> {
+        self.navigation_point.as_ref()
+    }
+}
+
+/// A materialized view whose rows are fresh with respect to the snapshot of its base table
+/// read by the query, so that the aggregations over the base table can be answered from it.
+#[derive(Clone, Debug)]
+pub struct MaterializedViewEntry {
+    /// Index of the table storing the rows of the view.
+    pub table_index: IndexType,
+    pub definition: MaterializedViewDefinition,
 }
 
 #[derive(Clone, Debug)]
@@ -296,6 +489,19 @@ pub enum ColumnEntry {
         alias: String,
         data_type: DataType,
     },
+
+    /// Column of the value at a path of a variant column of base table, which can be
+    /// read without the whole variant column, for example `SELECT t.v:a.b FROM t`.
+    VirtualColumn {
+        table_index: IndexType,
+        column_index: IndexType,
+        column_name: String,
+        source_column_name: String,
+        path: String,
+        /// The type that the value is cast to, `Variant` if it is not cast.
+        cast_type: TableDataType,
+        data_type: TableDataType,
+    },
 }
 
 impl ColumnEntry {
@@ -303,6 +509,7 @@ impl ColumnEntry {
         match self {
             ColumnEntry::BaseTableColumn { column_index, .. } => *column_index,
             ColumnEntry::DerivedColumn { column_index, .. } => *column_index,
+            ColumnEntry::VirtualColumn { column_index, .. } => *column_index,
         }
     }
 }
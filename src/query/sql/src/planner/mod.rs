@@ -30,12 +30,16 @@ pub use binder::ColumnBinding;
 pub use binder::ScalarBinder;
 pub use binder::SelectBuilder;
 pub use binder::Visibility;
+pub use expression_parser::parse_computed_expr;
 pub use expression_parser::parse_exprs;
 pub use expression_parser::parse_to_remote_string_exprs;
 pub use metadata::*;
 pub use planner::Planner;
 pub use plans::ScalarExpr;
+pub use semantic::bind_parameters;
 pub use semantic::normalize_identifier;
+pub use semantic::parameter_count;
+pub use semantic::substitute_parameters;
 pub use semantic::validate_function_arg;
 pub use semantic::IdentifierNormalizer;
 pub use semantic::NameResolutionContext;
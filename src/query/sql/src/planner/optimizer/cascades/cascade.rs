@@ -28,6 +28,7 @@ use crate::optimizer::cost::CostModel;
 use crate::optimizer::cost::DefaultCostModel;
 use crate::optimizer::format::display_memo;
 use crate::optimizer::memo::Memo;
+use crate::optimizer::rule::RuleID;
 use crate::optimizer::rule::RuleSet;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::SExpr;
@@ -43,22 +44,30 @@ pub struct CascadesOptimizer {
 
     /// group index -> best cost context
     pub best_cost_map: HashMap<IndexType, CostContext>,
+    /// Whether the joins have been reordered by `DpJoinReorder`.
+    join_reordered: bool,
     _ctx: Arc<dyn TableContext>,
 }
 
 impl CascadesOptimizer {
-    pub fn create(ctx: Arc<dyn TableContext>) -> Result<Self> {
-        let explore_rules = if ctx.get_settings().get_enable_cbo()? {
+    /// If the joins have been reordered (see `DpJoinReorder`), the join orders
+    /// are not explored again, only the sides of the joins are commuted, so the
+    /// outer, semi and anti joins can still build the hash table on the smaller side.
+    pub fn create(ctx: Arc<dyn TableContext>, join_reordered: bool) -> Result<Self> {
+        let explore_rules = if !ctx.get_settings().get_enable_cbo()? {
+            RuleSet::create_with_ids(vec![]).unwrap()
+        } else if join_reordered {
+            RuleSet::create_with_ids(vec![RuleID::CommuteJoin]).unwrap()
+        } else {
             let enable_bushy_join = ctx.get_settings().get_enable_bushy_join()? != 0;
             get_explore_rule_set(enable_bushy_join)
-        } else {
-            RuleSet::create_with_ids(vec![]).unwrap()
         };
         Ok(CascadesOptimizer {
             memo: Memo::create(),
             explore_rules,
             cost_model: Box::new(DefaultCostModel),
             best_cost_map: HashMap::new(),
+            join_reordered,
             _ctx: ctx,
         })
     }
@@ -124,7 +133,14 @@ impl CascadesOptimizer {
             .map(|index| self.find_optimal_plan(*index))
             .collect::<Result<Vec<_>>>()?;
 
-        let result = SExpr::create(m_expr.plan.clone(), children, None, None);
+        // The cardinalities of the reordered joins are estimated by `DpJoinReorder`, keep
+        // them in the physical plan instead of deriving them from the join conditions again.
+        let rel_prop = if self.join_reordered {
+            Some(Box::new(group.relational_prop.clone()))
+        } else {
+            None
+        };
+        let result = SExpr::create(m_expr.plan.clone(), children, None, rel_prop);
 
         Ok(result)
     }
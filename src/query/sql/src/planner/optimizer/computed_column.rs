@@ -0,0 +1,80 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::DataType;
+
+use crate::binder::ColumnBinding;
+use crate::binder::Visibility;
+use crate::optimizer::scan_column_rewriter::ScanColumnRewriter;
+use crate::optimizer::SExpr;
+use crate::plans::ScalarExpr;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MetadataRef;
+
+/// Rewrite the expressions computing the stored computed columns of a table, like `a + 1`
+/// for the column `b BIGINT AS (a + 1) STORED`, into reads of the stored columns, so that
+/// the statistics and indexes of the stored columns can be used to prune the blocks.
+pub fn rewrite_with_computed_columns(metadata: MetadataRef, s_expr: SExpr) -> Result<SExpr> {
+    if !metadata.read().has_stored_computed_columns() {
+        return Ok(s_expr);
+    }
+    ScanColumnRewriter::new(|scalar: &ScalarExpr, table_index| {
+        match_computed_column(&metadata, scalar, table_index)
+    })
+    .rewrite(&s_expr)
+}
+
+/// Match the expression with the expression of a stored computed column of the scanned
+/// table, which must also be of the same type as the column.
+fn match_computed_column(
+    metadata: &MetadataRef,
+    scalar: &ScalarExpr,
+    table_index: IndexType,
+) -> Option<ColumnBinding> {
+    if matches!(
+        scalar,
+        ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_)
+    ) {
+        return None;
+    }
+
+    let metadata = metadata.read();
+    let column_index = metadata
+        .stored_computed_columns(table_index)
+        .iter()
+        .find(|(_, expr)| expr == scalar)
+        .map(|(column_index, _)| *column_index)?;
+    let (column_name, data_type) = match metadata.column(column_index) {
+        ColumnEntry::BaseTableColumn {
+            column_name,
+            data_type,
+            ..
+        } => (column_name.clone(), DataType::from(data_type)),
+        _ => return None,
+    };
+    if data_type != scalar.data_type() {
+        return None;
+    }
+
+    Some(ColumnBinding {
+        database_name: None,
+        table_name: None,
+        column_name,
+        index: column_index,
+        data_type: Box::new(data_type),
+        visibility: Visibility::InVisible,
+    })
+}
@@ -24,8 +24,8 @@ use crate::plans::JoinType;
 use crate::plans::RelOperator;
 use crate::plans::Scan;
 
-static COST_FACTOR_COMPUTE_PER_ROW: f64 = 1.0;
-static COST_FACTOR_HASH_TABLE_PER_ROW: f64 = 10.0;
+pub static COST_FACTOR_COMPUTE_PER_ROW: f64 = 1.0;
+pub static COST_FACTOR_HASH_TABLE_PER_ROW: f64 = 10.0;
 
 #[derive(Default)]
 pub struct DefaultCostModel;
@@ -39,7 +39,10 @@ impl CostModel for DefaultCostModel {
 fn compute_cost_impl(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
     match &m_expr.plan {
         RelOperator::Scan(plan) => compute_cost_scan(memo, m_expr, plan),
-        RelOperator::DummyTableScan(_) => Ok(Cost(0.0)),
+        RelOperator::DummyTableScan(_)
+        | RelOperator::MaterializedCte(_)
+        | RelOperator::RecursiveCte(_) => Ok(Cost(0.0)),
+        RelOperator::CteScan(_) => compute_cost_cte_scan(memo, m_expr),
         RelOperator::Join(plan) => compute_cost_join(memo, m_expr, plan),
         RelOperator::UnionAll(_) => compute_cost_union_all(memo, m_expr),
 
@@ -47,7 +50,8 @@ fn compute_cost_impl(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
         | RelOperator::Filter(_)
         | RelOperator::Aggregate(_)
         | RelOperator::Sort(_)
-        | RelOperator::Limit(_) => compute_cost_unary_common_operator(memo, m_expr),
+        | RelOperator::Limit(_)
+        | RelOperator::ProjectSet(_) => compute_cost_unary_common_operator(memo, m_expr),
 
         _ => Err(ErrorCode::Internal("Cannot compute cost from logical plan")),
     }
@@ -62,6 +66,13 @@ fn compute_cost_scan(memo: &Memo, m_expr: &MExpr, _plan: &Scan) -> Result<Cost>
     Ok(Cost(cost))
 }
 
+fn compute_cost_cte_scan(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
+    // The CTE is computed by `MaterializedCte`, scanning it is cheap in-memory reading.
+    let group = memo.group(m_expr.group_index)?;
+    let cost = group.relational_prop.cardinality * COST_FACTOR_COMPUTE_PER_ROW;
+    Ok(Cost(cost))
+}
+
 fn compute_cost_join(memo: &Memo, m_expr: &MExpr, plan: &Join) -> Result<Cost> {
     let build_group = m_expr.child_group(memo, 1)?;
     let probe_group = m_expr.child_group(memo, 0)?;
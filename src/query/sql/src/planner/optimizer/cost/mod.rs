@@ -20,3 +20,5 @@ pub use cost::Cost;
 pub use cost::CostContext;
 pub use cost::CostModel;
 pub use cost_model::DefaultCostModel;
+pub use cost_model::COST_FACTOR_COMPUTE_PER_ROW;
+pub use cost_model::COST_FACTOR_HASH_TABLE_PER_ROW;
@@ -45,6 +45,7 @@ use crate::plans::JoinType;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::PatternPlan;
+use crate::plans::ProjectSet;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
@@ -385,6 +386,11 @@ impl SubqueryRewriter {
                         column_name,
                         data_type,
                         ..
+                    }
+                    | ColumnEntry::VirtualColumn {
+                        column_name,
+                        data_type,
+                        ..
                     } => (column_name, DataType::from(data_type)),
                     ColumnEntry::DerivedColumn {
                         alias, data_type, ..
@@ -451,7 +457,8 @@ impl SubqueryRewriter {
                 for derived_column in self.derived_columns.values() {
                     let column_entry = metadata.column(*derived_column);
                     let data_type = match column_entry {
-                        ColumnEntry::BaseTableColumn { data_type, .. } => DataType::from(data_type),
+                        ColumnEntry::BaseTableColumn { data_type, .. }
+                        | ColumnEntry::VirtualColumn { data_type, .. } => DataType::from(data_type),
                         ColumnEntry::DerivedColumn { data_type, .. } => data_type.clone(),
                     };
                     let column_binding = ColumnBinding {
@@ -561,7 +568,8 @@ impl SubqueryRewriter {
                         let metadata = self.metadata.read();
                         let column_entry = metadata.column(*derived_column);
                         let data_type = match column_entry {
-                            ColumnEntry::BaseTableColumn { data_type, .. } => {
+                            ColumnEntry::BaseTableColumn { data_type, .. }
+                            | ColumnEntry::VirtualColumn { data_type, .. } => {
                                 DataType::from(data_type)
                             }
                             ColumnEntry::DerivedColumn { data_type, .. } => data_type.clone(),
@@ -632,6 +640,34 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(plan.plan().clone(), flatten_plan))
             }
 
+            RelOperator::ProjectSet(project_set) => {
+                if project_set
+                    .used_columns()?
+                    .iter()
+                    .any(|index| correlated_columns.contains(index))
+                {
+                    need_cross_join = true;
+                }
+                let flatten_plan = self.flatten(
+                    plan.child(0)?,
+                    correlated_columns,
+                    flatten_info,
+                    need_cross_join,
+                )?;
+                let mut args = Vec::with_capacity(project_set.args.len());
+                for arg in project_set.args.iter() {
+                    args.push(self.flatten_scalar(arg, correlated_columns)?);
+                }
+                Ok(SExpr::create_unary(
+                    ProjectSet {
+                        args,
+                        ..project_set.clone()
+                    }
+                    .into(),
+                    flatten_plan,
+                ))
+            }
+
             RelOperator::UnionAll(op) => {
                 if op
                     .used_columns()?
@@ -688,7 +724,7 @@ impl SubqueryRewriter {
                 }
                 Ok(scalar.clone())
             }
-            ScalarExpr::ConstantExpr(_) => Ok(scalar.clone()),
+            ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => Ok(scalar.clone()),
             ScalarExpr::AndExpr(and_expr) => {
                 let left = self.flatten_scalar(&and_expr.left, correlated_columns)?;
                 let right = self.flatten_scalar(&and_expr.right, correlated_columns)?;
@@ -775,7 +811,8 @@ impl SubqueryRewriter {
             let metadata = self.metadata.read();
             let column_entry = metadata.column(*correlated_column);
             let data_type = match column_entry {
-                ColumnEntry::BaseTableColumn { data_type, .. } => DataType::from(data_type),
+                ColumnEntry::BaseTableColumn { data_type, .. }
+                | ColumnEntry::VirtualColumn { data_type, .. } => DataType::from(data_type),
                 ColumnEntry::DerivedColumn { data_type, .. } => data_type.clone(),
             };
             let right_column = ScalarExpr::BoundColumnRef(BoundColumnRef {
@@ -19,10 +19,12 @@ use common_exception::Result;
 use once_cell::sync::Lazy;
 
 use super::prune_unused_columns::UnusedColumnPruner;
+use crate::optimizer::computed_column::rewrite_with_computed_columns;
 use crate::optimizer::heuristic::decorrelate::decorrelate_subquery;
 use crate::optimizer::heuristic::prewhere_optimization::PrewhereOptimizer;
 use crate::optimizer::heuristic::RuleList;
 use crate::optimizer::rule::TransformResult;
+use crate::optimizer::virtual_column::rewrite_with_virtual_columns;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -45,9 +47,12 @@ pub static DEFAULT_REWRITE_RULES: Lazy<Vec<RuleID>> = Lazy::new(|| {
         RuleID::PushDownLimitOuterJoin,
         RuleID::PushDownLimitScan,
         RuleID::PushDownFilterEvalScalar,
+        RuleID::InferFilter,
         RuleID::PushDownFilterJoin,
         RuleID::FoldCountAggregate,
+        RuleID::EagerAggregation,
         RuleID::SplitAggregate,
+        RuleID::EliminateCommonSubexpression,
         RuleID::PushDownFilterScan,
         RuleID::PushDownSortScan,
     ]
@@ -85,6 +90,11 @@ impl HeuristicOptimizer {
     }
 
     fn post_optimize(&mut self, s_expr: SExpr) -> Result<SExpr> {
+        // The filters have been pushed down to the scans, so the expressions over the scans
+        // can be rewritten into reads of the stored computed columns and virtual columns.
+        let s_expr = rewrite_with_computed_columns(self.metadata.clone(), s_expr)?;
+        let s_expr = rewrite_with_virtual_columns(self.metadata.clone(), s_expr)?;
+
         let prewhere_optimizer = PrewhereOptimizer::new(self.metadata.clone());
         let s_expr = prewhere_optimizer.prewhere_optimize(s_expr)?;
 
@@ -214,7 +214,44 @@ impl UnusedColumnPruner {
                 ))
             }
 
-            RelOperator::DummyTableScan(_) => Ok(expr.clone()),
+            RelOperator::ProjectSet(p) => {
+                // The set-returning function is always evaluated even if the generated column
+                // is not required, because it decides the number of output rows.
+                for arg in p.args.iter() {
+                    required.extend(arg.used_columns());
+                }
+                Ok(SExpr::create_unary(
+                    RelOperator::ProjectSet(p.clone()),
+                    Self::keep_required_columns(expr.child(0)?, required)?,
+                ))
+            }
+
+            RelOperator::MaterializedCte(p) => {
+                // All the columns of the CTE are kept, they are read by the `CteScan`s by position.
+                Ok(SExpr::create_binary(
+                    RelOperator::MaterializedCte(p.clone()),
+                    Self::keep_required_columns(expr.child(0)?, p.used_columns()?)?,
+                    Self::keep_required_columns(expr.child(1)?, required)?,
+                ))
+            }
+
+            RelOperator::RecursiveCte(p) => {
+                // Both terms produce all the columns of the CTE, which are fed back to the
+                // recursive term by position.
+                Ok(SExpr::create_binary(
+                    RelOperator::RecursiveCte(p.clone()),
+                    Self::keep_required_columns(
+                        expr.child(0)?,
+                        p.left_output_columns.iter().cloned().collect(),
+                    )?,
+                    Self::keep_required_columns(
+                        expr.child(1)?,
+                        p.right_output_columns.iter().cloned().collect(),
+                    )?,
+                ))
+            }
+
+            RelOperator::DummyTableScan(_) | RelOperator::CteScan(_) => Ok(expr.clone()),
 
             _ => Err(ErrorCode::Internal(
                 "Attempting to prune columns of a physical plan is not allowed",
@@ -118,7 +118,22 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(plan.into(), input))
             }
 
-            RelOperator::Join(_) | RelOperator::UnionAll(_) => Ok(SExpr::create_binary(
+            RelOperator::ProjectSet(mut plan) => {
+                let mut input = self.rewrite(s_expr.child(0)?)?;
+
+                for arg in plan.args.iter_mut() {
+                    let res = self.try_rewrite_subquery(arg, &input, false)?;
+                    input = res.1;
+                    *arg = res.0;
+                }
+
+                Ok(SExpr::create_unary(plan.into(), input))
+            }
+
+            RelOperator::Join(_)
+            | RelOperator::UnionAll(_)
+            | RelOperator::MaterializedCte(_)
+            | RelOperator::RecursiveCte(_) => Ok(SExpr::create_binary(
                 s_expr.plan().clone(),
                 self.rewrite(s_expr.child(0)?)?,
                 self.rewrite(s_expr.child(1)?)?,
@@ -129,7 +144,9 @@ impl SubqueryRewriter {
                 self.rewrite(s_expr.child(0)?)?,
             )),
 
-            RelOperator::DummyTableScan(_) | RelOperator::Scan(_) => Ok(s_expr.clone()),
+            RelOperator::DummyTableScan(_) | RelOperator::Scan(_) | RelOperator::CteScan(_) => {
+                Ok(s_expr.clone())
+            }
 
             _ => Err(ErrorCode::Internal("Invalid plan type")),
         }
@@ -146,7 +163,9 @@ impl SubqueryRewriter {
         match scalar {
             ScalarExpr::BoundColumnRef(_) => Ok((scalar.clone(), s_expr.clone())),
 
-            ScalarExpr::ConstantExpr(_) => Ok((scalar.clone(), s_expr.clone())),
+            ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => {
+                Ok((scalar.clone(), s_expr.clone()))
+            }
 
             ScalarExpr::AndExpr(expr) => {
                 // Notice that the conjunctions has been flattened in binder, if we encounter
@@ -531,7 +550,9 @@ pub fn check_child_expr_in_subquery(
 ) -> Result<(ScalarExpr, bool)> {
     match child_expr {
         ScalarExpr::BoundColumnRef(_) => Ok((child_expr.clone(), op != &ComparisonOp::Equal)),
-        ScalarExpr::ConstantExpr(_) => Ok((child_expr.clone(), true)),
+        ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => {
+            Ok((child_expr.clone(), true))
+        }
         ScalarExpr::CastExpr(cast) => {
             let arg = &cast.argument;
             let (_, is_non_equi_condition) = check_child_expr_in_subquery(arg, op)?;
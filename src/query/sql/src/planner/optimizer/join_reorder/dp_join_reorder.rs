@@ -0,0 +1,345 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+
+use crate::binder::JoinPredicate;
+use crate::optimizer::cost::COST_FACTOR_COMPUTE_PER_ROW;
+use crate::optimizer::cost::COST_FACTOR_HASH_TABLE_PER_ROW;
+use crate::optimizer::join_reorder::is_reorderable_join;
+use crate::optimizer::join_reorder::JoinGraph;
+use crate::optimizer::join_reorder::RelationSet;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::SExpr;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::IndexType;
+
+/// A join tree of a set of relations in the join graph.
+#[derive(Clone, Debug)]
+struct JoinPlan {
+    s_expr: SExpr,
+    prop: RelationalProperty,
+    relations: RelationSet,
+    /// The cost of the joins in the tree, see `DefaultCostModel`.
+    cost: f64,
+}
+
+/// Reorder the inner joins and cross joins with dynamic programming before the cascades
+/// optimizer, so the exhaustive join transformation rules are not needed any more.
+///
+/// For the joins of at most `dp_threshold` relations, the best join tree is enumerated
+/// bottom-up by the size of the connected relation sets (DPsize), so cross products are
+/// only introduced if the join graph is disconnected. The joins of more relations are
+/// reordered greedily by joining the pair of trees with the smallest result at a time.
+///
+/// The joins are costed in the same way as `DefaultCostModel` with the cardinalities
+/// estimated from the column statistics, so the smaller child of a join is always
+/// used as the build side.
+pub struct DpJoinReorder {
+    enable: bool,
+    dp_threshold: usize,
+    /// Whether all the joins are reordered.
+    reordered: bool,
+}
+
+impl DpJoinReorder {
+    pub fn create(ctx: Arc<dyn TableContext>) -> Result<Self> {
+        let settings = ctx.get_settings();
+        Ok(DpJoinReorder {
+            enable: settings.get_enable_dp_join_reorder()?,
+            dp_threshold: settings.get_join_reorder_dp_threshold()? as usize,
+            reordered: true,
+        })
+    }
+
+    /// Returns true if all the joins have been reordered by the last optimization.
+    pub fn reordered(&self) -> bool {
+        self.enable && self.reordered
+    }
+
+    pub fn optimize(&mut self, s_expr: &SExpr) -> Result<SExpr> {
+        if !self.enable {
+            return Ok(s_expr.clone());
+        }
+
+        if is_reorderable_join(s_expr) {
+            let graph = JoinGraph::build(s_expr, &mut |relation| self.optimize(relation))?;
+            if let Some(graph) = graph {
+                return self.reorder(&graph);
+            }
+            self.reordered = false;
+        }
+
+        let children = s_expr
+            .children()
+            .iter()
+            .map(|child| self.optimize(child))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(s_expr.replace_children(children))
+    }
+
+    fn reorder(&self, graph: &JoinGraph) -> Result<SExpr> {
+        let leaves = graph
+            .relations
+            .iter()
+            .enumerate()
+            .map(|(index, relation)| JoinPlan {
+                s_expr: relation.s_expr.clone(),
+                prop: relation.prop.clone(),
+                relations: 1 << index,
+                cost: 0.0,
+            })
+            .collect::<Vec<_>>();
+
+        let plan = if leaves.len() <= self.dp_threshold {
+            // The join graph may be disconnected, fallback to the greedy algorithm.
+            match self.solve_dp(graph, leaves.clone())? {
+                Some(plan) => plan,
+                None => self.solve_greedy(graph, leaves)?,
+            }
+        } else {
+            self.solve_greedy(graph, leaves)?
+        };
+        Ok(plan.s_expr)
+    }
+
+    /// Find the best join tree without cross products, returns `None` if the join graph
+    /// is disconnected.
+    fn solve_dp(&self, graph: &JoinGraph, leaves: Vec<JoinPlan>) -> Result<Option<JoinPlan>> {
+        let num_relations = leaves.len();
+        let mut best_plans: HashMap<RelationSet, JoinPlan> = HashMap::new();
+        // relation sets of each size which have a join tree
+        let mut levels: Vec<Vec<RelationSet>> = vec![vec![]; num_relations + 1];
+        for leaf in leaves {
+            levels[1].push(leaf.relations);
+            best_plans.insert(leaf.relations, leaf);
+        }
+
+        for size in 2..=num_relations {
+            let mut plans: HashMap<RelationSet, JoinPlan> = HashMap::new();
+            for left_size in 1..=size / 2 {
+                let right_size = size - left_size;
+                for left in levels[left_size].iter() {
+                    for right in levels[right_size].iter() {
+                        if left & right != 0
+                            || (left_size == right_size && left > right)
+                            || !graph.is_connected(*left, *right)
+                        {
+                            continue;
+                        }
+                        let plan = self.join(graph, &best_plans[left], &best_plans[right])?;
+                        match plans.get(&plan.relations) {
+                            Some(best) if best.cost <= plan.cost => {}
+                            _ => {
+                                plans.insert(plan.relations, plan);
+                            }
+                        }
+                    }
+                }
+            }
+            // Enumerate the relation sets in a fixed order, so the plans of the same cost
+            // are chosen deterministically.
+            let mut relation_sets = plans.keys().cloned().collect::<Vec<_>>();
+            relation_sets.sort_unstable();
+            levels[size] = relation_sets;
+            best_plans.extend(plans);
+        }
+
+        let all_relations = RelationSet::MAX >> (RelationSet::BITS as usize - num_relations);
+        Ok(best_plans.remove(&all_relations))
+    }
+
+    /// Join the pair of trees with the smallest result until there is only one tree, the
+    /// trees without predicates between them are cross joined from the smallest ones.
+    fn solve_greedy(&self, graph: &JoinGraph, mut plans: Vec<JoinPlan>) -> Result<JoinPlan> {
+        while plans.len() > 1 {
+            let mut best: Option<(usize, usize, JoinPlan)> = None;
+            for i in 0..plans.len() {
+                for j in i + 1..plans.len() {
+                    if !graph.is_connected(plans[i].relations, plans[j].relations) {
+                        continue;
+                    }
+                    let plan = self.join(graph, &plans[i], &plans[j])?;
+                    if best.as_ref().map_or(true, |(_, _, best)| {
+                        plan.prop.cardinality < best.prop.cardinality
+                    }) {
+                        best = Some((i, j, plan));
+                    }
+                }
+            }
+
+            let (i, j, plan) = match best {
+                Some(best) => best,
+                None => {
+                    let mut indices = (0..plans.len()).collect::<Vec<_>>();
+                    indices.sort_by(|a, b| {
+                        plans[*a]
+                            .prop
+                            .cardinality
+                            .total_cmp(&plans[*b].prop.cardinality)
+                    });
+                    let (i, j) = (indices[0].min(indices[1]), indices[0].max(indices[1]));
+                    let plan = self.join(graph, &plans[i], &plans[j])?;
+                    (i, j, plan)
+                }
+            };
+            plans.remove(j);
+            plans.remove(i);
+            plans.push(plan);
+        }
+
+        Ok(plans.pop().unwrap())
+    }
+
+    fn join(&self, graph: &JoinGraph, left: &JoinPlan, right: &JoinPlan) -> Result<JoinPlan> {
+        // The right child is the build side of hash join.
+        let (probe, build) = if left.prop.cardinality >= right.prop.cardinality {
+            (left, right)
+        } else {
+            (right, left)
+        };
+
+        let mut join = Join::default();
+        for predicate in graph.predicates_between(probe.relations, build.relations) {
+            match JoinPredicate::new(predicate, &probe.prop, &build.prop) {
+                JoinPredicate::Both { left, right } => {
+                    join.left_conditions.push(left.clone());
+                    join.right_conditions.push(right.clone());
+                }
+                _ => join.non_equi_conditions.push(predicate.clone()),
+            }
+        }
+        if !join.left_conditions.is_empty() {
+            join.join_type = JoinType::Inner;
+        }
+
+        let relations = probe.relations | build.relations;
+        let cardinality = if relations.count_ones() > 2 {
+            Some(join_cardinality(&join, probe, build))
+        } else {
+            None
+        };
+        let mut s_expr =
+            SExpr::create_binary(join.into(), probe.s_expr.clone(), build.s_expr.clone());
+        let mut prop = RelExpr::with_s_expr(&s_expr).derive_relational_prop()?;
+        if let Some(cardinality) = cardinality {
+            prop.cardinality = cardinality;
+        }
+        s_expr.rel_prop = Some(Box::new(prop.clone()));
+        Ok(JoinPlan {
+            s_expr,
+            cost: build.prop.cardinality * COST_FACTOR_HASH_TABLE_PER_ROW
+                + probe.prop.cardinality * COST_FACTOR_COMPUTE_PER_ROW
+                + probe.cost
+                + build.cost,
+            prop,
+            relations,
+        })
+    }
+}
+
+/// Estimate the cardinality of joining the two trees, at least one of which is a join.
+///
+/// `Join` estimates each condition with the histograms of the base tables, which don't
+/// know the rows already filtered by the joins below, and it applies the conditions
+/// implied by the joins below (e.g. the last edge of a cycle) once more. So the keys of
+/// each equivalence class are only counted once here with their ndv, and the ndv of a
+/// key is bounded by the cardinality of its side.
+fn join_cardinality(join: &Join, probe: &JoinPlan, build: &JoinPlan) -> f64 {
+    let mut classes = HashMap::new();
+    collect_equalities(&probe.s_expr, &mut classes);
+    collect_equalities(&build.s_expr, &mut classes);
+
+    let ndv = |plan: &JoinPlan, column: IndexType| {
+        plan.prop
+            .statistics
+            .column_stats
+            .get(&column)
+            .map(|stat| stat.ndv.min(plan.prop.cardinality))
+    };
+    let mut cardinality = probe.prop.cardinality * build.prop.cardinality;
+    for (left, right) in join
+        .left_conditions
+        .iter()
+        .zip(join.right_conditions.iter())
+    {
+        let (left, right) = match (single_column(left), single_column(right)) {
+            (Some(left), Some(right)) => (left, right),
+            _ => continue,
+        };
+        let (left_root, right_root) = (find(&classes, left), find(&classes, right));
+        if left_root == right_root {
+            continue;
+        }
+        classes.insert(left_root, right_root);
+        if let (Some(left_ndv), Some(right_ndv)) = (ndv(probe, left), ndv(build, right)) {
+            let max_ndv = left_ndv.max(right_ndv);
+            cardinality = if max_ndv == 0.0 {
+                0.0
+            } else {
+                cardinality / max_ndv
+            };
+        }
+    }
+    cardinality
+}
+
+/// Union the keys of the equi-join conditions of the joins in `s_expr`, `classes` maps
+/// a column to its parent in the equivalence class.
+fn collect_equalities(s_expr: &SExpr, classes: &mut HashMap<IndexType, IndexType>) {
+    if let RelOperator::Join(join) = s_expr.plan() {
+        if !matches!(join.join_type, JoinType::Inner | JoinType::Cross) {
+            return;
+        }
+        for (left, right) in join
+            .left_conditions
+            .iter()
+            .zip(join.right_conditions.iter())
+        {
+            if let (Some(left), Some(right)) = (single_column(left), single_column(right)) {
+                let (left_root, right_root) = (find(classes, left), find(classes, right));
+                if left_root != right_root {
+                    classes.insert(left_root, right_root);
+                }
+            }
+        }
+        for child in s_expr.children() {
+            collect_equalities(child, classes);
+        }
+    }
+}
+
+fn find(classes: &HashMap<IndexType, IndexType>, mut column: IndexType) -> IndexType {
+    while let Some(parent) = classes.get(&column) {
+        column = *parent;
+    }
+    column
+}
+
+fn single_column(scalar: &ScalarExpr) -> Option<IndexType> {
+    let used_columns = scalar.used_columns();
+    if used_columns.len() == 1 {
+        used_columns.into_iter().next()
+    } else {
+        None
+    }
+}
@@ -0,0 +1,189 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::binder::contain_subquery;
+use crate::optimizer::rule::get_join_predicates;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::SExpr;
+use crate::plans::Filter;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+
+/// A set of relations in the join graph, the i-th bit represents the i-th relation.
+pub type RelationSet = u64;
+
+/// Max number of relations can be represented by a `RelationSet`.
+pub const MAX_JOIN_RELATIONS: usize = RelationSet::BITS as usize;
+
+/// A relation of the join graph, which is a subtree of the plan that isn't an inner
+/// join or a cross join.
+#[derive(Clone, Debug)]
+pub struct JoinRelation {
+    pub s_expr: SExpr,
+    pub prop: RelationalProperty,
+}
+
+/// A predicate which references more than one relation of the join graph.
+#[derive(Clone, Debug)]
+pub struct JoinEdge {
+    pub predicate: ScalarExpr,
+    pub relations: RelationSet,
+}
+
+/// The join graph of a tree of inner joins and cross joins, the join predicates are
+/// the edges between the relations.
+#[derive(Clone, Debug)]
+pub struct JoinGraph {
+    pub relations: Vec<JoinRelation>,
+    pub edges: Vec<JoinEdge>,
+}
+
+/// Check if the join can be reordered with its children, only the inner joins and
+/// cross joins which don't come from subqueries can be reordered.
+pub fn is_reorderable_join(s_expr: &SExpr) -> bool {
+    match s_expr.plan() {
+        RelOperator::Join(join) => {
+            matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+                && join.marker_index.is_none()
+                && !join.from_correlated_subquery
+        }
+        _ => false,
+    }
+}
+
+impl JoinGraph {
+    /// Extract the join graph of the joins rooted at `s_expr`, each relation is optimized
+    /// by `optimize_relation` before it's added into the graph. The predicates referencing
+    /// a single relation are pushed down to the relation as a filter.
+    ///
+    /// Returns `None` if the joins can't be reordered, e.g. there are too many relations,
+    /// or some predicate references the columns from outside.
+    pub fn build(
+        s_expr: &SExpr,
+        optimize_relation: &mut impl FnMut(&SExpr) -> Result<SExpr>,
+    ) -> Result<Option<JoinGraph>> {
+        let mut relations = vec![];
+        let mut predicates = vec![];
+        collect_relations(s_expr, &mut relations, &mut predicates)?;
+        if relations.len() > MAX_JOIN_RELATIONS {
+            return Ok(None);
+        }
+
+        let mut relations = relations
+            .into_iter()
+            .map(|relation| {
+                let s_expr = optimize_relation(relation)?;
+                let prop = RelExpr::with_s_expr(&s_expr).derive_relational_prop()?;
+                Ok((s_expr, prop))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut edges = vec![];
+        let mut filters = vec![vec![]; relations.len()];
+        for predicate in predicates {
+            if contain_subquery(&predicate) {
+                return Ok(None);
+            }
+            let used_columns = predicate.used_columns();
+            let mut set: RelationSet = 0;
+            let mut resolved_columns = ColumnSet::new();
+            for (index, (_, prop)) in relations.iter().enumerate() {
+                if !used_columns.is_disjoint(&prop.output_columns) {
+                    set |= 1 << index;
+                    resolved_columns.extend(used_columns.intersection(&prop.output_columns));
+                }
+            }
+            if set == 0 || resolved_columns.len() != used_columns.len() {
+                return Ok(None);
+            }
+            if set.count_ones() == 1 {
+                filters[set.trailing_zeros() as usize].push(predicate);
+            } else {
+                edges.push(JoinEdge {
+                    predicate,
+                    relations: set,
+                });
+            }
+        }
+
+        for ((s_expr, prop), predicates) in relations.iter_mut().zip(filters.into_iter()) {
+            if predicates.is_empty() {
+                continue;
+            }
+            let filter = Filter {
+                predicates,
+                is_having: false,
+            };
+            *s_expr = SExpr::create_unary(filter.into(), s_expr.clone());
+            *prop = RelExpr::with_s_expr(s_expr).derive_relational_prop()?;
+        }
+
+        let relations = relations
+            .into_iter()
+            .map(|(mut s_expr, prop)| {
+                // Cache the property, it's used to derive the properties of the joins.
+                s_expr.rel_prop = Some(Box::new(prop.clone()));
+                JoinRelation { s_expr, prop }
+            })
+            .collect();
+        Ok(Some(JoinGraph { relations, edges }))
+    }
+
+    /// Check if there are predicates between the two disjoint sets of relations.
+    pub fn is_connected(&self, left: RelationSet, right: RelationSet) -> bool {
+        self.edges
+            .iter()
+            .any(|edge| is_edge_between(edge, left, right))
+    }
+
+    /// Get the predicates that can be evaluated by joining the two disjoint sets of
+    /// relations, the predicates within either set have been evaluated before.
+    pub fn predicates_between(
+        &self,
+        left: RelationSet,
+        right: RelationSet,
+    ) -> impl Iterator<Item = &ScalarExpr> {
+        self.edges
+            .iter()
+            .filter(move |edge| is_edge_between(edge, left, right))
+            .map(|edge| &edge.predicate)
+    }
+}
+
+fn is_edge_between(edge: &JoinEdge, left: RelationSet, right: RelationSet) -> bool {
+    edge.relations & !(left | right) == 0
+        && edge.relations & left != 0
+        && edge.relations & right != 0
+}
+
+fn collect_relations<'a>(
+    s_expr: &'a SExpr,
+    relations: &mut Vec<&'a SExpr>,
+    predicates: &mut Vec<ScalarExpr>,
+) -> Result<()> {
+    if !is_reorderable_join(s_expr) {
+        relations.push(s_expr);
+        return Ok(());
+    }
+    let join: Join = s_expr.plan().clone().try_into()?;
+    predicates.extend(get_join_predicates(&join)?);
+    collect_relations(s_expr.child(0)?, relations, predicates)?;
+    collect_relations(s_expr.child(1)?, relations, predicates)
+}
@@ -0,0 +1,266 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_functions::aggregates::AggregateFunctionFactory;
+
+use crate::binder::ColumnBinding;
+use crate::binder::Visibility;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::MaterializedViewColumn;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::plans::Statistics;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MaterializedViewEntry;
+use crate::MetadataRef;
+
+/// Match an initial aggregation with group keys right over a table scan, i.e.
+/// `Aggregate -> EvalScalar -> Scan`, as the binder produces for `SELECT ... FROM t GROUP BY ...`.
+pub fn match_aggregate_scan(s_expr: &SExpr) -> Option<(&Aggregate, &EvalScalar, &Scan)> {
+    let aggregate = match s_expr.plan() {
+        RelOperator::Aggregate(aggregate)
+            if aggregate.mode == AggregateMode::Initial
+                && !aggregate.from_distinct
+                && !aggregate.group_items.is_empty() =>
+        {
+            aggregate
+        }
+        _ => return None,
+    };
+    let child = s_expr.child(0).ok()?;
+    let eval_scalar = match child.plan() {
+        RelOperator::EvalScalar(eval_scalar) => eval_scalar,
+        _ => return None,
+    };
+    let scan = match child.child(0).ok()?.plan() {
+        RelOperator::Scan(scan)
+            if scan.push_down_predicates.is_none() && scan.prewhere.is_none() =>
+        {
+            scan
+        }
+        _ => return None,
+    };
+    Some((aggregate, eval_scalar, scan))
+}
+
+/// Answer the aggregations over a table from a fresh materialized view of the table.
+///
+/// An aggregation can be answered from a view if its group keys are group keys of the view,
+/// and its aggregate functions are stored in the view. The view may be grouped by more keys,
+/// so its partial aggregates are merged by aggregating them again, e.g. `COUNT` by `SUM`.
+pub struct MaterializedViewRewriter {
+    metadata: MetadataRef,
+}
+
+impl MaterializedViewRewriter {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self { metadata }
+    }
+
+    pub fn rewrite(&self, s_expr: &SExpr) -> Result<SExpr> {
+        if let Some((aggregate, eval_scalar, scan)) = match_aggregate_scan(s_expr) {
+            let views = self
+                .metadata
+                .read()
+                .materialized_views(scan.table_index)
+                .to_vec();
+            for view in views.iter() {
+                if let Some(result) = self.try_rewrite(aggregate, eval_scalar, scan, view)? {
+                    return Ok(result);
+                }
+            }
+            return Ok(s_expr.clone());
+        }
+
+        let children = s_expr
+            .children()
+            .iter()
+            .map(|child| self.rewrite(child))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(s_expr.replace_children(children))
+    }
+
+    fn try_rewrite(
+        &self,
+        aggregate: &Aggregate,
+        eval_scalar: &EvalScalar,
+        scan: &Scan,
+        view: &MaterializedViewEntry,
+    ) -> Result<Option<SExpr>> {
+        let metadata = self.metadata.read();
+
+        // Resolve the columns referred by the aggregation to the names of the base table columns.
+        let base_column = |index: IndexType| match metadata.column(index) {
+            ColumnEntry::BaseTableColumn {
+                table_index,
+                column_name,
+                path_indices: None,
+                ..
+            } if *table_index == scan.table_index => Some(column_name.clone()),
+            _ => None,
+        };
+        let mut input_columns = HashMap::with_capacity(eval_scalar.items.len());
+        for item in eval_scalar.items.iter() {
+            match &item.scalar {
+                ScalarExpr::BoundColumnRef(column_ref) => {
+                    match base_column(column_ref.column.index) {
+                        Some(column) => input_columns.insert(item.index, column),
+                        None => return Ok(None),
+                    }
+                }
+                _ => return Ok(None),
+            };
+        }
+        let resolve = |scalar: &ScalarExpr| match scalar {
+            ScalarExpr::BoundColumnRef(column_ref) => input_columns
+                .get(&column_ref.column.index)
+                .cloned()
+                .or_else(|| base_column(column_ref.column.index)),
+            _ => None,
+        };
+
+        // Find the column of the view storing the given group key or aggregate.
+        let view_table = metadata.table(view.table_index);
+        let view_columns = metadata
+            .columns_by_table_index(view.table_index)
+            .into_iter()
+            .filter_map(|column| match column {
+                ColumnEntry::BaseTableColumn {
+                    column_index,
+                    column_name,
+                    data_type,
+                    path_indices: None,
+                    ..
+                } => Some((column_name, (column_index, DataType::from(&data_type)))),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+        let view_schema = view_table.table().schema();
+        let find = |target: &MaterializedViewColumn| {
+            let (_, field) = view
+                .definition
+                .columns
+                .iter()
+                .zip(view_schema.fields())
+                .find(|(column, _)| *column == target)?;
+            let (index, data_type) = view_columns.get(field.name())?;
+            Some(ScalarExpr::BoundColumnRef(BoundColumnRef {
+                column: ColumnBinding {
+                    database_name: Some(view_table.database().to_string()),
+                    table_name: Some(view_table.name().to_string()),
+                    column_name: field.name().clone(),
+                    index: *index,
+                    data_type: Box::new(data_type.clone()),
+                    visibility: Visibility::Visible,
+                },
+            }))
+        };
+
+        let mut items = Vec::with_capacity(aggregate.group_items.len());
+        for item in aggregate.group_items.iter() {
+            let column = match resolve(&item.scalar) {
+                Some(column) => column,
+                None => return Ok(None),
+            };
+            let scalar = match find(&MaterializedViewColumn::GroupKey { column }) {
+                Some(scalar) if scalar.data_type() == item.scalar.data_type() => scalar,
+                _ => return Ok(None),
+            };
+            items.push(ScalarItem {
+                scalar,
+                index: item.index,
+            });
+        }
+
+        let mut aggregate_functions = Vec::with_capacity(aggregate.aggregate_functions.len());
+        for item in aggregate.aggregate_functions.iter() {
+            let agg = match &item.scalar {
+                ScalarExpr::AggregateFunction(agg) if !agg.distinct && agg.params.is_empty() => agg,
+                _ => return Ok(None),
+            };
+            let func_name = agg.func_name.to_lowercase();
+            let merge_func_name = match MaterializedViewColumn::merge_func_name(&func_name) {
+                Some(merge_func_name) => merge_func_name,
+                None => return Ok(None),
+            };
+            let arg = match agg.args.as_slice() {
+                [] => None,
+                [arg] => match resolve(arg) {
+                    Some(column) => Some(column),
+                    None => return Ok(None),
+                },
+                _ => return Ok(None),
+            };
+            let scalar = match find(&MaterializedViewColumn::Aggregate { func_name, arg }) {
+                Some(scalar) => scalar,
+                None => return Ok(None),
+            };
+            let merge_func =
+                AggregateFunctionFactory::instance()
+                    .get(merge_func_name, vec![], vec![scalar.data_type()])?;
+            if merge_func.return_type()? != *agg.return_type {
+                return Ok(None);
+            }
+            aggregate_functions.push(ScalarItem {
+                scalar: AggregateFunction {
+                    display_name: agg.display_name.clone(),
+                    func_name: merge_func_name.to_string(),
+                    distinct: false,
+                    params: vec![],
+                    args: vec![scalar],
+                    return_type: agg.return_type.clone(),
+                }
+                .into(),
+                index: item.index,
+            });
+        }
+
+        let view_scan = Scan {
+            table_index: view.table_index,
+            columns: view_columns.values().map(|(index, _)| *index).collect(),
+            push_down_predicates: None,
+            limit: None,
+            order_by: None,
+            prewhere: None,
+            statistics: Statistics {
+                statistics: view_table.table().table_statistics()?,
+                col_stats: HashMap::new(),
+                is_accurate: true,
+            },
+        };
+        Ok(Some(SExpr::create_unary(
+            Aggregate {
+                aggregate_functions,
+                ..aggregate.clone()
+            }
+            .into(),
+            SExpr::create_unary(
+                EvalScalar { items }.into(),
+                SExpr::create_leaf(view_scan.into()),
+            ),
+        )))
+    }
+}
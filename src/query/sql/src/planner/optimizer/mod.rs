@@ -13,12 +13,15 @@
 // limitations under the License.
 
 mod cascades;
+mod computed_column;
 mod cost;
 mod distributed;
 mod format;
 mod group;
 mod heuristic;
+mod join_reorder;
 mod m_expr;
+mod materialized_view;
 mod memo;
 #[allow(clippy::module_inception)]
 mod optimizer;
@@ -26,11 +29,14 @@ mod pattern_extractor;
 mod property;
 mod rule;
 mod s_expr;
+mod scan_column_rewriter;
 mod util;
+mod virtual_column;
 
 pub use heuristic::HeuristicOptimizer;
 pub use heuristic::DEFAULT_REWRITE_RULES;
 pub use m_expr::MExpr;
+pub use materialized_view::match_aggregate_scan;
 pub use memo::Memo;
 pub use optimizer::optimize;
 pub use optimizer::OptimizerConfig;
@@ -26,6 +26,8 @@ use super::Memo;
 use crate::optimizer::cascades::CascadesOptimizer;
 use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::heuristic::RuleList;
+use crate::optimizer::join_reorder::DpJoinReorder;
+use crate::optimizer::materialized_view::MaterializedViewRewriter;
 use crate::optimizer::util::contains_local_table_scan;
 use crate::optimizer::HeuristicOptimizer;
 use crate::optimizer::SExpr;
@@ -141,14 +143,18 @@ pub fn optimize_query(
     bind_context: Box<BindContext>,
     s_expr: SExpr,
 ) -> Result<SExpr> {
-    let rules = RuleList::create(DEFAULT_REWRITE_RULES.clone())?;
+    let s_expr = rewrite_with_materialized_views(metadata.clone(), s_expr)?;
+    let rules = RuleList::create(DEFAULT_REWRITE_RULES.clone(), metadata.clone())?;
 
     let contains_local_table_scan = contains_local_table_scan(&s_expr, &metadata);
 
     let mut heuristic = HeuristicOptimizer::new(ctx.clone(), bind_context, metadata, rules);
     let mut result = heuristic.optimize(s_expr)?;
 
-    let mut cascades = CascadesOptimizer::create(ctx.clone())?;
+    let mut join_reorder = DpJoinReorder::create(ctx.clone())?;
+    result = join_reorder.optimize(&result)?;
+
+    let mut cascades = CascadesOptimizer::create(ctx.clone(), join_reorder.reordered())?;
     result = cascades.optimize(result)?;
 
     // So far, we don't have ability to execute distributed query
@@ -162,6 +168,13 @@ pub fn optimize_query(
     Ok(result)
 }
 
+fn rewrite_with_materialized_views(metadata: MetadataRef, s_expr: SExpr) -> Result<SExpr> {
+    if !metadata.read().has_materialized_views() {
+        return Ok(s_expr);
+    }
+    MaterializedViewRewriter::new(metadata).rewrite(&s_expr)
+}
+
 // TODO(leiysky): reuse the optimization logic with `optimize_query`
 fn get_optimized_memo(
     ctx: Arc<dyn TableContext>,
@@ -169,12 +182,16 @@ fn get_optimized_memo(
     metadata: MetadataRef,
     bind_context: Box<BindContext>,
 ) -> Result<(Memo, HashMap<IndexType, CostContext>)> {
-    let rules = RuleList::create(DEFAULT_REWRITE_RULES.clone())?;
+    let s_expr = rewrite_with_materialized_views(metadata.clone(), s_expr)?;
+    let rules = RuleList::create(DEFAULT_REWRITE_RULES.clone(), metadata.clone())?;
 
     let mut heuristic = HeuristicOptimizer::new(ctx.clone(), bind_context, metadata, rules);
-    let result = heuristic.optimize(s_expr)?;
+    let mut result = heuristic.optimize(s_expr)?;
+
+    let mut join_reorder = DpJoinReorder::create(ctx.clone())?;
+    result = join_reorder.optimize(&result)?;
 
-    let mut cascades = CascadesOptimizer::create(ctx)?;
+    let mut cascades = CascadesOptimizer::create(ctx, join_reorder.reordered())?;
     cascades.optimize(result)?;
     Ok((cascades.memo, cascades.best_cost_map))
 }
@@ -33,10 +33,10 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// it is difficult to give the exact frequency of the skew data
 /// when the skew data and other data fall into the same bucket
 ///
-/// We choose this approach because so far the histogram is originally
-/// constructed from NDV(number of distinct values) and the total number
-/// of rows instead of maintaining a real histogram for each column,
-/// which brings the assumption that the data is uniformly distributed.
+/// The histogram is either collected from the sampled values by `ANALYZE TABLE`,
+/// or constructed from NDV(number of distinct values) and the total number
+/// of rows if the table is not analyzed, which brings the assumption that
+/// the data is uniformly distributed.
 #[derive(Debug, Clone)]
 pub struct Histogram {
     pub buckets: Vec<HistogramBucket>,
@@ -109,85 +109,38 @@ impl<'a> SelectivityEstimator<'a> {
                 return DEFAULT_SELECTIVITY;
             };
 
-            match &comp_expr.op {
+            // The histogram only contains the non-null values.
+            let num_values = col_hist.num_values();
+            if num_values <= 0.0 {
+                return DEFAULT_SELECTIVITY;
+            }
+            let non_null_fraction = num_values / (num_values + column_stat.null_count as f64);
+
+            let fraction = match &comp_expr.op {
                 ComparisonOp::Equal => {
-                    // For equal predicate, we just use cardinality of a single
-                    // value to estimate the selectivity. This assumes that
-                    // the column is in a uniform distribution.
-                    return evaluate_equal(col_hist, column_stat, constant);
+                    // For equal predicate, we use the average frequency of the values
+                    // in the bucket the constant falls into.
+                    return evaluate_equal(col_hist, column_stat, constant) * non_null_fraction;
                 }
                 ComparisonOp::NotEqual => {
                     // For not equal predicate, we treat it as opposite of equal predicate.
-                    return 1.0 - evaluate_equal(col_hist, column_stat, constant);
+                    return (1.0 - evaluate_equal(col_hist, column_stat, constant))
+                        * non_null_fraction;
                 }
+                ComparisonOp::LT => less_fraction(col_hist, &const_datum, false),
+                ComparisonOp::LTE => less_fraction(col_hist, &const_datum, true),
+                // Greater than predicate is the opposite of less than or equal to predicate.
                 ComparisonOp::GT => {
-                    // For greater than predicate, we use the number of values
-                    // that are greater than the constant value to estimate the
-                    // selectivity.
-                    let mut num_greater = 0.0;
-                    for bucket in col_hist.buckets_iter() {
-                        if let Ok(ord) = bucket.upper_bound().compare(&const_datum) {
-                            if ord == Ordering::Less || ord == Ordering::Equal {
-                                num_greater += bucket.num_values();
-                            } else {
-                                break;
-                            }
-                        } else {
-                            return DEFAULT_SELECTIVITY;
-                        }
-                    }
-                    return 1.0 - num_greater / col_hist.num_values();
-                }
-                ComparisonOp::LT => {
-                    // For less than predicate, we treat it as opposite of
-                    // greater than predicate.
-                    let mut num_greater = 0.0;
-                    for bucket in col_hist.buckets_iter() {
-                        if let Ok(ord) = bucket.upper_bound().compare(&const_datum) {
-                            if ord == Ordering::Less {
-                                num_greater += bucket.num_values();
-                            } else {
-                                break;
-                            }
-                        } else {
-                            return DEFAULT_SELECTIVITY;
-                        }
-                    }
-                    return num_greater / col_hist.num_values();
+                    less_fraction(col_hist, &const_datum, true).map(|fraction| 1.0 - fraction)
                 }
                 ComparisonOp::GTE => {
-                    // Greater than or equal to predicate is similar to greater than predicate.
-                    let mut num_greater = 0.0;
-                    for bucket in col_hist.buckets_iter() {
-                        if let Ok(ord) = bucket.upper_bound().compare(&const_datum) {
-                            if ord == Ordering::Less || ord == Ordering::Equal {
-                                num_greater += bucket.num_values();
-                            } else {
-                                break;
-                            }
-                        } else {
-                            return DEFAULT_SELECTIVITY;
-                        }
-                    }
-                    return 1.0 - num_greater / col_hist.num_values();
+                    less_fraction(col_hist, &const_datum, false).map(|fraction| 1.0 - fraction)
                 }
-                ComparisonOp::LTE => {
-                    // Less than or equal to predicate is similar to less than predicate.
-                    let mut num_greater = 0.0;
-                    for bucket in col_hist.buckets_iter() {
-                        if let Ok(ord) = bucket.upper_bound().compare(&const_datum) {
-                            if ord == Ordering::Less || ord == Ordering::Equal {
-                                num_greater += bucket.num_values();
-                            } else {
-                                break;
-                            }
-                        } else {
-                            return DEFAULT_SELECTIVITY;
-                        }
-                    }
-                    return num_greater / col_hist.num_values();
-                }
-            }
+            };
+            return match fraction {
+                Some(fraction) => fraction.clamp(0.0, 1.0) * non_null_fraction,
+                None => DEFAULT_SELECTIVITY,
+            };
         }
 
         DEFAULT_SELECTIVITY
@@ -239,8 +192,88 @@ fn compare_equal(datum: &Option<Datum>, col_hist: &Histogram, column_stat: &Colu
             {
                 return 0.0;
             }
+
+            // The values in a bucket are assumed to have the same frequency, so
+            // skewed values get a higher selectivity than the others.
+            for bucket in col_hist.buckets_iter() {
+                if let Ok(Ordering::Greater | Ordering::Equal) =
+                    bucket.upper_bound().compare(constant_datum)
+                {
+                    if bucket.num_distinct() > 0.0 {
+                        return (bucket.num_values()
+                            / bucket.num_distinct()
+                            / col_hist.num_values())
+                        .min(1.0);
+                    }
+                    break;
+                }
+            }
         }
     }
 
     1.0 / col_hist.num_distinct_values()
 }
+
+/// Estimate the fraction of the values in the histogram that are less than the datum,
+/// or equal to it if `inclusive` is true. The first bucket only holds the min value, both
+/// for the histograms of `ANALYZE TABLE` and the ones derived from the NDV. The lower bound
+/// of the other buckets is the upper bound of the previous one (exclusive), the values inside
+/// them are assumed to be evenly spread between the bounds.
+/// Returns `None` if the datum can't be compared with the histogram.
+fn less_fraction(col_hist: &Histogram, datum: &Datum, inclusive: bool) -> Option<f64> {
+    let mut buckets = col_hist.buckets_iter();
+    let first = buckets.next()?;
+    let mut lower_bound = first.upper_bound();
+    let mut num_less = match lower_bound.compare(datum).ok()? {
+        Ordering::Less => first.num_values(),
+        Ordering::Equal if inclusive => first.num_values(),
+        _ => return Some(0.0),
+    };
+    for bucket in buckets {
+        let upper_bound = bucket.upper_bound();
+        let num_equal = if bucket.num_distinct() > 0.0 {
+            bucket.num_values() / bucket.num_distinct()
+        } else {
+            0.0
+        };
+        match upper_bound.compare(datum).ok()? {
+            Ordering::Less => {
+                num_less += bucket.num_values();
+                lower_bound = upper_bound;
+                continue;
+            }
+            Ordering::Equal => {
+                num_less += if inclusive {
+                    bucket.num_values()
+                } else {
+                    bucket.num_values() - num_equal
+                };
+            }
+            Ordering::Greater => {
+                if lower_bound.compare(datum).ok()? != Ordering::Less {
+                    break;
+                }
+                // Interpolate inside the bucket for numeric values, otherwise assume
+                // half of the values in the bucket are less than the datum.
+                let ratio = match (
+                    lower_bound.to_double(),
+                    upper_bound.to_double(),
+                    datum.to_double(),
+                ) {
+                    (Ok(lower), Ok(upper), Ok(value)) if upper > lower => {
+                        (value - lower) / (upper - lower)
+                    }
+                    _ => 0.5,
+                };
+                let num_range = (bucket.num_values() - num_equal).max(0.0);
+                num_less += num_range * ratio;
+                if inclusive {
+                    num_less += num_equal;
+                }
+            }
+        }
+        break;
+    }
+
+    Some(num_less / col_hist.num_values())
+}
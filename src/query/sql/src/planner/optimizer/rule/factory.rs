@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::rewrite::RuleEagerAggregation;
+use super::rewrite::RuleEliminateCommonSubexpression;
 use super::rewrite::RuleEliminateEvalScalar;
 use super::rewrite::RuleFoldCountAggregate;
+use super::rewrite::RuleInferFilter;
 use super::rewrite::RuleNormalizeDisjunctiveFilter;
 use super::rewrite::RuleNormalizeScalarFilter;
 use super::rewrite::RulePushDownFilterEvalScalar;
@@ -42,12 +46,22 @@ use crate::optimizer::rule::transform::RuleLeftExchangeJoin;
 use crate::optimizer::rule::transform::RuleRightExchangeJoin;
 use crate::optimizer::rule::RuleID;
 use crate::optimizer::rule::RulePtr;
+use crate::MetadataRef;
 
-pub struct RuleFactory;
+pub struct RuleFactory {
+    metadata: Option<MetadataRef>,
+}
 
 impl RuleFactory {
     pub fn create() -> Self {
-        RuleFactory {}
+        RuleFactory { metadata: None }
+    }
+
+    /// Create a factory that can also build the rules which allocate new columns.
+    pub fn create_with_metadata(metadata: MetadataRef) -> Self {
+        RuleFactory {
+            metadata: Some(metadata),
+        }
     }
 
     pub fn create_rule(&self, id: RuleID) -> Result<RulePtr> {
@@ -56,6 +70,7 @@ impl RuleFactory {
             RuleID::PushDownFilterUnion => Ok(Box::new(RulePushDownFilterUnion::new())),
             RuleID::PushDownFilterEvalScalar => Ok(Box::new(RulePushDownFilterEvalScalar::new())),
             RuleID::PushDownFilterJoin => Ok(Box::new(RulePushDownFilterJoin::new())),
+            RuleID::InferFilter => Ok(Box::new(RuleInferFilter::new())),
             RuleID::PushDownFilterScan => Ok(Box::new(RulePushDownFilterScan::new())),
             RuleID::PushDownLimitUnion => Ok(Box::new(RulePushDownLimitUnion::new())),
             RuleID::PushDownLimitScan => Ok(Box::new(RulePushDownLimitScan::new())),
@@ -69,6 +84,20 @@ impl RuleFactory {
             RuleID::MergeFilter => Ok(Box::new(RuleMergeFilter::new())),
             RuleID::NormalizeScalarFilter => Ok(Box::new(RuleNormalizeScalarFilter::new())),
             RuleID::SplitAggregate => Ok(Box::new(RuleSplitAggregate::new())),
+            RuleID::EagerAggregation => match &self.metadata {
+                Some(metadata) => Ok(Box::new(RuleEagerAggregation::new(metadata.clone()))),
+                None => Err(ErrorCode::Internal(
+                    "Rule EagerAggregation requires the metadata of the query",
+                )),
+            },
+            RuleID::EliminateCommonSubexpression => match &self.metadata {
+                Some(metadata) => Ok(Box::new(RuleEliminateCommonSubexpression::new(
+                    metadata.clone(),
+                ))),
+                None => Err(ErrorCode::Internal(
+                    "Rule EliminateCommonSubexpression requires the metadata of the query",
+                )),
+            },
             RuleID::FoldCountAggregate => Ok(Box::new(RuleFoldCountAggregate::new())),
             RuleID::NormalizeDisjunctiveFilter => {
                 Ok(Box::new(RuleNormalizeDisjunctiveFilter::new()))
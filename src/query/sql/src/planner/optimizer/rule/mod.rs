@@ -27,4 +27,5 @@ pub use rule::RuleID;
 pub use rule::RulePtr;
 pub use rule_set::AppliedRules;
 pub use rule_set::RuleSet;
+pub use transform::get_join_predicates;
 pub use transform_result::TransformResult;
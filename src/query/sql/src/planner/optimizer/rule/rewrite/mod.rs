@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod rule_eager_aggregation;
+mod rule_eliminate_common_subexpression;
 mod rule_eliminate_eval_scalar;
 mod rule_eliminate_filter;
 mod rule_fold_count_aggregate;
+mod rule_infer_filter;
 mod rule_merge_eval_scalar;
 mod rule_merge_filter;
 mod rule_normalize_disjunctive_filter;
@@ -32,9 +35,12 @@ mod rule_push_down_limit_union;
 mod rule_push_down_sort_scan;
 mod rule_split_aggregate;
 
+pub use rule_eager_aggregation::RuleEagerAggregation;
+pub use rule_eliminate_common_subexpression::RuleEliminateCommonSubexpression;
 pub use rule_eliminate_eval_scalar::RuleEliminateEvalScalar;
 pub use rule_eliminate_filter::RuleEliminateFilter;
 pub use rule_fold_count_aggregate::RuleFoldCountAggregate;
+pub use rule_infer_filter::RuleInferFilter;
 pub use rule_merge_eval_scalar::RuleMergeEvalScalar;
 pub use rule_merge_filter::RuleMergeFilter;
 pub use rule_normalize_disjunctive_filter::RuleNormalizeDisjunctiveFilter;
@@ -0,0 +1,352 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::DataType;
+
+use crate::binder::ColumnBinding;
+use crate::binder::Visibility;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::RuleID;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MetadataRef;
+
+/// The pre-aggregation is only worth it if it shrinks its input to at most
+/// this fraction of the rows.
+const EAGER_AGGREGATION_RATIO: f64 = 0.5;
+
+/// Push a partial aggregation below an inner join (eager aggregation).
+///
+/// The aggregate functions are evaluated on the side of the join that provides
+/// all of their arguments, grouped by the group keys and join keys from that side.
+/// The original aggregate then merges the partial results above the join:
+///
+///     Aggregate(sum(a), count(*))           Aggregate(sum(s), sum(c))
+///     |  group by t1.k                      |  group by t1.k
+///     Join(t1.k = t2.k)           =>        Join(t1.k = t2.k)
+///     /            \                        /            \
+///    t1            t2             Aggregate(s=sum(a), c=count(*))   t2
+///                                      |  group by t1.k
+///                                     t1
+///
+/// Only `sum`, `count`, `min` and `max` without `DISTINCT` can be split this way.
+/// The rewrite is applied when the statistics estimate that the pre-aggregation
+/// reduces the rows of the join input at least by half.
+pub struct RuleEagerAggregation {
+    id: RuleID,
+    pattern: SExpr,
+    metadata: MetadataRef,
+}
+
+impl RuleEagerAggregation {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::EagerAggregation,
+            //  Aggregate
+            //  \
+            //   *
+            pattern: SExpr::create_unary(
+                PatternPlan {
+                    plan_type: RelOp::Aggregate,
+                }
+                .into(),
+                SExpr::create_leaf(
+                    PatternPlan {
+                        plan_type: RelOp::Pattern,
+                    }
+                    .into(),
+                ),
+            ),
+            metadata,
+        }
+    }
+
+    /// Try to push the aggregation down to the `side` child of the join.
+    fn try_push_down(
+        &self,
+        agg: &Aggregate,
+        eval_scalar: Option<&EvalScalar>,
+        join: &Join,
+        join_expr: &SExpr,
+        side: usize,
+    ) -> Result<Option<SExpr>> {
+        let side_expr = join_expr.child(side)?;
+        if matches!(side_expr.plan(), RelOperator::Aggregate(_)) {
+            return Ok(None);
+        }
+        let side_prop = RelExpr::with_s_expr(side_expr).derive_relational_prop()?;
+
+        // Split the evaluated scalars into the ones computable from this side,
+        // which are pushed down with the aggregation, and the remaining ones.
+        let mut pushed_items = vec![];
+        let mut remaining_items = vec![];
+        if let Some(eval_scalar) = eval_scalar {
+            for item in eval_scalar.items.iter() {
+                if item
+                    .scalar
+                    .used_columns()
+                    .is_subset(&side_prop.output_columns)
+                {
+                    pushed_items.push(item.clone());
+                } else {
+                    remaining_items.push(item.clone());
+                }
+            }
+        }
+        let mut available_columns = side_prop.output_columns.clone();
+        available_columns.extend(pushed_items.iter().map(|item| item.index));
+
+        // All the arguments of the aggregate functions must come from this side.
+        let mut agg_funcs = Vec::with_capacity(agg.aggregate_functions.len());
+        for item in agg.aggregate_functions.iter() {
+            let func = match &item.scalar {
+                ScalarExpr::AggregateFunction(func) => func,
+                _ => return Ok(None),
+            };
+            if !func.args.iter().all(|arg| {
+                arg.used_columns()
+                    .iter()
+                    .all(|column| available_columns.contains(column))
+            }) {
+                return Ok(None);
+            }
+            agg_funcs.push((item.index, func));
+        }
+
+        // The partial aggregation is grouped by all the columns of this side
+        // that are still needed above it.
+        let mut required_columns = ColumnSet::new();
+        for item in agg.group_items.iter() {
+            required_columns.insert(item.index);
+            required_columns.extend(item.scalar.used_columns());
+        }
+        for condition in join
+            .left_conditions
+            .iter()
+            .chain(join.right_conditions.iter())
+            .chain(join.non_equi_conditions.iter())
+        {
+            required_columns.extend(condition.used_columns());
+        }
+        for item in remaining_items.iter() {
+            required_columns.extend(item.scalar.used_columns());
+        }
+        let mut group_columns: Vec<IndexType> = required_columns
+            .intersection(&available_columns)
+            .cloned()
+            .collect();
+        if group_columns.is_empty() {
+            return Ok(None);
+        }
+        group_columns.sort();
+
+        let mut metadata = self.metadata.write();
+        let group_items: Vec<ScalarItem> = group_columns
+            .into_iter()
+            .map(|index| ScalarItem {
+                scalar: BoundColumnRef {
+                    column: column_binding(metadata.column(index), index),
+                }
+                .into(),
+                index,
+            })
+            .collect();
+
+        let mut lower_functions = Vec::with_capacity(agg_funcs.len());
+        let mut upper_functions = Vec::with_capacity(agg_funcs.len());
+        for (index, func) in agg_funcs {
+            let lower_index =
+                metadata.add_derived_column(func.display_name.clone(), *func.return_type.clone());
+            lower_functions.push(ScalarItem {
+                scalar: func.clone().into(),
+                index: lower_index,
+            });
+
+            let func_name = match func.func_name.as_str() {
+                "count" => "sum".to_string(),
+                name => name.to_string(),
+            };
+            upper_functions.push(ScalarItem {
+                scalar: AggregateFunction {
+                    display_name: func.display_name.clone(),
+                    func_name,
+                    distinct: false,
+                    params: vec![],
+                    args: vec![
+                        BoundColumnRef {
+                            column: ColumnBinding {
+                                database_name: None,
+                                table_name: None,
+                                column_name: func.display_name.clone(),
+                                index: lower_index,
+                                data_type: func.return_type.clone(),
+                                visibility: Visibility::Visible,
+                            },
+                        }
+                        .into(),
+                    ],
+                    return_type: func.return_type.clone(),
+                }
+                .into(),
+                index,
+            });
+        }
+        drop(metadata);
+
+        let mut lower_input = side_expr.clone();
+        if !pushed_items.is_empty() {
+            lower_input = SExpr::create_unary(
+                EvalScalar {
+                    items: pushed_items,
+                }
+                .into(),
+                lower_input,
+            );
+        }
+        let input_prop = RelExpr::with_s_expr(&lower_input).derive_relational_prop()?;
+        let lower = SExpr::create_unary(
+            Aggregate {
+                mode: AggregateMode::Initial,
+                group_items,
+                aggregate_functions: lower_functions,
+                from_distinct: agg.from_distinct,
+                limit: None,
+            }
+            .into(),
+            lower_input,
+        );
+
+        // Cost check: skip the rewrite if the pre-aggregation barely reduces the rows.
+        let lower_prop = RelExpr::with_s_expr(&lower).derive_relational_prop()?;
+        if lower_prop.cardinality > input_prop.cardinality * EAGER_AGGREGATION_RATIO {
+            return Ok(None);
+        }
+
+        let mut children = join_expr.children().to_vec();
+        children[side] = lower;
+        let mut result = join_expr.replace_children(children);
+        if !remaining_items.is_empty() {
+            result = SExpr::create_unary(
+                EvalScalar {
+                    items: remaining_items,
+                }
+                .into(),
+                result,
+            );
+        }
+        let mut result = SExpr::create_unary(
+            Aggregate {
+                aggregate_functions: upper_functions,
+                ..agg.clone()
+            }
+            .into(),
+            result,
+        );
+        result.set_applied_rule(&self.id);
+        Ok(Some(result))
+    }
+}
+
+impl Rule for RuleEagerAggregation {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let agg: Aggregate = s_expr.plan().clone().try_into()?;
+        if agg.mode != AggregateMode::Initial || agg.limit.is_some() || agg.group_items.is_empty() {
+            return Ok(());
+        }
+        let splittable = agg.aggregate_functions.iter().all(|item| {
+            matches!(
+                &item.scalar,
+                ScalarExpr::AggregateFunction(func)
+                    if !func.distinct
+                        && matches!(func.func_name.as_str(), "sum" | "count" | "min" | "max")
+            )
+        });
+        if !splittable {
+            return Ok(());
+        }
+
+        let child = s_expr.child(0)?;
+        let (eval_scalar, join_expr) = match child.plan() {
+            RelOperator::EvalScalar(eval_scalar) => (Some(eval_scalar), child.child(0)?),
+            _ => (None, child),
+        };
+        let join = match join_expr.plan() {
+            RelOperator::Join(join)
+                if join.join_type == JoinType::Inner && join.marker_index.is_none() =>
+            {
+                join
+            }
+            _ => return Ok(()),
+        };
+
+        for side in 0..2 {
+            if let Some(result) = self.try_push_down(&agg, eval_scalar, join, join_expr, side)? {
+                state.add_result(result);
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+
+    fn pattern(&self) -> &SExpr {
+        &self.pattern
+    }
+}
+
+fn column_binding(entry: &ColumnEntry, index: IndexType) -> ColumnBinding {
+    let (column_name, data_type) = match entry {
+        ColumnEntry::BaseTableColumn {
+            column_name,
+            data_type,
+            ..
+        }
+        | ColumnEntry::VirtualColumn {
+            column_name,
+            data_type,
+            ..
+        } => (column_name.clone(), DataType::from(data_type)),
+        ColumnEntry::DerivedColumn {
+            alias, data_type, ..
+        } => (alias.clone(), data_type.clone()),
+    };
+    ColumnBinding {
+        database_name: None,
+        table_name: None,
+        column_name,
+        index,
+        data_type: Box::new(data_type),
+        visibility: Visibility::Visible,
+    }
+}
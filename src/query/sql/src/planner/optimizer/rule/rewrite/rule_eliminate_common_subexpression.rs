@@ -0,0 +1,294 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::Result;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+
+use crate::binder::ColumnBinding;
+use crate::binder::Visibility;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::RuleID;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::SExpr;
+use crate::plans::AndExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::ComparisonExpr;
+use crate::plans::EvalScalar;
+use crate::plans::FunctionCall;
+use crate::plans::NotExpr;
+use crate::plans::OrExpr;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::IndexType;
+use crate::MetadataRef;
+
+/// Compute the identical scalar subexpressions of an `EvalScalar` only once.
+///
+/// The subexpressions that appear more than once are evaluated by a new
+/// `EvalScalar` below the original one, and replaced with references to their
+/// results:
+///
+///     EvalScalar(x = (a + b) * 2,              EvalScalar(x = c * 2, y = c + 1)
+///                y = (a + b) + 1)      =>      |
+///     |                                        EvalScalar(c = a + b)
+///     *                                        |
+///                                              *
+///
+/// Subqueries, aggregate functions and non-deterministic functions are never
+/// shared. The arguments of conditional functions are not shared either, since
+/// they may be evaluated only for some of the rows.
+pub struct RuleEliminateCommonSubexpression {
+    id: RuleID,
+    pattern: SExpr,
+    metadata: MetadataRef,
+}
+
+impl RuleEliminateCommonSubexpression {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::EliminateCommonSubexpression,
+            //  EvalScalar
+            //  \
+            //   *
+            pattern: SExpr::create_unary(
+                PatternPlan {
+                    plan_type: RelOp::EvalScalar,
+                }
+                .into(),
+                SExpr::create_leaf(
+                    PatternPlan {
+                        plan_type: RelOp::Pattern,
+                    }
+                    .into(),
+                ),
+            ),
+            metadata,
+        }
+    }
+}
+
+impl Rule for RuleEliminateCommonSubexpression {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let eval_scalar: EvalScalar = s_expr.plan().clone().try_into()?;
+
+        let mut counts = HashMap::new();
+        for item in eval_scalar.items.iter() {
+            count_subexpressions(&item.scalar, &mut counts);
+        }
+        if counts.values().all(|count| *count < 2) {
+            return Ok(());
+        }
+
+        let mut common = CommonSubexpressions {
+            counts: &counts,
+            metadata: &self.metadata,
+            extracted: HashMap::new(),
+            lower_items: vec![],
+        };
+        let upper_items = eval_scalar
+            .items
+            .iter()
+            .map(|item| ScalarItem {
+                scalar: common.replace(&item.scalar),
+                index: item.index,
+            })
+            .collect();
+
+        let mut result = SExpr::create_unary(
+            EvalScalar { items: upper_items }.into(),
+            SExpr::create_unary(
+                EvalScalar {
+                    items: common.lower_items,
+                }
+                .into(),
+                s_expr.child(0)?.clone(),
+            ),
+        );
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn pattern(&self) -> &SExpr {
+        &self.pattern
+    }
+}
+
+/// Count the occurrences of the shareable subexpressions of `scalar`.
+/// Returns whether `scalar` itself can be shared.
+fn count_subexpressions(scalar: &ScalarExpr, counts: &mut HashMap<ScalarExpr, usize>) -> bool {
+    let shareable = match scalar {
+        ScalarExpr::BoundColumnRef(_)
+        | ScalarExpr::ConstantExpr(_)
+        | ScalarExpr::PlaceholderExpr(_) => return true,
+        ScalarExpr::AggregateFunction(_) | ScalarExpr::SubqueryExpr(_) => return false,
+        ScalarExpr::AndExpr(AndExpr { left, right, .. })
+        | ScalarExpr::OrExpr(OrExpr { left, right, .. })
+        | ScalarExpr::ComparisonExpr(ComparisonExpr { left, right, .. }) => {
+            // Count both sides even if the left one can't be shared.
+            let left = count_subexpressions(left, counts);
+            let right = count_subexpressions(right, counts);
+            left && right
+        }
+        ScalarExpr::NotExpr(NotExpr { argument, .. })
+        | ScalarExpr::CastExpr(CastExpr { argument, .. }) => count_subexpressions(argument, counts),
+        ScalarExpr::FunctionCall(func) => {
+            if is_non_deterministic(&func.func_name) {
+                return false;
+            }
+            if is_conditional(&func.func_name) {
+                func.arguments.iter().all(is_shareable)
+            } else {
+                func.arguments
+                    .iter()
+                    .fold(true, |acc, arg| count_subexpressions(arg, counts) && acc)
+            }
+        }
+    };
+    if shareable && !scalar.used_columns().is_empty() {
+        *counts.entry(scalar.clone()).or_insert(0) += 1;
+    }
+    shareable
+}
+
+/// Whether `scalar` can be shared, without counting its subexpressions.
+fn is_shareable(scalar: &ScalarExpr) -> bool {
+    match scalar {
+        ScalarExpr::BoundColumnRef(_)
+        | ScalarExpr::ConstantExpr(_)
+        | ScalarExpr::PlaceholderExpr(_) => true,
+        ScalarExpr::AggregateFunction(_) | ScalarExpr::SubqueryExpr(_) => false,
+        ScalarExpr::AndExpr(AndExpr { left, right, .. })
+        | ScalarExpr::OrExpr(OrExpr { left, right, .. })
+        | ScalarExpr::ComparisonExpr(ComparisonExpr { left, right, .. }) => {
+            is_shareable(left) && is_shareable(right)
+        }
+        ScalarExpr::NotExpr(NotExpr { argument, .. })
+        | ScalarExpr::CastExpr(CastExpr { argument, .. }) => is_shareable(argument),
+        ScalarExpr::FunctionCall(func) => {
+            !is_non_deterministic(&func.func_name) && func.arguments.iter().all(is_shareable)
+        }
+    }
+}
+
+fn is_conditional(func_name: &str) -> bool {
+    matches!(func_name, "if" | "multi_if")
+}
+
+fn is_non_deterministic(func_name: &str) -> bool {
+    let name = BUILTIN_FUNCTIONS
+        .aliases
+        .get(func_name)
+        .map(String::as_str)
+        .unwrap_or(func_name);
+    BUILTIN_FUNCTIONS.funcs.get(name).map_or(false, |funcs| {
+        funcs
+            .iter()
+            .any(|func| func.signature.property.non_deterministic)
+    })
+}
+
+struct CommonSubexpressions<'a> {
+    counts: &'a HashMap<ScalarExpr, usize>,
+    metadata: &'a MetadataRef,
+    /// The column indexes of the subexpressions computed by the lower `EvalScalar`.
+    extracted: HashMap<ScalarExpr, IndexType>,
+    lower_items: Vec<ScalarItem>,
+}
+
+impl CommonSubexpressions<'_> {
+    /// Replace the common subexpressions of `scalar` top-down, so the largest
+    /// shared subexpression is computed once as a whole.
+    fn replace(&mut self, scalar: &ScalarExpr) -> ScalarExpr {
+        if self.counts.get(scalar).map_or(false, |count| *count > 1) {
+            return self.extract(scalar);
+        }
+        match scalar {
+            ScalarExpr::AndExpr(expr) => AndExpr {
+                left: Box::new(self.replace(&expr.left)),
+                right: Box::new(self.replace(&expr.right)),
+                return_type: expr.return_type.clone(),
+            }
+            .into(),
+            ScalarExpr::OrExpr(expr) => OrExpr {
+                left: Box::new(self.replace(&expr.left)),
+                right: Box::new(self.replace(&expr.right)),
+                return_type: expr.return_type.clone(),
+            }
+            .into(),
+            ScalarExpr::ComparisonExpr(expr) => ComparisonExpr {
+                op: expr.op.clone(),
+                left: Box::new(self.replace(&expr.left)),
+                right: Box::new(self.replace(&expr.right)),
+                return_type: expr.return_type.clone(),
+            }
+            .into(),
+            ScalarExpr::NotExpr(expr) => NotExpr {
+                argument: Box::new(self.replace(&expr.argument)),
+                return_type: expr.return_type.clone(),
+            }
+            .into(),
+            ScalarExpr::CastExpr(expr) => CastExpr {
+                argument: Box::new(self.replace(&expr.argument)),
+                ..expr.clone()
+            }
+            .into(),
+            ScalarExpr::FunctionCall(func) if !is_conditional(&func.func_name) => FunctionCall {
+                arguments: func.arguments.iter().map(|arg| self.replace(arg)).collect(),
+                ..func.clone()
+            }
+            .into(),
+            _ => scalar.clone(),
+        }
+    }
+
+    fn extract(&mut self, scalar: &ScalarExpr) -> ScalarExpr {
+        let data_type = scalar.data_type();
+        let index = match self.extracted.get(scalar) {
+            Some(index) => *index,
+            None => {
+                let index = self
+                    .metadata
+                    .write()
+                    .add_derived_column("_cse".to_string(), data_type.clone());
+                self.extracted.insert(scalar.clone(), index);
+                self.lower_items.push(ScalarItem {
+                    scalar: scalar.clone(),
+                    index,
+                });
+                index
+            }
+        };
+        BoundColumnRef {
+            column: ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name: "_cse".to_string(),
+                index,
+                data_type: Box::new(data_type),
+                visibility: Visibility::Visible,
+            },
+        }
+        .into()
+    }
+}
@@ -0,0 +1,302 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::Result;
+
+use crate::binder::ColumnBinding;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::RuleID;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::ComparisonExpr;
+use crate::plans::ComparisonOp;
+use crate::plans::Filter;
+use crate::plans::JoinType;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::IndexType;
+
+/// Infer new predicates from the equivalence classes of the columns of inner joins.
+///
+/// The columns compared by `=`, in the filter or as equi-join conditions of the inner
+/// joins below it, are equivalent. A comparison between a column and a constant holds
+/// for every column equivalent to it, so it's copied for each of them:
+///
+///     Filter(a.k = 5)                     Filter(a.k = 5, b.k = 5)
+///     |                            =>     |
+///     InnerJoin(a.k = b.k)                InnerJoin(a.k = b.k)
+///
+/// The inferred predicates are then pushed down to the scans by `PushDownFilterJoin`,
+/// where they can be used to prune the blocks of both tables. Only columns of the same
+/// type are considered equivalent, so the inferred predicates can be evaluated as-is.
+pub struct RuleInferFilter {
+    id: RuleID,
+    pattern: SExpr,
+}
+
+impl RuleInferFilter {
+    pub fn new() -> Self {
+        Self {
+            id: RuleID::InferFilter,
+            // Filter
+            //  \
+            //   Join
+            //   | \
+            //   |  *
+            //   *
+            pattern: SExpr::create_unary(
+                PatternPlan {
+                    plan_type: RelOp::Filter,
+                }
+                .into(),
+                SExpr::create_binary(
+                    PatternPlan {
+                        plan_type: RelOp::Join,
+                    }
+                    .into(),
+                    SExpr::create_leaf(
+                        PatternPlan {
+                            plan_type: RelOp::Pattern,
+                        }
+                        .into(),
+                    ),
+                    SExpr::create_leaf(
+                        PatternPlan {
+                            plan_type: RelOp::Pattern,
+                        }
+                        .into(),
+                    ),
+                ),
+            ),
+        }
+    }
+}
+
+impl Rule for RuleInferFilter {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let filter: Filter = s_expr.plan().clone().try_into()?;
+        let join_expr = s_expr.child(0)?;
+        if !is_inner_join(join_expr) {
+            return Ok(());
+        }
+
+        let mut classes = EquivalenceClasses::default();
+        for predicate in filter.predicates.iter() {
+            if let Some((left, right)) = column_equality(predicate) {
+                classes.union(left, right);
+            }
+        }
+        collect_join_equalities(join_expr, &mut classes)?;
+
+        let output_columns = RelExpr::with_s_expr(join_expr)
+            .derive_relational_prop()?
+            .output_columns;
+        let mut predicates = filter.predicates.clone();
+        for predicate in filter.predicates.iter() {
+            let (column, comparison) = match constant_comparison(predicate) {
+                Some(comparison) => comparison,
+                None => continue,
+            };
+            for equivalent in classes.equivalent_columns(column.index) {
+                if !output_columns.contains(&equivalent.index) {
+                    continue;
+                }
+                let inferred = replace_column(comparison, &column, equivalent);
+                if !predicates.contains(&inferred) {
+                    predicates.push(inferred);
+                }
+            }
+        }
+        if predicates.len() == filter.predicates.len() {
+            return Ok(());
+        }
+
+        let mut result = SExpr::create_unary(
+            Filter {
+                predicates,
+                is_having: filter.is_having,
+            }
+            .into(),
+            join_expr.clone(),
+        );
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn pattern(&self) -> &SExpr {
+        &self.pattern
+    }
+}
+
+fn is_inner_join(s_expr: &SExpr) -> bool {
+    matches!(
+        s_expr.plan(),
+        RelOperator::Join(join)
+            if matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+    )
+}
+
+/// Collect the equi-join conditions of the tree of inner joins rooted at `s_expr`.
+fn collect_join_equalities(s_expr: &SExpr, classes: &mut EquivalenceClasses) -> Result<()> {
+    if let RelOperator::Join(join) = s_expr.plan() {
+        if !matches!(join.join_type, JoinType::Inner | JoinType::Cross) {
+            return Ok(());
+        }
+        for (left, right) in join
+            .left_conditions
+            .iter()
+            .zip(join.right_conditions.iter())
+        {
+            if let (ScalarExpr::BoundColumnRef(left), ScalarExpr::BoundColumnRef(right)) =
+                (left, right)
+            {
+                if left.column.data_type == right.column.data_type {
+                    classes.union(&left.column, &right.column);
+                }
+            }
+        }
+        for child in s_expr.children() {
+            collect_join_equalities(child, classes)?;
+        }
+    }
+    Ok(())
+}
+
+/// Match `column = column` of the same type.
+fn column_equality(predicate: &ScalarExpr) -> Option<(&ColumnBinding, &ColumnBinding)> {
+    match predicate {
+        ScalarExpr::ComparisonExpr(ComparisonExpr {
+            op: ComparisonOp::Equal,
+            left,
+            right,
+            ..
+        }) => match (left.as_ref(), right.as_ref()) {
+            (ScalarExpr::BoundColumnRef(left), ScalarExpr::BoundColumnRef(right))
+                if left.column.data_type == right.column.data_type =>
+            {
+                Some((&left.column, &right.column))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Match a comparison between a column and a constant, in either order.
+fn constant_comparison(predicate: &ScalarExpr) -> Option<(ColumnBinding, &ComparisonExpr)> {
+    match predicate {
+        ScalarExpr::ComparisonExpr(comparison) => {
+            match (comparison.left.as_ref(), comparison.right.as_ref()) {
+                (ScalarExpr::BoundColumnRef(column), other)
+                | (other, ScalarExpr::BoundColumnRef(column))
+                    if is_constant(other) =>
+                {
+                    Some((column.column.clone(), comparison))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_constant(scalar: &ScalarExpr) -> bool {
+    match scalar {
+        ScalarExpr::ConstantExpr(_) => true,
+        ScalarExpr::CastExpr(cast) => is_constant(&cast.argument),
+        _ => false,
+    }
+}
+
+fn replace_column(
+    comparison: &ComparisonExpr,
+    column: &ColumnBinding,
+    equivalent: &ColumnBinding,
+) -> ScalarExpr {
+    let replace = |scalar: &ScalarExpr| match scalar {
+        ScalarExpr::BoundColumnRef(column_ref) if column_ref.column.index == column.index => {
+            BoundColumnRef {
+                column: equivalent.clone(),
+            }
+            .into()
+        }
+        _ => scalar.clone(),
+    };
+    ComparisonExpr {
+        op: comparison.op.clone(),
+        left: Box::new(replace(&comparison.left)),
+        right: Box::new(replace(&comparison.right)),
+        return_type: comparison.return_type.clone(),
+    }
+    .into()
+}
+
+/// Disjoint sets of equivalent columns.
+#[derive(Default)]
+struct EquivalenceClasses {
+    parents: HashMap<IndexType, IndexType>,
+    columns: HashMap<IndexType, ColumnBinding>,
+}
+
+impl EquivalenceClasses {
+    fn find(&self, mut index: IndexType) -> IndexType {
+        while let Some(parent) = self.parents.get(&index) {
+            if *parent == index {
+                break;
+            }
+            index = *parent;
+        }
+        index
+    }
+
+    fn union(&mut self, left: &ColumnBinding, right: &ColumnBinding) {
+        for column in [left, right] {
+            self.columns
+                .entry(column.index)
+                .or_insert_with(|| column.clone());
+            self.parents.entry(column.index).or_insert(column.index);
+        }
+        let left_root = self.find(left.index);
+        let right_root = self.find(right.index);
+        if left_root != right_root {
+            self.parents.insert(left_root, right_root);
+        }
+    }
+
+    /// The other columns in the same class as `index`, ordered by column index.
+    fn equivalent_columns(&self, index: IndexType) -> Vec<&ColumnBinding> {
+        if !self.columns.contains_key(&index) {
+            return vec![];
+        }
+        let root = self.find(index);
+        let mut columns: Vec<&ColumnBinding> = self
+            .columns
+            .values()
+            .filter(|column| column.index != index && self.find(column.index) == root)
+            .collect();
+        columns.sort_by_key(|column| column.index);
+        columns
+    }
+}
@@ -148,7 +148,9 @@ fn replace_column_binding(
             }
             Ok(ScalarExpr::BoundColumnRef(column))
         }
-        constant_expr @ ScalarExpr::ConstantExpr(_) => Ok(constant_expr),
+        constant_expr @ (ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_)) => {
+            Ok(constant_expr)
+        }
         ScalarExpr::AndExpr(expr) => Ok(ScalarExpr::AndExpr(AndExpr {
             left: Box::new(replace_column_binding(index_pairs, *expr.left)?),
             right: Box::new(replace_column_binding(index_pairs, *expr.right)?),
@@ -38,6 +38,7 @@ pub enum RuleID {
     PushDownFilterEvalScalar,
     PushDownFilterUnion,
     PushDownFilterJoin,
+    InferFilter,
     PushDownFilterScan,
     PushDownLimitUnion,
     PushDownLimitOuterJoin,
@@ -47,10 +48,12 @@ pub enum RuleID {
     PushDownLimitScan,
     PushDownSortScan,
     EliminateEvalScalar,
+    EliminateCommonSubexpression,
     EliminateFilter,
     MergeEvalScalar,
     MergeFilter,
     SplitAggregate,
+    EagerAggregation,
     FoldCountAggregate,
 
     // Exploration rules
@@ -69,6 +72,7 @@ impl Display for RuleID {
             RuleID::PushDownFilterUnion => write!(f, "PushDownFilterUnion"),
             RuleID::PushDownFilterEvalScalar => write!(f, "PushDownFilterEvalScalar"),
             RuleID::PushDownFilterJoin => write!(f, "PushDownFilterJoin"),
+            RuleID::InferFilter => write!(f, "InferFilter"),
             RuleID::PushDownFilterScan => write!(f, "PushDownFilterScan"),
             RuleID::PushDownLimitUnion => write!(f, "PushDownLimitUnion"),
             RuleID::PushDownLimitOuterJoin => write!(f, "PushDownLimitOuterJoin"),
@@ -78,11 +82,13 @@ impl Display for RuleID {
             RuleID::PushDownLimitScan => write!(f, "PushDownLimitScan"),
             RuleID::PushDownSortScan => write!(f, "PushDownSortScan"),
             RuleID::EliminateEvalScalar => write!(f, "EliminateEvalScalar"),
+            RuleID::EliminateCommonSubexpression => write!(f, "EliminateCommonSubexpression"),
             RuleID::EliminateFilter => write!(f, "EliminateFilter"),
             RuleID::MergeEvalScalar => write!(f, "MergeEvalScalar"),
             RuleID::MergeFilter => write!(f, "MergeFilter"),
             RuleID::NormalizeScalarFilter => write!(f, "NormalizeScalarFilter"),
             RuleID::SplitAggregate => write!(f, "SplitAggregate"),
+            RuleID::EagerAggregation => write!(f, "EagerAggregation"),
             RuleID::NormalizeDisjunctiveFilter => write!(f, "NormalizeDisjunctiveFilter"),
             RuleID::FoldCountAggregate => write!(f, "FoldCountAggregate"),
 
@@ -0,0 +1,178 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::binder::ColumnBinding;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::AggregateFunction;
+use crate::plans::AndExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::ComparisonExpr;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::NotExpr;
+use crate::plans::OrExpr;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::IndexType;
+
+/// Rewrite the expressions of the filters and evaluations right over a table scan, and the
+/// predicates pushed down to the scan, into reads of the columns returned by the matcher,
+/// which is called with the expression and the index of the scanned table.
+///
+/// The columns read by the rewritten expressions are added to the columns of the scan.
+pub struct ScanColumnRewriter<F> {
+    matcher: F,
+}
+
+impl<F> ScanColumnRewriter<F>
+where F: Fn(&ScalarExpr, IndexType) -> Option<ColumnBinding>
+{
+    pub fn new(matcher: F) -> Self {
+        Self { matcher }
+    }
+
+    pub fn rewrite(&self, s_expr: &SExpr) -> Result<SExpr> {
+        if let Some(table_index) = Self::scan_table_index(s_expr) {
+            let mut columns = ColumnSet::new();
+            return self.rewrite_scan_chain(s_expr, table_index, &mut columns);
+        }
+
+        let children = s_expr
+            .children()
+            .iter()
+            .map(|child| self.rewrite(child))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(s_expr.replace_children(children))
+    }
+
+    /// The table index of the scan under a chain of filters and evaluations.
+    fn scan_table_index(s_expr: &SExpr) -> Option<IndexType> {
+        match s_expr.plan() {
+            RelOperator::Filter(_) | RelOperator::EvalScalar(_) => {
+                Self::scan_table_index(s_expr.child(0).ok()?)
+            }
+            RelOperator::Scan(scan) => Some(scan.table_index),
+            _ => None,
+        }
+    }
+
+    fn rewrite_scan_chain(
+        &self,
+        s_expr: &SExpr,
+        table_index: IndexType,
+        columns: &mut ColumnSet,
+    ) -> Result<SExpr> {
+        match s_expr.plan() {
+            RelOperator::Filter(filter) => {
+                let filter = Filter {
+                    predicates: filter
+                        .predicates
+                        .iter()
+                        .map(|scalar| self.rewrite_scalar(scalar, table_index, columns))
+                        .collect(),
+                    is_having: filter.is_having,
+                };
+                let child = self.rewrite_scan_chain(s_expr.child(0)?, table_index, columns)?;
+                Ok(SExpr::create_unary(filter.into(), child))
+            }
+            RelOperator::EvalScalar(eval_scalar) => {
+                let eval_scalar = EvalScalar {
+                    items: eval_scalar
+                        .items
+                        .iter()
+                        .map(|item| ScalarItem {
+                            scalar: self.rewrite_scalar(&item.scalar, table_index, columns),
+                            index: item.index,
+                        })
+                        .collect(),
+                };
+                let child = self.rewrite_scan_chain(s_expr.child(0)?, table_index, columns)?;
+                Ok(SExpr::create_unary(eval_scalar.into(), child))
+            }
+            RelOperator::Scan(scan) => {
+                let mut scan = scan.clone();
+                scan.push_down_predicates = scan.push_down_predicates.map(|predicates| {
+                    predicates
+                        .iter()
+                        .map(|scalar| self.rewrite_scalar(scalar, table_index, columns))
+                        .collect()
+                });
+                scan.columns.extend(columns.iter().copied());
+                Ok(SExpr::create_leaf(scan.into()))
+            }
+            _ => Ok(s_expr.clone()),
+        }
+    }
+
+    fn rewrite_scalar(
+        &self,
+        scalar: &ScalarExpr,
+        table_index: IndexType,
+        columns: &mut ColumnSet,
+    ) -> ScalarExpr {
+        if let Some(column) = (self.matcher)(scalar, table_index) {
+            columns.insert(column.index);
+            return ScalarExpr::BoundColumnRef(BoundColumnRef { column });
+        }
+
+        let mut rewrite =
+            |scalar: &ScalarExpr| Box::new(self.rewrite_scalar(scalar, table_index, columns));
+        match scalar {
+            ScalarExpr::AndExpr(expr) => ScalarExpr::AndExpr(AndExpr {
+                left: rewrite(&expr.left),
+                right: rewrite(&expr.right),
+                return_type: expr.return_type.clone(),
+            }),
+            ScalarExpr::OrExpr(expr) => ScalarExpr::OrExpr(OrExpr {
+                left: rewrite(&expr.left),
+                right: rewrite(&expr.right),
+                return_type: expr.return_type.clone(),
+            }),
+            ScalarExpr::NotExpr(expr) => ScalarExpr::NotExpr(NotExpr {
+                argument: rewrite(&expr.argument),
+                return_type: expr.return_type.clone(),
+            }),
+            ScalarExpr::ComparisonExpr(expr) => ScalarExpr::ComparisonExpr(ComparisonExpr {
+                op: expr.op.clone(),
+                left: rewrite(&expr.left),
+                right: rewrite(&expr.right),
+                return_type: expr.return_type.clone(),
+            }),
+            ScalarExpr::AggregateFunction(expr) => {
+                ScalarExpr::AggregateFunction(AggregateFunction {
+                    args: expr.args.iter().map(|arg| *rewrite(arg)).collect(),
+                    ..expr.clone()
+                })
+            }
+            ScalarExpr::FunctionCall(expr) => ScalarExpr::FunctionCall(FunctionCall {
+                arguments: expr.arguments.iter().map(|arg| *rewrite(arg)).collect(),
+                ..expr.clone()
+            }),
+            ScalarExpr::CastExpr(expr) => ScalarExpr::CastExpr(CastExpr {
+                argument: rewrite(&expr.argument),
+                ..expr.clone()
+            }),
+            ScalarExpr::BoundColumnRef(_)
+            | ScalarExpr::ConstantExpr(_)
+            | ScalarExpr::PlaceholderExpr(_)
+            | ScalarExpr::SubqueryExpr(_) => scalar.clone(),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Literal;
+use storages_common_table_meta::table::VirtualColumnKey;
+
+use crate::binder::ColumnBinding;
+use crate::binder::Visibility;
+use crate::optimizer::scan_column_rewriter::ScanColumnRewriter;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::FunctionCall;
+use crate::plans::ScalarExpr;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MetadataRef;
+
+/// Rewrite the accesses to the paths of variant columns, like `v:user.id` or
+/// `CAST(v:user.id AS BIGINT)`, into reads of the virtual columns of the table, so that
+/// the values are read directly instead of decoding the whole variant values.
+pub fn rewrite_with_virtual_columns(metadata: MetadataRef, s_expr: SExpr) -> Result<SExpr> {
+    if !metadata.read().has_virtual_columns() {
+        return Ok(s_expr);
+    }
+    ScanColumnRewriter::new(|scalar: &ScalarExpr, table_index| {
+        match_virtual_column(&metadata, scalar, table_index)
+    })
+    .rewrite(&s_expr)
+}
+
+/// Match the access to a path of a variant column of the scanned table, or the cast of
+/// it, with a virtual column of the table.
+fn match_virtual_column(
+    metadata: &MetadataRef,
+    scalar: &ScalarExpr,
+    table_index: IndexType,
+) -> Option<ColumnBinding> {
+    let (access, cast_type) = match scalar {
+        ScalarExpr::CastExpr(CastExpr {
+            is_try: false,
+            argument,
+            target_type,
+            ..
+        }) => (&**argument, target_type.remove_nullable()),
+        _ => (scalar, DataType::Variant),
+    };
+
+    let mut keys = vec![];
+    let mut expr = access;
+    while let ScalarExpr::FunctionCall(FunctionCall {
+        params,
+        arguments,
+        func_name,
+        ..
+    }) = expr
+    {
+        if func_name != "get" || !params.is_empty() || arguments.len() != 2 {
+            return None;
+        }
+        let key = match &arguments[1] {
+            ScalarExpr::ConstantExpr(constant) => match &constant.value {
+                Literal::String(name) => {
+                    VirtualColumnKey::Name(String::from_utf8(name.clone()).ok()?)
+                }
+                Literal::UInt8(index) => VirtualColumnKey::Index(*index as u64),
+                Literal::UInt16(index) => VirtualColumnKey::Index(*index as u64),
+                Literal::UInt32(index) => VirtualColumnKey::Index(*index as u64),
+                Literal::UInt64(index) => VirtualColumnKey::Index(*index),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        keys.push(key);
+        expr = &arguments[0];
+    }
+    if keys.is_empty() {
+        return None;
+    }
+    keys.reverse();
+
+    let ScalarExpr::BoundColumnRef(BoundColumnRef { column }) = expr else {
+        return None;
+    };
+    let metadata = metadata.read();
+    let source_column_name = match metadata.column(column.index) {
+        ColumnEntry::BaseTableColumn {
+            table_index: index,
+            column_name,
+            path_indices: None,
+            ..
+        } if *index == table_index => column_name.clone(),
+        _ => return None,
+    };
+    let path = VirtualColumnKey::format_path(&keys);
+    let return_type = scalar.data_type();
+
+    metadata
+        .virtual_columns_by_table_index(table_index)
+        .into_iter()
+        .find_map(|entry| match entry {
+            ColumnEntry::VirtualColumn {
+                column_index,
+                column_name,
+                source_column_name: source,
+                path: virtual_path,
+                cast_type: virtual_cast_type,
+                data_type,
+                ..
+            } if source == source_column_name
+                && virtual_path == path
+                && DataType::from(&virtual_cast_type) == cast_type
+                && DataType::from(&data_type) == return_type =>
+            {
+                Some(ColumnBinding {
+                    database_name: None,
+                    table_name: None,
+                    column_name,
+                    index: column_index,
+                    data_type: Box::new(return_type.clone()),
+                    visibility: Visibility::InVisible,
+                })
+            }
+            _ => None,
+        })
+}
@@ -26,9 +26,11 @@ use common_ast::Backtrace;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::DataType;
 use parking_lot::RwLock;
 
 use super::semantic::DistinctToGroupBy;
+use super::semantic::PlaceholderNumbering;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
@@ -43,11 +45,33 @@ const PROBE_INSERT_MAX_TOKENS: usize = 128 * 8;
 
 pub struct Planner {
     ctx: Arc<dyn TableContext>,
+    /// Types of the parameters bound to the placeholders of a prepared statement.
+    parameter_types: Vec<DataType>,
+    /// Whether the types of the parameters not bound are inferred.
+    infer_parameter_types: bool,
 }
 
 impl Planner {
     pub fn new(ctx: Arc<dyn TableContext>) -> Self {
-        Planner { ctx }
+        Planner {
+            ctx,
+            parameter_types: vec![],
+            infer_parameter_types: false,
+        }
+    }
+
+    /// Plan the placeholders `?` and `$n` as the parameters of given types,
+    /// which are bound to the optimized plan with [`crate::bind_parameters`].
+    pub fn with_parameter_types(mut self, parameter_types: Vec<DataType>) -> Self {
+        self.parameter_types = parameter_types;
+        self
+    }
+
+    /// Plan the placeholders without bound parameters, the types of which are inferred into
+    /// the metadata, see [`crate::Metadata::inferred_parameter_types`].
+    pub fn with_inferred_parameter_types(mut self) -> Self {
+        self.infer_parameter_types = true;
+        self
     }
 
     pub async fn plan_sql(&mut self, sql: &str) -> Result<(Plan, MetadataRef, Option<String>)> {
@@ -82,10 +106,15 @@ impl Planner {
                 // Step 2: Parse the SQL.
                 let backtrace = Backtrace::new();
                 let (mut stmt, format) = parse_sql(&tokens, sql_dialect, &backtrace)?;
-                self.replace_stmt(&mut stmt);
+                self.replace_stmt(&mut stmt, &tokens);
 
                 // Step 3: Bind AST with catalog, and generate a pure logical SExpr
-                let metadata = Arc::new(RwLock::new(Metadata::default()));
+                let mut metadata = Metadata::default();
+                metadata.set_parameter_types(self.parameter_types.clone());
+                if self.infer_parameter_types {
+                    metadata.set_infer_parameter_types();
+                }
+                let metadata = Arc::new(RwLock::new(metadata));
                 let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
                 let binder = Binder::new(
                     self.ctx.clone(),
@@ -148,7 +177,9 @@ impl Planner {
         }
     }
 
-    fn replace_stmt(&self, stmt: &mut Statement) {
+    fn replace_stmt(&self, stmt: &mut Statement, tokens: &[Token]) {
+        walk_statement_mut(&mut PlaceholderNumbering::new(tokens), stmt);
+
         let mut visitors = vec![DistinctToGroupBy::default()];
         for v in visitors.iter_mut() {
             walk_statement_mut(v, stmt)
@@ -14,15 +14,21 @@
 mod account;
 mod catalog;
 mod database;
+mod index;
 mod stage;
+mod stream;
 mod table;
 mod udf;
 mod view;
+mod virtual_column;
 
 pub use account::*;
 pub use catalog::*;
 pub use database::*;
+pub use index::*;
 pub use stage::*;
+pub use stream::*;
 pub use table::*;
 pub use udf::*;
 pub use view::*;
+pub use virtual_column::*;
@@ -28,6 +28,7 @@ use common_meta_app::schema::DropTableReq;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
 use common_storage::StorageParams;
+use storages_common_table_meta::table::SnapshotRefKind;
 
 use crate::plans::Plan;
 
@@ -50,6 +51,7 @@ pub struct CreateTablePlanV2 {
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
+    pub clone_source: Option<CloneTableSource>,
 }
 
 impl CreateTablePlanV2 {
@@ -58,6 +60,15 @@ impl CreateTablePlanV2 {
     }
 }
 
+/// The table of `CREATE TABLE ... CLONE`, whose data at `point` the new table shares.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloneTableSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub point: Option<NavigationPoint>,
+}
+
 /// Desc.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeTablePlan {
@@ -142,6 +153,58 @@ impl AnalyzeTablePlan {
     }
 }
 
+/// Vacuum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VacuumTablePlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub option: VacuumTableOption,
+}
+
+impl VacuumTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        if self.option.dry_run {
+            Arc::new(DataSchema::new(vec![DataField::new(
+                "file",
+                DataType::String,
+            )]))
+        } else {
+            Arc::new(DataSchema::empty())
+        }
+    }
+}
+
+/// Purges the storage of the tables dropped out of the retention period, of the given database
+/// or else all the databases of the catalog.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VacuumDropTablePlan {
+    pub catalog: String,
+    pub database: Option<String>,
+    pub option: VacuumTableOption,
+}
+
+impl VacuumDropTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        if self.option.dry_run {
+            Arc::new(DataSchema::new(vec![
+                DataField::new("database", DataType::String),
+                DataField::new("table", DataType::String),
+                DataField::new("file", DataType::String),
+            ]))
+        } else {
+            Arc::new(DataSchema::empty())
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VacuumTableOption {
+    /// Defaults to the `retention_period` setting.
+    pub retain_hours: Option<u64>,
+    pub dry_run: bool,
+}
+
 /// Rename.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RenameTablePlan {
@@ -274,3 +337,53 @@ impl DropTableClusterKeyPlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+/// Tag or branch.
+#[derive(Clone, Debug)]
+pub struct CreateSnapshotRefPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub name: String,
+    pub kind: SnapshotRefKind,
+    /// The snapshot of the tag or branch, the current snapshot of the table if not specified.
+    pub point: Option<NavigationPoint>,
+}
+
+impl CreateSnapshotRefPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DropSnapshotRefPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub name: String,
+    pub kind: SnapshotRefKind,
+}
+
+impl DropSnapshotRefPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FastForwardTablePlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub branch: String,
+}
+
+impl FastForwardTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
@@ -15,6 +15,9 @@ use std::sync::Arc;
 
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_expression::TableSchemaRef;
+
+use crate::plans::TableOptions;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateViewPlan {
@@ -63,3 +66,156 @@ impl DropViewPlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+/// A column of a materialized view, in the order of the defining query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MaterializedViewColumn {
+    /// A group key, which is a column of the base table.
+    GroupKey { column: String },
+    /// A decomposable aggregate function over a column of the base table,
+    /// the argument is `None` for `COUNT(*)`.
+    Aggregate {
+        func_name: String,
+        arg: Option<String>,
+    },
+}
+
+impl MaterializedViewColumn {
+    /// The aggregate function to merge the partial results of the given aggregate function.
+    pub fn merge_func_name(func_name: &str) -> Option<&'static str> {
+        match func_name {
+            "sum" | "count" => Some("sum"),
+            "min" => Some("min"),
+            "max" => Some("max"),
+            _ => None,
+        }
+    }
+}
+
+/// The definition of a materialized view, which must be an aggregation
+/// `SELECT <group keys and aggregates> FROM <table> GROUP BY <group keys>`
+/// over a fuse table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaterializedViewDefinition {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub columns: Vec<MaterializedViewColumn>,
+}
+
+impl MaterializedViewDefinition {
+    /// The defining query, with the columns named by `column_names`.
+    ///
+    /// All the identifiers are quoted with backticks, so that it can be parsed with any dialect,
+    /// which requires the identifiers not to contain backticks.
+    pub fn query(&self, column_names: &[String]) -> String {
+        let items = self
+            .columns
+            .iter()
+            .zip(column_names)
+            .map(|(column, name)| match column {
+                MaterializedViewColumn::GroupKey { column } => {
+                    format!("{} AS {}", quote_ident(column), quote_ident(name))
+                }
+                MaterializedViewColumn::Aggregate { func_name, arg } => format!(
+                    "{}({}) AS {}",
+                    func_name,
+                    arg.as_deref().map_or("*".to_string(), quote_ident),
+                    quote_ident(name)
+                ),
+            })
+            .collect::<Vec<_>>();
+        let group_keys = self
+            .columns
+            .iter()
+            .filter_map(|column| match column {
+                MaterializedViewColumn::GroupKey { column } => Some(quote_ident(column)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        format!(
+            "SELECT {} FROM {}.{}.{} GROUP BY {}",
+            items.join(", "),
+            quote_ident(&self.catalog),
+            quote_ident(&self.database),
+            quote_ident(&self.table),
+            group_keys.join(", ")
+        )
+    }
+
+    /// The query to merge the rows of the view `catalog.database.view` with the rows of the
+    /// defining query, which produces the refreshed rows of the view.
+    pub fn merge_query(
+        &self,
+        catalog: &str,
+        database: &str,
+        view: &str,
+        column_names: &[String],
+    ) -> String {
+        let mut group_keys = vec![];
+        let items = self
+            .columns
+            .iter()
+            .zip(column_names)
+            .map(|(column, name)| match column {
+                MaterializedViewColumn::GroupKey { .. } => {
+                    group_keys.push(quote_ident(name));
+                    quote_ident(name)
+                }
+                MaterializedViewColumn::Aggregate { func_name, .. } => format!(
+                    "{}({}) AS {}",
+                    MaterializedViewColumn::merge_func_name(func_name).unwrap_or(func_name),
+                    quote_ident(name),
+                    quote_ident(name)
+                ),
+            })
+            .collect::<Vec<_>>();
+        format!(
+            "SELECT {} FROM (SELECT * FROM {}.{}.{} UNION ALL {}) AS _materialized_view GROUP BY {}",
+            items.join(", "),
+            quote_ident(catalog),
+            quote_ident(database),
+            quote_ident(view),
+            self.query(column_names),
+            group_keys.join(", ")
+        )
+    }
+}
+
+fn quote_ident(ident: &str) -> String {
+    format!("`{}`", ident)
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateMaterializedViewPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub viewname: String,
+    /// Schema and options of the fuse table storing the rows of the view.
+    pub schema: TableSchemaRef,
+    pub options: TableOptions,
+    pub definition: MaterializedViewDefinition,
+}
+
+impl CreateMaterializedViewPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshMaterializedViewPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub viewname: String,
+    pub definition: MaterializedViewDefinition,
+}
+
+impl RefreshMaterializedViewPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
@@ -51,6 +51,8 @@ pub struct Insert {
     pub table_id: MetaId,
     pub schema: TableSchemaRef,
     pub overwrite: bool,
+    /// Write to the given branch instead of the main snapshot chain.
+    pub branch: Option<String>,
     pub source: InsertInputSource,
 }
 
@@ -60,6 +62,7 @@ impl PartialEq for Insert {
             && self.database == other.database
             && self.table == other.table
             && self.schema == other.schema
+            && self.branch == other.branch
     }
 }
 
@@ -82,6 +85,7 @@ impl std::fmt::Debug for Insert {
             .field("table_id", &self.table_id)
             .field("schema", &self.schema)
             .field("overwrite", &self.overwrite)
+            .field("branch", &self.branch)
             .finish()
     }
 }
@@ -0,0 +1,90 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// Compute a common table expression once before running the query that references it.
+///
+/// The left child is the definition of the CTE, whose result is buffered and read by
+/// every `CteScan` with the same `cte_idx` in the right child. The output is the output
+/// of the right child.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MaterializedCte {
+    pub cte_idx: IndexType,
+    /// Columns of the left child that make up the CTE, in order.
+    pub left_output_columns: Vec<IndexType>,
+}
+
+impl MaterializedCte {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        Ok(self.left_output_columns.iter().cloned().collect())
+    }
+}
+
+impl Operator for MaterializedCte {
+    fn rel_op(&self) -> RelOp {
+        RelOp::MaterializedCte
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        let left_prop = rel_expr.derive_relational_prop_child(0)?;
+        let right_prop = rel_expr.derive_relational_prop_child(1)?;
+
+        // Derive outer columns
+        let outer_columns = left_prop
+            .outer_columns
+            .union(&right_prop.outer_columns)
+            .cloned()
+            .collect();
+
+        // Derive used columns
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(left_prop.used_columns);
+        used_columns.extend(right_prop.used_columns);
+
+        Ok(RelationalProperty {
+            output_columns: right_prop.output_columns,
+            outer_columns,
+            used_columns,
+            cardinality: right_prop.cardinality,
+            statistics: right_prop.statistics,
+        })
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(1)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
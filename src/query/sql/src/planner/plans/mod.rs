@@ -15,6 +15,7 @@
 mod aggregate;
 mod call;
 mod copy_v2;
+mod cte_scan;
 mod ddl;
 mod delete;
 mod dummy_table_scan;
@@ -26,11 +27,14 @@ mod join;
 mod kill;
 mod limit;
 mod list;
+mod materialized_cte;
 mod operator;
 mod pattern;
 mod plan;
 mod presign;
+mod project_set;
 mod recluster_table;
+mod recursive_cte;
 mod revert_table;
 mod scalar;
 mod scan;
@@ -43,6 +47,7 @@ mod update;
 pub use aggregate::*;
 pub use call::CallPlan;
 pub use copy_v2::*;
+pub use cte_scan::CteScan;
 pub use ddl::*;
 pub use delete::DeletePlan;
 pub use dummy_table_scan::DummyTableScan;
@@ -55,13 +60,16 @@ pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
 pub use list::ListPlan;
+pub use materialized_cte::MaterializedCte;
 pub use operator::*;
 pub use pattern::PatternPlan;
 pub use plan::Plan::*;
 pub use plan::RewriteKind::*;
 pub use plan::*;
 pub use presign::*;
+pub use project_set::ProjectSet;
 pub use recluster_table::ReclusterTablePlan;
+pub use recursive_cte::RecursiveCte;
 pub use revert_table::RevertTablePlan;
 pub use scalar::*;
 pub use scan::*;
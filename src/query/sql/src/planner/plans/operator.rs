@@ -19,12 +19,16 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 
 use super::aggregate::Aggregate;
+use super::cte_scan::CteScan;
 use super::dummy_table_scan::DummyTableScan;
 use super::eval_scalar::EvalScalar;
 use super::filter::Filter;
 use super::join::Join;
 use super::limit::Limit;
+use super::materialized_cte::MaterializedCte;
 use super::pattern::PatternPlan;
+use super::project_set::ProjectSet;
+use super::recursive_cte::RecursiveCte;
 use super::scan::Scan;
 use super::sort::Sort;
 use super::union_all::UnionAll;
@@ -67,6 +71,10 @@ pub enum RelOp {
     Exchange,
     UnionAll,
     DummyTableScan,
+    ProjectSet,
+    CteScan,
+    MaterializedCte,
+    RecursiveCte,
 
     // Pattern
     Pattern,
@@ -85,6 +93,10 @@ pub enum RelOperator {
     Exchange(Exchange),
     UnionAll(UnionAll),
     DummyTableScan(DummyTableScan),
+    ProjectSet(ProjectSet),
+    CteScan(CteScan),
+    MaterializedCte(MaterializedCte),
+    RecursiveCte(RecursiveCte),
 
     Pattern(PatternPlan),
 }
@@ -103,6 +115,10 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.rel_op(),
             RelOperator::UnionAll(rel_op) => rel_op.rel_op(),
             RelOperator::DummyTableScan(rel_op) => rel_op.rel_op(),
+            RelOperator::ProjectSet(rel_op) => rel_op.rel_op(),
+            RelOperator::CteScan(rel_op) => rel_op.rel_op(),
+            RelOperator::MaterializedCte(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCte(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -119,6 +135,10 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::UnionAll(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::ProjectSet(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::CteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::MaterializedCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -135,6 +155,10 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::UnionAll(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::ProjectSet(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::CteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::MaterializedCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -179,6 +203,18 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::ProjectSet(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::CteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::MaterializedCte(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::RecursiveCte(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -386,3 +422,79 @@ impl TryFrom<RelOperator> for DummyTableScan {
         }
     }
 }
+
+impl From<ProjectSet> for RelOperator {
+    fn from(v: ProjectSet) -> Self {
+        Self::ProjectSet(v)
+    }
+}
+
+impl TryFrom<RelOperator> for ProjectSet {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::ProjectSet(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to ProjectSet",
+            ))
+        }
+    }
+}
+
+impl From<CteScan> for RelOperator {
+    fn from(v: CteScan) -> Self {
+        Self::CteScan(v)
+    }
+}
+
+impl TryFrom<RelOperator> for CteScan {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::CteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to CteScan",
+            ))
+        }
+    }
+}
+
+impl From<MaterializedCte> for RelOperator {
+    fn from(v: MaterializedCte) -> Self {
+        Self::MaterializedCte(v)
+    }
+}
+
+impl TryFrom<RelOperator> for MaterializedCte {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::MaterializedCte(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to MaterializedCte",
+            ))
+        }
+    }
+}
+
+impl From<RecursiveCte> for RelOperator {
+    fn from(v: RecursiveCte) -> Self {
+        Self::RecursiveCte(v)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCte {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::RecursiveCte(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCte",
+            ))
+        }
+    }
+}
@@ -44,29 +44,40 @@ use crate::plans::AnalyzeTablePlan;
 use crate::plans::CallPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
+use crate::plans::CreateInvertedIndexPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateSnapshotRefPlan;
 use crate::plans::CreateStagePlan;
+use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTablePlanV2;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
+use crate::plans::CreateVirtualColumnPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropDatabasePlan;
+use crate::plans::DropInvertedIndexPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropSnapshotRefPlan;
 use crate::plans::DropStagePlan;
+use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
+use crate::plans::DropVirtualColumnPlan;
 use crate::plans::ExistsTablePlan;
+use crate::plans::FastForwardTablePlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::KillPlan;
 use crate::plans::ListPlan;
 use crate::plans::OptimizeTablePlan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
 use crate::plans::RenameTablePlan;
@@ -86,6 +97,8 @@ use crate::plans::UndropDatabasePlan;
 use crate::plans::UndropTablePlan;
 use crate::plans::UpdatePlan;
 use crate::plans::UseDatabasePlan;
+use crate::plans::VacuumDropTablePlan;
+use crate::plans::VacuumTablePlan;
 use crate::BindContext;
 use crate::MetadataRef;
 
@@ -141,9 +154,14 @@ pub enum Plan {
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
     RevertTable(Box<RevertTablePlan>),
+    CreateSnapshotRef(Box<CreateSnapshotRefPlan>),
+    DropSnapshotRef(Box<DropSnapshotRefPlan>),
+    FastForwardTable(Box<FastForwardTablePlan>),
     TruncateTable(Box<TruncateTablePlan>),
     OptimizeTable(Box<OptimizeTablePlan>),
     AnalyzeTable(Box<AnalyzeTablePlan>),
+    VacuumTable(Box<VacuumTablePlan>),
+    VacuumDropTable(Box<VacuumDropTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
 
     // Insert
@@ -155,6 +173,20 @@ pub enum Plan {
     CreateView(Box<CreateViewPlan>),
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
+
+    // Indexes
+    CreateInvertedIndex(Box<CreateInvertedIndexPlan>),
+    DropInvertedIndex(Box<DropInvertedIndexPlan>),
+
+    // Virtual columns
+    CreateVirtualColumn(Box<CreateVirtualColumnPlan>),
+    DropVirtualColumn(Box<DropVirtualColumnPlan>),
+
+    // Streams
+    CreateStream(Box<CreateStreamPlan>),
+    DropStream(Box<DropStreamPlan>),
 
     // Account
     AlterUser(Box<AlterUserPlan>),
@@ -250,10 +282,20 @@ impl Display for Plan {
             Plan::TruncateTable(_) => write!(f, "TruncateTable"),
             Plan::OptimizeTable(_) => write!(f, "OptimizeTable"),
             Plan::AnalyzeTable(_) => write!(f, "AnalyzeTable"),
+            Plan::VacuumTable(_) => write!(f, "VacuumTable"),
+            Plan::VacuumDropTable(_) => write!(f, "VacuumDropTable"),
             Plan::ExistsTable(_) => write!(f, "ExistsTable"),
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateMaterializedView(_) => write!(f, "CreateMaterializedView"),
+            Plan::RefreshMaterializedView(_) => write!(f, "RefreshMaterializedView"),
+            Plan::CreateInvertedIndex(_) => write!(f, "CreateInvertedIndex"),
+            Plan::DropInvertedIndex(_) => write!(f, "DropInvertedIndex"),
+            Plan::CreateVirtualColumn(_) => write!(f, "CreateVirtualColumn"),
+            Plan::DropVirtualColumn(_) => write!(f, "DropVirtualColumn"),
+            Plan::CreateStream(_) => write!(f, "CreateStream"),
+            Plan::DropStream(_) => write!(f, "DropStream"),
             Plan::AlterUser(_) => write!(f, "AlterUser"),
             Plan::CreateUser(_) => write!(f, "CreateUser"),
             Plan::DropUser(_) => write!(f, "DropUser"),
@@ -293,6 +335,9 @@ impl Display for Plan {
             Plan::ExplainAst { .. } => write!(f, "ExplainAst"),
             Plan::ExplainSyntax { .. } => write!(f, "ExplainSyntax"),
             Plan::RevertTable(..) => write!(f, "RevertTable"),
+            Plan::CreateSnapshotRef(..) => write!(f, "CreateSnapshotRef"),
+            Plan::DropSnapshotRef(..) => write!(f, "DropSnapshotRef"),
+            Plan::FastForwardTable(..) => write!(f, "FastForwardTable"),
         }
     }
 }
@@ -333,10 +378,20 @@ impl Plan {
             Plan::TruncateTable(plan) => plan.schema(),
             Plan::OptimizeTable(plan) => plan.schema(),
             Plan::AnalyzeTable(plan) => plan.schema(),
+            Plan::VacuumTable(plan) => plan.schema(),
+            Plan::VacuumDropTable(plan) => plan.schema(),
             Plan::ExistsTable(plan) => plan.schema(),
             Plan::CreateView(plan) => plan.schema(),
             Plan::AlterView(plan) => plan.schema(),
             Plan::DropView(plan) => plan.schema(),
+            Plan::CreateMaterializedView(plan) => plan.schema(),
+            Plan::RefreshMaterializedView(plan) => plan.schema(),
+            Plan::CreateInvertedIndex(plan) => plan.schema(),
+            Plan::DropInvertedIndex(plan) => plan.schema(),
+            Plan::CreateVirtualColumn(plan) => plan.schema(),
+            Plan::DropVirtualColumn(plan) => plan.schema(),
+            Plan::CreateStream(plan) => plan.schema(),
+            Plan::DropStream(plan) => plan.schema(),
             Plan::AlterUser(plan) => plan.schema(),
             Plan::CreateUser(plan) => plan.schema(),
             Plan::DropUser(plan) => plan.schema(),
@@ -374,6 +429,9 @@ impl Plan {
             Plan::ShowObjectGrantPrivileges(plan) => plan.schema(),
             Plan::ShowGrantTenantsOfShare(plan) => plan.schema(),
             Plan::RevertTable(plan) => plan.schema(),
+            Plan::CreateSnapshotRef(plan) => plan.schema(),
+            Plan::DropSnapshotRef(plan) => plan.schema(),
+            Plan::FastForwardTable(plan) => plan.schema(),
         }
     }
 }
@@ -0,0 +1,112 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
+use crate::IndexType;
+
+/// Evaluate a set-returning function such as `unnest` for each row of the input,
+/// the input row is repeated for each generated value and the rows generating
+/// no values are removed. It's the lateral apply of a set-returning table function
+/// whose arguments reference the columns of the preceding tables.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProjectSet {
+    pub func_name: String,
+    pub args: Vec<ScalarExpr>,
+    pub return_type: Box<DataType>,
+    /// The index of the column of the generated values
+    pub index: IndexType,
+}
+
+impl ProjectSet {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        used_columns.insert(self.index);
+        for arg in self.args.iter() {
+            used_columns.extend(arg.used_columns());
+        }
+        Ok(used_columns)
+    }
+}
+
+impl Operator for ProjectSet {
+    fn rel_op(&self) -> RelOp {
+        RelOp::ProjectSet
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(0)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns
+        let mut output_columns = input_prop.output_columns;
+        output_columns.insert(self.index);
+
+        // Derive outer columns
+        let mut outer_columns = input_prop.outer_columns;
+        for arg in self.args.iter() {
+            let outer = arg
+                .used_columns()
+                .difference(&output_columns)
+                .cloned()
+                .collect::<ColumnSet>();
+            outer_columns = outer_columns.union(&outer).cloned().collect();
+        }
+        outer_columns = outer_columns.difference(&output_columns).cloned().collect();
+
+        // The number of generated values is unknown, so we take the cardinality of the input.
+        let cardinality = input_prop.cardinality;
+        // Derive used columns
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(input_prop.used_columns);
+
+        Ok(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+            cardinality,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+                is_accurate: false,
+            },
+        })
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// Evaluate a recursive common table expression.
+///
+/// The left child is the anchor term, which is evaluated once to produce the initial
+/// working table. The right child is the recursive term, whose `CteScan` with the same
+/// `cte_idx` reads the working table. The recursive term is evaluated repeatedly, each
+/// time on the output of the previous iteration, until it produces no rows. The output
+/// is the union of the output of all iterations.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecursiveCte {
+    pub cte_idx: IndexType,
+    /// Discard the rows that have been produced already, for `UNION` without `ALL`.
+    pub distinct: bool,
+    /// Columns of the anchor term that make up the result, in order.
+    pub left_output_columns: Vec<IndexType>,
+    /// Columns of the recursive term that make up the result, in order.
+    pub right_output_columns: Vec<IndexType>,
+}
+
+impl RecursiveCte {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        Ok(self
+            .left_output_columns
+            .iter()
+            .chain(self.right_output_columns.iter())
+            .cloned()
+            .collect())
+    }
+}
+
+impl Operator for RecursiveCte {
+    fn rel_op(&self) -> RelOp {
+        RelOp::RecursiveCte
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        let left_prop = rel_expr.derive_relational_prop_child(0)?;
+        let right_prop = rel_expr.derive_relational_prop_child(1)?;
+
+        // Derive output columns
+        let output_columns = self.left_output_columns.iter().cloned().collect();
+
+        // Derive outer columns
+        let outer_columns = left_prop
+            .outer_columns
+            .union(&right_prop.outer_columns)
+            .cloned()
+            .collect();
+
+        // Derive used columns
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(left_prop.used_columns);
+        used_columns.extend(right_prop.used_columns);
+
+        // The number of iterations is unknown, estimate with a single iteration.
+        let cardinality = left_prop.cardinality + right_prop.cardinality;
+
+        Ok(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+            cardinality,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+                is_accurate: false,
+            },
+        })
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        _required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(RequiredProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+}
@@ -39,6 +39,7 @@ pub enum ScalarExpr {
     // after making functions static typed?
     CastExpr(CastExpr),
     SubqueryExpr(SubqueryExpr),
+    PlaceholderExpr(PlaceholderExpr),
 }
 
 impl ScalarExpr {
@@ -54,6 +55,7 @@ impl ScalarExpr {
             ScalarExpr::FunctionCall(scalar) => (*scalar.return_type).clone(),
             ScalarExpr::CastExpr(scalar) => (*scalar.target_type).clone(),
             ScalarExpr::SubqueryExpr(scalar) => scalar.data_type(),
+            ScalarExpr::PlaceholderExpr(scalar) => (*scalar.data_type).clone(),
         }
     }
 
@@ -93,6 +95,7 @@ impl ScalarExpr {
             }
             ScalarExpr::CastExpr(scalar) => scalar.argument.used_columns(),
             ScalarExpr::SubqueryExpr(scalar) => scalar.outer_columns.clone(),
+            ScalarExpr::PlaceholderExpr(_) => ColumnSet::new(),
         }
     }
 }
@@ -279,6 +282,25 @@ impl TryFrom<ScalarExpr> for SubqueryExpr {
     }
 }
 
+impl From<PlaceholderExpr> for ScalarExpr {
+    fn from(v: PlaceholderExpr) -> Self {
+        Self::PlaceholderExpr(v)
+    }
+}
+
+impl TryFrom<ScalarExpr> for PlaceholderExpr {
+    type Error = ErrorCode;
+    fn try_from(value: ScalarExpr) -> Result<Self> {
+        if let ScalarExpr::PlaceholderExpr(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast Scalar to PlaceholderExpr",
+            ))
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct BoundColumnRef {
     pub column: ColumnBinding,
@@ -291,6 +313,15 @@ pub struct ConstantExpr {
     pub data_type: Box<DataType>,
 }
 
+/// A parameter placeholder of a prepared statement, which is replaced by
+/// a constant before building the physical plan.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PlaceholderExpr {
+    /// Index of the parameter, numbered from 1.
+    pub index: usize,
+    pub data_type: Box<DataType>,
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct AndExpr {
     pub left: Box<ScalarExpr>,
@@ -27,6 +27,8 @@ use crate::optimizer::ColumnStat;
 use crate::optimizer::ColumnStatSet;
 use crate::optimizer::Datum;
 use crate::optimizer::Distribution;
+use crate::optimizer::Histogram;
+use crate::optimizer::HistogramBucket;
 use crate::optimizer::PhysicalProperty;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
@@ -123,13 +125,30 @@ impl Operator for Scan {
                 let min_datum = Datum::from_data_value(&min);
                 let max_datum = Datum::from_data_value(&max);
                 if let (Some(min), Some(max)) = (min_datum, max_datum) {
-                    let histogram = histogram_from_ndv(
-                        col_stat.number_of_distinct_values,
-                        num_rows,
-                        Some((min.clone(), max.clone())),
-                        DEFAULT_HISTOGRAM_BUCKETS,
-                    )
-                    .ok();
+                    // Prefer the histogram collected by `ANALYZE TABLE`, otherwise assume
+                    // the values are uniformly distributed.
+                    let histogram = match &col_stat.histogram {
+                        Some(buckets) => buckets
+                            .iter()
+                            .map(|bucket| {
+                                Datum::from_data_value(&bucket.upper_bound).map(|upper_bound| {
+                                    HistogramBucket::new(
+                                        upper_bound,
+                                        bucket.num_values,
+                                        bucket.num_distinct,
+                                    )
+                                })
+                            })
+                            .collect::<Option<Vec<_>>>()
+                            .map(Histogram::new),
+                        None => histogram_from_ndv(
+                            col_stat.number_of_distinct_values,
+                            num_rows,
+                            Some((min.clone(), max.clone())),
+                            DEFAULT_HISTOGRAM_BUCKETS,
+                        )
+                        .ok(),
+                    };
                     let column_stat = ColumnStat {
                         min,
                         max,
@@ -70,7 +70,7 @@ impl<'a> GroupingChecker<'a> {
                     &column.column.column_name
                 )).set_span(span))
             }
-            ScalarExpr::ConstantExpr(_) => Ok(scalar.clone()),
+            ScalarExpr::ConstantExpr(_) | ScalarExpr::PlaceholderExpr(_) => Ok(scalar.clone()),
             ScalarExpr::AndExpr(scalar) => Ok(AndExpr {
                 left: Box::new(self.resolve(&scalar.left, span)?),
                 right: Box::new(self.resolve(&scalar.right, span)?),
@@ -111,6 +111,12 @@ impl ScalarExpr {
                 data_type: subquery.data_type(),
                 display_name: DUMMY_NAME.to_string(),
             },
+            ScalarExpr::PlaceholderExpr(placeholder) => RawExpr::ColumnRef {
+                span: None,
+                id: format!("${}", placeholder.index),
+                data_type: (*placeholder.data_type).clone(),
+                display_name: format!("${}", placeholder.index),
+            },
         }
     }
 
@@ -205,6 +211,12 @@ impl ScalarExpr {
                 data_type: subquery.data_type(),
                 display_name: DUMMY_NAME.to_string(),
             },
+            ScalarExpr::PlaceholderExpr(placeholder) => RawExpr::ColumnRef {
+                span: None,
+                id: DUMMY_INDEX,
+                data_type: (*placeholder.data_type).clone(),
+                display_name: format!("${}", placeholder.index),
+            },
         }
     }
 
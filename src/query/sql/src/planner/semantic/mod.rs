@@ -16,6 +16,7 @@ mod distinct_to_groupby;
 mod grouping_check;
 mod lowering;
 mod name_resolution;
+mod placeholder;
 mod type_check;
 
 pub use distinct_to_groupby::DistinctToGroupBy;
@@ -24,5 +25,11 @@ pub use name_resolution::compare_table_name;
 pub use name_resolution::normalize_identifier;
 pub use name_resolution::IdentifierNormalizer;
 pub use name_resolution::NameResolutionContext;
+pub use placeholder::bind_parameters;
+pub use placeholder::contain_placeholder;
+pub use placeholder::parameter_count;
+pub use placeholder::substitute_parameters;
+pub use placeholder::unsupported_placeholder;
+pub use placeholder::PlaceholderNumbering;
 pub use type_check::validate_function_arg;
 pub use type_check::TypeChecker;
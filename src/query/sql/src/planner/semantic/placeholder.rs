@@ -0,0 +1,352 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::parser::token::Token;
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::token::Tokenizer;
+use common_ast::VisitorMut;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::Literal;
+
+use crate::optimizer::SExpr;
+use crate::plans::AggregateFunction;
+use crate::plans::AndExpr;
+use crate::plans::CastExpr;
+use crate::plans::ComparisonExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::Exchange;
+use crate::plans::FunctionCall;
+use crate::plans::NotExpr;
+use crate::plans::OrExpr;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::SubqueryExpr;
+
+/// Returns the number of parameters of a statement, which is the count of the
+/// anonymous `?` placeholders, or the largest index of the `$n` placeholders.
+pub fn parameter_count(tokens: &[Token]) -> Result<usize> {
+    let mut anonymous = 0;
+    let mut numbered = 0;
+    for token in tokens {
+        match token.kind {
+            TokenKind::Placeholder => anonymous += 1,
+            TokenKind::NumberedPlaceholder => numbered = numbered.max(placeholder_index(token)?),
+            _ => {}
+        }
+    }
+    if anonymous > 0 && numbered > 0 {
+        return Err(ErrorCode::SyntaxException(
+            "Cannot mix `?` and `$n` placeholders in one statement",
+        ));
+    }
+    Ok(anonymous + numbered)
+}
+
+/// Substitutes the placeholders of a statement with the parameters as SQL literals,
+/// which is used for the statements whose plans can't carry parameters.
+pub fn substitute_parameters(sql: &str, params: &[Literal]) -> Result<String> {
+    let mut result = String::with_capacity(sql.len());
+    let mut last = 0;
+    let mut anonymous = 0;
+    // Stop on unrecognized token like the planner, the values inputs (e.g. CSV)
+    // of `INSERT` statement after it are kept as is.
+    for token in Tokenizer::new(sql).map_while(|token| token.ok()) {
+        let index = match token.kind {
+            TokenKind::Placeholder => {
+                anonymous += 1;
+                anonymous
+            }
+            TokenKind::NumberedPlaceholder => placeholder_index(&token)?,
+            _ => continue,
+        };
+        let param = params.get(index - 1).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("No value is bound to parameter ${index}"))
+        })?;
+        result.push_str(&sql[last..token.span.start]);
+        result.push_str(&literal_to_sql(param));
+        last = token.span.end;
+    }
+    result.push_str(&sql[last..]);
+    Ok(result)
+}
+
+/// The error of a placeholder in a clause which only accepts constants, e.g. `LIMIT ?`.
+pub fn unsupported_placeholder(span: Span, clause: &str) -> ErrorCode {
+    ErrorCode::UnsupportedPlaceholder(format!(
+        "Parameter placeholders are not supported in {clause}"
+    ))
+    .set_span(span)
+}
+
+/// Returns true if the scalar has a placeholder, the value of which is unknown until bound.
+pub fn contain_placeholder(scalar: &ScalarExpr) -> bool {
+    match scalar {
+        ScalarExpr::PlaceholderExpr(_) => true,
+        ScalarExpr::BoundColumnRef(_)
+        | ScalarExpr::ConstantExpr(_)
+        | ScalarExpr::SubqueryExpr(_) => false,
+        ScalarExpr::AndExpr(expr) => {
+            contain_placeholder(&expr.left) || contain_placeholder(&expr.right)
+        }
+        ScalarExpr::OrExpr(expr) => {
+            contain_placeholder(&expr.left) || contain_placeholder(&expr.right)
+        }
+        ScalarExpr::NotExpr(expr) => contain_placeholder(&expr.argument),
+        ScalarExpr::ComparisonExpr(expr) => {
+            contain_placeholder(&expr.left) || contain_placeholder(&expr.right)
+        }
+        ScalarExpr::AggregateFunction(agg) => agg.args.iter().any(contain_placeholder),
+        ScalarExpr::FunctionCall(func) => func.arguments.iter().any(contain_placeholder),
+        ScalarExpr::CastExpr(cast) => contain_placeholder(&cast.argument),
+    }
+}
+
+fn placeholder_index(token: &Token) -> Result<usize> {
+    match token.text()[1..].parse::<usize>() {
+        Ok(index) if index > 0 => Ok(index),
+        _ => Err(ErrorCode::SyntaxException(format!(
+            "Invalid placeholder `{}`, the parameters are numbered from 1",
+            token.text()
+        ))),
+    }
+}
+
+fn literal_to_sql(literal: &Literal) -> String {
+    // Negative numbers are parenthesized, or `a-?` would become a comment.
+    fn number<T: ToString + Default + PartialOrd>(v: T) -> String {
+        if v < T::default() {
+            format!("({})", v.to_string())
+        } else {
+            v.to_string()
+        }
+    }
+    fn float(v: f64) -> String {
+        if !v.is_finite() {
+            format!("CAST('{v}' AS DOUBLE)")
+        } else if v < 0.0 {
+            format!("({v:?})")
+        } else {
+            format!("{v:?}")
+        }
+    }
+
+    match literal {
+        Literal::Null => "NULL".to_string(),
+        Literal::Boolean(v) => v.to_string().to_uppercase(),
+        Literal::Int8(v) => number(*v),
+        Literal::Int16(v) => number(*v),
+        Literal::Int32(v) => number(*v),
+        Literal::Int64(v) => number(*v),
+        Literal::UInt8(v) => number(*v),
+        Literal::UInt16(v) => number(*v),
+        Literal::UInt32(v) => number(*v),
+        Literal::UInt64(v) => number(*v),
+        Literal::Float32(v) => float(v.into_inner() as f64),
+        Literal::Float64(v) => float(v.into_inner()),
+        Literal::String(v) => {
+            let s = String::from_utf8_lossy(v);
+            format!("'{}'", s.replace('\\', "\\\\").replace('\'', "''"))
+        }
+    }
+}
+
+/// Numbers the anonymous `?` placeholders by their positions in the SQL text,
+/// so that they can be bound like `$1`, `$2`, ... .
+pub struct PlaceholderNumbering {
+    positions: Vec<usize>,
+}
+
+impl PlaceholderNumbering {
+    pub fn new(tokens: &[Token]) -> Self {
+        let positions = tokens
+            .iter()
+            .filter(|token| token.kind == TokenKind::Placeholder)
+            .map(|token| token.span.start)
+            .collect();
+        PlaceholderNumbering { positions }
+    }
+}
+
+impl VisitorMut for PlaceholderNumbering {
+    fn visit_placeholder(&mut self, span: Span, index: &mut usize) {
+        if *index != 0 {
+            return;
+        }
+        if let Some(span) = span {
+            if let Ok(position) = self.positions.binary_search(&span.start) {
+                *index = position + 1;
+            }
+        }
+    }
+}
+
+/// Binds the parameters to the placeholders of an optimized plan, the types of the
+/// parameters must be the ones the plan is bound with.
+pub fn bind_parameters(s_expr: &SExpr, params: &[Literal]) -> Result<SExpr> {
+    let children = s_expr
+        .children()
+        .iter()
+        .map(|child| bind_parameters(child, params))
+        .collect::<Result<Vec<_>>>()?;
+
+    let bind_scalars = |scalars: &[ScalarExpr]| {
+        scalars
+            .iter()
+            .map(|scalar| bind_scalar_parameters(scalar, params))
+            .collect::<Result<Vec<_>>>()
+    };
+    let bind_items = |items: &[ScalarItem]| {
+        items
+            .iter()
+            .map(|item| {
+                Ok(ScalarItem {
+                    scalar: bind_scalar_parameters(&item.scalar, params)?,
+                    index: item.index,
+                })
+            })
+            .collect::<Result<Vec<_>>>()
+    };
+
+    let plan: RelOperator = match s_expr.plan() {
+        RelOperator::Scan(scan) => {
+            let mut scan = scan.clone();
+            scan.push_down_predicates = scan
+                .push_down_predicates
+                .as_deref()
+                .map(&bind_scalars)
+                .transpose()?;
+            if let Some(prewhere) = &mut scan.prewhere {
+                prewhere.predicates = bind_scalars(&prewhere.predicates)?;
+            }
+            scan.into()
+        }
+        RelOperator::Join(join) => {
+            let mut join = join.clone();
+            join.left_conditions = bind_scalars(&join.left_conditions)?;
+            join.right_conditions = bind_scalars(&join.right_conditions)?;
+            join.non_equi_conditions = bind_scalars(&join.non_equi_conditions)?;
+            join.into()
+        }
+        RelOperator::EvalScalar(eval_scalar) => {
+            let mut eval_scalar = eval_scalar.clone();
+            eval_scalar.items = bind_items(&eval_scalar.items)?;
+            eval_scalar.into()
+        }
+        RelOperator::Filter(filter) => {
+            let mut filter = filter.clone();
+            filter.predicates = bind_scalars(&filter.predicates)?;
+            filter.into()
+        }
+        RelOperator::Aggregate(aggregate) => {
+            let mut aggregate = aggregate.clone();
+            aggregate.group_items = bind_items(&aggregate.group_items)?;
+            aggregate.aggregate_functions = bind_items(&aggregate.aggregate_functions)?;
+            aggregate.into()
+        }
+        RelOperator::ProjectSet(project_set) => {
+            let mut project_set = project_set.clone();
+            project_set.args = bind_scalars(&project_set.args)?;
+            project_set.into()
+        }
+        RelOperator::Exchange(Exchange::Hash(keys)) => Exchange::Hash(bind_scalars(keys)?).into(),
+        plan => plan.clone(),
+    };
+
+    let mut s_expr = s_expr.replace_children(children);
+    s_expr.plan = plan;
+    Ok(s_expr)
+}
+
+fn bind_scalar_parameters(scalar: &ScalarExpr, params: &[Literal]) -> Result<ScalarExpr> {
+    let bind = |scalar: &ScalarExpr| bind_scalar_parameters(scalar, params).map(Box::new);
+    let bind_all = |scalars: &[ScalarExpr]| {
+        scalars
+            .iter()
+            .map(|scalar| bind_scalar_parameters(scalar, params))
+            .collect::<Result<Vec<_>>>()
+    };
+
+    Ok(match scalar {
+        ScalarExpr::PlaceholderExpr(placeholder) => {
+            let value = placeholder
+                .index
+                .checked_sub(1)
+                .and_then(|index| params.get(index))
+                .ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "No value is bound to parameter ${}",
+                        placeholder.index
+                    ))
+                })?;
+            ConstantExpr {
+                value: value.clone(),
+                data_type: placeholder.data_type.clone(),
+            }
+            .into()
+        }
+        ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_) => scalar.clone(),
+        ScalarExpr::AndExpr(expr) => AndExpr {
+            left: bind(&expr.left)?,
+            right: bind(&expr.right)?,
+            return_type: expr.return_type.clone(),
+        }
+        .into(),
+        ScalarExpr::OrExpr(expr) => OrExpr {
+            left: bind(&expr.left)?,
+            right: bind(&expr.right)?,
+            return_type: expr.return_type.clone(),
+        }
+        .into(),
+        ScalarExpr::NotExpr(expr) => NotExpr {
+            argument: bind(&expr.argument)?,
+            return_type: expr.return_type.clone(),
+        }
+        .into(),
+        ScalarExpr::ComparisonExpr(expr) => ComparisonExpr {
+            op: expr.op.clone(),
+            left: bind(&expr.left)?,
+            right: bind(&expr.right)?,
+            return_type: expr.return_type.clone(),
+        }
+        .into(),
+        ScalarExpr::AggregateFunction(agg) => AggregateFunction {
+            args: bind_all(&agg.args)?,
+            ..agg.clone()
+        }
+        .into(),
+        ScalarExpr::FunctionCall(func) => FunctionCall {
+            arguments: bind_all(&func.arguments)?,
+            ..func.clone()
+        }
+        .into(),
+        ScalarExpr::CastExpr(cast) => CastExpr {
+            argument: bind(&cast.argument)?,
+            ..cast.clone()
+        }
+        .into(),
+        ScalarExpr::SubqueryExpr(subquery) => SubqueryExpr {
+            subquery: Box::new(bind_parameters(&subquery.subquery, params)?),
+            child_expr: subquery
+                .child_expr
+                .as_ref()
+                .map(|child_expr| bind(child_expr))
+                .transpose()?,
+            ..subquery.clone()
+        }
+        .into(),
+    })
+}
@@ -32,6 +32,7 @@ use common_ast::parser::parse_expr;
 use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
 use common_catalog::catalog::CatalogManager;
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -50,8 +51,12 @@ use common_expression::TableDataType;
 use common_functions::aggregates::AggregateCountFunction;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
+use common_functions::scalars::bm25_idf;
+use common_functions::scalars::TextTokenizer;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_functions::srfs::BUILTIN_SRFS;
 use common_users::UserApiProvider;
+use storages_common_table_meta::table::InvertedIndexDefinition;
 
 use super::name_resolution::NameResolutionContext;
 use super::normalize_identifier;
@@ -70,6 +75,7 @@ use crate::plans::ConstantExpr;
 use crate::plans::FunctionCall;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
+use crate::plans::PlaceholderExpr;
 use crate::plans::ScalarExpr;
 use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
@@ -636,6 +642,25 @@ impl<'a> TypeChecker<'a> {
                 ))
             }
 
+            Expr::Placeholder { span, index } => {
+                let data_type = self
+                    .metadata
+                    .write()
+                    .infer_parameter_type(*index, required_type)
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(format!("No value is bound to parameter ${index}"))
+                            .set_span(*span)
+                    })?;
+                Box::new((
+                    PlaceholderExpr {
+                        index: *index,
+                        data_type: Box::new(data_type.clone()),
+                    }
+                    .into(),
+                    data_type,
+                ))
+            }
+
             Expr::FunctionCall {
                 span,
                 distinct,
@@ -652,6 +677,14 @@ impl<'a> TypeChecker<'a> {
                     return self.resolve_udf(*span, func_name, args).await;
                 }
 
+                if BUILTIN_SRFS.contains(func_name) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "set-returning function {} is only supported in FROM clause",
+                        func_name
+                    ))
+                    .set_span(*span));
+                }
+
                 let args: Vec<&Expr> = args.iter().collect();
 
                 if AggregateFunctionFactory::instance().contains(func_name) {
@@ -904,6 +937,8 @@ impl<'a> TypeChecker<'a> {
 
             Expr::Array { span, exprs, .. } => self.resolve_array(*span, exprs).await?,
 
+            Expr::Map { span, kvs, .. } => self.resolve_map(*span, kvs).await?,
+
             Expr::Position {
                 substr_expr,
                 str_expr,
@@ -960,8 +995,7 @@ impl<'a> TypeChecker<'a> {
         let mut args = vec![];
         let mut arg_types = vec![];
 
-        for argument in arguments {
-            let box (arg, mut arg_type) = self.resolve(argument, None).await?;
+        for (arg, mut arg_type) in self.resolve_arguments(arguments).await? {
             if let ScalarExpr::SubqueryExpr(subquery) = &arg {
                 if subquery.typ == SubqueryType::Scalar && !arg.data_type().is_nullable() {
                     arg_type = arg_type.wrap_nullable();
@@ -1018,6 +1052,13 @@ impl<'a> TypeChecker<'a> {
         };
         let registry = &BUILTIN_FUNCTIONS;
         let expr = type_check::check(&raw_expr, registry)?;
+        if let common_expression::Expr::FunctionCall { function, .. } = &expr {
+            if function.signature.property.non_deterministic
+                || is_time_dependent_function(&function.signature.name)
+            {
+                self.metadata.write().set_non_deterministic();
+            }
+        }
 
         Ok(Box::new((
             FunctionCall {
@@ -1031,6 +1072,36 @@ impl<'a> TypeChecker<'a> {
         )))
     }
 
+    /// Resolve the arguments of a function in order. The placeholders are resolved after the
+    /// other arguments, whose type is required for the placeholders without bound parameters.
+    #[async_recursion::async_recursion]
+    async fn resolve_arguments(
+        &mut self,
+        arguments: &[&Expr],
+    ) -> Result<Vec<(ScalarExpr, DataType)>> {
+        let mut resolved = Vec::with_capacity(arguments.len());
+        for argument in arguments {
+            match argument {
+                Expr::Placeholder { .. } => resolved.push(None),
+                _ => resolved.push(Some(*self.resolve(argument, None).await?)),
+            }
+        }
+
+        let required_type = resolved
+            .iter()
+            .flatten()
+            .map(|(_, data_type)| data_type.remove_nullable())
+            .next();
+        let mut args = Vec::with_capacity(arguments.len());
+        for (argument, resolved) in arguments.iter().zip(resolved) {
+            match resolved {
+                Some(arg) => args.push(arg),
+                None => args.push(*self.resolve(argument, required_type.clone()).await?),
+            }
+        }
+        Ok(args)
+    }
+
     /// Resolve binary expressions. Most of the binary expressions
     /// would be transformed into `FunctionCall`, except comparison
     /// expressions, conjunction(`AND`) and disjunction(`OR`).
@@ -1068,8 +1139,9 @@ impl<'a> TypeChecker<'a> {
             | BinaryOperator::Eq
             | BinaryOperator::NotEq => {
                 let op = ComparisonOp::try_from(op)?;
-                let box (left, _) = self.resolve(left, None).await?;
-                let box (right, _) = self.resolve(right, None).await?;
+                let mut operands = self.resolve_arguments(&[left, right]).await?;
+                let (right, _) = operands.pop().unwrap();
+                let (left, _) = operands.pop().unwrap();
 
                 let (_, data_type) = *self
                     .resolve_scalar_function_call(
@@ -1428,6 +1500,7 @@ impl<'a> TypeChecker<'a> {
             "ifnull",
             "is_null",
             "coalesce",
+            "score",
         ]
     }
 
@@ -1487,16 +1560,19 @@ impl<'a> TypeChecker<'a> {
                 )
                 .await,
             ),
-            ("connection_id", &[]) => Some(
-                self.resolve(
-                    &Expr::Literal {
-                        span,
-                        lit: Literal::String(self.ctx.get_connection_id()),
-                    },
-                    None,
+            ("connection_id", &[]) => {
+                self.metadata.write().set_non_deterministic();
+                Some(
+                    self.resolve(
+                        &Expr::Literal {
+                            span,
+                            lit: Literal::String(self.ctx.get_connection_id()),
+                        },
+                        None,
+                    )
+                    .await,
                 )
-                .await,
-            ),
+            }
             ("timezone", &[]) => {
                 let tz = self.ctx.get_settings().get_timezone().unwrap();
                 Some(
@@ -1578,6 +1654,7 @@ impl<'a> TypeChecker<'a> {
                     .await,
                 )
             }
+            ("score", &[]) => Some(self.resolve_score_function(span).await),
             ("coalesce", args) => {
                 // coalesce(arg0, arg1, ..., argN) is essentially
                 // multi_if(is_not_null(arg0), assume_not_null(arg0), is_not_null(arg1), assume_not_null(arg1), ..., argN)
@@ -1629,6 +1706,109 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Resolve the column of a full-text predicate to its table, column name and the tokenizer
+    /// of the inverted index covering it.
+    ///
+    /// Returns `None` if the expression is not a column covered by an inverted index.
+    async fn resolve_inverted_index_column(
+        &mut self,
+        column: &Expr,
+    ) -> Result<Option<(Arc<dyn Table>, String, String)>> {
+        let box (scalar, _) = self.resolve(column, None).await?;
+        let ScalarExpr::BoundColumnRef(BoundColumnRef { column }) = scalar else {
+            return Ok(None);
+        };
+        let metadata = self.metadata.read();
+        let ColumnEntry::BaseTableColumn {
+            table_index,
+            column_name,
+            path_indices: None,
+            ..
+        } = metadata.column(column.index) else {
+            return Ok(None);
+        };
+        let table = metadata.table(*table_index).table();
+        let indexes = InvertedIndexDefinition::from_table_options(table.options())?;
+        Ok(
+            InvertedIndexDefinition::column_tokenizer(&indexes, column_name)
+                .map(|tokenizer| (table.clone(), column_name.clone(), tokenizer.to_string())),
+        )
+    }
+
+    /// Resolve `score()` to the BM25 relevance of the rows to the full-text query of the
+    /// WHERE clause, with the term statistics collected from the inverted index of the column.
+    async fn resolve_score_function(&mut self, span: Span) -> Result<Box<(ScalarExpr, DataType)>> {
+        let Some((column, query, tokenizer)) = self.bind_context.full_text_query.clone() else {
+            return Err(ErrorCode::SemanticError(
+                "score() requires a match(column, query[, tokenizer]) predicate in the WHERE clause"
+                    .to_string(),
+            )
+            .set_span(span));
+        };
+        let tokenizer = TextTokenizer::try_from(tokenizer.as_str())?;
+        let (table, column_name) = match self.resolve_inverted_index_column(&column).await? {
+            Some((table, column_name, index_tokenizer))
+                if TextTokenizer::try_from(index_tokenizer.as_str())? == tokenizer =>
+            {
+                (table, column_name)
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "score() requires an inverted index with the '{}' tokenizer on the column of match(column, query[, tokenizer])",
+                    tokenizer.name()
+                ))
+                .set_span(span));
+            }
+        };
+
+        let terms = tokenizer.query_terms(query.as_bytes());
+        let statistics = table
+            .inverted_index_statistics(self.ctx.clone(), &column_name, &terms)
+            .await?
+            .unwrap_or_default();
+        let avg_doc_len = if statistics.num_docs == 0 {
+            0.0
+        } else {
+            statistics.num_tokens as f64 / statistics.num_docs as f64
+        };
+        let mut stats = Vec::with_capacity(terms.len() + 1);
+        stats.push(avg_doc_len);
+        for i in 0..terms.len() {
+            let doc_freq = statistics.doc_freqs.get(i).copied().unwrap_or_default();
+            stats.push(bm25_idf(statistics.num_docs, doc_freq));
+        }
+
+        let stats = Expr::Array {
+            span,
+            exprs: stats
+                .into_iter()
+                .map(|stat| Expr::Literal {
+                    span,
+                    lit: Literal::Float(stat),
+                })
+                .collect(),
+        };
+        self.resolve_function(
+            span,
+            "match_score",
+            vec![],
+            &[
+                &column,
+                &Expr::Literal {
+                    span,
+                    lit: Literal::String(query),
+                },
+                &Expr::Literal {
+                    span,
+                    lit: Literal::String(tokenizer.name().to_string()),
+                },
+                &stats,
+            ],
+            None,
+        )
+        .await
+    }
+
     #[async_recursion::async_recursion]
     async fn resolve_trim_function(
         &mut self,
@@ -1741,6 +1921,24 @@ impl<'a> TypeChecker<'a> {
             .await
     }
 
+    #[async_recursion::async_recursion]
+    async fn resolve_map(
+        &mut self,
+        span: Span,
+        kvs: &[(Expr, Expr)],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let mut args = Vec::with_capacity(kvs.len() * 2);
+        for (key_expr, val_expr) in kvs {
+            let box (key_arg, _data_type) = self.resolve(key_expr, None).await?;
+            args.push(key_arg);
+            let box (val_arg, _data_type) = self.resolve(val_expr, None).await?;
+            args.push(val_arg);
+        }
+
+        self.resolve_scalar_function_call(span, "map", vec![], args, None)
+            .await
+    }
+
     #[async_recursion::async_recursion]
     async fn resolve_tuple(
         &mut self,
@@ -1890,8 +2088,11 @@ impl<'a> TypeChecker<'a> {
                 data_type: Box::new(path_data_type.clone()),
             }
             .into();
-            if let TableDataType::Array(inner_type) = table_data_type {
-                table_data_type = *inner_type;
+            match table_data_type {
+                TableDataType::Array(inner_type) | TableDataType::Map(inner_type) => {
+                    table_data_type = *inner_type;
+                }
+                _ => {}
             }
             table_data_type = TableDataType::wrap_nullable(&table_data_type);
             scalar = FunctionCall {
@@ -2242,6 +2443,18 @@ impl<'a> TypeChecker<'a> {
                         .map(|expr| self.clone_expr_with_replacement(expr, replacement_fn))
                         .collect::<Result<Vec<Expr>>>()?,
                 }),
+                Expr::Map { span, kvs } => Ok(Expr::Map {
+                    span: *span,
+                    kvs: kvs
+                        .iter()
+                        .map(|(key_expr, val_expr)| {
+                            Ok((
+                                self.clone_expr_with_replacement(key_expr, replacement_fn)?,
+                                self.clone_expr_with_replacement(val_expr, replacement_fn)?,
+                            ))
+                        })
+                        .collect::<Result<Vec<(Expr, Expr)>>>()?,
+                }),
                 Expr::Interval { span, expr, unit } => Ok(Expr::Interval {
                     span: *span,
                     expr: Box::new(
@@ -2315,6 +2528,16 @@ impl<'a> TypeChecker<'a> {
             TypeName::Nullable(inner_type) => {
                 TableDataType::Nullable(Box::new(Self::resolve_type_name(inner_type)?))
             }
+            TypeName::Map { key_type, val_type } => {
+                let key_type = Self::resolve_type_name(key_type)?;
+                if key_type != TableDataType::String {
+                    return Err(ErrorCode::Internal(format!(
+                        "Invalid map key type \'{}\', only String is supported",
+                        key_type
+                    )));
+                }
+                TableDataType::Map(Box::new(Self::resolve_type_name(val_type)?))
+            }
             TypeName::Variant => TableDataType::Variant,
             name => {
                 return Err(ErrorCode::Internal(format!(
@@ -2357,3 +2580,9 @@ pub fn validate_function_arg(
         }
     }
 }
+
+/// Functions whose results depend on the time the query runs, which are not marked as
+/// non-deterministic because they are constant within a query.
+fn is_time_dependent_function(name: &str) -> bool {
+    matches!(name, "now" | "today" | "yesterday" | "tomorrow")
+}
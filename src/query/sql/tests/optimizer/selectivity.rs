@@ -0,0 +1,96 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Literal;
+use common_sql::optimizer::ColumnStat;
+use common_sql::optimizer::Datum;
+use common_sql::optimizer::Histogram;
+use common_sql::optimizer::HistogramBucket;
+use common_sql::optimizer::SelectivityEstimator;
+use common_sql::optimizer::Statistics;
+use common_sql::plans::BoundColumnRef;
+use common_sql::plans::ComparisonExpr;
+use common_sql::plans::ComparisonOp;
+use common_sql::plans::ConstantExpr;
+use common_sql::ColumnBinding;
+use common_sql::ScalarExpr;
+use common_sql::Visibility;
+
+fn comparison(op: ComparisonOp, value: u64) -> ScalarExpr {
+    let data_type = DataType::Number(NumberDataType::UInt64);
+    ComparisonExpr {
+        op,
+        left: Box::new(
+            BoundColumnRef {
+                column: ColumnBinding {
+                    database_name: None,
+                    table_name: None,
+                    column_name: "a".to_string(),
+                    index: 0,
+                    data_type: Box::new(data_type.clone()),
+                    visibility: Visibility::Visible,
+                },
+            }
+            .into(),
+        ),
+        right: Box::new(
+            ConstantExpr {
+                value: Literal::UInt64(value),
+                data_type: Box::new(data_type),
+            }
+            .into(),
+        ),
+        return_type: Box::new(DataType::Boolean),
+    }
+    .into()
+}
+
+#[test]
+fn test_selectivity_with_histogram() {
+    // The first bucket only holds the min value, like the histograms of `ANALYZE TABLE`.
+    let histogram = Histogram::new(vec![
+        HistogramBucket::new(Datum::UInt(1), 10.0, 1.0),
+        HistogramBucket::new(Datum::UInt(10), 40.0, 9.0),
+        HistogramBucket::new(Datum::UInt(20), 50.0, 10.0),
+    ]);
+    let statistics = Statistics {
+        precise_cardinality: None,
+        column_stats: HashMap::from([(0, ColumnStat {
+            min: Datum::UInt(1),
+            max: Datum::UInt(20),
+            ndv: 20.0,
+            null_count: 0,
+            histogram: Some(histogram),
+        })]),
+        is_accurate: false,
+    };
+    let estimator = SelectivityEstimator::new(&statistics);
+    let selectivity = |op, value| estimator.compute_selectivity(&comparison(op, value));
+
+    assert_eq!(selectivity(ComparisonOp::LT, 0), 0.0);
+    assert_eq!(selectivity(ComparisonOp::LT, 1), 0.0);
+    assert_eq!(selectivity(ComparisonOp::LTE, 1), 0.1);
+    assert_eq!(selectivity(ComparisonOp::GT, 1), 0.9);
+    assert_eq!(selectivity(ComparisonOp::LTE, 10), 0.5);
+    assert_eq!(selectivity(ComparisonOp::GT, 20), 0.0);
+
+    // Interpolated between the min value and the upper bound of the second bucket.
+    let num_range = 40.0 - 40.0 / 9.0;
+    let expected = (10.0 + num_range * (5.0 - 1.0) / (10.0 - 1.0)) / 100.0;
+    assert!((selectivity(ComparisonOp::LT, 5) - expected).abs() < 1e-9);
+}
@@ -24,11 +24,13 @@ use storages_common_cache::InMemoryItemCacheHolder;
 use crate::caches::BloomIndexFilterCache;
 use crate::caches::BloomIndexMetaCache;
 use crate::caches::FileMetaDataCache;
+use crate::caches::InvertedIndexCache;
 use crate::caches::SegmentInfoCache;
 use crate::caches::TableSnapshotCache;
 use crate::caches::TableSnapshotStatisticCache;
 
 static DEFAULT_FILE_META_DATA_CACHE_ITEMS: u64 = 3000;
+static DEFAULT_INVERTED_INDEX_CACHE_ITEMS: u64 = 3000;
 
 /// Where all the caches reside
 pub struct CacheManager {
@@ -37,6 +39,7 @@ pub struct CacheManager {
     segment_info_cache: Option<SegmentInfoCache>,
     bloom_index_filter_cache: Option<BloomIndexFilterCache>,
     bloom_index_meta_cache: Option<BloomIndexMetaCache>,
+    inverted_index_cache: Option<InvertedIndexCache>,
     file_meta_data_cache: Option<FileMetaDataCache>,
 }
 
@@ -51,6 +54,7 @@ impl CacheManager {
                 segment_info_cache: None,
                 bloom_index_filter_cache: None,
                 bloom_index_meta_cache: None,
+                inverted_index_cache: None,
                 file_meta_data_cache: None,
                 table_statistic_cache: None,
             }));
@@ -62,12 +66,14 @@ impl CacheManager {
                 Self::new_item_cache(config.table_cache_bloom_index_filter_count);
             let bloom_index_meta_cache =
                 Self::new_item_cache(config.table_cache_bloom_index_meta_count);
+            let inverted_index_cache = Self::new_item_cache(DEFAULT_INVERTED_INDEX_CACHE_ITEMS);
             let file_meta_data_cache = Self::new_item_cache(DEFAULT_FILE_META_DATA_CACHE_ITEMS);
             GlobalInstance::set(Arc::new(Self {
                 table_snapshot_cache,
                 segment_info_cache,
                 bloom_index_filter_cache,
                 bloom_index_meta_cache,
+                inverted_index_cache,
                 file_meta_data_cache,
                 table_statistic_cache,
             }));
@@ -100,6 +106,10 @@ impl CacheManager {
         self.bloom_index_meta_cache.clone()
     }
 
+    pub fn get_inverted_index_cache(&self) -> Option<InvertedIndexCache> {
+        self.inverted_index_cache.clone()
+    }
+
     pub fn get_file_meta_data_cache(&self) -> Option<FileMetaDataCache> {
         self.file_meta_data_cache.clone()
     }
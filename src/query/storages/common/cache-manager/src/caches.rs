@@ -16,6 +16,7 @@ use common_arrow::parquet::metadata::FileMetaData;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache::InMemoryItemCacheHolder;
 use storages_common_index::filters::Xor8Filter;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
@@ -34,6 +35,8 @@ pub type BloomIndexFilterCache = InMemoryItemCacheHolder<Xor8Filter>;
 pub struct BloomIndexMeta(pub FileMetaData);
 /// In memory object cache of parquet FileMetaData of bloom index data
 pub type BloomIndexMetaCache = InMemoryItemCacheHolder<BloomIndexMeta>;
+/// In memory object cache of the inverted indexes of data blocks
+pub type InvertedIndexCache = InMemoryItemCacheHolder<InvertedIndex>;
 /// In memory object cache of parquet FileMetaData of external parquet files
 pub type FileMetaDataCache = InMemoryItemCacheHolder<FileMetaData>;
 
@@ -82,6 +85,13 @@ impl CachedObject<Xor8Filter> for Xor8Filter {
     }
 }
 
+impl CachedObject<InvertedIndex> for InvertedIndex {
+    type Cache = InvertedIndexCache;
+    fn cache() -> Option<Self::Cache> {
+        CacheManager::instance().get_inverted_index_cache()
+    }
+}
+
 impl CachedObject<FileMetaData> for FileMetaData {
     type Cache = FileMetaDataCache;
     fn cache() -> Option<Self::Cache> {
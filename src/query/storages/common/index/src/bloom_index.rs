@@ -21,6 +21,7 @@ use common_exception::Result;
 use common_exception::Span;
 use common_expression::converts::scalar_to_datavalue;
 use common_expression::eval_function;
+use common_expression::types::number::NumberScalar;
 use common_expression::types::DataType;
 use common_expression::types::NullableType;
 use common_expression::types::Number;
@@ -49,6 +50,8 @@ use crate::filters::V2BloomBlock;
 use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
 use crate::Index;
+use crate::TextIndexOptions;
+use crate::TextPredicate;
 
 /// BlockFilter represents multiple per-column filters(bloom filter or xor filter etc) for data block.
 ///
@@ -87,6 +90,9 @@ pub struct BloomIndex {
 
     /// Approximate distinct count of columns generated by xor hash function.
     pub column_distinct_count: HashMap<usize, usize>,
+
+    /// The ngram and token filters of string columns, which are stored beside the xor filters.
+    pub text_index: TextIndexOptions,
 }
 
 /// FilterExprEvalResult represents the evaluation result of an expression by a filter.
@@ -110,6 +116,7 @@ impl BloomIndex {
         filter_schema: TableSchemaRef,
         filters: Vec<Arc<Xor8Filter>>,
         version: u64,
+        text_index: TextIndexOptions,
     ) -> Result<Self> {
         Ok(Self {
             version,
@@ -118,6 +125,7 @@ impl BloomIndex {
             filter_schema,
             filters,
             column_distinct_count: HashMap::new(),
+            text_index,
         })
     }
 
@@ -129,6 +137,7 @@ impl BloomIndex {
         source_schema: TableSchemaRef,
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
+        text_index: &TextIndexOptions,
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -208,6 +217,37 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        // create the text filters of string columns
+        for i in 0..num_columns {
+            let source_field = source_schema.field(i);
+            let data_type = &data_blocks_tobe_indexed[0].get_by_offset(i).data_type;
+            let kinds = text_index.filter_kinds(source_field.name());
+            if kinds.is_empty() || data_type.remove_nullable() != DataType::String {
+                continue;
+            }
+
+            let source_columns = data_blocks_tobe_indexed
+                .iter()
+                .map(|block| {
+                    let value = &block.get_by_offset(i).value;
+                    value
+                        .convert_to_full_column(data_type, block.num_rows())
+                        .remove_nullable()
+                })
+                .collect::<Vec<_>>();
+            for kind in kinds {
+                let values = source_columns
+                    .iter()
+                    .filter_map(Column::as_string)
+                    .flat_map(|column| column.iter());
+                if let Some(filter) = kind.build_filter(values)? {
+                    let filter_name = kind.filter_column_name(source_field.name());
+                    filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+                    filters.push(Arc::new(filter));
+                }
+            }
+        }
+
         let filter_schema = Arc::new(TableSchema::new(filter_fields));
 
         Ok(Some(Self {
@@ -217,6 +257,7 @@ impl BloomIndex {
             filter_schema,
             filters,
             column_distinct_count,
+            text_index: text_index.clone(),
         }))
     }
 
@@ -261,6 +302,26 @@ impl BloomIndex {
             },
         )?;
 
+        if !self.text_index.is_empty() {
+            visit_expr_text_predicate(&mut expr, &mut |span, col_name, predicate, return_type| {
+                // If no value of the column contains the text, we rewrite the predicate to
+                // `false`, or to `0` for the position of the text.
+                if self.find_text(col_name, &predicate)? == FilterEvalResult::MustFalse {
+                    let scalar = match predicate {
+                        TextPredicate::Contains(_) => Scalar::Number(NumberScalar::UInt64(0)),
+                        _ => Scalar::Boolean(false),
+                    };
+                    Ok(Some(Expr::Constant {
+                        span,
+                        scalar,
+                        data_type: return_type.clone(),
+                    }))
+                } else {
+                    Ok(None)
+                }
+            })?;
+        }
+
         let (new_expr, _) = ConstantFolder::fold(&expr, self.func_ctx, &BUILTIN_FUNCTIONS);
 
         match new_expr {
@@ -322,6 +383,22 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find the text filter columns used by the `LIKE`, `position`/`locate` and `match`
+    /// predicates in the expression.
+    pub fn find_text_filter_columns(
+        expr: &Expr<String>,
+        text_index: &TextIndexOptions,
+    ) -> Result<Vec<String>> {
+        let mut cols = Vec::new();
+        visit_expr_text_predicate(&mut expr.clone(), &mut |_, col_name, _, _| {
+            for kind in text_index.filter_kinds(col_name) {
+                cols.push(kind.filter_column_name(col_name));
+            }
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(column_name: &str) -> String {
@@ -364,6 +441,21 @@ impl BloomIndex {
             Ok(FilterEvalResult::MustFalse)
         }
     }
+
+    fn find_text(&self, column_name: &str, predicate: &TextPredicate) -> Result<FilterEvalResult> {
+        for kind in self.text_index.filter_kinds(column_name) {
+            let filter_column = &kind.filter_column_name(column_name);
+            if !self.filter_schema.has_field(filter_column) {
+                continue;
+            }
+
+            let idx = self.filter_schema.index_of(filter_column)?;
+            if !kind.may_satisfy(&self.filters[idx], predicate) {
+                return Ok(FilterEvalResult::MustFalse);
+            }
+        }
+        Ok(FilterEvalResult::Uncertain)
+    }
 }
 
 fn visit_expr_column_eq_constant(
@@ -413,3 +505,70 @@ fn visit_expr_column_eq_constant(
 
     Ok(())
 }
+
+fn visit_expr_text_predicate(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, TextPredicate, &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `Column LIKE <constant>`, `position(<constant> IN Column)`,
+    // `instr(Column, <constant>)` or `match(Column, <constant>)`.
+    let new_expr = match expr {
+        Expr::FunctionCall {
+            span,
+            function,
+            args,
+            return_type,
+            ..
+        } => match (function.signature.name.as_str(), args.as_slice()) {
+            (
+                name @ ("like" | "match" | "instr"),
+                [
+                    Expr::ColumnRef { id, .. },
+                    Expr::Constant {
+                        scalar: Scalar::String(text),
+                        ..
+                    },
+                ],
+            ) => {
+                let predicate = match name {
+                    "like" => TextPredicate::Like(text),
+                    "match" => TextPredicate::Match(text),
+                    _ => TextPredicate::Contains(text),
+                };
+                visitor(*span, id, predicate, return_type)?
+            }
+            (
+                "position" | "locate",
+                [
+                    Expr::Constant {
+                        scalar: Scalar::String(text),
+                        ..
+                    },
+                    Expr::ColumnRef { id, .. },
+                ],
+            ) => visitor(*span, id, TextPredicate::Contains(text), return_type)?,
+            _ => None,
+        },
+        _ => None,
+    };
+    // If the visitor returns a new expression, then replace with the current expression.
+    if let Some(new_expr) = new_expr {
+        *expr = new_expr;
+        return Ok(());
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_text_predicate(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_text_predicate(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,272 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::ConstantFolder;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableSchema;
+use common_functions::scalars::TextTokenizer;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+use serde::Deserialize;
+use serde::Serialize;
+use storages_common_table_meta::table::InvertedIndexDefinition;
+
+use crate::FilterEvalResult;
+
+/// The terms of an indexed column of a block.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct InvertedIndexColumn {
+    pub tokenizer: String,
+    /// The number of tokens of all the rows, which gives the average row length of BM25.
+    pub num_tokens: u64,
+    /// The offsets of the rows containing each term.
+    pub postings: BTreeMap<String, Vec<u32>>,
+}
+
+/// InvertedIndex maps the terms of the indexed string columns of a block to the rows
+/// containing them. It is written beside the block by the inverted indexes of the table.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct InvertedIndex {
+    pub num_rows: u64,
+    pub columns: BTreeMap<String, InvertedIndexColumn>,
+}
+
+impl InvertedIndex {
+    pub const VERSION: u64 = 0;
+
+    /// Builds the index of the columns covered by `indexes`, returns None if the block
+    /// has none of them.
+    pub fn try_create(
+        source_schema: &TableSchema,
+        block: &DataBlock,
+        indexes: &[InvertedIndexDefinition],
+    ) -> Result<Option<Self>> {
+        let mut columns = BTreeMap::new();
+        for index in indexes {
+            let tokenizer = TextTokenizer::try_from(index.tokenizer.as_str())?;
+            for column_name in &index.columns {
+                let Ok(offset) = source_schema.index_of(column_name) else {
+                    continue;
+                };
+                let entry = block.get_by_offset(offset);
+                if entry.data_type.remove_nullable() != DataType::String {
+                    continue;
+                }
+
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, block.num_rows());
+                let (column, validity) = match column {
+                    Column::Nullable(c) => (c.column, Some(c.validity)),
+                    c => (c, None),
+                };
+                let Some(values) = column.as_string() else {
+                    continue;
+                };
+
+                let mut index_column = InvertedIndexColumn {
+                    tokenizer: tokenizer.name().to_string(),
+                    ..Default::default()
+                };
+                for (row, value) in values.iter().enumerate() {
+                    if validity.as_ref().map_or(false, |v| !v.get_bit(row)) {
+                        continue;
+                    }
+                    for token in tokenizer.tokenize(value) {
+                        index_column.num_tokens += 1;
+                        let rows = index_column
+                            .postings
+                            .entry(String::from_utf8_lossy(&token).into_owned())
+                            .or_insert_with(Vec::new);
+                        if rows.last() != Some(&(row as u32)) {
+                            rows.push(row as u32);
+                        }
+                    }
+                }
+                columns.insert(column_name.clone(), index_column);
+            }
+        }
+
+        if columns.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            num_rows: block.num_rows() as u64,
+            columns,
+        }))
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self)
+            .map_err(|e| ErrorCode::StorageOther(format!("failed to encode inverted index: {}", e)))
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        serde_json::from_slice(bytes)
+            .map_err(|e| ErrorCode::StorageOther(format!("failed to decode inverted index: {}", e)))
+    }
+
+    /// The number of rows of the column containing the term.
+    pub fn doc_freq(&self, column_name: &str, term: &[u8]) -> u64 {
+        self.columns
+            .get(column_name)
+            .and_then(|c| c.postings.get(String::from_utf8_lossy(term).as_ref()))
+            .map_or(0, |rows| rows.len() as u64)
+    }
+
+    /// Returns false if no row of the column has all the terms of the query.
+    pub fn may_match(&self, column_name: &str, tokenizer: TextTokenizer, query: &[u8]) -> bool {
+        let Some(column) = self.columns.get(column_name) else {
+            return true;
+        };
+        match TextTokenizer::try_from(column.tokenizer.as_str()) {
+            // An index knows the terms of the query if it is built by the same tokenizer, or
+            // by the english tokenizer, whose terms are the lowercase of any other term.
+            // A term the index tokenizer drops, like a stop word, may match any row.
+            Ok(index_tokenizer)
+                if index_tokenizer == tokenizer || index_tokenizer == TextTokenizer::English =>
+            {
+                tokenizer.query_terms(query).iter().all(|term| {
+                    index_tokenizer.tokenize(term).all(|term| {
+                        column
+                            .postings
+                            .contains_key(String::from_utf8_lossy(&term).as_ref())
+                    })
+                })
+            }
+            _ => true,
+        }
+    }
+
+    /// Rewrites the `match` predicates that no row satisfies to `false`, and tells whether
+    /// the filter can be true for the block.
+    pub fn apply(&self, func_ctx: FunctionContext, mut expr: Expr<String>) -> FilterEvalResult {
+        rewrite_unmatched_predicates(&mut expr, self);
+
+        let (new_expr, _) = ConstantFolder::fold(&expr, func_ctx, &BUILTIN_FUNCTIONS);
+        match new_expr {
+            Expr::Constant {
+                scalar: Scalar::Boolean(false),
+                ..
+            } => FilterEvalResult::MustFalse,
+            _ => FilterEvalResult::Uncertain,
+        }
+    }
+
+    /// The columns searched by `match(Column, <constant>[, <tokenizer>])` in the filter.
+    pub fn find_match_columns(expr: &Expr<String>) -> Vec<String> {
+        let mut columns = vec![];
+        visit_match_predicate(expr, &mut |column_name, _, _| {
+            columns.push(column_name.to_string())
+        });
+        columns
+    }
+}
+
+fn visit_match_predicate(
+    expr: &Expr<String>,
+    visitor: &mut impl FnMut(&str, &[u8], TextTokenizer),
+) {
+    match expr {
+        Expr::FunctionCall { function, args, .. } => {
+            if let Some((column_name, query, tokenizer)) =
+                match_predicate(&function.signature.name, args)
+            {
+                visitor(column_name, query, tokenizer);
+            }
+            for arg in args {
+                visit_match_predicate(arg, visitor);
+            }
+        }
+        Expr::Cast { expr, .. } => visit_match_predicate(expr, visitor),
+        _ => (),
+    }
+}
+
+fn rewrite_unmatched_predicates(expr: &mut Expr<String>, index: &InvertedIndex) {
+    match expr {
+        Expr::FunctionCall {
+            span,
+            function,
+            args,
+            return_type,
+            ..
+        } => {
+            if let Some((column_name, query, tokenizer)) =
+                match_predicate(&function.signature.name, args)
+            {
+                if !index.may_match(column_name, tokenizer, query) {
+                    *expr = Expr::Constant {
+                        span: *span,
+                        scalar: Scalar::Boolean(false),
+                        data_type: return_type.clone(),
+                    };
+                }
+                return;
+            }
+            for arg in args.iter_mut() {
+                rewrite_unmatched_predicates(arg, index);
+            }
+        }
+        Expr::Cast { expr, .. } => rewrite_unmatched_predicates(expr, index),
+        _ => (),
+    }
+}
+
+// Find patterns like `match(Column, <constant>[, <tokenizer>])`.
+fn match_predicate<'a>(
+    name: &str,
+    args: &'a [Expr<String>],
+) -> Option<(&'a str, &'a [u8], TextTokenizer)> {
+    match (name, args) {
+        (
+            "match",
+            [
+                Expr::ColumnRef { id, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(query),
+                    ..
+                },
+            ],
+        ) => Some((id.as_str(), query.as_slice(), TextTokenizer::Default)),
+        (
+            "match",
+            [
+                Expr::ColumnRef { id, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(query),
+                    ..
+                },
+                Expr::Constant {
+                    scalar: Scalar::String(tokenizer),
+                    ..
+                },
+            ],
+        ) => {
+            let tokenizer = TextTokenizer::try_from(String::from_utf8_lossy(tokenizer).as_ref());
+            tokenizer
+                .ok()
+                .map(|tokenizer| (id.as_str(), query.as_slice(), tokenizer))
+        }
+        _ => None,
+    }
+}
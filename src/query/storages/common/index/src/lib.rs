@@ -17,11 +17,19 @@
 mod bloom_index;
 pub mod filters;
 mod index;
+mod inverted_index;
 mod page_index;
 mod range_index;
+mod text_index;
 
 pub use bloom_index::BloomIndex;
 pub use bloom_index::FilterEvalResult;
 pub use index::Index;
+pub use inverted_index::InvertedIndex;
+pub use inverted_index::InvertedIndexColumn;
 pub use page_index::PageIndex;
 pub use range_index::RangeIndex;
+pub use text_index::TextFilterKind;
+pub use text_index::TextIndexOptions;
+pub use text_index::TextPredicate;
+pub use text_index::DEFAULT_NGRAM_SIZE;
@@ -0,0 +1,197 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_exception::Result;
+use common_functions::scalars::tokenize_text;
+
+use crate::filters::Filter;
+use crate::filters::FilterBuilder;
+use crate::filters::Xor8Builder;
+use crate::filters::Xor8Filter;
+
+pub const DEFAULT_NGRAM_SIZE: usize = 3;
+
+/// The text filters to build for string columns beside their xor8 filters,
+/// which prune blocks by `LIKE`, `position`/`locate` and `match` predicates.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextIndexOptions {
+    /// Columns indexed by the ngrams of their values.
+    pub ngram_columns: Vec<String>,
+    /// The number of bytes of an ngram.
+    pub ngram_size: usize,
+    /// Columns indexed by the tokens of their values, as split by `tokenize_text`.
+    pub token_columns: Vec<String>,
+}
+
+impl TextIndexOptions {
+    pub fn is_empty(&self) -> bool {
+        self.ngram_columns.is_empty() && self.token_columns.is_empty()
+    }
+
+    /// The kinds of text filter built for the column.
+    pub fn filter_kinds(&self, column_name: &str) -> Vec<TextFilterKind> {
+        let mut kinds = vec![];
+        if self.ngram_size > 0 && self.ngram_columns.iter().any(|c| c == column_name) {
+            kinds.push(TextFilterKind::Ngram(self.ngram_size));
+        }
+        if self.token_columns.iter().any(|c| c == column_name) {
+            kinds.push(TextFilterKind::Token);
+        }
+        kinds
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextFilterKind {
+    Ngram(usize),
+    Token,
+}
+
+/// A predicate on a string column that only holds for values containing some substrings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextPredicate<'a> {
+    /// `column LIKE pattern`
+    Like(&'a [u8]),
+    /// `position(needle IN column) > 0` and alike
+    Contains(&'a [u8]),
+    /// `match(column, query)`, every token of the query is a token of the value
+    Match(&'a [u8]),
+}
+
+impl TextFilterKind {
+    /// The ngram filter of a column is stored with field name 'Ngram{n}(column_name)', the
+    /// ngram size is a part of the name so that blocks written with another size are skipped.
+    pub fn filter_column_name(&self, column_name: &str) -> String {
+        match self {
+            TextFilterKind::Ngram(n) => format!("Ngram{}({})", n, column_name),
+            TextFilterKind::Token => format!("Token({})", column_name),
+        }
+    }
+
+    /// Build the filter of the keys of the values, returns None if there is no key at all.
+    pub fn build_filter<'a>(
+        &self,
+        values: impl Iterator<Item = &'a [u8]>,
+    ) -> Result<Option<Xor8Filter>> {
+        let mut keys = HashSet::new();
+        for value in values {
+            match self {
+                TextFilterKind::Ngram(n) => keys.extend(value.windows(*n)),
+                TextFilterKind::Token => keys.extend(tokenize_text(value)),
+            }
+        }
+        if keys.is_empty() {
+            return Ok(None);
+        }
+
+        let mut builder = Xor8Builder::create();
+        for key in keys {
+            builder.add_key(&key);
+        }
+        Ok(Some(builder.build()?))
+    }
+
+    /// Returns false if the filter is sure that no value satisfies the predicate,
+    /// e.g. one of the keys required by the predicate is not in the filter.
+    pub fn may_satisfy(&self, filter: &Xor8Filter, predicate: &TextPredicate) -> bool {
+        self.required_keys(predicate)
+            .iter()
+            .all(|key| filter.contains(key))
+    }
+
+    fn required_keys<'a>(&self, predicate: &TextPredicate<'a>) -> Vec<&'a [u8]> {
+        let fragments = match predicate {
+            TextPredicate::Like(pattern) => like_fragments(pattern),
+            TextPredicate::Contains(needle) => vec![Fragment {
+                text: needle,
+                left_bounded: false,
+                right_bounded: false,
+            }],
+            TextPredicate::Match(query) => tokenize_text(query)
+                .map(|token| Fragment {
+                    text: token,
+                    left_bounded: true,
+                    right_bounded: true,
+                })
+                .collect(),
+        };
+
+        let mut keys = vec![];
+        for fragment in fragments {
+            match self {
+                TextFilterKind::Ngram(n) => keys.extend(fragment.text.windows(*n)),
+                TextFilterKind::Token => keys.extend(fragment.bounded_tokens()),
+            }
+        }
+        keys
+    }
+}
+
+/// A substring that every value satisfying a predicate contains.
+struct Fragment<'a> {
+    text: &'a [u8],
+    /// Whether the substring starts at a token boundary of the value.
+    left_bounded: bool,
+    /// Whether the substring ends at a token boundary of the value.
+    right_bounded: bool,
+}
+
+impl<'a> Fragment<'a> {
+    /// The tokens of the fragment that are also tokens of the value, a token cut by the
+    /// start or the end of the fragment may be a part of a longer token of the value.
+    fn bounded_tokens(&self) -> Vec<&'a [u8]> {
+        let text = self.text;
+        let mut tokens = vec![];
+        for token in tokenize_text(text) {
+            let start = token.as_ptr() as usize - text.as_ptr() as usize;
+            let end = start + token.len();
+            if (start > 0 || self.left_bounded) && (end < text.len() || self.right_bounded) {
+                tokens.push(token);
+            }
+        }
+        tokens
+    }
+}
+
+/// Split the LIKE pattern by the wildcards into the literal substrings of the matched values.
+///
+/// An escaped character is skipped rather than kept as a literal, as the fast paths of `like`
+/// match some patterns with backslashes as is.
+fn like_fragments(pattern: &[u8]) -> Vec<Fragment> {
+    let mut fragments = vec![];
+    let mut start = 0;
+    let mut i = 0;
+    while i <= pattern.len() {
+        if i < pattern.len() && !matches!(pattern[i], b'%' | b'_' | b'\\') {
+            i += 1;
+            continue;
+        }
+        if i > start {
+            fragments.push(Fragment {
+                text: &pattern[start..i],
+                left_bounded: start == 0,
+                right_bounded: i == pattern.len(),
+            });
+        }
+        i += if i < pattern.len() && pattern[i] == b'\\' {
+            2
+        } else {
+            1
+        };
+        start = i;
+    }
+    fragments
+}
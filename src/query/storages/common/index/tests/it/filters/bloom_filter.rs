@@ -37,6 +37,7 @@ use common_functions::scalars::BUILTIN_FUNCTIONS;
 use storages_common_index::filters::BlockFilter as LatestBloom;
 use storages_common_index::BloomIndex;
 use storages_common_index::FilterEvalResult;
+use storages_common_index::TextIndexOptions;
 use storages_common_table_meta::meta::Versioned;
 
 #[test]
@@ -71,6 +72,7 @@ fn test_bloom_filter() -> Result<()> {
         schema,
         LatestBloom::VERSION,
         &blocks_ref,
+        &TextIndexOptions::default(),
     )?
     .unwrap();
 
@@ -117,6 +119,106 @@ fn test_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_text_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("ngram", TableDataType::String),
+        TableField::new("token", TableDataType::String),
+    ]));
+    let blocks = vec![DataBlock::new_from_columns(vec![
+        StringType::from_data(vec!["error-code-123 in handler", "ok"]),
+        StringType::from_data(vec!["error-code-123 in handler", "ok"]),
+    ])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+    let text_index = TextIndexOptions {
+        ngram_columns: vec!["ngram".to_string()],
+        ngram_size: 3,
+        token_columns: vec!["token".to_string()],
+    };
+
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        schema,
+        LatestBloom::VERSION,
+        &blocks_ref,
+        &text_index,
+    )?
+    .unwrap();
+
+    let cases = [
+        ("like", "ngram", "%code-123%", FilterEvalResult::Uncertain),
+        ("like", "ngram", "%code-124%", FilterEvalResult::MustFalse),
+        ("like", "ngram", "err_r%", FilterEvalResult::Uncertain),
+        ("like", "ngram", "%xy%", FilterEvalResult::Uncertain),
+        ("like", "token", "%code-123%", FilterEvalResult::Uncertain),
+        ("like", "token", "%-code-%", FilterEvalResult::Uncertain),
+        ("like", "token", "%-cod-%", FilterEvalResult::MustFalse),
+        ("like", "token", "%od%", FilterEvalResult::Uncertain),
+        ("like", "token", "error%", FilterEvalResult::Uncertain),
+        ("like", "token", "erro%", FilterEvalResult::Uncertain),
+        ("like", "token", "warn %", FilterEvalResult::MustFalse),
+        (
+            "match",
+            "token",
+            "handler code",
+            FilterEvalResult::Uncertain,
+        ),
+        ("match", "token", "handle", FilterEvalResult::MustFalse),
+        ("match", "ngram", "handler", FilterEvalResult::Uncertain),
+        ("match", "ngram", "warning", FilterEvalResult::MustFalse),
+        ("instr", "ngram", "in hand", FilterEvalResult::Uncertain),
+        ("instr", "ngram", "on hand", FilterEvalResult::MustFalse),
+    ];
+    for (func, col_name, text, expected) in cases {
+        let expr = check_function(
+            None,
+            func,
+            &[],
+            &[
+                Expr::ColumnRef {
+                    span: None,
+                    id: col_name.to_string(),
+                    data_type: DataType::String,
+                    display_name: col_name.to_string(),
+                },
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::String(text.as_bytes().to_vec()),
+                    data_type: DataType::String,
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )?;
+        // `instr` returns the position of the text, which is 0 if not found.
+        let expr = if func == "instr" {
+            check_function(
+                None,
+                "gt",
+                &[],
+                &[expr, Expr::Constant {
+                    span: None,
+                    scalar: Scalar::Number(NumberScalar::UInt64(0)),
+                    data_type: DataType::Number(NumberDataType::UInt64),
+                }],
+                &BUILTIN_FUNCTIONS,
+            )?
+        } else {
+            expr
+        };
+        assert_eq!(
+            BloomIndex::find_text_filter_columns(&expr, &text_index)?.len(),
+            1
+        );
+        assert_eq!(
+            expected,
+            index.apply(expr, &HashMap::new())?,
+            "{func}({col_name}, '{text}')"
+        );
+    }
+
+    Ok(())
+}
+
 fn eval_index(index: &BloomIndex, col_name: &str, val: Scalar, ty: DataType) -> FilterEvalResult {
     let expr = check_function(
         None,
@@ -13,12 +13,16 @@
 //  limitations under the License.
 
 pub use v0::ColumnMeta as SingleColumnMeta;
+pub use v1::HistogramBucket;
 pub use v1::TableSnapshotStatistics;
 pub use v2::BlockMeta;
 pub use v2::ColumnMeta;
 pub use v2::SegmentInfo;
+pub use v2::SnapshotMutation;
 pub use v2::TableSnapshot;
 pub use v2::TableSnapshotLite;
+pub use v2::VirtualBlockMeta;
+pub use v2::VirtualColumnMeta;
 
 use super::v0;
 use super::v1;
@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 
+use common_expression::Scalar;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -31,14 +32,39 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
 
     pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// Number of null values of each column.
+    #[serde(default)]
+    pub column_null_counts: HashMap<ColumnId, u64>,
+
+    /// Equi-height histograms of the non-null values of each column, the buckets are
+    /// ordered by their upper bounds.
+    #[serde(default)]
+    pub column_histograms: HashMap<ColumnId, Vec<HistogramBucket>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HistogramBucket {
+    /// The max value in the bucket, the lower bound is the upper bound of the previous bucket.
+    pub upper_bound: Scalar,
+    /// Estimated number of values in the bucket.
+    pub num_values: f64,
+    /// Estimated number of distinct values in the bucket.
+    pub num_distinct: f64,
 }
 
 impl TableSnapshotStatistics {
-    pub fn new(column_distinct_values: HashMap<ColumnId, u64>) -> Self {
+    pub fn new(
+        column_distinct_values: HashMap<ColumnId, u64>,
+        column_null_counts: HashMap<ColumnId, u64>,
+        column_histograms: HashMap<ColumnId, Vec<HistogramBucket>>,
+    ) -> Self {
         Self {
             format_version: TableSnapshotStatistics::VERSION,
             snapshot_id: SnapshotId::new_v4(),
             column_distinct_values,
+            column_null_counts,
+            column_histograms,
         }
     }
 
@@ -49,4 +75,12 @@ impl TableSnapshotStatistics {
     pub fn get_column_distinct_values(&self) -> &HashMap<ColumnId, u64> {
         &self.column_distinct_values
     }
+
+    pub fn get_column_null_counts(&self) -> &HashMap<ColumnId, u64> {
+        &self.column_null_counts
+    }
+
+    pub fn get_column_histograms(&self) -> &HashMap<ColumnId, Vec<HistogramBucket>> {
+        &self.column_histograms
+    }
 }
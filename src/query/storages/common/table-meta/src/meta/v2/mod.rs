@@ -18,5 +18,8 @@ mod snapshot;
 pub use segment::BlockMeta;
 pub use segment::ColumnMeta;
 pub use segment::SegmentInfo;
+pub use segment::VirtualBlockMeta;
+pub use segment::VirtualColumnMeta;
+pub use snapshot::SnapshotMutation;
 pub use snapshot::TableSnapshot;
 pub use snapshot::TableSnapshotLite;
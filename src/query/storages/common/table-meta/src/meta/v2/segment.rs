@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_arrow::native::ColumnMeta as NativeColumnMeta;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use enum_as_inner::EnumAsInner;
 use serde::Deserialize;
@@ -59,6 +60,23 @@ pub struct BlockMeta {
     #[serde(default)]
     pub bloom_filter_index_size: u64,
     pub compression: Compression,
+    /// location of inverted index
+    #[serde(default)]
+    pub inverted_index_location: Option<Location>,
+    #[serde(default)]
+    pub inverted_index_size: u64,
+    /// location of deletion vector, the offsets of the rows removed by deletes,
+    /// which are skipped when the block is read
+    #[serde(default)]
+    pub deletion_vector_location: Option<Location>,
+    #[serde(default)]
+    pub deletion_vector_size: u64,
+    /// number of rows in the deletion vector, which are included in `row_count`
+    #[serde(default)]
+    pub deleted_row_count: u64,
+    /// the virtual columns of the block, stored in its file after the columns of the table
+    #[serde(default)]
+    pub virtual_block_meta: Option<VirtualBlockMeta>,
 }
 
 impl BlockMeta {
@@ -74,6 +92,8 @@ impl BlockMeta {
         bloom_filter_index_location: Option<Location>,
         bloom_filter_index_size: u64,
         compression: Compression,
+        inverted_index_location: Option<Location>,
+        inverted_index_size: u64,
     ) -> Self {
         Self {
             row_count,
@@ -86,12 +106,57 @@ impl BlockMeta {
             bloom_filter_index_location,
             bloom_filter_index_size,
             compression,
+            inverted_index_location,
+            inverted_index_size,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
+            virtual_block_meta: None,
         }
     }
 
     pub fn compression(&self) -> Compression {
         self.compression
     }
+
+    /// The number of rows of the block that have not been deleted.
+    pub fn live_row_count(&self) -> u64 {
+        self.row_count - self.deleted_row_count
+    }
+}
+
+/// The virtual columns of a block, the values at some paths of its variant columns
+/// extracted into typed columns, which are stored in the file of the block after the
+/// columns of the table.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VirtualBlockMeta {
+    /// location of the bloom filter index of the virtual columns
+    pub bloom_filter_index_location: Option<Location>,
+    pub bloom_filter_index_size: u64,
+    /// the virtual columns in the order they are stored
+    pub columns: Vec<VirtualColumnMeta>,
+}
+
+impl VirtualBlockMeta {
+    /// The position among the virtual columns of the block of `name` cast to `cast_type`.
+    pub fn column_position(&self, name: &str, cast_type: &TableDataType) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|c| c.name == name && &c.cast_type == cast_type)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VirtualColumnMeta {
+    /// name of the virtual column, `<source column>:<path>`
+    pub name: String,
+    /// the type that the values are cast to, `Variant` if they are not cast
+    pub cast_type: TableDataType,
+    /// the type of the stored column
+    pub data_type: TableDataType,
+    /// meta of the column in the file of the block
+    pub col_meta: ColumnMeta,
+    pub col_stats: Option<ColumnStatistics>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, EnumAsInner)]
@@ -194,6 +259,12 @@ impl BlockMeta {
             bloom_filter_index_location: None,
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
+            inverted_index_location: None,
+            inverted_index_size: 0,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
+            virtual_block_meta: None,
         }
     }
 
@@ -224,6 +295,12 @@ impl BlockMeta {
             bloom_filter_index_location: s.bloom_filter_index_location.clone(),
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
+            inverted_index_location: None,
+            inverted_index_size: 0,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
+            virtual_block_meta: None,
         }
     }
 }
@@ -60,6 +60,32 @@ pub struct TableSnapshot {
     // The metadata of the cluster keys.
     pub cluster_key_meta: Option<ClusterKey>,
     pub table_statistics_location: Option<String>,
+
+    /// The mutation that committed this snapshot, `None` if the snapshot was not committed by a
+    /// mutation (or was written before mutations were recorded).
+    #[serde(default)]
+    pub mutation: Option<SnapshotMutation>,
+}
+
+/// The kind of mutation that rewrote blocks of a table.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnapshotMutation {
+    Delete,
+    Update,
+    /// Merges blocks without changing the rows of the table.
+    Compact,
+    /// Sorts blocks by the cluster key without changing the rows of the table.
+    Recluster,
+}
+
+impl SnapshotMutation {
+    /// Whether the mutation only reorganizes the rows of the table.
+    pub fn keeps_rows(&self) -> bool {
+        matches!(
+            self,
+            SnapshotMutation::Compact | SnapshotMutation::Recluster
+        )
+    }
 }
 
 impl TableSnapshot {
@@ -91,6 +117,7 @@ impl TableSnapshot {
             segments,
             cluster_key_meta,
             table_statistics_location,
+            mutation: None,
         }
     }
 
@@ -131,6 +158,7 @@ impl From<v0::TableSnapshot> for TableSnapshot {
             segments: s.segments.into_iter().map(|l| (l, 0)).collect(),
             cluster_key_meta: None,
             table_statistics_location: None,
+            mutation: None,
         }
     }
 }
@@ -150,6 +178,7 @@ impl From<v1::TableSnapshot> for TableSnapshot {
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            mutation: None,
         }
     }
 }
@@ -0,0 +1,57 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_INVERTED_INDEXES;
+
+/// An inverted index created on some string columns of a table by `CREATE INVERTED INDEX`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InvertedIndexDefinition {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub tokenizer: String,
+}
+
+impl InvertedIndexDefinition {
+    /// Loads the inverted indexes kept in the table options.
+    pub fn from_table_options(options: &BTreeMap<String, String>) -> Result<Vec<Self>> {
+        match options.get(OPT_KEY_INVERTED_INDEXES) {
+            None => Ok(vec![]),
+            Some(value) => serde_json::from_str(value).map_err(|e| {
+                ErrorCode::StorageOther(format!("invalid inverted index definitions: {}", e))
+            }),
+        }
+    }
+
+    /// Encodes the inverted indexes as the value of the table option.
+    pub fn to_table_option(indexes: &[Self]) -> Result<String> {
+        serde_json::to_string(indexes).map_err(|e| {
+            ErrorCode::StorageOther(format!("invalid inverted index definitions: {}", e))
+        })
+    }
+
+    /// The tokenizer of the inverted index on the column, if any.
+    pub fn column_tokenizer<'a>(indexes: &'a [Self], column: &str) -> Option<&'a str> {
+        indexes
+            .iter()
+            .find(|index| index.columns.iter().any(|c| c == column))
+            .map(|index| index.tokenizer.as_str())
+    }
+}
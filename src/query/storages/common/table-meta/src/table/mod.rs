@@ -12,10 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod inverted_index;
+mod snapshot_ref;
+mod stream;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
+mod virtual_column;
 
+pub use inverted_index::InvertedIndexDefinition;
+pub use snapshot_ref::SnapshotRef;
+pub use snapshot_ref::SnapshotRefKind;
+pub use stream::*;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
 pub use table_prefix::*;
+pub use virtual_column::virtual_column_name;
+pub use virtual_column::VirtualColumnDefinition;
+pub use virtual_column::VirtualColumnKey;
@@ -0,0 +1,79 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_SNAPSHOT_REFS;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnapshotRefKind {
+    /// Pins a snapshot, created by `ALTER TABLE ... CREATE TAG`.
+    Tag,
+    /// The head of a snapshot chain written apart from the table, created by
+    /// `ALTER TABLE ... CREATE BRANCH`.
+    Branch,
+}
+
+impl Display for SnapshotRefKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            SnapshotRefKind::Tag => write!(f, "tag"),
+            SnapshotRefKind::Branch => write!(f, "branch"),
+        }
+    }
+}
+
+/// A named snapshot of a table. The snapshots of tags and branches are never purged.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotRef {
+    pub name: String,
+    pub kind: SnapshotRefKind,
+    pub snapshot_location: String,
+}
+
+impl SnapshotRef {
+    /// Loads the tags and branches kept in the table options.
+    pub fn from_table_options(options: &BTreeMap<String, String>) -> Result<Vec<Self>> {
+        match options.get(OPT_KEY_SNAPSHOT_REFS) {
+            None => Ok(vec![]),
+            Some(value) => serde_json::from_str(value).map_err(|e| {
+                ErrorCode::StorageOther(format!("invalid snapshot references: {}", e))
+            }),
+        }
+    }
+
+    /// Encodes the tags and branches as the value of the table option.
+    pub fn to_table_option(refs: &[Self]) -> Result<String> {
+        serde_json::to_string(refs)
+            .map_err(|e| ErrorCode::StorageOther(format!("invalid snapshot references: {}", e)))
+    }
+
+    /// The tag or branch of the name, which must be of the given kind.
+    pub fn find<'a>(refs: &'a [Self], name: &str, kind: SnapshotRefKind) -> Result<&'a Self> {
+        match refs.iter().find(|r| r.name == name) {
+            Some(r) if r.kind == kind => Ok(r),
+            _ => Err(ErrorCode::UnknownSnapshotReference(format!(
+                "unknown {} '{}'",
+                kind, name
+            ))),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::TableDataType;
+use common_expression::TableField;
+
+/// The engine of the tables created by `CREATE STREAM`. A stream keeps no data, it reads the
+/// changes of a fuse table since its offset.
+pub const STREAM_ENGINE: &str = "STREAM";
+
+/// The database of the table a stream is created on.
+pub const OPT_KEY_STREAM_SOURCE_DATABASE: &str = "table_database";
+/// The name of the table a stream is created on.
+pub const OPT_KEY_STREAM_SOURCE_TABLE: &str = "table_name";
+/// The id of the table a stream is created on, which tells it from a re-created one.
+pub const OPT_KEY_STREAM_SOURCE_TABLE_ID: &str = "table_id";
+/// The snapshot location of the table at the offset of a stream, absent if the table had no
+/// snapshot then.
+pub const OPT_KEY_STREAM_OFFSET: &str = "offset_snapshot_location";
+
+/// `INSERT` or `DELETE`, the change of a row read from a stream.
+pub const STREAM_COLUMN_CHANGE_ACTION: &str = "change$action";
+/// Whether the row read from a stream is one half of an update.
+pub const STREAM_COLUMN_CHANGE_IS_UPDATE: &str = "change$is_update";
+
+pub const STREAM_CHANGE_ACTION_INSERT: &str = "INSERT";
+pub const STREAM_CHANGE_ACTION_DELETE: &str = "DELETE";
+
+/// The metadata columns following the columns of the table in the schema of a stream.
+pub fn stream_change_fields() -> Vec<TableField> {
+    vec![
+        TableField::new(STREAM_COLUMN_CHANGE_ACTION, TableDataType::String),
+        TableField::new(STREAM_COLUMN_CHANGE_IS_UPDATE, TableDataType::Boolean),
+    ]
+}
@@ -21,6 +21,29 @@ pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
 pub const OPT_KEY_STORAGE_FORMAT: &str = "storage_format";
 pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
 
+/// The defining query of a materialized view, kept on the fuse table storing its rows.
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+/// The id of the base table that a materialized view aggregates.
+pub const OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID: &str = "materialized_view_source_table_id";
+/// The snapshot location of the base table that a materialized view was last refreshed to.
+pub const OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT_LOCATION: &str = "materialized_view_snapshot_location";
+/// The inverted indexes created on the table, see `InvertedIndexDefinition`.
+pub const OPT_KEY_INVERTED_INDEXES: &str = "inverted_indexes";
+/// The comma separated ids of the tables whose files a cloned table shares, nearest first.
+pub const OPT_KEY_CLONE_SOURCE_TABLE_IDS: &str = "clone_source_table_ids";
+/// The snapshot of the source table that a cloned table was created from.
+pub const OPT_KEY_CLONE_SOURCE_SNAPSHOT_LOCATION: &str = "clone_source_snapshot_location";
+/// The comma separated ids of the clones and streams reading the files of the table,
+/// registered when they are created.
+pub const OPT_KEY_DEPENDENT_TABLE_IDS: &str = "dependent_table_ids";
+/// The tags and branches of the table, see `SnapshotRef`.
+pub const OPT_KEY_SNAPSHOT_REFS: &str = "snapshot_refs";
+/// The branch written by a statement instead of the main snapshot chain. Only set on the table
+/// instance of the statement, it is never committed.
+pub const OPT_KEY_SNAPSHOT_BRANCH: &str = "snapshot_branch";
+/// The virtual columns of the table, see `VirtualColumnDefinition`.
+pub const OPT_KEY_VIRTUAL_COLUMNS: &str = "virtual_columns";
+
 /// Legacy table snapshot location key
 ///
 /// # Deprecated
@@ -38,6 +61,16 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_INVERTED_INDEXES);
+    r.insert(OPT_KEY_CLONE_SOURCE_TABLE_IDS);
+    r.insert(OPT_KEY_CLONE_SOURCE_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_DEPENDENT_TABLE_IDS);
+    r.insert(OPT_KEY_SNAPSHOT_REFS);
+    r.insert(OPT_KEY_SNAPSHOT_BRANCH);
+    r.insert(OPT_KEY_VIRTUAL_COLUMNS);
     r
 });
 
@@ -46,6 +79,16 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_INVERTED_INDEXES);
+    r.insert(OPT_KEY_CLONE_SOURCE_TABLE_IDS);
+    r.insert(OPT_KEY_CLONE_SOURCE_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_DEPENDENT_TABLE_IDS);
+    r.insert(OPT_KEY_SNAPSHOT_REFS);
+    r.insert(OPT_KEY_SNAPSHOT_BRANCH);
+    r.insert(OPT_KEY_VIRTUAL_COLUMNS);
     r
 });
 
@@ -0,0 +1,176 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_VIRTUAL_COLUMNS;
+
+/// A virtual column of a table, the value at a path of a variant column, created by
+/// `CREATE VIRTUAL COLUMN` or detected by the writes to the columns listed in the
+/// `auto_virtual_columns` option.
+///
+/// The blocks written after it is created store its values as a typed column with
+/// statistics and bloom filters, queries accessing the path read that column instead of
+/// decoding the variant values.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VirtualColumnDefinition {
+    pub source_column: String,
+    /// The path of the value, in the form formatted by `VirtualColumnKey::format_path`.
+    pub path: String,
+    /// The type that the value is cast to, `Variant` if it is not cast.
+    pub cast_type: TableDataType,
+}
+
+impl VirtualColumnDefinition {
+    /// The name of the virtual column, `<source column>:<path>`.
+    pub fn name(&self) -> String {
+        virtual_column_name(&self.source_column, &self.path)
+    }
+
+    /// Loads the virtual columns kept in the table options.
+    pub fn from_table_options(options: &BTreeMap<String, String>) -> Result<Vec<Self>> {
+        match options.get(OPT_KEY_VIRTUAL_COLUMNS) {
+            None => Ok(vec![]),
+            Some(value) => serde_json::from_str(value).map_err(|e| {
+                ErrorCode::StorageOther(format!("invalid virtual column definitions: {}", e))
+            }),
+        }
+    }
+
+    /// Encodes the virtual columns as the value of the table option.
+    pub fn to_table_option(columns: &[Self]) -> Result<String> {
+        serde_json::to_string(columns).map_err(|e| {
+            ErrorCode::StorageOther(format!("invalid virtual column definitions: {}", e))
+        })
+    }
+}
+
+pub fn virtual_column_name(source_column: &str, path: &str) -> String {
+    format!("{}:{}", source_column, path)
+}
+
+/// A step of the path of a virtual column.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VirtualColumnKey {
+    /// The field of an object.
+    Name(String),
+    /// The element of an array.
+    Index(u64),
+}
+
+impl VirtualColumnKey {
+    /// Formats the path made of `keys`, like `user.id` or `items[0]["sku id"]`.
+    ///
+    /// The names made of letters, digits and underscores are separated by periods, the other
+    /// names are quoted in brackets, as the indexes are.
+    pub fn format_path(keys: &[Self]) -> String {
+        let mut path = String::new();
+        for key in keys {
+            match key {
+                VirtualColumnKey::Name(name) if Self::is_plain_name(name) => {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(name);
+                }
+                VirtualColumnKey::Name(name) => {
+                    path.push_str("[\"");
+                    for c in name.chars() {
+                        if c == '"' || c == '\\' {
+                            path.push('\\');
+                        }
+                        path.push(c);
+                    }
+                    path.push_str("\"]");
+                }
+                VirtualColumnKey::Index(index) => {
+                    path.push_str(&format!("[{}]", index));
+                }
+            }
+        }
+        path
+    }
+
+    /// Parses a path formatted by `format_path`.
+    pub fn parse_path(path: &str) -> Result<Vec<Self>> {
+        let invalid = || ErrorCode::BadArguments(format!("invalid virtual column path: {}", path));
+
+        let mut keys = vec![];
+        let mut chars = path.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '[' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    let mut name = String::new();
+                    loop {
+                        match chars.next().ok_or_else(invalid)? {
+                            '\\' => name.push(chars.next().ok_or_else(invalid)?),
+                            '"' => break,
+                            c => name.push(c),
+                        }
+                    }
+                    if chars.next() != Some(']') {
+                        return Err(invalid());
+                    }
+                    keys.push(VirtualColumnKey::Name(name));
+                }
+                '[' => {
+                    let mut index = String::new();
+                    loop {
+                        match chars.next().ok_or_else(invalid)? {
+                            ']' => break,
+                            c => index.push(c),
+                        }
+                    }
+                    let index = index.parse::<u64>().map_err(|_| invalid())?;
+                    keys.push(VirtualColumnKey::Index(index));
+                }
+                c if (c == '.' && !keys.is_empty())
+                    || (Self::is_plain_char(c) && keys.is_empty()) =>
+                {
+                    let mut name = String::new();
+                    if c != '.' {
+                        name.push(c);
+                    }
+                    while let Some(c) = chars.next_if(|c| Self::is_plain_char(*c)) {
+                        name.push(c);
+                    }
+                    if name.is_empty() {
+                        return Err(invalid());
+                    }
+                    keys.push(VirtualColumnKey::Name(name));
+                }
+                _ => return Err(invalid()),
+            }
+        }
+        if keys.is_empty() {
+            return Err(invalid());
+        }
+        Ok(keys)
+    }
+
+    fn is_plain_name(name: &str) -> bool {
+        !name.is_empty() && name.chars().all(Self::is_plain_char)
+    }
+
+    fn is_plain_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '_'
+    }
+}
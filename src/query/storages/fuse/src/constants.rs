@@ -17,10 +17,24 @@ pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
 pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
+/// Comma separated string columns to build ngram filters for.
+pub const FUSE_OPT_KEY_NGRAM_INDEX_COLUMNS: &str = "ngram_index_columns";
+pub const FUSE_OPT_KEY_NGRAM_SIZE: &str = "ngram_size";
+/// Comma separated string columns to build token filters for.
+pub const FUSE_OPT_KEY_TOKENBF_INDEX_COLUMNS: &str = "tokenbf_index_columns";
+/// Record the rows removed by deletes in deletion vectors instead of rewriting the blocks.
+pub const FUSE_OPT_KEY_ENABLE_DELETION_VECTORS: &str = "enable_deletion_vectors";
+/// Comma separated variant columns to detect the hot paths of during writes, which are
+/// stored as virtual columns. Ignored by the tables of native storage format, which read the
+/// paths from the variant columns.
+pub const FUSE_OPT_KEY_AUTO_VIRTUAL_COLUMNS: &str = "auto_virtual_columns";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
 pub const FUSE_TBL_XOR_BLOOM_INDEX_PREFIX: &str = "_i_b_v2";
+pub const FUSE_TBL_INVERTED_INDEX_PREFIX: &str = "_i_inv";
+pub const FUSE_TBL_DELETION_VECTOR_PREFIX: &str = "_dv";
+pub const FUSE_TBL_VIRTUAL_BLOOM_INDEX_PREFIX: &str = "_vb_i";
 pub const FUSE_TBL_SEGMENT_PREFIX: &str = "_sg";
 pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
 pub const FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX: &str = "_ts";
@@ -27,6 +27,9 @@ use common_exception::Result;
 use common_expression::Scalar;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+use storages_common_table_meta::meta::VirtualBlockMeta;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct FusePartInfo {
@@ -37,10 +40,16 @@ pub struct FusePartInfo {
     pub nums_rows: usize,
     pub columns_meta: HashMap<usize, ColumnMeta>,
     pub compression: Compression,
+    /// The statistics of the block, used to prune the part with runtime filters
+    pub columns_stat: StatisticsOfColumns,
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     /// page range in the file
     pub range: Option<Range<usize>>,
+    /// The deleted rows of the block, `nums_rows` includes them
+    pub deletion_vector_location: Option<Location>,
+    /// The virtual columns stored by the block
+    pub virtual_block_meta: Option<VirtualBlockMeta>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -64,14 +73,18 @@ impl PartInfo for FusePartInfo {
 }
 
 impl FusePartInfo {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         location: String,
         format_version: u64,
         rows_count: u64,
         columns_meta: HashMap<usize, ColumnMeta>,
         compression: Compression,
+        columns_stat: StatisticsOfColumns,
         sort_min_max: Option<(Scalar, Scalar)>,
         range: Option<Range<usize>>,
+        deletion_vector_location: Option<Location>,
+        virtual_block_meta: Option<VirtualBlockMeta>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -79,8 +92,11 @@ impl FusePartInfo {
             columns_meta,
             nums_rows: rows_count as usize,
             compression,
+            columns_stat,
             sort_min_max,
             range,
+            deletion_vector_location,
+            virtual_block_meta,
         }))
     }
 
@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::str;
@@ -24,19 +25,25 @@ use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::VirtualColumnInfo;
 use common_catalog::table::AppendMode;
 use common_catalog::table::ColumnId;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::ColumnStatisticsProvider;
 use common_catalog::table::CompactTarget;
+use common_catalog::table::HistogramBucket;
 use common_catalog::table::NavigationDescriptor;
+use common_catalog::table::TermStatistics;
 use common_catalog::table_context::TableContext;
 use common_catalog::table_mutator::TableMutator;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::infer_schema_type;
+use common_expression::types::DataType;
 use common_expression::BlockThresholds;
 use common_expression::DataBlock;
 use common_expression::RemoteExpr;
+use common_expression::TableSchemaRef;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_meta_app::schema::DatabaseType;
@@ -53,6 +60,8 @@ use common_storage::StorageMetricsLayer;
 use opendal::layers::CacheLayer;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
+use storages_common_index::TextIndexOptions;
+use storages_common_index::DEFAULT_NGRAM_SIZE;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
 use storages_common_table_meta::meta::Statistics as FuseStatistics;
@@ -60,14 +69,21 @@ use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::table_storage_prefix;
+use storages_common_table_meta::table::InvertedIndexDefinition;
+use storages_common_table_meta::table::SnapshotRef;
+use storages_common_table_meta::table::SnapshotRefKind;
 use storages_common_table_meta::table::TableCompression;
+use storages_common_table_meta::table::VirtualColumnDefinition;
+use storages_common_table_meta::table::VirtualColumnKey;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_BRANCH;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use uuid::Uuid;
 
+use crate::io::virtual_column_expr;
 use crate::io::MetaReaders;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::WriteSettings;
@@ -79,10 +95,14 @@ use crate::TableStatistics;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
+use crate::FUSE_OPT_KEY_AUTO_VIRTUAL_COLUMNS;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_NGRAM_INDEX_COLUMNS;
+use crate::FUSE_OPT_KEY_NGRAM_SIZE;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
+use crate::FUSE_OPT_KEY_TOKENBF_INDEX_COLUMNS;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
 
 #[derive(Clone)]
@@ -96,6 +116,9 @@ pub struct FuseTable {
 
     pub(crate) operator: Operator,
     pub(crate) data_metrics: Arc<StorageMetrics>,
+
+    /// The table options updated in the same table meta as the snapshots committed.
+    pub(crate) commit_options: BTreeMap<String, String>,
 }
 
 impl FuseTable {
@@ -157,6 +180,7 @@ impl FuseTable {
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
             table_compression: table_compression.as_str().try_into()?,
+            commit_options: BTreeMap::new(),
         }))
     }
 
@@ -191,9 +215,62 @@ impl FuseTable {
             table_compression: self.table_compression,
             max_page_size,
             block_per_seg,
+            text_index: self.text_index_options(),
+            inverted_indexes: self.inverted_indexes(),
+            virtual_columns: self.virtual_column_definitions(),
+            auto_virtual_columns: self.auto_virtual_columns(),
+        }
+    }
+
+    /// The inverted indexes created on the table by `CREATE INVERTED INDEX`.
+    pub fn inverted_indexes(&self) -> Vec<InvertedIndexDefinition> {
+        InvertedIndexDefinition::from_table_options(self.table_info.options()).unwrap_or_default()
+    }
+
+    /// The ngram and token filters to build beside the bloom filters, chosen by table options.
+    pub fn text_index_options(&self) -> TextIndexOptions {
+        TextIndexOptions {
+            ngram_columns: self.get_column_list_option(FUSE_OPT_KEY_NGRAM_INDEX_COLUMNS),
+            ngram_size: self.get_option(FUSE_OPT_KEY_NGRAM_SIZE, DEFAULT_NGRAM_SIZE),
+            token_columns: self.get_column_list_option(FUSE_OPT_KEY_TOKENBF_INDEX_COLUMNS),
         }
     }
 
+    /// The virtual columns created on the table by `CREATE VIRTUAL COLUMN`, or detected by
+    /// the writes to the columns listed in the `auto_virtual_columns` option.
+    ///
+    /// They are only stored by the tables of parquet format, in the block files after the
+    /// columns of the table. The native blocks are read as they are, the paths of the variant
+    /// columns are extracted by the queries, and `CREATE VIRTUAL COLUMN` is rejected for them.
+    pub fn virtual_column_definitions(&self) -> Vec<VirtualColumnDefinition> {
+        if !matches!(self.storage_format, FuseStorageFormat::Parquet) {
+            return vec![];
+        }
+        VirtualColumnDefinition::from_table_options(self.table_info.options()).unwrap_or_default()
+    }
+
+    /// The variant columns whose hot paths are detected during writes.
+    pub fn auto_virtual_columns(&self) -> Vec<String> {
+        if !matches!(self.storage_format, FuseStorageFormat::Parquet) {
+            return vec![];
+        }
+        self.get_column_list_option(FUSE_OPT_KEY_AUTO_VIRTUAL_COLUMNS)
+    }
+
+    // The columns listed in the comma separated table option.
+    fn get_column_list_option(&self, opt_key: &str) -> Vec<String> {
+        self.table_info
+            .options()
+            .get(opt_key)
+            .map(|v| {
+                v.split(',')
+                    .map(|c| c.trim().to_string())
+                    .filter(|c| !c.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     pub fn parse_storage_prefix(table_info: &TableInfo) -> Result<String> {
         let table_id = table_info.ident.table_id;
         let db_id = table_info
@@ -275,6 +352,11 @@ impl FuseTable {
             }
             DatabaseType::NormalDB => {
                 let options = self.table_info.options();
+                if let Some(branch) = options.get(OPT_KEY_SNAPSHOT_BRANCH) {
+                    let refs = SnapshotRef::from_table_options(options)?;
+                    let branch_ref = SnapshotRef::find(&refs, branch, SnapshotRefKind::Branch)?;
+                    return Ok(Some(branch_ref.snapshot_location.clone()));
+                }
                 Ok(options
                     .get(OPT_KEY_SNAPSHOT_LOCATION)
                     // for backward compatibility, we check the legacy table option
@@ -284,6 +366,37 @@ impl FuseTable {
         }
     }
 
+    /// The branch the table is written to instead of its main snapshot chain, if any.
+    pub fn branch(&self) -> Option<&str> {
+        self.table_info
+            .options()
+            .get(OPT_KEY_SNAPSHOT_BRANCH)
+            .map(|branch| branch.as_str())
+    }
+
+    /// The table reading and writing the head snapshot of the branch, the commits of which move
+    /// the branch instead of the table.
+    pub fn with_branch(&self, branch: &str) -> Result<Arc<dyn Table>> {
+        let refs = SnapshotRef::from_table_options(self.table_info.options())?;
+        SnapshotRef::find(&refs, branch, SnapshotRefKind::Branch)?;
+        let mut table_info = self.table_info.clone();
+        table_info
+            .meta
+            .options
+            .insert(OPT_KEY_SNAPSHOT_BRANCH.to_owned(), branch.to_owned());
+        let table: Arc<FuseTable> = FuseTable::do_create(table_info)?.into();
+        Ok(table)
+    }
+
+    /// The table committing the options along with its snapshots, the options are kept by the
+    /// retries of the commits.
+    pub fn with_commit_options(&self, options: BTreeMap<String, String>) -> Result<Arc<dyn Table>> {
+        let mut table = FuseTable::do_create(self.table_info.clone())?;
+        table.commit_options = options;
+        let table: Arc<FuseTable> = table.into();
+        Ok(table)
+    }
+
     pub fn get_operator(&self) -> Operator {
         self.operator.clone()
     }
@@ -304,6 +417,17 @@ impl FuseTable {
     pub fn transient(&self) -> bool {
         self.table_info.meta.options.contains_key("TRANSIENT")
     }
+
+    /// The schema of the columns written into the blocks, virtual computed columns are
+    /// evaluated at read time and are not stored.
+    pub fn storage_schema(&self) -> TableSchemaRef {
+        let schema = self.table_info.schema();
+        if schema.has_virtual_computed_fields() {
+            Arc::new(schema.remove_virtual_computed_fields())
+        } else {
+            schema
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -340,7 +464,7 @@ impl Table for FuseTable {
                 .iter()
                 .map(|k| {
                     k.project_column_ref(|index| {
-                        table_meta.schema().field(*index).name().to_string()
+                        table_meta.storage_schema().field(*index).name().to_string()
                     })
                     .as_remote_expr()
                 })
@@ -520,19 +644,10 @@ impl Table for FuseTable {
         let provider = if let Some(snapshot) = self.read_table_snapshot().await? {
             let stats = &snapshot.summary.col_stats;
             let table_statistics = self.read_table_snapshot_statistics(Some(&snapshot)).await?;
-            if let Some(table_statistics) = table_statistics {
-                FuseTableColumnStatisticsProvider {
-                    column_stats: stats.clone(),
-                    row_count: snapshot.summary.row_count,
-                    // save row count first
-                    column_distinct_values: Some(table_statistics.column_distinct_values.clone()),
-                }
-            } else {
-                FuseTableColumnStatisticsProvider {
-                    column_stats: stats.clone(),
-                    row_count: snapshot.summary.row_count,
-                    column_distinct_values: None,
-                }
+            FuseTableColumnStatisticsProvider {
+                column_stats: stats.clone(),
+                row_count: snapshot.summary.row_count,
+                table_statistics,
             }
         } else {
             FuseTableColumnStatisticsProvider::default()
@@ -540,6 +655,15 @@ impl Table for FuseTable {
         Ok(Box::new(provider))
     }
 
+    async fn inverted_index_statistics(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        column: &str,
+        terms: &[Vec<u8>],
+    ) -> Result<Option<TermStatistics>> {
+        self.do_inverted_index_statistics(ctx, column, terms).await
+    }
+
     #[tracing::instrument(level = "debug", name = "fuse_table_navigate_to", skip_all)]
     async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
         match point {
@@ -549,6 +673,12 @@ impl Table for FuseTable {
             NavigationPoint::TimePoint(time_point) => {
                 Ok(self.navigate_to_time_point(*time_point).await?)
             }
+            NavigationPoint::Tag(name) => Ok(self
+                .navigate_to_snapshot_ref(name, SnapshotRefKind::Tag)
+                .await?),
+            NavigationPoint::Branch(name) => Ok(self
+                .navigate_to_snapshot_ref(name, SnapshotRefKind::Branch)
+                .await?),
         }
     }
 
@@ -615,6 +745,33 @@ impl Table for FuseTable {
     fn support_prewhere(&self) -> bool {
         matches!(self.storage_format, FuseStorageFormat::Native)
     }
+
+    fn virtual_columns(&self) -> Vec<VirtualColumnInfo> {
+        let schema = self.storage_schema();
+        self.virtual_column_definitions()
+            .into_iter()
+            .filter_map(|definition| {
+                let index = schema.index_of(&definition.source_column).ok()?;
+                let source_type = DataType::from(schema.field(index).data_type());
+                let keys = VirtualColumnKey::parse_path(&definition.path).ok()?;
+                let expr = virtual_column_expr(
+                    index,
+                    &definition.source_column,
+                    &source_type,
+                    &keys,
+                    &definition.cast_type,
+                )
+                .ok()?;
+                Some(VirtualColumnInfo {
+                    name: definition.name(),
+                    data_type: infer_schema_type(expr.data_type()).ok()?,
+                    source_column_name: definition.source_column,
+                    path: definition.path,
+                    cast_type: definition.cast_type,
+                })
+            })
+            .collect()
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -641,21 +798,36 @@ impl FromStr for FuseStorageFormat {
 #[derive(Default)]
 struct FuseTableColumnStatisticsProvider {
     column_stats: HashMap<ColumnId, FuseColumnStatistics>,
-    pub column_distinct_values: Option<HashMap<ColumnId, u64>>,
+    pub table_statistics: Option<Arc<TableSnapshotStatistics>>,
     pub row_count: u64,
 }
 
 impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
     fn column_statistics(&self, column_id: ColumnId) -> Option<ColumnStatistics> {
         let col_stats = &self.column_stats.get(&column_id);
+        let table_statistics = self.table_statistics.as_ref();
         col_stats.map(|s| ColumnStatistics {
             min: s.min.clone(),
             max: s.max.clone(),
-            null_count: s.null_count,
-            number_of_distinct_values: self
-                .column_distinct_values
-                .as_ref()
-                .map_or(self.row_count, |map| map.get(&column_id).map_or(0, |v| *v)),
+            // prefer the null count collected by `ANALYZE TABLE` if any
+            null_count: table_statistics
+                .and_then(|t| t.column_null_counts.get(&column_id).cloned())
+                .unwrap_or(s.null_count),
+            number_of_distinct_values: table_statistics.map_or(self.row_count, |t| {
+                t.column_distinct_values.get(&column_id).map_or(0, |v| *v)
+            }),
+            histogram: table_statistics
+                .and_then(|t| t.column_histograms.get(&column_id))
+                .map(|buckets| {
+                    buckets
+                        .iter()
+                        .map(|bucket| HistogramBucket {
+                            upper_bound: bucket.upper_bound.clone(),
+                            num_values: bucket.num_values,
+                            num_distinct: bucket.num_distinct,
+                        })
+                        .collect()
+                }),
         })
     }
 }
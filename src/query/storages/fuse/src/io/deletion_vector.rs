@@ -0,0 +1,118 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+use storages_common_table_meta::meta::Location;
+
+use crate::fuse_part::FusePartInfo;
+use crate::io::BlockReader;
+
+/// The offsets of the rows of a block that have been removed by deletes, stored as a
+/// roaring bitmap in a file referenced by the `BlockMeta`.
+///
+/// The block file is left untouched, readers skip the deleted rows instead.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeletionVector {
+    rows: RoaringBitmap,
+}
+
+impl DeletionVector {
+    pub const VERSION: u64 = 0;
+
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let rows = RoaringBitmap::deserialize_from(data)
+            .map_err(|e| ErrorCode::StorageOther(format!("invalid deletion vector: {}", e)))?;
+        Ok(DeletionVector { rows })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut data = Vec::with_capacity(self.rows.serialized_size());
+        self.rows.serialize_into(&mut data)?;
+        Ok(data)
+    }
+
+    /// Mark the rows set in `deleted`, a filter of the whole block, as deleted.
+    ///
+    /// Returns the number of rows that were not deleted before.
+    pub fn delete(&mut self, deleted: &Bitmap) -> u64 {
+        let num_deleted = self.len();
+        for (offset, is_deleted) in deleted.iter().enumerate() {
+            if is_deleted {
+                self.rows.insert(offset as u32);
+            }
+        }
+        self.len() - num_deleted
+    }
+
+    /// The number of deleted rows.
+    pub fn len(&self) -> u64 {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// The filter of the rows `offset..offset + num_rows` of the block that have not been deleted.
+    pub fn filter(&self, offset: usize, num_rows: usize) -> Bitmap {
+        let mut filter = MutableBitmap::from_len_set(num_rows);
+        let (start, end) = (offset as u32, (offset + num_rows) as u32);
+        for row in self.rows.range(start..end) {
+            filter.set((row - start) as usize, false);
+        }
+        filter.into()
+    }
+
+    /// Remove the deleted rows from `block`, which holds the rows of the block from `offset`.
+    pub fn apply(&self, block: DataBlock, offset: usize) -> Result<DataBlock> {
+        let filter = self.filter(offset, block.num_rows());
+        DataBlock::filter_with_bitmap(block, &filter)
+    }
+
+    pub async fn read(dal: &Operator, location: &Location) -> Result<Self> {
+        let data = dal.object(&location.0).read().await?;
+        Self::from_bytes(&data)
+    }
+
+    pub fn sync_read(dal: &Operator, location: &Location) -> Result<Self> {
+        let data = dal.object(&location.0).blocking_read()?;
+        Self::from_bytes(&data)
+    }
+}
+
+impl BlockReader {
+    /// Read the deletion vector of the block of `part`, if some of its rows have been deleted.
+    pub async fn read_deletion_vector(
+        &self,
+        part: &FusePartInfo,
+    ) -> Result<Option<DeletionVector>> {
+        match &part.deletion_vector_location {
+            None => Ok(None),
+            Some(location) => Ok(Some(DeletionVector::read(&self.operator, location).await?)),
+        }
+    }
+
+    pub fn sync_read_deletion_vector(&self, part: &FusePartInfo) -> Result<Option<DeletionVector>> {
+        match &part.deletion_vector_location {
+            None => Ok(None),
+            Some(location) => Ok(Some(DeletionVector::sync_read(&self.operator, location)?)),
+        }
+    }
+}
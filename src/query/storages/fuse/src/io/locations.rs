@@ -28,7 +28,12 @@ use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::index::filters::BlockFilter;
+use crate::index::InvertedIndex;
+use crate::io::DeletionVector;
+use crate::FUSE_TBL_DELETION_VECTOR_PREFIX;
+use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_VIRTUAL_BLOOM_INDEX_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
@@ -91,6 +96,46 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn block_inverted_index_location(&self, block_id: &Uuid) -> Location {
+        (
+            format!(
+                "{}/{}/{}_v{}.json",
+                &self.prefix,
+                FUSE_TBL_INVERTED_INDEX_PREFIX,
+                block_id.as_simple(),
+                InvertedIndex::VERSION,
+            ),
+            InvertedIndex::VERSION,
+        )
+    }
+
+    pub fn block_virtual_column_bloom_index_location(&self, block_id: &Uuid) -> Location {
+        (
+            format!(
+                "{}/{}/{}_v{}.parquet",
+                &self.prefix,
+                FUSE_TBL_VIRTUAL_BLOOM_INDEX_PREFIX,
+                block_id.as_simple(),
+                BlockFilter::VERSION,
+            ),
+            BlockFilter::VERSION,
+        )
+    }
+
+    pub fn gen_deletion_vector_location(&self) -> Location {
+        let deletion_vector_uuid = Uuid::new_v4();
+        (
+            format!(
+                "{}/{}/{}_v{}.bin",
+                &self.prefix,
+                FUSE_TBL_DELETION_VECTOR_PREFIX,
+                deletion_vector_uuid.as_simple(),
+                DeletionVector::VERSION,
+            ),
+            DeletionVector::VERSION,
+        )
+    }
+
     pub fn gen_segment_info_location(&self) -> String {
         let segment_uuid = Uuid::new_v4().simple().to_string();
         format!(
@@ -12,13 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod deletion_vector;
 mod files;
 mod locations;
 mod read;
 mod segments;
 mod snapshots;
+mod virtual_column;
 mod write;
 
+pub use deletion_vector::DeletionVector;
 pub use files::Files;
 pub use locations::TableMetaLocationGenerator;
 pub use read::BlockReader;
@@ -37,6 +40,10 @@ pub use segments::SegmentsIO;
 pub use snapshots::ListSnapshotLiteOption;
 pub use snapshots::SnapshotLiteListExtended;
 pub use snapshots::SnapshotsIO;
+pub use virtual_column::extract_virtual_columns;
+pub use virtual_column::virtual_column_expr;
+pub use virtual_column::virtual_columns_schema;
+pub use virtual_column::VirtualColumnReader;
 pub use write::write_block;
 pub use write::write_data;
 pub use write::CachedMetaWriter;
@@ -41,6 +41,7 @@ use storages_common_table_meta::meta::ColumnMeta;
 
 use crate::fuse_part::FusePartInfo;
 use crate::io::read::ReadSettings;
+use crate::io::DeletionVector;
 use crate::metrics::*;
 
 // TODO: make BlockReader as a trait.
@@ -81,6 +82,8 @@ where Self: 'static
     path: String,
     owner_memory: OwnerMemory,
     columns_chunks: HashMap<usize, (usize, Range<usize>)>,
+    /// The deleted rows of the block, which are removed after the columns are deserialized
+    pub deletion_vector: Option<DeletionVector>,
 }
 
 impl MergeIOReadResult
@@ -91,6 +94,7 @@ where Self: 'static
             path,
             owner_memory,
             columns_chunks: HashMap::with_capacity(capacity),
+            deletion_vector: None,
         }
     }
 
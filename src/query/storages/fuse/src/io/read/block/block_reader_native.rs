@@ -26,14 +26,17 @@ use common_expression::Column;
 use common_expression::DataBlock;
 use common_expression::Value;
 use opendal::Object;
+use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ColumnMeta;
 
 use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::metrics::metrics_inc_remote_io_read_bytes;
 use crate::metrics::metrics_inc_remote_io_read_milliseconds;
 use crate::metrics::metrics_inc_remote_io_read_parts;
 use crate::metrics::metrics_inc_remote_io_seeks;
+use crate::FuseTable;
 
 // Native storage format
 
@@ -162,6 +165,37 @@ impl BlockReader {
         Ok((index, fuse_reader))
     }
 
+    /// Read all the pages of a native block and convert them to one DataBlock.
+    pub async fn read_native_by_meta(&self, meta: &BlockMeta) -> Result<DataBlock> {
+        let part = FuseTable::all_columns_part(None, &None, meta);
+        let mut readers = self.async_read_native_columns_data(part).await?;
+
+        let mut entries = Vec::with_capacity(readers.len());
+        for (id, (_, f)) in self.project_indices.iter() {
+            let Some((_, reader)) = readers.iter_mut().find(|(index, _)| *index == *id) else {
+                continue;
+            };
+            let mut columns = vec![];
+            while reader.has_next() {
+                let array = reader.next_array()?;
+                columns.push(Column::from_arrow(array.as_ref(), f));
+            }
+            entries.push(BlockEntry {
+                data_type: f.clone(),
+                value: Value::Column(Column::concat(&columns)),
+            });
+        }
+        let block = DataBlock::new(entries, meta.row_count as usize);
+
+        // Skip the rows removed by deletes.
+        match &meta.deletion_vector_location {
+            Some(location) => DeletionVector::read(&self.operator, location)
+                .await?
+                .apply(block, 0),
+            None => Ok(block),
+        }
+    }
+
     pub fn build_block(&self, chunks: Vec<(usize, Box<dyn Array>)>) -> Result<DataBlock> {
         let mut entries = Vec::with_capacity(chunks.len());
         // they are already the leaf columns without inner
@@ -36,6 +36,7 @@ use crate::fuse_part::FusePartInfo;
 use crate::io::read::block::decompressor::BuffedBasicDecompressor;
 use crate::io::read::ReadSettings;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::UncompressedBuffer;
 use crate::metrics::*;
 
@@ -72,13 +73,21 @@ impl BlockReader {
             .map(|(index, chunk)| (*index, *chunk))
             .collect::<Vec<_>>();
 
-        self.deserialize_parquet_chunks_with_buffer(
+        let block = self.deserialize_parquet_chunks_with_buffer(
             num_rows,
             &meta.compression,
             &columns_meta,
             columns_chunk,
             None,
-        )
+        )?;
+
+        // Skip the rows removed by deletes.
+        match &meta.deletion_vector_location {
+            Some(location) => DeletionVector::read(&self.operator, location)
+                .await?
+                .apply(block, 0),
+            None => Ok(block),
+        }
     }
 
     /// Deserialize column chunks data from parquet format to DataBlock.
@@ -124,7 +133,8 @@ impl BlockReader {
         columns_chunks: Vec<(usize, &[u8])>,
         uncompressed_buffer: Option<Arc<UncompressedBuffer>>,
     ) -> Result<DataBlock> {
-        if columns_chunks.is_empty() {
+        // the chunks may hold the virtual columns read along with the projected columns
+        if columns_chunks.is_empty() || self.project_indices.is_empty() {
             return Ok(DataBlock::new(vec![], num_rows));
         }
 
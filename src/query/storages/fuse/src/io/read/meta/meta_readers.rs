@@ -23,6 +23,7 @@ use storages_common_cache::LoadParams;
 use storages_common_cache::Loader;
 use storages_common_cache_manager::BloomIndexMeta;
 use storages_common_cache_manager::CacheManager;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::SegmentInfoVersion;
 use storages_common_table_meta::meta::SnapshotVersion;
@@ -36,6 +37,7 @@ pub type TableSnapshotStatisticsReader =
     InMemoryItemCacheReader<TableSnapshotStatistics, LoaderWrapper<Operator>>;
 pub type BloomIndexFileMetaDataReader =
     InMemoryItemCacheReader<BloomIndexMeta, LoaderWrapper<Operator>>;
+pub type InvertedIndexReader = InMemoryItemCacheReader<InvertedIndex, LoaderWrapper<Operator>>;
 pub type TableSnapshotReader = InMemoryItemCacheReader<TableSnapshot, LoaderWrapper<Operator>>;
 pub type SegmentInfoReader =
     InMemoryItemCacheReader<SegmentInfo, LoaderWrapper<(Operator, TableSchemaRef)>>;
@@ -74,6 +76,14 @@ impl MetaReaders {
             LoaderWrapper(dal),
         )
     }
+
+    pub fn inverted_index_reader(dal: Operator) -> InvertedIndexReader {
+        InvertedIndexReader::new(
+            CacheManager::instance().get_inverted_index_cache(),
+            "inverted_index_cache".to_owned(),
+            LoaderWrapper(dal),
+        )
+    }
 }
 
 // workaround for the orphan rules
@@ -127,6 +137,14 @@ impl Loader<BloomIndexMeta> for LoaderWrapper<Operator> {
     }
 }
 
+#[async_trait::async_trait]
+impl Loader<InvertedIndex> for LoaderWrapper<Operator> {
+    async fn load(&self, params: &LoadParams) -> Result<InvertedIndex> {
+        let data = self.0.object(&params.location).read().await?;
+        InvertedIndex::from_bytes(&data)
+    }
+}
+
 async fn bytes_reader(op: &Operator, path: &str, len: Option<u64>) -> Result<ObjectReader> {
     let object = op.object(path);
 
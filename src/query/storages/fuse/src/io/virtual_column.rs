@@ -0,0 +1,507 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::plan::VirtualColumnInfo;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::infer_schema_type;
+use common_expression::type_check::check_cast;
+use common_expression::type_check::check_function;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_jsonb::Number;
+use opendal::Operator;
+use storages_common_table_meta::table::VirtualColumnDefinition;
+use storages_common_table_meta::table::VirtualColumnKey;
+
+use crate::fuse_part::FusePartInfo;
+use crate::io::BlockReader;
+use crate::io::MergeIOReadResult;
+use crate::io::ReadSettings;
+
+// The number of paths detected in a variant column at most.
+const MAX_DETECTED_PATHS: usize = 16;
+// The depth of the nested objects the paths are detected in at most.
+const MAX_DETECTED_DEPTH: usize = 4;
+
+/// The expression extracting the value at `keys` of the column `source_index`, cast to
+/// `cast_type`, the same as the one bound from `CAST(<source>:<path> AS <cast_type>)`.
+pub fn virtual_column_expr(
+    source_index: usize,
+    source_name: &str,
+    source_type: &DataType,
+    keys: &[VirtualColumnKey],
+    cast_type: &TableDataType,
+) -> Result<Expr> {
+    let mut expr = Expr::ColumnRef {
+        span: None,
+        id: source_index,
+        data_type: source_type.clone(),
+        display_name: source_name.to_string(),
+    };
+    for key in keys {
+        let (scalar, data_type) = match key {
+            VirtualColumnKey::Name(name) => {
+                (Scalar::String(name.as_bytes().to_vec()), DataType::String)
+            }
+            VirtualColumnKey::Index(index) => (
+                Scalar::Number(NumberScalar::UInt64(*index)),
+                DataType::Number(NumberDataType::UInt64),
+            ),
+        };
+        let key = Expr::Constant {
+            span: None,
+            scalar,
+            data_type,
+        };
+        expr = check_function(None, "get", &[], &[expr, key], &BUILTIN_FUNCTIONS)?;
+    }
+    match cast_type {
+        TableDataType::Variant => Ok(expr),
+        _ => check_cast(
+            None,
+            false,
+            expr,
+            &DataType::from(cast_type),
+            &BUILTIN_FUNCTIONS,
+        ),
+    }
+}
+
+/// Extracts the virtual columns of a block: the declared ones, followed by the paths detected
+/// in the columns listed in `auto_virtual_columns`.
+///
+/// A virtual column whose values can not be extracted, e.g. some of them can not be cast, is
+/// left out and extracted from the source column at read time instead.
+pub fn extract_virtual_columns(
+    func_ctx: FunctionContext,
+    source_schema: &TableSchema,
+    block: &DataBlock,
+    definitions: &[VirtualColumnDefinition],
+    auto_virtual_columns: &[String],
+) -> Result<Vec<(VirtualColumnDefinition, BlockEntry)>> {
+    let num_rows = block.num_rows();
+    let mut definitions = definitions.to_vec();
+    for source_column in auto_virtual_columns {
+        let Ok(index) = source_schema.index_of(source_column) else {
+            continue;
+        };
+        for definition in
+            detect_virtual_columns(source_column, block.get_by_offset(index), num_rows)
+        {
+            if definitions.iter().all(|d| d.name() != definition.name()) {
+                definitions.push(definition);
+            }
+        }
+    }
+
+    let evaluator = Evaluator::new(block, func_ctx, &BUILTIN_FUNCTIONS);
+    let mut columns = Vec::with_capacity(definitions.len());
+    for definition in definitions {
+        let Ok(index) = source_schema.index_of(&definition.source_column) else {
+            continue;
+        };
+        let keys = VirtualColumnKey::parse_path(&definition.path)?;
+        let source_type = &block.get_by_offset(index).data_type;
+        let Ok(expr) = virtual_column_expr(
+            index,
+            &definition.source_column,
+            source_type,
+            &keys,
+            &definition.cast_type,
+        ) else {
+            continue;
+        };
+        if let Ok(value) = evaluator.run(&expr) {
+            let data_type = expr.data_type().clone();
+            let column = value.convert_to_full_column(&data_type, num_rows);
+            columns.push((definition, BlockEntry {
+                data_type,
+                value: Value::Column(column),
+            }));
+        }
+    }
+    Ok(columns)
+}
+
+/// The schema of the virtual columns extracted from a block, named after them.
+pub fn virtual_columns_schema(
+    columns: &[(VirtualColumnDefinition, BlockEntry)],
+) -> Result<TableSchema> {
+    let fields = columns
+        .iter()
+        .map(|(definition, entry)| {
+            Ok(TableField::new(
+                &definition.name(),
+                infer_schema_type(&entry.data_type)?,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(TableSchema::new(fields))
+}
+
+/// Detects the paths of the scalar values present in at least half of the rows of a variant
+/// column, the most frequent ones first.
+///
+/// The values of a path are cast to the type they share if they are present in all the rows,
+/// otherwise they are kept as variant.
+fn detect_virtual_columns(
+    source_column: &str,
+    entry: &BlockEntry,
+    num_rows: usize,
+) -> Vec<VirtualColumnDefinition> {
+    if num_rows == 0 || entry.data_type.remove_nullable() != DataType::Variant {
+        return vec![];
+    }
+
+    let mut paths = HashMap::new();
+    let column = entry
+        .value
+        .convert_to_full_column(&entry.data_type, num_rows);
+    for value in column.iter() {
+        if let ScalarRef::Variant(bytes) = value {
+            if let Ok(value) = common_jsonb::from_slice(bytes) {
+                collect_paths(&value, &mut vec![], &mut paths);
+            }
+        }
+    }
+
+    let mut paths = paths
+        .into_iter()
+        .filter(|(_, (count, _))| count * 2 >= num_rows)
+        .collect::<Vec<_>>();
+    paths.sort_by(|(a, (a_count, _)), (b, (b_count, _))| b_count.cmp(a_count).then(a.cmp(b)));
+    paths
+        .into_iter()
+        .take(MAX_DETECTED_PATHS)
+        .map(|(names, (count, data_type))| {
+            let cast_type = match data_type {
+                Some(data_type) if count == num_rows => data_type,
+                _ => TableDataType::Variant,
+            };
+            let keys = names
+                .into_iter()
+                .map(VirtualColumnKey::Name)
+                .collect::<Vec<_>>();
+            VirtualColumnDefinition {
+                source_column: source_column.to_string(),
+                path: VirtualColumnKey::format_path(&keys),
+                cast_type,
+            }
+        })
+        .collect()
+}
+
+// Counts the paths of the scalar values in the object, along with the type they share, `None`
+// if they are of different types.
+fn collect_paths(
+    value: &common_jsonb::Value,
+    path: &mut Vec<String>,
+    paths: &mut HashMap<Vec<String>, (usize, Option<TableDataType>)>,
+) {
+    let common_jsonb::Value::Object(object) = value else {
+        return;
+    };
+    for (name, value) in object {
+        path.push(name.clone());
+        match value {
+            common_jsonb::Value::Object(_) if path.len() < MAX_DETECTED_DEPTH => {
+                collect_paths(value, path, paths);
+            }
+            common_jsonb::Value::Bool(_)
+            | common_jsonb::Value::Number(_)
+            | common_jsonb::Value::String(_) => {
+                let data_type = scalar_type(value);
+                paths
+                    .entry(path.clone())
+                    .and_modify(|(count, shared)| {
+                        *count += 1;
+                        *shared = shared.take().and_then(|t| merge_types(t, &data_type));
+                    })
+                    .or_insert((1, Some(data_type)));
+            }
+            _ => {}
+        }
+        path.pop();
+    }
+}
+
+fn scalar_type(value: &common_jsonb::Value) -> TableDataType {
+    match value {
+        common_jsonb::Value::Bool(_) => TableDataType::Boolean,
+        common_jsonb::Value::Number(Number::Int64(_)) => {
+            TableDataType::Number(NumberDataType::Int64)
+        }
+        common_jsonb::Value::Number(Number::UInt64(_)) => {
+            TableDataType::Number(NumberDataType::UInt64)
+        }
+        common_jsonb::Value::Number(Number::Float64(_)) => {
+            TableDataType::Number(NumberDataType::Float64)
+        }
+        _ => TableDataType::String,
+    }
+}
+
+// The type the values of both types can be cast to, the numbers of different types are taken
+// as signed integers or floats.
+fn merge_types(a: TableDataType, b: &TableDataType) -> Option<TableDataType> {
+    match (&a, b) {
+        _ if &a == b => Some(a),
+        (TableDataType::Number(a), TableDataType::Number(b)) => {
+            if a.is_float() || b.is_float() {
+                Some(TableDataType::Number(NumberDataType::Float64))
+            } else {
+                Some(TableDataType::Number(NumberDataType::Int64))
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Reads the virtual columns pushed down to a scan, along with the columns projected in the
+/// same merged IO of the block file, which stores them after the columns of the table. The
+/// virtual columns are extracted from their source columns for the blocks not storing them,
+/// e.g. the ones written before the virtual columns are created.
+///
+/// Only the tables of parquet storage format store virtual columns, see
+/// `FuseTable::virtual_column_definitions`.
+pub struct VirtualColumnReader {
+    func_ctx: FunctionContext,
+    virtual_columns: Vec<VirtualColumnInfo>,
+    // the number of the leaf columns of the table, the chunks of the virtual columns stored
+    // are keyed after them
+    num_leaves: usize,
+    // reads the source columns of the virtual columns
+    source_reader: Arc<BlockReader>,
+    // the expressions extracting the virtual columns from the source columns read
+    exprs: Vec<Expr>,
+}
+
+impl VirtualColumnReader {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        operator: Operator,
+        schema: TableSchemaRef,
+        virtual_columns: Vec<VirtualColumnInfo>,
+    ) -> Result<Arc<Self>> {
+        let mut source_indices = virtual_columns
+            .iter()
+            .map(|c| schema.index_of(&c.source_column_name))
+            .collect::<Result<Vec<_>>>()?;
+        source_indices.sort();
+        source_indices.dedup();
+
+        let exprs = virtual_columns
+            .iter()
+            .map(|c| {
+                let index = schema.index_of(&c.source_column_name)?;
+                let position = source_indices.iter().position(|i| *i == index).unwrap();
+                virtual_column_expr(
+                    position,
+                    &c.source_column_name,
+                    &DataType::from(schema.field(index).data_type()),
+                    &VirtualColumnKey::parse_path(&c.path)?,
+                    &c.cast_type,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let num_leaves = schema.leaf_fields().len();
+        let source_reader =
+            BlockReader::create(operator, schema, Projection::Columns(source_indices))?;
+
+        Ok(Arc::new(VirtualColumnReader {
+            func_ctx: ctx.get_function_context()?,
+            virtual_columns,
+            num_leaves,
+            source_reader,
+            exprs,
+        }))
+    }
+
+    /// The fields of the virtual columns, which follow the projected columns.
+    pub fn fields(&self) -> Vec<TableField> {
+        self.virtual_columns
+            .iter()
+            .map(|c| TableField::new(&c.name, c.data_type.clone()))
+            .collect()
+    }
+
+    /// Reads the columns projected by `block_reader` and the data of the virtual columns.
+    pub async fn read_columns_data_by_merge_io(
+        &self,
+        settings: &ReadSettings,
+        block_reader: &BlockReader,
+        part: &FusePartInfo,
+    ) -> Result<MergeIOReadResult> {
+        let object = block_reader.operator.object(&part.location);
+        BlockReader::merge_io_read(settings, object, self.ranges(block_reader, part)).await
+    }
+
+    pub fn sync_read_columns_data_by_merge_io(
+        &self,
+        settings: &ReadSettings,
+        block_reader: &BlockReader,
+        part: &FusePartInfo,
+    ) -> Result<MergeIOReadResult> {
+        let object = block_reader.operator.object(&part.location);
+        BlockReader::sync_merge_io_read(settings, object, self.ranges(block_reader, part))
+    }
+
+    /// Deserializes the virtual columns of the part, in the order they are pushed down.
+    pub fn deserialize_virtual_columns(
+        &self,
+        part: &FusePartInfo,
+        read_res: &MergeIOReadResult,
+    ) -> Result<Vec<BlockEntry>> {
+        let num_rows = part.nums_rows;
+        let positions = self.stored_positions(part);
+        let chunks = read_res.columns_chunks()?;
+
+        let stored_block = match &part.virtual_block_meta {
+            Some(meta) if positions.iter().any(Option::is_some) => {
+                let fields = meta
+                    .columns
+                    .iter()
+                    .map(|c| TableField::new(&c.name, c.data_type.clone()))
+                    .collect();
+                let columns_meta = meta
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .map(|(position, c)| (position, c.col_meta.clone()))
+                    .collect::<HashMap<_, _>>();
+                let stored_positions = positions.iter().flatten().cloned().collect::<Vec<_>>();
+                let reader = BlockReader::create(
+                    self.source_reader.operator.clone(),
+                    Arc::new(TableSchema::new(fields)),
+                    Projection::Columns(stored_positions),
+                )?;
+                let stored_chunks = chunks
+                    .iter()
+                    .filter(|(index, _)| *index >= self.num_leaves)
+                    .map(|(index, chunk)| (index - self.num_leaves, *chunk))
+                    .collect();
+                Some(reader.deserialize_parquet_chunks_with_buffer(
+                    num_rows,
+                    &part.compression,
+                    &columns_meta,
+                    stored_chunks,
+                    None,
+                )?)
+            }
+            _ => None,
+        };
+        let source_block = match positions.iter().any(Option::is_none) {
+            true => Some(self.source_reader.deserialize_parquet_chunks_with_buffer(
+                num_rows,
+                &part.compression,
+                &part.columns_meta,
+                chunks,
+                None,
+            )?),
+            false => None,
+        };
+
+        let mut stored_offset = 0;
+        let mut entries = Vec::with_capacity(self.virtual_columns.len());
+        for (position, expr) in positions.iter().zip(self.exprs.iter()) {
+            match (position, &stored_block, &source_block) {
+                (Some(_), Some(stored_block), _) => {
+                    entries.push(stored_block.get_by_offset(stored_offset).clone());
+                    stored_offset += 1;
+                }
+                (None, _, Some(source_block)) => {
+                    let evaluator = Evaluator::new(source_block, self.func_ctx, &BUILTIN_FUNCTIONS);
+                    let data_type = expr.data_type().clone();
+                    let column = evaluator
+                        .run(expr)?
+                        .convert_to_full_column(&data_type, num_rows);
+                    entries.push(BlockEntry {
+                        data_type,
+                        value: Value::Column(column),
+                    });
+                }
+                _ => {
+                    return Err(ErrorCode::Internal(format!(
+                        "the data of virtual columns of block {} is not read",
+                        part.location
+                    )));
+                }
+            }
+        }
+        Ok(entries)
+    }
+
+    // The positions among the virtual columns of the block, of the virtual columns stored by
+    // it.
+    fn stored_positions(&self, part: &FusePartInfo) -> Vec<Option<usize>> {
+        self.virtual_columns
+            .iter()
+            .map(|c| {
+                let meta = part.virtual_block_meta.as_ref()?;
+                let position = meta.column_position(&c.name, &c.cast_type)?;
+                (meta.columns[position].data_type == c.data_type).then_some(position)
+            })
+            .collect()
+    }
+
+    // The ranges in the block file of the columns projected by `block_reader`, of the source
+    // columns of the virtual columns not stored by the block, and of the virtual columns
+    // stored, which are keyed after the leaf columns of the table.
+    fn ranges(&self, block_reader: &BlockReader, part: &FusePartInfo) -> Vec<(usize, Range<u64>)> {
+        let positions = self.stored_positions(part);
+        let mut indices = block_reader
+            .project_indices
+            .keys()
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        if positions.iter().any(Option::is_none) {
+            indices.extend(self.source_reader.project_indices.keys().cloned());
+        }
+
+        let mut ranges = Vec::with_capacity(indices.len() + positions.len());
+        for index in indices {
+            let (offset, len) = part.columns_meta[&index].offset_length();
+            ranges.push((index, offset..(offset + len)));
+        }
+        if let Some(meta) = &part.virtual_block_meta {
+            for position in positions.iter().flatten() {
+                let (offset, len) = meta.columns[*position].col_meta.offset_length();
+                ranges.push((self.num_leaves + position, offset..(offset + len)));
+            }
+        }
+        ranges
+    }
+}
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use storages_common_index::TextIndexOptions;
+use storages_common_table_meta::table::InvertedIndexDefinition;
 use storages_common_table_meta::table::TableCompression;
+use storages_common_table_meta::table::VirtualColumnDefinition;
 
 use crate::FuseStorageFormat;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
@@ -26,6 +29,14 @@ pub struct WriteSettings {
     pub max_page_size: usize,
 
     pub block_per_seg: usize,
+
+    pub text_index: TextIndexOptions,
+
+    pub inverted_indexes: Vec<InvertedIndexDefinition>,
+
+    pub virtual_columns: Vec<VirtualColumnDefinition>,
+    // the variant columns whose hot paths are detected and stored as virtual columns
+    pub auto_virtual_columns: Vec<String>,
 }
 
 impl Default for WriteSettings {
@@ -35,6 +46,10 @@ impl Default for WriteSettings {
             table_compression: TableCompression::LZ4,
             max_page_size: DEFAULT_ROW_PER_PAGE,
             block_per_seg: DEFAULT_BLOCK_PER_SEGMENT,
+            text_index: TextIndexOptions::default(),
+            inverted_indexes: vec![],
+            virtual_columns: vec![],
+            auto_virtual_columns: vec![],
         }
     }
 }
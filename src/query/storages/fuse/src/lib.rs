@@ -26,6 +26,7 @@ pub mod io;
 pub mod operations;
 pub mod pruning;
 pub mod statistics;
+mod stream_table;
 pub mod table_functions;
 
 mod metrics;
@@ -39,6 +40,7 @@ pub use constants::*;
 pub use fuse_table::FuseStorageFormat;
 pub use fuse_table::FuseTable;
 pub use io::MergeIOReadResult;
+pub use stream_table::StreamTable;
 
 pub use crate::metrics::metrics_reset;
 
@@ -15,17 +15,31 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_catalog::plan::Projection;
+use common_catalog::table::ColumnId;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::HistogramBucket;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use tracing::warn;
 
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
 use crate::FuseTable;
 
+/// Max number of blocks sampled to build the column histograms.
+const HISTOGRAM_SAMPLE_BLOCKS: usize = 16;
+/// Number of buckets of the column histograms.
+const HISTOGRAM_BUCKETS: usize = 100;
+
 impl FuseTable {
     pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
         // 1. Read table snapshot.
@@ -47,20 +61,26 @@ impl FuseTable {
         if let Some(snapshot) = snapshot_opt {
             // 2. Iterator segments and blocks to estimate statistics.
             let mut sum_map = HashMap::new();
+            let mut null_count_map: HashMap<ColumnId, u64> = HashMap::new();
             let mut row_count_sum = 0;
             let mut block_count_sum: u64 = 0;
+            let mut blocks = vec![];
 
-            let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+            let segments_io =
+                SegmentsIO::create(ctx.clone(), self.operator.clone(), self.storage_schema());
             let segments = segments_io.read_segments(&snapshot.segments).await?;
             for segment in segments {
                 let segment = segment?;
-                segment.blocks.iter().for_each(|block| {
-                    let block = block.as_ref();
+                segment.blocks.iter().for_each(|block_meta| {
+                    let block = block_meta.as_ref();
                     let row_count = block.row_count;
                     if row_count != 0 {
                         block_count_sum += 1;
                         row_count_sum += row_count;
+                        blocks.push(block_meta.clone());
                         for (i, col_stat) in block.col_stats.iter() {
+                            *null_count_map.entry(*i).or_default() += col_stat.null_count;
+
                             let density = match col_stat.distinct_of_values {
                                 Some(ndv) => ndv as f64 / row_count as f64,
                                 None => 0.0,
@@ -84,8 +104,14 @@ impl FuseTable {
                 ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
             }
 
-            // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            // 3. Sample blocks to build the histograms of columns.
+            let histogram_map = self
+                .build_column_histograms(ctx, &blocks, row_count_sum, &null_count_map, &ndv_map)
+                .await?;
+
+            // 4. Generate new table statistics
+            let table_statistics =
+                TableSnapshotStatistics::new(ndv_map, null_count_map, histogram_map);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -93,7 +119,7 @@ impl FuseTable {
                     table_statistics.format_version(),
                 )?;
 
-            // 4. Save table statistics
+            // 5. Save table statistics
             let mut new_snapshot = TableSnapshot::from_previous(&snapshot);
             new_snapshot.table_statistics_location = Some(table_statistics_location);
             FuseTable::commit_to_meta_server(
@@ -109,4 +135,121 @@ impl FuseTable {
 
         Ok(())
     }
+    /// Build the histograms of the boolean, number and string columns from the
+    /// evenly sampled blocks.
+    async fn build_column_histograms(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        blocks: &[Arc<BlockMeta>],
+        row_count: u64,
+        null_counts: &HashMap<ColumnId, u64>,
+        ndvs: &HashMap<ColumnId, u64>,
+    ) -> Result<HashMap<ColumnId, Vec<HistogramBucket>>> {
+        let mut histograms = HashMap::new();
+        if blocks.is_empty() {
+            return Ok(histograms);
+        }
+
+        let schema = self.storage_schema();
+        let mut projection = vec![];
+        let mut column_ids = vec![];
+        let mut column_id = 0;
+        for (index, field) in schema.fields().iter().enumerate() {
+            if matches!(
+                field.data_type().remove_nullable(),
+                TableDataType::Boolean | TableDataType::Number(_) | TableDataType::String
+            ) {
+                projection.push(index);
+                column_ids.push(column_id as ColumnId);
+            }
+            column_id += TableSchema::new(vec![field.clone()]).leaf_fields().len();
+        }
+        if projection.is_empty() {
+            return Ok(histograms);
+        }
+
+        let step = (blocks.len() + HISTOGRAM_SAMPLE_BLOCKS - 1) / HISTOGRAM_SAMPLE_BLOCKS;
+        let block_reader = self.create_block_reader(Projection::Columns(projection))?;
+        let settings = ReadSettings::from_ctx(ctx)?;
+        let mut samples = vec![vec![]; column_ids.len()];
+        for block in blocks.iter().step_by(step) {
+            let data_block = if self.is_native() {
+                block_reader.read_native_by_meta(block).await?
+            } else {
+                block_reader.read_parquet_by_meta(&settings, block).await?
+            };
+            let num_rows = data_block.num_rows();
+            for (entry, values) in data_block.columns().iter().zip(samples.iter_mut()) {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                values.extend(
+                    column
+                        .iter()
+                        .filter(|value| *value != ScalarRef::Null)
+                        .map(|value| value.to_owned()),
+                );
+            }
+        }
+
+        for (column_id, values) in column_ids.into_iter().zip(samples.into_iter()) {
+            let null_count = null_counts.get(&column_id).cloned().unwrap_or(0);
+            let buckets = build_histogram(
+                values,
+                row_count.saturating_sub(null_count),
+                ndvs.get(&column_id).cloned(),
+            );
+            if !buckets.is_empty() {
+                histograms.insert(column_id, buckets);
+            }
+        }
+        Ok(histograms)
+    }
+}
+
+/// Build an equi-height histogram from the sampled non-null values of a column, the
+/// equal values are never split into different buckets. The counts of the buckets are
+/// scaled from the sample to the `num_values` values and `ndv` distinct values of the table.
+fn build_histogram(
+    mut values: Vec<Scalar>,
+    num_values: u64,
+    ndv: Option<u64>,
+) -> Vec<HistogramBucket> {
+    if values.is_empty() {
+        return vec![];
+    }
+    values.sort();
+
+    let depth = (values.len() + HISTOGRAM_BUCKETS - 1) / HISTOGRAM_BUCKETS;
+    let mut buckets = Vec::with_capacity(HISTOGRAM_BUCKETS);
+    let mut bucket_values = 0;
+    let mut bucket_distinct = 0;
+    for (i, value) in values.iter().enumerate() {
+        bucket_values += 1;
+        if i == 0 || values[i - 1] != *value {
+            bucket_distinct += 1;
+        }
+        let is_last = i + 1 == values.len();
+        // The first bucket only holds the min value, which is the lower bound of the
+        // histogram, see `less_fraction` of the selectivity estimation.
+        let is_full = buckets.is_empty() || bucket_values >= depth;
+        if is_last || (is_full && values[i + 1] != *value) {
+            buckets.push(HistogramBucket {
+                upper_bound: value.clone(),
+                num_values: bucket_values as f64,
+                num_distinct: bucket_distinct as f64,
+            });
+            bucket_values = 0;
+            bucket_distinct = 0;
+        }
+    }
+
+    let sample_distinct: f64 = buckets.iter().map(|bucket| bucket.num_distinct).sum();
+    let values_scale = (num_values as f64 / values.len() as f64).max(1.0);
+    let distinct_scale = ndv.map_or(1.0, |ndv| (ndv as f64 / sample_distinct).max(1.0));
+    for bucket in buckets.iter_mut() {
+        bucket.num_values *= values_scale;
+        bucket.num_distinct = (bucket.num_distinct * distinct_scale).min(bucket.num_values);
+    }
+    buckets
 }
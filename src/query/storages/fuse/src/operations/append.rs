@@ -115,7 +115,7 @@ impl FuseTable {
                     self.meta_location_generator().clone(),
                     cluster_stats_gen.clone(),
                     block_compact_thresholds,
-                    self.table_info.schema(),
+                    self.storage_schema(),
                     Some(transform_output_port),
                 )
             })?;
@@ -129,7 +129,7 @@ impl FuseTable {
                     self.meta_location_generator().clone(),
                     cluster_stats_gen.clone(),
                     block_compact_thresholds,
-                    self.table_info.schema(),
+                    self.storage_schema(),
                     None,
                 )
             })?;
@@ -150,7 +150,7 @@ impl FuseTable {
             return Ok(ClusterStatsGenerator::default());
         }
 
-        let input_schema = self.table_info.schema();
+        let input_schema = self.storage_schema();
         let mut merged: Vec<DataField> =
             input_schema.fields().iter().map(DataField::from).collect();
 
@@ -0,0 +1,448 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::Scalar;
+use common_expression::Value;
+use futures::TryStreamExt;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SnapshotMutation;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::STREAM_CHANGE_ACTION_DELETE;
+use storages_common_table_meta::table::STREAM_CHANGE_ACTION_INSERT;
+
+use crate::io::BlockReader;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::SegmentsIO;
+use crate::io::SnapshotHistoryReader;
+use crate::FuseTable;
+
+/// A block is identified by its data file and its deletion vector, a block whose deletion
+/// vector is rewritten is removed and added again.
+type BlockKey = (Location, Option<Location>);
+
+/// The blocks deleted and inserted by one or more mutations that rewrote them together since
+/// the offset of a stream. The rows of the deleted blocks that are inserted again unchanged
+/// cancel each other out, so the changes of a group are read by one partition.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ChangedBlocks {
+    pub deleted: Vec<Arc<BlockMeta>>,
+    pub inserted: Vec<Arc<BlockMeta>>,
+    /// Whether the blocks were rewritten by an update.
+    pub is_update: bool,
+}
+
+/// The blocks of the mutations that rewrote the same blocks since the offset.
+#[derive(Default)]
+struct ChangeGroup {
+    deleted: HashMap<BlockKey, Arc<BlockMeta>>,
+    inserted: HashMap<BlockKey, Arc<BlockMeta>>,
+    is_update: bool,
+    /// Whether a mutation of the group changed rows, a group rewritten only by compaction or
+    /// reclustering has no changes.
+    changes_rows: bool,
+}
+
+impl ChangeGroup {
+    fn merge(&mut self, other: ChangeGroup) {
+        self.deleted.extend(other.deleted);
+        self.inserted.extend(other.inserted);
+        self.is_update |= other.is_update;
+        self.changes_rows |= other.changes_rows;
+    }
+}
+
+impl FuseTable {
+    /// Returns the blocks changed since the snapshot at `offset`, or since the table was created
+    /// if there is no offset.
+    ///
+    /// The changes are computed by diffing the segments and blocks of each snapshot against its
+    /// previous one, from the offset up to the current snapshot. Blocks only deleted or only
+    /// inserted are returned one by one, the blocks rewritten by mutations are grouped with the
+    /// blocks they replaced.
+    pub async fn changed_blocks(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        offset: Option<&str>,
+    ) -> Result<Vec<ChangedBlocks>> {
+        let current = match self.snapshot_loc().await? {
+            Some(current) => current,
+            None => return Ok(vec![]),
+        };
+        if offset == Some(current.as_str()) {
+            return Ok(vec![]);
+        }
+
+        let (base, snapshots) = self.snapshots_since(current, offset).await?;
+        let groups = self.group_changes(ctx, base, snapshots).await?;
+
+        let mut changes = vec![];
+        for group in groups.into_iter().filter(|group| group.changes_rows) {
+            let deleted = sorted_blocks(group.deleted);
+            let inserted = sorted_blocks(group.inserted);
+            if deleted.is_empty() || inserted.is_empty() {
+                // No rows cancel each other out, every block is read on its own.
+                changes.extend(deleted.into_iter().map(|block| ChangedBlocks {
+                    deleted: vec![block],
+                    inserted: vec![],
+                    is_update: false,
+                }));
+                changes.extend(inserted.into_iter().map(|block| ChangedBlocks {
+                    deleted: vec![],
+                    inserted: vec![block],
+                    is_update: false,
+                }));
+            } else {
+                changes.push(ChangedBlocks {
+                    deleted,
+                    inserted,
+                    is_update: group.is_update,
+                });
+            }
+        }
+        Ok(changes)
+    }
+
+    /// Creates a reader of the rows inserted and deleted by `changes`.
+    pub async fn changes_reader(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        changes: ChangedBlocks,
+    ) -> Result<ChangesReader> {
+        let num_fields = self.storage_schema().fields().len();
+        let mut reader = ChangesReader {
+            block_reader: self
+                .create_block_reader(Projection::Columns((0..num_fields).collect()))?,
+            settings: ReadSettings::from_ctx(ctx)?,
+            is_native: self.is_native(),
+            cancels_rows: !changes.deleted.is_empty() && !changes.inserted.is_empty(),
+            deleted: changes.deleted.into(),
+            inserted: changes.inserted.into(),
+            is_update: changes.is_update,
+            deleted_rows: HashMap::new(),
+            unchanged_rows: HashMap::new(),
+        };
+        if reader.cancels_rows {
+            for meta in reader.deleted.clone() {
+                let block = reader.read_block(&meta).await?;
+                for key in row_keys(&block) {
+                    *reader.deleted_rows.entry(key).or_default() += 1;
+                }
+            }
+        }
+        Ok(reader)
+    }
+
+    /// Walks the history back from the current snapshot to the offset. Returns the snapshot at
+    /// the offset and the snapshots after it, newest first.
+    async fn snapshots_since(
+        &self,
+        current: String,
+        offset: Option<&str>,
+    ) -> Result<(Option<Arc<TableSnapshot>>, Vec<Arc<TableSnapshot>>)> {
+        let location_gen = self.meta_location_generator().clone();
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut history = reader.snapshot_history(
+            current.clone(),
+            self.snapshot_format_version().await?,
+            location_gen.clone(),
+        );
+
+        let mut location = Some(current);
+        let mut snapshots = vec![];
+        while let Some(snapshot) = history.try_next().await? {
+            if offset.is_some() && location.as_deref() == offset {
+                return Ok((Some(snapshot), snapshots));
+            }
+            location = match snapshot.prev_snapshot_id {
+                Some((id, ver)) => Some(location_gen.snapshot_location_from_uuid(&id, ver)?),
+                None => None,
+            };
+            snapshots.push(snapshot);
+        }
+
+        match offset {
+            None => Ok((None, snapshots)),
+            Some(offset) => Err(ErrorCode::IllegalStream(format!(
+                "snapshot '{}' is no longer in the history of table '{}'",
+                offset,
+                self.name()
+            ))),
+        }
+    }
+
+    /// Groups the blocks removed and added by each snapshot after `base`, from the oldest to the
+    /// newest. The blocks a mutation removed and added form a group, which takes over the
+    /// groups of the removed blocks that were added since the offset.
+    async fn group_changes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        base: Option<Arc<TableSnapshot>>,
+        snapshots: Vec<Arc<TableSnapshot>>,
+    ) -> Result<Vec<ChangeGroup>> {
+        let segments_io = SegmentsIO::create(ctx, self.get_operator(), self.storage_schema());
+        let mut prev_segments = base.map(|s| s.segments.clone()).unwrap_or_default();
+        let mut groups: Vec<ChangeGroup> = vec![];
+        // The group of each block added since the offset and not removed again.
+        let mut group_of: HashMap<BlockKey, usize> = HashMap::new();
+
+        for snapshot in snapshots.into_iter().rev() {
+            let prev = prev_segments.iter().collect::<HashSet<_>>();
+            let curr = snapshot.segments.iter().collect::<HashSet<_>>();
+            let removed_segments = prev_segments
+                .iter()
+                .filter(|s| !curr.contains(s))
+                .cloned()
+                .collect::<Vec<_>>();
+            let added_segments = snapshot
+                .segments
+                .iter()
+                .filter(|s| !prev.contains(s))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            let removed_blocks = Self::blocks_of(&segments_io, &removed_segments).await?;
+            let added_blocks = Self::blocks_of(&segments_io, &added_segments).await?;
+
+            // Blocks that only moved between segments, e.g. by segment compaction, are unchanged.
+            let step_removed = removed_blocks
+                .iter()
+                .filter(|(key, _)| !added_blocks.contains_key(*key))
+                .map(|(key, meta)| (key.clone(), meta.clone()))
+                .collect::<Vec<_>>();
+            let step_added = added_blocks
+                .into_iter()
+                .filter(|(key, _)| !removed_blocks.contains_key(key))
+                .collect::<Vec<_>>();
+
+            let mutation = snapshot.mutation;
+            let changes_rows = !mutation.map_or(false, |m| m.keeps_rows());
+
+            if step_added.is_empty() {
+                // The removed blocks are deleted entirely.
+                for (key, meta) in step_removed {
+                    match group_of.remove(&key) {
+                        Some(index) => {
+                            groups[index].inserted.remove(&key);
+                            groups[index].changes_rows |= changes_rows;
+                        }
+                        None => groups.push(ChangeGroup {
+                            deleted: HashMap::from([(key, meta)]),
+                            changes_rows,
+                            ..Default::default()
+                        }),
+                    }
+                }
+            } else if step_removed.is_empty() {
+                // The added blocks are appended.
+                for (key, meta) in step_added {
+                    group_of.insert(key.clone(), groups.len());
+                    groups.push(ChangeGroup {
+                        inserted: HashMap::from([(key, meta)]),
+                        changes_rows,
+                        ..Default::default()
+                    });
+                }
+            } else {
+                let index = groups.len();
+                let mut group = ChangeGroup {
+                    is_update: mutation == Some(SnapshotMutation::Update),
+                    changes_rows,
+                    ..Default::default()
+                };
+                for (key, meta) in step_removed {
+                    match group_of.remove(&key) {
+                        Some(other) => {
+                            if other != index {
+                                let other = std::mem::take(&mut groups[other]);
+                                for key in other.inserted.keys() {
+                                    group_of.insert(key.clone(), index);
+                                }
+                                group.merge(other);
+                            }
+                            group.inserted.remove(&key);
+                        }
+                        None => {
+                            group.deleted.insert(key, meta);
+                        }
+                    }
+                }
+                for (key, meta) in step_added {
+                    group_of.insert(key.clone(), index);
+                    group.inserted.insert(key, meta);
+                }
+                groups.push(group);
+            }
+
+            prev_segments = snapshot.segments.clone();
+        }
+
+        Ok(groups)
+    }
+
+    async fn blocks_of(
+        segments_io: &SegmentsIO,
+        segments: &[Location],
+    ) -> Result<HashMap<BlockKey, Arc<BlockMeta>>> {
+        let mut blocks = HashMap::new();
+        for segment in segments_io.read_segments(segments).await? {
+            for block in &segment?.blocks {
+                let key = (
+                    block.location.clone(),
+                    block.deletion_vector_location.clone(),
+                );
+                blocks.insert(key, block.clone());
+            }
+        }
+        Ok(blocks)
+    }
+}
+
+/// Reads the changes of [`ChangedBlocks`] block by block, the inserted blocks first.
+///
+/// The rows of the deleted blocks are kept in memory only to cancel out the rows inserted again
+/// unchanged, the deleted blocks are read again to output the rows that are really deleted.
+pub struct ChangesReader {
+    block_reader: Arc<BlockReader>,
+    settings: ReadSettings,
+    is_native: bool,
+    deleted: VecDeque<Arc<BlockMeta>>,
+    inserted: VecDeque<Arc<BlockMeta>>,
+    is_update: bool,
+    cancels_rows: bool,
+    /// The deleted rows not inserted again.
+    deleted_rows: HashMap<Vec<Scalar>, usize>,
+    /// The deleted rows inserted again unchanged.
+    unchanged_rows: HashMap<Vec<Scalar>, usize>,
+}
+
+impl ChangesReader {
+    /// Returns the next block of changes, with the table columns followed by the
+    /// `change$action` and `change$is_update` columns.
+    pub async fn next_block(&mut self) -> Result<Option<DataBlock>> {
+        while let Some(meta) = self.inserted.pop_front() {
+            let block = self.read_block(&meta).await?;
+            let mut rows = vec![];
+            for (row, key) in self.row_keys(&block).into_iter().enumerate() {
+                match self.deleted_rows.get_mut(&key) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        *self.unchanged_rows.entry(key).or_default() += 1;
+                    }
+                    _ => rows.push(row as u32),
+                }
+            }
+            if !rows.is_empty() {
+                let action = STREAM_CHANGE_ACTION_INSERT;
+                return with_change_columns(&block, &rows, action, self.is_update).map(Some);
+            }
+        }
+
+        while let Some(meta) = self.deleted.pop_front() {
+            let block = self.read_block(&meta).await?;
+            let mut rows = vec![];
+            for (row, key) in self.row_keys(&block).into_iter().enumerate() {
+                match self.unchanged_rows.get_mut(&key) {
+                    Some(count) if *count > 0 => *count -= 1,
+                    _ => rows.push(row as u32),
+                }
+            }
+            if !rows.is_empty() {
+                let action = STREAM_CHANGE_ACTION_DELETE;
+                return with_change_columns(&block, &rows, action, self.is_update).map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Reads a block with its deletion vector applied.
+    async fn read_block(&self, meta: &BlockMeta) -> Result<DataBlock> {
+        if self.is_native {
+            self.block_reader.read_native_by_meta(meta).await
+        } else {
+            self.block_reader
+                .read_parquet_by_meta(&self.settings, meta)
+                .await
+        }
+    }
+
+    /// The keys of the rows of `block`, or no keys if no rows cancel out.
+    fn row_keys(&self, block: &DataBlock) -> Vec<Vec<Scalar>> {
+        if !self.cancels_rows {
+            return vec![vec![]; block.num_rows()];
+        }
+        row_keys(block)
+    }
+}
+
+fn sorted_blocks(blocks: HashMap<BlockKey, Arc<BlockMeta>>) -> Vec<Arc<BlockMeta>> {
+    let mut blocks = blocks.into_values().collect::<Vec<_>>();
+    blocks.sort_by(|a, b| a.location.cmp(&b.location));
+    blocks
+}
+
+fn row_keys(block: &DataBlock) -> Vec<Vec<Scalar>> {
+    let columns = block
+        .columns()
+        .iter()
+        .map(|entry| {
+            entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows())
+        })
+        .collect::<Vec<_>>();
+    (0..block.num_rows())
+        .map(|row| {
+            columns
+                .iter()
+                .map(|c| c.index(row).unwrap().to_owned())
+                .collect()
+        })
+        .collect()
+}
+
+fn with_change_columns(
+    block: &DataBlock,
+    rows: &[u32],
+    action: &str,
+    is_update: bool,
+) -> Result<DataBlock> {
+    let mut block = block.take(rows)?;
+    block.add_column(BlockEntry {
+        data_type: DataType::String,
+        value: Value::Scalar(Scalar::String(action.as_bytes().to_vec())),
+    });
+    block.add_column(BlockEntry {
+        data_type: DataType::Boolean,
+        value: Value::Column(BooleanType::from_data(vec![is_update; rows.len()])),
+    });
+    Ok(block)
+}
@@ -0,0 +1,55 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use storages_common_table_meta::meta::TableSnapshot;
+use uuid::Uuid;
+
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Make the first snapshot of this newly created table refer to the segments of `source`,
+    /// for `CREATE TABLE ... CLONE`. No data is copied: the blocks stay where they are, and the
+    /// purge of either table keeps the files the other one still references.
+    pub async fn do_clone(&self, ctx: &dyn TableContext, source: &FuseTable) -> Result<()> {
+        let source_snapshot = match source.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            // nothing to share with an empty table.
+            None => return Ok(()),
+        };
+
+        let snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            self.schema().as_ref().clone(),
+            source_snapshot.summary.clone(),
+            source_snapshot.segments.clone(),
+            self.cluster_key_meta.clone(),
+            None,
+        );
+
+        FuseTable::commit_to_meta_server(
+            ctx,
+            &self.table_info,
+            &self.meta_location_generator,
+            snapshot,
+            None,
+            &self.operator,
+        )
+        .await
+    }
+}
@@ -38,11 +38,18 @@ use storages_common_cache_manager::CachedObject;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::SnapshotMutation;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::SnapshotRef;
+use storages_common_table_meta::table::SnapshotRefKind;
+use storages_common_table_meta::table::VirtualColumnDefinition;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_BRANCH;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_REFS;
+use storages_common_table_meta::table::OPT_KEY_VIRTUAL_COLUMNS;
 use tracing::debug;
 use tracing::info;
 use tracing::warn;
@@ -154,6 +161,16 @@ impl FuseTable {
                             );
                             common_base::base::tokio::time::sleep(d).await;
                             latest = tbl.refresh(ctx.as_ref()).await?;
+                            // keep committing to the branch the table is written to
+                            if let Some(branch) = self.branch() {
+                                latest = FuseTable::try_from_table(latest.as_ref())?
+                                    .with_branch(branch)?;
+                            }
+                            // and the options committed along with the snapshots
+                            if !self.commit_options.is_empty() {
+                                latest = FuseTable::try_from_table(latest.as_ref())?
+                                    .with_commit_options(self.commit_options.clone())?;
+                            }
                             tbl = FuseTable::try_from_table(latest.as_ref())?;
                             retry_times += 1;
                             continue;
@@ -248,9 +265,24 @@ impl FuseTable {
             index_data_bytes: new_snapshot.summary.index_size,
         };
 
+        let mut table_info = self.table_info.clone();
+        table_info.meta.options.extend(self.commit_options.clone());
+
+        // the virtual columns detected by the writes are defined along with the commit
+        let detected = self.detected_virtual_columns(operation_log);
+        if !detected.is_empty() {
+            let mut virtual_columns =
+                VirtualColumnDefinition::from_table_options(table_info.options())?;
+            virtual_columns.extend(detected);
+            table_info.meta.options.insert(
+                OPT_KEY_VIRTUAL_COLUMNS.to_owned(),
+                VirtualColumnDefinition::to_table_option(&virtual_columns)?,
+            );
+        }
+
         FuseTable::commit_to_meta_server(
             ctx.as_ref(),
-            &self.table_info,
+            &table_info,
             &self.meta_location_generator,
             new_snapshot,
             None,
@@ -259,6 +291,44 @@ impl FuseTable {
         .await
     }
 
+    // The virtual columns stored by the blocks appended, which are detected in the columns
+    // listed in the `auto_virtual_columns` option and not defined yet.
+    fn detected_virtual_columns(
+        &self,
+        operation_log: &TableOperationLog,
+    ) -> Vec<VirtualColumnDefinition> {
+        let auto_virtual_columns = self.auto_virtual_columns();
+        if auto_virtual_columns.is_empty() {
+            return vec![];
+        }
+
+        let mut defined = self.virtual_column_definitions();
+        let mut detected = vec![];
+        let virtual_columns = operation_log
+            .iter()
+            .flat_map(|entry| entry.segment_info.blocks.iter())
+            .filter_map(|block| block.virtual_block_meta.as_ref())
+            .flat_map(|meta| meta.columns.iter());
+        for column in virtual_columns {
+            if defined.iter().any(|d| d.name() == column.name) {
+                continue;
+            }
+            let definition = auto_virtual_columns.iter().find_map(|source_column| {
+                let path = column.name.strip_prefix(source_column)?.strip_prefix(':')?;
+                Some(VirtualColumnDefinition {
+                    source_column: source_column.clone(),
+                    path: path.to_string(),
+                    cast_type: column.cast_type.clone(),
+                })
+            });
+            if let Some(definition) = definition {
+                defined.push(definition.clone());
+                detected.push(definition);
+            }
+        }
+        detected
+    }
+
     fn merge_table_operations(
         schema: &TableSchema,
         previous: Option<Arc<TableSnapshot>>,
@@ -327,24 +397,44 @@ impl FuseTable {
 
         // 2. prepare table meta
         let mut new_table_meta = table_info.meta.clone();
-        // 2.1 set new snapshot location
-        new_table_meta.options.insert(
-            OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
-            snapshot_location.clone(),
-        );
+        let branch = new_table_meta.options.remove(OPT_KEY_SNAPSHOT_BRANCH);
+        match &branch {
+            // 2.1 a branch moves its head, the table and its statistics are kept
+            Some(branch) => {
+                let mut refs = SnapshotRef::from_table_options(&new_table_meta.options)?;
+                let branch_ref = refs
+                    .iter_mut()
+                    .find(|r| &r.name == branch && r.kind == SnapshotRefKind::Branch)
+                    .ok_or_else(|| {
+                        ErrorCode::UnknownSnapshotReference(format!("unknown branch '{}'", branch))
+                    })?;
+                branch_ref.snapshot_location = snapshot_location.clone();
+                new_table_meta.options.insert(
+                    OPT_KEY_SNAPSHOT_REFS.to_owned(),
+                    SnapshotRef::to_table_option(&refs)?,
+                );
+            }
+            None => {
+                // 2.1 set new snapshot location
+                new_table_meta.options.insert(
+                    OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
+                    snapshot_location.clone(),
+                );
+
+                // 2.2 setup table statistics
+                let stats = &snapshot.summary;
+                // update statistics
+                new_table_meta.statistics = TableStatistics {
+                    number_of_rows: stats.row_count,
+                    data_bytes: stats.uncompressed_byte_size,
+                    compressed_data_bytes: stats.compressed_byte_size,
+                    index_data_bytes: stats.index_size,
+                };
+            }
+        }
         // remove legacy options
         utils::remove_legacy_options(&mut new_table_meta.options);
 
-        // 2.2 setup table statistics
-        let stats = &snapshot.summary;
-        // update statistics
-        new_table_meta.statistics = TableStatistics {
-            number_of_rows: stats.row_count,
-            data_bytes: stats.uncompressed_byte_size,
-            compressed_data_bytes: stats.compressed_byte_size,
-            index_data_bytes: stats.index_size,
-        };
-
         // 3. prepare the request
         let catalog = ctx.get_catalog(&table_info.meta.catalog)?;
         let table_id = table_info.ident.table_id;
@@ -354,12 +444,15 @@ impl FuseTable {
             table_id,
             seq: MatchSeq::Exact(table_version),
             new_table_meta,
+            update_stream_metas: ctx.get_consumed_streams(),
         };
 
         // 3. let's roll
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
+                // the offsets of the consumed streams are advanced with this commit
+                ctx.clear_consumed_streams();
                 // upsert snapshot statistics cache
                 if let Some(snapshot_statistics) = table_statistics {
                     if let Some(location) = &snapshot.table_statistics_location {
@@ -369,8 +462,10 @@ impl FuseTable {
                 }
                 TableSnapshot::cache().put(snapshot_location.clone(), Arc::new(snapshot));
                 // try keep a hit file of last snapshot
-                Self::write_last_snapshot_hint(operator, location_generator, snapshot_location)
-                    .await;
+                if branch.is_none() {
+                    Self::write_last_snapshot_hint(operator, location_generator, snapshot_location)
+                        .await;
+                }
                 Ok(())
             }
             Err(e) => {
@@ -481,6 +576,7 @@ impl FuseTable {
         base_segments: Vec<Location>,
         base_summary: Statistics,
         abort_operation: AbortOperation,
+        mutation: SnapshotMutation,
     ) -> Result<()> {
         let mut retries = 0;
 
@@ -497,7 +593,7 @@ impl FuseTable {
             let mut snapshot_tobe_committed =
                 TableSnapshot::from_previous(latest_snapshot.as_ref());
 
-            let schema = self.schema();
+            let schema = self.storage_schema();
             let (segments_tobe_committed, statistics_tobe_committed) = Self::merge_with_base(
                 ctx.clone(),
                 self.operator.clone(),
@@ -509,6 +605,7 @@ impl FuseTable {
             .await?;
             snapshot_tobe_committed.segments = segments_tobe_committed;
             snapshot_tobe_committed.summary = statistics_tobe_committed;
+            snapshot_tobe_committed.mutation = Some(mutation);
 
             match Self::commit_to_meta_server(
                 ctx.as_ref(),
@@ -689,8 +786,9 @@ mod utils {
     #[inline]
     pub fn no_side_effects_in_meta_store(e: &ErrorCode) -> bool {
         // currently, the only error that we know,  which indicates there are no side effects
-        // is TABLE_VERSION_MISMATCHED
+        // is TABLE_VERSION_MISMATCHED, or STREAM_VERSION_MISMATCHED of a consumed stream
         e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+            || e.code() == ErrorCode::STREAM_VERSION_MISMATCHED
     }
 
     // check if there are any fuse table legacy options
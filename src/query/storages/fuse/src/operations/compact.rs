@@ -20,6 +20,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_pipeline_core::pipe::Pipe;
 use common_pipeline_core::pipe::PipeItem;
+use storages_common_table_meta::meta::SnapshotMutation;
 use storages_common_table_meta::meta::TableSnapshot;
 
 use crate::operations::mutation::BlockCompactMutator;
@@ -153,7 +154,7 @@ impl FuseTable {
                 block_reader.clone(),
                 self.meta_location_generator().clone(),
                 self.operator.clone(),
-                self.schema(),
+                self.storage_schema(),
                 thresholds,
                 self.get_write_settings(),
             )
@@ -165,6 +166,7 @@ impl FuseTable {
                 self,
                 ctx.clone(),
                 mutator.compact_params.base_snapshot.clone(),
+                SnapshotMutation::Compact,
                 input,
             )
         })?;
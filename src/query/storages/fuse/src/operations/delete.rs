@@ -41,6 +41,7 @@ use common_pipeline_core::pipe::Pipe;
 use common_pipeline_core::pipe::PipeItem;
 use common_sql::evaluator::BlockOperator;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SnapshotMutation;
 use storages_common_table_meta::meta::TableSnapshot;
 
 use crate::operations::mutation::MutationAction;
@@ -55,6 +56,7 @@ use crate::pipelines::Pipeline;
 use crate::pruning::FusePruner;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
+use crate::FUSE_OPT_KEY_ENABLE_DELETION_VECTORS;
 
 impl FuseTable {
     /// The flow of Pipeline is as follows:
@@ -65,6 +67,9 @@ impl FuseTable {
     /// +---------------+      +-----------------------+         |      +-----------------+      +------------+
     /// |MutationSourceN| ---> |SerializeDataTransformN|   ------
     /// +---------------+      +-----------------------+
+    ///
+    /// With deletion vectors enabled, MutationSource writes the deletion vectors and the blocks
+    /// are not rewritten, so there is no SerializeDataTransform.
     pub async fn do_delete(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -110,7 +115,7 @@ impl FuseTable {
             // if the `filter_expr` is of "constant" nullary :
             //   for the whole block, whether all of the rows should be kept or dropped,
             //   we can just return from here, without accessing the block data
-            if self.try_eval_const(ctx.clone(), &self.schema(), &filter_expr)? {
+            if self.try_eval_const(ctx.clone(), &self.storage_schema(), &filter_expr)? {
                 let progress_values = ProgressValues {
                     rows: snapshot.summary.row_count as usize,
                     bytes: snapshot.summary.uncompressed_byte_size as usize,
@@ -125,24 +130,40 @@ impl FuseTable {
             return Ok(());
         }
 
-        self.try_add_deletion_source(ctx.clone(), &filter_expr, col_indices, &snapshot, pipeline)
-            .await?;
+        let deletion_vectors = self.get_option(FUSE_OPT_KEY_ENABLE_DELETION_VECTORS, false);
+        self.try_add_deletion_source(
+            ctx.clone(),
+            &filter_expr,
+            col_indices,
+            &snapshot,
+            deletion_vectors,
+            pipeline,
+        )
+        .await?;
 
-        let cluster_stats_gen = self.cluster_stats_gen(ctx.clone())?;
-        pipeline.add_transform(|input, output| {
-            SerializeDataTransform::try_create(
-                ctx.clone(),
-                input,
-                output,
-                self,
-                cluster_stats_gen.clone(),
-            )
-        })?;
+        if !deletion_vectors {
+            let cluster_stats_gen = self.cluster_stats_gen(ctx.clone())?;
+            pipeline.add_transform(|input, output| {
+                SerializeDataTransform::try_create(
+                    ctx.clone(),
+                    input,
+                    output,
+                    self,
+                    cluster_stats_gen.clone(),
+                )
+            })?;
+        }
 
         self.try_add_mutation_transform(ctx.clone(), snapshot.segments.clone(), pipeline)?;
 
         pipeline.add_sink(|input| {
-            MutationSink::try_create(self, ctx.clone(), snapshot.clone(), input)
+            MutationSink::try_create(
+                self,
+                ctx.clone(),
+                snapshot.clone(),
+                SnapshotMutation::Delete,
+                input,
+            )
         })?;
         Ok(())
     }
@@ -188,6 +209,7 @@ impl FuseTable {
         filter: &RemoteExpr<String>,
         col_indices: Vec<usize>,
         base_snapshot: &TableSnapshot,
+        deletion_vectors: bool,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         let projection = Projection::Columns(col_indices.clone());
@@ -231,14 +253,21 @@ impl FuseTable {
         // Add source pipe.
         pipeline.add_source(
             |output| {
+                // Only the filter columns are needed to find the rows to delete.
+                let (action, remain_reader, ops) = if deletion_vectors {
+                    (MutationAction::MarkDeletion, Arc::new(None), vec![])
+                } else {
+                    (MutationAction::Deletion, remain_reader.clone(), ops.clone())
+                };
                 MutationSource::try_create(
                     ctx.clone(),
-                    MutationAction::Deletion,
+                    action,
                     output,
                     filter.clone(),
                     block_reader.clone(),
-                    remain_reader.clone(),
-                    ops.clone(),
+                    remain_reader,
+                    ops,
+                    self.meta_location_generator().clone(),
                 )
             },
             max_threads,
@@ -262,8 +291,9 @@ impl FuseTable {
         let pruner = FusePruner::create(
             &ctx,
             self.operator.clone(),
-            self.table_info.schema(),
+            self.storage_schema(),
             &push_down,
+            &self.text_index_options(),
         )?;
         let block_metas = pruner.pruning(segment_locations).await?;
 
@@ -274,7 +304,7 @@ impl FuseTable {
             .collect::<Vec<_>>();
 
         let (_, inner_parts) = self.read_partitions_with_metas(
-            self.table_info.schema(),
+            self.storage_schema(),
             None,
             &range_block_metas,
             base_snapshot.summary.block_count as usize,
@@ -313,7 +343,7 @@ impl FuseTable {
                 pipeline.add_pipe(Pipe::create(inputs_port.len(), 1, vec![PipeItem::create(
                     MutationTransform::try_create(
                         ctx,
-                        self.schema(),
+                        self.storage_schema(),
                         inputs_port.clone(),
                         output_port.clone(),
                         self.get_operator(),
@@ -335,7 +365,7 @@ impl FuseTable {
             return Ok(ClusterStatsGenerator::default());
         }
 
-        let input_schema = self.table_info.schema();
+        let input_schema = self.storage_schema();
         let mut merged: Vec<DataField> =
             input_schema.fields().iter().map(DataField::from).collect();
         let func_ctx = ctx.get_function_context()?;
@@ -383,7 +413,7 @@ impl FuseTable {
     }
 
     pub fn all_the_columns_ids(&self) -> Vec<usize> {
-        (0..self.table_info.schema().fields().len())
+        (0..self.storage_schema().fields().len())
             .into_iter()
             .collect::<Vec<usize>>()
     }
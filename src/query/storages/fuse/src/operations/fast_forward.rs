@@ -0,0 +1,97 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use futures::TryStreamExt;
+use storages_common_table_meta::table::SnapshotRef;
+use storages_common_table_meta::table::SnapshotRefKind;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::io::MetaReaders;
+use crate::io::SnapshotHistoryReader;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Moves the table to the head snapshot of the branch. The current snapshot of the table
+    /// must be in the history of the branch, so that no change of the table is lost.
+    pub async fn do_fast_forward(&self, ctx: &dyn TableContext, branch: &str) -> Result<()> {
+        let refs = SnapshotRef::from_table_options(self.table_info.options())?;
+        let branch_head = SnapshotRef::find(&refs, branch, SnapshotRefKind::Branch)?
+            .snapshot_location
+            .clone();
+
+        // shortcut. if the branch has not been written, just return ok
+        let head = self.snapshot_loc().await?;
+        if head.as_deref() == Some(branch_head.as_str()) {
+            return Ok(());
+        }
+
+        if let Some(head) = head {
+            let location_gen = self.meta_location_generator().clone();
+            let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+            let mut history = reader.snapshot_history(
+                branch_head.clone(),
+                TableMetaLocationGenerator::snapshot_version(&branch_head),
+                location_gen.clone(),
+            );
+            let mut is_ancestor = false;
+            while let Some(snapshot) = history.try_next().await? {
+                let location = location_gen.snapshot_location_from_uuid(
+                    &snapshot.snapshot_id,
+                    snapshot.format_version(),
+                )?;
+                if location == head {
+                    is_ancestor = true;
+                    break;
+                }
+            }
+            if !is_ancestor {
+                return Err(ErrorCode::IllegalFastForward(format!(
+                    "table '{}' has changed since branch '{}' was created, it can not be fast-forwarded",
+                    self.name(),
+                    branch
+                )));
+            }
+        }
+
+        // the schema and options are kept, only the snapshot and its statistics move
+        let branch_table = self
+            .navigate_to_snapshot_ref(branch, SnapshotRefKind::Branch)
+            .await?;
+        let mut new_table_meta = self.table_info.meta.clone();
+        new_table_meta
+            .options
+            .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), branch_head.clone());
+        new_table_meta.statistics = branch_table.table_info.meta.statistics.clone();
+
+        let catalog = ctx.get_catalog(self.table_info.catalog())?;
+        let req = UpdateTableMetaReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            new_table_meta,
+            update_stream_metas: vec![],
+        };
+        catalog.update_table_meta(&self.table_info, req).await?;
+
+        Self::write_last_snapshot_hint(&self.operator, &self.meta_location_generator, branch_head)
+            .await;
+        Ok(())
+    }
+}
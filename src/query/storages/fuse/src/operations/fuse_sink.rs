@@ -21,8 +21,10 @@ use async_trait::async_trait;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::BlockEntry;
 use common_expression::BlockThresholds;
 use common_expression::DataBlock;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
@@ -37,7 +39,12 @@ use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+use storages_common_table_meta::meta::VirtualBlockMeta;
+use storages_common_table_meta::meta::VirtualColumnMeta;
+use storages_common_table_meta::table::InvertedIndexDefinition;
 use storages_common_table_meta::table::TableCompression;
+use storages_common_table_meta::table::VirtualColumnDefinition;
 
 use super::AppendOperationLogEntry;
 use crate::io;
@@ -53,6 +60,7 @@ use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::processor::ProcessorPtr;
 use crate::pipelines::processors::Processor;
+use crate::statistics::gen_columns_statistics;
 use crate::statistics::BlockStatistics;
 use crate::statistics::ClusterStatsGenerator;
 use crate::statistics::StatisticsAccumulator;
@@ -70,12 +78,16 @@ impl BloomIndexState {
         source_schema: TableSchemaRef,
         block: &DataBlock,
         location: Location,
+        text_index: &TextIndexOptions,
     ) -> Result<Option<Self>> {
         // write index
-        let maybe_bloom_index =
-            BloomIndex::try_create(ctx.get_function_context()?, source_schema, location.1, &[
-                block,
-            ])?;
+        let maybe_bloom_index = BloomIndex::try_create(
+            ctx.get_function_context()?,
+            source_schema,
+            location.1,
+            &[block],
+            text_index,
+        )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
             let filter_schema = bloom_index.filter_schema;
@@ -100,6 +112,162 @@ impl BloomIndexState {
     }
 }
 
+pub struct InvertedIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+impl InvertedIndexState {
+    pub fn try_create(
+        source_schema: &TableSchema,
+        block: &DataBlock,
+        location: Location,
+        inverted_indexes: &[InvertedIndexDefinition],
+    ) -> Result<Option<Self>> {
+        if inverted_indexes.is_empty() {
+            return Ok(None);
+        }
+        match InvertedIndex::try_create(source_schema, block, inverted_indexes)? {
+            Some(inverted_index) => {
+                let data = inverted_index.to_bytes()?;
+                Ok(Some(Self {
+                    size: data.len() as u64,
+                    data,
+                    location,
+                }))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// The virtual columns extracted from a block, which are stored in the file of the block
+/// after the columns of the table, along with their bloom filters.
+pub struct VirtualColumnState {
+    columns: Vec<(VirtualColumnDefinition, BlockEntry)>,
+    schema: TableSchema,
+    col_stats: StatisticsOfColumns,
+    pub(crate) bloom_index_state: Option<BloomIndexState>,
+}
+
+impl VirtualColumnState {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        source_schema: &TableSchema,
+        block: &DataBlock,
+        bloom_index_location: Location,
+        virtual_columns: &[VirtualColumnDefinition],
+        auto_virtual_columns: &[String],
+    ) -> Result<Option<Self>> {
+        if virtual_columns.is_empty() && auto_virtual_columns.is_empty() {
+            return Ok(None);
+        }
+        let columns = io::extract_virtual_columns(
+            ctx.get_function_context()?,
+            source_schema,
+            block,
+            virtual_columns,
+            auto_virtual_columns,
+        )?;
+        if columns.is_empty() {
+            return Ok(None);
+        }
+
+        let schema = io::virtual_columns_schema(&columns)?;
+        let entries = columns.iter().map(|(_, entry)| entry.clone()).collect();
+        let virtual_block = DataBlock::new(entries, block.num_rows());
+        let bloom_index_state = BloomIndexState::try_create(
+            ctx,
+            Arc::new(schema.clone()),
+            &virtual_block,
+            bloom_index_location,
+            &TextIndexOptions::default(),
+        )?;
+        let column_distinct_count = bloom_index_state
+            .as_ref()
+            .map(|i| i.column_distinct_count.clone());
+        let col_stats = gen_columns_statistics(&virtual_block, column_distinct_count)?;
+
+        Ok(Some(Self {
+            columns,
+            schema,
+            col_stats,
+            bloom_index_state,
+        }))
+    }
+
+    /// The schema and the block to serialize into the file of the block, the virtual columns
+    /// follow the columns of the table.
+    pub fn block_with_virtual_columns(
+        &self,
+        source_schema: &TableSchema,
+        mut block: DataBlock,
+    ) -> (TableSchemaRef, DataBlock) {
+        let mut fields = source_schema.fields().clone();
+        fields.extend(self.schema.fields().iter().cloned());
+        for (_, entry) in &self.columns {
+            block.add_column(entry.clone());
+        }
+        (Arc::new(TableSchema::new(fields)), block)
+    }
+
+    /// Takes the metas of the virtual columns out of the column metas of the file of the block,
+    /// the virtual columns are not nested, each of them is one of the last leaf columns.
+    pub fn take_meta(
+        &self,
+        col_metas: &mut HashMap<ColumnId, ColumnMeta>,
+    ) -> Result<VirtualBlockMeta> {
+        let num_leaves = col_metas.len().saturating_sub(self.columns.len());
+        let mut col_stats = self.col_stats.clone();
+        let columns = self
+            .columns
+            .iter()
+            .zip(self.schema.fields())
+            .enumerate()
+            .map(|(idx, ((definition, _), field))| {
+                let col_meta = col_metas
+                    .remove(&((num_leaves + idx) as ColumnId))
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "meta of virtual column {} not found",
+                            field.name()
+                        ))
+                    })?;
+                Ok(VirtualColumnMeta {
+                    name: definition.name(),
+                    cast_type: definition.cast_type.clone(),
+                    data_type: field.data_type().clone(),
+                    col_meta,
+                    col_stats: col_stats.remove(&(idx as ColumnId)),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(VirtualBlockMeta {
+            bloom_filter_index_location: self
+                .bloom_index_state
+                .as_ref()
+                .map(|i| i.location.clone()),
+            bloom_filter_index_size: self.bloom_index_state.as_ref().map_or(0, |i| i.size),
+            columns,
+        })
+    }
+
+    /// Writes the bloom filters of the virtual columns.
+    pub async fn write(&self, data_accessor: &Operator) -> Result<()> {
+        if let Some(bloom_index_state) = &self.bloom_index_state {
+            io::write_data(
+                &bloom_index_state.data,
+                data_accessor,
+                &bloom_index_state.location.0,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
 enum State {
     None,
     NeedSerialize(DataBlock),
@@ -109,6 +277,9 @@ enum State {
         meta_data: HashMap<ColumnId, ColumnMeta>,
         block_statistics: BlockStatistics,
         bloom_index_state: Option<BloomIndexState>,
+        inverted_index_state: Option<InvertedIndexState>,
+        virtual_column_state: Option<VirtualColumnState>,
+        virtual_block_meta: Option<VirtualBlockMeta>,
     },
     GenerateSegment,
     SerializedSegment {
@@ -226,6 +397,23 @@ impl Processor for FuseTableSink {
                     self.source_schema.clone(),
                     &block,
                     location,
+                    &self.write_settings.text_index,
+                )?;
+                let location = self.meta_locations.block_inverted_index_location(&block_id);
+                let inverted_index_state = InvertedIndexState::try_create(
+                    &self.source_schema,
+                    &block,
+                    location,
+                    &self.write_settings.inverted_indexes,
+                )?;
+                let virtual_column_state = VirtualColumnState::try_create(
+                    self.ctx.clone(),
+                    &self.source_schema,
+                    &block,
+                    self.meta_locations
+                        .block_virtual_column_bloom_index_location(&block_id),
+                    &self.write_settings.virtual_columns,
+                    &self.write_settings.auto_virtual_columns,
                 )?;
                 let column_distinct_count = bloom_index_state
                     .as_ref()
@@ -239,8 +427,16 @@ impl Processor for FuseTableSink {
 
                 // we need a configuration of block size threshold here
                 let mut data = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-                let (size, meta_data) =
-                    io::write_block(&self.write_settings, &self.source_schema, block, &mut data)?;
+                let (schema, block) = match &virtual_column_state {
+                    Some(state) => state.block_with_virtual_columns(&self.source_schema, block),
+                    None => (self.source_schema.clone(), block),
+                };
+                let (size, mut meta_data) =
+                    io::write_block(&self.write_settings, &schema, block, &mut data)?;
+                let virtual_block_meta = virtual_column_state
+                    .as_ref()
+                    .map(|state| state.take_meta(&mut meta_data))
+                    .transpose()?;
 
                 self.state = State::Serialized {
                     data,
@@ -248,6 +444,9 @@ impl Processor for FuseTableSink {
                     block_statistics,
                     meta_data,
                     bloom_index_state,
+                    inverted_index_state,
+                    virtual_column_state,
+                    virtual_block_meta,
                 };
             }
             State::GenerateSegment => {
@@ -296,6 +495,9 @@ impl Processor for FuseTableSink {
                 meta_data,
                 block_statistics,
                 bloom_index_state,
+                inverted_index_state,
+                virtual_column_state,
+                virtual_block_meta,
             } => {
                 let start = Instant::now();
 
@@ -335,6 +537,21 @@ impl Processor for FuseTableSink {
                     }
                 }
 
+                // write inverted index
+                if let Some(ref inverted_index_state) = inverted_index_state {
+                    io::write_data(
+                        &inverted_index_state.data,
+                        &self.data_accessor,
+                        &inverted_index_state.location.0,
+                    )
+                    .await?;
+                }
+
+                // write bloom filter index of virtual columns
+                if let Some(ref virtual_column_state) = virtual_column_state {
+                    virtual_column_state.write(&self.data_accessor).await?;
+                }
+
                 let (bloom_index_location, bloom_index_size) =
                     if let Some(bloom_index_state) = bloom_index_state {
                         (
@@ -352,6 +569,9 @@ impl Processor for FuseTableSink {
                     bloom_index_location,
                     bloom_index_size,
                     self.write_settings.table_compression.into(),
+                    inverted_index_state.as_ref().map(|i| i.location.clone()),
+                    inverted_index_state.map_or(0, |i| i.size),
+                    virtual_block_meta,
                 )?;
 
                 if self.accumulator.summary_block_count >= self.write_settings.block_per_seg as u64
@@ -21,6 +21,7 @@ use common_pipeline_core::Pipeline;
 
 use crate::fuse_table::FuseStorageFormat;
 use crate::io::BlockReader;
+use crate::io::VirtualColumnReader;
 use crate::operations::read::build_fuse_parquet_source_pipeline;
 use crate::operations::read::fuse_source::build_fuse_native_source_pipeline;
 
@@ -29,6 +30,7 @@ pub fn build_fuse_source_pipeline(
     pipeline: &mut Pipeline,
     storage_format: FuseStorageFormat,
     block_reader: Arc<BlockReader>,
+    virtual_reader: Option<Arc<VirtualColumnReader>>,
     plan: &DataSourcePlan,
     max_io_requests: usize,
 ) -> Result<()> {
@@ -47,7 +49,9 @@ pub fn build_fuse_source_pipeline(
             ctx,
             pipeline,
             block_reader,
+            virtual_reader,
             max_threads,
+            plan,
             max_io_requests,
         ),
     }
@@ -24,7 +24,11 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::TableInfo;
+use futures::TryStreamExt;
 use storages_common_cache::CacheAccessor;
+use storages_common_cache::LoadParams;
 use storages_common_cache_manager::BloomIndexMeta;
 use storages_common_cache_manager::CachedObject;
 use storages_common_table_meta::meta::Location;
@@ -33,19 +37,30 @@ use storages_common_table_meta::meta::SnapshotId;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotLite;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
+use storages_common_table_meta::table::SnapshotRef;
+use storages_common_table_meta::table::OPT_KEY_CLONE_SOURCE_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_DEPENDENT_TABLE_IDS;
+use storages_common_table_meta::table::OPT_KEY_STREAM_OFFSET;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_TABLE_ID;
+use storages_common_table_meta::table::STREAM_ENGINE;
 use tracing::info;
 use tracing::warn;
 
 use crate::io::Files;
 use crate::io::ListSnapshotLiteOption;
+use crate::io::MetaReaders;
 use crate::io::SegmentsIO;
+use crate::io::SnapshotHistoryReader;
 use crate::io::SnapshotsIO;
+use crate::io::TableMetaLocationGenerator;
 use crate::FuseTable;
 
 #[derive(Default)]
-struct LocationTuple {
-    block_location: HashSet<String>,
-    bloom_location: HashSet<String>,
+pub(crate) struct LocationTuple {
+    pub(crate) block_location: HashSet<String>,
+    pub(crate) bloom_location: HashSet<String>,
+    pub(crate) inverted_index_location: HashSet<String>,
+    pub(crate) deletion_vector_location: HashSet<String>,
 }
 
 impl FuseTable {
@@ -157,6 +172,16 @@ impl FuseTable {
             status_snapshot_scan_cost += start.elapsed().as_secs();
         }
 
+        // Files shared with cloned tables: the ones from the tables this table is cloned from
+        // are not under its prefix, and the ones of this table may be referenced by its clones.
+        // The snapshots since the offset of a stream on this table are still read by the stream,
+        // and the snapshots of the tags and branches of this table are pinned.
+        let (
+            snapshots_pinned,
+            segments_referenced_by_dependents,
+            locations_referenced_by_dependents,
+        ) = self.get_locations_referenced_by_dependents(ctx).await?;
+
         // 3. Find.
         let mut snapshots_to_be_purged = HashSet::new();
         let mut segments_to_be_purged = HashSet::new();
@@ -169,6 +194,9 @@ impl FuseTable {
                 if keep_last_snapshot && snapshot.snapshot_id == root_snapshot_id {
                     continue;
                 }
+                if snapshots_pinned.contains(&snapshot.snapshot_id) {
+                    continue;
+                }
                 snapshots_to_be_purged.insert((snapshot.snapshot_id, snapshot.format_version));
             }
         }
@@ -180,6 +208,11 @@ impl FuseTable {
                 if keep_last_snapshot && segments_referenced_by_root.contains(segment) {
                     continue;
                 }
+                if !self.is_own_location(&segment.0)
+                    || segments_referenced_by_dependents.contains(segment)
+                {
+                    continue;
+                }
                 segments_to_be_purged.insert(segment.clone());
             }
         }
@@ -229,6 +262,13 @@ impl FuseTable {
                         {
                             continue;
                         }
+                        if !self.is_own_location(loc)
+                            || locations_referenced_by_dependents
+                                .block_location
+                                .contains(loc)
+                        {
+                            continue;
+                        }
                         block_locations_to_be_pruged.insert(loc.to_string());
                     }
                     status_block_to_be_purged_count += block_locations_to_be_pruged.len();
@@ -245,6 +285,13 @@ impl FuseTable {
                         {
                             continue;
                         }
+                        if !self.is_own_location(loc)
+                            || locations_referenced_by_dependents
+                                .bloom_location
+                                .contains(loc)
+                        {
+                            continue;
+                        }
                         bloom_locations_to_be_pruged.insert(loc.to_string());
                     }
                     status_bloom_to_be_purged_count += bloom_locations_to_be_pruged.len();
@@ -255,7 +302,61 @@ impl FuseTable {
                     .await?;
                 }
 
-                // 3. Try to purge segment file chunks.
+                // 3. Try to purge inverted index file chunks.
+                {
+                    let mut inverted_index_locations_to_be_purged = HashSet::new();
+                    for loc in &locations.inverted_index_location {
+                        if keep_last_snapshot
+                            && locations_referenced_by_root
+                                .inverted_index_location
+                                .contains(loc)
+                        {
+                            continue;
+                        }
+                        if !self.is_own_location(loc)
+                            || locations_referenced_by_dependents
+                                .inverted_index_location
+                                .contains(loc)
+                        {
+                            continue;
+                        }
+                        inverted_index_locations_to_be_purged.insert(loc.to_string());
+                    }
+                    self.try_purge_location_files(
+                        ctx.clone(),
+                        inverted_index_locations_to_be_purged,
+                    )
+                    .await?;
+                }
+
+                // 4. Try to purge deletion vector file chunks.
+                {
+                    let mut deletion_vector_locations_to_be_purged = HashSet::new();
+                    for loc in &locations.deletion_vector_location {
+                        if keep_last_snapshot
+                            && locations_referenced_by_root
+                                .deletion_vector_location
+                                .contains(loc)
+                        {
+                            continue;
+                        }
+                        if !self.is_own_location(loc)
+                            || locations_referenced_by_dependents
+                                .deletion_vector_location
+                                .contains(loc)
+                        {
+                            continue;
+                        }
+                        deletion_vector_locations_to_be_purged.insert(loc.to_string());
+                    }
+                    self.try_purge_location_files(
+                        ctx.clone(),
+                        deletion_vector_locations_to_be_purged,
+                    )
+                    .await?;
+                }
+
+                // 5. Try to purge segment file chunks.
                 {
                     let segment_locations_to_be_purged = HashSet::from_iter(
                         chunk
@@ -297,6 +398,7 @@ impl FuseTable {
                 snapshots_to_be_purged.into_iter().chain(
                     orphan_snapshots
                         .into_iter()
+                        .filter(|lite| !snapshots_pinned.contains(&lite.snapshot_id))
                         .map(|lite| (lite.snapshot_id, lite.format_version)),
                 ),
             );
@@ -396,6 +498,173 @@ impl FuseTable {
         segment_with_refer_index.into_keys().collect()
     }
 
+    fn is_own_location(&self, location: &str) -> bool {
+        location.starts_with(&format!("{}/", self.meta_location_generator.prefix()))
+    }
+
+    // Collect the snapshots, segments and files still read by the tables depending on this table:
+    // any snapshot of the tables cloned from this table, and the snapshots of this table since
+    // the offset of the streams on it. Along with the snapshots of the tags and branches.
+    //
+    // The dependents are registered on this table when they are created, dropped ones are
+    // included as long as their meta is kept, since they can be undropped.
+    pub(crate) async fn get_locations_referenced_by_dependents(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<(HashSet<SnapshotId>, HashSet<Location>, LocationTuple)> {
+        let table_id = self.get_id().to_string();
+        let catalog = ctx.get_catalog(&self.table_info.meta.catalog)?;
+
+        // the dependents registered after this instance of the table was loaded count as well.
+        let (_, table_meta) = catalog.get_table_meta_by_id(self.get_id()).await?;
+        let dependent_ids = table_meta
+            .options
+            .get(OPT_KEY_DEPENDENT_TABLE_IDS)
+            .map(|ids| ids.split(',').collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let mut segments = HashSet::new();
+        let mut stream_offsets = vec![];
+        for dependent_id in dependent_ids {
+            let Ok(dependent_id) = dependent_id.parse::<u64>() else {
+                warn!("invalid dependent table id {} of table {}", dependent_id, table_id);
+                continue;
+            };
+            let (ident, meta) = match catalog.get_table_meta_by_id(dependent_id).await {
+                // the dependent is dropped, and its meta removed.
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => continue,
+                Err(e) => return Err(e),
+                Ok(v) => v,
+            };
+            if meta.engine == STREAM_ENGINE {
+                if meta.options.get(OPT_KEY_STREAM_SOURCE_TABLE_ID) == Some(&table_id) {
+                    stream_offsets.push(meta.options.get(OPT_KEY_STREAM_OFFSET).cloned());
+                }
+                continue;
+            }
+
+            let table_info = TableInfo {
+                ident,
+                desc: "".to_owned(),
+                name: "".to_owned(),
+                meta: meta.as_ref().clone(),
+                tenant: ctx.get_tenant(),
+                db_type: DatabaseType::NormalDB,
+            };
+            let table = catalog.get_table_by_info(&table_info)?;
+            let clone_table = FuseTable::try_from_table(table.as_ref())?;
+            let root_snapshot_location = match clone_table.snapshot_loc().await? {
+                Some(location) => location,
+                None => {
+                    // the first snapshot of the clone is not committed yet, it is going to
+                    // refer to the segments of the snapshot it is cloned from.
+                    if let Some(location) = meta.options.get(OPT_KEY_CLONE_SOURCE_SNAPSHOT_LOCATION)
+                    {
+                        let reader = MetaReaders::table_snapshot_reader(self.operator.clone());
+                        let params = LoadParams {
+                            ver: TableMetaLocationGenerator::snapshot_version(location),
+                            location: location.clone(),
+                            len_hint: None,
+                        };
+                        match reader.read(&params).await {
+                            Ok(snapshot) => segments.extend(snapshot.segments.iter().cloned()),
+                            Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {}
+                            Err(e) => return Err(e),
+                        }
+                    }
+                    continue;
+                }
+            };
+            if let Some(snapshot) = clone_table.read_table_snapshot().await? {
+                segments.extend(snapshot.segments.iter().cloned());
+            }
+            let snapshots_io = SnapshotsIO::create(
+                ctx.clone(),
+                clone_table.operator.clone(),
+                clone_table.snapshot_format_version().await?,
+            );
+            let snapshot_lites_extended = snapshots_io
+                .read_snapshot_lites_ext(
+                    root_snapshot_location,
+                    None,
+                    ListSnapshotLiteOption::NeedSegmentsWithExclusion(None),
+                    None,
+                    |_| {},
+                )
+                .await?;
+            segments.extend(snapshot_lites_extended.segment_locations.into_keys());
+        }
+        let mut snapshots = self
+            .get_snapshots_referenced_by_streams(stream_offsets, &mut segments)
+            .await?;
+
+        let reader = MetaReaders::table_snapshot_reader(self.operator.clone());
+        for snapshot_ref in SnapshotRef::from_table_options(self.table_info.options())? {
+            let location = snapshot_ref.snapshot_location;
+            let params = LoadParams {
+                ver: TableMetaLocationGenerator::snapshot_version(&location),
+                location,
+                len_hint: None,
+            };
+            let snapshot = reader.read(&params).await?;
+            snapshots.insert(snapshot.snapshot_id);
+            segments.extend(snapshot.segments.iter().cloned());
+        }
+
+        if segments.is_empty() {
+            return Ok((snapshots, segments, LocationTuple::default()));
+        }
+        let segment_locations = Vec::from_iter(segments.iter().cloned());
+        let locations = self
+            .get_block_locations(ctx.clone(), &segment_locations)
+            .await?;
+        Ok((snapshots, segments, locations))
+    }
+
+    // Walk the history back from the root snapshot to the oldest of the stream offsets, a stream
+    // without offset reads the whole history.
+    async fn get_snapshots_referenced_by_streams(
+        &self,
+        stream_offsets: Vec<Option<String>>,
+        segments: &mut HashSet<Location>,
+    ) -> Result<HashSet<SnapshotId>> {
+        let mut snapshots = HashSet::new();
+        if stream_offsets.is_empty() {
+            return Ok(snapshots);
+        }
+        let root_snapshot_location = match self.snapshot_loc().await? {
+            Some(location) => location,
+            None => return Ok(snapshots),
+        };
+
+        let read_all = stream_offsets.iter().any(|offset| offset.is_none());
+        let mut offsets_pending = stream_offsets.into_iter().flatten().collect::<HashSet<_>>();
+        let location_gen = self.meta_location_generator.clone();
+        let reader = MetaReaders::table_snapshot_reader(self.operator.clone());
+        let mut history = reader.snapshot_history(
+            root_snapshot_location.clone(),
+            self.snapshot_format_version().await?,
+            location_gen.clone(),
+        );
+
+        let mut location = Some(root_snapshot_location);
+        while let Some(snapshot) = history.try_next().await? {
+            if !read_all && offsets_pending.is_empty() {
+                break;
+            }
+            if let Some(location) = &location {
+                offsets_pending.remove(location);
+            }
+            snapshots.insert(snapshot.snapshot_id);
+            segments.extend(snapshot.segments.iter().cloned());
+            location = match snapshot.prev_snapshot_id {
+                Some((id, ver)) => Some(location_gen.snapshot_location_from_uuid(&id, ver)?),
+                None => None,
+            };
+        }
+        Ok(snapshots)
+    }
+
     // Purge file by location chunks.
     async fn try_purge_location_files(
         &self,
@@ -425,15 +694,18 @@ impl FuseTable {
             .await
     }
 
-    async fn get_block_locations(
+    pub(crate) async fn get_block_locations(
         &self,
         ctx: Arc<dyn TableContext>,
         segment_locations: &[Location],
     ) -> Result<LocationTuple> {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
+        let mut inverted_indexes = HashSet::new();
+        let mut deletion_vectors = HashSet::new();
 
-        let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let fuse_segments =
+            SegmentsIO::create(ctx.clone(), self.operator.clone(), self.storage_schema());
         let segments = fuse_segments.read_segments(segment_locations).await?;
         for (idx, segment) in segments.iter().enumerate() {
             let segment = segment.clone();
@@ -459,12 +731,29 @@ impl FuseTable {
                         .unwrap_or_default()
                         .0,
                 );
+                if let Some(location) = &block_meta.inverted_index_location {
+                    inverted_indexes.insert(location.0.clone());
+                }
+                if let Some(location) = &block_meta.deletion_vector_location {
+                    deletion_vectors.insert(location.0.clone());
+                }
+                // the virtual columns are stored by the block, their bloom filters are purged
+                // along with the block ones
+                if let Some(location) = block_meta
+                    .virtual_block_meta
+                    .as_ref()
+                    .and_then(|m| m.bloom_filter_index_location.as_ref())
+                {
+                    blooms.insert(location.0.clone());
+                }
             }
         }
 
         Ok(LocationTuple {
             block_location: blocks,
             bloom_location: blooms,
+            inverted_index_location: inverted_indexes,
+            deletion_vector_location: deletion_vectors,
         })
     }
 }
@@ -0,0 +1,107 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table::TermStatistics;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::table::InvertedIndexDefinition;
+
+use crate::io::try_join_futures_with_vec;
+use crate::io::MetaReaders;
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+
+impl FuseTable {
+    pub async fn do_inverted_index_statistics(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        column: &str,
+        terms: &[Vec<u8>],
+    ) -> Result<Option<TermStatistics>> {
+        let indexes = self.inverted_indexes();
+        if InvertedIndexDefinition::column_tokenizer(&indexes, column).is_none() {
+            return Ok(None);
+        }
+
+        let mut stats = TermStatistics {
+            doc_freqs: vec![0; terms.len()],
+            ..Default::default()
+        };
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(Some(stats));
+        };
+
+        // Blocks written before the index was created have no index file, and are left out
+        // until they are rewritten by compaction or recluster.
+        let segments_io =
+            SegmentsIO::create(ctx.clone(), self.operator.clone(), self.storage_schema());
+        let mut locations = vec![];
+        for segment in segments_io.read_segments(&snapshot.segments).await? {
+            let segment = segment?;
+            locations.extend(
+                segment
+                    .blocks
+                    .iter()
+                    .filter_map(|block| block.inverted_index_location.clone()),
+            );
+        }
+
+        let column = Arc::new(column.to_string());
+        let terms = Arc::new(terms.to_vec());
+        let handles = locations
+            .into_iter()
+            .map(|location| {
+                let reader = MetaReaders::inverted_index_reader(self.operator.clone());
+                let column = column.clone();
+                let terms = terms.clone();
+                async move {
+                    let index = reader
+                        .read(&LoadParams {
+                            location: location.0,
+                            len_hint: None,
+                            ver: location.1,
+                        })
+                        .await?;
+                    let Some(index_column) = index.columns.get(column.as_str()) else {
+                        return Ok::<_, ErrorCode>(None);
+                    };
+                    let doc_freqs = terms
+                        .iter()
+                        .map(|term| index.doc_freq(&column, term))
+                        .collect::<Vec<_>>();
+                    Ok(Some((index.num_rows, index_column.num_tokens, doc_freqs)))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let block_stats =
+            try_join_futures_with_vec(ctx, handles, "inverted-index-statistics-worker".to_owned())
+                .await?;
+        for block_stat in block_stats {
+            if let Some((num_rows, num_tokens, doc_freqs)) = block_stat? {
+                stats.num_docs += num_rows;
+                stats.num_tokens += num_tokens;
+                for (total, doc_freq) in stats.doc_freqs.iter_mut().zip(doc_freqs) {
+                    *total += doc_freq;
+                }
+            }
+        }
+        Ok(Some(stats))
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use chrono::Duration;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use futures::TryStreamExt;
+
+use crate::io::MetaReaders;
+use crate::io::SnapshotHistoryReader;
+use crate::FuseTable;
+use crate::DEFAULT_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+
+/// Under-sized segments are only compacted when there are at least so many of them.
+const MIN_SEGMENTS_TO_COMPACT: usize = 4;
+/// Under-sized blocks are only compacted when there are at least so many of them.
+const MIN_BLOCKS_TO_COMPACT: u64 = 4;
+
+/// The maintenance a fuse table needs, in the order they should be run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaintenanceAction {
+    CompactSegments,
+    CompactBlocks,
+    Recluster,
+    Purge,
+}
+
+impl Display for MaintenanceAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            MaintenanceAction::CompactSegments => write!(f, "compact_segments"),
+            MaintenanceAction::CompactBlocks => write!(f, "compact_blocks"),
+            MaintenanceAction::Recluster => write!(f, "recluster"),
+            MaintenanceAction::Purge => write!(f, "purge"),
+        }
+    }
+}
+
+impl FuseTable {
+    /// Checks the current snapshot of the table for the maintenance it needs:
+    /// - the segments are compacted if they are more than twice as many as needed to hold the blocks,
+    /// - the blocks are compacted if there are too many of them not being perfect,
+    /// - a table with cluster key is reclustered, which does nothing if it is well clustered,
+    /// - the history is purged if more than `max_snapshots` snapshots are beyond the retention
+    ///   period, the snapshots within it are never purged.
+    pub async fn pending_maintenance(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        max_snapshots: usize,
+    ) -> Result<Vec<MaintenanceAction>> {
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(vec![]),
+        };
+
+        let mut actions = vec![];
+        let summary = &snapshot.summary;
+        let block_per_segment =
+            self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+        let needed_segments = std::cmp::max(
+            (summary.block_count as usize + block_per_segment - 1) / block_per_segment,
+            1,
+        );
+        let segments = snapshot.segments.len();
+        if segments >= MIN_SEGMENTS_TO_COMPACT && segments > needed_segments * 2 {
+            actions.push(MaintenanceAction::CompactSegments);
+        }
+
+        if summary
+            .block_count
+            .saturating_sub(summary.perfect_block_count)
+            >= MIN_BLOCKS_TO_COMPACT
+        {
+            actions.push(MaintenanceAction::CompactBlocks);
+        }
+
+        if self.cluster_key_meta.is_some() && summary.block_count > 1 {
+            actions.push(MaintenanceAction::Recluster);
+        }
+
+        // The same retention point as purging, snapshots are walked from the newest so the
+        // history is only read until there are too many snapshots beyond it.
+        let retention_interval = Duration::hours(ctx.get_settings().get_retention_period()? as i64);
+        let retention_point = snapshot.timestamp.map(|s| s - retention_interval);
+        let location_gen = self.meta_location_generator().clone();
+        let location = location_gen
+            .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot.format_version())?;
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut history =
+            reader.snapshot_history(location, snapshot.format_version(), location_gen);
+        let mut snapshots = 0;
+        while let Some(snapshot) = history.try_next().await? {
+            if snapshot.timestamp >= retention_point {
+                continue;
+            }
+            snapshots += 1;
+            if snapshots > max_snapshots {
+                actions.push(MaintenanceAction::Purge);
+                break;
+            }
+        }
+
+        Ok(actions)
+    }
+}
@@ -14,11 +14,16 @@
 
 mod analyze;
 mod append;
+mod changes;
+mod clone;
 mod commit;
 mod compact;
 mod delete;
+mod fast_forward;
 mod fuse_sink;
 mod gc;
+mod inverted_index;
+mod maintenance;
 mod mutation;
 mod navigate;
 mod operation_log;
@@ -27,15 +32,21 @@ mod read_partitions;
 mod recluster;
 mod truncate;
 mod update;
+mod vacuum;
 
 mod fuse_source;
 mod read;
 mod revert;
 pub mod util;
 
+pub use changes::ChangedBlocks;
+pub use changes::ChangesReader;
 pub use compact::CompactOptions;
 pub use fuse_sink::BloomIndexState;
 pub use fuse_sink::FuseTableSink;
+pub use fuse_sink::InvertedIndexState;
+pub use fuse_sink::VirtualColumnState;
+pub use maintenance::MaintenanceAction;
 pub use mutation::ReclusterMutator;
 pub use mutation::SegmentCompactMutator;
 pub use mutation::SegmentCompactionState;
@@ -92,7 +92,7 @@ impl BaseMutator {
         let mut segments_editor =
             HashMap::<_, _, RandomState>::from_iter(segments.clone().into_iter().enumerate());
 
-        let schema = Arc::new(self.base_snapshot.schema.clone());
+        let schema = Arc::new(self.base_snapshot.schema.remove_virtual_computed_fields());
         let segment_reader = MetaReaders::segment_info_reader(self.data_accessor.clone(), schema);
 
         let seg_writer = SegmentWriter::new(&self.data_accessor, &self.location_generator);
@@ -68,7 +68,12 @@ impl BlockCompactMutator {
         let snapshot = self.compact_params.base_snapshot.clone();
         let segment_locations = &snapshot.segments;
 
-        let schema = Arc::new(self.compact_params.base_snapshot.schema.clone());
+        let schema = Arc::new(
+            self.compact_params
+                .base_snapshot
+                .schema
+                .remove_virtual_computed_fields(),
+        );
         // Read all segments information in parallel.
         let segments_io = SegmentsIO::create(self.ctx.clone(), self.operator.clone(), schema);
         let segments = segments_io
@@ -167,6 +172,10 @@ impl CompactPartBuilder {
         segments.len() != 1
             || (segments[0].summary.block_count > 1
                 && segments[0].summary.perfect_block_count != segments[0].summary.block_count)
+            || segments[0]
+                .blocks
+                .iter()
+                .any(|b| b.deletion_vector_location.is_some())
     }
 
     fn add(&mut self, segment: Arc<SegmentInfo>) -> Vec<Vec<Arc<SegmentInfo>>> {
@@ -26,7 +26,7 @@ use storages_common_table_meta::meta::SegmentInfo;
 pub enum CompactTask {
     // Only one block, no need to do a compact.
     Trival(Arc<BlockMeta>),
-    // Multiple blocks, need to do compact.
+    // Multiple blocks, or a block with deleted rows, need to do compact.
     Normal(Vec<Arc<BlockMeta>>),
 }
 
@@ -160,7 +160,7 @@ impl CompactTaskBuilder {
     }
 
     fn add(&mut self, block: &Arc<BlockMeta>, thresholds: BlockThresholds) -> Vec<CompactTask> {
-        self.total_rows += block.row_count as usize;
+        self.total_rows += block.live_row_count() as usize;
         self.total_size += block.block_size as usize;
 
         if !thresholds.check_large_enough(self.total_rows, self.total_size) {
@@ -198,7 +198,10 @@ impl CompactTaskBuilder {
     fn create_task(blocks: Vec<Arc<BlockMeta>>) -> CompactTask {
         match blocks.len() {
             0 => panic!("the blocks is empty"),
-            1 => CompactTask::Trival(blocks[0].clone()),
+            // The deleted rows of a block are only removed by rewriting it.
+            1 if blocks[0].deletion_vector_location.is_none() => {
+                CompactTask::Trival(blocks[0].clone())
+            }
             _ => CompactTask::Normal(blocks),
         }
     }
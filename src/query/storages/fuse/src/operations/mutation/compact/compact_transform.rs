@@ -48,6 +48,8 @@ use crate::io::WriteSettings;
 use crate::metrics::*;
 use crate::operations::mutation::AbortOperation;
 use crate::operations::mutation::SerializeState;
+use crate::operations::InvertedIndexState;
+use crate::operations::VirtualColumnState;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
@@ -238,6 +240,7 @@ impl Processor for CompactTransform {
                         self.schema.clone(),
                         block_location.1,
                         &[&new_block],
+                        &self.write_settings.text_index,
                     )?;
 
                     let (index_data, index_size, index_location) = match maybe_bloom_index {
@@ -258,17 +261,45 @@ impl Processor for CompactTransform {
                         None => (None, 0u64, None),
                     };
 
-                    // serialize data block.
-                    let mut block_data = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-                    let (file_size, col_metas) = io::write_block(
-                        &self.write_settings,
+                    // build inverted index.
+                    let location = self.location_gen.block_inverted_index_location(&block_id);
+                    let (inverted_index_data, inverted_index_size, inverted_index_location) =
+                        match InvertedIndexState::try_create(
+                            &self.schema,
+                            &new_block,
+                            location,
+                            &self.write_settings.inverted_indexes,
+                        )? {
+                            Some(state) => (Some(state.data), state.size, Some(state.location)),
+                            None => (None, 0u64, None),
+                        };
+
+                    // extract virtual columns.
+                    let virtual_column_state = VirtualColumnState::try_create(
+                        self.ctx.clone(),
                         &self.schema,
-                        new_block,
-                        &mut block_data,
+                        &new_block,
+                        self.location_gen
+                            .block_virtual_column_bloom_index_location(&block_id),
+                        &self.write_settings.virtual_columns,
+                        &self.write_settings.auto_virtual_columns,
                     )?;
 
+                    // serialize data block, followed by the virtual columns.
+                    let mut block_data = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
+                    let (schema, new_block) = match &virtual_column_state {
+                        Some(state) => state.block_with_virtual_columns(&self.schema, new_block),
+                        None => (self.schema.clone(), new_block),
+                    };
+                    let (file_size, mut col_metas) =
+                        io::write_block(&self.write_settings, &schema, new_block, &mut block_data)?;
+                    let virtual_block_meta = virtual_column_state
+                        .as_ref()
+                        .map(|state| state.take_meta(&mut col_metas))
+                        .transpose()?;
+
                     // new block meta.
-                    let new_meta = BlockMeta::new(
+                    let mut new_meta = BlockMeta::new(
                         row_count,
                         block_size,
                         file_size,
@@ -279,7 +310,10 @@ impl Processor for CompactTransform {
                         index_location.clone(),
                         index_size,
                         self.write_settings.table_compression.into(),
+                        inverted_index_location.clone(),
+                        inverted_index_size,
                     );
+                    new_meta.virtual_block_meta = virtual_block_meta;
                     self.abort_operation.add_block(&new_meta);
                     self.block_metas.push(Arc::new(new_meta));
 
@@ -288,6 +322,9 @@ impl Processor for CompactTransform {
                         block_location: block_location.0,
                         index_data,
                         index_location: index_location.map(|l| l.0),
+                        inverted_index_data,
+                        inverted_index_location: inverted_index_location.map(|l| l.0),
+                        virtual_column_state,
                     });
                 }
                 self.state = State::SerializedBlocks(serialize_states);
@@ -338,7 +375,7 @@ impl Processor for CompactTransform {
                 while let Some(task) = self.compact_tasks.pop_front() {
                     let metas = task.get_block_metas();
                     // Only one block, no need to do a compact.
-                    if metas.len() == 1 {
+                    if matches!(task, CompactTask::Trival(_)) {
                         stats_of_columns.push(vec![]);
                         trivals.push_back(metas[0].clone());
                         continue;
@@ -360,7 +397,7 @@ impl Processor for CompactTransform {
                     let mut meta_stats = Vec::with_capacity(metas.len());
                     for meta in metas {
                         let progress_values = ProgressValues {
-                            rows: meta.row_count as usize,
+                            rows: meta.live_row_count() as usize,
                             bytes: meta.block_size as usize,
                         };
                         self.scan_progress.incr(&progress_values);
@@ -416,6 +453,14 @@ impl Processor for CompactTransform {
                         {
                             write_data(&index_data, dal, &index_location).await?;
                         }
+                        if let (Some(index_data), Some(index_location)) =
+                            (state.inverted_index_data, state.inverted_index_location)
+                        {
+                            write_data(&index_data, dal, &index_location).await?;
+                        }
+                        if let Some(virtual_column_state) = &state.virtual_column_state {
+                            virtual_column_state.write(dal).await?;
+                        }
                         // write block data.
                         write_data(&state.block_data, dal, &state.block_location).await
                     });
@@ -21,6 +21,7 @@ use metrics::gauge;
 use opendal::Operator;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::SnapshotMutation;
 use storages_common_table_meta::meta::Statistics;
 
 use crate::io::SegmentWriter;
@@ -85,7 +86,12 @@ impl TableMutator for SegmentCompactMutator {
             return Ok(false);
         }
 
-        let schema = Arc::new(self.compact_params.base_snapshot.schema.clone());
+        let schema = Arc::new(
+            self.compact_params
+                .base_snapshot
+                .schema
+                .remove_virtual_computed_fields(),
+        );
         // 1. read all the segments
         let fuse_segment_io =
             SegmentsIO::create(self.ctx.clone(), self.data_accessor.clone(), schema);
@@ -136,6 +142,7 @@ impl TableMutator for SegmentCompactMutator {
                 self.compaction.segments_locations,
                 self.compaction.statistics,
                 abort_action,
+                SnapshotMutation::Compact,
             )
             .await
     }
@@ -32,6 +32,7 @@ pub use compact::MergeSegmentsTransform;
 pub use compact::SegmentCompactMutator;
 pub use compact::SegmentCompactionState;
 pub use compact::SegmentCompactor;
+pub use mutation_meta::DeletedRows;
 pub use mutation_meta::Mutation;
 pub use mutation_meta::MutationSinkMeta;
 pub use mutation_meta::MutationTransformMeta;
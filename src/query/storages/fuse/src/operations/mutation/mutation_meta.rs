@@ -81,6 +81,17 @@ pub enum Mutation {
     DoNothing,
     Replaced(Arc<BlockMeta>),
     Deleted,
+    /// Some rows of the block are deleted, the block file is kept.
+    MarkDeleted(DeletedRows),
+}
+
+/// The deletion vector written for a block by a delete.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeletedRows {
+    pub location: Location,
+    pub size: u64,
+    /// The number of deleted rows of the block, including the rows deleted before.
+    pub row_count: u64,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
@@ -23,6 +23,7 @@ use common_exception::Result;
 use common_expression::BlockMetaInfoPtr;
 use opendal::Operator;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SnapshotMutation;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::TableSnapshot;
 
@@ -66,6 +67,7 @@ pub struct MutationSink {
 
     table: Arc<dyn Table>,
     base_snapshot: Arc<TableSnapshot>,
+    mutation: SnapshotMutation,
     // locations all the merged segments.
     merged_segments: Vec<Location>,
     // summarised statistics of all the merged segments.
@@ -82,6 +84,7 @@ impl MutationSink {
         table: &FuseTable,
         ctx: Arc<dyn TableContext>,
         base_snapshot: Arc<TableSnapshot>,
+        mutation: SnapshotMutation,
         input: Arc<InputPort>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(MutationSink {
@@ -91,6 +94,7 @@ impl MutationSink {
             location_gen: table.meta_location_generator.clone(),
             table: Arc::new(table.clone()),
             base_snapshot,
+            mutation,
             merged_segments: vec![],
             merged_statistics: Statistics::default(),
             abort_operation: AbortOperation::default(),
@@ -160,6 +164,7 @@ impl Processor for MutationSink {
                 self.abort_operation = meta.abort_operation.clone();
 
                 let mut new_snapshot = TableSnapshot::from_previous(&self.base_snapshot);
+                new_snapshot.mutation = Some(self.mutation);
                 new_snapshot.segments = self.merged_segments.clone();
                 new_snapshot.summary = self.merged_statistics.clone();
                 self.state = State::TryCommit(new_snapshot);
@@ -237,14 +242,18 @@ impl Processor for MutationSink {
             }
             State::MergeSegments(appended_segments) => {
                 let mut new_snapshot = TableSnapshot::from_previous(&self.base_snapshot);
+                new_snapshot.mutation = Some(self.mutation);
                 if !appended_segments.is_empty() {
                     self.merged_segments = appended_segments
                         .iter()
                         .chain(self.merged_segments.iter())
                         .cloned()
                         .collect();
-                    let segments_io =
-                        SegmentsIO::create(self.ctx.clone(), self.dal.clone(), self.table.schema());
+                    let segments_io = SegmentsIO::create(
+                        self.ctx.clone(),
+                        self.dal.clone(),
+                        Arc::new(self.table.schema().remove_virtual_computed_fields()),
+                    );
                     let append_segment_infos =
                         segments_io.read_segments(&appended_segments).await?;
                     for result in append_segment_infos.into_iter() {
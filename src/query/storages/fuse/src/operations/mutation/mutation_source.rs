@@ -16,6 +16,7 @@ use std::any::Any;
 use std::ops::Not;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
 use common_catalog::plan::PartInfoPtr;
@@ -38,8 +39,13 @@ use storages_common_table_meta::meta::ClusterStatistics;
 
 use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::mutation::DeletedRows;
+use crate::operations::mutation::Mutation;
 use crate::operations::mutation::MutationPartInfo;
+use crate::operations::mutation::MutationTransformMeta;
 use crate::operations::mutation::SerializeDataMeta;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
@@ -49,6 +55,9 @@ use crate::MergeIOReadResult;
 
 pub enum MutationAction {
     Deletion,
+    /// Deletion that records the deleted rows in deletion vectors instead of rewriting the
+    /// blocks, the output goes to `MutationTransform` directly.
+    MarkDeletion,
     Update,
 }
 
@@ -67,6 +76,7 @@ enum State {
         filter: Value<AnyType>,
     },
     PerformOperator(DataBlock),
+    WriteDeletionVector(DeletionVector),
     Output(Option<PartInfoPtr>, DataBlock),
     Finish,
 }
@@ -82,9 +92,11 @@ pub struct MutationSource {
     remain_reader: Arc<Option<BlockReader>>,
     operators: Vec<BlockOperator>,
     action: MutationAction,
+    location_gen: TableMetaLocationGenerator,
 
     index: BlockMetaIndex,
     origin_stats: Option<ClusterStatistics>,
+    deletion_vector: Option<DeletionVector>,
 }
 
 impl MutationSource {
@@ -97,6 +109,7 @@ impl MutationSource {
         block_reader: Arc<BlockReader>,
         remain_reader: Arc<Option<BlockReader>>,
         operators: Vec<BlockOperator>,
+        location_gen: TableMetaLocationGenerator,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
         Ok(ProcessorPtr::create(Box::new(MutationSource {
@@ -109,10 +122,17 @@ impl MutationSource {
             remain_reader,
             operators,
             action,
+            location_gen,
             index: BlockMetaIndex::default(),
             origin_stats: None,
+            deletion_vector: None,
         })))
     }
+
+    fn mutation_output(&self, op: Mutation) -> State {
+        let meta = MutationTransformMeta::create(self.index.clone(), op);
+        State::Output(self.ctx.get_partition(), DataBlock::empty_with_meta(meta))
+    }
 }
 
 #[async_trait::async_trait]
@@ -160,7 +180,10 @@ impl Processor for MutationSource {
             }
         }
 
-        if matches!(self.state, State::ReadData(_) | State::ReadRemain { .. }) {
+        if matches!(
+            self.state,
+            State::ReadData(_) | State::ReadRemain { .. } | State::WriteDeletionVector(_)
+        ) {
             Ok(Event::Async)
         } else {
             Ok(Event::Sync)
@@ -178,6 +201,12 @@ impl Processor for MutationSource {
                 let mut data_block = self
                     .block_reader
                     .deserialize_parquet_chunks(part.clone(), chunks)?;
+                if !matches!(self.action, MutationAction::MarkDeletion) {
+                    // Skip the rows removed by former deletes.
+                    if let Some(deletion_vector) = &self.deletion_vector {
+                        data_block = deletion_vector.apply(data_block, 0)?;
+                    }
+                }
                 let num_rows = data_block.num_rows();
 
                 if let Some(filter) = self.filter.as_ref() {
@@ -193,6 +222,15 @@ impl Processor for MutationSource {
                                 "Result of filter expression cannot be converted to boolean.",
                             )
                         })?;
+                    let predicates = match (&self.action, &self.deletion_vector) {
+                        (MutationAction::MarkDeletion, Some(deletion_vector)) => {
+                            // Only the rows not deleted yet are affected.
+                            let bitmap: Bitmap =
+                                FilterHelpers::filter_to_bitmap(predicates, num_rows).into();
+                            Value::Column(&bitmap & &deletion_vector.filter(0, num_rows))
+                        }
+                        _ => predicates,
+                    };
 
                     let affect_rows = match &predicates {
                         Value::Scalar(v) => {
@@ -240,6 +278,18 @@ impl Processor for MutationSource {
                                     }
                                 }
                             }
+                            MutationAction::MarkDeletion => {
+                                let deleted: Bitmap =
+                                    FilterHelpers::filter_to_bitmap(predicates, num_rows).into();
+                                let mut deletion_vector =
+                                    self.deletion_vector.take().unwrap_or_default();
+                                deletion_vector.delete(&deleted);
+                                self.state = if deletion_vector.len() == num_rows as u64 {
+                                    self.mutation_output(Mutation::Deleted)
+                                } else {
+                                    State::WriteDeletionVector(deletion_vector)
+                                };
+                            }
                             MutationAction::Update => {
                                 let filter = Value::upcast(predicates);
                                 if self.remain_reader.is_none() {
@@ -257,6 +307,8 @@ impl Processor for MutationSource {
                                 }
                             }
                         }
+                    } else if matches!(self.action, MutationAction::MarkDeletion) {
+                        self.state = self.mutation_output(Mutation::DoNothing);
                     } else {
                         // Do nothing.
                         self.state = State::Output(self.ctx.get_partition(), DataBlock::empty());
@@ -283,10 +335,14 @@ impl Processor for MutationSource {
                         .map(|(column_idx, column_chunk)| (column_idx, column_chunk))
                         .collect::<Vec<_>>();
 
-                    let remain_block = remain_reader.deserialize_parquet_chunks(part, chunks)?;
+                    let mut remain_block =
+                        remain_reader.deserialize_parquet_chunks(part, chunks)?;
+                    if let Some(deletion_vector) = &self.deletion_vector {
+                        remain_block = deletion_vector.apply(remain_block, 0)?;
+                    }
 
                     match self.action {
-                        MutationAction::Deletion => {
+                        MutationAction::Deletion | MutationAction::MarkDeletion => {
                             let remain_block = remain_block.filter(&filter)?;
                             for col in remain_block.columns() {
                                 data_block.add_column(col.clone());
@@ -331,6 +387,7 @@ impl Processor for MutationSource {
                 self.origin_stats = part.cluster_stats.clone();
                 let inner_part = part.inner_part.clone();
                 let fuse_part = FusePartInfo::from_part(&inner_part)?;
+                self.deletion_vector = self.block_reader.read_deletion_vector(fuse_part).await?;
 
                 let read_res = self
                     .block_reader
@@ -368,6 +425,21 @@ impl Processor for MutationSource {
                     return Err(ErrorCode::Internal("It's a bug. No remain reader"));
                 }
             }
+            State::WriteDeletionVector(deletion_vector) => {
+                let location = self.location_gen.gen_deletion_vector_location();
+                let data = deletion_vector.to_bytes()?;
+                let size = data.len() as u64;
+                self.block_reader
+                    .operator
+                    .object(&location.0)
+                    .write(data)
+                    .await?;
+                self.state = self.mutation_output(Mutation::MarkDeleted(DeletedRows {
+                    location,
+                    size,
+                    row_count: deletion_vector.len(),
+                }));
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
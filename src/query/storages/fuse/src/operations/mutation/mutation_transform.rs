@@ -35,6 +35,7 @@ use crate::io::try_join_futures_with_vec;
 use crate::io::SegmentsIO;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::mutation::AbortOperation;
+use crate::operations::mutation::DeletedRows;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::MutationSinkMeta;
 use crate::operations::mutation::MutationTransformMeta;
@@ -46,7 +47,14 @@ use crate::pipelines::processors::Processor;
 use crate::statistics::reducers::merge_statistics_mut;
 use crate::statistics::reducers::reduce_block_metas;
 
-type MutationMap = HashMap<usize, (Vec<(usize, Arc<BlockMeta>)>, Vec<usize>)>;
+type MutationMap = HashMap<
+    usize,
+    (
+        Vec<(usize, Arc<BlockMeta>)>,
+        Vec<usize>,
+        Vec<(usize, DeletedRows)>,
+    ),
+>;
 
 struct SerializedData {
     data: Vec<u8>,
@@ -238,15 +246,27 @@ impl Processor for MutationTransform {
                     Mutation::Replaced(block_meta) => {
                         self.input_metas
                             .entry(meta.index.segment_idx)
-                            .and_modify(|v| v.0.push((meta.index.block_idx, block_meta.clone())))
-                            .or_insert((vec![(meta.index.block_idx, block_meta.clone())], vec![]));
+                            .or_default()
+                            .0
+                            .push((meta.index.block_idx, block_meta.clone()));
                         self.abort_operation.add_block(block_meta);
                     }
                     Mutation::Deleted => {
                         self.input_metas
                             .entry(meta.index.segment_idx)
-                            .and_modify(|v| v.1.push(meta.index.block_idx))
-                            .or_insert((vec![], vec![meta.index.block_idx]));
+                            .or_default()
+                            .1
+                            .push(meta.index.block_idx);
+                    }
+                    Mutation::MarkDeleted(deleted_rows) => {
+                        self.input_metas
+                            .entry(meta.index.segment_idx)
+                            .or_default()
+                            .2
+                            .push((meta.index.block_idx, deleted_rows.clone()));
+                        // Only the deletion vector is new, the block file is still referenced.
+                        self.abort_operation
+                            .add_deletion_vector(deleted_rows.location.0.clone());
                     }
                     Mutation::DoNothing => (),
                 }
@@ -258,7 +278,7 @@ impl Processor for MutationTransform {
                 let mut segments_editor =
                     BTreeMap::<_, _>::from_iter(segments.into_iter().enumerate());
                 for (seg_idx, seg_info) in segment_infos.iter().enumerate() {
-                    if let Some((replaced, deleted)) = self.input_metas.get(&seg_idx) {
+                    if let Some((replaced, deleted, marked)) = self.input_metas.get(&seg_idx) {
                         // prepare the new segment
                         let mut new_segment =
                             SegmentInfo::new(seg_info.blocks.clone(), seg_info.summary.clone());
@@ -272,6 +292,16 @@ impl Processor for MutationTransform {
                         for (idx, new_meta) in replaced {
                             block_editor.insert(*idx, new_meta.clone());
                         }
+                        for (idx, deleted_rows) in marked {
+                            if let Some(block_meta) = block_editor.get_mut(idx) {
+                                let mut new_meta = block_meta.as_ref().clone();
+                                new_meta.deletion_vector_location =
+                                    Some(deleted_rows.location.clone());
+                                new_meta.deletion_vector_size = deleted_rows.size;
+                                new_meta.deleted_row_count = deleted_rows.row_count;
+                                *block_meta = Arc::new(new_meta);
+                            }
+                        }
                         for idx in deleted {
                             block_editor.remove(idx);
                         }
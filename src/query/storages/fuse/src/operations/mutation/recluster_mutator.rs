@@ -24,6 +24,7 @@ use common_expression::Scalar;
 use opendal::Operator;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::SnapshotMutation;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
 
@@ -109,7 +110,7 @@ impl TableMutator for ReclusterMutator {
                     .and_modify(|v| v.1.push(i))
                     .or_insert((vec![], vec![i]));
 
-                total_rows += meta.row_count;
+                total_rows += meta.live_row_count();
                 total_bytes += meta.block_size;
             }
 
@@ -241,6 +242,7 @@ impl TableMutator for ReclusterMutator {
                 segments,
                 summary,
                 abort_operation,
+                SnapshotMutation::Recluster,
             )
             .await
     }
@@ -26,10 +26,13 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
+use storages_common_index::TextIndexOptions;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
+use storages_common_table_meta::table::InvertedIndexDefinition;
 use storages_common_table_meta::table::TableCompression;
+use storages_common_table_meta::table::VirtualColumnDefinition;
 
 use crate::io::write_data;
 use crate::io::TableMetaLocationGenerator;
@@ -38,6 +41,8 @@ use crate::operations::mutation::MutationTransformMeta;
 use crate::operations::mutation::SerializeDataMeta;
 use crate::operations::util;
 use crate::operations::BloomIndexState;
+use crate::operations::InvertedIndexState;
+use crate::operations::VirtualColumnState;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::Processor;
@@ -50,6 +55,9 @@ pub struct SerializeState {
     pub block_location: String,
     pub index_data: Option<Vec<u8>>,
     pub index_location: Option<String>,
+    pub inverted_index_data: Option<Vec<u8>>,
+    pub inverted_index_location: Option<String>,
+    pub virtual_column_state: Option<VirtualColumnState>,
 }
 
 enum State {
@@ -74,6 +82,10 @@ pub struct SerializeDataTransform {
     index: BlockMetaIndex,
     origin_stats: Option<ClusterStatistics>,
     table_compression: TableCompression,
+    text_index: TextIndexOptions,
+    inverted_indexes: Vec<InvertedIndexDefinition>,
+    virtual_columns: Vec<VirtualColumnDefinition>,
+    auto_virtual_columns: Vec<String>,
 }
 
 impl SerializeDataTransform {
@@ -93,10 +105,14 @@ impl SerializeDataTransform {
             location_gen: table.meta_location_generator().clone(),
             dal: table.get_operator(),
             cluster_stats_gen,
-            schema: table.schema(),
+            schema: table.storage_schema(),
             index: BlockMetaIndex::default(),
             origin_stats: None,
             table_compression: table.table_compression,
+            text_index: table.text_index_options(),
+            inverted_indexes: table.inverted_indexes(),
+            virtual_columns: table.virtual_column_definitions(),
+            auto_virtual_columns: table.auto_virtual_columns(),
         })))
     }
 }
@@ -178,22 +194,46 @@ impl Processor for SerializeDataTransform {
                     self.schema.clone(),
                     &block,
                     location,
+                    &self.text_index,
+                )?;
+                let location = self.location_gen.block_inverted_index_location(&block_id);
+                let inverted_index_state = InvertedIndexState::try_create(
+                    &self.schema,
+                    &block,
+                    location,
+                    &self.inverted_indexes,
+                )?;
+                let virtual_column_state = VirtualColumnState::try_create(
+                    self.ctx.clone(),
+                    &self.schema,
+                    &block,
+                    self.location_gen
+                        .block_virtual_column_bloom_index_location(&block_id),
+                    &self.virtual_columns,
+                    &self.auto_virtual_columns,
                 )?;
                 let column_distinct_count = bloom_index_state
                     .as_ref()
                     .map(|i| i.column_distinct_count.clone());
                 let col_stats = gen_columns_statistics(&block, column_distinct_count)?;
 
-                // serialize data block.
+                // serialize data block, followed by the virtual columns.
                 let mut block_data = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-                let schema = self.schema.clone();
+                let (schema, block) = match &virtual_column_state {
+                    Some(state) => state.block_with_virtual_columns(&self.schema, block),
+                    None => (self.schema.clone(), block),
+                };
                 let (file_size, meta_data) = blocks_to_parquet(
                     &schema,
                     vec![block],
                     &mut block_data,
                     self.table_compression,
                 )?;
-                let col_metas = util::column_metas(&meta_data)?;
+                let mut col_metas = util::column_metas(&meta_data)?;
+                let virtual_block_meta = virtual_column_state
+                    .as_ref()
+                    .map(|state| state.take_meta(&mut col_metas))
+                    .transpose()?;
 
                 let (index_data, index_location, index_size) =
                     if let Some(bloom_index_state) = bloom_index_state {
@@ -205,9 +245,14 @@ impl Processor for SerializeDataTransform {
                     } else {
                         (None, None, 0u64)
                     };
+                let (inverted_index_data, inverted_index_location, inverted_index_size) =
+                    match inverted_index_state {
+                        Some(state) => (Some(state.data), Some(state.location), state.size),
+                        None => (None, None, 0u64),
+                    };
 
                 // new block meta.
-                let new_meta = Arc::new(BlockMeta::new(
+                let mut new_meta = BlockMeta::new(
                     row_count,
                     block_size,
                     file_size,
@@ -218,7 +263,10 @@ impl Processor for SerializeDataTransform {
                     index_location.clone(),
                     index_size,
                     self.table_compression.into(),
-                ));
+                    inverted_index_location.clone(),
+                    inverted_index_size,
+                );
+                new_meta.virtual_block_meta = virtual_block_meta;
 
                 self.state = State::Serialized(
                     SerializeState {
@@ -226,8 +274,11 @@ impl Processor for SerializeDataTransform {
                         block_location: block_location.0,
                         index_data,
                         index_location: index_location.map(|l| l.0),
+                        inverted_index_data,
+                        inverted_index_location: inverted_index_location.map(|l| l.0),
+                        virtual_column_state,
                     },
-                    new_meta,
+                    Arc::new(new_meta),
                 );
             }
             State::Output(op) => {
@@ -255,6 +306,15 @@ impl Processor for SerializeDataTransform {
                 {
                     write_data(&index_data, &self.dal, &index_location).await?;
                 }
+                if let (Some(index_data), Some(index_location)) = (
+                    serialize_state.inverted_index_data,
+                    serialize_state.inverted_index_location,
+                ) {
+                    write_data(&index_data, &self.dal, &index_location).await?;
+                }
+                if let Some(virtual_column_state) = &serialize_state.virtual_column_state {
+                    virtual_column_state.write(&self.dal).await?;
+                }
 
                 self.state = State::Output(Mutation::Replaced(block_meta));
             }
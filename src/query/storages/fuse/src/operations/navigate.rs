@@ -20,11 +20,15 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::TableStatistics;
 use futures::TryStreamExt;
+use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::SnapshotRef;
+use storages_common_table_meta::table::SnapshotRefKind;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::io::MetaReaders;
 use crate::io::SnapshotHistoryReader;
+use crate::io::TableMetaLocationGenerator;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -85,46 +89,69 @@ impl FuseTable {
         }
 
         if let Some(snapshot) = instant {
-            // Load the table instance by the snapshot
-
-            // The `seq` of ident that we cloned here is JUST a place holder
-            // we should NOT use it other than a pure place holder.
-            let mut table_info = self.table_info.clone();
-
-            // There are more to be kept in snapshot, like engine_options, ordering keys...
-            // or we could just keep a clone of TableMeta in the snapshot.
-            //
-            // currently, here are what we can recovery from the snapshot:
-
-            // 1. the table schema
-            table_info.meta.schema = Arc::new(snapshot.schema.clone());
-
-            // 2. the table option `snapshot_location`
-            let ver = snapshot.format_version();
-            let loc = self
-                .meta_location_generator
-                .snapshot_location_from_uuid(&snapshot.snapshot_id, ver)?;
-            table_info
-                .meta
-                .options
-                .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), loc);
-
-            // 3. The statistics
-            let summary = &snapshot.summary;
-            table_info.meta.statistics = TableStatistics {
-                number_of_rows: summary.row_count,
-                data_bytes: summary.uncompressed_byte_size,
-                compressed_data_bytes: summary.compressed_byte_size,
-                index_data_bytes: summary.index_size,
-            };
-
-            // let's instantiate it
-            let table = FuseTable::do_create(table_info)?;
-            Ok(table.into())
+            self.load_table_at(snapshot.as_ref())
         } else {
             Err(ErrorCode::TableHistoricalDataNotFound(
                 "No historical data found at given point",
             ))
         }
     }
+
+    /// Navigates to the snapshot pinned by a tag, or to the head snapshot of a branch.
+    pub async fn navigate_to_snapshot_ref(
+        &self,
+        name: &str,
+        kind: SnapshotRefKind,
+    ) -> Result<Arc<FuseTable>> {
+        let refs = SnapshotRef::from_table_options(self.table_info.options())?;
+        let location = SnapshotRef::find(&refs, name, kind)?
+            .snapshot_location
+            .clone();
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            ver: TableMetaLocationGenerator::snapshot_version(&location),
+            location,
+            len_hint: None,
+        };
+        let snapshot = reader.read(&params).await?;
+        self.load_table_at(snapshot.as_ref())
+    }
+
+    // Load the table instance by the snapshot
+    fn load_table_at(&self, snapshot: &TableSnapshot) -> Result<Arc<FuseTable>> {
+        // The `seq` of ident that we cloned here is JUST a place holder
+        // we should NOT use it other than a pure place holder.
+        let mut table_info = self.table_info.clone();
+
+        // There are more to be kept in snapshot, like engine_options, ordering keys...
+        // or we could just keep a clone of TableMeta in the snapshot.
+        //
+        // currently, here are what we can recovery from the snapshot:
+
+        // 1. the table schema
+        table_info.meta.schema = Arc::new(snapshot.schema.clone());
+
+        // 2. the table option `snapshot_location`
+        let ver = snapshot.format_version();
+        let loc = self
+            .meta_location_generator
+            .snapshot_location_from_uuid(&snapshot.snapshot_id, ver)?;
+        table_info
+            .meta
+            .options
+            .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), loc);
+
+        // 3. The statistics
+        let summary = &snapshot.summary;
+        table_info.meta.statistics = TableStatistics {
+            number_of_rows: summary.row_count,
+            data_bytes: summary.uncompressed_byte_size,
+            compressed_data_bytes: summary.compressed_byte_size,
+            index_data_bytes: summary.index_size,
+        };
+
+        // let's instantiate it
+        let table = FuseTable::do_create(table_info)?;
+        Ok(table.into())
+    }
 }
@@ -21,6 +21,7 @@ use common_pipeline_core::Pipeline;
 use tracing::info;
 
 use crate::io::BlockReader;
+use crate::io::VirtualColumnReader;
 use crate::operations::read::native_data_source_deserializer::NativeDeserializeDataTransform;
 use crate::operations::read::native_data_source_reader::ReadNativeDataSource;
 use crate::operations::read::parquet_data_source_deserializer::DeserializeDataTransform;
@@ -38,7 +39,12 @@ pub fn build_fuse_native_source_pipeline(
         true => {
             pipeline.add_source(
                 |output| {
-                    ReadNativeDataSource::<true>::create(ctx.clone(), output, block_reader.clone())
+                    ReadNativeDataSource::<true>::create(
+                        ctx.clone(),
+                        output,
+                        block_reader.clone(),
+                        plan,
+                    )
                 },
                 max_threads,
             )?;
@@ -47,7 +53,12 @@ pub fn build_fuse_native_source_pipeline(
             info!("read block data adjust max io requests:{}", max_io_requests);
             pipeline.add_source(
                 |output| {
-                    ReadNativeDataSource::<false>::create(ctx.clone(), output, block_reader.clone())
+                    ReadNativeDataSource::<false>::create(
+                        ctx.clone(),
+                        output,
+                        block_reader.clone(),
+                        plan,
+                    )
                 },
                 max_io_requests,
             )?;
@@ -77,14 +88,22 @@ pub fn build_fuse_parquet_source_pipeline(
     ctx: Arc<dyn TableContext>,
     pipeline: &mut Pipeline,
     block_reader: Arc<BlockReader>,
+    virtual_reader: Option<Arc<VirtualColumnReader>>,
     max_threads: usize,
+    plan: &DataSourcePlan,
     max_io_requests: usize,
 ) -> Result<()> {
     match block_reader.support_blocking_api() {
         true => {
             pipeline.add_source(
                 |output| {
-                    ReadParquetDataSource::<true>::create(ctx.clone(), output, block_reader.clone())
+                    ReadParquetDataSource::<true>::create(
+                        ctx.clone(),
+                        output,
+                        block_reader.clone(),
+                        virtual_reader.clone(),
+                        plan,
+                    )
                 },
                 max_threads,
             )?;
@@ -97,6 +116,8 @@ pub fn build_fuse_parquet_source_pipeline(
                         ctx.clone(),
                         output,
                         block_reader.clone(),
+                        virtual_reader.clone(),
+                        plan,
                     )
                 },
                 max_io_requests,
@@ -116,6 +137,8 @@ pub fn build_fuse_parquet_source_pipeline(
         DeserializeDataTransform::create(
             ctx.clone(),
             block_reader.clone(),
+            virtual_reader.clone(),
+            plan,
             transform_input,
             transform_output,
         )
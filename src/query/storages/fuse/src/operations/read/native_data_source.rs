@@ -23,6 +23,7 @@ use common_expression::BlockMetaInfoPtr;
 use serde::Deserializer;
 use serde::Serializer;
 
+use crate::io::DeletionVector;
 use crate::io::NativeReaderExt;
 
 pub type DataChunks = Vec<(usize, NativeReader<Box<dyn NativeReaderExt>>)>;
@@ -30,11 +31,20 @@ pub type DataChunks = Vec<(usize, NativeReader<Box<dyn NativeReaderExt>>)>;
 pub struct NativeDataSourceMeta {
     pub part: Vec<PartInfoPtr>,
     pub chunks: Vec<DataChunks>,
+    pub deletion_vectors: Vec<Option<DeletionVector>>,
 }
 
 impl NativeDataSourceMeta {
-    pub fn create(part: Vec<PartInfoPtr>, chunks: Vec<DataChunks>) -> BlockMetaInfoPtr {
-        Box::new(NativeDataSourceMeta { part, chunks })
+    pub fn create(
+        part: Vec<PartInfoPtr>,
+        chunks: Vec<DataChunks>,
+        deletion_vectors: Vec<Option<DeletionVector>>,
+    ) -> BlockMetaInfoPtr {
+        Box::new(NativeDataSourceMeta {
+            part,
+            chunks,
+            deletion_vectors,
+        })
     }
 }
 
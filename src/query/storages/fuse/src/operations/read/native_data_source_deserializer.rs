@@ -16,12 +16,14 @@ use std::any::Any;
 use std::collections::VecDeque;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::plan::TopK;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::filter_helper::FilterHelpers;
@@ -45,11 +47,14 @@ use storages_common_index::RangeIndex;
 
 use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::metrics::metrics_inc_pruning_prewhere_nums;
 use crate::operations::read::native_data_source::DataChunks;
 use crate::operations::read::native_data_source::NativeDataSourceMeta;
+use crate::operations::read::runtime_filter_prunner::runtime_filter_bitmap;
 
 pub struct NativeDeserializeDataTransform {
+    ctx: Arc<dyn TableContext>,
     func_ctx: FunctionContext,
     scan_progress: Arc<Progress>,
     block_reader: Arc<BlockReader>,
@@ -59,12 +64,16 @@ pub struct NativeDeserializeDataTransform {
     output_data: Option<DataBlock>,
     parts: VecDeque<PartInfoPtr>,
     chunks: VecDeque<DataChunks>,
+    deletion_vectors: VecDeque<Option<DeletionVector>>,
+    // The number of rows of the current part that have been read.
+    offset: usize,
 
     prewhere_columns: Vec<usize>,
     remain_columns: Vec<usize>,
 
     src_schema: DataSchema,
     output_schema: DataSchema,
+    prewhere_schema: DataSchema,
     prewhere_filter: Arc<Option<Expr>>,
 
     skipped_page: usize,
@@ -72,6 +81,7 @@ pub struct NativeDeserializeDataTransform {
     read_columns: Vec<usize>,
     top_k: Option<(TopK, TopKSorter, usize)>,
     topn_finish: bool,
+    scan_id: usize,
 }
 
 impl NativeDeserializeDataTransform {
@@ -141,6 +151,7 @@ impl NativeDeserializeDataTransform {
 
         Ok(ProcessorPtr::create(Box::new(
             NativeDeserializeDataTransform {
+                ctx,
                 func_ctx,
                 scan_progress,
                 block_reader,
@@ -149,16 +160,20 @@ impl NativeDeserializeDataTransform {
                 output_data: None,
                 parts: VecDeque::new(),
                 chunks: VecDeque::new(),
+                deletion_vectors: VecDeque::new(),
+                offset: 0,
 
                 prewhere_columns,
                 remain_columns,
                 src_schema,
                 output_schema,
+                prewhere_schema,
                 prewhere_filter,
                 skipped_page: 0,
                 top_k,
                 topn_finish: false,
                 read_columns: vec![],
+                scan_id: plan.scan_id,
             },
         )))
     }
@@ -182,7 +197,13 @@ impl NativeDeserializeDataTransform {
         )
     }
 
-    fn add_block(&mut self, data_block: DataBlock) -> Result<()> {
+    /// The runtime filters which can be fully evaluated on the prewhere columns have been
+    /// applied to the pages, the others are applied to the rows of the block here.
+    fn add_block(
+        &mut self,
+        data_block: DataBlock,
+        runtime_filters: Option<&RuntimeFilterInfo>,
+    ) -> Result<()> {
         let rows = data_block.num_rows();
         if rows == 0 {
             return Ok(());
@@ -192,6 +213,19 @@ impl NativeDeserializeDataTransform {
             bytes: data_block.memory_size(),
         };
         self.scan_progress.incr(&progress_values);
+        let data_block = match runtime_filters {
+            Some(filters) => match runtime_filter_bitmap(
+                filters,
+                self.func_ctx,
+                &data_block,
+                &self.output_schema,
+                Some(&self.prewhere_schema),
+            )? {
+                Some(bitmap) => DataBlock::filter_with_bitmap(data_block, &bitmap)?,
+                None => data_block,
+            },
+            None => data_block,
+        };
         self.output_data = Some(data_block);
         Ok(())
     }
@@ -211,6 +245,28 @@ impl NativeDeserializeDataTransform {
         }
     }
 
+    fn pop_part(&mut self) -> PartInfoPtr {
+        let _ = self.chunks.pop_front();
+        let _ = self.deletion_vectors.pop_front();
+        self.offset = 0;
+        self.parts.pop_front().unwrap()
+    }
+
+    /// The filter of the next `num_rows` rows of the current part that have not been deleted,
+    /// if the part has a deletion vector.
+    fn live_rows(
+        deletion_vectors: &VecDeque<Option<DeletionVector>>,
+        offset: &mut usize,
+        num_rows: usize,
+    ) -> Option<Bitmap> {
+        let start = *offset;
+        *offset += num_rows;
+        deletion_vectors
+            .front()
+            .and_then(|deletion_vector| deletion_vector.as_ref())
+            .map(|deletion_vector| deletion_vector.filter(start, num_rows))
+    }
+
     fn skip_chunks_page(read_columns: &[usize], chunks: &mut DataChunks) -> Result<()> {
         for (index, chunk) in chunks.iter_mut().enumerate() {
             if read_columns.contains(&index) {
@@ -276,6 +332,9 @@ impl Processor for NativeDeserializeDataTransform {
                         return Ok(Event::Finished);
                     }
                     self.chunks = VecDeque::from(std::mem::take(&mut source_meta.chunks));
+                    self.deletion_vectors =
+                        VecDeque::from(std::mem::take(&mut source_meta.deletion_vectors));
+                    self.offset = 0;
                     return Ok(Event::Sync);
                 }
             }
@@ -294,15 +353,20 @@ impl Processor for NativeDeserializeDataTransform {
     }
 
     fn process(&mut self) -> Result<()> {
+        let runtime_filters = self.ctx.get_runtime_filter_with_id(self.scan_id);
         if let Some(chunks) = self.chunks.front_mut() {
             // this means it's empty projection
             if chunks.is_empty() {
-                let _ = self.chunks.pop_front();
-                let part = self.parts.pop_front().unwrap();
+                let deletion_vector = self.deletion_vectors.front().cloned().flatten();
+                let part = self.pop_part();
 
                 let part = FusePartInfo::from_part(&part)?;
                 let data_block = DataBlock::new(vec![], part.nums_rows);
-                self.add_block(data_block)?;
+                let data_block = match deletion_vector {
+                    Some(deletion_vector) => deletion_vector.apply(data_block, 0)?,
+                    None => data_block,
+                };
+                self.add_block(data_block, None)?;
                 return Ok(());
             }
 
@@ -315,8 +379,7 @@ impl Processor for NativeDeserializeDataTransform {
                     let chunk = chunks.get_mut(*index).unwrap();
                     if !chunk.1.has_next() {
                         // No data anymore
-                        let _ = self.chunks.pop_front();
-                        let _ = self.parts.pop_front().unwrap();
+                        let _ = self.pop_part();
                         self.check_topn();
                         // check finished
                         return Ok(());
@@ -329,6 +392,7 @@ impl Processor for NativeDeserializeDataTransform {
 
                     arrays.push((chunk.0, array));
                     if sorter.never_match_any(&col) {
+                        self.offset += col.len();
                         self.skipped_page += 1;
                         return Self::skip_chunks_page(&self.read_columns, chunks);
                     }
@@ -343,8 +407,7 @@ impl Processor for NativeDeserializeDataTransform {
                 let chunk = chunks.get_mut(*index).unwrap();
                 if !chunk.1.has_next() {
                     // No data anymore
-                    let _ = self.chunks.pop_front();
-                    let _ = self.parts.pop_front().unwrap();
+                    let _ = self.pop_part();
 
                     self.check_topn();
                     return Ok(());
@@ -353,15 +416,69 @@ impl Processor for NativeDeserializeDataTransform {
                 arrays.push((chunk.0, chunk.1.next_array()?));
             }
 
-            let data_block = match self.prewhere_filter.as_ref() {
-                Some(filter) => {
+            let data_block = match (self.prewhere_filter.as_ref(), &runtime_filters) {
+                (None, None) => {
+                    for index in self.remain_columns.iter() {
+                        let chunk = chunks.get_mut(*index).unwrap();
+                        arrays.push((chunk.0, chunk.1.next_array()?));
+                    }
+                    let block = self.block_reader.build_block(arrays)?;
+                    match Self::live_rows(
+                        &self.deletion_vectors,
+                        &mut self.offset,
+                        block.num_rows(),
+                    ) {
+                        Some(live_rows) => DataBlock::filter_with_bitmap(block, &live_rows),
+                        None => Ok(block),
+                    }
+                }
+                (prewhere_filter, runtime_filters) => {
                     let prewhere_block = self.block_reader.build_block(arrays.clone())?;
-                    let evaluator =
-                        Evaluator::new(&prewhere_block, self.func_ctx, &BUILTIN_FUNCTIONS);
-                    let result = evaluator
-                        .run(filter)
-                        .map_err(|e| e.add_message("eval prewhere filter failed:"))?;
-                    let filter = FilterHelpers::cast_to_nonull_boolean(&result).unwrap();
+                    let filter = match prewhere_filter {
+                        Some(filter) => {
+                            let evaluator =
+                                Evaluator::new(&prewhere_block, self.func_ctx, &BUILTIN_FUNCTIONS);
+                            let result = evaluator
+                                .run(filter)
+                                .map_err(|e| e.add_message("eval prewhere filter failed:"))?;
+                            FilterHelpers::cast_to_nonull_boolean(&result).unwrap()
+                        }
+                        None => Value::Scalar(true),
+                    };
+                    // The runtime filters on the prewhere columns skip the remaining columns
+                    // of the page just like the prewhere filter.
+                    let runtime_rows = match runtime_filters {
+                        Some(filters) => runtime_filter_bitmap(
+                            filters,
+                            self.func_ctx,
+                            &prewhere_block,
+                            &self.prewhere_schema,
+                            None,
+                        )?,
+                        None => None,
+                    };
+                    let filter = match runtime_rows {
+                        Some(runtime_rows) => {
+                            let bitmap: Bitmap =
+                                FilterHelpers::filter_to_bitmap(filter, prewhere_block.num_rows())
+                                    .into();
+                            Value::Column(&bitmap & &runtime_rows)
+                        }
+                        None => filter,
+                    };
+                    let filter = match Self::live_rows(
+                        &self.deletion_vectors,
+                        &mut self.offset,
+                        prewhere_block.num_rows(),
+                    ) {
+                        Some(live_rows) => {
+                            let bitmap: Bitmap =
+                                FilterHelpers::filter_to_bitmap(filter, prewhere_block.num_rows())
+                                    .into();
+                            Value::Column(&bitmap & &live_rows)
+                        }
+                        None => filter,
+                    };
 
                     // Step 3: Apply the filter, if it's all filtered, we can skip the remain columns.
                     if FilterHelpers::is_all_unset(&filter) {
@@ -404,17 +521,10 @@ impl Processor for NativeDeserializeDataTransform {
                     let block = block.resort(&self.src_schema, &self.output_schema)?;
                     block.filter_boolean_value(filter)
                 }
-                None => {
-                    for index in self.remain_columns.iter() {
-                        let chunk = chunks.get_mut(*index).unwrap();
-                        arrays.push((chunk.0, chunk.1.next_array()?));
-                    }
-                    self.block_reader.build_block(arrays)
-                }
             }?;
 
             // Step 5: Add the block to output data
-            self.add_block(data_block)?;
+            self.add_block(data_block, runtime_filters.as_deref())?;
         }
 
         Ok(())
@@ -16,10 +16,13 @@ use std::any::Any;
 use std::sync::Arc;
 
 use common_base::base::tokio;
+use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
@@ -27,18 +30,27 @@ use common_pipeline_core::processors::Processor;
 use common_pipeline_sources::processors::sources::SyncSource;
 use common_pipeline_sources::processors::sources::SyncSourcer;
 
+use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::operations::read::native_data_source::DataChunks;
 use crate::operations::read::native_data_source::NativeDataSourceMeta;
+use crate::operations::read::runtime_filter_prunner::fetch_partitions;
 
 pub struct ReadNativeDataSource<const BLOCKING_IO: bool> {
     finished: bool,
     batch_size: usize,
     ctx: Arc<dyn TableContext>,
     block_reader: Arc<BlockReader>,
+    scan_id: usize,
+    table_schema: TableSchemaRef,
 
     output: Arc<OutputPort>,
-    output_data: Option<(Vec<PartInfoPtr>, Vec<DataChunks>)>,
+    output_data: Option<(
+        Vec<PartInfoPtr>,
+        Vec<DataChunks>,
+        Vec<Option<DeletionVector>>,
+    )>,
 }
 
 impl ReadNativeDataSource<true> {
@@ -46,6 +58,7 @@ impl ReadNativeDataSource<true> {
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
+        plan: &DataSourcePlan,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         SyncSourcer::create(ctx.clone(), output.clone(), ReadNativeDataSource::<true> {
@@ -53,6 +66,8 @@ impl ReadNativeDataSource<true> {
             output,
             batch_size,
             block_reader,
+            scan_id: plan.scan_id,
+            table_schema: plan.source_info.schema(),
             finished: false,
             output_data: None,
         })
@@ -64,6 +79,7 @@ impl ReadNativeDataSource<false> {
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
+        plan: &DataSourcePlan,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         Ok(ProcessorPtr::create(Box::new(ReadNativeDataSource::<
@@ -73,6 +89,8 @@ impl ReadNativeDataSource<false> {
             output,
             batch_size,
             block_reader,
+            scan_id: plan.scan_id,
+            table_schema: plan.source_info.schema(),
             finished: false,
             output_data: None,
         })))
@@ -83,13 +101,20 @@ impl SyncSource for ReadNativeDataSource<true> {
     const NAME: &'static str = "SyncReadNativeDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.ctx.get_partition() {
+        let parts = fetch_partitions(&self.ctx, 1, self.scan_id, &self.table_schema)?;
+        match parts.into_iter().next() {
             None => Ok(None),
-            Some(part) => Ok(Some(DataBlock::empty_with_meta(
-                NativeDataSourceMeta::create(vec![part.clone()], vec![
-                    self.block_reader.sync_read_native_columns_data(part)?,
-                ]),
-            ))),
+            Some(part) => {
+                let deletion_vector = self
+                    .block_reader
+                    .sync_read_deletion_vector(FusePartInfo::from_part(&part)?)?;
+                let chunks = self
+                    .block_reader
+                    .sync_read_native_columns_data(part.clone())?;
+                Ok(Some(DataBlock::empty_with_meta(
+                    NativeDataSourceMeta::create(vec![part], vec![chunks], vec![deletion_vector]),
+                )))
+            }
         }
     }
 }
@@ -118,8 +143,12 @@ impl Processor for ReadNativeDataSource<false> {
             return Ok(Event::NeedConsume);
         }
 
-        if let Some((part, data)) = self.output_data.take() {
-            let output = DataBlock::empty_with_meta(NativeDataSourceMeta::create(part, data));
+        if let Some((part, data, deletion_vectors)) = self.output_data.take() {
+            let output = DataBlock::empty_with_meta(NativeDataSourceMeta::create(
+                part,
+                data,
+                deletion_vectors,
+            ));
             self.output.push_data(Ok(output));
             // return Ok(Event::NeedConsume);
         }
@@ -128,7 +157,7 @@ impl Processor for ReadNativeDataSource<false> {
     }
 
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.ctx.get_partitions(self.batch_size);
+        let parts = fetch_partitions(&self.ctx, self.batch_size, self.scan_id, &self.table_schema)?;
 
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
@@ -138,13 +167,21 @@ impl Processor for ReadNativeDataSource<false> {
 
                 chunks.push(async move {
                     let handler = tokio::spawn(async move {
-                        block_reader.async_read_native_columns_data(part).await
+                        let deletion_vector = block_reader
+                            .read_deletion_vector(FusePartInfo::from_part(&part)?)
+                            .await?;
+                        let chunks = block_reader.async_read_native_columns_data(part).await?;
+                        Ok::<_, ErrorCode>((chunks, deletion_vector))
                     });
                     handler.await.unwrap()
                 });
             }
 
-            self.output_data = Some((parts, futures::future::try_join_all(chunks).await?));
+            let (chunks, deletion_vectors) = futures::future::try_join_all(chunks)
+                .await?
+                .into_iter()
+                .unzip();
+            self.output_data = Some((parts, chunks, deletion_vectors));
             return Ok(());
         }
 
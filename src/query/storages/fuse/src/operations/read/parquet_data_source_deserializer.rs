@@ -18,10 +18,14 @@ use std::time::Instant;
 
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
+use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::TableSchemaRef;
 use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
@@ -31,13 +35,21 @@ use common_pipeline_core::processors::Processor;
 use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
 use crate::io::UncompressedBuffer;
+use crate::io::VirtualColumnReader;
 use crate::metrics::metrics_inc_remote_io_deserialize_milliseconds;
 use crate::operations::read::parquet_data_source::DataSourceMeta;
+use crate::operations::read::runtime_filter_prunner::runtime_filter_bitmap;
+use crate::operations::read::runtime_filter_prunner::runtime_filter_pruner;
 use crate::MergeIOReadResult;
 
 pub struct DeserializeDataTransform {
+    ctx: Arc<dyn TableContext>,
     scan_progress: Arc<Progress>,
     block_reader: Arc<BlockReader>,
+    virtual_reader: Option<Arc<VirtualColumnReader>>,
+    scan_id: usize,
+    table_schema: TableSchemaRef,
+    src_schema: DataSchema,
 
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
@@ -53,14 +65,27 @@ impl DeserializeDataTransform {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         block_reader: Arc<BlockReader>,
+        virtual_reader: Option<Arc<VirtualColumnReader>>,
+        plan: &DataSourcePlan,
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
     ) -> Result<ProcessorPtr> {
         let buffer_size = ctx.get_settings().get_parquet_uncompressed_buffer_size()? as usize;
         let scan_progress = ctx.get_scan_progress();
+        let mut src_schema: DataSchema = (block_reader.schema().as_ref()).into();
+        if let Some(virtual_reader) = &virtual_reader {
+            let mut fields = src_schema.fields().clone();
+            fields.extend(virtual_reader.fields().iter().map(DataField::from));
+            src_schema = DataSchema::new(fields);
+        }
         Ok(ProcessorPtr::create(Box::new(DeserializeDataTransform {
+            ctx,
             scan_progress,
             block_reader,
+            virtual_reader,
+            scan_id: plan.scan_id,
+            table_schema: plan.source_info.schema(),
+            src_schema,
             input,
             output,
             output_data: None,
@@ -134,18 +159,37 @@ impl Processor for DeserializeDataTransform {
         let part = self.parts.pop();
         let chunks = self.chunks.pop();
         if let Some((part, read_res)) = part.zip(chunks) {
+            // The runtime filters may be built after the part was fetched,
+            // check them again before paying for the deserialization.
+            let runtime_filters = self.ctx.get_runtime_filter_with_id(self.scan_id);
+            if let Some(filters) = &runtime_filters {
+                let func_ctx = self.ctx.get_function_context()?;
+                if runtime_filter_pruner(self.table_schema.clone(), &part, filters, func_ctx)? {
+                    return Ok(());
+                }
+            }
+
             let start = Instant::now();
 
             let columns_chunks = read_res.columns_chunks()?;
             let part = FusePartInfo::from_part(&part)?;
 
-            let data_block = self.block_reader.deserialize_parquet_chunks_with_buffer(
+            let mut data_block = self.block_reader.deserialize_parquet_chunks_with_buffer(
                 part.nums_rows,
                 &part.compression,
                 &part.columns_meta,
                 columns_chunks,
                 Some(self.uncompressed_buffer.clone()),
             )?;
+            if let Some(virtual_reader) = &self.virtual_reader {
+                for entry in virtual_reader.deserialize_virtual_columns(part, &read_res)? {
+                    data_block.add_column(entry);
+                }
+            }
+            let data_block = match &read_res.deletion_vector {
+                Some(deletion_vector) => deletion_vector.apply(data_block, 0)?,
+                None => data_block,
+            };
 
             // Perf.
             {
@@ -158,6 +202,22 @@ impl Processor for DeserializeDataTransform {
             };
             self.scan_progress.incr(&progress_values);
 
+            let data_block = match &runtime_filters {
+                Some(filters) if !data_block.is_empty() => {
+                    let func_ctx = self.ctx.get_function_context()?;
+                    match runtime_filter_bitmap(
+                        filters,
+                        func_ctx,
+                        &data_block,
+                        &self.src_schema,
+                        None,
+                    )? {
+                        Some(bitmap) => DataBlock::filter_with_bitmap(data_block, &bitmap)?,
+                        None => data_block,
+                    }
+                }
+                _ => data_block,
+            };
             self.output_data = Some(data_block);
         }
 
@@ -16,10 +16,13 @@ use std::any::Any;
 use std::sync::Arc;
 
 use common_base::base::tokio;
+use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
@@ -30,7 +33,9 @@ use common_pipeline_sources::processors::sources::SyncSourcer;
 use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
+use crate::io::VirtualColumnReader;
 use crate::operations::read::parquet_data_source::DataSourceMeta;
+use crate::operations::read::runtime_filter_prunner::fetch_partitions;
 use crate::MergeIOReadResult;
 
 pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
@@ -38,6 +43,9 @@ pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
     ctx: Arc<dyn TableContext>,
     batch_size: usize,
     block_reader: Arc<BlockReader>,
+    virtual_reader: Option<Arc<VirtualColumnReader>>,
+    scan_id: usize,
+    table_schema: TableSchemaRef,
 
     output: Arc<OutputPort>,
     output_data: Option<(Vec<PartInfoPtr>, Vec<MergeIOReadResult>)>,
@@ -48,6 +56,8 @@ impl ReadParquetDataSource<true> {
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
+        virtual_reader: Option<Arc<VirtualColumnReader>>,
+        plan: &DataSourcePlan,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         SyncSourcer::create(ctx.clone(), output.clone(), ReadParquetDataSource::<true> {
@@ -55,6 +65,9 @@ impl ReadParquetDataSource<true> {
             output,
             batch_size,
             block_reader,
+            virtual_reader,
+            scan_id: plan.scan_id,
+            table_schema: plan.source_info.schema(),
             finished: false,
             output_data: None,
         })
@@ -66,6 +79,8 @@ impl ReadParquetDataSource<false> {
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
+        virtual_reader: Option<Arc<VirtualColumnReader>>,
+        plan: &DataSourcePlan,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         Ok(ProcessorPtr::create(Box::new(ReadParquetDataSource::<
@@ -75,6 +90,9 @@ impl ReadParquetDataSource<false> {
             output,
             batch_size,
             block_reader,
+            virtual_reader,
+            scan_id: plan.scan_id,
+            table_schema: plan.source_info.schema(),
             finished: false,
             output_data: None,
         })))
@@ -85,15 +103,29 @@ impl SyncSource for ReadParquetDataSource<true> {
     const NAME: &'static str = "SyncReadParquetDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.ctx.get_partition() {
+        let parts = fetch_partitions(&self.ctx, 1, self.scan_id, &self.table_schema)?;
+        match parts.into_iter().next() {
             None => Ok(None),
-            Some(part) => Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
-                vec![part.clone()],
-                vec![self.block_reader.sync_read_columns_data_by_merge_io(
-                    &ReadSettings::from_ctx(&self.ctx)?,
-                    part,
-                )?],
-            )))),
+            Some(part) => {
+                let settings = ReadSettings::from_ctx(&self.ctx)?;
+                let mut read_res = match &self.virtual_reader {
+                    Some(virtual_reader) => virtual_reader.sync_read_columns_data_by_merge_io(
+                        &settings,
+                        &self.block_reader,
+                        FusePartInfo::from_part(&part)?,
+                    )?,
+                    None => self
+                        .block_reader
+                        .sync_read_columns_data_by_merge_io(&settings, part.clone())?,
+                };
+                read_res.deletion_vector = self
+                    .block_reader
+                    .sync_read_deletion_vector(FusePartInfo::from_part(&part)?)?;
+                Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
+                    vec![part],
+                    vec![read_res],
+                ))))
+            }
         }
     }
 }
@@ -133,26 +165,39 @@ impl Processor for ReadParquetDataSource<false> {
     }
 
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.ctx.get_partitions(self.batch_size);
+        let parts = fetch_partitions(&self.ctx, self.batch_size, self.scan_id, &self.table_schema)?;
 
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
             for part in &parts {
                 let part = part.clone();
                 let block_reader = self.block_reader.clone();
+                let virtual_reader = self.virtual_reader.clone();
                 let settings = ReadSettings::from_ctx(&self.ctx)?;
 
                 chunks.push(async move {
                     tokio::spawn(async move {
                         let part = FusePartInfo::from_part(&part)?;
 
-                        block_reader
-                            .read_columns_data_by_merge_io(
-                                &settings,
-                                &part.location,
-                                &part.columns_meta,
-                            )
-                            .await
+                        // the virtual columns are read along with the projected columns
+                        let mut read_res = match virtual_reader {
+                            Some(virtual_reader) => {
+                                virtual_reader
+                                    .read_columns_data_by_merge_io(&settings, &block_reader, part)
+                                    .await?
+                            }
+                            None => {
+                                block_reader
+                                    .read_columns_data_by_merge_io(
+                                        &settings,
+                                        &part.location,
+                                        &part.columns_meta,
+                                    )
+                                    .await?
+                            }
+                        };
+                        read_res.deletion_vector = block_reader.read_deletion_vector(part).await?;
+                        Ok::<_, ErrorCode>(read_res)
                     })
                     .await
                     .unwrap()
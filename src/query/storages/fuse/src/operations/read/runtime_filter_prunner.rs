@@ -0,0 +1,180 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::filter_helper::FilterHelpers;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+use storages_common_index::BloomIndex;
+use storages_common_index::RangeIndex;
+
+use crate::fuse_part::FusePartInfo;
+
+/// Fetch the next batch of parts to read, the parts pruned by the runtime filters
+/// of the scan are skipped. Returns empty only if all the parts are consumed.
+pub fn fetch_partitions(
+    ctx: &Arc<dyn TableContext>,
+    batch_size: usize,
+    scan_id: usize,
+    table_schema: &TableSchemaRef,
+) -> Result<Vec<PartInfoPtr>> {
+    loop {
+        let parts = ctx.get_partitions(batch_size);
+        if parts.is_empty() {
+            return Ok(parts);
+        }
+        let Some(filters) = ctx.get_runtime_filter_with_id(scan_id) else {
+            return Ok(parts);
+        };
+
+        let func_ctx = ctx.get_function_context()?;
+        let mut remain_parts = Vec::with_capacity(parts.len());
+        for part in parts.into_iter() {
+            if !runtime_filter_pruner(table_schema.clone(), &part, &filters, func_ctx)? {
+                remain_parts.push(part);
+            }
+        }
+        if !remain_parts.is_empty() {
+            return Ok(remain_parts);
+        }
+    }
+}
+
+/// Returns true if none of the rows of the part can pass the runtime filters,
+/// which is checked against the statistics of the block.
+pub fn runtime_filter_pruner(
+    table_schema: TableSchemaRef,
+    part: &PartInfoPtr,
+    filters: &RuntimeFilterInfo,
+    func_ctx: FunctionContext,
+) -> Result<bool> {
+    let range_filters = filters.range_filters();
+    if range_filters.is_empty() {
+        return Ok(false);
+    }
+
+    let part = FusePartInfo::from_part(part)?;
+    let range_index = RangeIndex::try_create(func_ctx, &range_filters, table_schema)?;
+    Ok(!range_index.apply(&part.columns_stat)?)
+}
+
+/// Evaluate the runtime filters on the block, returns the rows which can pass all of them,
+/// or `None` if no filter can be evaluated on the block.
+///
+/// The filters referencing columns which are not in the `schema` of the block are ignored,
+/// so are the filters which can be fully evaluated on the `evaluated` schema, they have been
+/// applied to an earlier projection of the same rows, like the prewhere columns of a page.
+pub fn runtime_filter_bitmap(
+    filters: &RuntimeFilterInfo,
+    func_ctx: FunctionContext,
+    data_block: &DataBlock,
+    schema: &DataSchema,
+    evaluated: Option<&DataSchema>,
+) -> Result<Option<Bitmap>> {
+    let is_evaluated = |expr: &Expr<String>| {
+        evaluated.map_or(false, |evaluated| {
+            project_to_block(expr, evaluated).is_some()
+        })
+    };
+
+    let evaluator = Evaluator::new(data_block, func_ctx, &BUILTIN_FUNCTIONS);
+    let mut bitmap: Option<Bitmap> = None;
+    let mut and_bitmap = |filter: Value<_>| {
+        let filter: Bitmap = FilterHelpers::filter_to_bitmap(filter, data_block.num_rows()).into();
+        bitmap = Some(match bitmap.take() {
+            None => filter,
+            Some(bitmap) => &bitmap & &filter,
+        });
+    };
+
+    for filter in filters.get_inlist().iter().chain(filters.get_min_max()) {
+        if is_evaluated(filter) {
+            continue;
+        }
+        let Some(filter) = project_to_block(filter, schema) else {
+            continue;
+        };
+        let result = evaluator.run(&filter)?;
+        and_bitmap(FilterHelpers::cast_to_nonull_boolean(&result).unwrap());
+    }
+
+    for (probe_key, bloom) in filters.get_bloom() {
+        if is_evaluated(probe_key) {
+            continue;
+        }
+        let Some(probe_key) = project_to_block(probe_key, schema) else {
+            continue;
+        };
+        let data_type = probe_key.data_type().clone();
+        let column = evaluator
+            .run(&probe_key)?
+            .convert_to_full_column(&data_type, data_block.num_rows());
+        let digest_type = if data_type.is_nullable() {
+            DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64)))
+        } else {
+            DataType::Number(NumberDataType::UInt64)
+        };
+        let digests =
+            BloomIndex::calculate_column_digest(func_ctx, &column, &data_type, &digest_type)?;
+        let filter: MutableBitmap = match digests {
+            Column::Nullable(nullable) => {
+                let column = UInt64Type::try_downcast_column(&nullable.column).unwrap();
+                column
+                    .iter()
+                    .zip(nullable.validity.iter())
+                    .map(|(digest, valid)| valid && bloom.contains_digest(*digest))
+                    .collect()
+            }
+            column => {
+                let column = UInt64Type::try_downcast_column(&column).unwrap();
+                column
+                    .iter()
+                    .map(|digest| bloom.contains_digest(*digest))
+                    .collect()
+            }
+        };
+        and_bitmap(Value::Column(filter.into()));
+    }
+
+    Ok(bitmap)
+}
+
+fn project_to_block(expr: &Expr<String>, schema: &DataSchema) -> Option<Expr> {
+    if expr
+        .column_refs()
+        .keys()
+        .any(|name| schema.index_of(name).is_err())
+    {
+        return None;
+    }
+    Some(expr.project_column_ref(|name| schema.index_of(name).unwrap()))
+}
@@ -25,23 +25,45 @@ use common_pipeline_core::Pipeline;
 
 use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::io::BlockReader;
+use crate::io::VirtualColumnReader;
 use crate::operations::fuse_source::build_fuse_source_pipeline;
 use crate::FuseTable;
 
 impl FuseTable {
     pub fn create_block_reader(&self, projection: Projection) -> Result<Arc<BlockReader>> {
-        let table_schema = self.table_info.schema();
+        let table_schema = self.storage_schema();
         BlockReader::create(self.operator.clone(), table_schema, projection)
     }
 
     // Build the block reader.
     fn build_block_reader(&self, plan: &DataSourcePlan) -> Result<Arc<BlockReader>> {
         self.create_block_reader(PushDownInfo::projection_of_push_downs(
-            &self.table_info.schema(),
+            &self.storage_schema(),
             &plan.push_downs,
         ))
     }
 
+    // Build the reader of the virtual columns pushed down, if any.
+    fn build_virtual_column_reader(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+    ) -> Result<Option<Arc<VirtualColumnReader>>> {
+        match plan
+            .push_downs
+            .as_ref()
+            .and_then(|push_downs| push_downs.virtual_columns.clone())
+        {
+            Some(virtual_columns) => Ok(Some(VirtualColumnReader::try_create(
+                ctx,
+                self.operator.clone(),
+                self.storage_schema(),
+                virtual_columns,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
     fn adjust_io_request(&self, ctx: &Arc<dyn TableContext>) -> Result<usize> {
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
         let max_io_requests = ctx.get_settings().get_max_storage_io_requests()? as usize;
@@ -101,6 +123,7 @@ impl FuseTable {
         }
 
         let block_reader = self.build_block_reader(plan)?;
+        let virtual_reader = self.build_virtual_column_reader(ctx.clone(), plan)?;
         let max_io_requests = self.adjust_io_request(&ctx)?;
 
         build_fuse_source_pipeline(
@@ -108,6 +131,7 @@ impl FuseTable {
             pipeline,
             self.storage_format,
             block_reader,
+            virtual_reader,
             plan,
             max_io_requests,
         )
@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Range;
 use std::sync::Arc;
 use std::time::Instant;
@@ -32,6 +33,7 @@ use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
 use common_storage::ColumnNodes;
 use opendal::Operator;
+use storages_common_cache::LoadParams;
 use storages_common_index::Index;
 use storages_common_index::RangeIndex;
 use storages_common_table_meta::meta::BlockMeta;
@@ -41,6 +43,8 @@ use tracing::info;
 
 use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::fuse_part::FusePartInfo;
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
 use crate::pruning::FusePruner;
 use crate::FuseTable;
 
@@ -92,6 +96,63 @@ impl FuseTable {
         }
     }
 
+    /// Read the partitions of the segments appended since the snapshot at `since_location`.
+    ///
+    /// Returns `None` if the table has been changed by other operations than appending since
+    /// then, e.g. deletion or compaction, in which case the whole table has to be read again.
+    pub async fn read_appended_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        since_location: &str,
+    ) -> Result<Option<(PartStatistics, Partitions)>> {
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(None),
+        };
+
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            location: since_location.to_string(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(since_location),
+        };
+        // The snapshot may have been purged already.
+        let since_snapshot = match reader.read(&params).await {
+            Ok(since_snapshot) => since_snapshot,
+            Err(_) => return Ok(None),
+        };
+
+        let current_segments = snapshot.segments.iter().collect::<HashSet<_>>();
+        if since_snapshot
+            .segments
+            .iter()
+            .any(|segment| !current_segments.contains(segment))
+        {
+            return Ok(None);
+        }
+
+        let since_segments = since_snapshot.segments.iter().collect::<HashSet<_>>();
+        let appended_segments = snapshot
+            .segments
+            .iter()
+            .filter(|segment| !since_segments.contains(segment))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let partitions = self
+            .prune_snapshot_blocks(
+                ctx,
+                self.operator.clone(),
+                push_downs,
+                self.table_info.clone(),
+                appended_segments,
+                snapshot.summary.block_count as usize,
+            )
+            .await?;
+        Ok(Some(partitions))
+    }
+
     #[tracing::instrument(level = "debug", name = "prune_snapshot_blocks", skip_all, fields(ctx.id = ctx.get_id().as_str()))]
     pub async fn prune_snapshot_blocks(
         &self,
@@ -108,16 +169,24 @@ impl FuseTable {
             segments_location.len()
         );
 
+        let table_schema = Arc::new(table_info.schema().remove_virtual_computed_fields());
         let pruner = if !self.is_native() || self.cluster_key_meta.is_none() {
-            FusePruner::create(&ctx, dal, table_info.schema(), &push_downs)?
+            FusePruner::create(
+                &ctx,
+                dal,
+                table_schema.clone(),
+                &push_downs,
+                &self.text_index_options(),
+            )?
         } else {
             let cluster_keys = self.cluster_keys(ctx.clone());
 
             FusePruner::create_with_pages(
                 &ctx,
                 dal,
-                table_info.schema(),
+                table_schema.clone(),
                 &push_downs,
+                &self.text_index_options(),
                 self.cluster_key_meta.clone(),
                 cluster_keys,
             )?
@@ -136,7 +205,7 @@ impl FuseTable {
             .map(|(block_meta_index, block_meta)| (block_meta_index.range, block_meta))
             .collect::<Vec<_>>();
         self.read_partitions_with_metas(
-            table_info.schema(),
+            table_schema,
             push_downs,
             &block_metas,
             summary,
@@ -159,7 +228,7 @@ impl FuseTable {
 
         let top_k = push_downs
             .as_ref()
-            .map(|p| p.top_k(self.schema().as_ref(), RangeIndex::supported_type))
+            .map(|p| p.top_k(self.storage_schema().as_ref(), RangeIndex::supported_type))
             .unwrap_or_default();
         let (mut statistics, parts) =
             Self::to_partitions(block_metas, &column_nodes, top_k, push_downs);
@@ -207,14 +276,17 @@ impl FuseTable {
         let (mut statistics, mut partitions) = match &push_down {
             None => Self::all_columns_partitions(&block_metas, top_k.clone(), limit),
             Some(extras) => match &extras.projection {
-                None => Self::all_columns_partitions(&block_metas, top_k.clone(), limit),
-                Some(projection) => Self::projection_partitions(
-                    &block_metas,
-                    column_nodes,
-                    projection,
-                    top_k.clone(),
-                    limit,
-                ),
+                // the source columns of the virtual columns may be read as well
+                Some(projection) if extras.virtual_columns.is_none() => {
+                    Self::projection_partitions(
+                        &block_metas,
+                        column_nodes,
+                        projection,
+                        top_k.clone(),
+                        limit,
+                    )
+                }
+                _ => Self::all_columns_partitions(&block_metas, top_k.clone(), limit),
             },
         };
 
@@ -247,7 +319,7 @@ impl FuseTable {
 
         let mut remaining = limit;
         for (range, block_meta) in block_metas.iter() {
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
             partitions
                 .partitions
                 .push(Self::all_columns_part(range.clone(), &top_k, block_meta));
@@ -292,7 +364,7 @@ impl FuseTable {
                 top_k.clone(),
                 projection,
             ));
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
 
             statistics.read_rows += rows;
             let columns = projection.project_column_nodes(column_nodes).unwrap();
@@ -332,6 +404,8 @@ impl FuseTable {
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
         let format_version = meta.location.1;
+        // The offsets of the deletion vector are of the whole block, which is read without page pruning.
+        let range = range.filter(|_| meta.deletion_vector_location.is_none());
 
         let sort_min_max = top_k.as_ref().map(|top_k| {
             let stat = meta.col_stats.get(&top_k.column_id).unwrap();
@@ -344,8 +418,11 @@ impl FuseTable {
             rows_count,
             columns_meta,
             meta.compression(),
+            meta.col_stats.clone(),
             sort_min_max,
             range,
+            meta.deletion_vector_location.clone(),
+            meta.virtual_block_meta.clone(),
         )
     }
 
@@ -371,6 +448,8 @@ impl FuseTable {
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
         let format_version = meta.location.1;
+        // The offsets of the deletion vector are of the whole block, which is read without page pruning.
+        let range = range.filter(|_| meta.deletion_vector_location.is_none());
 
         let sort_min_max = top_k.map(|top_k| {
             let stat = meta.col_stats.get(&top_k.column_id).unwrap();
@@ -386,8 +465,11 @@ impl FuseTable {
             rows_count,
             columns_meta,
             meta.compression(),
+            meta.col_stats.clone(),
             sort_min_max,
             range,
+            meta.deletion_vector_location.clone(),
+            meta.virtual_block_meta.clone(),
         )
     }
 }
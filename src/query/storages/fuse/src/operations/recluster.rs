@@ -61,9 +61,15 @@ impl FuseTable {
             return Ok(None);
         };
 
-        let schema = self.table_info.schema();
+        let schema = self.storage_schema();
         let segment_locations = snapshot.segments.clone();
-        let pruner = FusePruner::create(&ctx, self.operator.clone(), schema, &push_downs)?;
+        let pruner = FusePruner::create(
+            &ctx,
+            self.operator.clone(),
+            schema,
+            &push_downs,
+            &self.text_index_options(),
+        )?;
         let block_metas = pruner.pruning(segment_locations).await?;
 
         let default_cluster_key_id = self.cluster_key_meta.clone().unwrap().0;
@@ -114,7 +120,7 @@ impl FuseTable {
             .map(|meta| (None, meta.clone()))
             .collect();
         let (statistics, parts) = self.read_partitions_with_metas(
-            self.table_info.schema(),
+            self.storage_schema(),
             None,
             &block_metas,
             partitions_total,
@@ -131,6 +137,7 @@ impl FuseTable {
             description,
             tbl_args: self.table_args(),
             push_downs: None,
+            scan_id: 0,
         };
 
         ctx.set_partitions(plan.parts.clone())?;
@@ -209,7 +216,7 @@ impl FuseTable {
                 self.meta_location_generator().clone(),
                 cluster_stats_gen.clone(),
                 block_compact_thresholds,
-                self.table_info.schema(),
+                self.storage_schema(),
                 None,
             )
         })?;
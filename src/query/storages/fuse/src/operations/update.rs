@@ -19,12 +19,17 @@ use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::DataSchema;
+use common_expression::Expr;
 use common_expression::RemoteExpr;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 use common_sql::evaluator::BlockOperator;
+use common_sql::parse_computed_expr;
+use storages_common_table_meta::meta::SnapshotMutation;
 use storages_common_table_meta::meta::TableSnapshot;
 
 use crate::operations::mutation::MutationAction;
@@ -64,7 +69,7 @@ impl FuseTable {
         let mut filter = filter;
         if col_indices.is_empty() && filter.is_some() {
             let filter_expr = filter.clone().unwrap();
-            if !self.try_eval_const(ctx.clone(), &self.schema(), &filter_expr)? {
+            if !self.try_eval_const(ctx.clone(), &self.storage_schema(), &filter_expr)? {
                 // The condition is always false, do nothing.
                 return Ok(());
             }
@@ -96,7 +101,13 @@ impl FuseTable {
         self.try_add_mutation_transform(ctx.clone(), snapshot.segments.clone(), pipeline)?;
 
         pipeline.add_sink(|input| {
-            MutationSink::try_create(self, ctx.clone(), snapshot.clone(), input)
+            MutationSink::try_create(
+                self,
+                ctx.clone(),
+                snapshot.clone(),
+                SnapshotMutation::Update,
+                input,
+            )
         })?;
         Ok(())
     }
@@ -111,7 +122,7 @@ impl FuseTable {
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         let all_col_ids = self.all_the_columns_ids();
-        let schema = self.schema();
+        let schema = self.storage_schema();
 
         let mut offset_map = BTreeMap::new();
         let mut remain_reader = None;
@@ -167,6 +178,30 @@ impl FuseTable {
             offset_map.insert(id, pos);
             pos += 1;
         }
+
+        // The stored computed columns are computed again from the updated columns.
+        let data_schema = Arc::new(DataSchema::from(schema.clone()));
+        for (id, field) in schema.fields().iter().enumerate() {
+            let Some(computed_expr) = field.computed_expr() else {
+                continue;
+            };
+            let expr = parse_computed_expr(ctx.clone(), data_schema.clone(), computed_expr.expr())?
+                .project_column_ref(|index| offset_map[index]);
+            let data_type = DataType::from(field.data_type());
+            let expr = if expr.data_type() != &data_type {
+                Expr::Cast {
+                    span: None,
+                    is_try: false,
+                    expr: Box::new(expr),
+                    dest_type: data_type,
+                }
+            } else {
+                expr
+            };
+            ops.push(BlockOperator::Map { expr });
+            offset_map.insert(id, pos);
+            pos += 1;
+        }
         ops.push(BlockOperator::Project {
             projection: offset_map.values().cloned().collect(),
         });
@@ -202,6 +237,7 @@ impl FuseTable {
                     block_reader.clone(),
                     remain_reader.clone(),
                     ops.clone(),
+                    self.meta_location_generator().clone(),
                 )
             },
             max_threads,
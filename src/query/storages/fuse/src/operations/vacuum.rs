@@ -0,0 +1,231 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::TimeZone;
+use chrono::Utc;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_meta_app::schema::RemoveDroppedTableReq;
+use futures::TryStreamExt;
+use opendal::ObjectMode;
+use storages_common_table_meta::table::SnapshotRef;
+use tracing::info;
+
+use crate::constants::FUSE_TBL_BLOCK_INDEX_PREFIX;
+use crate::constants::FUSE_TBL_BLOCK_PREFIX;
+use crate::constants::FUSE_TBL_DELETION_VECTOR_PREFIX;
+use crate::constants::FUSE_TBL_INVERTED_INDEX_PREFIX;
+use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
+use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
+use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
+use crate::constants::FUSE_TBL_VIRTUAL_BLOOM_INDEX_PREFIX;
+use crate::constants::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
+use crate::io::Files;
+use crate::io::MetaReaders;
+use crate::io::SnapshotHistoryReader;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+// The directories under the table prefix the fuse files are written to.
+const FUSE_TBL_FILE_DIRS: [&str; 9] = [
+    FUSE_TBL_BLOCK_PREFIX,
+    FUSE_TBL_BLOCK_INDEX_PREFIX,
+    FUSE_TBL_XOR_BLOOM_INDEX_PREFIX,
+    FUSE_TBL_INVERTED_INDEX_PREFIX,
+    FUSE_TBL_DELETION_VECTOR_PREFIX,
+    FUSE_TBL_VIRTUAL_BLOOM_INDEX_PREFIX,
+    FUSE_TBL_SEGMENT_PREFIX,
+    FUSE_TBL_SNAPSHOT_PREFIX,
+    FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX,
+];
+
+impl FuseTable {
+    /// Removes the files under the table prefix that no snapshot references, e.g. the ones left
+    /// by aborted inserts and failed mutations. Files written within the retention period are
+    /// kept, as they may belong to a transaction not yet committed.
+    ///
+    /// Returns the files removed, or the ones that would be removed if `dry_run`.
+    pub async fn do_vacuum(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        retention: Duration,
+        dry_run: bool,
+    ) -> Result<Vec<String>> {
+        let retention_point = Utc::now() - retention;
+        let referenced_files = self.get_referenced_files(ctx).await?;
+        let orphan_files = self
+            .list_table_files()
+            .await?
+            .into_iter()
+            .filter(|(location, last_modified)| {
+                *last_modified < retention_point && !referenced_files.contains(location)
+            })
+            .map(|(location, _)| location)
+            .collect::<Vec<_>>();
+
+        info!(
+            "vacuum: {} orphan files found. table: {}, ident {}",
+            orphan_files.len(),
+            self.table_info.desc,
+            self.table_info.ident,
+        );
+        if !dry_run {
+            let fuse_file = Files::create(ctx.clone(), self.operator.clone());
+            fuse_file.remove_file_in_batch(&orphan_files).await?;
+        }
+        Ok(orphan_files)
+    }
+
+    /// Removes all the files of a dropped table, except the ones still read by the tables
+    /// cloned from it.
+    ///
+    /// The meta of the table is removed before its files, so that a table whose files are
+    /// removed can never be undropped.
+    ///
+    /// Returns the files removed, or the ones that would be removed if `dry_run`.
+    pub async fn do_vacuum_drop(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        dry_run: bool,
+    ) -> Result<Vec<String>> {
+        let referenced_files = self.get_files_referenced_by_dependents(ctx).await?;
+        let mut files = self
+            .list_table_files()
+            .await?
+            .into_iter()
+            .map(|(location, _)| location)
+            .filter(|location| !referenced_files.contains(location))
+            .collect::<Vec<_>>();
+        // the last snapshot hint
+        let prefix = format!("{}/", self.meta_location_generator.prefix());
+        files.extend(
+            self.list_files(&prefix)
+                .await?
+                .into_iter()
+                .map(|(location, _)| location),
+        );
+
+        info!(
+            "vacuum drop: {} files found. table: {}, ident {}",
+            files.len(),
+            self.table_info.desc,
+            self.table_info.ident,
+        );
+        if !dry_run {
+            let catalog = ctx.get_catalog(&self.table_info.meta.catalog)?;
+            catalog
+                .remove_dropped_table(RemoveDroppedTableReq {
+                    table_id: self.get_id(),
+                })
+                .await?;
+
+            let fuse_file = Files::create(ctx.clone(), self.operator.clone());
+            fuse_file.remove_file_in_batch(&files).await?;
+        }
+        Ok(files)
+    }
+
+    // The files referenced by any snapshot in the history of the table, and of its tags and
+    // branches, along with the ones read by the tables depending on it.
+    async fn get_referenced_files(&self, ctx: &Arc<dyn TableContext>) -> Result<HashSet<String>> {
+        let mut roots = self.snapshot_loc().await?.into_iter().collect::<Vec<_>>();
+        roots.extend(
+            SnapshotRef::from_table_options(self.table_info.options())?
+                .into_iter()
+                .map(|snapshot_ref| snapshot_ref.snapshot_location),
+        );
+
+        let mut files = HashSet::new();
+        let mut segments = HashSet::new();
+        let location_gen = self.meta_location_generator.clone();
+        for root in roots {
+            let reader = MetaReaders::table_snapshot_reader(self.operator.clone());
+            let version = TableMetaLocationGenerator::snapshot_version(&root);
+            let mut history = reader.snapshot_history(root, version, location_gen.clone());
+            while let Some(snapshot) = history.try_next().await? {
+                let location = location_gen.snapshot_location_from_uuid(
+                    &snapshot.snapshot_id,
+                    snapshot.format_version(),
+                )?;
+                // the rest of the history has been walked from another root
+                if !files.insert(location) {
+                    break;
+                }
+                if let Some(location) = &snapshot.table_statistics_location {
+                    files.insert(location.clone());
+                }
+                segments.extend(snapshot.segments.iter().cloned());
+            }
+        }
+
+        let segment_locations = Vec::from_iter(segments);
+        let locations = self
+            .get_block_locations(ctx.clone(), &segment_locations)
+            .await?;
+        files.extend(segment_locations.into_iter().map(|location| location.0));
+        files.extend(locations.block_location);
+        files.extend(locations.bloom_location);
+        files.extend(locations.inverted_index_location);
+        files.extend(locations.deletion_vector_location);
+        files.extend(self.get_files_referenced_by_dependents(ctx).await?);
+        Ok(files)
+    }
+
+    async fn get_files_referenced_by_dependents(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<HashSet<String>> {
+        let (_, segments, locations) = self.get_locations_referenced_by_dependents(ctx).await?;
+        let mut files = HashSet::new();
+        files.extend(segments.into_iter().map(|location| location.0));
+        files.extend(locations.block_location);
+        files.extend(locations.bloom_location);
+        files.extend(locations.inverted_index_location);
+        files.extend(locations.deletion_vector_location);
+        Ok(files)
+    }
+
+    // List the fuse files under the table prefix, along with their last modified time.
+    async fn list_table_files(&self) -> Result<Vec<(String, DateTime<Utc>)>> {
+        let mut files = vec![];
+        for dir in FUSE_TBL_FILE_DIRS {
+            let prefix = format!("{}/{}/", self.meta_location_generator.prefix(), dir);
+            files.extend(self.list_files(&prefix).await?);
+        }
+        Ok(files)
+    }
+
+    // List the files right under the prefix, the ones of unknown age are taken as just written.
+    async fn list_files(&self, prefix: &str) -> Result<Vec<(String, DateTime<Utc>)>> {
+        let mut files = vec![];
+        let mut ds = self.operator.object(prefix).list().await?;
+        while let Some(de) = ds.try_next().await? {
+            if de.mode().await? != ObjectMode::FILE {
+                continue;
+            }
+            let last_modified = de
+                .last_modified()
+                .await?
+                .map_or(Utc::now(), |t| Utc.timestamp(t.unix_timestamp(), 0));
+            files.push((de.path().to_string(), last_modified));
+        }
+        Ok(files)
+    }
+}
@@ -54,7 +54,7 @@ impl BlockPruner {
         }
     }
 
-    // async pruning with bloom index.
+    // async pruning with bloom index and inverted index.
     async fn block_pruning(
         &self,
         bloom_pruner: &Arc<dyn BloomPruner + Send + Sync>,
@@ -91,8 +91,11 @@ impl BlockPruner {
                 }
 
                 let block_meta = block_meta.clone();
-                let row_count = block_meta.row_count;
-                if range_pruner.should_keep(&block_meta.col_stats) {
+                let row_count = block_meta.live_row_count();
+                if range_pruner.should_keep(&self.pruning_ctx.col_stats(
+                    &block_meta.col_stats,
+                    block_meta.virtual_block_meta.as_ref(),
+                )) {
                     // Perf.
                     {
                         metrics_inc_blocks_range_pruning_after(1);
@@ -107,6 +110,8 @@ impl BlockPruner {
                     let page_pruner = page_pruner.clone();
                     let index_location = block_meta.bloom_filter_index_location.clone();
                     let index_size = block_meta.bloom_filter_index_size;
+                    let inverted_index_location = block_meta.inverted_index_location.clone();
+                    let virtual_index = self.pruning_ctx.virtual_bloom_index(&block_meta);
 
                     let v: BlockPruningFuture = Box::new(move |permit: OwnedSemaphorePermit| {
                         Box::pin(async move {
@@ -120,6 +125,15 @@ impl BlockPruner {
 
                             let _permit = permit;
                             let keep = bloom_pruner.should_keep(&index_location, index_size).await
+                                && match virtual_index {
+                                    Some((location, size)) => {
+                                        bloom_pruner.should_keep(&Some(location), size).await
+                                    }
+                                    None => true,
+                                }
+                                && bloom_pruner
+                                    .should_keep_by_inverted_index(&inverted_index_location)
+                                    .await
                                 && limit_pruner.within_limit(row_count);
 
                             if keep {
@@ -215,9 +229,11 @@ impl BlockPruner {
             if limit_pruner.exceeded() {
                 break;
             }
-            let row_count = block_meta.row_count;
-            if range_pruner.should_keep(&block_meta.col_stats)
-                && limit_pruner.within_limit(row_count)
+            let row_count = block_meta.live_row_count();
+            if range_pruner.should_keep(&self.pruning_ctx.col_stats(
+                &block_meta.col_stats,
+                block_meta.virtual_block_meta.as_ref(),
+            )) && limit_pruner.within_limit(row_count)
             {
                 // Perf.
                 {
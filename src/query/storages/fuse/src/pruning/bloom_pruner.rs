@@ -25,16 +25,24 @@ use common_expression::Scalar;
 use common_expression::TableSchemaRef;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 use opendal::Operator;
+use storages_common_cache::LoadParams;
 use storages_common_index::BloomIndex;
 use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+use storages_common_index::TextIndexOptions;
 use storages_common_table_meta::meta::Location;
 
 use crate::io::BloomBlockFilterReader;
+use crate::io::MetaReaders;
 
 #[async_trait::async_trait]
 pub trait BloomPruner {
     // returns ture, if target should NOT be pruned (false positive allowed)
     async fn should_keep(&self, index_location: &Option<Location>, index_length: u64) -> bool;
+
+    // returns true, if the `match` predicates may be satisfied by the rows of the block
+    // according to its inverted index
+    async fn should_keep_by_inverted_index(&self, index_location: &Option<Location>) -> bool;
 }
 
 pub struct BloomPrunerCreator {
@@ -54,6 +62,12 @@ pub struct BloomPrunerCreator {
 
     /// the schema of data being indexed
     data_schema: TableSchemaRef,
+
+    /// the ngram and token filters of the table
+    text_index: TextIndexOptions,
+
+    /// whether the filter has `match` predicates, which are answered by inverted indexes
+    has_match_predicates: bool,
 }
 
 impl BloomPrunerCreator {
@@ -62,6 +76,7 @@ impl BloomPrunerCreator {
         schema: &TableSchemaRef,
         dal: Operator,
         filter_expr: Option<&[Expr<String>]>,
+        text_index: &TextIndexOptions,
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
             if expr.is_empty() {
@@ -79,17 +94,25 @@ impl BloomPrunerCreator {
 
             let (optimized_expr, _) = ConstantFolder::fold(&expr, func_ctx, &BUILTIN_FUNCTIONS);
             let point_query_cols = BloomIndex::find_eq_columns(&optimized_expr)?;
+            let text_filter_cols =
+                BloomIndex::find_text_filter_columns(&optimized_expr, text_index)?;
+            let match_cols = InvertedIndex::find_match_columns(&optimized_expr);
 
             tracing::debug!(
-                "Bloom filter expr {:?}, optimized {:?}, point_query_cols: {:?}",
+                "Bloom filter expr {:?}, optimized {:?}, point_query_cols: {:?}, text_filter_cols: {:?}, match_cols: {:?}",
                 expr.sql_display(),
                 optimized_expr.sql_display(),
-                point_query_cols
+                point_query_cols,
+                text_filter_cols,
+                match_cols
             );
 
-            if !point_query_cols.is_empty() {
+            if !point_query_cols.is_empty()
+                || !text_filter_cols.is_empty()
+                || !match_cols.is_empty()
+            {
                 // convert to filter column names
-                let mut filter_block_cols = vec![];
+                let mut filter_block_cols = text_filter_cols;
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
                 for (col_name, scalar, ty) in point_query_cols.iter() {
                     filter_block_cols.push(BloomIndex::build_filter_column_name(col_name));
@@ -106,6 +129,8 @@ impl BloomPrunerCreator {
                     scalar_map,
                     dal,
                     data_schema: schema.clone(),
+                    text_index: text_index.clone(),
+                    has_match_predicates: !match_cols.is_empty(),
                 };
                 return Ok(Some(Arc::new(creator)));
             }
@@ -127,6 +152,7 @@ impl BloomPrunerCreator {
                 filter.filter_schema,
                 filter.filters,
                 index_location.1,
+                self.text_index.clone(),
             )?
             .apply(self.filter_expression.clone(), &self.scalar_map)?
                 != FilterEvalResult::MustFalse),
@@ -139,11 +165,28 @@ impl BloomPrunerCreator {
             Err(e) => Err(e),
         }
     }
+
+    // Check a block is hit or not by its inverted index.
+    pub async fn apply_inverted_index(&self, index_location: &Location) -> Result<bool> {
+        let reader = MetaReaders::inverted_index_reader(self.dal.clone());
+        let index = reader
+            .read(&LoadParams {
+                location: index_location.0.clone(),
+                len_hint: None,
+                ver: index_location.1,
+            })
+            .await?;
+        Ok(index.apply(self.func_ctx, self.filter_expression.clone())
+            != FilterEvalResult::MustFalse)
+    }
 }
 
 #[async_trait::async_trait]
 impl BloomPruner for BloomPrunerCreator {
     async fn should_keep(&self, index_location: &Option<Location>, index_length: u64) -> bool {
+        if self.index_columns.is_empty() {
+            return true;
+        }
         if let Some(loc) = index_location {
             // load filter, and try pruning according to filter expression
             match self.apply(loc, index_length).await {
@@ -158,4 +201,26 @@ impl BloomPruner for BloomPrunerCreator {
             true
         }
     }
+
+    async fn should_keep_by_inverted_index(&self, index_location: &Option<Location>) -> bool {
+        if !self.has_match_predicates {
+            return true;
+        }
+        // blocks written before the inverted index was created have no index file
+        if let Some(loc) = index_location {
+            match self.apply_inverted_index(loc).await {
+                Ok(v) => v,
+                Err(e) => {
+                    // swallow exceptions intentionally, corrupted index should not prevent execution
+                    tracing::warn!(
+                        "failed to apply inverted index pruner, returning true. {}",
+                        e
+                    );
+                    true
+                }
+            }
+        } else {
+            true
+        }
+    }
 }
@@ -12,17 +12,22 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use common_base::base::tokio::sync::Semaphore;
 use common_base::runtime::Runtime;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::VirtualColumnInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::RemoteExpr;
+use common_expression::TableField;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 use opendal::Operator;
+use storages_common_index::TextIndexOptions;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_pruner::Limiter;
 use storages_common_pruner::LimiterPrunerCreator;
@@ -34,6 +39,8 @@ use storages_common_pruner::TopNPrunner;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+use storages_common_table_meta::meta::VirtualBlockMeta;
 use tracing::warn;
 
 use crate::pruning::BloomPruner;
@@ -52,9 +59,66 @@ pub struct PruningContext {
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
 
+    /// The virtual columns pushed down, which follow the leaf columns of the table in the
+    /// schema that the range and bloom pruners are built on.
+    pub virtual_columns: Vec<VirtualColumnInfo>,
+    pub virtual_column_offset: usize,
+
     pub pruning_stats: Arc<FusePruningStatistics>,
 }
 
+impl PruningContext {
+    /// The column statistics to prune the block or segment with, along with the ones of the
+    /// stored virtual columns that are pushed down.
+    pub fn col_stats<'a>(
+        &self,
+        col_stats: &'a StatisticsOfColumns,
+        virtual_block_meta: Option<&VirtualBlockMeta>,
+    ) -> Cow<'a, StatisticsOfColumns> {
+        if self.virtual_columns.is_empty() {
+            return Cow::Borrowed(col_stats);
+        }
+
+        let mut col_stats = col_stats.clone();
+        for (i, virtual_column) in self.virtual_columns.iter().enumerate() {
+            let column_id = (self.virtual_column_offset + i) as u32;
+            let stats = virtual_block_meta
+                .and_then(|meta| {
+                    meta.columns.iter().find(|c| {
+                        c.name == virtual_column.name
+                            && c.cast_type == virtual_column.cast_type
+                            && c.data_type == virtual_column.data_type
+                    })
+                })
+                .and_then(|c| c.col_stats.clone());
+            // the statistics of the columns dropped from the table may be kept with the id
+            match stats {
+                Some(stats) => col_stats.insert(column_id, stats),
+                None => col_stats.remove(&column_id),
+            };
+        }
+        Cow::Owned(col_stats)
+    }
+
+    /// The bloom filter index of the virtual columns of the block, if the stored columns
+    /// named as the ones pushed down are all of the types expected.
+    pub fn virtual_bloom_index(&self, block_meta: &BlockMeta) -> Option<(Location, u64)> {
+        let meta = block_meta.virtual_block_meta.as_ref()?;
+        let location = meta.bloom_filter_index_location.clone()?;
+        let compatible = meta.columns.iter().all(|c| {
+            self.virtual_columns
+                .iter()
+                .filter(|v| v.name == c.name)
+                .all(|v| v.cast_type == c.cast_type && v.data_type == c.data_type)
+        });
+        let used = meta
+            .columns
+            .iter()
+            .any(|c| self.virtual_columns.iter().any(|v| v.name == c.name));
+        (compatible && used).then_some((location, meta.bloom_filter_index_size))
+    }
+}
+
 pub struct FusePruner {
     pub table_schema: TableSchemaRef,
     pub pruning_ctx: Arc<PruningContext>,
@@ -68,8 +132,9 @@ impl FusePruner {
         dal: Operator,
         table_schema: TableSchemaRef,
         push_down: &Option<PushDownInfo>,
+        text_index: &TextIndexOptions,
     ) -> Result<Self> {
-        Self::create_with_pages(ctx, dal, table_schema, push_down, None, vec![])
+        Self::create_with_pages(ctx, dal, table_schema, push_down, text_index, None, vec![])
     }
 
     // Create fuse pruner with pages.
@@ -78,6 +143,7 @@ impl FusePruner {
         dal: Operator,
         table_schema: TableSchemaRef,
         push_down: &Option<PushDownInfo>,
+        text_index: &TextIndexOptions,
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
     ) -> Result<Self> {
@@ -100,18 +166,38 @@ impl FusePruner {
         // prepare the limiter. in case that limit is none, an unlimited limiter will be returned
         let limit_pruner = LimiterPrunerCreator::create(limit);
 
+        // The virtual columns are appended to the schema, so that the filters on them could
+        // be answered by their statistics and bloom filters as well.
+        let virtual_columns = push_down
+            .as_ref()
+            .and_then(|extra| extra.virtual_columns.clone())
+            .unwrap_or_default();
+        let virtual_column_offset = table_schema.leaf_fields().len();
+        let index_schema = if virtual_columns.is_empty() {
+            table_schema.clone()
+        } else {
+            let mut fields = table_schema.fields().clone();
+            fields.extend(
+                virtual_columns
+                    .iter()
+                    .map(|c| TableField::new(&c.name, c.data_type.clone())),
+            );
+            Arc::new(TableSchema::new_from(fields, table_schema.meta().clone()))
+        };
+
         // Range filter.
         // if filter_expression is none, an dummy pruner will be returned, which prunes nothing
         let range_pruner =
-            RangePrunerCreator::try_create(func_ctx, &table_schema, filter_exprs.as_deref())?;
+            RangePrunerCreator::try_create(func_ctx, &index_schema, filter_exprs.as_deref())?;
 
-        // Bloom pruner.
+        // Bloom pruner, which also prunes the `match` predicates by inverted indexes.
         // None will be returned, if filter is not applicable (e.g. unsuitable filter expression, index not available, etc.)
         let bloom_pruner = BloomPrunerCreator::create(
             func_ctx,
-            &table_schema,
+            &index_schema,
             dal.clone(),
             filter_exprs.as_deref(),
+            text_index,
         )?;
 
         // Page pruner, used in native format
@@ -155,6 +241,8 @@ impl FusePruner {
             range_pruner,
             bloom_pruner,
             page_pruner,
+            virtual_columns,
+            virtual_column_offset,
             pruning_stats,
         });
 
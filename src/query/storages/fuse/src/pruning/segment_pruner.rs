@@ -136,7 +136,9 @@ impl SegmentPruner {
 
         // Segment range pruning.
         let range_pruner = pruning_ctx.range_pruner.clone();
-        let result = if range_pruner.should_keep(&segment_info.summary.col_stats) {
+        let result = if range_pruner
+            .should_keep(&pruning_ctx.col_stats(&segment_info.summary.col_stats, None))
+        {
             // Perf.
             {
                 metrics_inc_segments_range_pruning_after(1);
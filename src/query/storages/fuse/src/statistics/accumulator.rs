@@ -25,6 +25,7 @@ use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::StatisticsOfColumns;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::meta::VirtualBlockMeta;
 
 use crate::statistics::block_statistics::BlockStatistics;
 
@@ -50,6 +51,7 @@ impl StatisticsAccumulator {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn add_block(
         &mut self,
         file_size: u64,
@@ -58,6 +60,9 @@ impl StatisticsAccumulator {
         bloom_filter_index_location: Option<Location>,
         bloom_filter_index_size: u64,
         block_compression: meta::Compression,
+        inverted_index_location: Option<Location>,
+        inverted_index_size: u64,
+        virtual_block_meta: Option<VirtualBlockMeta>,
     ) -> Result<()> {
         self.add(
             file_size,
@@ -66,6 +71,9 @@ impl StatisticsAccumulator {
             bloom_filter_index_location,
             bloom_filter_index_size,
             block_compression,
+            inverted_index_location,
+            inverted_index_size,
+            virtual_block_meta,
         )
     }
 
@@ -77,8 +85,11 @@ impl StatisticsAccumulator {
     ) -> Result<()> {
         let bloom_filter_index_location = block_meta.bloom_filter_index_location;
         let bloom_filter_index_size = block_meta.bloom_filter_index_size;
+        let inverted_index_location = block_meta.inverted_index_location;
+        let inverted_index_size = block_meta.inverted_index_size;
         let file_size = block_meta.file_size;
         let col_metas = block_meta.col_metas;
+        let virtual_block_meta = block_meta.virtual_block_meta;
 
         self.add(
             file_size,
@@ -87,6 +98,9 @@ impl StatisticsAccumulator {
             bloom_filter_index_location,
             bloom_filter_index_size,
             block_compression,
+            inverted_index_location,
+            inverted_index_size,
+            virtual_block_meta,
         )
     }
 
@@ -94,6 +108,7 @@ impl StatisticsAccumulator {
         super::reduce_block_statistics(&self.blocks_statistics, None)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn add(
         &mut self,
         file_size: u64,
@@ -102,9 +117,15 @@ impl StatisticsAccumulator {
         bloom_filter_index_location: Option<Location>,
         bloom_filter_index_size: u64,
         block_compression: meta::Compression,
+        inverted_index_location: Option<Location>,
+        inverted_index_size: u64,
+        virtual_block_meta: Option<VirtualBlockMeta>,
     ) -> Result<()> {
         self.file_size += file_size;
-        self.index_size += bloom_filter_index_size;
+        self.index_size += bloom_filter_index_size + inverted_index_size;
+        self.index_size += virtual_block_meta
+            .as_ref()
+            .map_or(0, |m| m.bloom_filter_index_size);
         self.summary_block_count += 1;
         self.in_memory_size += block_statistics.block_bytes_size;
         self.summary_row_count += block_statistics.block_rows_size;
@@ -124,7 +145,7 @@ impl StatisticsAccumulator {
             self.perfect_block_count += 1;
         }
 
-        self.blocks_metas.push(Arc::new(BlockMeta::new(
+        let mut block_meta = BlockMeta::new(
             row_count,
             block_size,
             file_size,
@@ -135,7 +156,11 @@ impl StatisticsAccumulator {
             bloom_filter_index_location,
             bloom_filter_index_size,
             block_compression,
-        )));
+            inverted_index_location,
+            inverted_index_size,
+        );
+        block_meta.virtual_block_meta = virtual_block_meta;
+        self.blocks_metas.push(Arc::new(block_meta));
 
         Ok(())
     }
@@ -161,12 +161,19 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
 
     block_metas.iter().for_each(|b| {
         let b = b.borrow();
-        row_count += b.row_count;
+        row_count += b.live_row_count();
         block_count += 1;
         uncompressed_byte_size += b.block_size;
         compressed_byte_size += b.file_size;
-        index_size += b.bloom_filter_index_size;
-        if thresholds.check_large_enough(b.row_count as usize, b.block_size as usize) {
+        index_size += b.bloom_filter_index_size + b.inverted_index_size;
+        index_size += b
+            .virtual_block_meta
+            .as_ref()
+            .map_or(0, |m| m.bloom_filter_index_size);
+        // A block with deleted rows is left to compaction to be rewritten.
+        if b.deletion_vector_location.is_none()
+            && thresholds.check_large_enough(b.row_count as usize, b.block_size as usize)
+        {
             perfect_block_count += 1;
         }
     });
@@ -0,0 +1,270 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateStreamMetaReq;
+use common_meta_types::MatchSeq;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::processors::sources::AsyncSource;
+use common_pipeline_sources::processors::sources::AsyncSourcer;
+use storages_common_table_meta::table::stream_change_fields;
+use storages_common_table_meta::table::OPT_KEY_STREAM_OFFSET;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_DATABASE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_TABLE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_TABLE_ID;
+use storages_common_table_meta::table::STREAM_ENGINE;
+
+use crate::operations::ChangedBlocks;
+use crate::operations::ChangesReader;
+use crate::FuseTable;
+
+/// A stream over a fuse table, reading the rows inserted into and deleted from the table since
+/// the offset of the stream.
+///
+/// Reading a stream registers its offset to be moved to the current snapshot of the table, the
+/// move is committed along with the table written by the statement, if there is one.
+pub struct StreamTable {
+    table_info: TableInfo,
+}
+
+impl StreamTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        Ok(Box::new(StreamTable { table_info }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: STREAM_ENGINE.to_string(),
+            comment: "STREAM STORAGE (CHANGES OF A FUSE TABLE)".to_string(),
+            support_cluster_key: false,
+        }
+    }
+
+    fn option(&self, key: &str) -> Result<&str> {
+        match self.table_info.options().get(key) {
+            Some(value) => Ok(value.as_str()),
+            None => Err(ErrorCode::IllegalStream(format!(
+                "stream '{}' has no option '{}'",
+                self.table_info.name, key
+            ))),
+        }
+    }
+
+    fn offset(&self) -> Option<String> {
+        self.table_info
+            .options()
+            .get(OPT_KEY_STREAM_OFFSET)
+            .cloned()
+    }
+
+    /// Resolves the table the stream is created on, which must be the same table as the one
+    /// the stream was created on and still have the columns of the stream.
+    async fn source_table(&self, ctx: &Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
+        let table = ctx
+            .get_table(
+                self.table_info.catalog(),
+                self.option(OPT_KEY_STREAM_SOURCE_DATABASE)?,
+                self.option(OPT_KEY_STREAM_SOURCE_TABLE)?,
+            )
+            .await?;
+        if table.get_id().to_string() != self.option(OPT_KEY_STREAM_SOURCE_TABLE_ID)? {
+            return Err(ErrorCode::IllegalStream(format!(
+                "the table of stream '{}' has been dropped or re-created",
+                self.table_info.name
+            )));
+        }
+
+        let stream_schema = self.table_info.schema();
+        let stream_fields = stream_schema.fields();
+        let num_fields = stream_fields.len() - stream_change_fields().len();
+        let source_schema = table.schema().remove_virtual_computed_fields();
+        let source_fields = source_schema.fields();
+        let same_columns = source_fields.len() == num_fields
+            && source_fields
+                .iter()
+                .zip(stream_fields.iter())
+                .all(|(a, b)| a.name() == b.name() && a.data_type() == b.data_type());
+        if !same_columns {
+            return Err(ErrorCode::IllegalStream(format!(
+                "the columns of the table of stream '{}' have been changed",
+                self.table_info.name
+            )));
+        }
+        Ok(table)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let source = self.source_table(&ctx).await?;
+        let fuse_table = FuseTable::try_from_table(source.as_ref())?;
+
+        // The offset moves to the snapshot the changes are read up to.
+        let mut options = self.table_info.options().clone();
+        match fuse_table.snapshot_loc().await? {
+            Some(location) => options.insert(OPT_KEY_STREAM_OFFSET.to_string(), location),
+            None => options.remove(OPT_KEY_STREAM_OFFSET),
+        };
+        ctx.add_consumed_stream(UpdateStreamMetaReq {
+            stream_id: self.get_id(),
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            options,
+        });
+
+        let offset = self.offset();
+        let changes = fuse_table
+            .changed_blocks(ctx.clone(), offset.as_deref())
+            .await?;
+        let source_table_info = source.get_table_info();
+        let parts = changes
+            .into_iter()
+            .map(|changes| {
+                let part = StreamPartInfo {
+                    source_table_info: source_table_info.clone(),
+                    changes,
+                };
+                Arc::new(Box::new(part) as Box<dyn PartInfo>)
+            })
+            .collect();
+        Ok((
+            PartStatistics::default(),
+            Partitions::create(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::max(1, std::cmp::min(max_threads, plan.parts.len()));
+        pipeline.add_source(
+            |output| StreamSource::create(ctx.clone(), output),
+            max_threads,
+        )
+    }
+}
+
+/// The changes of a group of blocks of the table a stream is created on, since the offset of
+/// the stream.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct StreamPartInfo {
+    pub source_table_info: TableInfo,
+    pub changes: ChangedBlocks,
+}
+
+#[typetag::serde(name = "stream")]
+impl PartInfo for StreamPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<StreamPartInfo>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        for block in self.changes.deleted.iter().chain(&self.changes.inserted) {
+            block.location.hash(&mut s);
+        }
+        s.finish()
+    }
+}
+
+/// Reads the changes of the partitions block by block.
+struct StreamSource {
+    ctx: Arc<dyn TableContext>,
+    reader: Option<ChangesReader>,
+}
+
+impl StreamSource {
+    pub fn create(ctx: Arc<dyn TableContext>, output: Arc<OutputPort>) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, StreamSource { ctx, reader: None })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for StreamSource {
+    const NAME: &'static str = "stream";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(reader) = &mut self.reader {
+                if let Some(block) = reader.next_block().await? {
+                    return Ok(Some(block));
+                }
+                self.reader = None;
+            }
+
+            let part = match self.ctx.get_partition() {
+                Some(part) => part,
+                None => return Ok(None),
+            };
+            let part = match part.as_any().downcast_ref::<StreamPartInfo>() {
+                Some(part) => part,
+                None => {
+                    return Err(ErrorCode::Internal(
+                        "Cannot downcast from PartInfo to StreamPartInfo.",
+                    ));
+                }
+            };
+
+            let fuse_table = FuseTable::do_create(part.source_table_info.clone())?;
+            let reader = fuse_table
+                .changes_reader(&self.ctx, part.changes.clone())
+                .await?;
+            self.reader = Some(reader);
+        }
+    }
+}
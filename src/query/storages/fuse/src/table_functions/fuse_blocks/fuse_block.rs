@@ -108,7 +108,7 @@ impl<'a> FuseBlock<'a> {
         let segments_io = SegmentsIO::create(
             self.ctx.clone(),
             self.table.operator.clone(),
-            self.table.schema(),
+            self.table.storage_schema(),
         );
         let segments = segments_io.read_segments(&snapshot.segments).await?;
         for segment in segments {
@@ -90,7 +90,7 @@ impl<'a> FuseSegment<'a> {
         let segments_io = SegmentsIO::create(
             self.ctx.clone(),
             self.table.operator.clone(),
-            self.table.schema(),
+            self.table.storage_schema(),
         );
         let segments = segments_io.read_segments(segment_locations).await?;
         for (idx, segment) in segments.iter().enumerate() {
@@ -0,0 +1,84 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// A maintenance action run on a table by the background maintenance service.
+#[derive(Clone)]
+pub struct BackgroundTaskLogElement {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub database: String,
+    pub table: String,
+    /// The maintenance action, like `compact_segments` or `purge`.
+    pub action: String,
+    /// What scheduled the action, a `commit` to the table or the periodic `check`.
+    pub trigger: String,
+    /// Empty if the action succeeded.
+    pub error: String,
+}
+
+impl SystemLogElement for BackgroundTaskLogElement {
+    const TABLE_NAME: &'static str = "background_tasks";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("start_time", TableDataType::Timestamp),
+            TableField::new("end_time", TableDataType::Timestamp),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("action", TableDataType::String),
+            TableField::new("trigger", TableDataType::String),
+            TableField::new("error", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.start_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.end_time).as_ref());
+        for value in [
+            &self.database,
+            &self.table,
+            &self.action,
+            &self.trigger,
+            &self.error,
+        ] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.as_bytes().to_vec()).as_ref());
+        }
+        Ok(())
+    }
+}
+
+pub type BackgroundTasksQueue = SystemLogQueue<BackgroundTaskLogElement>;
+pub type BackgroundTasksTable = SystemLogTable<BackgroundTaskLogElement>;
@@ -15,6 +15,7 @@
 #![allow(clippy::uninlined_format_args)]
 #![feature(type_alias_impl_trait)]
 
+mod background_tasks_table;
 mod build_options_table;
 mod catalogs_table;
 mod clustering_history_table;
@@ -41,6 +42,9 @@ mod tables_table;
 mod tracing_table;
 mod users_table;
 
+pub use background_tasks_table::BackgroundTaskLogElement;
+pub use background_tasks_table::BackgroundTasksQueue;
+pub use background_tasks_table::BackgroundTasksTable;
 pub use build_options_table::BuildOptionsTable;
 pub use catalogs_table::CatalogsTable;
 pub use clustering_history_table::ClusteringHistoryLogElement;